@@ -0,0 +1,50 @@
+//! Benchmarks for loading, updating, and drawing skeletons of various sizes.
+//!
+//! Run with `cargo bench --features bench`, or `cargo bench --features bench -- <filter>` to run a
+//! subset, such as `cargo bench --features bench -- draw/simple/medium`. Results are written to
+//! `target/criterion`.
+
+use rusty_spine::bench::{
+    bench_all,
+    criterion::{criterion_group, criterion_main, Criterion},
+    BenchSkeleton,
+};
+
+const BENCH_SKELETONS: [BenchSkeleton; 3] = [
+    BenchSkeleton::new(
+        "small",
+        include_bytes!("../assets/coin/export/coin.atlas"),
+        "animation",
+    )
+    .with_json(include_bytes!("../assets/coin/export/coin-pro.json"))
+    .with_binary(include_bytes!("../assets/coin/export/coin-pro.skel")),
+    BenchSkeleton::new(
+        "medium",
+        include_bytes!("../assets/spineboy/export/spineboy.atlas"),
+        "portal",
+    )
+    .with_json(include_bytes!(
+        "../assets/spineboy/export/spineboy-pro.json"
+    ))
+    .with_binary(include_bytes!(
+        "../assets/spineboy/export/spineboy-pro.skel"
+    )),
+    BenchSkeleton::new(
+        "large",
+        include_bytes!("../assets/celestial-circus/export/celestial-circus.atlas"),
+        "swing",
+    )
+    .with_json(include_bytes!(
+        "../assets/celestial-circus/export/celestial-circus-pro.json"
+    ))
+    .with_binary(include_bytes!(
+        "../assets/celestial-circus/export/celestial-circus-pro.skel"
+    )),
+];
+
+fn benches(c: &mut Criterion) {
+    bench_all(c, &BENCH_SKELETONS);
+}
+
+criterion_group!(group, benches);
+criterion_main!(group);
@@ -19,6 +19,22 @@ bitflags! {
     }
 }
 
+/// Cheap features are checked in every combination, while features which pull in large
+/// dependencies are only checked on their own.
+const FEATURES: Features = Features {
+    combined: &["draw_functions", "mint", "strict-ffi", "compat"],
+    separate: &[
+        "libc",
+        "glam",
+        "debug_backtraces",
+        "profile",
+        "parallel",
+        "bevy",
+        "wgpu-renderer",
+        "bench",
+    ],
+};
+
 fn main() -> anyhow::Result<()> {
     let arguments = [
         ("check", Check::CHECK),
@@ -49,10 +65,17 @@ fn main() -> anyhow::Result<()> {
 
     let sh = Shell::new()?;
     if what_to_run.contains(Check::CHECK) {
-        check(&sh, Target::Default, Features(&["libc", "mint"]))?;
+        check(&sh, Target::Default, FEATURES)?;
     }
     if what_to_run.contains(Check::WASM_CHECK) {
-        check(&sh, Target::Wasm, Features(&["mint"]))?;
+        check(
+            &sh,
+            Target::Wasm,
+            Features {
+                combined: &["mint"],
+                separate: &[],
+            },
+        )?;
     }
     if what_to_run.contains(Check::EXAMPLE_CHECK) {
         example_check(&sh)?;
@@ -61,7 +84,7 @@ fn main() -> anyhow::Result<()> {
         fmt(&sh)?;
     }
     if what_to_run.contains(Check::TEST) {
-        test(&sh, Features(&["libc", "mint"]))?;
+        test(&sh, FEATURES)?;
     }
     if what_to_run.contains(Check::DOC_TEST) {
         doc_test(&sh)?;
@@ -79,9 +102,10 @@ fn check(sh: &Shell, target: Target, features: Features) -> anyhow::Result<()> {
     let target_flags = &target.flags();
     let feature_combination_flags = features.combination_flags();
     for feature_flags in feature_combination_flags.iter() {
+        // Builds without `std` need `libm` for float math.
         cmd!(
             sh,
-            "cargo rustc {target_flags...} {feature_flags...} -- -D warnings"
+            "cargo rustc {target_flags...} {feature_flags...} --features libm -- -D warnings"
         )
         .run()?;
     }
@@ -103,9 +127,10 @@ fn fmt(sh: &Shell) -> anyhow::Result<()> {
 fn test(sh: &Shell, features: Features) -> anyhow::Result<()> {
     let feature_combination_flags = features.combination_flags();
     for feature_flags in feature_combination_flags.iter() {
+        // The tests load assets from disk, which requires `std`.
         cmd!(
             sh,
-            "cargo test --workspace --lib --bins --tests {feature_flags...}"
+            "cargo test --workspace --lib --bins --tests {feature_flags...} --features std"
         )
         .run()?;
     }
@@ -14,15 +14,20 @@ impl Target {
     }
 }
 
-pub struct Features(pub &'static [&'static str]);
+pub struct Features {
+    /// Features to run with in every combination.
+    pub combined: &'static [&'static str],
+    /// Features to run with once each, on their own.
+    pub separate: &'static [&'static str],
+}
 
 impl Features {
     pub fn combination_flags(&self) -> Vec<Vec<String>> {
         let mut feature_combinations: Vec<Vec<String>> =
             vec![vec!["--no-default-features".to_owned()]];
-        for k in 1..=self.0.len() {
+        for k in 1..=self.combined.len() {
             feature_combinations.extend::<Vec<_>>(
-                self.0
+                self.combined
                     .iter()
                     .combinations(k)
                     .map(|features| {
@@ -35,6 +40,13 @@ impl Features {
                     .collect::<Vec<_>>(),
             );
         }
+        feature_combinations.extend(self.separate.iter().map(|feature| {
+            vec![
+                "--no-default-features".to_owned(),
+                "--features".to_owned(),
+                (*feature).to_owned(),
+            ]
+        }));
         feature_combinations
     }
 }
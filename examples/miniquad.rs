@@ -55,7 +55,7 @@ use miniquad::*;
 use rusty_spine::{
     atlas::{AtlasFilter, AtlasFormat, AtlasWrap},
     controller::{SkeletonController, SkeletonControllerSettings},
-    draw::{ColorSpace, CullDirection},
+    draw::{self, ColorSpace, CullDirection},
     AnimationEvent, AnimationStateData, Atlas, BlendMode, Color, Physics, SkeletonBinary,
     SkeletonJson,
 };
@@ -235,6 +235,7 @@ impl Spine {
                 premultiplied_alpha,
                 cull_direction: CullDirection::CounterClockwise,
                 color_space: ColorSpace::SRGB,
+                ..Default::default()
             });
 
         // Listen for animation events
@@ -244,8 +245,14 @@ impl Spine {
                 AnimationEvent::Start { track_entry } => {
                     println!("Animation {} started!", track_entry.track_index());
                 }
-                AnimationEvent::Interrupt { track_entry } => {
-                    println!("Animation {} interrupted!", track_entry.track_index());
+                AnimationEvent::Interrupt {
+                    track_entry,
+                    reason,
+                } => {
+                    println!(
+                        "Animation {} interrupted ({reason:?})!",
+                        track_entry.track_index()
+                    );
                 }
                 AnimationEvent::End { track_entry } => {
                     println!("Animation {} ended!", track_entry.track_index());
@@ -312,15 +319,10 @@ impl Spine {
 /// Convert a [`rusty_spine::BlendMode`] to a pair of [`miniquad::BlendState`]s. One for alpha, one
 /// for color.
 ///
-/// Spine supports 4 different blend modes:
-/// - [`rusty_spine::BlendMode::Additive`]
-/// - [`rusty_spine::BlendMode::Multiply`]
-/// - [`rusty_spine::BlendMode::Normal`]
-/// - [`rusty_spine::BlendMode::Screen`]
-///
-/// And blend states are different depending on if the texture has premultiplied alpha values.
-///
-/// So, 8 blend states must be supported. See [`GetBlendStates::get_blend_states`] below.
+/// Spine supports 4 different blend modes, and blend states are different depending on if the
+/// texture has premultiplied alpha values, so 8 blend states must be supported. The
+/// renderer-agnostic factors for each are provided by [`rusty_spine::BlendMode::blend_factors`],
+/// so they only need to be converted to miniquad's types.
 struct BlendStates {
     alpha_blend: BlendState,
     color_blend: BlendState,
@@ -332,107 +334,48 @@ trait GetBlendStates {
 
 impl GetBlendStates for BlendMode {
     fn get_blend_states(&self, premultiplied_alpha: bool) -> BlendStates {
-        match self {
-            Self::Additive => match premultiplied_alpha {
-                // Case 1: Additive Blend Mode, Normal Alpha
-                false => BlendStates {
-                    alpha_blend: BlendState::new(Equation::Add, BlendFactor::One, BlendFactor::One),
-                    color_blend: BlendState::new(
-                        Equation::Add,
-                        BlendFactor::Value(BlendValue::SourceAlpha),
-                        BlendFactor::One,
-                    ),
-                },
-                // Case 2: Additive Blend Mode, Premultiplied Alpha
-                true => BlendStates {
-                    alpha_blend: BlendState::new(Equation::Add, BlendFactor::One, BlendFactor::One),
-                    color_blend: BlendState::new(Equation::Add, BlendFactor::One, BlendFactor::One),
-                },
-            },
-            Self::Multiply => match premultiplied_alpha {
-                // Case 3: Multiply Blend Mode, Normal Alpha
-                false => BlendStates {
-                    alpha_blend: BlendState::new(
-                        Equation::Add,
-                        BlendFactor::OneMinusValue(BlendValue::SourceAlpha),
-                        BlendFactor::OneMinusValue(BlendValue::SourceAlpha),
-                    ),
-                    color_blend: BlendState::new(
-                        Equation::Add,
-                        BlendFactor::Value(BlendValue::DestinationColor),
-                        BlendFactor::OneMinusValue(BlendValue::SourceAlpha),
-                    ),
-                },
-                // Case 4: Multiply Blend Mode, Premultiplied Alpha
-                true => BlendStates {
-                    alpha_blend: BlendState::new(
-                        Equation::Add,
-                        BlendFactor::OneMinusValue(BlendValue::SourceAlpha),
-                        BlendFactor::OneMinusValue(BlendValue::SourceAlpha),
-                    ),
-                    color_blend: BlendState::new(
-                        Equation::Add,
-                        BlendFactor::Value(BlendValue::DestinationColor),
-                        BlendFactor::OneMinusValue(BlendValue::SourceAlpha),
-                    ),
-                },
-            },
-            Self::Normal => match premultiplied_alpha {
-                // Case 5: Normal Blend Mode, Normal Alpha
-                false => BlendStates {
-                    alpha_blend: BlendState::new(
-                        Equation::Add,
-                        BlendFactor::One,
-                        BlendFactor::OneMinusValue(BlendValue::SourceAlpha),
-                    ),
-                    color_blend: BlendState::new(
-                        Equation::Add,
-                        BlendFactor::Value(BlendValue::SourceAlpha),
-                        BlendFactor::OneMinusValue(BlendValue::SourceAlpha),
-                    ),
-                },
-                // Case 6: Normal Blend Mode, Premultiplied Alpha
-                true => BlendStates {
-                    alpha_blend: BlendState::new(
-                        Equation::Add,
-                        BlendFactor::One,
-                        BlendFactor::OneMinusValue(BlendValue::SourceAlpha),
-                    ),
-                    color_blend: BlendState::new(
-                        Equation::Add,
-                        BlendFactor::One,
-                        BlendFactor::OneMinusValue(BlendValue::SourceAlpha),
-                    ),
-                },
-            },
-            Self::Screen => match premultiplied_alpha {
-                // Case 7: Screen Blend Mode, Normal Alpha
-                false => BlendStates {
-                    alpha_blend: BlendState::new(
-                        Equation::Add,
-                        BlendFactor::OneMinusValue(BlendValue::SourceColor),
-                        BlendFactor::OneMinusValue(BlendValue::SourceAlpha),
-                    ),
-                    color_blend: BlendState::new(
-                        Equation::Add,
-                        BlendFactor::One,
-                        BlendFactor::OneMinusValue(BlendValue::SourceAlpha),
-                    ),
-                },
-                // Case 8: Screen Blend Mode, Premultiplied Alpha
-                true => BlendStates {
-                    alpha_blend: BlendState::new(
-                        Equation::Add,
-                        BlendFactor::OneMinusValue(BlendValue::SourceColor),
-                        BlendFactor::OneMinusValue(BlendValue::SourceAlpha),
-                    ),
-                    color_blend: BlendState::new(
-                        Equation::Add,
-                        BlendFactor::One,
-                        BlendFactor::OneMinusValue(BlendValue::SourceAlpha),
-                    ),
-                },
-            },
+        fn convert_factor(factor: draw::BlendFactor) -> BlendFactor {
+            match factor {
+                draw::BlendFactor::Zero => BlendFactor::Zero,
+                draw::BlendFactor::One => BlendFactor::One,
+                draw::BlendFactor::SourceColor => BlendFactor::Value(BlendValue::SourceColor),
+                draw::BlendFactor::OneMinusSourceColor => {
+                    BlendFactor::OneMinusValue(BlendValue::SourceColor)
+                }
+                draw::BlendFactor::SourceAlpha => BlendFactor::Value(BlendValue::SourceAlpha),
+                draw::BlendFactor::OneMinusSourceAlpha => {
+                    BlendFactor::OneMinusValue(BlendValue::SourceAlpha)
+                }
+                draw::BlendFactor::DestinationColor => {
+                    BlendFactor::Value(BlendValue::DestinationColor)
+                }
+                draw::BlendFactor::OneMinusDestinationColor => {
+                    BlendFactor::OneMinusValue(BlendValue::DestinationColor)
+                }
+                draw::BlendFactor::DestinationAlpha => {
+                    BlendFactor::Value(BlendValue::DestinationAlpha)
+                }
+                draw::BlendFactor::OneMinusDestinationAlpha => {
+                    BlendFactor::OneMinusValue(BlendValue::DestinationAlpha)
+                }
+            }
+        }
+        fn convert_component(component: draw::BlendComponent) -> BlendState {
+            let equation = match component.equation {
+                draw::BlendEquation::Add => Equation::Add,
+                draw::BlendEquation::Subtract => Equation::Subtract,
+                draw::BlendEquation::ReverseSubtract => Equation::ReverseSubtract,
+            };
+            BlendState::new(
+                equation,
+                convert_factor(component.source_factor),
+                convert_factor(component.destination_factor),
+            )
+        }
+        let blend_factors = self.blend_factors(premultiplied_alpha);
+        BlendStates {
+            alpha_blend: convert_component(blend_factors.alpha),
+            color_blend: convert_component(blend_factors.color),
         }
     }
 }
@@ -762,26 +705,21 @@ fn main() {
                 }
             }
         }
-        atlas_page
-            .renderer_object()
-            .set(SpineTexture::NeedsToBeLoaded {
-                path: path.to_owned(),
-                min_filter: convert_filter(atlas_page.min_filter()),
-                mag_filter: convert_filter(atlas_page.mag_filter()),
-                x_wrap: convert_wrap(atlas_page.u_wrap()),
-                y_wrap: convert_wrap(atlas_page.v_wrap()),
-                format: convert_format(atlas_page.format()),
-            });
+        atlas_page.set_texture(SpineTexture::NeedsToBeLoaded {
+            path: path.to_owned(),
+            min_filter: convert_filter(atlas_page.min_filter()),
+            mag_filter: convert_filter(atlas_page.mag_filter()),
+            x_wrap: convert_wrap(atlas_page.u_wrap()),
+            y_wrap: convert_wrap(atlas_page.v_wrap()),
+            format: convert_format(atlas_page.format()),
+        });
     });
     let texture_delete_queue: Arc<Mutex<Vec<Texture>>> = Arc::new(Mutex::new(vec![]));
     let texture_delete_queue_cb = texture_delete_queue.clone();
-    rusty_spine::extension::set_dispose_texture_cb(move |atlas_page| unsafe {
-        if let Some(SpineTexture::Loaded(texture)) =
-            atlas_page.renderer_object().get::<SpineTexture>()
-        {
-            texture_delete_queue_cb.lock().unwrap().push(*texture);
+    rusty_spine::extension::set_dispose_texture_cb(move |atlas_page| {
+        if let Some(SpineTexture::Loaded(texture)) = atlas_page.take_texture::<SpineTexture>() {
+            texture_delete_queue_cb.lock().unwrap().push(texture);
         }
-        atlas_page.renderer_object().dispose::<SpineTexture>();
     });
     let conf = conf::Conf {
         window_title: "rusty_spine".to_owned(),
@@ -1,8 +1,16 @@
+use crate::prelude::*;
 use crate::{
-    c::spAnimation,
-    c_interface::{NewFromPtr, SyncPtr},
+    c::{
+        spAnimation, spAnimation_apply, spAttachmentTimeline, spEvent, spEventTimeline, spTimeline,
+    },
+    c_interface::{from_c_str, CTmpPtrIterator, CTmpRef, NewFromPtr, SyncPtr},
+    error::SpineError,
+    event::Event,
+    skeleton::Skeleton,
 };
 
+use animation::*;
+
 /// Stores timelines for animating a skeleton.
 ///
 /// [Spine API Reference](http://esotericsoftware.com/spine-api-reference#Animation)
@@ -33,6 +41,886 @@ impl Animation {
         duration,
         f32
     );
+
+    /// The number of timelines in the animation.
+    #[must_use]
+    pub fn timelines_count(&self) -> usize {
+        unsafe { (*self.c_ptr_ref().timelines).size as usize }
+    }
+
+    /// An iterator over the [`Timeline`]s in the animation, for inspecting what the animation
+    /// keys.
+    ///
+    /// ```
+    /// # #[path="./test.rs"]
+    /// # mod test;
+    /// use rusty_spine::animation::{TimelineTarget, TimelineType};
+    ///
+    /// # let (skeleton, _) = test::TestAsset::spineboy().instance(true);
+    /// let skeleton_data = skeleton.data();
+    /// let walk = skeleton_data.find_animation("walk").unwrap();
+    /// for timeline in walk.timelines() {
+    ///     if let (TimelineType::Rotate, TimelineTarget::Bone(bone_index)) =
+    ///         (timeline.timeline_type(), timeline.target())
+    ///     {
+    ///         let bone_data = skeleton_data.bones().nth(bone_index).unwrap();
+    ///         println!("{} rotates {} times", bone_data.name(), timeline.frame_count());
+    ///     }
+    /// }
+    /// ```
+    #[must_use]
+    pub fn timelines(&self) -> CTmpPtrIterator<'_, Self, Timeline, spTimeline> {
+        CTmpPtrIterator::new(
+            self,
+            unsafe { (*self.c_ptr_ref().timelines).items },
+            self.timelines_count(),
+        )
+    }
+
+    /// The timeline at `index`, see [`timelines`](`Self::timelines`).
+    #[must_use]
+    pub fn timeline_at_index(&self, index: usize) -> Option<CTmpRef<'_, Self, Timeline>> {
+        if index < self.timelines_count() {
+            Some(CTmpRef::new(self, unsafe {
+                Timeline::new_from_ptr(*(*self.c_ptr_ref().timelines).items.add(index))
+            }))
+        } else {
+            None
+        }
+    }
+
+    /// Every event keyed in the animation, sorted by time. Each [`Event`] has the time it is
+    /// keyed, its payload of int, float, string, volume, and balance values, and the
+    /// [`EventData`](`crate::EventData`) it was keyed from. Useful for exporting event timings to
+    /// external tools, such as for generating hitbox or sound timelines, without applying the
+    /// animation.
+    ///
+    /// ```
+    /// # #[path="./test.rs"]
+    /// # mod test;
+    /// # let (skeleton, _) = test::TestAsset::spineboy().instance(true);
+    /// let skeleton_data = skeleton.data();
+    /// let run = skeleton_data.find_animation("run").unwrap();
+    /// for event in run.event_schedule() {
+    ///     println!("{} at {}s", event.data().name(), event.time());
+    /// }
+    /// ```
+    #[must_use]
+    pub fn event_schedule(&self) -> Vec<CTmpRef<'_, Self, Event>> {
+        let mut events = vec![];
+        for timeline in self.timelines() {
+            if timeline.timeline_type() == TimelineType::Event {
+                let c_events = unsafe { (*timeline.c_ptr().cast::<spEventTimeline>()).events };
+                events.extend((0..timeline.frame_count()).map(|frame_index| {
+                    CTmpRef::new(self, unsafe {
+                        Event::new_from_ptr(*c_events.add(frame_index))
+                    })
+                }));
+            }
+        }
+        events.sort_by(|a, b| a.time().total_cmp(&b.time()));
+        events
+    }
+
+    /// The attachment this animation shows in the slot at `slot_index` at `time`, resolved from
+    /// its attachment timeline without applying the animation to a skeleton. Useful for knowing
+    /// what is visually equipped, such as in gear previews or server side logic. Returns [`None`]
+    /// if the animation doesn't key attachments for the slot, so the slot keeps whichever
+    /// attachment it already has.
+    ///
+    /// ```
+    /// # #[path="./test.rs"]
+    /// # mod test;
+    /// use rusty_spine::animation::KeyedAttachment;
+    ///
+    /// # let (skeleton, _) = test::TestAsset::spineboy().instance(true);
+    /// let skeleton_data = skeleton.data();
+    /// let shoot = skeleton_data.find_animation("shoot").unwrap();
+    /// let muzzle = skeleton_data.find_slot("muzzle").unwrap();
+    /// if let Some(KeyedAttachment::Named(name)) = shoot.attachment_at(muzzle.index(), 0.1) {
+    ///     println!("muzzle shows {name}");
+    /// }
+    /// ```
+    ///
+    /// `time` is not wrapped around the animation's duration, so for looping animations pass the
+    /// time modulo [`duration`](`Self::duration`).
+    #[must_use]
+    pub fn attachment_at(&self, slot_index: usize, time: f32) -> Option<KeyedAttachment<'_>> {
+        let timeline = self.timelines().find(|timeline| {
+            timeline.timeline_type() == TimelineType::Attachment
+                && timeline.target() == TimelineTarget::Slot(slot_index)
+        })?;
+        let Some(frame_index) = timeline
+            .frame_times()
+            .take_while(|frame_time| *frame_time <= time)
+            .count()
+            .checked_sub(1)
+        else {
+            return Some(KeyedAttachment::Setup);
+        };
+        let c_name = unsafe {
+            *(*timeline.c_ptr().cast::<spAttachmentTimeline>())
+                .attachmentNames
+                .add(frame_index)
+        };
+        Some(if c_name.is_null() {
+            KeyedAttachment::Hidden
+        } else {
+            KeyedAttachment::Named(unsafe { from_c_str(core::ffi::CStr::from_ptr(c_name)) })
+        })
+    }
+
+    /// Every attachment name keyed by this animation's attachment timelines, with the index of
+    /// the slot it is keyed for.
+    #[cfg(feature = "draw_functions")]
+    pub(crate) fn keyed_attachment_names(&self) -> Vec<(usize, &str)> {
+        let mut names = vec![];
+        for timeline in self.timelines() {
+            if timeline.timeline_type() != TimelineType::Attachment {
+                continue;
+            }
+            let TimelineTarget::Slot(slot_index) = timeline.target() else {
+                continue;
+            };
+            let c_names =
+                unsafe { (*timeline.c_ptr().cast::<spAttachmentTimeline>()).attachmentNames };
+            for frame_index in 0..timeline.frame_times().count() {
+                let c_name = unsafe { *c_names.add(frame_index) };
+                if !c_name.is_null() {
+                    names.push((slot_index, unsafe {
+                        from_c_str(core::ffi::CStr::from_ptr(c_name))
+                    }));
+                }
+            }
+        }
+        names
+    }
+
+    /// Apply the animation's timelines to a skeleton at `time`, without an
+    /// [`AnimationState`](`crate::AnimationState`). Useful for sampling animations directly, such
+    /// as for blending poses from game code, rendering thumbnails, or scrubbing in tools.
+    ///
+    /// ```
+    /// # #[path="./test.rs"]
+    /// # mod test;
+    /// use rusty_spine::{
+    ///     animation::{MixBlend, MixDirection},
+    ///     Physics, Skeleton,
+    /// };
+    ///
+    /// # let (skeleton_data, _) = test::TestAsset::spineboy().instance_data(true);
+    /// let mut skeleton = Skeleton::new(skeleton_data.clone());
+    /// let walk = skeleton_data.find_animation("walk").unwrap();
+    /// let mut events = vec![];
+    /// walk.apply(
+    ///     &mut skeleton,
+    ///     0.,
+    ///     0.5,
+    ///     false,
+    ///     Some(&mut events),
+    ///     1.,
+    ///     MixBlend::Setup,
+    ///     MixDirection::In,
+    /// )?;
+    /// skeleton.update_world_transform(Physics::None);
+    /// # Ok::<(), rusty_spine::SpineError>(())
+    /// ```
+    ///
+    /// - `last_time` is the time the animation was last applied, and events keyed after it and up
+    ///   to `time` are fired. Pass `-1` to fire events keyed at `time` 0.
+    /// - `time` is the time within the animation. When `looping`, it wraps around the animation's
+    ///   duration.
+    /// - `events` collects the fired events, if not `None`.
+    /// - `alpha` is how much of the animation to mix with the current or setup pose, from 0 to 1.
+    /// - `blend` controls what the animation is mixed with, see [`MixBlend`].
+    /// - `direction` is whether the animation is mixing in or out, which affects timelines that
+    ///   can't be mixed, such as attachment and draw order timelines. See [`MixDirection`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SpineError::NotFound`] if the animation is not from the skeleton's
+    /// [`SkeletonData`](`crate::SkeletonData`), as its timelines would refer to bones and slots
+    /// which don't exist.
+    #[allow(clippy::too_many_arguments)]
+    pub fn apply<'a>(
+        &'a self,
+        skeleton: &mut Skeleton,
+        last_time: f32,
+        time: f32,
+        looping: bool,
+        events: Option<&mut Vec<CTmpRef<'a, Self, Event>>>,
+        alpha: f32,
+        blend: MixBlend,
+        direction: MixDirection,
+    ) -> Result<(), SpineError> {
+        let skeleton_data = unsafe { &*skeleton.c_ptr_ref().data };
+        let animations = unsafe {
+            core::slice::from_raw_parts(
+                skeleton_data.animations,
+                skeleton_data.animationsCount as usize,
+            )
+        };
+        if !animations.contains(&self.c_ptr()) {
+            return Err(SpineError::new_not_found("Animation", self.name()));
+        }
+        // Event timelines fire each frame at most once per pass, and make two passes when
+        // `last_time` is after `time`.
+        let events_capacity = 2 * self
+            .timelines()
+            .filter(|timeline| timeline.timeline_type() == TimelineType::Event)
+            .map(|timeline| timeline.frame_count())
+            .sum::<usize>();
+        let mut c_events: Vec<*mut spEvent> = vec![core::ptr::null_mut(); events_capacity];
+        let mut events_count = 0;
+        unsafe {
+            spAnimation_apply(
+                self.c_ptr(),
+                skeleton.c_ptr(),
+                last_time,
+                time,
+                i32::from(looping),
+                if events.is_some() {
+                    c_events.as_mut_ptr()
+                } else {
+                    core::ptr::null_mut()
+                },
+                &mut events_count,
+                alpha,
+                blend as _,
+                direction as _,
+            );
+        }
+        if let Some(events) = events {
+            events.extend(
+                c_events[..events_count as usize]
+                    .iter()
+                    .map(|c_event| CTmpRef::new(self, unsafe { Event::new_from_ptr(*c_event) })),
+            );
+        }
+        Ok(())
+    }
+
     c_ptr!(c_animation, spAnimation);
-    // TODO: timeline accessors
+}
+
+pub mod animation {
+    //! Types related to animations.
+    //!
+    //! To inspect the timelines of an animation, see [`Animation::timelines`].
+
+    use crate::c::{
+        spAlphaTimeline, spAttachmentTimeline, spCurveTimeline, spDeformTimeline,
+        spIkConstraintTimeline, spInheritTimeline, spMixBlend, spMixDirection,
+        spPathConstraintMixTimeline, spPhysicsConstraintResetTimeline, spPhysicsConstraintTimeline,
+        spRotateTimeline, spSequenceTimeline, spTimelineType, spTimeline_getDuration,
+        spTransformConstraintTimeline,
+    };
+
+    use super::*;
+
+    /// A timeline of keyframes for a single property of a skeleton.
+    ///
+    /// Each frame has a time followed by the timeline's values, see
+    /// [`frame_values`](`Self::frame_values`) for what the values are for each timeline type.
+    ///
+    /// [Spine API Reference](http://esotericsoftware.com/spine-api-reference#Timeline)
+    #[derive(Debug)]
+    pub struct Timeline {
+        c_timeline: SyncPtr<spTimeline>,
+    }
+
+    impl NewFromPtr<spTimeline> for Timeline {
+        unsafe fn new_from_ptr(c_timeline: *mut spTimeline) -> Self {
+            Self {
+                c_timeline: SyncPtr(c_timeline),
+            }
+        }
+    }
+
+    impl Timeline {
+        c_accessor_enum!(
+            /// The type of the timeline, which determines what it keys.
+            timeline_type,
+            type_0,
+            TimelineType
+        );
+        c_accessor!(
+            /// The number of keyframes in the timeline.
+            frame_count,
+            frameCount,
+            usize
+        );
+        c_accessor!(
+            /// The number of entries stored per keyframe, which is the time followed by the
+            /// values.
+            frame_entries,
+            frameEntries,
+            usize
+        );
+
+        /// The time of the last keyframe, in seconds.
+        #[must_use]
+        pub fn duration(&self) -> f32 {
+            unsafe { spTimeline_getDuration(self.c_ptr()) }
+        }
+
+        /// The skeleton part keyed by the timeline.
+        #[must_use]
+        pub fn target(&self) -> TimelineTarget {
+            fn index(index: i32) -> usize {
+                index.max(0) as usize
+            }
+            let c_timeline = self.c_ptr();
+            unsafe {
+                match self.timeline_type() {
+                    TimelineType::Rotate
+                    | TimelineType::Translate
+                    | TimelineType::TranslateX
+                    | TimelineType::TranslateY
+                    | TimelineType::Scale
+                    | TimelineType::ScaleX
+                    | TimelineType::ScaleY
+                    | TimelineType::Shear
+                    | TimelineType::ShearX
+                    | TimelineType::ShearY => {
+                        // Every bone timeline is a curve timeline followed by the bone index
+                        TimelineTarget::Bone(index(
+                            (*c_timeline.cast::<spRotateTimeline>()).boneIndex,
+                        ))
+                    }
+                    TimelineType::Inherit => TimelineTarget::Bone(index(
+                        (*c_timeline.cast::<spInheritTimeline>()).boneIndex,
+                    )),
+                    TimelineType::Rgba
+                    | TimelineType::Rgb
+                    | TimelineType::Rgba2
+                    | TimelineType::Rgb2
+                    | TimelineType::Alpha => {
+                        // Every color timeline is a curve timeline followed by the slot index
+                        TimelineTarget::Slot(index(
+                            (*c_timeline.cast::<spAlphaTimeline>()).slotIndex,
+                        ))
+                    }
+                    TimelineType::Attachment => TimelineTarget::Slot(index(
+                        (*c_timeline.cast::<spAttachmentTimeline>()).slotIndex,
+                    )),
+                    TimelineType::Deform => TimelineTarget::Slot(index(
+                        (*c_timeline.cast::<spDeformTimeline>()).slotIndex,
+                    )),
+                    TimelineType::Sequence => TimelineTarget::Slot(index(
+                        (*c_timeline.cast::<spSequenceTimeline>()).slotIndex,
+                    )),
+                    TimelineType::IkConstraint => TimelineTarget::IkConstraint(index(
+                        (*c_timeline.cast::<spIkConstraintTimeline>()).ikConstraintIndex,
+                    )),
+                    TimelineType::TransformConstraint => {
+                        TimelineTarget::TransformConstraint(index(
+                            (*c_timeline.cast::<spTransformConstraintTimeline>())
+                                .transformConstraintIndex,
+                        ))
+                    }
+                    TimelineType::PathConstraintPosition
+                    | TimelineType::PathConstraintSpacing
+                    | TimelineType::PathConstraintMix => TimelineTarget::PathConstraint(index(
+                        (*c_timeline.cast::<spPathConstraintMixTimeline>()).pathConstraintIndex,
+                    )),
+                    TimelineType::PhysicsConstraintInertia
+                    | TimelineType::PhysicsConstraintStrength
+                    | TimelineType::PhysicsConstraintDamping
+                    | TimelineType::PhysicsConstraintMass
+                    | TimelineType::PhysicsConstraintWind
+                    | TimelineType::PhysicsConstraintGravity
+                    | TimelineType::PhysicsConstraintMix => {
+                        let physics_constraint_index = (*c_timeline
+                            .cast::<spPhysicsConstraintTimeline>())
+                        .physicsConstraintIndex;
+                        TimelineTarget::PhysicsConstraint(
+                            usize::try_from(physics_constraint_index).ok(),
+                        )
+                    }
+                    TimelineType::PhysicsConstraintReset => {
+                        let physics_constraint_index =
+                            (*c_timeline.cast::<spPhysicsConstraintResetTimeline>())
+                                .physicsConstraintIndex;
+                        TimelineTarget::PhysicsConstraint(
+                            usize::try_from(physics_constraint_index).ok(),
+                        )
+                    }
+                    TimelineType::DrawOrder | TimelineType::Event | TimelineType::Unknown => {
+                        TimelineTarget::Skeleton
+                    }
+                }
+            }
+        }
+
+        /// The raw keyframe data, with [`frame_entries`](`Self::frame_entries`) entries for each
+        /// keyframe.
+        #[must_use]
+        pub fn frames(&self) -> &[f32] {
+            unsafe {
+                let frames = self.c_ptr_ref().frames;
+                if frames.is_null() || (*frames).items.is_null() {
+                    &[]
+                } else {
+                    core::slice::from_raw_parts((*frames).items, (*frames).size as usize)
+                }
+            }
+        }
+
+        /// An iterator over the time of each keyframe, in seconds.
+        pub fn frame_times(&self) -> impl Iterator<Item = f32> + '_ {
+            self.frames()
+                .chunks_exact(self.frame_entries().max(1))
+                .map(|frame| frame[0])
+        }
+
+        /// The time of a keyframe, in seconds.
+        #[must_use]
+        pub fn frame_time(&self, frame_index: usize) -> Option<f32> {
+            self.frame(frame_index).map(|frame| frame[0])
+        }
+
+        /// The values of a keyframe, which depend on the [`timeline_type`](`Self::timeline_type`):
+        ///
+        /// - [`Rotate`](`TimelineType::Rotate`): `[degrees]`.
+        /// - [`Translate`](`TimelineType::Translate`), [`Scale`](`TimelineType::Scale`), and
+        ///   [`Shear`](`TimelineType::Shear`): `[x, y]`.
+        /// - Single axis bone timelines, such as [`TranslateX`](`TimelineType::TranslateX`):
+        ///   `[value]`.
+        /// - [`Rgba`](`TimelineType::Rgba`): `[r, g, b, a]`.
+        /// - [`Rgb`](`TimelineType::Rgb`): `[r, g, b]`.
+        /// - [`Rgba2`](`TimelineType::Rgba2`): `[r, g, b, a, dark_r, dark_g, dark_b]`.
+        /// - [`Rgb2`](`TimelineType::Rgb2`): `[r, g, b, dark_r, dark_g, dark_b]`.
+        /// - [`Alpha`](`TimelineType::Alpha`): `[a]`.
+        ///
+        /// Values for other timeline types are stored as they are by the Spine runtime. Timelines
+        /// which store their keys separately, such as attachment, deform, draw order, and event
+        /// timelines, have no values.
+        #[must_use]
+        pub fn frame_values(&self, frame_index: usize) -> Option<&[f32]> {
+            self.frame(frame_index).map(|frame| &frame[1..])
+        }
+
+        /// How the timeline's values change from a keyframe to the next keyframe, or [`None`] if
+        /// the timeline doesn't interpolate between keyframes, such as for attachment timelines.
+        #[must_use]
+        pub fn curve_type(&self, frame_index: usize) -> Option<CurveType> {
+            if frame_index >= self.frame_count() || !self.timeline_type().is_curve() {
+                return None;
+            }
+            let curves = unsafe { (*self.c_ptr().cast::<spCurveTimeline>()).curves };
+            let curve_type = unsafe {
+                if curves.is_null() || frame_index >= (*curves).size as usize {
+                    return None;
+                }
+                *(*curves).items.add(frame_index)
+            };
+            Some(match curve_type as i32 {
+                0 => CurveType::Linear,
+                1 => CurveType::Stepped,
+                _ => CurveType::Bezier,
+            })
+        }
+
+        fn frame(&self, frame_index: usize) -> Option<&[f32]> {
+            let frame_entries = self.frame_entries();
+            self.frames()
+                .get(frame_index * frame_entries..(frame_index + 1) * frame_entries)
+        }
+
+        c_ptr!(c_timeline, spTimeline);
+    }
+
+    /// The type of a [`Timeline`], see [`Timeline::timeline_type`].
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub enum TimelineType {
+        Attachment = 0,
+        Alpha = 1,
+        PathConstraintPosition = 2,
+        PathConstraintSpacing = 3,
+        Rotate = 4,
+        ScaleX = 5,
+        ScaleY = 6,
+        ShearX = 7,
+        ShearY = 8,
+        TranslateX = 9,
+        TranslateY = 10,
+        Scale = 11,
+        Shear = 12,
+        Translate = 13,
+        Deform = 14,
+        Sequence = 15,
+        Inherit = 16,
+        IkConstraint = 17,
+        PathConstraintMix = 18,
+        PhysicsConstraintInertia = 19,
+        PhysicsConstraintStrength = 20,
+        PhysicsConstraintDamping = 21,
+        PhysicsConstraintMass = 22,
+        PhysicsConstraintWind = 23,
+        PhysicsConstraintGravity = 24,
+        PhysicsConstraintMix = 25,
+        PhysicsConstraintReset = 26,
+        Rgb2 = 27,
+        Rgba2 = 28,
+        Rgba = 29,
+        Rgb = 30,
+        TransformConstraint = 31,
+        DrawOrder = 32,
+        Event = 33,
+        Unknown = 99,
+    }
+
+    impl TimelineType {
+        /// Returns `true` for timelines which interpolate between keyframes along a curve.
+        #[must_use]
+        pub const fn is_curve(self) -> bool {
+            !matches!(
+                self,
+                Self::Attachment
+                    | Self::Sequence
+                    | Self::Inherit
+                    | Self::PhysicsConstraintReset
+                    | Self::DrawOrder
+                    | Self::Event
+                    | Self::Unknown
+            )
+        }
+    }
+
+    impl From<spTimelineType> for TimelineType {
+        fn from(timeline_type: spTimelineType) -> Self {
+            match timeline_type {
+                0 => Self::Attachment,
+                1 => Self::Alpha,
+                2 => Self::PathConstraintPosition,
+                3 => Self::PathConstraintSpacing,
+                4 => Self::Rotate,
+                5 => Self::ScaleX,
+                6 => Self::ScaleY,
+                7 => Self::ShearX,
+                8 => Self::ShearY,
+                9 => Self::TranslateX,
+                10 => Self::TranslateY,
+                11 => Self::Scale,
+                12 => Self::Shear,
+                13 => Self::Translate,
+                14 => Self::Deform,
+                15 => Self::Sequence,
+                16 => Self::Inherit,
+                17 => Self::IkConstraint,
+                18 => Self::PathConstraintMix,
+                19 => Self::PhysicsConstraintInertia,
+                20 => Self::PhysicsConstraintStrength,
+                21 => Self::PhysicsConstraintDamping,
+                22 => Self::PhysicsConstraintMass,
+                23 => Self::PhysicsConstraintWind,
+                24 => Self::PhysicsConstraintGravity,
+                25 => Self::PhysicsConstraintMix,
+                26 => Self::PhysicsConstraintReset,
+                27 => Self::Rgb2,
+                28 => Self::Rgba2,
+                29 => Self::Rgba,
+                30 => Self::Rgb,
+                31 => Self::TransformConstraint,
+                32 => Self::DrawOrder,
+                33 => Self::Event,
+                _ => Self::Unknown,
+            }
+        }
+    }
+
+    /// The skeleton part keyed by a [`Timeline`], see [`Timeline::target`]. Indices are into the
+    /// lists of the same name in [`SkeletonData`](`crate::SkeletonData`).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub enum TimelineTarget {
+        Bone(usize),
+        Slot(usize),
+        IkConstraint(usize),
+        TransformConstraint(usize),
+        PathConstraint(usize),
+        /// A physics constraint, or [`None`] for timelines which key every physics constraint.
+        PhysicsConstraint(Option<usize>),
+        /// The whole skeleton, for draw order and event timelines.
+        Skeleton,
+    }
+
+    /// The attachment an animation shows in a slot, see [`Animation::attachment_at`].
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub enum KeyedAttachment<'a> {
+        /// The slot's setup pose attachment, from
+        /// [`SlotData::attachment_name`](`crate::SlotData::attachment_name`), which is shown
+        /// before the first keyframe.
+        Setup,
+        /// The attachment with this name, in the skeleton's skin.
+        Named(&'a str),
+        /// No attachment, hiding the slot.
+        Hidden,
+    }
+
+    /// How a [`Timeline`]'s values change between keyframes, see [`Timeline::curve_type`].
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub enum CurveType {
+        /// The values change at a constant rate.
+        Linear,
+        /// The values hold until the next keyframe.
+        Stepped,
+        /// The values change along a bezier curve set in the Spine editor.
+        Bezier,
+    }
+
+    /// Controls how an animation's values are mixed with the skeleton's current values, see
+    /// [`Animation::apply`].
+    ///
+    /// [Spine API Reference](http://esotericsoftware.com/spine-api-reference#MixBlend)
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub enum MixBlend {
+        /// Mix from the setup pose, ignoring the current pose. Properties without keys at `time`
+        /// are set to the setup pose.
+        Setup = 0,
+        /// Mix from the current pose, but properties without keys at `time` are set to the setup
+        /// pose. Used for the first animation applied to a skeleton.
+        First = 1,
+        /// Mix from the current pose. Properties without keys at `time` are left unchanged.
+        Replace = 2,
+        /// Add the animation's values to the current pose, for layering animations.
+        Add = 3,
+    }
+
+    impl From<spMixBlend> for MixBlend {
+        fn from(mix_blend: spMixBlend) -> Self {
+            match mix_blend {
+                1 => Self::First,
+                2 => Self::Replace,
+                3 => Self::Add,
+                _ => Self::Setup,
+            }
+        }
+    }
+
+    /// Whether an animation is mixing in or out, see [`Animation::apply`]. Attachment and draw
+    /// order timelines only apply when mixing in, since they can't be partially applied.
+    ///
+    /// [Spine API Reference](http://esotericsoftware.com/spine-api-reference#MixDirection)
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub enum MixDirection {
+        In = 0,
+        Out = 1,
+    }
+
+    impl From<spMixDirection> for MixDirection {
+        fn from(mix_direction: spMixDirection) -> Self {
+            match mix_direction {
+                1 => Self::Out,
+                _ => Self::In,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{test::TestAsset, AnimationState, Physics, Skeleton, SpineError};
+
+    use super::animation::*;
+
+    #[test]
+    fn attachment_at() {
+        let (skeleton, _) = TestAsset::spineboy().instance(true);
+        let skeleton_data = skeleton.data();
+        let muzzle = skeleton_data.find_slot("muzzle").unwrap().index();
+        let shoot = skeleton_data.find_animation("shoot").unwrap();
+        assert_eq!(
+            shoot.attachment_at(muzzle, 0.),
+            Some(KeyedAttachment::Setup)
+        );
+        assert_eq!(
+            shoot.attachment_at(muzzle, 0.0333),
+            Some(KeyedAttachment::Named("muzzle01"))
+        );
+        assert_eq!(
+            shoot.attachment_at(muzzle, 0.15),
+            Some(KeyedAttachment::Named("muzzle04"))
+        );
+        assert_eq!(
+            shoot.attachment_at(muzzle, 1.),
+            Some(KeyedAttachment::Hidden)
+        );
+
+        let walk = skeleton_data.find_animation("walk").unwrap();
+        assert_eq!(walk.attachment_at(muzzle, 0.1), None);
+    }
+
+    #[test]
+    fn event_schedule() {
+        let json = r#"{
+            "skeleton": { "spine": "4.2.00" },
+            "bones": [ { "name": "root" } ],
+            "events": {
+                "hit": { "int": 1 },
+                "sound": { "audio": "step.ogg", "volume": 0.5 }
+            },
+            "animations": { "attack": { "events": [
+                { "time": 0.1, "name": "sound", "volume": 0.75 },
+                { "time": 0.4, "name": "hit", "int": 3, "float": 2.5, "string": "heavy" },
+                { "time": 0.4, "name": "sound" }
+            ] } }
+        }"#;
+        let skeleton_data = TestAsset::spineboy()
+            .skeleton_json()
+            .read_skeleton_data(json.as_bytes())
+            .unwrap();
+        let attack = skeleton_data.find_animation("attack").unwrap();
+        let schedule = attack.event_schedule();
+        let names: Vec<_> = schedule
+            .iter()
+            .map(|event| event.data().name().to_owned())
+            .collect();
+        assert_eq!(names, ["sound", "hit", "sound"]);
+        let times: Vec<_> = schedule.iter().map(|event| event.time()).collect();
+        assert_eq!(times, [0.1, 0.4, 0.4]);
+        let hit = &schedule[1];
+        assert_eq!(hit.int_value(), 3);
+        assert_eq!(hit.float_value(), 2.5);
+        assert_eq!(hit.string_value(), "heavy");
+        let sound = &schedule[0];
+        assert_eq!(sound.data().audio_path(), "step.ogg");
+        assert_eq!(sound.data().volume(), 0.5);
+        assert_eq!(sound.volume(), 0.75);
+
+        let skeleton_data = TestAsset::spineboy().skeleton_data(true);
+        let walk = skeleton_data.find_animation("walk").unwrap();
+        let times: Vec<_> = walk.event_schedule().iter().map(|e| e.time()).collect();
+        assert_eq!(times, [0., 0.5]);
+        let idle = skeleton_data.find_animation("idle").unwrap();
+        assert!(idle.event_schedule().is_empty());
+    }
+
+    #[test]
+    fn timelines() {
+        let skeleton_data = TestAsset::spineboy().skeleton_data(true);
+        let walk = skeleton_data.find_animation("walk").unwrap();
+        assert_eq!(walk.timelines().count(), walk.timelines_count());
+        assert!(walk.timeline_at_index(walk.timelines_count()).is_none());
+
+        let head_index = skeleton_data.find_bone("head").unwrap().index();
+        let head_rotate = walk
+            .timelines()
+            .find(|timeline| {
+                timeline.timeline_type() == TimelineType::Rotate
+                    && timeline.target() == TimelineTarget::Bone(head_index)
+            })
+            .unwrap();
+        assert_eq!(head_rotate.frame_entries(), 2);
+        assert!(head_rotate.frame_count() > 1);
+        assert_eq!(head_rotate.frame_times().count(), head_rotate.frame_count());
+        assert_eq!(head_rotate.frame_time(0), Some(0.));
+        assert_eq!(head_rotate.frame_values(0).unwrap().len(), 1);
+        assert!(head_rotate
+            .frame_values(head_rotate.frame_count())
+            .is_none());
+        assert!(head_rotate.curve_type(0).is_some());
+        assert!(head_rotate.duration() <= walk.duration());
+        assert_eq!(
+            head_rotate.duration(),
+            head_rotate
+                .frame_time(head_rotate.frame_count() - 1)
+                .unwrap()
+        );
+
+        for timeline in walk.timelines() {
+            assert_ne!(timeline.timeline_type(), TimelineType::Unknown);
+            if let TimelineTarget::Bone(bone_index) = timeline.target() {
+                assert!(bone_index < skeleton_data.bones_count());
+            }
+        }
+
+        let shoot = skeleton_data.find_animation("shoot").unwrap();
+        let rgba = shoot
+            .timelines()
+            .find(|timeline| timeline.timeline_type() == TimelineType::Rgba)
+            .unwrap();
+        assert!(matches!(rgba.target(), TimelineTarget::Slot(_)));
+        assert_eq!(rgba.frame_values(0).unwrap().len(), 4);
+        let attachment = shoot
+            .timelines()
+            .find(|timeline| timeline.timeline_type() == TimelineType::Attachment)
+            .unwrap();
+        assert!(attachment.frame_values(0).unwrap().is_empty());
+        assert!(attachment.curve_type(0).is_none());
+    }
+
+    #[test]
+    fn apply() {
+        let (skeleton_data, animation_state_data) = TestAsset::spineboy().instance_data(true);
+        let mut skeleton = Skeleton::new(skeleton_data.clone());
+        let mut expected = Skeleton::new(skeleton_data.clone());
+        let mut animation_state = AnimationState::new(animation_state_data);
+        let run = skeleton_data.find_animation("run").unwrap();
+
+        let mut events = vec![];
+        run.apply(
+            &mut skeleton,
+            -1.,
+            0.3,
+            true,
+            Some(&mut events),
+            1.,
+            MixBlend::Setup,
+            MixDirection::In,
+        )
+        .unwrap();
+        skeleton.update_world_transform(Physics::None);
+        animation_state
+            .set_animation_by_name(0, "run", true)
+            .unwrap();
+        animation_state.update(0.3);
+        animation_state.apply(&mut expected);
+        expected.update_world_transform(Physics::None);
+        for (bone, expected_bone) in skeleton.bones().zip(expected.bones()) {
+            assert!((bone.world_x() - expected_bone.world_x()).abs() < 0.001);
+            assert!((bone.world_y() - expected_bone.world_y()).abs() < 0.001);
+        }
+
+        // Wrapping around the loop fires the events from `last_time` to the end, then from the
+        // start to `time`.
+        let mut event_count = |last_time: f32, time: f32, looping: bool| {
+            let mut events = vec![];
+            run.apply(
+                &mut skeleton,
+                last_time,
+                time,
+                looping,
+                Some(&mut events),
+                1.,
+                MixBlend::Replace,
+                MixDirection::In,
+            )
+            .unwrap();
+            assert!(events.iter().all(|event| event.data().name() == "footstep"));
+            events.len()
+        };
+        let all = event_count(-1., run.duration(), false);
+        assert!(all > 0);
+        assert_eq!(
+            event_count(run.duration() * 0.5, run.duration() * 1.45, true),
+            all
+        );
+
+        let alien_data = TestAsset::all()[1].skeleton_data(true);
+        let alien_run = alien_data.find_animation("run").unwrap();
+        assert!(matches!(
+            alien_run.apply(
+                &mut skeleton,
+                0.,
+                0.,
+                false,
+                None,
+                1.,
+                MixBlend::Setup,
+                MixDirection::In
+            ),
+            Err(SpineError::NotFound { .. })
+        ));
+    }
 }
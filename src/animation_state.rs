@@ -1,24 +1,39 @@
-use std::sync::Arc;
+use alloc::{
+    collections::{BTreeMap, BTreeSet},
+    sync::Arc,
+};
+use core::{
+    any::Any,
+    sync::atomic::{AtomicBool, AtomicUsize, Ordering},
+};
 
+#[cfg(not(feature = "std"))]
+use crate::math::Float;
+use crate::prelude::*;
 use crate::{
-    animation::Animation,
+    animation_mod::Animation,
     animation_state_data::AnimationStateData,
     c::{
-        c_void, spAnimation, spAnimationState, spAnimationStateData, spAnimationState_addAnimation,
+        _spAnimationState, _spEventQueue_drain, _spEventQueue_event, c_void, spAnimation,
+        spAnimationState, spAnimationStateData, spAnimationState_addAnimation,
         spAnimationState_addAnimationByName, spAnimationState_addEmptyAnimation,
         spAnimationState_apply, spAnimationState_clearListenerNotifications,
         spAnimationState_clearNext, spAnimationState_clearTrack, spAnimationState_clearTracks,
         spAnimationState_create, spAnimationState_dispose, spAnimationState_disposeStatics,
         spAnimationState_getCurrent, spAnimationState_setAnimation,
         spAnimationState_setAnimationByName, spAnimationState_setEmptyAnimation,
-        spAnimationState_setEmptyAnimations, spAnimationState_update, spEvent, spEventType,
-        spTrackEntry, spTrackEntry_getAnimationTime, spTrackEntry_getTrackComplete,
+        spAnimationState_setEmptyAnimations, spAnimationState_update, spEvent, spEventTimeline,
+        spEventType, spTrackEntry, spTrackEntry_getAnimationTime, spTrackEntry_getTrackComplete,
+        SP_TIMELINE_EVENT,
     },
     c_interface::{to_c_str, CTmpMut, CTmpRef, NewFromPtr, SyncPtr},
+    diagnostics::{self, LiveObjectKind},
     error::SpineError,
     event::Event,
+    event_queue::{EventQueue, EventQueueSettings, EventQueueStatistics},
+    random::Random,
     skeleton::Skeleton,
-    AnimationEvent,
+    AnimationEvent, InterruptReason, OwnedAnimationEvent,
 };
 
 /// Applies animations over time, queues animations for later playback, mixes (crossfading) between
@@ -32,7 +47,7 @@ use crate::{
 pub struct AnimationState {
     c_animation_state: SyncPtr<spAnimationState>,
     owns_memory: bool,
-    _animation_state_data: Option<Arc<AnimationStateData>>,
+    animation_state_data: Option<Arc<AnimationStateData>>,
 }
 
 impl NewFromPtr<spAnimationState> for AnimationState {
@@ -40,7 +55,7 @@ impl NewFromPtr<spAnimationState> for AnimationState {
         Self {
             c_animation_state: SyncPtr(c_animation_state),
             owns_memory: false,
-            _animation_state_data: None,
+            animation_state_data: None,
         }
     }
 }
@@ -49,6 +64,7 @@ impl AnimationState {
     #[must_use]
     pub fn new(animation_state_data: Arc<AnimationStateData>) -> Self {
         let c_animation_state = unsafe { spAnimationState_create(animation_state_data.c_ptr()) };
+        diagnostics::track(LiveObjectKind::AnimationState, c_animation_state);
         unsafe {
             (*c_animation_state).userData =
                 (Box::leak(Box::default()) as *mut AnimationStateUserData).cast::<c_void>();
@@ -56,18 +72,225 @@ impl AnimationState {
         Self {
             c_animation_state: SyncPtr(c_animation_state),
             owns_memory: true,
-            _animation_state_data: Some(animation_state_data),
+            animation_state_data: Some(animation_state_data),
         }
     }
 
+    /// Increments the track entry animation and mix times and delivers queued listener
+    /// notifications, discarding notifications according to the
+    /// [event queue settings](`Self::set_event_queue_settings`).
     pub fn update(&mut self, delta: f32) {
-        unsafe {
+        let _ = self.try_update(delta);
+    }
+
+    /// Same as [`update`](`Self::update`), but reports discarded notifications.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SpineError::EventQueueOverflow`] if notifications were discarded using
+    /// [`EventQueueOverflow::Error`](`crate::EventQueueOverflow::Error`).
+    pub fn try_update(&mut self, delta: f32) -> Result<(), SpineError> {
+        self.limit_event_queue(|| unsafe {
             spAnimationState_update(self.c_ptr(), delta);
-        }
+        })
     }
 
+    /// Poses the skeleton using the track entry animations and delivers queued listener
+    /// notifications, discarding notifications according to the
+    /// [event queue settings](`Self::set_event_queue_settings`). Returns `true` if any
+    /// animations were applied.
     pub fn apply(&self, skeleton: &mut Skeleton) -> bool {
-        unsafe { spAnimationState_apply(self.c_animation_state.0, skeleton.c_ptr()) != 0 }
+        let mut applied = false;
+        let _ = self.limit_event_queue(|| {
+            applied = unsafe {
+                let applied = spAnimationState_apply(self.c_animation_state.0, skeleton.c_ptr());
+                queue_offset_events(self.c_animation_state.0);
+                applied != 0
+            };
+        });
+        applied
+    }
+
+    /// Same as [`apply`](`Self::apply`), but reports discarded notifications.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SpineError::EventQueueOverflow`] if notifications were discarded using
+    /// [`EventQueueOverflow::Error`](`crate::EventQueueOverflow::Error`).
+    pub fn try_apply(&self, skeleton: &mut Skeleton) -> Result<bool, SpineError> {
+        let mut applied = false;
+        self.limit_event_queue(|| {
+            applied = unsafe {
+                let applied = spAnimationState_apply(self.c_animation_state.0, skeleton.c_ptr());
+                queue_offset_events(self.c_animation_state.0);
+                applied != 0
+            };
+        })?;
+        Ok(applied)
+    }
+
+    /// Runs `f` with the Spine event queue held back, then applies the overflow policy before
+    /// delivering the queued notifications.
+    fn limit_event_queue<F: FnOnce()>(&self, f: F) -> Result<(), SpineError> {
+        unsafe {
+            let queue = (*self.c_ptr().cast::<_spAnimationState>()).queue;
+            let user_data = self.c_ptr_ref().userData.cast::<AnimationStateUserData>();
+            if user_data.is_null() || (*queue).drainDisabled != 0 {
+                f();
+                return Ok(());
+            }
+            (*queue).drainDisabled = 1;
+            f();
+            let result = (*user_data).event_queue.limit(queue);
+            (*queue).drainDisabled = 0;
+            _spEventQueue_drain(queue);
+            (*user_data).offset_events.clear();
+            result
+        }
+    }
+
+    /// The limits for listener notifications queued during a single update or apply.
+    #[must_use]
+    pub fn event_queue_settings(&self) -> EventQueueSettings {
+        self.user_data()
+            .map(|user_data| user_data.event_queue.settings)
+            .unwrap_or_default()
+    }
+
+    /// Set the limits for listener notifications queued during a single update or apply. By
+    /// default, every notification is delivered, matching the Spine runtime.
+    ///
+    /// ```
+    /// # #[path="./test.rs"]
+    /// # mod test;
+    /// use rusty_spine::{EventQueueOverflow, EventQueueSettings};
+    ///
+    /// # let (_, mut animation_state) = test::TestAsset::spineboy().instance(true);
+    /// // Never deliver more than 32 notifications per update, even after a long frame
+    /// animation_state.set_event_queue_settings(
+    ///     EventQueueSettings::new()
+    ///         .with_capacity(32)
+    ///         .with_overflow(EventQueueOverflow::DropOldest),
+    /// );
+    /// animation_state.update(10.);
+    /// println!("{:?}", animation_state.event_queue_statistics());
+    /// ```
+    pub fn set_event_queue_settings(&mut self, settings: EventQueueSettings) {
+        let queue = unsafe { (*self.c_ptr().cast::<_spAnimationState>()).queue };
+        if let Some(user_data) = self.user_data_mut() {
+            user_data.event_queue.settings = settings;
+            unsafe { user_data.event_queue.reserve(queue) };
+        }
+    }
+
+    /// Counters for the listener notifications delivered and discarded by this animation state.
+    #[must_use]
+    pub fn event_queue_statistics(&self) -> EventQueueStatistics {
+        self.user_data()
+            .map(|user_data| user_data.event_queue.statistics)
+            .unwrap_or_default()
+    }
+
+    /// Reset the [`event_queue_statistics`](`Self::event_queue_statistics`) counters.
+    pub fn reset_event_queue_statistics(&mut self) {
+        if let Some(user_data) = self.user_data_mut() {
+            user_data.event_queue.statistics = EventQueueStatistics::default();
+        }
+    }
+
+    /// The [`TrackEntry::shortest_rotation`] setting for track entries created by this animation
+    /// state, so games which rotate whole skeletons through animation don't spin the long way
+    /// around when mixing. Defaults to `false`.
+    ///
+    /// ```
+    /// # #[path="./test.rs"]
+    /// # mod test;
+    /// # let (_, mut animation_state) = test::TestAsset::spineboy().instance(true);
+    /// animation_state.set_default_shortest_rotation(true);
+    /// let track_entry = animation_state.set_animation_by_name(0, "walk", true)?;
+    /// assert!(track_entry.shortest_rotation());
+    /// # Ok::<(), rusty_spine::SpineError>(())
+    /// ```
+    ///
+    /// Only affects track entries created after the setting is changed. The setting can still be
+    /// overridden for a single track entry with [`TrackEntry::set_shortest_rotation`].
+    pub fn set_default_shortest_rotation(&mut self, shortest_rotation: bool) {
+        if let Some(user_data) = self.user_data_mut() {
+            user_data.default_shortest_rotation = shortest_rotation;
+        }
+    }
+
+    /// The [`TrackEntry::shortest_rotation`] setting for new track entries, see
+    /// [`set_default_shortest_rotation`](`Self::set_default_shortest_rotation`).
+    #[must_use]
+    pub fn default_shortest_rotation(&self) -> bool {
+        self.user_data()
+            .is_some_and(|user_data| user_data.default_shortest_rotation)
+    }
+
+    /// Wraps a track entry just created by this animation state, applying the defaults for new
+    /// track entries.
+    unsafe fn new_track_entry(
+        &mut self,
+        c_track_entry: *mut spTrackEntry,
+    ) -> CTmpMut<'_, Self, TrackEntry> {
+        if self.default_shortest_rotation() && !c_track_entry.is_null() {
+            (*c_track_entry).shortestRotation = 1;
+        }
+        CTmpMut::new(self, TrackEntry::new_from_ptr(c_track_entry))
+    }
+
+    /// Calls `replace` with the C animation state to replace the current track entry of the track
+    /// at `track_index`, or of every track if [`None`], so their interrupt events report
+    /// `reason`.
+    fn replace_tracks<R>(
+        &mut self,
+        track_index: Option<usize>,
+        reason: InterruptReason,
+        replace: impl FnOnce(*mut spAnimationState) -> R,
+    ) -> R {
+        let c_animation_state = self.c_ptr();
+        if let Some(user_data) = self.user_data_mut() {
+            let tracks_count = unsafe { (*c_animation_state).tracksCount as usize };
+            let track_indices = track_index.map_or(0..tracks_count, |track_index| {
+                track_index..(track_index + 1).min(tracks_count)
+            });
+            for track_index in track_indices {
+                let current = unsafe { *(*c_animation_state).tracks.add(track_index) };
+                if current.is_null() {
+                    continue;
+                }
+                // The runtime discards track entries which were never applied, instead of mixing
+                // them out.
+                let reason = if unsafe { (*current).nextTrackLast } == -1. {
+                    InterruptReason::Discarded
+                } else {
+                    reason
+                };
+                user_data
+                    .interrupt_reasons
+                    .insert(TrackEntry::generation(current), reason);
+            }
+        }
+        replace(c_animation_state)
+    }
+
+    fn user_data(&self) -> Option<&AnimationStateUserData> {
+        unsafe {
+            self.c_ptr_ref()
+                .userData
+                .cast::<AnimationStateUserData>()
+                .as_ref()
+        }
+    }
+
+    fn user_data_mut(&mut self) -> Option<&mut AnimationStateUserData> {
+        unsafe {
+            self.c_ptr_ref()
+                .userData
+                .cast::<AnimationStateUserData>()
+                .as_mut()
+        }
     }
 
     /// Clears all animations in all track entries in this animation state.
@@ -80,7 +303,7 @@ impl AnimationState {
     /// Clears animations for the given track entry index in this animation state.
     pub fn clear_track(&mut self, track_index: usize) {
         unsafe {
-            spAnimationState_clearTrack(self.c_ptr(), track_index as i32);
+            spAnimationState_clearTrack(self.c_ptr(), c_track_index(track_index));
         }
     }
 
@@ -99,15 +322,19 @@ impl AnimationState {
         looping: bool,
     ) -> CTmpMut<Self, TrackEntry> {
         let c_animation_name = to_c_str(animation_name);
-        CTmpMut::new(
-            self,
-            TrackEntry::new_from_ptr(spAnimationState_setAnimationByName(
-                self.c_ptr(),
-                track_index as i32,
-                c_animation_name.as_ptr(),
-                i32::from(looping),
-            )),
-        )
+        let c_track_entry = self.replace_tracks(
+            Some(track_index),
+            InterruptReason::Replaced,
+            |c_animation_state| {
+                spAnimationState_setAnimationByName(
+                    c_animation_state,
+                    c_track_index(track_index),
+                    c_animation_name.as_ptr(),
+                    i32::from(looping),
+                )
+            },
+        );
+        self.new_track_entry(c_track_entry)
     }
 
     /// Sets the animation for the given track by name, clearing any queued tracks, and returning
@@ -136,6 +363,72 @@ impl AnimationState {
         }
     }
 
+    /// Sets the animation for the given track by name, but only plays the section of the animation
+    /// between `from` and `to` (in seconds). Useful for playing a sub-range of a longer authored
+    /// animation, such as only the wind-up of an attack.
+    ///
+    /// ```
+    /// # #[path="./test.rs"]
+    /// # mod test;
+    /// # let (_, mut animation_state) = test::TestAsset::spineboy().instance(true);
+    /// animation_state.play_section(0, "shoot", 0.0, 0.1, false).unwrap();
+    /// ```
+    ///
+    /// Events keyed before `from` are not fired. If `looping` is true, the animation loops back to
+    /// `from` after reaching `to`. See [`TrackEntry::set_animation_start`] and
+    /// [`TrackEntry::set_animation_end`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SpineError::NotFound`] if an animation doesn't exist with the given name.
+    pub fn play_section(
+        &mut self,
+        track_index: usize,
+        animation_name: &str,
+        from: f32,
+        to: f32,
+        looping: bool,
+    ) -> Result<CTmpMut<'_, Self, TrackEntry>, SpineError> {
+        let mut track_entry = self.set_animation_by_name(track_index, animation_name, looping)?;
+        let from = from.max(0.);
+        track_entry.set_animation_start(from);
+        track_entry.set_animation_end(to.max(from));
+        track_entry.set_animation_last(from);
+        Ok(track_entry)
+    }
+
+    /// Sets the animation for the given track by name, starting at a random time within the
+    /// animation. Useful for desynchronizing crowds of skeletons playing the same animation. The
+    /// same seed always produces the same start time.
+    ///
+    /// ```
+    /// # #[path="./test.rs"]
+    /// # mod test;
+    /// # let (_, mut animation_state) = test::TestAsset::spineboy().instance(true);
+    /// # let entity_id = 7;
+    /// animation_state.set_animation_with_random_offset(0, "idle", true, entity_id)?;
+    /// # Ok::<(), rusty_spine::SpineError>(())
+    /// ```
+    ///
+    /// Events keyed before the start time are not fired. To desynchronize many
+    /// [`SkeletonController`](`crate::controller::SkeletonController`)s at once, see
+    /// [`SkeletonController::stagger`](`crate::controller::SkeletonController::stagger`).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SpineError::NotFound`] if an animation doesn't exist with the given name.
+    pub fn set_animation_with_random_offset(
+        &mut self,
+        track_index: usize,
+        animation_name: &str,
+        looping: bool,
+        seed: u64,
+    ) -> Result<CTmpMut<'_, Self, TrackEntry>, SpineError> {
+        let mut track_entry = self.set_animation_by_name(track_index, animation_name, looping)?;
+        track_entry.skip_to_fraction(Random::new(seed).next_f32());
+        Ok(track_entry)
+    }
+
     /// Sets the animation for the given track, clearning any queued tracks, and returning the
     /// track index. If the track index doesn't exist then it will be created.
     pub fn set_animation(
@@ -144,17 +437,19 @@ impl AnimationState {
         animation: &Animation,
         looping: bool,
     ) -> CTmpMut<Self, TrackEntry> {
-        unsafe {
-            CTmpMut::new(
-                self,
-                TrackEntry::new_from_ptr(spAnimationState_setAnimation(
-                    self.c_ptr(),
-                    track_index as i32,
+        let c_track_entry = self.replace_tracks(
+            Some(track_index),
+            InterruptReason::Replaced,
+            |c_animation_state| unsafe {
+                spAnimationState_setAnimation(
+                    c_animation_state,
+                    c_track_index(track_index),
                     animation.c_ptr(),
                     i32::from(looping),
-                )),
-            )
-        }
+                )
+            },
+        );
+        unsafe { self.new_track_entry(c_track_entry) }
     }
 
     /// Queues the animation in the given track by name, returning the track index. If the track
@@ -173,16 +468,13 @@ impl AnimationState {
         delay: f32,
     ) -> CTmpMut<Self, TrackEntry> {
         let c_animation_name = to_c_str(animation_name);
-        CTmpMut::new(
-            self,
-            TrackEntry::new_from_ptr(spAnimationState_addAnimationByName(
-                self.c_ptr(),
-                track_index as i32,
-                c_animation_name.as_ptr(),
-                i32::from(looping),
-                delay,
-            )),
-        )
+        self.new_track_entry(spAnimationState_addAnimationByName(
+            self.c_ptr(),
+            c_track_index(track_index),
+            c_animation_name.as_ptr(),
+            i32::from(looping),
+            delay,
+        ))
     }
 
     /// Queues the animation in the given track by name, returning the track index. If the track
@@ -222,36 +514,60 @@ impl AnimationState {
         delay: f32,
     ) -> CTmpMut<Self, TrackEntry> {
         unsafe {
-            CTmpMut::new(
-                self,
-                TrackEntry::new_from_ptr(spAnimationState_addAnimation(
-                    self.c_ptr(),
-                    track_index as i32,
-                    animation.c_ptr(),
-                    i32::from(looping),
-                    delay,
-                )),
-            )
+            self.new_track_entry(spAnimationState_addAnimation(
+                self.c_ptr(),
+                c_track_index(track_index),
+                animation.c_ptr(),
+                i32::from(looping),
+                delay,
+            ))
         }
     }
 
+    /// Sets an empty animation for a track, discarding any queued animations, and mixes to it
+    /// over `mix_duration` seconds. An empty animation has no timelines and serves as a
+    /// placeholder for mixing in or out.
+    ///
+    /// ```
+    /// # #[path="./test.rs"]
+    /// # mod test;
+    /// # let (_, mut animation_state) = test::TestAsset::spineboy().instance(true);
+    /// // Play a one-shot animation on a higher track, then mix back out to the lower tracks
+    /// animation_state.set_animation_by_name(1, "shoot", false)?;
+    /// animation_state.add_empty_animation(1, 0.2, 0.);
+    /// # Ok::<(), rusty_spine::SpineError>(())
+    /// ```
+    ///
+    /// Mixing to an empty animation causes the previous animation to be applied less and less
+    /// over the mix duration. Properties keyed in the previous animation transition to the value
+    /// from lower tracks, or to the setup pose value if no lower tracks key the property. A mix
+    /// duration of `0` still mixes out over one frame.
     pub fn set_empty_animation(
         &mut self,
         track_index: usize,
         mix_duration: f32,
     ) -> CTmpMut<Self, TrackEntry> {
-        unsafe {
-            CTmpMut::new(
-                self,
-                TrackEntry::new_from_ptr(spAnimationState_setEmptyAnimation(
-                    self.c_ptr(),
-                    track_index as i32,
+        let c_track_entry = self.replace_tracks(
+            Some(track_index),
+            InterruptReason::Emptied,
+            |c_animation_state| unsafe {
+                spAnimationState_setEmptyAnimation(
+                    c_animation_state,
+                    c_track_index(track_index),
                     mix_duration,
-                )),
-            )
-        }
+                )
+            },
+        );
+        unsafe { self.new_track_entry(c_track_entry) }
     }
 
+    /// Queues an empty animation to be played after the current or last queued animation for a
+    /// track, mixing to it over `mix_duration` seconds. If the track is empty, this is the same as
+    /// [`set_empty_animation`](`Self::set_empty_animation`).
+    ///
+    /// If `delay` is greater than `0`, it sets [`TrackEntry::delay`]. Otherwise, the delay is set
+    /// so the mix ends `delay` seconds after the end of the previous track entry (or its next
+    /// loop completion, if it is looping), which with a `delay` of `0` is exactly when it ends.
     pub fn add_empty_animation(
         &mut self,
         track_index: usize,
@@ -259,28 +575,33 @@ impl AnimationState {
         delay: f32,
     ) -> CTmpMut<Self, TrackEntry> {
         unsafe {
-            CTmpMut::new(
-                self,
-                TrackEntry::new_from_ptr(spAnimationState_addEmptyAnimation(
-                    self.c_ptr(),
-                    track_index as i32,
-                    mix_duration,
-                    delay,
-                )),
-            )
+            self.new_track_entry(spAnimationState_addEmptyAnimation(
+                self.c_ptr(),
+                c_track_index(track_index),
+                mix_duration,
+                delay,
+            ))
         }
     }
 
+    /// Sets an empty animation for every track, discarding any queued animations, and mixes to
+    /// it over `mix_duration` seconds. Use this to mix the skeleton back to the setup pose. See
+    /// [`set_empty_animation`](`Self::set_empty_animation`).
     pub fn set_empty_animations(&mut self, mix_duration: f32) {
-        unsafe {
-            spAnimationState_setEmptyAnimations(self.c_ptr(), mix_duration);
+        self.replace_tracks(None, InterruptReason::Emptied, |c_animation_state| unsafe {
+            spAnimationState_setEmptyAnimations(c_animation_state, mix_duration);
+        });
+        if self.default_shortest_rotation() {
+            for mut track_entry in self.tracks_mut().flatten() {
+                track_entry.set_shortest_rotation(true);
+            }
         }
     }
 
     #[must_use]
     pub fn get_current(&self, track_index: usize) -> Option<CTmpRef<Self, TrackEntry>> {
         unsafe {
-            let ptr = spAnimationState_getCurrent(self.c_ptr(), track_index as i32);
+            let ptr = spAnimationState_getCurrent(self.c_ptr(), c_track_index(track_index));
             if !ptr.is_null() {
                 Some(CTmpRef::new(self, TrackEntry::new_from_ptr(ptr)))
             } else {
@@ -301,8 +622,8 @@ impl AnimationState {
     ///     AnimationEvent::Start { track_entry } => {
     ///         println!("Animation {} started!", track_entry.track_index());
     ///     }
-    ///     AnimationEvent::Interrupt { track_entry } => {
-    ///         println!("Animation {} interrupted!", track_entry.track_index());
+    ///     AnimationEvent::Interrupt { track_entry, reason } => {
+    ///         println!("Animation {} interrupted ({reason:?})!", track_entry.track_index());
     ///     }
     ///     AnimationEvent::End { track_entry } => {
     ///         println!("Animation {} ended!", track_entry.track_index());
@@ -343,67 +664,257 @@ impl AnimationState {
     where
         F: Fn(&AnimationState, AnimationEvent) + 'static,
     {
-        extern "C" fn c_listener(
-            c_animation_state: *mut spAnimationState,
-            c_event_type: spEventType,
-            c_track_entry: *mut spTrackEntry,
-            c_event: *mut spEvent,
-        ) {
-            let user_data = unsafe {
-                &mut *((*c_animation_state)
-                    .userData
-                    .cast::<AnimationStateUserData>())
-            };
-            if let Some(listener) = &user_data.listener {
-                let animation_state = unsafe { AnimationState::new_from_ptr(c_animation_state) };
-                let track_entry = unsafe { TrackEntry::new_from_ptr(c_track_entry) };
-                let event_type = EventType::from(c_event_type);
-                match event_type {
-                    EventType::Start => {
-                        listener(&animation_state, AnimationEvent::Start { track_entry });
-                    }
-                    EventType::Interrupt => {
-                        listener(&animation_state, AnimationEvent::Interrupt { track_entry });
-                    }
-                    EventType::End => {
-                        listener(&animation_state, AnimationEvent::End { track_entry });
-                    }
-                    EventType::Complete => {
-                        listener(&animation_state, AnimationEvent::Complete { track_entry });
-                    }
-                    EventType::Dispose => {
-                        listener(&animation_state, AnimationEvent::Dispose { track_entry });
-                    }
-                    EventType::Event => {
-                        assert!(!c_event.is_null());
-                        let event = unsafe { Event::new_from_ptr(c_event) };
-                        let raw_event = unsafe { Event::new_from_ptr(c_event) };
-                        listener(
-                            &animation_state,
-                            AnimationEvent::Event {
-                                track_entry,
-                                name: event.data().name(),
-                                time: event.time(),
-                                int: event.int_value(),
-                                float: event.float_value(),
-                                string: event.string_value(),
-                                audio_path: event.data().audio_path(),
-                                volume: event.volume(),
-                                balance: event.balance(),
-                                event: raw_event,
-                            },
-                        );
-                    }
-                    EventType::Unknown => {}
-                };
+        if let Some(user_data) = self.user_data_mut() {
+            user_data.listener = Some(Box::new(listener));
+            self.install_listener();
+        }
+    }
+
+    /// Remove the event listener set with [`set_listener`](`Self::set_listener`).
+    pub fn clear_listener(&mut self) {
+        if let Some(user_data) = self.user_data_mut() {
+            user_data.listener = None;
+        }
+    }
+
+    /// Set an event listener for a single track, replacing any listener previously set for the
+    /// track. Track listeners are called before the listener set with
+    /// [`set_listener`](`Self::set_listener`), and remain set when the track's animation changes.
+    ///
+    /// ```
+    /// # #[path="./test.rs"]
+    /// # mod test;
+    /// # use rusty_spine::{AnimationState, AnimationEvent};
+    /// # let (_, mut animation_state) = test::TestAsset::spineboy().instance(true);
+    /// animation_state.set_track_listener(1, |_, animation_event| {
+    ///     if let AnimationEvent::Complete { .. } = animation_event {
+    ///         println!("Attack finished!");
+    ///     }
+    /// });
+    /// ```
+    pub fn set_track_listener<F>(&mut self, track_index: usize, listener: F)
+    where
+        F: Fn(&AnimationState, AnimationEvent) + 'static,
+    {
+        if let Some(user_data) = self.user_data_mut() {
+            user_data
+                .track_listeners
+                .insert(track_index, Box::new(listener));
+            self.install_listener();
+        }
+    }
+
+    /// Remove the event listener set for a track with
+    /// [`set_track_listener`](`Self::set_track_listener`).
+    pub fn clear_track_listener(&mut self, track_index: usize) {
+        if let Some(user_data) = self.user_data_mut() {
+            user_data.track_listeners.remove(&track_index);
+        }
+    }
+
+    /// Add an event listener which stays set until the returned [`ListenerHandle`] is dropped.
+    /// Unlike [`set_listener`](`Self::set_listener`), any number of these listeners can be set
+    /// at once, so independent systems don't replace each other's listeners. They are called in
+    /// the order they were added, after the listeners set with
+    /// [`set_track_listener`](`Self::set_track_listener`) and
+    /// [`set_listener`](`Self::set_listener`).
+    ///
+    /// ```
+    /// # #[path="./test.rs"]
+    /// # mod test;
+    /// # use rusty_spine::{AnimationState, AnimationEvent};
+    /// # let (_, mut animation_state) = test::TestAsset::spineboy().instance(true);
+    /// let listener = animation_state.add_listener(|_, animation_event| {
+    ///     if let AnimationEvent::Complete { track_entry } = animation_event {
+    ///         println!("{} finished!", track_entry.animation().name());
+    ///     }
+    /// });
+    /// // ...
+    /// drop(listener); // the listener is removed
+    /// ```
+    ///
+    /// Use [`ListenerHandle::keep`] to keep a listener for as long as the animation state exists.
+    pub fn add_listener<F>(&mut self, listener: F) -> ListenerHandle
+    where
+        F: Fn(&AnimationState, AnimationEvent) + 'static,
+    {
+        self.add_handle_listener(None, Box::new(listener))
+    }
+
+    /// Add an event listener for a single track, which stays set until the returned
+    /// [`ListenerHandle`] is dropped. See [`add_listener`](`Self::add_listener`).
+    pub fn add_track_listener<F>(&mut self, track_index: usize, listener: F) -> ListenerHandle
+    where
+        F: Fn(&AnimationState, AnimationEvent) + 'static,
+    {
+        self.add_handle_listener(Some(track_index), Box::new(listener))
+    }
+
+    fn add_handle_listener(
+        &mut self,
+        track_index: Option<usize>,
+        listener: AnimationStateListenerCb,
+    ) -> ListenerHandle {
+        let detached = Arc::new(AtomicBool::new(false));
+        if let Some(user_data) = self.user_data_mut() {
+            user_data.remove_detached_listeners();
+            user_data.handle_listeners.push(HandleListener {
+                track_index,
+                detached: detached.clone(),
+                listener,
+            });
+            self.install_listener();
+        }
+        ListenerHandle {
+            detached: Some(detached),
+        }
+    }
+
+    /// Store `data` with a track entry until the track entry is disposed, when it is dropped.
+    /// Replaces any data previously stored with the track entry.
+    fn set_track_entry_data(
+        &mut self,
+        c_track_entry: *mut spTrackEntry,
+        data: Box<dyn Any + Send>,
+    ) {
+        let generation = TrackEntry::generation(c_track_entry);
+        if let Some(user_data) = self.user_data_mut() {
+            user_data.track_entry_data.insert(generation, data);
+            self.install_listener();
+        }
+    }
+
+    fn set_event_time_offset_for(&mut self, c_track_entry: *mut spTrackEntry, offset: f32) {
+        let generation = TrackEntry::generation(c_track_entry);
+        if let Some(user_data) = self.user_data_mut() {
+            if offset == 0. {
+                user_data.event_time_offsets.remove(&generation);
+            } else {
+                user_data.event_time_offsets.insert(generation, offset);
             }
+            self.install_listener();
         }
-        let user_data = unsafe {
-            &mut *((*self.c_animation_state.0)
-                .userData
-                .cast::<AnimationStateUserData>())
-        };
-        user_data.listener = Some(Box::new(listener));
+    }
+
+    fn event_time_offset_for(&self, c_track_entry: *mut spTrackEntry) -> f32 {
+        let generation = unsafe { (*c_track_entry).userData.addr() };
+        self.user_data()
+            .and_then(|user_data| user_data.event_time_offsets.get(&generation))
+            .copied()
+            .unwrap_or(0.)
+    }
+
+    fn track_entry_data(&self, c_track_entry: *mut spTrackEntry) -> Option<&(dyn Any + Send)> {
+        let generation = unsafe { (*c_track_entry).userData.addr() };
+        self.user_data()?
+            .track_entry_data
+            .get(&generation)
+            .map(AsRef::as_ref)
+    }
+
+    fn track_entry_data_mut(
+        &mut self,
+        c_track_entry: *mut spTrackEntry,
+    ) -> Option<&mut (dyn Any + Send)> {
+        let generation = unsafe { (*c_track_entry).userData.addr() };
+        self.user_data_mut()?
+            .track_entry_data
+            .get_mut(&generation)
+            .map(AsMut::as_mut)
+    }
+
+    /// If `true`, events are stored as they are delivered so they can be handled later with
+    /// [`drain_events`](`Self::drain_events`), instead of from within a listener. Disabling
+    /// buffering discards any events which have not been drained. Defaults to `false`.
+    ///
+    /// ```
+    /// # #[path="./test.rs"]
+    /// # mod test;
+    /// # use rusty_spine::{AnimationState, OwnedAnimationEvent};
+    /// # let (mut skeleton, mut animation_state) = test::TestAsset::spineboy().instance(true);
+    /// animation_state.set_event_buffering(true);
+    /// animation_state.set_animation_by_name(0, "run", true)?;
+    /// animation_state.update(0.016);
+    /// animation_state.apply(&mut skeleton);
+    /// for animation_event in animation_state.drain_events() {
+    ///     if let OwnedAnimationEvent::Event { name, .. } = animation_event {
+    ///         println!("Event: {name}");
+    ///     }
+    /// }
+    /// # Ok::<(), rusty_spine::SpineError>(())
+    /// ```
+    pub fn set_event_buffering(&mut self, event_buffering: bool) {
+        if let Some(user_data) = self.user_data_mut() {
+            user_data.event_buffering = event_buffering;
+            if !event_buffering {
+                user_data.buffered_events.clear();
+            }
+            self.install_listener();
+        }
+    }
+
+    /// If `true`, events are buffered until drained, see
+    /// [`set_event_buffering`](`Self::set_event_buffering`).
+    #[must_use]
+    pub fn event_buffering(&self) -> bool {
+        self.user_data()
+            .is_some_and(|user_data| user_data.event_buffering)
+    }
+
+    /// Remove and return all buffered events, in the order they were delivered.
+    ///
+    /// See [`set_event_buffering`](`Self::set_event_buffering`).
+    pub fn drain_events(&mut self) -> impl Iterator<Item = OwnedAnimationEvent> + '_ {
+        self.user_data_mut()
+            .into_iter()
+            .flat_map(|user_data| user_data.buffered_events.drain(..))
+    }
+
+    /// Stop delivering events with any of the given names to listeners and the
+    /// [event buffer](`Self::set_event_buffering`), such as noisy footstep events during a
+    /// cutscene. Other notifications, such as [`AnimationEvent::Complete`], are still delivered.
+    ///
+    /// ```
+    /// # #[path="./test.rs"]
+    /// # mod test;
+    /// # let (_, mut animation_state) = test::TestAsset::spineboy().instance(true);
+    /// animation_state.mute_events(&["footstep"]);
+    /// // ... play the cutscene ...
+    /// animation_state.unmute_events(&["footstep"]);
+    /// ```
+    pub fn mute_events(&mut self, names: &[&str]) {
+        if let Some(user_data) = self.user_data_mut() {
+            user_data
+                .muted_events
+                .extend(names.iter().map(|name| (*name).to_owned()));
+        }
+    }
+
+    /// Deliver events with any of the given names again, see
+    /// [`mute_events`](`Self::mute_events`).
+    pub fn unmute_events(&mut self, names: &[&str]) {
+        if let Some(user_data) = self.user_data_mut() {
+            for name in names {
+                user_data.muted_events.remove(*name);
+            }
+        }
+    }
+
+    /// Deliver all events again, see [`mute_events`](`Self::mute_events`).
+    pub fn unmute_all_events(&mut self) {
+        if let Some(user_data) = self.user_data_mut() {
+            user_data.muted_events.clear();
+        }
+    }
+
+    /// Returns `true` if events named `name` are muted, see [`mute_events`](`Self::mute_events`).
+    #[must_use]
+    pub fn is_event_muted(&self, name: &str) -> bool {
+        self.user_data()
+            .is_some_and(|user_data| user_data.muted_events.contains(name))
+    }
+
+    fn install_listener(&mut self) {
         unsafe {
             self.c_ptr_mut().listener = Some(c_listener);
         }
@@ -421,14 +932,33 @@ impl AnimationState {
         }
     }
 
-    c_accessor_tmp_ptr_mut!(
+    c_accessor_tmp_ptr!(
         /// The [`AnimationStateData`] to look up mix durations.
         data,
-        data_mut,
         data,
         AnimationStateData,
         spAnimationStateData
     );
+
+    /// The mutable [`AnimationStateData`] to look up mix durations. Changes affect every
+    /// animation state sharing this data.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SpineError::Frozen`] if the data has been frozen with
+    /// [`AnimationStateData::freeze`].
+    pub fn data_mut(&mut self) -> Result<CTmpMut<'_, Self, AnimationStateData>, SpineError> {
+        if self
+            .animation_state_data
+            .as_ref()
+            .is_some_and(|animation_state_data| animation_state_data.is_frozen())
+        {
+            return Err(SpineError::new_frozen("AnimationStateData"));
+        }
+        Ok(CTmpMut::new(self, unsafe {
+            AnimationStateData::new_from_ptr(self.c_ptr_ref().data)
+        }))
+    }
     c_accessor!(tracks_count, tracksCount, usize);
     c_accessor_array_nullable!(
         tracks,
@@ -465,6 +995,7 @@ impl AnimationState {
 impl Drop for AnimationState {
     fn drop(&mut self) {
         if self.owns_memory {
+            diagnostics::untrack(LiveObjectKind::AnimationState, self.c_animation_state.0);
             unsafe {
                 (*self.c_animation_state.0).listener = None;
                 drop(Box::from_raw(
@@ -485,6 +1016,319 @@ type AnimationStateListenerCb = Box<dyn Fn(&AnimationState, AnimationEvent)>;
 #[derive(Default)]
 struct AnimationStateUserData {
     listener: Option<AnimationStateListenerCb>,
+    track_listeners: BTreeMap<usize, AnimationStateListenerCb>,
+    handle_listeners: Vec<HandleListener>,
+    /// Data stored with track entries, by their generation, see [`TrackEntry::generation`].
+    track_entry_data: BTreeMap<usize, Box<dyn Any + Send>>,
+    event_buffering: bool,
+    buffered_events: Vec<OwnedAnimationEvent>,
+    event_queue: EventQueue,
+    default_shortest_rotation: bool,
+    /// Why track entries replaced by this animation state were interrupted, by their
+    /// generation, until their interrupt event is delivered. Interrupted track entries not listed
+    /// were replaced by the next queued track entry.
+    interrupt_reasons: BTreeMap<usize, InterruptReason>,
+    /// The names of events which are not delivered, see [`AnimationState::mute_events`].
+    muted_events: BTreeSet<String>,
+    /// The event time offsets of track entries, by their generation, see
+    /// [`TrackEntryHandle::set_event_time_offset`].
+    event_time_offsets: BTreeMap<usize, f32>,
+    /// The `(track entry, event)` pairs queued by [`queue_offset_events`] which have not been
+    /// delivered yet. Other events of the current track entries with an offset are not delivered.
+    offset_events: Vec<(usize, usize)>,
+}
+
+impl AnimationStateUserData {
+    fn remove_detached_listeners(&mut self) {
+        self.handle_listeners
+            .retain(|listener| !listener.detached.load(Ordering::Acquire));
+    }
+}
+
+struct HandleListener {
+    track_index: Option<usize>,
+    detached: Arc<AtomicBool>,
+    listener: AnimationStateListenerCb,
+}
+
+/// Keeps a listener added with [`AnimationState::add_listener`] or
+/// [`AnimationState::add_track_listener`] set, removing it when dropped.
+///
+/// The handle can be dropped at any time, including from within a listener, and can outlive its
+/// animation state.
+#[derive(Debug)]
+#[must_use = "dropping a listener handle removes its listener"]
+pub struct ListenerHandle {
+    detached: Option<Arc<AtomicBool>>,
+}
+
+impl ListenerHandle {
+    /// Remove the listener, same as dropping the handle.
+    pub fn detach(self) {}
+
+    /// Keep the listener for as long as its animation state exists, without a handle.
+    pub fn keep(mut self) {
+        self.detached = None;
+    }
+
+    /// Returns `true` if the listener is still set on its animation state.
+    #[must_use]
+    pub fn is_attached(&self) -> bool {
+        self.detached
+            .as_ref()
+            .is_some_and(|detached| Arc::strong_count(detached) > 1)
+    }
+}
+
+impl Drop for ListenerHandle {
+    fn drop(&mut self) {
+        if let Some(detached) = &self.detached {
+            detached.store(true, Ordering::Release);
+        }
+    }
+}
+
+extern "C" fn c_listener(
+    c_animation_state: *mut spAnimationState,
+    c_event_type: spEventType,
+    c_track_entry: *mut spTrackEntry,
+    c_event: *mut spEvent,
+) {
+    let Some(user_data) = (unsafe {
+        (*c_animation_state)
+            .userData
+            .cast::<AnimationStateUserData>()
+            .as_mut()
+    }) else {
+        return;
+    };
+    if EventType::from(c_event_type) == EventType::Event
+        && user_data
+            .event_time_offsets
+            .contains_key(&unsafe { (*c_track_entry).userData.addr() })
+        && unsafe { is_current(c_animation_state, c_track_entry) }
+    {
+        let key = (c_track_entry.addr(), c_event.addr());
+        let Some(index) = user_data.offset_events.iter().position(|pair| *pair == key) else {
+            // The event is fired at its offset time instead.
+            return;
+        };
+        user_data.offset_events.swap_remove(index);
+    }
+    if EventType::from(c_event_type) == EventType::Event
+        && !user_data.muted_events.is_empty()
+        && user_data
+            .muted_events
+            .contains(unsafe { Event::new_from_ptr(c_event) }.data().name())
+    {
+        return;
+    }
+    let animation_state = unsafe { AnimationState::new_from_ptr(c_animation_state) };
+    let track_index = unsafe { (*c_track_entry).trackIndex as usize };
+    let generation = unsafe { (*c_track_entry).userData.addr() };
+    let interrupt_reason = match EventType::from(c_event_type) {
+        EventType::Interrupt => user_data
+            .interrupt_reasons
+            .remove(&generation)
+            .unwrap_or(InterruptReason::Queued),
+        _ => InterruptReason::Queued,
+    };
+    if let Some(listener) = user_data.track_listeners.get(&track_index) {
+        with_animation_event(
+            c_event_type,
+            c_track_entry,
+            c_event,
+            interrupt_reason,
+            |animation_event| {
+                listener(&animation_state, animation_event);
+            },
+        );
+    }
+    if let Some(listener) = &user_data.listener {
+        with_animation_event(
+            c_event_type,
+            c_track_entry,
+            c_event,
+            interrupt_reason,
+            |animation_event| {
+                listener(&animation_state, animation_event);
+            },
+        );
+    }
+    user_data.remove_detached_listeners();
+    for handle_listener in &user_data.handle_listeners {
+        if handle_listener
+            .track_index
+            .is_some_and(|index| index != track_index)
+        {
+            continue;
+        }
+        with_animation_event(
+            c_event_type,
+            c_track_entry,
+            c_event,
+            interrupt_reason,
+            |animation_event| {
+                if !handle_listener.detached.load(Ordering::Acquire) {
+                    (handle_listener.listener)(&animation_state, animation_event);
+                }
+            },
+        );
+    }
+    if user_data.event_buffering {
+        with_animation_event(
+            c_event_type,
+            c_track_entry,
+            c_event,
+            interrupt_reason,
+            |animation_event| {
+                user_data.buffered_events.push(animation_event.into());
+            },
+        );
+    }
+    if EventType::from(c_event_type) == EventType::Dispose {
+        user_data.track_entry_data.remove(&generation);
+        user_data.interrupt_reasons.remove(&generation);
+        user_data.event_time_offsets.remove(&generation);
+    }
+}
+
+/// Returns `true` if the track entry is the current entry of its track, rather than mixing out.
+unsafe fn is_current(
+    c_animation_state: *mut spAnimationState,
+    c_track_entry: *mut spTrackEntry,
+) -> bool {
+    let track_index = (*c_track_entry).trackIndex;
+    track_index < (*c_animation_state).tracksCount
+        && *(*c_animation_state).tracks.add(track_index as usize) == c_track_entry
+}
+
+/// Queues the events of current track entries with an event time offset, after
+/// `spAnimationState_apply` has queued their events without the offset. The events fired are
+/// those keyed between the track times of the previous and this apply, moved by the offset.
+unsafe fn queue_offset_events(c_animation_state: *mut spAnimationState) {
+    let Some(user_data) = (*c_animation_state)
+        .userData
+        .cast::<AnimationStateUserData>()
+        .as_mut()
+    else {
+        return;
+    };
+    if user_data.event_time_offsets.is_empty() {
+        return;
+    }
+    let queue = (*c_animation_state.cast::<_spAnimationState>()).queue;
+    for track_index in 0..(*c_animation_state).tracksCount.max(0) as usize {
+        let c_track_entry = *(*c_animation_state).tracks.add(track_index);
+        if c_track_entry.is_null() || (*c_track_entry).delay > 0. || (*c_track_entry).reverse != 0 {
+            continue;
+        }
+        let Some(offset) = user_data
+            .event_time_offsets
+            .get(&(*c_track_entry).userData.addr())
+        else {
+            continue;
+        };
+        let entry = &*c_track_entry;
+        // The first apply fires events keyed at the start, and events already due if early.
+        let start = if entry.trackLast < 0. {
+            f32::NEG_INFINITY
+        } else {
+            entry.trackLast - offset
+        };
+        let end = entry.trackTime - offset;
+        if end <= start {
+            continue;
+        }
+        let duration = entry.animationEnd - entry.animationStart;
+        let timelines = (*entry.animation).timelines;
+        let mut events = vec![];
+        for timeline_index in 0..(*timelines).size as usize {
+            let timeline = *(*timelines).items.add(timeline_index);
+            if (*timeline).type_0 != SP_TIMELINE_EVENT {
+                continue;
+            }
+            let c_events = (*timeline.cast::<spEventTimeline>()).events;
+            for frame_index in 0..(*(*timeline).frames).size as usize {
+                let c_event = *c_events.add(frame_index);
+                let event_time = (*c_event).time;
+                if event_time < entry.animationStart || event_time > entry.animationEnd {
+                    continue;
+                }
+                let mut track_time = event_time - entry.animationStart;
+                if entry.loop_0 != 0 && duration > 0. {
+                    if start > track_time {
+                        track_time += ((start - track_time) / duration).floor() * duration;
+                    }
+                    while track_time <= end {
+                        if track_time > start {
+                            events.push((track_time, c_event));
+                        }
+                        track_time += duration;
+                    }
+                } else if track_time > start && track_time <= end {
+                    events.push((track_time, c_event));
+                }
+            }
+        }
+        events.sort_by(|a, b| a.0.total_cmp(&b.0));
+        for (_, c_event) in events {
+            _spEventQueue_event(queue, c_track_entry, c_event);
+            user_data
+                .offset_events
+                .push((c_track_entry.addr(), c_event.addr()));
+        }
+    }
+}
+
+/// Converts a track index to the C int used by the Spine runtime.
+#[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+fn c_track_index(track_index: usize) -> i32 {
+    ffi_check!(
+        i32::try_from(track_index).is_ok(),
+        "track index {track_index} does not fit in a C int"
+    );
+    track_index as i32
+}
+
+fn with_animation_event<F>(
+    c_event_type: spEventType,
+    c_track_entry: *mut spTrackEntry,
+    c_event: *mut spEvent,
+    interrupt_reason: InterruptReason,
+    f: F,
+) where
+    F: FnOnce(AnimationEvent),
+{
+    let track_entry = unsafe { TrackEntry::new_from_ptr(c_track_entry) };
+    match EventType::from(c_event_type) {
+        EventType::Start => f(AnimationEvent::Start { track_entry }),
+        EventType::Interrupt => f(AnimationEvent::Interrupt {
+            track_entry,
+            reason: interrupt_reason,
+        }),
+        EventType::End => f(AnimationEvent::End { track_entry }),
+        EventType::Complete => f(AnimationEvent::Complete { track_entry }),
+        EventType::Dispose => f(AnimationEvent::Dispose { track_entry }),
+        EventType::Event => {
+            assert!(!c_event.is_null());
+            let event = unsafe { Event::new_from_ptr(c_event) };
+            let raw_event = unsafe { Event::new_from_ptr(c_event) };
+            f(AnimationEvent::Event {
+                track_entry,
+                name: event.data().name(),
+                time: event.time(),
+                int: event.int_value(),
+                float: event.float_value(),
+                string: event.string_value(),
+                audio_path: event.audio_path(),
+                volume: event.volume(),
+                balance: event.balance(),
+                event: raw_event,
+            });
+        }
+        EventType::Unknown => {}
+    }
 }
 
 /// The variants of event types.
@@ -558,14 +1402,49 @@ impl TrackEntry {
         unsafe { spTrackEntry_getTrackComplete(self.c_ptr()) }
     }
 
+    /// Returns `true` if the handle's track entry is still playing, queued, or being mixed from
+    /// on its track, and is the same track entry the handle was created for.
     fn handle_valid(handle: &TrackEntryHandle) -> bool {
-        let track_count = unsafe { (*handle.c_parent.0).tracksCount };
-        if handle.index < track_count {
-            let track_at_index =
-                unsafe { *(*handle.c_parent.0).tracks.offset(handle.index as isize) };
-            track_at_index == handle.c_item.0
-        } else {
-            false
+        let c_animation_state = handle.c_parent.0;
+        let c_track_entry = handle.c_item.0;
+        unsafe {
+            if handle.index < 0 || handle.index >= (*c_animation_state).tracksCount {
+                return false;
+            }
+            let current = *(*c_animation_state).tracks.offset(handle.index as isize);
+            let mut found = false;
+            for first in [
+                current,
+                current
+                    .as_ref()
+                    .map_or(core::ptr::null_mut(), |c| c.mixingFrom),
+            ] {
+                let mut entry = first;
+                while !entry.is_null() && !found {
+                    found = entry == c_track_entry;
+                    entry = if first == current {
+                        (*entry).next
+                    } else {
+                        (*entry).mixingFrom
+                    };
+                }
+            }
+            found && (*c_track_entry).userData.addr() == handle.generation
+        }
+    }
+
+    /// A number unique to this track entry, stored in its `userData`, so handles can tell it
+    /// apart from a later track entry allocated at the same address.
+    fn generation(c_track_entry: *mut spTrackEntry) -> usize {
+        static NEXT_GENERATION: AtomicUsize = AtomicUsize::new(1);
+        unsafe {
+            let generation = (*c_track_entry).userData.addr();
+            if generation != 0 {
+                return generation;
+            }
+            let generation = NEXT_GENERATION.fetch_add(1, Ordering::Relaxed);
+            (*c_track_entry).userData = core::ptr::without_provenance_mut(generation);
+            generation
         }
     }
 
@@ -615,6 +1494,29 @@ impl TrackEntry {
         spTrackEntry
     );
     c_accessor!(track_index, trackIndex, usize);
+
+    /// Moves this track entry `fraction` of the way between
+    /// [`animation_start`](`Self::animation_start`) and [`animation_end`](`Self::animation_end`),
+    /// without firing the events keyed before that time.
+    pub(crate) fn skip_to_fraction(&mut self, fraction: f32) {
+        let offset = (self.animation_end() - self.animation_start()) * fraction;
+        self.seek(offset);
+    }
+
+    /// Jumps this track entry to the given track time without firing the events or completions
+    /// between the current time and the new time.
+    pub(crate) fn seek(&mut self, track_time: f32) {
+        self.set_track_time(track_time);
+        let animation_time = self.animation_time();
+        unsafe {
+            let c_track_entry = self.c_ptr_mut();
+            c_track_entry.animationLast = animation_time;
+            c_track_entry.nextAnimationLast = animation_time;
+            c_track_entry.trackLast = track_time;
+            c_track_entry.nextTrackLast = track_time;
+        }
+    }
+
     c_accessor_bool_mut!(
         /// If `true`, the animation will repeat. If `false` it will not, instead its last frame is
         /// applied if played beyond its duration.
@@ -693,7 +1595,7 @@ impl TrackEntry {
         /// Set the mix attachment threshold, see
         /// [`mix_attachment_threshold`](`Self::mix_attachment_threshold`).
         set_mix_attachment_threshold,
-        alphaAttachmentThreshold,
+        mixAttachmentThreshold,
         f32
     );
     c_accessor_mut!(
@@ -867,36 +1769,281 @@ impl TrackEntry {
     c_ptr!(c_track_entry, spTrackEntry);
 }
 
-c_handle_indexed_decl!(
-    /// A storeable reference to a [`TrackEntry`].
+/// A storeable reference to a [`TrackEntry`], which can be used to adjust a playing animation
+/// over several frames.
+///
+/// Can be acquired from a
+/// [`CTmpRef<AnimationState, TrackEntry>`] or [`CTmpMut<AnimationState, TrackEntry>`] acquired
+/// from an [`AnimationState`] instance.
+///
+/// ```
+/// # #[path="./test.rs"]
+/// # mod test;
+/// # use rusty_spine::{AnimationState, EventType, TrackEntryHandle};
+/// # let (_, animation_state) = test::TestAsset::spineboy().instance(true);
+/// let track_entry_handles: Vec<TrackEntryHandle> = animation_state.tracks().map(|track| track.unwrap().handle()).collect();
+/// for track_entry_handle in track_entry_handles.iter() {
+///     let track_entry = track_entry_handle.get(&animation_state).unwrap();
+///     println!("{}", track_entry.animation().name());
+/// }
+/// ```
+///
+/// A handle stays valid while its track entry is playing, queued, or being mixed from. Once the
+/// track entry is disposed, such as when its track is cleared or its animation is replaced, the
+/// handle's getters return [`None`] and its setters return [`SpineError::NotFound`], even if a
+/// new track entry is later allocated at the same address.
+///
+/// ```
+/// # #[path="./test.rs"]
+/// # mod test;
+/// # let (_, mut animation_state) = test::TestAsset::spineboy().instance(true);
+/// let fade_in = animation_state.set_animation_by_name(1, "aim", true)?.handle();
+/// for frame in 1..=10 {
+///     fade_in.set_alpha(&mut animation_state, frame as f32 / 10.)?;
+///     animation_state.update(0.016);
+/// }
+/// animation_state.clear_track(1);
+/// assert_eq!(fade_in.alpha(&animation_state), None);
+/// # Ok::<(), rusty_spine::SpineError>(())
+/// ```
+///
+/// Track entries store the handle's generation in their `userData`, which must not be used by
+/// other code.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TrackEntryHandle {
+    index: i32,
+    generation: usize,
+    c_item: SyncPtr<spTrackEntry>,
+    c_parent: SyncPtr<spAnimationState>,
+}
+
+macro_rules! track_entry_handle_accessor {
+    ($rust:ident, $rust_set:ident, $type:ty) => {
+        #[doc = concat!("See [`TrackEntry::", stringify!($rust), "`], or [`None`] if the track entry was disposed.")]
+        #[must_use]
+        pub fn $rust(&self, animation_state: &AnimationState) -> Option<$type> {
+            self.get(animation_state).map(|track_entry| track_entry.$rust())
+        }
+
+        #[doc = concat!("See [`TrackEntry::", stringify!($rust_set), "`].")]
+        ///
+        /// # Errors
+        ///
+        /// Returns [`SpineError::NotFound`] if the track entry was disposed.
+        pub fn $rust_set(
+            &self,
+            animation_state: &mut AnimationState,
+            value: $type,
+        ) -> Result<(), SpineError> {
+            self.get_mut(animation_state)
+                .map(|mut track_entry| track_entry.$rust_set(value))
+                .ok_or_else(|| SpineError::new_not_found("Track entry", &self.index.to_string()))
+        }
+    };
+}
+
+impl TrackEntryHandle {
+    #[must_use]
+    pub(crate) fn new(
+        index: i32,
+        c_item: *const spTrackEntry,
+        c_parent: *const spAnimationState,
+    ) -> Self {
+        Self {
+            index,
+            generation: TrackEntry::generation(c_item.cast_mut()),
+            c_item: SyncPtr(c_item.cast_mut()),
+            c_parent: SyncPtr(c_parent.cast_mut()),
+        }
+    }
+
+    /// The index of the track the track entry was created on.
+    #[must_use]
+    pub const fn track_index(&self) -> usize {
+        self.index as usize
+    }
+
+    /// Returns `true` if the track entry has not been disposed.
+    #[must_use]
+    pub fn is_valid(&self, animation_state: &AnimationState) -> bool {
+        animation_state.c_ptr() == self.c_parent.0 && TrackEntry::handle_valid(self)
+    }
+
+    /// Safely acquired the item, verifying its existence using its parent.
+    #[must_use]
+    pub fn get<'a>(
+        &self,
+        animation_state: &'a AnimationState,
+    ) -> Option<CTmpRef<'a, AnimationState, TrackEntry>> {
+        if self.is_valid(animation_state) {
+            Some(CTmpRef::new(animation_state, unsafe {
+                TrackEntry::new_from_ptr(self.c_item.0)
+            }))
+        } else {
+            None
+        }
+    }
+
+    /// Safely acquired the item, verifying its existence using its parent.
+    #[must_use]
+    pub fn get_mut<'a>(
+        &self,
+        animation_state: &'a mut AnimationState,
+    ) -> Option<CTmpMut<'a, AnimationState, TrackEntry>> {
+        if self.is_valid(animation_state) {
+            Some(CTmpMut::new(animation_state, unsafe {
+                TrackEntry::new_from_ptr(self.c_item.0)
+            }))
+        } else {
+            None
+        }
+    }
+
+    /// # Safety
     ///
-    /// Can be acquired from a
-    /// [`CTmpRef<AnimationState, TrackEntry>`] or [`CTmpMut<AnimationState, TrackEntry>`] acquired
-    /// from an [`AnimationState`] instance.
+    /// Acquire the item without any checks. This is a direct pointer access which is fast
+    /// but will segfault if the data has been disposed of already.
+    #[must_use]
+    pub unsafe fn get_unchecked(&self) -> TrackEntry {
+        TrackEntry::new_from_ptr(self.c_item.0)
+    }
+
+    /// Store `data` with the track entry, replacing any data previously stored with it. The data
+    /// is dropped when the track entry is disposed, so it can't outlive the track entry.
     ///
     /// ```
     /// # #[path="./test.rs"]
     /// # mod test;
-    /// # use rusty_spine::{AnimationState, EventType, TrackEntryHandle};
-    /// # let (_, animation_state) = test::TestAsset::spineboy().instance(true);
-    /// let track_entry_handles: Vec<TrackEntryHandle> = animation_state.tracks().map(|track| track.unwrap().handle()).collect();
-    /// for track_entry_handle in track_entry_handles.iter() {
-    ///     let track_entry = track_entry_handle.get(&animation_state).unwrap();
-    ///     println!("{}", track_entry.animation().name());
+    /// # let (_, mut animation_state) = test::TestAsset::spineboy().instance(true);
+    /// struct Attack {
+    ///     damage: u32,
     /// }
+    ///
+    /// let shoot = animation_state.set_animation_by_name(1, "shoot", false)?.handle();
+    /// shoot.set_user_data(&mut animation_state, Attack { damage: 10 })?;
+    /// assert_eq!(shoot.user_data::<Attack>(&animation_state).unwrap().damage, 10);
+    /// animation_state.clear_track(1);
+    /// assert!(shoot.user_data::<Attack>(&animation_state).is_none());
+    /// # Ok::<(), rusty_spine::SpineError>(())
     /// ```
-    TrackEntryHandle,
-    TrackEntry,
-    AnimationState,
-    spTrackEntry,
-    spAnimationState
-);
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SpineError::NotFound`] if the track entry was disposed.
+    pub fn set_user_data<T: Any + Send>(
+        &self,
+        animation_state: &mut AnimationState,
+        data: T,
+    ) -> Result<(), SpineError> {
+        if self.is_valid(animation_state) {
+            animation_state.set_track_entry_data(self.c_item.0, Box::new(data));
+            Ok(())
+        } else {
+            Err(SpineError::new_not_found(
+                "Track entry",
+                &self.index.to_string(),
+            ))
+        }
+    }
+
+    /// The data stored with [`set_user_data`](`Self::set_user_data`), or [`None`] if the track
+    /// entry was disposed or stores no data of type `T`.
+    #[must_use]
+    pub fn user_data<'a, T: Any + Send>(
+        &self,
+        animation_state: &'a AnimationState,
+    ) -> Option<&'a T> {
+        if self.is_valid(animation_state) {
+            animation_state
+                .track_entry_data(self.c_item.0)?
+                .downcast_ref()
+        } else {
+            None
+        }
+    }
+
+    /// The mutable data stored with [`set_user_data`](`Self::set_user_data`), or [`None`] if the
+    /// track entry was disposed or stores no data of type `T`.
+    #[must_use]
+    pub fn user_data_mut<'a, T: Any + Send>(
+        &self,
+        animation_state: &'a mut AnimationState,
+    ) -> Option<&'a mut T> {
+        if self.is_valid(animation_state) {
+            animation_state
+                .track_entry_data_mut(self.c_item.0)?
+                .downcast_mut()
+        } else {
+            None
+        }
+    }
+
+    /// Fire the track entry's events `offset` seconds of track time later than they are keyed,
+    /// or earlier if negative, without changing the pose. Useful for networked clients which
+    /// present animations with a delay, so sounds and effects stay in sync with the server.
+    ///
+    /// ```
+    /// # #[path="./test.rs"]
+    /// # mod test;
+    /// # let (_, mut animation_state) = test::TestAsset::spineboy().instance(true);
+    /// # let presentation_delay = 0.1;
+    /// let run = animation_state.set_animation_by_name(0, "run", true)?.handle();
+    /// run.set_event_time_offset(&mut animation_state, -presentation_delay)?;
+    /// # Ok::<(), rusty_spine::SpineError>(())
+    /// ```
+    ///
+    /// Only the events of the current track entry of a track are moved: events of a track entry
+    /// mixing out fire at their keyed time, and late events still pending when the track entry is
+    /// replaced are not fired. Events are not fired when the track entry is
+    /// [reversed](`TrackEntry::reverse`).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SpineError::NotFound`] if the track entry was disposed.
+    pub fn set_event_time_offset(
+        &self,
+        animation_state: &mut AnimationState,
+        offset: f32,
+    ) -> Result<(), SpineError> {
+        if self.is_valid(animation_state) {
+            animation_state.set_event_time_offset_for(self.c_item.0, offset);
+            Ok(())
+        } else {
+            Err(SpineError::new_not_found(
+                "Track entry",
+                &self.index.to_string(),
+            ))
+        }
+    }
+
+    /// The event time offset, see [`set_event_time_offset`](`Self::set_event_time_offset`), or
+    /// [`None`] if the track entry was disposed.
+    #[must_use]
+    pub fn event_time_offset(&self, animation_state: &AnimationState) -> Option<f32> {
+        self.is_valid(animation_state)
+            .then(|| animation_state.event_time_offset_for(self.c_item.0))
+    }
+
+    track_entry_handle_accessor!(alpha, set_alpha, f32);
+    track_entry_handle_accessor!(mix_duration, set_mix_duration, f32);
+    track_entry_handle_accessor!(timescale, set_timescale, f32);
+    track_entry_handle_accessor!(track_time, set_track_time, f32);
+    track_entry_handle_accessor!(reverse, set_reverse, bool);
+    track_entry_handle_accessor!(hold_previous, set_hold_previous, bool);
+    track_entry_handle_accessor!(shortest_rotation, set_shortest_rotation, bool);
+}
 
 impl<'a> CTmpRef<'a, AnimationState, TrackEntry> {
     #[must_use]
     pub fn handle(&self) -> TrackEntryHandle {
         TrackEntryHandle::new(self.track_index() as i32, self.c_ptr(), self.parent.c_ptr())
     }
+
+    /// The data stored with the track entry, see [`TrackEntryHandle::set_user_data`].
+    #[must_use]
+    pub fn user_data<T: Any + Send>(&self) -> Option<&T> {
+        self.parent.track_entry_data(self.c_ptr())?.downcast_ref()
+    }
 }
 
 impl<'a> CTmpMut<'a, AnimationState, TrackEntry> {
@@ -904,11 +2051,90 @@ impl<'a> CTmpMut<'a, AnimationState, TrackEntry> {
     pub fn handle(&self) -> TrackEntryHandle {
         TrackEntryHandle::new(self.track_index() as i32, self.c_ptr(), self.parent.c_ptr())
     }
+
+    /// Fire the track entry's events later or earlier than they are keyed, see
+    /// [`TrackEntryHandle::set_event_time_offset`].
+    pub fn set_event_time_offset(&mut self, offset: f32) {
+        let (animation_state, track_entry) = self.unwrap_parent_child();
+        let c_track_entry = track_entry.c_ptr();
+        animation_state.set_event_time_offset_for(c_track_entry, offset);
+    }
+
+    /// The event time offset, see [`TrackEntryHandle::set_event_time_offset`].
+    #[must_use]
+    pub fn event_time_offset(&self) -> f32 {
+        self.parent.event_time_offset_for(self.c_ptr())
+    }
+
+    /// Store `data` with the track entry until it is disposed, see
+    /// [`TrackEntryHandle::set_user_data`].
+    pub fn set_user_data<T: Any + Send>(&mut self, data: T) {
+        let (animation_state, track_entry) = self.unwrap_parent_child();
+        let c_track_entry = track_entry.c_ptr();
+        animation_state.set_track_entry_data(c_track_entry, Box::new(data));
+    }
+
+    /// The data stored with the track entry, see [`TrackEntryHandle::set_user_data`].
+    #[must_use]
+    pub fn user_data<T: Any + Send>(&self) -> Option<&T> {
+        self.parent.track_entry_data(self.c_ptr())?.downcast_ref()
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::test::TestAsset;
+    use std::{
+        cell::{Cell, RefCell},
+        rc::Rc,
+        sync::{
+            atomic::{AtomicUsize, Ordering},
+            Arc,
+        },
+    };
+
+    use crate::{
+        test::TestAsset, AnimationEvent, AnimationState, AnimationStateData, EventAudio,
+        EventQueueOverflow, EventQueueSettings, InterruptReason, OwnedAnimationEvent, Skeleton,
+        SpineError,
+    };
+
+    #[test]
+    fn track_entry_handle() {
+        let (mut skeleton, mut animation_state) = TestAsset::spineboy().instance(true);
+        let walk = animation_state
+            .set_animation_by_name(0, "walk", true)
+            .unwrap()
+            .handle();
+        let run = animation_state
+            .add_animation_by_name(0, "run", true, 0.)
+            .unwrap()
+            .handle();
+        assert!(walk.is_valid(&animation_state));
+        assert!(run.is_valid(&animation_state));
+        walk.set_timescale(&mut animation_state, 2.).unwrap();
+        run.set_reverse(&mut animation_state, true).unwrap();
+        assert_eq!(walk.timescale(&animation_state), Some(2.));
+        assert_eq!(run.reverse(&animation_state), Some(true));
+
+        animation_state.update(0.1);
+        animation_state.apply(&mut skeleton);
+        let jump = animation_state
+            .set_animation_by_name(0, "jump", false)
+            .unwrap()
+            .handle();
+        jump.set_mix_duration(&mut animation_state, 0.5).unwrap();
+        assert_eq!(walk.alpha(&animation_state), Some(1.));
+        assert!(!run.is_valid(&animation_state));
+        assert!(run.set_alpha(&mut animation_state, 0.5).is_err());
+
+        animation_state.clear_tracks();
+        assert!(!walk.is_valid(&animation_state));
+        assert!(!jump.is_valid(&animation_state));
+        for _ in 0..10 {
+            let _ = animation_state.set_animation_by_name(0, "jump", false);
+            assert!(jump.get(&animation_state).is_none());
+        }
+    }
 
     #[test]
     fn track_entry_optional() {
@@ -954,4 +2180,417 @@ mod tests {
         let _ = animation_state.set_animation_by_name(0, "run", true);
         assert!(track_handle.get(&animation_state).is_none());
     }
+
+    #[test]
+    fn play_section() {
+        let (_, mut animation_state) = TestAsset::spineboy().instance(true);
+        assert!(animation_state
+            .play_section(0, "missing", 0.1, 0.2, false)
+            .is_err());
+        let _ = animation_state.play_section(0, "run", 0.1, 0.3, true);
+        animation_state.update(0.5);
+        let track_entry = animation_state.track_at_index(0).unwrap();
+        assert_eq!(track_entry.animation_start(), 0.1);
+        assert_eq!(track_entry.animation_end(), 0.3);
+        let animation_time = track_entry.animation_time();
+        assert!((0.1..=0.3).contains(&animation_time));
+    }
+
+    #[test]
+    fn event_queue_overflow() {
+        let (mut skeleton, mut animation_state) = TestAsset::spineboy().instance(true);
+        let events = Rc::new(Cell::new(0));
+        let listener_events = events.clone();
+        animation_state.set_listener(move |_, animation_event| {
+            if let AnimationEvent::Event { .. } = animation_event {
+                listener_events.set(listener_events.get() + 1);
+            }
+        });
+        animation_state.set_event_queue_settings(
+            EventQueueSettings::new()
+                .with_capacity(1)
+                .with_overflow(EventQueueOverflow::Error),
+        );
+        let _ = animation_state.set_animation_by_name(0, "run", true);
+        animation_state.update(0.6);
+        let result = animation_state.try_apply(&mut skeleton);
+        assert!(matches!(
+            result,
+            Err(SpineError::EventQueueOverflow {
+                capacity: 1,
+                queued: 2
+            })
+        ));
+        assert_eq!(events.get(), 1);
+        let statistics = animation_state.event_queue_statistics();
+        assert_eq!(statistics.dropped, 1);
+        assert_eq!(statistics.overflows, 1);
+        assert_eq!(statistics.peak, 2);
+    }
+
+    #[test]
+    fn event_buffering() {
+        let (mut skeleton, mut animation_state) = TestAsset::spineboy().instance(true);
+        animation_state.set_event_buffering(true);
+        let _ = animation_state.set_animation_by_name(0, "run", true);
+        animation_state.update(0.6);
+        animation_state.apply(&mut skeleton);
+        let events: Vec<OwnedAnimationEvent> = animation_state.drain_events().collect();
+        assert!(matches!(
+            &events[0],
+            OwnedAnimationEvent::Start { track_index: 0, animation } if animation == "run"
+        ));
+        assert_eq!(
+            events
+                .iter()
+                .filter(|event| matches!(event, OwnedAnimationEvent::Event { name, .. } if name == "footstep"))
+                .count(),
+            2
+        );
+        assert_eq!(animation_state.drain_events().count(), 0);
+        animation_state.set_event_buffering(false);
+        animation_state.update(0.6);
+        animation_state.apply(&mut skeleton);
+        assert_eq!(animation_state.drain_events().count(), 0);
+    }
+
+    #[test]
+    fn muted_events() {
+        let footsteps = |animation_state: &mut AnimationState| {
+            animation_state
+                .drain_events()
+                .filter(|event| matches!(event, OwnedAnimationEvent::Event { name, .. } if name == "footstep"))
+                .count()
+        };
+        let (mut skeleton, mut animation_state) = TestAsset::spineboy().instance(true);
+        animation_state.set_event_buffering(true);
+        animation_state.mute_events(&["footstep"]);
+        assert!(animation_state.is_event_muted("footstep"));
+        let _ = animation_state.set_animation_by_name(0, "run", true);
+        animation_state.update(0.6);
+        animation_state.apply(&mut skeleton);
+        assert_eq!(footsteps(&mut animation_state), 0);
+        animation_state.unmute_events(&["footstep"]);
+        assert!(!animation_state.is_event_muted("footstep"));
+        animation_state.update(0.6);
+        animation_state.apply(&mut skeleton);
+        assert!(footsteps(&mut animation_state) > 0);
+    }
+
+    #[test]
+    fn event_time_offsets() {
+        // The frames at which footstep events are delivered.
+        let footstep_frames = |offset: f32| {
+            let (mut skeleton, mut animation_state) = TestAsset::spineboy().instance(true);
+            animation_state.set_event_buffering(true);
+            let run = animation_state
+                .set_animation_by_name(0, "run", true)
+                .unwrap()
+                .handle();
+            run.set_event_time_offset(&mut animation_state, offset)
+                .unwrap();
+            assert_eq!(run.event_time_offset(&animation_state), Some(offset));
+            let mut frames = vec![];
+            for frame in 0..120 {
+                animation_state.update(0.01);
+                animation_state.apply(&mut skeleton);
+                for event in animation_state.drain_events() {
+                    if matches!(event, OwnedAnimationEvent::Event { name, .. } if name == "footstep")
+                    {
+                        frames.push(frame);
+                    }
+                }
+            }
+            frames
+        };
+        let keyed = footstep_frames(0.);
+        let late = footstep_frames(0.1);
+        let early = footstep_frames(-0.1);
+        assert!(keyed.len() >= 3);
+        for (keyed, late) in keyed.iter().zip(&late) {
+            assert!((late - keyed - 10_i32).abs() <= 1, "{late} {keyed}");
+        }
+        // Early events keyed before the offset fire on the first frame.
+        for (keyed, early) in keyed.iter().zip(&early).skip(1) {
+            assert!((keyed - early - 10_i32).abs() <= 1, "{early} {keyed}");
+        }
+        assert!(early.len() >= keyed.len());
+    }
+
+    #[test]
+    fn interrupt_reasons() {
+        let (mut skeleton, mut animation_state) = TestAsset::spineboy().instance(true);
+        animation_state.set_event_buffering(true);
+        let interrupts = |animation_state: &mut AnimationState| {
+            animation_state
+                .drain_events()
+                .filter_map(|event| match event {
+                    OwnedAnimationEvent::Interrupt {
+                        animation, reason, ..
+                    } => Some((animation, reason)),
+                    _ => None,
+                })
+                .collect::<Vec<_>>()
+        };
+        let mut step = |animation_state: &mut AnimationState| {
+            animation_state.update(0.1);
+            animation_state.apply(&mut skeleton);
+        };
+        let _ = animation_state.set_animation_by_name(0, "walk", true);
+        let _ = animation_state.add_animation_by_name(0, "run", true, 0.05);
+        step(&mut animation_state);
+        step(&mut animation_state);
+        assert_eq!(
+            interrupts(&mut animation_state),
+            [("walk".to_owned(), InterruptReason::Queued)]
+        );
+        let _ = animation_state.set_animation_by_name(0, "jump", false);
+        assert_eq!(
+            interrupts(&mut animation_state),
+            [("run".to_owned(), InterruptReason::Replaced)]
+        );
+        let _ = animation_state.set_animation_by_name(0, "shoot", false);
+        assert_eq!(
+            interrupts(&mut animation_state),
+            [("jump".to_owned(), InterruptReason::Discarded)]
+        );
+        step(&mut animation_state);
+        let _ = interrupts(&mut animation_state);
+        animation_state.set_empty_animations(0.2);
+        assert_eq!(
+            interrupts(&mut animation_state),
+            [("shoot".to_owned(), InterruptReason::Emptied)]
+        );
+        animation_state.clear_tracks();
+        assert!(interrupts(&mut animation_state).is_empty());
+    }
+
+    #[test]
+    fn track_listener() {
+        let (mut skeleton, mut animation_state) = TestAsset::spineboy().instance(true);
+        let track_events = Rc::new(Cell::new(0));
+        let listener_track_events = track_events.clone();
+        animation_state.set_track_listener(1, move |_, animation_event| {
+            let OwnedAnimationEvent::Start { track_index, .. } = animation_event.into() else {
+                return;
+            };
+            assert_eq!(track_index, 1);
+            listener_track_events.set(listener_track_events.get() + 1);
+        });
+        let _ = animation_state.set_animation_by_name(0, "run", true);
+        let _ = animation_state.set_animation_by_name(1, "aim", true);
+        animation_state.update(0.1);
+        animation_state.apply(&mut skeleton);
+        assert_eq!(track_events.get(), 1);
+        animation_state.clear_track_listener(1);
+        let _ = animation_state.set_animation_by_name(1, "shoot", false);
+        animation_state.update(0.1);
+        animation_state.apply(&mut skeleton);
+        assert_eq!(track_events.get(), 1);
+    }
+
+    #[test]
+    fn listener_handle() {
+        let (mut skeleton, mut animation_state) = TestAsset::spineboy().instance(true);
+        let starts = Rc::new(Cell::new(0));
+        let listener_starts = starts.clone();
+        let listener = animation_state.add_listener(move |_, animation_event| {
+            if let AnimationEvent::Start { .. } = animation_event {
+                listener_starts.set(listener_starts.get() + 1);
+            }
+        });
+        let track_starts = starts.clone();
+        animation_state
+            .add_track_listener(1, move |_, animation_event| {
+                if let AnimationEvent::Start { .. } = animation_event {
+                    track_starts.set(track_starts.get() + 10);
+                }
+            })
+            .keep();
+        let _ = animation_state.set_animation_by_name(0, "run", true);
+        let _ = animation_state.set_animation_by_name(1, "aim", true);
+        animation_state.update(0.1);
+        animation_state.apply(&mut skeleton);
+        assert_eq!(starts.get(), 12);
+        assert!(listener.is_attached());
+
+        drop(listener);
+        let _ = animation_state.set_animation_by_name(0, "walk", true);
+        let _ = animation_state.set_animation_by_name(1, "shoot", false);
+        animation_state.update(0.1);
+        animation_state.apply(&mut skeleton);
+        assert_eq!(starts.get(), 22);
+
+        let listener = animation_state.add_listener(|_, _| {});
+        drop(animation_state);
+        assert!(!listener.is_attached());
+    }
+
+    #[test]
+    fn track_entry_user_data() {
+        struct DropCounter(Arc<AtomicUsize>);
+        impl Drop for DropCounter {
+            fn drop(&mut self) {
+                self.0.fetch_add(1, Ordering::Relaxed);
+            }
+        }
+
+        let (mut skeleton, mut animation_state) = TestAsset::spineboy().instance(true);
+        let drops = Arc::new(AtomicUsize::new(0));
+        animation_state
+            .set_animation_by_name(0, "walk", true)
+            .unwrap()
+            .set_user_data(DropCounter(drops.clone()));
+        let run = animation_state
+            .add_animation_by_name(0, "run", true, 0.)
+            .unwrap()
+            .handle();
+        run.set_user_data(&mut animation_state, 5_u32).unwrap();
+        assert!(animation_state
+            .track_at_index(0)
+            .unwrap()
+            .user_data::<DropCounter>()
+            .is_some());
+        assert_eq!(run.user_data::<u32>(&animation_state), Some(&5));
+        assert!(run.user_data::<i32>(&animation_state).is_none());
+        *run.user_data_mut::<u32>(&mut animation_state).unwrap() += 1;
+        assert_eq!(run.user_data::<u32>(&animation_state), Some(&6));
+
+        let _ = animation_state.set_animation_by_name(0, "jump", false);
+        animation_state.update(0.1);
+        animation_state.apply(&mut skeleton);
+        animation_state.clear_tracks();
+        assert_eq!(drops.load(Ordering::Relaxed), 1);
+        assert!(run.user_data::<u32>(&animation_state).is_none());
+        assert!(run.set_user_data(&mut animation_state, 1_u32).is_err());
+
+        animation_state
+            .set_animation_by_name(0, "walk", true)
+            .unwrap()
+            .set_user_data(DropCounter(drops.clone()));
+        drop(animation_state);
+        assert_eq!(drops.load(Ordering::Relaxed), 2);
+    }
+
+    #[test]
+    fn empty_animations() {
+        let (_, mut animation_state) = TestAsset::spineboy().instance(true);
+        let _ = animation_state.set_animation_by_name(0, "walk", true);
+        assert!(matches!(
+            animation_state.add_animation_by_name(0, "missing", false, 0.),
+            Err(SpineError::NotFound { .. })
+        ));
+        let _ = animation_state.add_empty_animation(0, 0.5, 0.);
+        {
+            let track_entry = animation_state.track_at_index(0).unwrap();
+            let next = track_entry.next().unwrap();
+            assert_eq!(next.animation().name(), "<empty>");
+            assert_eq!(next.mix_duration(), 0.5);
+        }
+
+        let _ = animation_state.set_animation_by_name(1, "shoot", false);
+        animation_state.set_empty_animations(0.25);
+        for track_index in 0..2 {
+            let track_entry = animation_state.track_at_index(track_index).unwrap();
+            assert_eq!(track_entry.animation().name(), "<empty>");
+            assert_eq!(track_entry.mix_duration(), 0.25);
+            assert!(track_entry.next().is_none());
+        }
+    }
+
+    #[test]
+    fn default_shortest_rotation() {
+        let (_, mut animation_state) = TestAsset::spineboy().instance(true);
+        assert!(!animation_state.default_shortest_rotation());
+        assert!(!animation_state
+            .set_animation_by_name(0, "walk", true)
+            .unwrap()
+            .shortest_rotation());
+
+        animation_state.set_default_shortest_rotation(true);
+        assert!(animation_state
+            .set_animation_by_name(0, "run", true)
+            .unwrap()
+            .shortest_rotation());
+        assert!(animation_state
+            .add_animation_by_name(0, "jump", false, 0.)
+            .unwrap()
+            .shortest_rotation());
+        assert!(animation_state
+            .add_empty_animation(0, 0.2, 0.)
+            .shortest_rotation());
+        let _ = animation_state.set_animation_by_name(1, "shoot", false);
+        animation_state.set_default_shortest_rotation(false);
+        animation_state.set_empty_animations(0.2);
+        for track_index in 0..2 {
+            let track_entry = animation_state.track_at_index(track_index).unwrap();
+            assert!(!track_entry.shortest_rotation());
+        }
+    }
+
+    #[test]
+    fn audio_events() {
+        let json = r#"{
+            "skeleton": { "spine": "4.2.00" },
+            "bones": [ { "name": "root" } ],
+            "events": {
+                "sound": { "audio": "step.ogg", "volume": 0.5, "balance": -0.25 },
+                "hit": { "int": 1 }
+            },
+            "animations": { "attack": { "events": [
+                { "time": 0.1, "name": "sound", "balance": 0.5 },
+                { "time": 0.2, "name": "hit" }
+            ] } }
+        }"#;
+        let skeleton_data = Arc::new(
+            TestAsset::spineboy()
+                .skeleton_json()
+                .read_skeleton_data(json.as_bytes())
+                .unwrap(),
+        );
+        let sound = skeleton_data.find_event("sound").unwrap();
+        assert_eq!(
+            sound.audio(),
+            Some(EventAudio {
+                path: "step.ogg",
+                volume: 0.5,
+                balance: -0.25
+            })
+        );
+        let mut skeleton = Skeleton::new(skeleton_data.clone());
+        let mut animation_state =
+            AnimationState::new(Arc::new(AnimationStateData::new(skeleton_data)));
+        let audio = Rc::new(RefCell::new(vec![]));
+        let listener_audio = audio.clone();
+        animation_state.set_listener(move |_, animation_event| {
+            if let AnimationEvent::Event {
+                audio_path,
+                volume,
+                balance,
+                event,
+                ..
+            } = animation_event
+            {
+                assert_eq!(event.audio_path(), audio_path);
+                listener_audio.borrow_mut().push((
+                    audio_path.to_owned(),
+                    volume,
+                    balance,
+                    event.audio().is_some(),
+                ));
+            }
+        });
+        animation_state
+            .set_animation_by_name(0, "attack", false)
+            .unwrap();
+        animation_state.update(0.3);
+        animation_state.apply(&mut skeleton);
+        assert_eq!(
+            *audio.borrow(),
+            [
+                ("step.ogg".to_owned(), 0.5, 0.5, true),
+                (String::new(), 0., 0., false)
+            ]
+        );
+    }
 }
@@ -1,19 +1,34 @@
-use std::sync::Arc;
+use alloc::sync::Arc;
+use core::sync::atomic::{AtomicBool, Ordering};
 
+use crate::prelude::*;
 use crate::{
-    animation::Animation,
+    animation_mod::Animation,
     c::{
-        c_void, spAnimationStateData, spAnimationStateData_create, spAnimationStateData_dispose,
-        spAnimationStateData_getMix, spAnimationStateData_setMix,
-        spAnimationStateData_setMixByName, spSkeletonData,
+        _FromEntry, c_void, spAnimation, spAnimationStateData, spAnimationStateData_create,
+        spAnimationStateData_dispose, spAnimationStateData_getMix, spAnimationStateData_setMix,
+        spAnimationStateData_setMixByName, spSkeletonData, spSkeletonData_findAnimation,
     },
-    c_interface::{to_c_str, NewFromPtr, SyncPtr},
+    c_interface::{from_c_str, to_c_str, CTmpMut, NewFromPtr, SyncPtr},
+    diagnostics::{self, LiveObjectKind},
+    error::SpineError,
     skeleton_data::SkeletonData,
 };
 
 #[allow(unused_imports)]
 use crate::AnimationState;
 
+/// A mix duration set for a pair of animations, see [`AnimationStateData::mixes`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MixEntry<'a> {
+    /// The name of the animation being mixed from.
+    pub from: &'a str,
+    /// The name of the animation being mixed to.
+    pub to: &'a str,
+    /// The mix duration in seconds.
+    pub duration: f32,
+}
+
 /// Animation settings used to instantiate [`AnimationState`].
 ///
 /// [Spine API Reference](http://esotericsoftware.com/spine-api-reference#AnimationStateData)
@@ -25,7 +40,8 @@ use crate::AnimationState;
 /// # #[path="./test.rs"]
 /// # mod test;
 /// # let mut animation_state_data = test::TestAsset::spineboy().animation_state_data(true);
-/// animation_state_data.set_mix_by_name("walk", "run", 0.2);
+/// animation_state_data.set_mix_by_name("walk", "run", 0.2)?;
+/// # Ok::<(), rusty_spine::SpineError>(())
 /// ```
 ///
 /// This operation is one way, so to blend back and forth between the two animations, two mix
@@ -35,14 +51,16 @@ use crate::AnimationState;
 /// # #[path="./test.rs"]
 /// # mod test;
 /// # let mut animation_state_data = test::TestAsset::spineboy().animation_state_data(true);
-/// animation_state_data.set_mix_by_name("walk", "run", 0.2);
-/// animation_state_data.set_mix_by_name("run", "walk", 0.2);
+/// animation_state_data.set_mix_by_name("walk", "run", 0.2)?;
+/// animation_state_data.set_mix_by_name("run", "walk", 0.2)?;
+/// # Ok::<(), rusty_spine::SpineError>(())
 /// ```
 #[derive(Debug)]
 pub struct AnimationStateData {
     c_animation_state_data: SyncPtr<spAnimationStateData>,
     owns_memory: bool,
-    _skeleton_data: Option<Arc<SkeletonData>>,
+    skeleton_data: Option<Arc<SkeletonData>>,
+    frozen: AtomicBool,
 }
 
 impl NewFromPtr<spAnimationStateData> for AnimationStateData {
@@ -50,7 +68,8 @@ impl NewFromPtr<spAnimationStateData> for AnimationStateData {
         Self {
             c_animation_state_data: SyncPtr(c_animation_state_data),
             owns_memory: false,
-            _skeleton_data: None,
+            skeleton_data: None,
+            frozen: AtomicBool::new(false),
         }
     }
 }
@@ -59,14 +78,49 @@ impl AnimationStateData {
     #[must_use]
     pub fn new(skeleton_data: Arc<SkeletonData>) -> Self {
         let c_animation_state_data = unsafe { spAnimationStateData_create(skeleton_data.c_ptr()) };
+        diagnostics::track(LiveObjectKind::AnimationStateData, c_animation_state_data);
         Self {
             c_animation_state_data: SyncPtr(c_animation_state_data),
             owns_memory: true,
-            _skeleton_data: Some(skeleton_data),
+            skeleton_data: Some(skeleton_data),
+            frozen: AtomicBool::new(false),
         }
     }
 
-    pub fn set_mix_by_name(&mut self, from_name: &str, to_name: &str, duration: f32) {
+    /// Prevent any further changes to this animation state data, the same as
+    /// [`SkeletonData::freeze`]. Afterwards, the mix setters and
+    /// [`AnimationState::data_mut`] return [`SpineError::Frozen`].
+    pub fn freeze(&self) {
+        self.frozen.store(true, Ordering::Release);
+    }
+
+    /// Returns `true` if [`freeze`](`Self::freeze`) has been called.
+    #[must_use]
+    pub fn is_frozen(&self) -> bool {
+        self.frozen.load(Ordering::Acquire)
+    }
+
+    fn check_frozen(&self) -> Result<(), SpineError> {
+        if self.is_frozen() {
+            Err(SpineError::new_frozen("AnimationStateData"))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Set the mix duration when changing from the animation named `from_name` to the animation
+    /// named `to_name`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SpineError::Frozen`] if this data has been frozen.
+    pub fn set_mix_by_name(
+        &mut self,
+        from_name: &str,
+        to_name: &str,
+        duration: f32,
+    ) -> Result<(), SpineError> {
+        self.check_frozen()?;
         let c_from_name = to_c_str(from_name);
         let c_to_name = to_c_str(to_name);
         unsafe {
@@ -77,26 +131,155 @@ impl AnimationStateData {
                 duration,
             );
         }
+        Ok(())
     }
 
-    pub fn set_mix(&mut self, from: &Animation, to: &Animation, duration: f32) {
+    /// Set the mix duration when changing from the `from` animation to the `to` animation.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SpineError::Frozen`] if this data has been frozen.
+    pub fn set_mix(
+        &mut self,
+        from: &Animation,
+        to: &Animation,
+        duration: f32,
+    ) -> Result<(), SpineError> {
+        self.check_frozen()?;
         unsafe {
             spAnimationStateData_setMix(self.c_ptr(), from.c_ptr(), to.c_ptr(), duration);
         }
+        Ok(())
     }
 
     pub fn get_mix(&mut self, from: &Animation, to: &Animation) -> f32 {
         unsafe { spAnimationStateData_getMix(self.c_ptr(), from.c_ptr(), to.c_ptr()) }
     }
 
-    c_accessor_tmp_ptr_mut!(
-        skeleton_data,
-        skeleton_data_mut,
-        skeletonData,
-        SkeletonData,
-        spSkeletonData
-    );
-    c_accessor_mut!(default_mix, set_default_mix, defaultMix, f32);
+    /// Set the mix durations of many animation pairs, given as `(from_name, to_name, duration)`,
+    /// such as from a data file of crossfades.
+    ///
+    /// ```
+    /// # #[path="./test.rs"]
+    /// # mod test;
+    /// # let mut animation_state_data = test::TestAsset::spineboy().animation_state_data(true);
+    /// animation_state_data.set_mixes([
+    ///     ("walk", "run", 0.2),
+    ///     ("run", "walk", 0.2),
+    ///     ("idle", "walk", 0.1),
+    /// ])?;
+    /// assert_eq!(animation_state_data.mix_duration("run", "walk")?, 0.2);
+    /// # Ok::<(), rusty_spine::SpineError>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SpineError::Frozen`] if this data has been frozen, or [`SpineError::NotFound`]
+    /// if an animation does not exist. No mixes are set if there is an error.
+    pub fn set_mixes<'a>(
+        &mut self,
+        mixes: impl IntoIterator<Item = (&'a str, &'a str, f32)>,
+    ) -> Result<(), SpineError> {
+        self.check_frozen()?;
+        let mixes = mixes
+            .into_iter()
+            .map(|(from_name, to_name, duration)| {
+                Ok((
+                    self.find_animation(from_name)?,
+                    self.find_animation(to_name)?,
+                    duration,
+                ))
+            })
+            .collect::<Result<Vec<_>, SpineError>>()?;
+        for (from, to, duration) in mixes {
+            unsafe {
+                spAnimationStateData_setMix(self.c_ptr(), from, to, duration);
+            }
+        }
+        Ok(())
+    }
+
+    /// The mix duration when changing from the animation named `from_name` to the animation
+    /// named `to_name`, or the [default mix](`Self::default_mix`) if none has been set for the
+    /// pair.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SpineError::NotFound`] if either animation does not exist.
+    pub fn mix_duration(&self, from_name: &str, to_name: &str) -> Result<f32, SpineError> {
+        let from = self.find_animation(from_name)?;
+        let to = self.find_animation(to_name)?;
+        Ok(unsafe { spAnimationStateData_getMix(self.c_ptr(), from, to) })
+    }
+
+    /// The mix durations which have been set for animation pairs, in no particular order.
+    /// Animation pairs without an entry use the [default mix](`Self::default_mix`).
+    #[must_use]
+    pub fn mixes(&self) -> Vec<MixEntry<'_>> {
+        let name = |animation: *mut spAnimation| unsafe {
+            from_c_str(core::ffi::CStr::from_ptr((*animation).name))
+        };
+        let mut mixes = vec![];
+        let mut from_entry = unsafe { self.c_ptr_ref().entries.cast::<_FromEntry>() };
+        while let Some(from) = unsafe { from_entry.as_ref() } {
+            let mut to_entry = from.toEntries;
+            while let Some(to) = unsafe { to_entry.as_ref() } {
+                mixes.push(MixEntry {
+                    from: name(from.animation),
+                    to: name(to.animation),
+                    duration: to.duration,
+                });
+                to_entry = to.next;
+            }
+            from_entry = from.next;
+        }
+        mixes
+    }
+
+    fn find_animation(&self, name: &str) -> Result<*mut spAnimation, SpineError> {
+        let c_name = to_c_str(name);
+        let animation =
+            unsafe { spSkeletonData_findAnimation(self.c_ptr_ref().skeletonData, c_name.as_ptr()) };
+        if animation.is_null() {
+            Err(SpineError::new_not_found("Animation", name))
+        } else {
+            Ok(animation)
+        }
+    }
+
+    c_accessor_tmp_ptr!(skeleton_data, skeletonData, SkeletonData, spSkeletonData);
+
+    /// The mutable skeleton data this animation state data was created with.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SpineError::Frozen`] if this data or the skeleton data has been frozen.
+    pub fn skeleton_data_mut(&mut self) -> Result<CTmpMut<'_, Self, SkeletonData>, SpineError> {
+        self.check_frozen()?;
+        if self
+            .skeleton_data
+            .as_ref()
+            .is_some_and(|skeleton_data| skeleton_data.is_frozen())
+        {
+            return Err(SpineError::new_frozen("SkeletonData"));
+        }
+        Ok(CTmpMut::new(self, unsafe {
+            SkeletonData::new_from_ptr(self.c_ptr_ref().skeletonData)
+        }))
+    }
+
+    c_accessor!(default_mix, defaultMix, f32);
+
+    /// Set the mix duration to use when no mix duration has been set for an animation pair.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SpineError::Frozen`] if this data has been frozen.
+    pub fn set_default_mix(&mut self, default_mix: f32) -> Result<(), SpineError> {
+        self.check_frozen()?;
+        unsafe { self.c_ptr_mut().defaultMix = default_mix };
+        Ok(())
+    }
     c_accessor_passthrough!(entries, entries, *const c_void);
     c_ptr!(c_animation_state_data, spAnimationStateData);
 }
@@ -104,9 +287,87 @@ impl AnimationStateData {
 impl Drop for AnimationStateData {
     fn drop(&mut self) {
         if self.owns_memory {
+            diagnostics::untrack(
+                LiveObjectKind::AnimationStateData,
+                self.c_animation_state_data.0,
+            );
             unsafe {
                 spAnimationStateData_dispose(self.c_animation_state_data.0);
             }
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use alloc::sync::Arc;
+
+    use crate::{test::TestAsset, AnimationState, Skeleton, SpineError};
+
+    #[test]
+    fn freeze() {
+        let (skeleton_data, _) = TestAsset::spineboy().instance_data(true);
+        let mut animation_state_data = super::AnimationStateData::new(skeleton_data.clone());
+        assert!(animation_state_data.skeleton_data_mut().is_ok());
+        skeleton_data.freeze();
+        assert!(matches!(
+            animation_state_data.skeleton_data_mut(),
+            Err(SpineError::Frozen { .. })
+        ));
+        assert!(Skeleton::new(skeleton_data).data_mut().is_err());
+
+        assert!(animation_state_data
+            .set_mix_by_name("walk", "run", 0.2)
+            .is_ok());
+        animation_state_data.freeze();
+        assert!(animation_state_data
+            .set_mix_by_name("walk", "run", 0.5)
+            .is_err());
+        assert!(animation_state_data.set_default_mix(0.5).is_err());
+        assert_eq!(animation_state_data.default_mix(), 0.);
+        let mut animation_state = AnimationState::new(Arc::new(animation_state_data));
+        assert!(animation_state.data_mut().is_err());
+    }
+
+    #[test]
+    fn mixes() {
+        let mut animation_state_data = TestAsset::spineboy().animation_state_data(true);
+        animation_state_data.set_default_mix(0.1).unwrap();
+        animation_state_data
+            .set_mixes([("walk", "run", 0.2), ("run", "walk", 0.3)])
+            .unwrap();
+        assert!(matches!(
+            animation_state_data.set_mixes([("walk", "idle", 0.4), ("walk", "missing", 0.5)]),
+            Err(SpineError::NotFound { .. })
+        ));
+        assert_eq!(
+            animation_state_data.mix_duration("walk", "run").unwrap(),
+            0.2
+        );
+        assert_eq!(
+            animation_state_data.mix_duration("run", "walk").unwrap(),
+            0.3
+        );
+        assert_eq!(
+            animation_state_data.mix_duration("walk", "idle").unwrap(),
+            0.1
+        );
+        assert!(animation_state_data
+            .mix_duration("missing", "walk")
+            .is_err());
+
+        let mut mixes: Vec<_> = animation_state_data
+            .mixes()
+            .into_iter()
+            .map(|mix| (mix.from.to_owned(), mix.to.to_owned(), mix.duration))
+            .collect();
+        mixes.sort_by(|a, b| a.0.cmp(&b.0));
+        assert_eq!(
+            mixes,
+            [
+                ("run".to_owned(), "walk".to_owned(), 0.3),
+                ("walk".to_owned(), "run".to_owned(), 0.2)
+            ]
+        );
+    }
+}
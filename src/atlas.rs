@@ -1,11 +1,14 @@
-use std::ffi::{CStr, CString};
-use std::{path::Path, ptr::null_mut};
-
-use crate::c::{
-    spAtlasFilter, spAtlasFormat, spAtlasRegion, spAtlasWrap, spAtlas_createFromFile,
-    spTextureRegion,
-};
+use alloc::ffi::CString;
+use core::{ffi::CStr, ptr::null_mut};
+#[cfg(feature = "std")]
+use std::path::Path;
+
+#[cfg(feature = "std")]
+use crate::c::spAtlas_createFromFile;
+use crate::c::{spAtlasFilter, spAtlasFormat, spAtlasRegion, spAtlasWrap, spTextureRegion};
 use crate::c_interface::{CTmpRef, NewFromPtr, SyncPtr};
+use crate::diagnostics::{self, LiveObjectKind};
+use crate::extension::{create_atlas, TextureCallbacks};
 use crate::texture_region::TextureRegion;
 use crate::{
     c::{c_int, spAtlas, spAtlasPage, spAtlas_create, spAtlas_dispose},
@@ -52,20 +55,72 @@ impl Atlas {
     /// [`SpineError::PathNotUtf8`] if the specified `dir` is not utf-8. This function does not
     /// error if the atlas file is invalid or malformed. The file is parsed line-by-line and invalid
     /// lines are simply ignored.
+    #[cfg(feature = "std")]
     pub fn new<P: AsRef<Path>>(data: &[u8], dir: P) -> Result<Atlas, SpineError> {
-        let c_data = CString::new(data)?;
-        let Some(dir_path) = dir.as_ref().to_str() else {
+        Self::new_with_callbacks(data, dir, &TextureCallbacks::new())
+    }
+
+    /// Create an Atlas from an in-memory vector, with `callbacks` overriding the global texture
+    /// callbacks for its pages. See [`Atlas::new`].
+    ///
+    /// # Errors
+    ///
+    /// Returns the [`SpineError::NulError`] if `dir` or `data` contain an internal 0 byte. Returns
+    /// [`SpineError::PathNotUtf8`] if the specified `dir` is not utf-8.
+    #[cfg(feature = "std")]
+    pub fn new_with_callbacks<P: AsRef<Path>>(
+        data: &[u8],
+        dir: P,
+        callbacks: &TextureCallbacks,
+    ) -> Result<Atlas, SpineError> {
+        let Some(dir) = dir.as_ref().to_str() else {
             return Err(SpineError::PathNotUtf8);
         };
-        let c_dir = CString::new(dir_path)?;
-        let c_atlas = unsafe {
+        Self::new_in_dir(data, dir, callbacks)
+    }
+
+    /// Create an Atlas from an in-memory vector, with `dir` as the directory of its pages.
+    ///
+    /// # Errors
+    ///
+    /// Returns the [`SpineError::NulError`] if `dir` or `data` contain an internal 0 byte. This
+    /// function does not error if the atlas file is invalid or malformed.
+    #[cfg(not(feature = "std"))]
+    pub fn new<P: AsRef<str>>(data: &[u8], dir: P) -> Result<Atlas, SpineError> {
+        Self::new_with_callbacks(data, dir, &TextureCallbacks::new())
+    }
+
+    /// Create an Atlas from an in-memory vector, with `callbacks` overriding the global texture
+    /// callbacks for its pages. See [`Atlas::new`].
+    ///
+    /// # Errors
+    ///
+    /// Returns the [`SpineError::NulError`] if `dir` or `data` contain an internal 0 byte.
+    #[cfg(not(feature = "std"))]
+    pub fn new_with_callbacks<P: AsRef<str>>(
+        data: &[u8],
+        dir: P,
+        callbacks: &TextureCallbacks,
+    ) -> Result<Atlas, SpineError> {
+        Self::new_in_dir(data, dir.as_ref(), callbacks)
+    }
+
+    fn new_in_dir(
+        data: &[u8],
+        dir: &str,
+        callbacks: &TextureCallbacks,
+    ) -> Result<Atlas, SpineError> {
+        let c_data = CString::new(data)?;
+        let c_dir = CString::new(dir)?;
+        let c_atlas = create_atlas(callbacks, |renderer_object| unsafe {
             spAtlas_create(
                 c_data.as_ptr(),
                 data.len() as c_int,
                 c_dir.as_ptr(),
-                null_mut(),
+                renderer_object,
             )
-        };
+        });
+        diagnostics::track(LiveObjectKind::Atlas, c_atlas);
         Ok(Self {
             c_atlas: SyncPtr(c_atlas),
             owns_memory: true,
@@ -86,13 +141,34 @@ impl Atlas {
     /// [`SpineError::NulError`] if `path` contains an internal 0 byte or if the loaded atlas
     /// contains a 0 byte. Returns  [`SpineError::PathNotUtf8`] if the specified `path` is not
     /// utf-8.
+    #[cfg(feature = "std")]
     pub fn new_from_file<P: AsRef<Path>>(path: P) -> Result<Atlas, SpineError> {
+        Self::new_from_file_with_callbacks(path, &TextureCallbacks::new())
+    }
+
+    /// Create an Atlas from a file, with `callbacks` overriding the global texture callbacks for
+    /// its pages. See [`TextureCallbacks`] for an example.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SpineError::FailedToReadFile`] if the file could not be read, returns
+    /// [`SpineError::NulError`] if `path` contains an internal 0 byte or if the loaded atlas
+    /// contains a 0 byte. Returns  [`SpineError::PathNotUtf8`] if the specified `path` is not
+    /// utf-8.
+    #[cfg(feature = "std")]
+    pub fn new_from_file_with_callbacks<P: AsRef<Path>>(
+        path: P,
+        callbacks: &TextureCallbacks,
+    ) -> Result<Atlas, SpineError> {
         let Some(path_str) = path.as_ref().to_str() else {
             return Err(SpineError::PathNotUtf8);
         };
         let c_path = CString::new(path_str)?;
-        let c_atlas = unsafe { spAtlas_createFromFile(c_path.as_ptr(), null_mut()) };
+        let c_atlas = create_atlas(callbacks, |renderer_object| unsafe {
+            spAtlas_createFromFile(c_path.as_ptr(), renderer_object)
+        });
         if !c_atlas.is_null() {
+            diagnostics::track(LiveObjectKind::Atlas, c_atlas);
             Ok(Self {
                 c_atlas: SyncPtr(c_atlas),
                 owns_memory: true,
@@ -141,6 +217,7 @@ impl Atlas {
 impl Drop for Atlas {
     fn drop(&mut self) {
         if self.owns_memory {
+            diagnostics::untrack(LiveObjectKind::Atlas, self.c_atlas.0);
             unsafe {
                 spAtlas_dispose(self.c_atlas.0);
             }
@@ -153,10 +230,83 @@ pub mod atlas {
     //!
     //! To load an atlas file, see [`Atlas`].
 
+    use alloc::collections::BTreeMap;
+    use core::any::{Any, TypeId};
+
+    use crate::c::c_void;
     use crate::c_interface::from_c_str;
+    use crate::extension::PendingTexture;
+    use crate::prelude::*;
+    use crate::sync::{lock, Mutex};
 
     use super::*;
 
+    /// The type of each texture set with [`AtlasPage::set_texture`], by page address, so the
+    /// texture can be checked when read and dropped when the page is disposed.
+    static PAGE_TEXTURES: Mutex<BTreeMap<usize, PageTexture>> = Mutex::new(BTreeMap::new());
+
+    struct PageTexture {
+        type_id: TypeId,
+        drop: unsafe fn(*mut c_void),
+    }
+
+    unsafe fn drop_texture<T>(texture: *mut c_void) {
+        drop(Box::from_raw(texture.cast::<T>()));
+    }
+
+    /// The pages waiting for a texture deferred with [`AtlasPage::defer_texture`].
+    static PENDING_TEXTURES: Mutex<PendingTextures> = Mutex::new(PendingTextures {
+        next_id: 0,
+        pages: BTreeMap::new(),
+    });
+
+    struct PendingTextures {
+        next_id: u64,
+        /// Page and atlas addresses, by pending texture id.
+        pages: BTreeMap<u64, PendingPage>,
+    }
+
+    struct PendingPage {
+        page: usize,
+        atlas: usize,
+    }
+
+    /// Remove the page waiting for the pending texture, if it belongs to the atlas and was not
+    /// disposed or deferred again since.
+    pub(crate) fn take_pending_page(
+        pending_texture: PendingTexture,
+        c_atlas: *mut spAtlas,
+    ) -> Option<*mut spAtlasPage> {
+        let mut pending_textures = lock(&PENDING_TEXTURES);
+        let id = pending_texture.into_id();
+        if pending_textures.pages.get(&id)?.atlas != c_atlas.addr() {
+            return None;
+        }
+        pending_textures
+            .pages
+            .remove(&id)
+            .map(|pending_page| pending_page.page as *mut spAtlasPage)
+    }
+
+    fn cancel_pending_texture(c_atlas_page: *mut spAtlasPage) {
+        lock(&PENDING_TEXTURES)
+            .pages
+            .retain(|_, pending_page| pending_page.page != c_atlas_page.addr());
+    }
+
+    /// Drop the texture set with [`AtlasPage::set_texture`], if any, and cancel any texture
+    /// deferred with [`AtlasPage::defer_texture`].
+    pub(crate) fn dispose_page_texture(c_atlas_page: *mut spAtlasPage) {
+        cancel_pending_texture(c_atlas_page);
+        let page_texture = lock(&PAGE_TEXTURES).remove(&c_atlas_page.addr());
+        if let Some(page_texture) = page_texture {
+            unsafe {
+                let texture = core::mem::replace(&mut (*c_atlas_page).rendererObject, null_mut());
+                (page_texture.drop)(texture);
+            }
+        }
+    }
+
     /// Settings for an atlas backing texture contained in [`Atlas`].
     ///
     /// [Spine API Reference](http://esotericsoftware.com/spine-api-reference#AtlasPage)
@@ -234,6 +384,166 @@ pub mod atlas {
             pma
         );
         c_accessor_renderer_object!();
+
+        /// Store the engine texture for this page, replacing and dropping any texture previously
+        /// set. The texture is dropped when the page is disposed, after the callback set with
+        /// [`set_dispose_texture_cb`](`crate::extension::set_dispose_texture_cb`) runs. It must be
+        /// `Sync`, since an [`Atlas`] and its pages can be shared between threads.
+        ///
+        /// ```
+        /// struct SpineTexture(pub String);
+        ///
+        /// rusty_spine::extension::set_create_texture_cb(|atlas_page, path| {
+        ///     atlas_page.set_texture(SpineTexture(path.to_owned()));
+        /// });
+        /// ```
+        ///
+        /// The texture is stored in the page's [`renderer_object`](`Self::renderer_object`), so it
+        /// is also available from the `attachment_renderer_object` of renderables, as a pointer to
+        /// `T`.
+        ///
+        /// # Panics
+        ///
+        /// Panics if the renderer object was already set with
+        /// [`RendererObject::set`](`crate::RendererObject::set`).
+        pub fn set_texture<T: Any + Send + Sync>(&mut self, texture: T) {
+            let c_atlas_page = self.c_ptr();
+            dispose_page_texture(c_atlas_page);
+            let renderer_object = unsafe { &mut (*c_atlas_page).rendererObject };
+            assert!(
+                renderer_object.is_null(),
+                "Setting an atlas page texture when the renderer object is already set."
+            );
+            *renderer_object = Box::into_raw(Box::new(texture)).cast::<c_void>();
+            lock(&PAGE_TEXTURES).insert(
+                c_atlas_page.addr(),
+                PageTexture {
+                    type_id: TypeId::of::<T>(),
+                    drop: drop_texture::<T>,
+                },
+            );
+        }
+
+        /// The texture set with [`set_texture`](`Self::set_texture`), or [`None`] if no texture
+        /// of type `T` is set.
+        #[must_use]
+        pub fn texture<T: Any + Send + Sync>(&self) -> Option<&T> {
+            self.has_texture::<T>()
+                .then(|| unsafe { &*self.c_ptr_ref().rendererObject.cast_const().cast::<T>() })
+        }
+
+        /// The mutable texture set with [`set_texture`](`Self::set_texture`), or [`None`] if no
+        /// texture of type `T` is set.
+        #[must_use]
+        pub fn texture_mut<T: Any + Send + Sync>(&mut self) -> Option<&mut T> {
+            self.has_texture::<T>()
+                .then(|| unsafe { &mut *self.c_ptr_mut().rendererObject.cast::<T>() })
+        }
+
+        /// Remove and return the texture set with [`set_texture`](`Self::set_texture`), or
+        /// [`None`] if no texture of type `T` is set.
+        pub fn take_texture<T: Any + Send + Sync>(&mut self) -> Option<T> {
+            if !self.has_texture::<T>() {
+                return None;
+            }
+            lock(&PAGE_TEXTURES).remove(&self.c_ptr().addr());
+            let texture =
+                unsafe { core::mem::replace(&mut self.c_ptr_mut().rendererObject, null_mut()) };
+            Some(*unsafe { Box::from_raw(texture.cast::<T>()) })
+        }
+
+        /// Defer the texture of this page, so it can be loaded asynchronously, such as by fetching
+        /// the image on `wasm32`. Pass the returned token and the loaded texture to
+        /// [`resolve_texture`](`crate::extension::resolve_texture`) to complete the page, which
+        /// sets the texture as if with [`set_texture`](`Self::set_texture`). Until then, the
+        /// page has no texture and renderables using it have no `attachment_renderer_object`.
+        /// Any texture already set on the page is dropped.
+        ///
+        /// Deferring the texture again, setting it, or disposing the page cancels the pending
+        /// texture.
+        ///
+        /// ```
+        /// use std::sync::{Arc, Mutex};
+        ///
+        /// use rusty_spine::extension::{self, PendingTexture};
+        ///
+        /// struct SpineTexture(pub String);
+        ///
+        /// let requests: Arc<Mutex<Vec<(PendingTexture, String)>>> = Arc::default();
+        /// let fetch = requests.clone();
+        /// extension::set_create_texture_cb(move |atlas_page, path| {
+        ///     // start fetching the image at `path`
+        ///     fetch.lock().unwrap().push((atlas_page.defer_texture(), path.to_owned()));
+        /// });
+        ///
+        /// // ... later, once an image has loaded
+        /// # let path = "assets/spineboy/export/spineboy.atlas";
+        /// # let mut atlas = rusty_spine::Atlas::new_from_file(path)?;
+        /// let (pending_texture, path) = requests.lock().unwrap().remove(0);
+        /// let texture = SpineTexture(path);
+        /// if extension::resolve_texture(&mut atlas, pending_texture, texture).is_err() {
+        ///     // the page is gone, release the texture
+        /// }
+        /// # assert!(atlas.pages().next().unwrap().texture::<SpineTexture>().is_some());
+        /// # Ok::<(), rusty_spine::SpineError>(())
+        /// ```
+        #[must_use]
+        pub fn defer_texture(&mut self) -> PendingTexture {
+            let c_atlas_page = self.c_ptr();
+            dispose_page_texture(c_atlas_page);
+            let mut pending_textures = lock(&PENDING_TEXTURES);
+            let id = pending_textures.next_id;
+            pending_textures.next_id += 1;
+            pending_textures.pages.insert(
+                id,
+                PendingPage {
+                    page: c_atlas_page.addr(),
+                    atlas: unsafe { self.c_ptr_ref().atlas }.addr(),
+                },
+            );
+            PendingTexture::new(id)
+        }
+
+        /// If `true`, the texture was deferred with [`defer_texture`](`Self::defer_texture`) and
+        /// has not been resolved yet.
+        #[must_use]
+        pub fn texture_pending(&self) -> bool {
+            lock(&PENDING_TEXTURES)
+                .pages
+                .values()
+                .any(|pending_page| pending_page.page == self.c_ptr().addr())
+        }
+
+        /// Convert the page's image, as RGBA8 pixels loaded from its file, to or from
+        /// premultiplied alpha as needed by the renderer. The image is assumed to have
+        /// premultiplied alpha if [`pma`](`Self::pma`) is set, as exported by the Spine editor.
+        ///
+        /// ```
+        /// # #[path="./test.rs"]
+        /// # mod test;
+        /// # let atlas = test::TestAsset::spineboy().atlas();
+        /// # let page = atlas.pages().next().unwrap();
+        /// let mut rgba8 = vec![255, 128, 0, 128];
+        /// // the renderer blends with premultiplied alpha
+        /// page.convert_alpha(&mut rgba8, true);
+        /// # assert_eq!(rgba8, [128, 64, 0, 128]);
+        /// ```
+        ///
+        /// See [`premultiply_alpha`] and [`unpremultiply_alpha`].
+        pub fn convert_alpha(&self, rgba8: &mut [u8], premultiplied_alpha: bool) {
+            match (self.pma(), premultiplied_alpha) {
+                (false, true) => premultiply_alpha(rgba8),
+                (true, false) => unpremultiply_alpha(rgba8),
+                _ => {}
+            }
+        }
+
+        fn has_texture<T: Any>(&self) -> bool {
+            lock(&PAGE_TEXTURES)
+                .get(&self.c_ptr().addr())
+                .is_some_and(|page_texture| page_texture.type_id == TypeId::of::<T>())
+        }
+
         c_ptr!(c_atlas_page, spAtlasPage);
     }
 
@@ -250,6 +560,64 @@ pub mod atlas {
         }
     }
 
+    /// Multiply the color of each RGBA8 pixel by its alpha.
+    ///
+    /// Textures with premultiplied alpha avoid dark fringes around transparent edges when
+    /// filtered, and must be drawn with premultiplied alpha blending, see
+    /// [`BlendMode::blend_factors`](`crate::BlendMode::blend_factors`).
+    ///
+    /// ```
+    /// let mut rgba8 = vec![255, 128, 0, 128, 255, 255, 255, 0];
+    /// rusty_spine::atlas::premultiply_alpha(&mut rgba8);
+    /// assert_eq!(rgba8, [128, 64, 0, 128, 0, 0, 0, 0]);
+    /// ```
+    pub fn premultiply_alpha(rgba8: &mut [u8]) {
+        for pixel in rgba8.chunks_exact_mut(4) {
+            let alpha = u16::from(pixel[3]);
+            for channel in &mut pixel[0..3] {
+                *channel = ((u16::from(*channel) * alpha + 127) / 255) as u8;
+            }
+        }
+    }
+
+    /// Divide the color of each RGBA8 pixel by its alpha, reversing [`premultiply_alpha`].
+    /// The color of fully transparent pixels can't be recovered and becomes black.
+    pub fn unpremultiply_alpha(rgba8: &mut [u8]) {
+        for pixel in rgba8.chunks_exact_mut(4) {
+            let alpha = u16::from(pixel[3]);
+            for channel in &mut pixel[0..3] {
+                *channel = (u16::from(*channel) * 255 + alpha / 2)
+                    .checked_div(alpha)
+                    .map_or(0, |channel| channel.min(255) as u8);
+            }
+        }
+    }
+
+    /// Whether RGBA8 pixels could have premultiplied alpha, which is the case when no color
+    /// channel is greater than the pixel's alpha.
+    ///
+    /// This can detect a texture exported without premultiplied alpha which is about to be drawn
+    /// as if it had it, causing bright fringes around transparent edges. An image without
+    /// transparency, or without color in transparent areas, can't be told apart and returns
+    /// `true`.
+    ///
+    /// ```
+    /// # #[path="./test.rs"]
+    /// # mod test;
+    /// # let atlas = test::TestAsset::spineboy().atlas();
+    /// # let page = atlas.pages().next().unwrap();
+    /// # let rgba8 = vec![];
+    /// if page.pma() && !rusty_spine::atlas::could_be_premultiplied_alpha(&rgba8) {
+    ///     println!("{} was not exported with premultiplied alpha", page.name());
+    /// }
+    /// ```
+    #[must_use]
+    pub fn could_be_premultiplied_alpha(rgba8: &[u8]) -> bool {
+        rgba8
+            .chunks_exact(4)
+            .all(|pixel| pixel[0..3].iter().all(|channel| *channel <= pixel[3]))
+    }
+
     /// An iterator over each [`AtlasPage`] in an [`Atlas`].
     pub struct AtlasPageIterator<'a> {
         pub(crate) _atlas: &'a Atlas,
@@ -437,7 +805,17 @@ pub mod atlas {
 
 #[cfg(test)]
 mod tests {
-    use crate::test::TestAsset;
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    };
+
+    use crate::{
+        atlas::{could_be_premultiplied_alpha, premultiply_alpha, unpremultiply_alpha, AtlasPage},
+        c_interface::NewFromPtr,
+        extension::{resolve_texture, TextureCallbacks},
+        test::TestAsset,
+    };
 
     use super::Atlas;
 
@@ -451,4 +829,132 @@ mod tests {
         let atlas = Atlas::new_from_file(format!("missing/{}", TestAsset::spineboy().atlas_file));
         assert!(atlas.is_err());
     }
+
+    #[test]
+    fn page_texture() {
+        struct Texture(Arc<AtomicUsize>);
+        impl Drop for Texture {
+            fn drop(&mut self) {
+                self.0.fetch_add(1, Ordering::Relaxed);
+            }
+        }
+
+        let drops = Arc::new(AtomicUsize::new(0));
+        let atlas = TestAsset::all()[3].atlas();
+        for page in atlas.pages() {
+            let mut page = unsafe { AtlasPage::new_from_ptr(page.c_ptr()) };
+            page.set_texture(Texture(drops.clone()));
+        }
+        let pages_count = atlas.pages().count();
+        let first_page = atlas.pages().next().unwrap();
+        assert!(first_page.texture::<Texture>().is_some());
+        assert!(first_page.texture::<u32>().is_none());
+        assert!(!unsafe { first_page.c_ptr_ref().rendererObject }.is_null());
+        let mut first_page = unsafe { AtlasPage::new_from_ptr(first_page.c_ptr()) };
+        first_page.set_texture(5_u32);
+        assert_eq!(drops.load(Ordering::Relaxed), 1);
+        assert_eq!(first_page.take_texture::<u32>(), Some(5));
+        assert!(first_page.texture::<u32>().is_none());
+
+        drop(atlas);
+        assert_eq!(drops.load(Ordering::Relaxed), pages_count);
+    }
+
+    #[test]
+    fn texture_callbacks() {
+        let creates = Arc::new(AtomicUsize::new(0));
+        let disposes = Arc::new(AtomicUsize::new(0));
+        let callbacks = TextureCallbacks::new()
+            .create_texture_cb({
+                let creates = creates.clone();
+                move |atlas_page, _| {
+                    atlas_page.set_texture(creates.fetch_add(1, Ordering::Relaxed));
+                }
+            })
+            .dispose_texture_cb({
+                let disposes = disposes.clone();
+                move |atlas_page| {
+                    assert!(atlas_page.take_texture::<usize>().is_some());
+                    disposes.fetch_add(1, Ordering::Relaxed);
+                }
+            });
+        let atlas = Atlas::new_from_file_with_callbacks(TestAsset::all()[3].atlas_file, &callbacks)
+            .unwrap();
+        drop(callbacks);
+        let pages_count = atlas.pages().count();
+        assert!(pages_count > 1);
+        assert_eq!(creates.load(Ordering::Relaxed), pages_count);
+        assert!(unsafe { atlas.c_ptr_ref().rendererObject }.is_null());
+        assert_eq!(atlas.pages().nth(1).unwrap().texture::<usize>(), Some(&1));
+
+        let other_atlas = TestAsset::spineboy().atlas();
+        assert!(other_atlas
+            .pages()
+            .next()
+            .unwrap()
+            .texture::<usize>()
+            .is_none());
+        drop(other_atlas);
+        assert_eq!(disposes.load(Ordering::Relaxed), 0);
+        drop(atlas);
+        assert_eq!(disposes.load(Ordering::Relaxed), pages_count);
+    }
+
+    #[test]
+    fn defer_texture() {
+        let mut atlas = TestAsset::spineboy().atlas();
+        let mut page = unsafe { AtlasPage::new_from_ptr(atlas.pages().next().unwrap().c_ptr()) };
+        let stale = page.defer_texture();
+        let pending_texture = page.defer_texture();
+        assert_ne!(stale, pending_texture);
+        assert!(page.texture_pending());
+        assert_eq!(resolve_texture(&mut atlas, stale, 1_u32), Err(1));
+        assert!(resolve_texture(&mut atlas, pending_texture, 2_u32).is_ok());
+        assert!(!page.texture_pending());
+        assert_eq!(page.texture::<u32>(), Some(&2));
+
+        let pending_texture = page.defer_texture();
+        assert!(page.texture::<u32>().is_none());
+        let mut other_atlas = TestAsset::spineboy().atlas();
+        assert_eq!(
+            resolve_texture(&mut other_atlas, pending_texture, 3_u32),
+            Err(3)
+        );
+        assert!(page.texture_pending());
+
+        let pending_texture = page.defer_texture();
+        drop(atlas);
+        assert_eq!(
+            resolve_texture(&mut other_atlas, pending_texture, 4_u32),
+            Err(4)
+        );
+    }
+
+    #[test]
+    fn premultiplied_alpha() {
+        let load = |path: &str| image::open(path).unwrap().to_rgba8().into_raw();
+        let straight = load("assets/spineboy/export/spineboy.png");
+        let premultiplied = load("assets/spineboy/export/spineboy-pma.png");
+        assert!(!could_be_premultiplied_alpha(&straight));
+        assert!(could_be_premultiplied_alpha(&premultiplied));
+
+        let mut converted = straight.clone();
+        premultiply_alpha(&mut converted);
+        assert!(could_be_premultiplied_alpha(&converted));
+        unpremultiply_alpha(&mut converted);
+        for (pixel, converted_pixel) in straight.chunks_exact(4).zip(converted.chunks_exact(4)) {
+            if pixel[3] == 255 {
+                assert_eq!(pixel, converted_pixel);
+            }
+        }
+
+        let atlas = TestAsset::spineboy().atlas();
+        let page = atlas.pages().next().unwrap();
+        assert!(!page.pma());
+        let mut pixels = straight.clone();
+        page.convert_alpha(&mut pixels, false);
+        assert_eq!(pixels, straight);
+        page.convert_alpha(&mut pixels, true);
+        assert!(could_be_premultiplied_alpha(&pixels));
+    }
 }
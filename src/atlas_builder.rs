@@ -0,0 +1,377 @@
+use core::fmt::Write;
+#[cfg(feature = "std")]
+use std::path::Path;
+
+use crate::{
+    atlas::{AtlasFilter, AtlasFormat},
+    error::SpineError,
+    prelude::*,
+    Atlas,
+};
+
+const FORMAT_NAMES: [&str; 8] = [
+    "",
+    "Alpha",
+    "Intensity",
+    "LuminanceAlpha",
+    "RGB565",
+    "RGBA4444",
+    "RGB888",
+    "RGBA8888",
+];
+
+const FILTER_NAMES: [&str; 8] = [
+    "",
+    "Nearest",
+    "Linear",
+    "MipMap",
+    "MipMapNearestNearest",
+    "MipMapLinearNearest",
+    "MipMapNearestLinear",
+    "MipMapLinearLinear",
+];
+
+/// A region on an [`AtlasPageBuilder`], in pixels from the top left of the page's texture.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AtlasRegionBuilder {
+    name: String,
+    x: i32,
+    y: i32,
+    width: i32,
+    height: i32,
+    rotate: bool,
+    offsets: Option<[i32; 4]>,
+    index: i32,
+}
+
+impl AtlasRegionBuilder {
+    /// A region of `width` by `height` pixels at `x`, `y` on the page. Attachments find the region
+    /// by `name`.
+    #[must_use]
+    pub fn new(name: &str, x: i32, y: i32, width: i32, height: i32) -> Self {
+        Self {
+            name: name.to_owned(),
+            x,
+            y,
+            width,
+            height,
+            rotate: false,
+            offsets: None,
+            index: -1,
+        }
+    }
+
+    /// Whether the image was rotated 90 degrees counter-clockwise when packed. The region's
+    /// `width` and `height` are always those of the unrotated image, so a rotated region covers
+    /// `height` by `width` pixels of the texture.
+    #[must_use]
+    pub fn with_rotate(self, rotate: bool) -> Self {
+        Self { rotate, ..self }
+    }
+
+    /// For images which had whitespace trimmed when packed, the amount trimmed from the left and
+    /// bottom, and the size of the original image.
+    #[must_use]
+    pub fn with_offsets(
+        self,
+        offset_x: i32,
+        offset_y: i32,
+        original_width: i32,
+        original_height: i32,
+    ) -> Self {
+        Self {
+            offsets: Some([offset_x, offset_y, original_width, original_height]),
+            ..self
+        }
+    }
+
+    /// The frame number of the region, for images named with a frame number such as
+    /// `run_01.png`. Defaults to `-1` for regions which aren't part of a sequence.
+    #[must_use]
+    pub fn with_index(self, index: i32) -> Self {
+        Self { index, ..self }
+    }
+}
+
+/// A texture page of an [`AtlasBuilder`], loaded from the image `name` relative to the atlas
+/// directory.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AtlasPageBuilder {
+    name: String,
+    width: i32,
+    height: i32,
+    format: AtlasFormat,
+    min_filter: AtlasFilter,
+    mag_filter: AtlasFilter,
+    repeat_x: bool,
+    repeat_y: bool,
+    pma: bool,
+    regions: Vec<AtlasRegionBuilder>,
+}
+
+impl AtlasPageBuilder {
+    /// A page for the image `name` of `width` by `height` pixels, using RGBA8888 and linear
+    /// filtering.
+    #[must_use]
+    pub fn new(name: &str, width: i32, height: i32) -> Self {
+        Self {
+            name: name.to_owned(),
+            width,
+            height,
+            format: AtlasFormat::RGBA8888,
+            min_filter: AtlasFilter::Linear,
+            mag_filter: AtlasFilter::Linear,
+            repeat_x: false,
+            repeat_y: false,
+            pma: false,
+            regions: vec![],
+        }
+    }
+
+    /// The memory format to use for the texture.
+    #[must_use]
+    pub fn with_format(self, format: AtlasFormat) -> Self {
+        Self { format, ..self }
+    }
+
+    /// The texture's minification and magnification filters.
+    #[must_use]
+    pub fn with_filter(self, min_filter: AtlasFilter, mag_filter: AtlasFilter) -> Self {
+        Self {
+            min_filter,
+            mag_filter,
+            ..self
+        }
+    }
+
+    /// Whether the texture repeats on the X and Y axes, rather than clamping to the edge.
+    #[must_use]
+    pub fn with_repeat(self, repeat_x: bool, repeat_y: bool) -> Self {
+        Self {
+            repeat_x,
+            repeat_y,
+            ..self
+        }
+    }
+
+    /// Whether the texture's colors use premultiplied alpha.
+    #[must_use]
+    pub fn with_pma(self, pma: bool) -> Self {
+        Self { pma, ..self }
+    }
+
+    /// Add a region to the page.
+    #[must_use]
+    pub fn region(mut self, region: AtlasRegionBuilder) -> Self {
+        self.regions.push(region);
+        self
+    }
+}
+
+/// Creates an [`Atlas`] from region definitions rather than a `.atlas` file, for engines which do
+/// their own texture packing and only need Spine for animation.
+///
+/// ```
+/// use rusty_spine::{AtlasBuilder, AtlasPageBuilder, AtlasRegionBuilder};
+///
+/// let atlas = AtlasBuilder::new()
+///     .page(
+///         AtlasPageBuilder::new("characters.png", 512, 256)
+///             .region(AtlasRegionBuilder::new("head", 0, 0, 64, 64))
+///             .region(AtlasRegionBuilder::new("torso", 64, 0, 80, 120).with_rotate(true)),
+///     )
+///     .build("assets")?;
+/// assert_eq!(atlas.regions().count(), 2);
+/// # Ok::<(), rusty_spine::SpineError>(())
+/// ```
+///
+/// Textures are loaded for each page the same way as for [`Atlas::new`], through the callback
+/// set with [`extension::set_create_texture_cb`](`crate::extension::set_create_texture_cb`), with
+/// the path of the page's image relative to the directory passed to [`build`](`Self::build`).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AtlasBuilder {
+    pages: Vec<AtlasPageBuilder>,
+}
+
+impl AtlasBuilder {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a texture page and its regions.
+    #[must_use]
+    pub fn page(mut self, page: AtlasPageBuilder) -> Self {
+        self.pages.push(page);
+        self
+    }
+
+    /// Create the atlas, with page image paths relative to `dir`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SpineError::CreationFailed`] if a page or region name is empty, has leading or
+    /// trailing whitespace, or contains a line break or `:`, or if a page has no pixels. Returns
+    /// [`SpineError::NulError`] if a name or `dir` contain an internal 0 byte, or
+    /// [`SpineError::PathNotUtf8`] if `dir` is not utf-8.
+    #[cfg(feature = "std")]
+    pub fn build<P: AsRef<Path>>(&self, dir: P) -> Result<Atlas, SpineError> {
+        Atlas::new(self.to_atlas()?.as_bytes(), dir)
+    }
+
+    /// Create the atlas, with page image paths relative to `dir`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SpineError::CreationFailed`] if a page or region name is empty, has leading or
+    /// trailing whitespace, or contains a line break or `:`, or if a page has no pixels. Returns
+    /// [`SpineError::NulError`] if a name or `dir` contain an internal 0 byte.
+    #[cfg(not(feature = "std"))]
+    pub fn build<P: AsRef<str>>(&self, dir: P) -> Result<Atlas, SpineError> {
+        Atlas::new(self.to_atlas()?.as_bytes(), dir)
+    }
+
+    fn to_atlas(&self) -> Result<String, SpineError> {
+        let mut atlas = String::new();
+        for page in &self.pages {
+            check_name("atlas page", &page.name)?;
+            if page.width <= 0 || page.height <= 0 {
+                return Err(SpineError::new_creation_failed(&format!(
+                    "atlas page {} with a size of {}x{}",
+                    page.name, page.width, page.height
+                )));
+            }
+            let _ = writeln!(atlas, "\n{}", page.name);
+            let _ = writeln!(atlas, "size: {},{}", page.width, page.height);
+            let _ = writeln!(atlas, "format: {}", FORMAT_NAMES[page.format as usize]);
+            let _ = writeln!(
+                atlas,
+                "filter: {},{}",
+                FILTER_NAMES[page.min_filter as usize], FILTER_NAMES[page.mag_filter as usize]
+            );
+            let repeat = match (page.repeat_x, page.repeat_y) {
+                (true, true) => "xy",
+                (true, false) => "x",
+                (false, true) => "y",
+                (false, false) => "none",
+            };
+            let _ = writeln!(atlas, "repeat: {repeat}");
+            let _ = writeln!(atlas, "pma: {}", page.pma);
+            for region in &page.regions {
+                check_name("atlas region", &region.name)?;
+                let _ = writeln!(atlas, "{}", region.name);
+                let _ = writeln!(
+                    atlas,
+                    "bounds: {},{},{},{}",
+                    region.x, region.y, region.width, region.height
+                );
+                if let Some([offset_x, offset_y, original_width, original_height]) = region.offsets
+                {
+                    let _ = writeln!(
+                        atlas,
+                        "offsets: {offset_x},{offset_y},{original_width},{original_height}"
+                    );
+                }
+                if region.rotate {
+                    let _ = writeln!(atlas, "rotate: 90");
+                }
+                if region.index != -1 {
+                    let _ = writeln!(atlas, "index: {}", region.index);
+                }
+            }
+        }
+        Ok(atlas)
+    }
+}
+
+/// Names are written one per line and trimmed by the atlas parser, which treats lines with a `:`
+/// as key-value pairs.
+fn check_name(what: &str, name: &str) -> Result<(), SpineError> {
+    if name.is_empty() || name.trim() != name || name.contains(['\n', '\r', ':']) {
+        Err(SpineError::new_creation_failed(&format!(
+            "{what} named {name:?}"
+        )))
+    } else {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::sync::Arc;
+
+    use crate::{
+        BoneBuilder, RegionAttachmentBuilder, SkeletonDataBuilder, SlotBuilder, SpineError,
+    };
+
+    use super::*;
+
+    #[test]
+    fn build_atlas() {
+        let atlas = AtlasBuilder::new()
+            .page(
+                AtlasPageBuilder::new("first.png", 256, 128)
+                    .with_pma(true)
+                    .region(AtlasRegionBuilder::new("square", 0, 0, 64, 64))
+                    .region(AtlasRegionBuilder::new("tall", 64, 0, 32, 96).with_rotate(true)),
+            )
+            .page(
+                AtlasPageBuilder::new("second.png", 64, 64)
+                    .with_filter(AtlasFilter::Nearest, AtlasFilter::MipmapLinearLinear)
+                    .region(
+                        AtlasRegionBuilder::new("trimmed", 0, 0, 32, 32)
+                            .with_offsets(4, 8, 40, 48)
+                            .with_index(3),
+                    ),
+            )
+            .build("")
+            .unwrap();
+        assert_eq!(atlas.pages().count(), 2);
+        let first = atlas.find_page("first.png").unwrap();
+        assert_eq!((first.width(), first.height()), (256, 128));
+        assert!(first.pma());
+        let second = atlas.find_page("second.png").unwrap();
+        assert_eq!(second.min_filter(), AtlasFilter::Nearest);
+        assert_eq!(second.mag_filter(), AtlasFilter::MipmapLinearLinear);
+
+        let tall = atlas.find_region("tall").unwrap();
+        assert_eq!(tall.page().name(), "first.png");
+        let texture_region = tall.texture_region();
+        assert_eq!(texture_region.degrees(), 90);
+        assert_eq!((texture_region.width(), texture_region.height()), (32, 96));
+        assert_eq!(texture_region.u(), 0.25);
+        assert_eq!(texture_region.u2(), 0.625);
+        assert_eq!(texture_region.v2(), 0.25);
+
+        let trimmed = atlas.find_region("trimmed").unwrap();
+        assert_eq!(trimmed.page().name(), "second.png");
+        assert_eq!(trimmed.index(), 3);
+        let texture_region = trimmed.texture_region();
+        assert_eq!(texture_region.offset_x(), 4.);
+        assert_eq!(texture_region.original_height(), 48);
+
+        let skeleton_data = SkeletonDataBuilder::new()
+            .bone(BoneBuilder::new("root"))
+            .slot(SlotBuilder::new("square", "root").with_attachment("square"))
+            .region_attachment(RegionAttachmentBuilder::new("square", "square"))
+            .build(Arc::new(atlas));
+        assert!(skeleton_data.is_ok());
+    }
+
+    #[test]
+    fn invalid_names() {
+        for name in ["", " padded", "key: value", "two\nlines"] {
+            let atlas = AtlasBuilder::new()
+                .page(
+                    AtlasPageBuilder::new("page.png", 16, 16)
+                        .region(AtlasRegionBuilder::new(name, 0, 0, 16, 16)),
+                )
+                .build("");
+            assert!(matches!(atlas, Err(SpineError::CreationFailed { .. })));
+        }
+        let atlas = AtlasBuilder::new()
+            .page(AtlasPageBuilder::new("empty.png", 0, 16))
+            .build("");
+        assert!(matches!(atlas, Err(SpineError::CreationFailed { .. })));
+    }
+}
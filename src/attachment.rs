@@ -1,14 +1,23 @@
+#[cfg(feature = "draw_functions")]
+use crate::prelude::*;
 use crate::{
+    atlas::AtlasPage,
     bounding_box_attachment::BoundingBoxAttachment,
     c::{
-        spAttachment, spAttachmentType, spAttachment_dispose, spBoundingBoxAttachment,
-        spClippingAttachment, spMeshAttachment, spPointAttachment, spRegionAttachment,
+        spAtlasPage, spAtlasRegion, spAttachment, spAttachmentType, spAttachment_dispose,
+        spBoundingBoxAttachment, spClippingAttachment, spMeshAttachment, spPathAttachment,
+        spPointAttachment, spRegionAttachment, spSequence, spTextureRegion, spVertexAttachment,
+        spVertexAttachment_computeWorldVertices,
     },
-    c_interface::{NewFromPtr, SyncPtr},
+    c_interface::{CTmpRef, NewFromPtr, SyncPtr},
     clipping_attachment::ClippingAttachment,
+    error::SpineError,
     mesh_attachment::MeshAttachment,
+    path_attachment::PathAttachment,
     point_attachment::PointAttachment,
     region_attachment::RegionAttachment,
+    slot::Slot,
+    texture_region::TextureRegion,
 };
 
 /// Slot attachments.
@@ -73,6 +82,18 @@ impl Attachment {
         }
     }
 
+    /// Get this attachment as a [`PathAttachment`], or [`None`] if it's a different type.
+    #[must_use]
+    pub fn as_path(&self) -> Option<PathAttachment> {
+        if self.attachment_type() == AttachmentType::Path {
+            Some(unsafe {
+                PathAttachment::new_from_ptr(self.c_attachment.0.cast::<spPathAttachment>())
+            })
+        } else {
+            None
+        }
+    }
+
     /// Get this attachment as a [`PointAttachment`], or [`None`] if it's a different type.
     #[must_use]
     pub fn as_point(&self) -> Option<PointAttachment> {
@@ -97,6 +118,92 @@ impl Attachment {
         }
     }
 
+    /// The texture region drawn by a region or mesh attachment, with the atlas page it is on, or
+    /// [`None`] for other attachment types. For attachments with a
+    /// [`Sequence`](http://esotericsoftware.com/spine-sequences), this is the region of the
+    /// frame drawn last, or of the setup frame if the attachment hasn't been drawn.
+    ///
+    /// ```
+    /// # #[path="./test.rs"]
+    /// # mod test;
+    /// # let (skeleton, _) = test::TestAsset::spineboy().instance(true);
+    /// for slot in skeleton.draw_order() {
+    ///     let Some(attachment) = slot.attachment() else {
+    ///         continue;
+    ///     };
+    ///     if let Some(region) = attachment.texture_region() {
+    ///         // bind the texture for `region.page_index` or `region.page.renderer_object()`
+    ///         let [u, v, u2, v2] = region.uvs;
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// Attachments loaded with an [`Atlas`](`crate::Atlas`) always have a page. The region and
+    /// page are owned by the atlas, so they must not be used after the atlas is dropped.
+    #[must_use]
+    pub fn texture_region(&self) -> Option<AttachmentTextureRegion<'_>> {
+        let (mut c_region, c_sequence) = self.c_region_and_sequence()?;
+        // Sequences only set the region once drawn, so fall back to the setup frame
+        if c_region.is_null() && !c_sequence.is_null() {
+            unsafe {
+                let c_regions = (*c_sequence).regions;
+                let setup_index = (*c_sequence).setupIndex;
+                if !c_regions.is_null() && (0..(*c_regions).size).contains(&setup_index) {
+                    c_region = *(*c_regions).items.add(setup_index as usize);
+                }
+            }
+        }
+        let (c_page, page_index) = region_page(c_region)?;
+        let region = CTmpRef::new(self, unsafe { TextureRegion::new_from_ptr(c_region) });
+        Some(AttachmentTextureRegion {
+            page_index,
+            page: CTmpRef::new(self, unsafe { AtlasPage::new_from_ptr(c_page) }),
+            uvs: [region.u(), region.v(), region.u2(), region.v2()],
+            degrees: region.degrees(),
+            region,
+        })
+    }
+
+    fn c_region_and_sequence(&self) -> Option<(*mut spTextureRegion, *mut spSequence)> {
+        unsafe {
+            match self.attachment_type() {
+                AttachmentType::Region => {
+                    let c_region_attachment = &*self.c_ptr().cast::<spRegionAttachment>();
+                    Some((c_region_attachment.region, c_region_attachment.sequence))
+                }
+                AttachmentType::Mesh | AttachmentType::LinkedMesh => {
+                    let c_mesh_attachment = &*self.c_ptr().cast::<spMeshAttachment>();
+                    Some((c_mesh_attachment.region, c_mesh_attachment.sequence))
+                }
+                _ => None,
+            }
+        }
+    }
+
+    /// The index of every atlas page the attachment may draw from, including every frame of its
+    /// sequence, if any.
+    #[cfg(feature = "draw_functions")]
+    pub(crate) fn page_indices(&self) -> Vec<usize> {
+        let Some((c_region, c_sequence)) = self.c_region_and_sequence() else {
+            return vec![];
+        };
+        let mut c_regions = vec![c_region];
+        unsafe {
+            if let Some(c_sequence_regions) = c_sequence.as_ref().and_then(|c| c.regions.as_ref()) {
+                for index in 0..c_sequence_regions.size.max(0) as usize {
+                    c_regions.push(*c_sequence_regions.items.add(index));
+                }
+            }
+        }
+        let mut page_indices: Vec<usize> = c_regions
+            .into_iter()
+            .filter_map(|c_region| Some(region_page(c_region)?.1))
+            .collect();
+        page_indices.sort_unstable();
+        page_indices.dedup();
+        page_indices
+    }
+
     c_accessor_string!(
         /// The attachment's name.
         name,
@@ -111,6 +218,30 @@ impl Attachment {
     c_ptr!(c_attachment, spAttachment);
 }
 
+/// The atlas page of a texture region, and its index in the atlas.
+fn region_page(c_region: *mut spTextureRegion) -> Option<(*mut spAtlasPage, usize)> {
+    if c_region.is_null() {
+        return None;
+    }
+    // Regions are created from atlas regions, which start with their texture region
+    let c_page = unsafe { (*c_region.cast::<spAtlasRegion>()).page };
+    if c_page.is_null() {
+        return None;
+    }
+    let mut page_index = 0;
+    unsafe {
+        let c_atlas = (*c_page).atlas;
+        if !c_atlas.is_null() {
+            let mut c_other_page = (*c_atlas).pages;
+            while !c_other_page.is_null() && c_other_page != c_page {
+                page_index += 1;
+                c_other_page = (*c_other_page).next;
+            }
+        }
+    }
+    Some((c_page, page_index))
+}
+
 impl Clone for Attachment {
     fn clone(&self) -> Self {
         unsafe { Attachment::new_from_ptr(self.c_ptr()) }
@@ -125,6 +256,21 @@ impl Drop for Attachment {
     }
 }
 
+/// The texture drawn by a region or mesh attachment, see [`Attachment::texture_region`].
+#[derive(Debug)]
+pub struct AttachmentTextureRegion<'a> {
+    /// The index of [`page`](`Self::page`) in [`Atlas::pages`](`crate::Atlas::pages`).
+    pub page_index: usize,
+    /// The atlas page the region is on, whose renderer object usually holds the texture.
+    pub page: CTmpRef<'a, Attachment, AtlasPage>,
+    /// The texture coordinates of the region, as `[u, v, u2, v2]`.
+    pub uvs: [f32; 4],
+    /// The rotation of the region in the atlas page, in degrees.
+    pub degrees: i32,
+    /// The region, for its size and the whitespace trimmed around it.
+    pub region: CTmpRef<'a, Attachment, TextureRegion>,
+}
+
 /// The type variants of an [`Attachment`].
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum AttachmentType {
@@ -152,3 +298,123 @@ impl From<spAttachmentType> for AttachmentType {
         }
     }
 }
+
+/// Checks that the bone weights of a vertex attachment only refer to bones which exist, returning
+/// the reason if they don't. Unweighted attachments are always valid.
+pub(crate) fn check_vertex_bones(
+    vertex_attachment: &spVertexAttachment,
+    bones_count: usize,
+) -> Result<(), &'static str> {
+    let bones = if vertex_attachment.bones.is_null() {
+        &[]
+    } else {
+        unsafe {
+            core::slice::from_raw_parts(
+                vertex_attachment.bones,
+                vertex_attachment.bonesCount as usize,
+            )
+        }
+    };
+    // Each vertex lists its bone count, followed by that many bone indices
+    let mut index = 0;
+    while index < bones.len() {
+        let count = bones[index] as usize;
+        let Some(vertex_bones) = bones.get(index + 1..index + 1 + count) else {
+            return Err("malformed bone weights");
+        };
+        if vertex_bones
+            .iter()
+            .any(|bone_index| *bone_index < 0 || *bone_index as usize >= bones_count)
+        {
+            return Err("weighted to a bone the skeleton does not have");
+        }
+        index += count + 1;
+    }
+    Ok(())
+}
+
+/// The checked implementation of `compute_world_vertices` for vertex attachments.
+pub(crate) fn compute_world_vertices_checked(
+    vertex_attachment: *mut spVertexAttachment,
+    slot: &Slot,
+    start: i32,
+    count: i32,
+    world_vertices: &mut [f32],
+    offset: i32,
+    stride: i32,
+) -> Result<(), SpineError> {
+    let vertex_attachment_ref = unsafe { &*vertex_attachment };
+    let world_vertices_length = vertex_attachment_ref.worldVerticesLength;
+    assert!(
+        start >= 0 && count >= 0 && start % 2 == 0 && count % 2 == 0,
+        "start and count must be positive and even"
+    );
+    assert!(
+        start + count <= world_vertices_length,
+        "start + count exceeds world_vertices_length"
+    );
+    assert!(
+        offset >= 0 && stride >= 0,
+        "offset and stride must be positive"
+    );
+    if count == 0 {
+        return Ok(());
+    }
+    assert!(
+        (offset + (count / 2 - 1) * stride + 2) as usize <= world_vertices.len(),
+        "world_vertices is too small"
+    );
+    let name = unsafe { core::ffi::CStr::from_ptr(vertex_attachment_ref.super_0.name) }
+        .to_str()
+        .unwrap_or_default();
+    let deform_count = slot.deform_count();
+    if deform_count > 0 && deform_count < world_vertices_length as usize {
+        return Err(SpineError::new_incompatible_attachment(
+            name,
+            "the slot's deform buffer is smaller than the attachment",
+        ));
+    }
+    let bones_count = unsafe { (*slot.bone().c_ptr_ref().skeleton).bonesCount } as usize;
+    check_vertex_bones(vertex_attachment_ref, bones_count)
+        .map_err(|reason| SpineError::new_incompatible_attachment(name, reason))?;
+    unsafe {
+        spVertexAttachment_computeWorldVertices(
+            vertex_attachment,
+            slot.c_ptr(),
+            start,
+            count,
+            world_vertices.as_mut_ptr(),
+            offset,
+            stride,
+        );
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::collections::BTreeSet;
+
+    use crate::test::TestAsset;
+
+    #[test]
+    fn texture_region() {
+        let dragon = &TestAsset::all()[3];
+        let atlas = dragon.atlas();
+        let (skeleton, _) = dragon.instance(true);
+        let mut page_indices = BTreeSet::new();
+        for entry in skeleton.data().default_skin().attachments() {
+            let attachment = entry.attachment;
+            let Some(region) = attachment.texture_region() else {
+                assert!(attachment.as_region().is_none() && attachment.as_mesh().is_none());
+                continue;
+            };
+            let page = atlas.pages().nth(region.page_index).unwrap();
+            assert_eq!(page.name(), region.page.name());
+            assert_eq!(region.uvs[0], region.region.u());
+            assert_eq!(region.uvs[3], region.region.v2());
+            page_indices.insert(region.page_index);
+        }
+        assert!(page_indices.len() > 1);
+    }
+}
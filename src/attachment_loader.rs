@@ -49,14 +49,14 @@ impl AttachmentLoader {
         name: &str,
         path: &str,
     ) -> Result<Attachment, SpineError> {
-        let c_name = std::ffi::CString::new(name)?;
-        let c_path = std::ffi::CString::new(path)?;
+        let c_name = alloc::ffi::CString::new(name)?;
+        let c_path = alloc::ffi::CString::new(path)?;
 
         unsafe {
             let c_name = c_name.as_ptr();
             let c_path = c_path.as_ptr();
-            let c_skin = skin.map_or(std::ptr::null_mut(), |skin| skin.c_ptr());
-            let c_sequence = std::ptr::null_mut(); // What is this for?
+            let c_skin = skin.map_or(core::ptr::null_mut(), |skin| skin.c_ptr());
+            let c_sequence = core::ptr::null_mut(); // What is this for?
 
             let attachment = spAttachmentLoader_createAttachment(
                 self.c_ptr(),
@@ -0,0 +1,238 @@
+//! [Criterion](https://docs.rs/criterion) benchmarks for loading, updating, drawing, and clipping
+//! skeletons, available with the `bench` feature.
+//!
+//! The same benchmarks used by this crate can be run on your own skeletons and target hardware,
+//! to compare the cost of configuration options such as the color space and clipping. Add a
+//! bench target to your crate and pass your skeletons to [`bench_all`]:
+//!
+//! ```no_run
+//! use rusty_spine::bench::{
+//!     bench_all,
+//!     criterion::{criterion_group, criterion_main, Criterion},
+//!     BenchSkeleton,
+//! };
+//!
+//! fn benches(c: &mut Criterion) {
+//!     let skeletons = [BenchSkeleton::new(
+//!         "hero",
+//!         include_bytes!("../assets/spineboy/export/spineboy.atlas"),
+//!         "run",
+//!     )
+//!     .with_binary(include_bytes!("../assets/spineboy/export/spineboy-pro.skel"))];
+//!     bench_all(c, &skeletons);
+//! }
+//!
+//! criterion_group!(group, benches);
+//! criterion_main!(group);
+//! ```
+//!
+//! Benchmarks are named by group, variant, and skeleton, such as `draw/combined/hero/Linear`, so
+//! a subset can be run with `cargo bench -- draw/combined`. The runtime has no SIMD code paths, so
+//! there is no SIMD option to compare.
+
+use std::sync::Arc;
+
+pub use criterion;
+use criterion::{BenchmarkId, Criterion};
+
+use crate::{
+    draw::{ColorSpace, CombinedDrawer, CullDirection, SimpleDrawer},
+    AnimationState, AnimationStateData, Atlas, Physics, Skeleton, SkeletonBinary, SkeletonClipping,
+    SkeletonData, SkeletonJson,
+};
+
+/// A skeleton to benchmark, loaded from its exported files.
+#[derive(Debug, Clone, Copy)]
+pub struct BenchSkeleton<'a> {
+    /// The name the skeleton's benchmarks are reported under.
+    pub name: &'a str,
+    /// The `.atlas` file.
+    pub atlas_data: &'a [u8],
+    /// The `.json` file, if any.
+    pub json_data: Option<&'a [u8]>,
+    /// The `.skel` file, if any. Preferred over the JSON file when creating instances.
+    pub binary_data: Option<&'a [u8]>,
+    /// The animation played while updating and drawing.
+    pub animation: &'a str,
+}
+
+impl<'a> BenchSkeleton<'a> {
+    /// A skeleton with no skeleton files, which must be added with
+    /// [`with_json`](`Self::with_json`) or [`with_binary`](`Self::with_binary`).
+    #[must_use]
+    pub const fn new(name: &'a str, atlas_data: &'a [u8], animation: &'a str) -> Self {
+        Self {
+            name,
+            atlas_data,
+            json_data: None,
+            binary_data: None,
+            animation,
+        }
+    }
+
+    #[must_use]
+    pub const fn with_json(self, json_data: &'a [u8]) -> Self {
+        Self {
+            json_data: Some(json_data),
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub const fn with_binary(self, binary_data: &'a [u8]) -> Self {
+        Self {
+            binary_data: Some(binary_data),
+            ..self
+        }
+    }
+
+    fn atlas(&self) -> Arc<Atlas> {
+        Arc::new(Atlas::new(self.atlas_data, "").expect("failed to load atlas"))
+    }
+
+    fn skeleton_data(&self) -> SkeletonData {
+        let atlas = self.atlas();
+        match (self.binary_data, self.json_data) {
+            (Some(binary_data), _) => SkeletonBinary::new(atlas).read_skeleton_data(binary_data),
+            (None, Some(json_data)) => SkeletonJson::new(atlas).read_skeleton_data(json_data),
+            (None, None) => panic!("bench skeleton `{}` has no skeleton file", self.name),
+        }
+        .expect("failed to load skeleton data")
+    }
+
+    /// A skeleton playing the animation, advanced into it so its attachments are visible.
+    fn instance(&self) -> (Skeleton, AnimationState) {
+        let skeleton_data = Arc::new(self.skeleton_data());
+        let animation_state_data = Arc::new(AnimationStateData::new(skeleton_data.clone()));
+        let mut skeleton = Skeleton::new(skeleton_data);
+        let mut animation_state = AnimationState::new(animation_state_data);
+        animation_state
+            .set_animation_by_name(0, self.animation, true)
+            .expect("bench animation not found");
+        update(&mut skeleton, &mut animation_state, 0.5);
+        (skeleton, animation_state)
+    }
+}
+
+fn update(skeleton: &mut Skeleton, animation_state: &mut AnimationState, delta: f32) {
+    animation_state.update(delta);
+    animation_state.apply(skeleton);
+    skeleton.update(delta);
+    skeleton.update_world_transform(Physics::Update);
+}
+
+/// Runs every benchmark in this module.
+///
+/// # Panics
+///
+/// Panics if a skeleton fails to load, has no skeleton file, or doesn't have its animation.
+pub fn bench_all(c: &mut Criterion, skeletons: &[BenchSkeleton]) {
+    bench_load(c, skeletons);
+    bench_update(c, skeletons);
+    bench_draw(c, skeletons);
+    bench_clipping(c, skeletons);
+}
+
+/// Benchmarks loading the atlas and each skeleton file.
+///
+/// # Panics
+///
+/// Panics if a skeleton fails to load.
+pub fn bench_load(c: &mut Criterion, skeletons: &[BenchSkeleton]) {
+    let mut group = c.benchmark_group("load");
+    for skeleton in skeletons {
+        let atlas = skeleton.atlas();
+        group.bench_function(BenchmarkId::new("atlas", skeleton.name), |b| {
+            b.iter(|| Atlas::new(skeleton.atlas_data, "").unwrap());
+        });
+        if let Some(json_data) = skeleton.json_data {
+            let skeleton_json = SkeletonJson::new(atlas.clone());
+            group.bench_function(BenchmarkId::new("json", skeleton.name), |b| {
+                b.iter(|| -> SkeletonData { skeleton_json.read_skeleton_data(json_data).unwrap() });
+            });
+        }
+        if let Some(binary_data) = skeleton.binary_data {
+            let skeleton_binary = SkeletonBinary::new(atlas);
+            group.bench_function(BenchmarkId::new("binary", skeleton.name), |b| {
+                b.iter(|| -> SkeletonData {
+                    skeleton_binary.read_skeleton_data(binary_data).unwrap()
+                });
+            });
+        }
+    }
+    group.finish();
+}
+
+/// Benchmarks applying the animation and updating world transforms for one frame.
+///
+/// # Panics
+///
+/// Panics if a skeleton fails to load, has no skeleton file, or doesn't have its animation.
+pub fn bench_update(c: &mut Criterion, skeletons: &[BenchSkeleton]) {
+    let mut group = c.benchmark_group("update");
+    for skeleton in skeletons {
+        let (mut instance, mut animation_state) = skeleton.instance();
+        group.bench_function(skeleton.name, |b| {
+            b.iter(|| update(&mut instance, &mut animation_state, 1. / 60.));
+        });
+    }
+    group.finish();
+}
+
+/// Benchmarks generating renderables with the [`SimpleDrawer`] and [`CombinedDrawer`] in each
+/// [`ColorSpace`].
+///
+/// # Panics
+///
+/// Panics if a skeleton fails to load, has no skeleton file, or doesn't have its animation.
+pub fn bench_draw(c: &mut Criterion, skeletons: &[BenchSkeleton]) {
+    let mut group = c.benchmark_group("draw");
+    for skeleton in skeletons {
+        let (mut instance, _) = skeleton.instance();
+        for color_space in [ColorSpace::SRGB, ColorSpace::Linear] {
+            let parameter = format!("{}/{color_space:?}", skeleton.name);
+            let simple_drawer = SimpleDrawer {
+                cull_direction: CullDirection::Clockwise,
+                premultiplied_alpha: false,
+                color_space,
+            };
+            group.bench_function(BenchmarkId::new("simple", &parameter), |b| {
+                b.iter(|| simple_drawer.draw(&mut instance, None));
+            });
+            let combined_drawer = CombinedDrawer {
+                cull_direction: CullDirection::Clockwise,
+                premultiplied_alpha: false,
+                color_space,
+            };
+            group.bench_function(BenchmarkId::new("combined", &parameter), |b| {
+                b.iter(|| combined_drawer.draw(&mut instance, None));
+            });
+        }
+    }
+    group.finish();
+}
+
+/// Benchmarks generating combined renderables with and without clipping.
+///
+/// # Panics
+///
+/// Panics if a skeleton fails to load, has no skeleton file, or doesn't have its animation.
+pub fn bench_clipping(c: &mut Criterion, skeletons: &[BenchSkeleton]) {
+    let mut group = c.benchmark_group("clipping");
+    for skeleton in skeletons {
+        let (mut instance, _) = skeleton.instance();
+        let drawer = CombinedDrawer {
+            cull_direction: CullDirection::Clockwise,
+            premultiplied_alpha: false,
+            color_space: ColorSpace::SRGB,
+        };
+        group.bench_function(BenchmarkId::new("disabled", skeleton.name), |b| {
+            b.iter(|| drawer.draw(&mut instance, None));
+        });
+        let mut clipper = SkeletonClipping::new();
+        group.bench_function(BenchmarkId::new("enabled", skeleton.name), |b| {
+            b.iter(|| drawer.draw(&mut instance, Some(&mut clipper)));
+        });
+    }
+    group.finish();
+}
@@ -0,0 +1,485 @@
+//! [Bevy](https://bevyengine.org) asset loaders and mesh conversion, available with the `bevy`
+//! feature.
+//!
+//! Add [`SpinePlugin`] to load `.atlas` files as [`SpineAtlas`] assets, and `.json` and `.skel`
+//! files as [`SpineSkeletonData`] assets.
+//!
+//! ```no_run
+//! use bevy::prelude::*;
+//! use rusty_spine::bevy::{SpinePlugin, SpineSkeletonData};
+//!
+//! fn load(asset_server: Res<AssetServer>) {
+//!     let skeleton: Handle<SpineSkeletonData> = asset_server.load("spineboy/spineboy-pro.json");
+//! }
+//!
+//! App::new()
+//!     .add_plugins((MinimalPlugins, AssetPlugin::default(), SpinePlugin))
+//!     .add_systems(Startup, load)
+//!     .run();
+//! ```
+//!
+//! By default, skeleton files are loaded with the atlas next to them which has the same name, as
+//! exported by the Spine editor. For example, `spineboy-pro.json` is loaded with
+//! `spineboy-pro.atlas`. A different atlas can be chosen with [`SpineSkeletonLoaderSettings`].
+//! The atlas is loaded as a labeled asset, `spineboy-pro.json#atlas`.
+//!
+//! Renderables from a [`SkeletonController`] can be converted to a [`Mesh`] with
+//! [`From`], and their texture found with [`SpineAtlas::image`].
+//!
+//! [`SkeletonController`]: `crate::controller::SkeletonController`
+
+use std::{error::Error, fmt, io, path::Path, sync::Arc};
+
+use ::bevy::{
+    app::{App, Plugin},
+    asset::{
+        io::Reader, Asset, AssetApp, AssetLoader, AsyncReadExt, Handle, LoadContext,
+        LoadDirectError,
+    },
+    reflect::TypePath,
+    render::{
+        mesh::{Indices, Mesh, MeshVertexAttribute, PrimitiveTopology},
+        render_asset::RenderAssetUsages,
+        render_resource::VertexFormat,
+        texture::Image,
+    },
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    atlas::AtlasPage,
+    c::c_void,
+    c_interface::NewFromPtr,
+    controller::{SkeletonCombinedRenderable, SkeletonController, SkeletonRenderable},
+    draw::IndexFormat,
+    AnimationStateData, Atlas, SkeletonBinary, SkeletonData, SkeletonJson, SpineError,
+};
+
+/// Registers the Spine assets and their loaders.
+pub struct SpinePlugin;
+
+impl Plugin for SpinePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_asset::<SpineAtlas>()
+            .init_asset::<SpineSkeletonData>()
+            .register_asset_loader(SpineAtlasLoader)
+            .register_asset_loader(SpineSkeletonJsonLoader)
+            .register_asset_loader(SpineSkeletonBinaryLoader);
+    }
+}
+
+/// An [`Atlas`] loaded from an `.atlas` file, with the image of each page.
+#[derive(Asset, TypePath, Debug)]
+pub struct SpineAtlas {
+    pub atlas: Arc<Atlas>,
+    /// The image of each page, in the order of [`Atlas::pages`].
+    #[dependency]
+    pub pages: Vec<Handle<Image>>,
+}
+
+impl SpineAtlas {
+    /// The image of the page a renderable was drawn from, using its
+    /// `attachment_renderer_object`. Returns [`None`] if the renderable was not drawn from this
+    /// atlas.
+    #[must_use]
+    pub fn image(
+        &self,
+        attachment_renderer_object: Option<*const c_void>,
+    ) -> Option<&Handle<Image>> {
+        let attachment_renderer_object = attachment_renderer_object?;
+        self.atlas
+            .pages()
+            .zip(&self.pages)
+            .find(|(page, _)| {
+                unsafe { page.c_ptr_ref().rendererObject }.cast_const()
+                    == attachment_renderer_object
+            })
+            .map(|(_, image)| image)
+    }
+}
+
+/// A [`SkeletonData`] loaded from a `.json` or `.skel` file, with its atlas.
+#[derive(Asset, TypePath, Debug)]
+pub struct SpineSkeletonData {
+    pub skeleton_data: Arc<SkeletonData>,
+    pub animation_state_data: Arc<AnimationStateData>,
+    #[dependency]
+    pub atlas: Handle<SpineAtlas>,
+}
+
+impl SpineSkeletonData {
+    /// Create a new controller for this skeleton, see [`SkeletonController::new`].
+    #[must_use]
+    pub fn controller(&self) -> SkeletonController {
+        SkeletonController::new(
+            self.skeleton_data.clone(),
+            self.animation_state_data.clone(),
+        )
+    }
+}
+
+/// An error loading a Spine asset.
+#[derive(Debug)]
+pub enum SpineLoadError {
+    Io(io::Error),
+    Spine(SpineError),
+    Atlas(Box<LoadDirectError>),
+}
+
+impl From<io::Error> for SpineLoadError {
+    fn from(err: io::Error) -> Self {
+        Self::Io(err)
+    }
+}
+
+impl From<SpineError> for SpineLoadError {
+    fn from(err: SpineError) -> Self {
+        Self::Spine(err)
+    }
+}
+
+impl From<LoadDirectError> for SpineLoadError {
+    fn from(err: LoadDirectError) -> Self {
+        Self::Atlas(Box::new(err))
+    }
+}
+
+impl fmt::Display for SpineLoadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(error) => write!(f, "IO error: {error}"),
+            Self::Spine(error) => write!(f, "{error}"),
+            Self::Atlas(error) => write!(f, "Failed to load atlas: {error}"),
+        }
+    }
+}
+
+impl Error for SpineLoadError {}
+
+/// Loads `.atlas` files as [`SpineAtlas`] assets, loading the image of each page from the same
+/// directory.
+///
+/// Each page's image handle is stored with [`AtlasPage::set_texture`], unless a texture was
+/// already created with
+/// [`set_create_texture_cb`](`crate::extension::set_create_texture_cb`).
+#[derive(Default)]
+pub struct SpineAtlasLoader;
+
+impl AssetLoader for SpineAtlasLoader {
+    type Asset = SpineAtlas;
+    type Settings = ();
+    type Error = SpineLoadError;
+
+    async fn load<'a>(
+        &'a self,
+        reader: &'a mut Reader<'_>,
+        _settings: &'a (),
+        load_context: &'a mut LoadContext<'_>,
+    ) -> Result<SpineAtlas, SpineLoadError> {
+        let mut bytes = vec![];
+        reader.read_to_end(&mut bytes).await?;
+        let atlas = Atlas::new(&bytes, "")?;
+        let directory = load_context
+            .path()
+            .parent()
+            .map(Path::to_path_buf)
+            .unwrap_or_default();
+        let mut pages = vec![];
+        for page in atlas.pages() {
+            let image: Handle<Image> = load_context.load(directory.join(page.name()));
+            let mut page = unsafe { AtlasPage::new_from_ptr(page.c_ptr()) };
+            if unsafe { page.c_ptr_ref().rendererObject }.is_null() {
+                page.set_texture(image.clone());
+            }
+            pages.push(image);
+        }
+        Ok(SpineAtlas {
+            atlas: Arc::new(atlas),
+            pages,
+        })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["atlas"]
+    }
+}
+
+/// Settings for loading [`SpineSkeletonData`] assets.
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use rusty_spine::bevy::{SpineSkeletonData, SpineSkeletonLoaderSettings};
+///
+/// fn load(asset_server: Res<AssetServer>) {
+///     let skeleton: Handle<SpineSkeletonData> = asset_server.load_with_settings(
+///         "spineboy/spineboy-pro.json",
+///         |settings: &mut SpineSkeletonLoaderSettings| {
+///             settings.atlas = Some("spineboy.atlas".to_owned());
+///         },
+///     );
+/// }
+/// ```
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SpineSkeletonLoaderSettings {
+    /// The path of the atlas, relative to the skeleton file's directory. Defaults to the
+    /// skeleton's path with the `.atlas` extension.
+    pub atlas: Option<String>,
+}
+
+async fn load_atlas(
+    settings: &SpineSkeletonLoaderSettings,
+    load_context: &mut LoadContext<'_>,
+) -> Result<(Arc<Atlas>, Handle<SpineAtlas>), SpineLoadError> {
+    let path = load_context.path();
+    let atlas_path = settings.atlas.as_ref().map_or_else(
+        || path.with_extension("atlas"),
+        |atlas| path.parent().unwrap_or_else(|| Path::new("")).join(atlas),
+    );
+    let atlas = load_context
+        .loader()
+        .direct()
+        .load::<SpineAtlas>(atlas_path)
+        .await?;
+    let atlas_arc = atlas.get().atlas.clone();
+    let atlas_handle = load_context.add_loaded_labeled_asset("atlas", atlas);
+    Ok((atlas_arc, atlas_handle))
+}
+
+fn skeleton_asset(skeleton_data: SkeletonData, atlas: Handle<SpineAtlas>) -> SpineSkeletonData {
+    let skeleton_data = Arc::new(skeleton_data);
+    SpineSkeletonData {
+        animation_state_data: Arc::new(AnimationStateData::new(skeleton_data.clone())),
+        skeleton_data,
+        atlas,
+    }
+}
+
+/// Loads `.json` skeleton files as [`SpineSkeletonData`] assets.
+#[derive(Default)]
+pub struct SpineSkeletonJsonLoader;
+
+impl AssetLoader for SpineSkeletonJsonLoader {
+    type Asset = SpineSkeletonData;
+    type Settings = SpineSkeletonLoaderSettings;
+    type Error = SpineLoadError;
+
+    async fn load<'a>(
+        &'a self,
+        reader: &'a mut Reader<'_>,
+        settings: &'a SpineSkeletonLoaderSettings,
+        load_context: &'a mut LoadContext<'_>,
+    ) -> Result<SpineSkeletonData, SpineLoadError> {
+        let mut bytes = vec![];
+        reader.read_to_end(&mut bytes).await?;
+        let (atlas, atlas_handle) = load_atlas(settings, load_context).await?;
+        let skeleton_data = SkeletonJson::new(atlas)
+            .read_skeleton_data(&bytes)
+            .map_err(|error| error.with_path(&load_context.path().to_string_lossy()))?;
+        Ok(skeleton_asset(skeleton_data, atlas_handle))
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["json"]
+    }
+}
+
+/// Loads `.skel` skeleton files as [`SpineSkeletonData`] assets.
+#[derive(Default)]
+pub struct SpineSkeletonBinaryLoader;
+
+impl AssetLoader for SpineSkeletonBinaryLoader {
+    type Asset = SpineSkeletonData;
+    type Settings = SpineSkeletonLoaderSettings;
+    type Error = SpineLoadError;
+
+    async fn load<'a>(
+        &'a self,
+        reader: &'a mut Reader<'_>,
+        settings: &'a SpineSkeletonLoaderSettings,
+        load_context: &'a mut LoadContext<'_>,
+    ) -> Result<SpineSkeletonData, SpineLoadError> {
+        let mut bytes = vec![];
+        reader.read_to_end(&mut bytes).await?;
+        let (atlas, atlas_handle) = load_atlas(settings, load_context).await?;
+        let skeleton_data = SkeletonBinary::new(atlas)
+            .read_skeleton_data(&bytes)
+            .map_err(|error| error.with_path(&load_context.path().to_string_lossy()))?;
+        Ok(skeleton_asset(skeleton_data, atlas_handle))
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["skel"]
+    }
+}
+
+/// A mesh attribute for the dark color of two color tinting, see
+/// [`SkeletonController::uses_two_color_tint`].
+pub const ATTRIBUTE_DARK_COLOR: MeshVertexAttribute =
+    MeshVertexAttribute::new("Vertex_DarkColor", 2_118_413_907, VertexFormat::Float32x4);
+
+fn mesh(
+    vertices: Vec<[f32; 3]>,
+    uvs: Vec<[f32; 2]>,
+    colors: Vec<[f32; 4]>,
+    dark_colors: Vec<[f32; 4]>,
+    indices: Indices,
+) -> Mesh {
+    Mesh::new(
+        PrimitiveTopology::TriangleList,
+        RenderAssetUsages::default(),
+    )
+    .with_inserted_attribute(Mesh::ATTRIBUTE_POSITION, vertices)
+    .with_inserted_attribute(Mesh::ATTRIBUTE_UV_0, uvs)
+    .with_inserted_attribute(Mesh::ATTRIBUTE_COLOR, colors)
+    .with_inserted_attribute(ATTRIBUTE_DARK_COLOR, dark_colors)
+    .with_inserted_indices(indices)
+}
+
+/// Converts a renderable to a mesh with positions, UVs, colors, dark colors (see
+/// [`ATTRIBUTE_DARK_COLOR`]), and indices. Vertices are placed at `z = 0`.
+impl From<&SkeletonRenderable> for Mesh {
+    fn from(renderable: &SkeletonRenderable) -> Self {
+        let vertices_count = renderable.vertices.len();
+        let color = renderable.color;
+        let dark_color = renderable.dark_color;
+        mesh(
+            renderable
+                .vertices
+                .iter()
+                .map(|[x, y]| [*x, *y, 0.])
+                .collect(),
+            renderable.uvs.clone(),
+            vec![[color.r, color.g, color.b, color.a]; vertices_count],
+            vec![[dark_color.r, dark_color.g, dark_color.b, dark_color.a]; vertices_count],
+            Indices::U16(renderable.indices.clone()),
+        )
+    }
+}
+
+/// Converts a renderable to a mesh with positions, UVs, colors, dark colors (see
+/// [`ATTRIBUTE_DARK_COLOR`]), and indices. Vertices are placed at `z = 0`, and positions and UVs
+/// using [`VertexPositionFormat::Unorm16`](`crate::draw::VertexPositionFormat::Unorm16`) and
+/// packed colors are decoded. Indices use the renderable's
+/// [`index_format`](`SkeletonCombinedRenderable::index_format`).
+impl From<&SkeletonCombinedRenderable> for Mesh {
+    fn from(renderable: &SkeletonCombinedRenderable) -> Self {
+        let unorm = |value: u16| f32::from(value) / 65535.;
+        let vertices = if renderable.unorm_vertices.is_empty() {
+            renderable
+                .vertices
+                .iter()
+                .map(|[x, y]| [*x, *y, 0.])
+                .collect()
+        } else {
+            let [offset_x, offset_y] = renderable.vertex_offset;
+            let [scale_x, scale_y] = renderable.vertex_scale;
+            renderable
+                .unorm_vertices
+                .iter()
+                .map(|[x, y]| {
+                    [
+                        offset_x + unorm(*x) * scale_x,
+                        offset_y + unorm(*y) * scale_y,
+                        0.,
+                    ]
+                })
+                .collect()
+        };
+        let uvs = if renderable.unorm_uvs.is_empty() {
+            renderable.uvs.clone()
+        } else {
+            renderable
+                .unorm_uvs
+                .iter()
+                .map(|[u, v]| [unorm(*u), unorm(*v)])
+                .collect()
+        };
+        let colors = |colors: &[[f32; 4]], packed_colors: &[[u8; 4]]| {
+            if packed_colors.is_empty() {
+                colors.to_vec()
+            } else {
+                packed_colors
+                    .iter()
+                    .map(|color| color.map(|channel| f32::from(channel) / 255.))
+                    .collect()
+            }
+        };
+        mesh(
+            vertices,
+            uvs,
+            colors(&renderable.colors, &renderable.packed_colors),
+            colors(&renderable.dark_colors, &renderable.packed_dark_colors),
+            match renderable.index_format {
+                IndexFormat::U16 => Indices::U16(renderable.indices.clone()),
+                IndexFormat::U32 => Indices::U32(renderable.indices_u32.clone()),
+            },
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ::bevy::{
+        asset::{AssetApp, AssetPlugin, AssetServer, Assets},
+        prelude::{App, Mesh, MinimalPlugins},
+        render::texture::Image,
+    };
+
+    use super::{SpineAtlas, SpinePlugin, SpineSkeletonData, SpineSkeletonLoaderSettings};
+
+    #[test]
+    fn load_skeleton() {
+        let mut app = App::new();
+        app.add_plugins((
+            MinimalPlugins,
+            AssetPlugin {
+                file_path: "assets".to_owned(),
+                ..Default::default()
+            },
+            SpinePlugin,
+        ))
+        .init_asset::<Image>();
+        let handle = app
+            .world()
+            .resource::<AssetServer>()
+            .load_with_settings::<SpineSkeletonData, SpineSkeletonLoaderSettings>(
+                "spineboy/export/spineboy-pro.json",
+                |settings| settings.atlas = Some("spineboy.atlas".to_owned()),
+            );
+        for _ in 0..1000 {
+            app.update();
+            if app
+                .world()
+                .resource::<Assets<SpineSkeletonData>>()
+                .contains(&handle)
+            {
+                break;
+            }
+            std::thread::sleep(std::time::Duration::from_millis(1));
+        }
+        let world = app.world();
+        let skeleton = world
+            .resource::<Assets<SpineSkeletonData>>()
+            .get(&handle)
+            .expect("skeleton failed to load");
+        let atlas = world
+            .resource::<Assets<SpineAtlas>>()
+            .get(&skeleton.atlas)
+            .unwrap();
+        assert_eq!(atlas.pages.len(), 1);
+
+        let mut controller = skeleton.controller();
+        for renderable in controller.combined_renderables() {
+            assert!(atlas.image(renderable.attachment_renderer_object).is_some());
+            let mesh = Mesh::from(&renderable);
+            assert_eq!(
+                mesh.count_vertices(),
+                renderable
+                    .vertices
+                    .len()
+                    .max(renderable.unorm_vertices.len())
+            );
+        }
+    }
+}
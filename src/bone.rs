@@ -1,18 +1,24 @@
+#[cfg(not(feature = "std"))]
+use crate::math::Float;
 use crate::{
     c::{
         spBone, spBoneData, spBone_getWorldRotationX, spBone_getWorldRotationY,
         spBone_getWorldScaleX, spBone_getWorldScaleY, spBone_isYDown, spBone_localToWorld,
-        spBone_rotateWorld, spBone_setToSetupPose, spBone_setYDown, spBone_update,
-        spBone_updateAppliedTransform, spBone_updateWorldTransform,
+        spBone_localToWorldRotation, spBone_rotateWorld, spBone_setToSetupPose, spBone_setYDown,
+        spBone_update, spBone_updateAppliedTransform, spBone_updateWorldTransform,
         spBone_updateWorldTransformWith, spBone_worldToLocal, spBone_worldToLocalRotation,
         spInherit, spSkeleton,
     },
     c_interface::{NewFromPtr, SyncPtr},
+    error::SpineError,
+    skeleton_data::check_skeleton_data_frozen,
     Skeleton,
 };
 
+#[cfg(feature = "glam")]
+use glam::{Affine2, Mat2, Mat4, Vec2, Vec4};
 #[cfg(feature = "mint")]
-use mint::Vector2;
+use mint::{ColumnMatrix2x3, Vector2};
 
 /// A bone within the [`Skeleton`] hierarchy.
 ///
@@ -164,7 +170,118 @@ impl Bone {
     /// Transforms a local rotation to a world rotation.
     #[must_use]
     pub fn local_to_world_rotation(&self, local_rotation: f32) -> f32 {
-        unsafe { spBone_worldToLocalRotation(self.c_ptr(), local_rotation) }
+        unsafe { spBone_localToWorldRotation(self.c_ptr(), local_rotation) }
+    }
+
+    /// Transforms a point from world coordinates to the coordinates of the bone's parent, which
+    /// is the space of the bone's [`x`](`Self::x`) and [`y`](`Self::y`). For the root bone, this
+    /// undoes the skeleton's position and scale.
+    #[must_use]
+    pub fn world_to_parent(&self, world_x: f32, world_y: f32) -> (f32, f32) {
+        self.parent().map_or_else(
+            || {
+                let (skeleton_x, skeleton_y, scale_x, scale_y) = self.skeleton_transform();
+                (
+                    (world_x - skeleton_x) / scale_x,
+                    (world_y - skeleton_y) / scale_y,
+                )
+            },
+            |parent| parent.world_to_local(world_x, world_y),
+        )
+    }
+
+    /// Transforms a point from the coordinates of the bone's parent to world coordinates, see
+    /// [`world_to_parent`](`Self::world_to_parent`).
+    #[must_use]
+    pub fn parent_to_world(&self, x: f32, y: f32) -> (f32, f32) {
+        self.parent().map_or_else(
+            || {
+                let (skeleton_x, skeleton_y, scale_x, scale_y) = self.skeleton_transform();
+                (x * scale_x + skeleton_x, y * scale_y + skeleton_y)
+            },
+            |parent| parent.local_to_world(x, y),
+        )
+    }
+
+    /// Moves the bone to a position in world coordinates, such as to make a hand follow the mouse.
+    ///
+    /// ```
+    /// # #[path="./test.rs"]
+    /// # mod test;
+    /// use rusty_spine::Physics;
+    ///
+    /// # let (mut skeleton, _) = test::TestAsset::spineboy().instance(true);
+    /// # let (mouse_x, mouse_y) = (100., 200.);
+    /// skeleton.update_world_transform(Physics::Update);
+    /// let mut crosshair = skeleton.find_bone_mut("crosshair").unwrap();
+    /// crosshair.set_world_position(mouse_x, mouse_y);
+    /// skeleton.update_world_transform(Physics::None);
+    /// ```
+    ///
+    /// The local and applied translation are set, and the bone's world transform is updated. The
+    /// world transforms of child bones are not updated, so
+    /// [`Skeleton::update_world_transform`] should be called afterwards. Animations overwrite the
+    /// local translation when applied, so this should be called after
+    /// [`AnimationState::apply`](`crate::AnimationState::apply`) every frame.
+    pub fn set_world_position(&mut self, world_x: f32, world_y: f32) {
+        let (x, y) = self.world_to_parent(world_x, world_y);
+        self.set_x(x);
+        self.set_y(y);
+        self.set_applied_x(x);
+        self.set_applied_y(y);
+        self.update_world_transform_applied();
+    }
+
+    /// Rotates the bone so its X axis points in a direction in world coordinates, in degrees
+    /// counter clockwise. See [`set_world_position`](`Self::set_world_position`) for when the
+    /// world transforms are updated.
+    ///
+    /// This is exact for bones which inherit their parent's full transform, and approximate if
+    /// the parent is non-uniformly scaled or sheared.
+    pub fn set_world_rotation(&mut self, world_rotation: f32) {
+        let (sin, cos) = world_rotation.to_radians().sin_cos();
+        let (x, y) = self.parent().map_or_else(
+            || {
+                let (_, _, scale_x, scale_y) = self.skeleton_transform();
+                (cos / scale_x, sin / scale_y)
+            },
+            |parent| {
+                let (origin_x, origin_y) = parent.world_to_local(0., 0.);
+                let (x, y) = parent.world_to_local(cos, sin);
+                (x - origin_x, y - origin_y)
+            },
+        );
+        let parent_rotation = y.atan2(x).to_degrees();
+        self.set_rotation(parent_rotation - self.shear_x());
+        self.set_applied_rotation(parent_rotation - self.applied_shear_x());
+        self.update_world_transform_applied();
+    }
+
+    /// The skeleton's position and scale, including the Y flip of [`Bone::set_y_down`], as used
+    /// for the root bone.
+    fn skeleton_transform(&self) -> (f32, f32, f32, f32) {
+        let skeleton = unsafe { &*self.c_ptr_ref().skeleton };
+        let y_down = if Self::is_y_down() { -1. } else { 1. };
+        (
+            skeleton.x,
+            skeleton.y,
+            skeleton.scaleX,
+            skeleton.scaleY * y_down,
+        )
+    }
+
+    /// Recomputes the world transform from the applied transform, see
+    /// [`update_world_transform_with`](`Self::update_world_transform_with`).
+    fn update_world_transform_applied(&mut self) {
+        self.update_world_transform_with(
+            self.applied_x(),
+            self.applied_y(),
+            self.applied_rotation(),
+            self.applied_scale_x(),
+            self.applied_scale_y(),
+            self.applied_shear_x(),
+            self.applied_shear_y(),
+        );
     }
 
     /// Rotates the world transform the specified amount.
@@ -172,7 +289,7 @@ impl Bone {
     /// After changes are made to the world transform,
     /// [`update_applied_transform`](`Self::update_applied_transform`) should be called and update
     /// will need to be called on any child bones, recursively.
-    pub fn rotate_world(&self, degrees: f32) {
+    pub fn rotate_world(&mut self, degrees: f32) {
         unsafe {
             spBone_rotateWorld(self.c_ptr(), degrees);
         }
@@ -241,7 +358,9 @@ impl Bone {
         f32
     );
     c_accessor_mut!(
-        /// The applied local x translation.
+        /// The applied local x translation, which is the local x translation after constraints
+        /// were applied by the last world transform update. Unlike [`x`](`Self::x`), which is
+        /// set by animations, this is what the world transform was computed from.
         applied_x,
         /// Set the applied local x translation.
         set_applied_x,
@@ -249,7 +368,8 @@ impl Bone {
         f32
     );
     c_accessor_mut!(
-        /// The applied local y translation.
+        /// The applied local y translation, after constraints, see
+        /// [`applied_x`](`Self::applied_x`).
         applied_y,
         /// Set the applied local y translation.
         set_applied_y,
@@ -257,7 +377,8 @@ impl Bone {
         f32
     );
     c_accessor_mut!(
-        /// The applied local rotation in degrees, counter clockwise.
+        /// The applied local rotation in degrees, counter clockwise, after constraints, see
+        /// [`applied_x`](`Self::applied_x`).
         applied_rotation,
         /// Set the applied local rotation in degrees, counter clockwise.
         set_applied_rotation,
@@ -265,7 +386,7 @@ impl Bone {
         f32
     );
     c_accessor_mut!(
-        /// The applied local scaleX.
+        /// The applied local scaleX, after constraints, see [`applied_x`](`Self::applied_x`).
         applied_scale_x,
         /// Set the applied local scaleX.
         set_applied_scale_x,
@@ -273,7 +394,7 @@ impl Bone {
         f32
     );
     c_accessor_mut!(
-        /// The applied local scaleY.
+        /// The applied local scaleY, after constraints, see [`applied_x`](`Self::applied_x`).
         applied_scale_y,
         /// Set the applied local scaleY.
         set_applied_scale_y,
@@ -281,7 +402,7 @@ impl Bone {
         f32
     );
     c_accessor_mut!(
-        /// The applied local shearX.
+        /// The applied local shearX, after constraints, see [`applied_x`](`Self::applied_x`).
         applied_shear_x,
         /// Set the applied local shearX.
         set_applied_shear_x,
@@ -289,7 +410,7 @@ impl Bone {
         f32
     );
     c_accessor_mut!(
-        /// The applied local shearY.
+        /// The applied local shearY, after constraints, see [`applied_x`](`Self::applied_x`).
         applied_shear_y,
         /// Set the applied local shearY.
         set_applied_shear_y,
@@ -333,9 +454,9 @@ impl Bone {
         f32
     );
     c_accessor_mut!(
-        /// Set the world X translation.
+        /// The world X translation.
         world_x,
-        /// The world X translation. If changed,
+        /// Set the world X translation. If changed,
         /// [`update_applied_transform`](`Self::update_applied_transform`) should be called.
         set_world_x,
         worldX,
@@ -350,16 +471,30 @@ impl Bone {
         worldY,
         f32
     );
+    c_accessor_enum_mut!(
+        /// The transform mode for how parent world transforms affect this bone. Initialized from
+        /// [`BoneData::inherit`] and may be changed by inherit timelines.
+        inherit,
+        /// Set the transform mode for how parent world transforms affect this bone. If changed,
+        /// [`update_world_transform`](`Self::update_world_transform`) should be called.
+        ///
+        /// This is reset to [`BoneData::inherit`] by
+        /// [`set_to_setup_pose`](`Self::set_to_setup_pose`).
+        set_inherit,
+        inherit,
+        Inherit
+    );
     c_accessor_bool!(sorted, sorted);
     c_accessor_bool!(active, active);
-    c_accessor_tmp_ptr_mut!(
+    c_accessor_tmp_ptr_data_mut!(
         /// The bone's setup pose data.
         data,
         /// The bone's mutable setup pose data.
         data_mut,
         data,
         BoneData,
-        spBoneData
+        spBoneData,
+        Self::check_data_frozen
     );
     c_accessor_tmp_ptr_optional_mut!(parent, parent_mut, parent, Bone, spBone);
     c_accessor!(children_count, childrenCount, usize);
@@ -417,6 +552,10 @@ impl Bone {
     }
 
     c_ptr!(c_bone, spBone);
+
+    fn check_data_frozen(&self) -> Result<(), SpineError> {
+        check_skeleton_data_frozen(unsafe { (*self.c_ptr_ref().skeleton).data })
+    }
 }
 
 /// Functions available if using the `mint` feature.
@@ -454,12 +593,12 @@ impl Bone {
         self.set_world_y(translation.y);
     }
 
-    /// The applied translation.
+    /// The applied local translation, after constraints.
     #[must_use]
     pub fn applied_translation(&self) -> Vector2<f32> {
         Vector2 {
-            x: self.world_x(),
-            y: self.world_y(),
+            x: self.applied_x(),
+            y: self.applied_y(),
         }
     }
 
@@ -490,12 +629,12 @@ impl Bone {
     #[must_use]
     pub fn world_scale(&self) -> Vector2<f32> {
         Vector2 {
-            x: self.world_x(),
-            y: self.world_y(),
+            x: self.world_scale_x(),
+            y: self.world_scale_y(),
         }
     }
 
-    /// The applied scale.
+    /// The applied local scale, after constraints.
     #[must_use]
     pub fn applied_scale(&self) -> Vector2<f32> {
         Vector2 {
@@ -527,7 +666,7 @@ impl Bone {
         self.set_shear_y(shear.y);
     }
 
-    /// The applied shear.
+    /// The applied local shear, after constraints.
     #[must_use]
     pub fn applied_shear(&self) -> Vector2<f32> {
         Vector2 {
@@ -551,6 +690,23 @@ impl Bone {
         }
     }
 
+    /// The world transform as a column major matrix, transforming bone-local coordinates to world
+    /// coordinates as [`local_to_world`](`Self::local_to_world`) does.
+    #[must_use]
+    pub fn world_matrix(&self) -> ColumnMatrix2x3<f32> {
+        ColumnMatrix2x3 {
+            x: Vector2 {
+                x: self.a(),
+                y: self.c(),
+            },
+            y: Vector2 {
+                x: self.b(),
+                y: self.d(),
+            },
+            z: self.world_translation(),
+        }
+    }
+
     pub fn update_world_transform_with2(
         &mut self,
         translation: mint::Vector2<f32>,
@@ -573,6 +729,47 @@ impl Bone {
     }
 }
 
+/// Functions available if using the `glam` feature.
+#[cfg(feature = "glam")]
+impl Bone {
+    /// The world position.
+    #[must_use]
+    pub fn world_position_vec2(&self) -> Vec2 {
+        Vec2::new(self.world_x(), self.world_y())
+    }
+
+    /// The world transform, transforming bone-local coordinates to world coordinates as
+    /// [`local_to_world`](`Self::local_to_world`) does.
+    ///
+    /// ```
+    /// # #[path="./test.rs"]
+    /// # mod test;
+    /// # let (skeleton, _) = test::TestAsset::spineboy().instance(true);
+    /// let head = skeleton.find_bone("head").unwrap();
+    /// // the world position of a point 10 units along the head bone
+    /// let point = head.world_affine2().transform_point2(glam::Vec2::new(10., 0.));
+    /// ```
+    #[must_use]
+    pub fn world_affine2(&self) -> Affine2 {
+        Affine2::from_mat2_translation(
+            Mat2::from_cols(Vec2::new(self.a(), self.c()), Vec2::new(self.b(), self.d())),
+            self.world_position_vec2(),
+        )
+    }
+
+    /// The world transform as a 3D matrix at `z = 0`, such as for attaching a model to a bone. See
+    /// [`world_affine2`](`Self::world_affine2`).
+    #[must_use]
+    pub fn world_mat4(&self) -> Mat4 {
+        Mat4::from_cols(
+            Vec4::new(self.a(), self.c(), 0., 0.),
+            Vec4::new(self.b(), self.d(), 0., 0.),
+            Vec4::Z,
+            self.world_position_vec2().extend(0.).extend(1.),
+        )
+    }
+}
+
 c_handle_decl!(
     /// A storeable reference to a [`Bone`].
     ///
@@ -624,9 +821,12 @@ impl BoneData {
     c_accessor!(shear_y, shearY, f32);
     c_accessor_color!(color, color);
     c_accessor_bool!(skin_required, skinRequired);
-    c_accessor_enum!(
+    c_accessor_enum_mut!(
         /// The transform mode for how parent world transforms affect this bone.
         inherit,
+        /// Set the transform mode for how parent world transforms affect this bone. Bones copy
+        /// this mode when the skeleton is set to the setup pose.
+        set_inherit,
         inherit,
         Inherit
     );
@@ -663,7 +863,8 @@ impl BoneData {
 
 /// The inherited transform for how bones are affected by their parents.
 ///
-/// See [`BoneData::inherit`].
+/// See [`BoneData::inherit`] and [`Bone::inherit`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Inherit {
     Normal = 0,
     OnlyTranslation = 1,
@@ -685,3 +886,132 @@ impl From<spInherit> for Inherit {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::Inherit;
+    use crate::{test::TestAsset, Physics};
+
+    #[test]
+    fn applied_transform() {
+        let (mut skeleton, _) = TestAsset::spineboy().instance(true);
+        skeleton.set_to_setup_pose();
+        let mut target = skeleton.find_bone_mut("rear-leg-target").unwrap();
+        let target_x = target.x();
+        target.set_x(target_x - 40.);
+        skeleton.update_world_transform(Physics::None);
+
+        let thigh = skeleton.find_bone("rear-thigh").unwrap();
+        assert_eq!(thigh.rotation(), thigh.data().rotation());
+        assert!((thigh.applied_rotation() - thigh.rotation()).abs() > 1.);
+        assert_eq!(thigh.applied_x(), thigh.x());
+    }
+
+    #[test]
+    fn world_transform_helpers() {
+        let (mut skeleton, _) = TestAsset::spineboy().instance(true);
+        skeleton.set_x(50.);
+        skeleton.set_y(-20.);
+        skeleton.set_scale_x(2.);
+        skeleton.update_world_transform(Physics::None);
+        for name in ["root", "head"] {
+            let bone = skeleton.find_bone(name).unwrap();
+            let (world_x, world_y) = bone.parent_to_world(bone.x(), bone.y());
+            assert!((world_x - bone.world_x()).abs() < 0.01);
+            assert!((world_y - bone.world_y()).abs() < 0.01);
+            let (x, y) = bone.world_to_parent(world_x, world_y);
+            assert!((x - bone.x()).abs() < 0.01 && (y - bone.y()).abs() < 0.01);
+            let rotation = bone.world_to_local_rotation(40.);
+            assert!((bone.local_to_world_rotation(rotation) - 40.).abs() < 0.01);
+        }
+
+        let mut head = skeleton.find_bone_mut("head").unwrap();
+        head.set_world_position(120., 300.);
+        head.set_world_rotation(-30.);
+        assert!((head.world_x() - 120.).abs() < 0.01);
+        assert!((head.world_rotation_x() + 30.).abs() < 0.01);
+        skeleton.update_world_transform(Physics::None);
+        let head = skeleton.find_bone("head").unwrap();
+        assert!((head.world_x() - 120.).abs() < 0.01);
+        assert!((head.world_y() - 300.).abs() < 0.01);
+        assert!((head.world_rotation_x() + 30.).abs() < 0.01);
+    }
+
+    #[test]
+    fn inherit() {
+        let (mut skeleton, _) = TestAsset::spineboy().instance(true);
+        skeleton.set_to_setup_pose();
+        let mut head = skeleton.find_bone_mut("head").unwrap();
+        assert_eq!(head.data().inherit(), Inherit::Normal);
+        assert_eq!(head.inherit(), Inherit::Normal);
+        head.set_inherit(Inherit::OnlyTranslation);
+        let head_rotation = head.rotation();
+        let mut neck = skeleton.find_bone_mut("neck").unwrap();
+        let neck_rotation = neck.rotation();
+        neck.set_rotation(neck_rotation + 45.);
+        skeleton.update_world_transform(Physics::None);
+
+        // with only translation inherited, the parent rotation does not affect the head
+        let head = skeleton.find_bone("head").unwrap();
+        assert_eq!(head.inherit(), Inherit::OnlyTranslation);
+        assert!((head.world_rotation_x() - head_rotation).abs() < 0.01);
+
+        skeleton.set_to_setup_pose();
+        let head = skeleton.find_bone("head").unwrap();
+        assert_eq!(head.inherit(), Inherit::Normal);
+    }
+
+    #[cfg(feature = "glam")]
+    #[test]
+    fn world_affine2() {
+        let (mut skeleton, _) = TestAsset::spineboy().instance(true);
+        skeleton.set_scale_x(-2.);
+        skeleton.find_bone_mut("head").unwrap().set_shear_x(20.);
+        skeleton.update_world_transform(Physics::None);
+        let head = skeleton.find_bone("head").unwrap();
+        let affine2 = head.world_affine2();
+        let mat4 = head.world_mat4();
+        for (x, y) in [(0., 0.), (10., 0.), (-5., 30.)] {
+            let (world_x, world_y) = head.local_to_world(x, y);
+            let expected = glam::Vec2::new(world_x, world_y);
+            let point = glam::Vec2::new(x, y);
+            assert!(affine2.transform_point2(point).abs_diff_eq(expected, 0.01));
+            assert!(mat4
+                .transform_point3(point.extend(0.))
+                .abs_diff_eq(expected.extend(0.), 0.01));
+        }
+    }
+
+    #[cfg(feature = "mint")]
+    #[test]
+    fn mint_applied_translation_and_world_scale() {
+        let (mut skeleton, _) = TestAsset::spineboy().instance(true);
+        skeleton.set_x(100.);
+        skeleton.set_scale_x(2.);
+        skeleton.set_scale_y(3.);
+        skeleton.update_world_transform(Physics::None);
+        for bone in skeleton.bones() {
+            let applied_translation = bone.applied_translation();
+            assert_eq!(applied_translation.x, bone.applied_x());
+            assert_eq!(applied_translation.y, bone.applied_y());
+            let world_scale = bone.world_scale();
+            assert_eq!(world_scale.x, bone.world_scale_x());
+            assert_eq!(world_scale.y, bone.world_scale_y());
+        }
+        let root = skeleton.find_bone("root").unwrap();
+        assert_ne!(root.applied_translation().x, root.world_x());
+        assert_ne!(root.world_scale().x, root.world_x());
+    }
+
+    #[cfg(feature = "mint")]
+    #[test]
+    fn world_matrix() {
+        let (mut skeleton, _) = TestAsset::spineboy().instance(true);
+        skeleton.update_world_transform(Physics::None);
+        let head = skeleton.find_bone("head").unwrap();
+        let matrix = head.world_matrix();
+        let (world_x, world_y) = head.local_to_world(10., 5.);
+        assert!((matrix.x.x * 10. + matrix.y.x * 5. + matrix.z.x - world_x).abs() < 0.01);
+        assert!((matrix.x.y * 10. + matrix.y.y * 5. + matrix.z.y - world_y).abs() < 0.01);
+    }
+}
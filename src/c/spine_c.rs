@@ -2521,7 +2521,7 @@ pub unsafe extern "C" fn _spTranslateTimeline_apply(
     mut blend: spMixBlend,
     mut _direction: spMixDirection,
 ) {
-    let mut bone: *mut spBone = std::ptr::null_mut::<spBone>();
+    let mut bone: *mut spBone = core::ptr::null_mut::<spBone>();
     let mut x: c_float = 0.;
     let mut y: c_float = 0.;
     let mut t: c_float = 0.;
@@ -2681,7 +2681,7 @@ pub unsafe extern "C" fn _spTranslateXTimeline_apply(
     mut blend: spMixBlend,
     mut _direction: spMixDirection,
 ) {
-    let mut bone: *mut spBone = std::ptr::null_mut::<spBone>();
+    let mut bone: *mut spBone = core::ptr::null_mut::<spBone>();
     let mut x: c_float = 0.;
     let mut self_0: *mut spTranslateXTimeline = timeline.cast::<spTranslateXTimeline>();
     let mut frames: *mut c_float = (*(*self_0).super_0.super_0.frames).items;
@@ -2797,7 +2797,7 @@ pub unsafe extern "C" fn _spTranslateYTimeline_apply(
     mut blend: spMixBlend,
     mut _direction: spMixDirection,
 ) {
-    let mut bone: *mut spBone = std::ptr::null_mut::<spBone>();
+    let mut bone: *mut spBone = core::ptr::null_mut::<spBone>();
     let mut y: c_float = 0.;
     let mut self_0: *mut spTranslateYTimeline = timeline.cast::<spTranslateYTimeline>();
     let mut frames: *mut c_float = (*(*self_0).super_0.super_0.frames).items;
@@ -2913,7 +2913,7 @@ pub unsafe extern "C" fn _spScaleTimeline_apply(
     mut blend: spMixBlend,
     mut direction: spMixDirection,
 ) {
-    let mut bone: *mut spBone = std::ptr::null_mut::<spBone>();
+    let mut bone: *mut spBone = core::ptr::null_mut::<spBone>();
     let mut i: c_int = 0;
     let mut curveType: c_int = 0;
     let mut x: c_float = 0.;
@@ -3383,7 +3383,7 @@ pub unsafe extern "C" fn _spShearTimeline_apply(
     mut blend: spMixBlend,
     mut _direction: spMixDirection,
 ) {
-    let mut bone: *mut spBone = std::ptr::null_mut::<spBone>();
+    let mut bone: *mut spBone = core::ptr::null_mut::<spBone>();
     let mut x: c_float = 0.;
     let mut y: c_float = 0.;
     let mut t: c_float = 0.;
@@ -3736,7 +3736,7 @@ pub unsafe extern "C" fn _spRGBATimeline_apply(
     mut blend: spMixBlend,
     mut _direction: spMixDirection,
 ) {
-    let mut slot: *mut spSlot = std::ptr::null_mut::<spSlot>();
+    let mut slot: *mut spSlot = core::ptr::null_mut::<spSlot>();
     let mut i: c_int = 0;
     let mut curveType: c_int = 0;
     let mut r: c_float = 0.;
@@ -3744,8 +3744,8 @@ pub unsafe extern "C" fn _spRGBATimeline_apply(
     let mut b: c_float = 0.;
     let mut a: c_float = 0.;
     let mut t: c_float = 0.;
-    let mut color: *mut spColor = std::ptr::null_mut::<spColor>();
-    let mut setup: *mut spColor = std::ptr::null_mut::<spColor>();
+    let mut color: *mut spColor = core::ptr::null_mut::<spColor>();
+    let mut setup: *mut spColor = core::ptr::null_mut::<spColor>();
     let mut self_0: *mut spRGBATimeline = timeline.cast::<spRGBATimeline>();
     let mut frames: *mut c_float = (*(*self_0).super_0.super_0.frames).items;
     let mut curves: *mut c_float = (*(*self_0).super_0.curves).items;
@@ -3934,15 +3934,15 @@ pub unsafe extern "C" fn _spRGBTimeline_apply(
     mut blend: spMixBlend,
     mut _direction: spMixDirection,
 ) {
-    let mut slot: *mut spSlot = std::ptr::null_mut::<spSlot>();
+    let mut slot: *mut spSlot = core::ptr::null_mut::<spSlot>();
     let mut i: c_int = 0;
     let mut curveType: c_int = 0;
     let mut r: c_float = 0.;
     let mut g: c_float = 0.;
     let mut b: c_float = 0.;
     let mut t: c_float = 0.;
-    let mut color: *mut spColor = std::ptr::null_mut::<spColor>();
-    let mut setup: *mut spColor = std::ptr::null_mut::<spColor>();
+    let mut color: *mut spColor = core::ptr::null_mut::<spColor>();
+    let mut setup: *mut spColor = core::ptr::null_mut::<spColor>();
     let mut self_0: *mut spRGBTimeline = timeline.cast::<spRGBTimeline>();
     let mut frames: *mut c_float = (*(*self_0).super_0.super_0.frames).items;
     let mut curves: *mut c_float = (*(*self_0).super_0.curves).items;
@@ -4117,10 +4117,10 @@ pub unsafe extern "C" fn _spAlphaTimeline_apply(
     mut blend: spMixBlend,
     mut _direction: spMixDirection,
 ) {
-    let mut slot: *mut spSlot = std::ptr::null_mut::<spSlot>();
+    let mut slot: *mut spSlot = core::ptr::null_mut::<spSlot>();
     let mut a: c_float = 0.;
-    let mut color: *mut spColor = std::ptr::null_mut::<spColor>();
-    let mut setup: *mut spColor = std::ptr::null_mut::<spColor>();
+    let mut color: *mut spColor = core::ptr::null_mut::<spColor>();
+    let mut setup: *mut spColor = core::ptr::null_mut::<spColor>();
     let mut self_0: *mut spAlphaTimeline = timeline.cast::<spAlphaTimeline>();
     let mut frames: *mut c_float = (*(*self_0).super_0.super_0.frames).items;
     slot = *((*skeleton).slots).offset((*self_0).slotIndex as isize);
@@ -4237,7 +4237,7 @@ pub unsafe extern "C" fn _spRGBA2Timeline_apply(
     mut blend: spMixBlend,
     mut _direction: spMixDirection,
 ) {
-    let mut slot: *mut spSlot = std::ptr::null_mut::<spSlot>();
+    let mut slot: *mut spSlot = core::ptr::null_mut::<spSlot>();
     let mut i: c_int = 0;
     let mut curveType: c_int = 0;
     let mut r: c_float = 0.;
@@ -4248,10 +4248,10 @@ pub unsafe extern "C" fn _spRGBA2Timeline_apply(
     let mut g2: c_float = 0.;
     let mut b2: c_float = 0.;
     let mut t: c_float = 0.;
-    let mut light: *mut spColor = std::ptr::null_mut::<spColor>();
-    let mut setupLight: *mut spColor = std::ptr::null_mut::<spColor>();
-    let mut dark: *mut spColor = std::ptr::null_mut::<spColor>();
-    let mut setupDark: *mut spColor = std::ptr::null_mut::<spColor>();
+    let mut light: *mut spColor = core::ptr::null_mut::<spColor>();
+    let mut setupLight: *mut spColor = core::ptr::null_mut::<spColor>();
+    let mut dark: *mut spColor = core::ptr::null_mut::<spColor>();
+    let mut setupDark: *mut spColor = core::ptr::null_mut::<spColor>();
     let mut self_0: *mut spRGBA2Timeline = timeline.cast::<spRGBA2Timeline>();
     let mut frames: *mut c_float = (*(*self_0).super_0.super_0.frames).items;
     let mut curves: *mut c_float = (*(*self_0).super_0.curves).items;
@@ -4501,7 +4501,7 @@ pub unsafe extern "C" fn _spRGB2Timeline_apply(
     mut blend: spMixBlend,
     mut _direction: spMixDirection,
 ) {
-    let mut slot: *mut spSlot = std::ptr::null_mut::<spSlot>();
+    let mut slot: *mut spSlot = core::ptr::null_mut::<spSlot>();
     let mut i: c_int = 0;
     let mut curveType: c_int = 0;
     let mut r: c_float = 0.;
@@ -4511,10 +4511,10 @@ pub unsafe extern "C" fn _spRGB2Timeline_apply(
     let mut g2: c_float = 0.;
     let mut b2: c_float = 0.;
     let mut t: c_float = 0.;
-    let mut light: *mut spColor = std::ptr::null_mut::<spColor>();
-    let mut setupLight: *mut spColor = std::ptr::null_mut::<spColor>();
-    let mut dark: *mut spColor = std::ptr::null_mut::<spColor>();
-    let mut setupDark: *mut spColor = std::ptr::null_mut::<spColor>();
+    let mut light: *mut spColor = core::ptr::null_mut::<spColor>();
+    let mut setupLight: *mut spColor = core::ptr::null_mut::<spColor>();
+    let mut dark: *mut spColor = core::ptr::null_mut::<spColor>();
+    let mut setupDark: *mut spColor = core::ptr::null_mut::<spColor>();
     let mut self_0: *mut spRGB2Timeline = timeline.cast::<spRGB2Timeline>();
     let mut frames: *mut c_float = (*(*self_0).super_0.super_0.frames).items;
     let mut curves: *mut c_float = (*(*self_0).super_0.curves).items;
@@ -4740,7 +4740,7 @@ unsafe extern "C" fn _spSetAttachment(
     spSlot_setAttachment(
         slot,
         if attachmentName.is_null() {
-            std::ptr::null_mut::<spAttachment>()
+            core::ptr::null_mut::<spAttachment>()
         } else {
             spSkeleton_getAttachmentForSlotIndex(skeleton, (*timeline).slotIndex, attachmentName)
         },
@@ -4758,7 +4758,7 @@ pub unsafe extern "C" fn _spAttachmentTimeline_apply(
     mut blend: spMixBlend,
     mut direction: spMixDirection,
 ) {
-    let mut attachmentName: *const c_char = std::ptr::null::<c_char>();
+    let mut attachmentName: *const c_char = core::ptr::null::<c_char>();
     let mut self_0: *mut spAttachmentTimeline = timeline.cast::<spAttachmentTimeline>();
     let mut frames: *mut c_float = (*(*self_0).super_0.frames).items;
     let mut slot: *mut spSlot = *((*skeleton).slots).offset((*self_0).slotIndex as isize);
@@ -4872,7 +4872,7 @@ pub unsafe extern "C" fn spAttachmentTimeline_setFrame(
         spine_strcpy(*fresh3, attachmentName);
     } else {
         let fresh4 = &mut (*((*self_0).attachmentNames).offset(frame as isize));
-        *fresh4 = std::ptr::null_mut::<c_char>();
+        *fresh4 = core::ptr::null_mut::<c_char>();
     };
 }
 #[no_mangle]
@@ -4985,12 +4985,12 @@ pub unsafe extern "C" fn _spDeformTimeline_apply(
     let mut i: c_int = 0;
     let mut vertexCount: c_int = 0;
     let mut percent: c_float = 0.;
-    let mut prevVertices: *const c_float = std::ptr::null::<c_float>();
-    let mut nextVertices: *const c_float = std::ptr::null::<c_float>();
-    let mut frames: *mut c_float = std::ptr::null_mut::<c_float>();
+    let mut prevVertices: *const c_float = core::ptr::null::<c_float>();
+    let mut nextVertices: *const c_float = core::ptr::null::<c_float>();
+    let mut frames: *mut c_float = core::ptr::null_mut::<c_float>();
     let mut framesCount: c_int = 0;
-    let mut frameVertices: *mut *mut c_float = std::ptr::null_mut::<*mut c_float>();
-    let mut deformArray: *mut c_float = std::ptr::null_mut::<c_float>();
+    let mut frameVertices: *mut *mut c_float = core::ptr::null_mut::<*mut c_float>();
+    let mut deformArray: *mut c_float = core::ptr::null_mut::<c_float>();
     let mut self_0: *mut spDeformTimeline = timeline.cast::<spDeformTimeline>();
     let mut slot: *mut spSlot = *((*skeleton).slots).offset((*self_0).slotIndex as isize);
     if (*(*slot).bone).active == 0 {
@@ -5096,7 +5096,7 @@ pub unsafe extern "C" fn _spDeformTimeline_apply(
             }
         } else {
             let mut vertexAttachment_2: *mut spVertexAttachment =
-                std::ptr::null_mut::<spVertexAttachment>();
+                core::ptr::null_mut::<spVertexAttachment>();
             match blend as c_uint {
                 0 => {
                     vertexAttachment_2 = (*slot).attachment.cast::<spVertexAttachment>();
@@ -5190,7 +5190,7 @@ pub unsafe extern "C" fn _spDeformTimeline_apply(
         }
     } else {
         let mut vertexAttachment_4: *mut spVertexAttachment =
-            std::ptr::null_mut::<spVertexAttachment>();
+            core::ptr::null_mut::<spVertexAttachment>();
         match blend as c_uint {
             0 => {
                 vertexAttachment_4 = (*slot).attachment.cast::<spVertexAttachment>();
@@ -5351,7 +5351,7 @@ pub unsafe extern "C" fn spDeformTimeline_setFrame(
     _spFree((*((*self_0).frameVertices).offset(frame as isize)).cast::<c_void>());
     if vertices.is_null() {
         let fresh5 = &mut (*((*self_0).frameVertices).offset(frame as isize));
-        *fresh5 = std::ptr::null_mut::<c_float>();
+        *fresh5 = core::ptr::null_mut::<c_float>();
     } else {
         let fresh6 = &mut (*((*self_0).frameVertices).offset(frame as isize));
         *fresh6 = _spMalloc(
@@ -5386,8 +5386,8 @@ pub unsafe extern "C" fn _spSequenceTimeline_apply(
 ) {
     let mut self_0: *mut spSequenceTimeline = timeline.cast::<spSequenceTimeline>();
     let mut slot: *mut spSlot = *((*skeleton).slots).offset((*self_0).slotIndex as isize);
-    let mut slotAttachment: *mut spAttachment = std::ptr::null_mut::<spAttachment>();
-    let mut frames: *mut c_float = std::ptr::null_mut::<c_float>();
+    let mut slotAttachment: *mut spAttachment = core::ptr::null_mut::<spAttachment>();
+    let mut frames: *mut c_float = core::ptr::null_mut::<c_float>();
     let mut i: c_int = 0;
     let mut modeAndIndex: c_int = 0;
     let mut count: c_int = 0;
@@ -5395,7 +5395,7 @@ pub unsafe extern "C" fn _spSequenceTimeline_apply(
     let mut mode: c_int = 0;
     let mut before: c_float = 0.;
     let mut delay: c_float = 0.;
-    let mut sequence: *mut spSequence = std::ptr::null_mut::<spSequence>();
+    let mut sequence: *mut spSequence = core::ptr::null_mut::<spSequence>();
     if (*(*slot).bone).active == 0 {
         return;
     }
@@ -5695,7 +5695,7 @@ pub unsafe extern "C" fn _spDrawOrderTimeline_apply(
     mut direction: spMixDirection,
 ) {
     let mut i: c_int = 0;
-    let mut drawOrderToSetupIndex: *const c_int = std::ptr::null::<c_int>();
+    let mut drawOrderToSetupIndex: *const c_int = core::ptr::null::<c_int>();
     let mut self_0: *mut spDrawOrderTimeline = timeline.cast::<spDrawOrderTimeline>();
     let mut frames: *mut c_float = (*(*self_0).super_0.frames).items;
     if direction as c_uint == SP_MIX_DIRECTION_OUT as c_int as c_uint {
@@ -5811,7 +5811,7 @@ pub unsafe extern "C" fn spDrawOrderTimeline_setFrame(
     _spFree((*((*self_0).drawOrders).offset(frame as isize)).cast::<c_void>());
     if drawOrder.is_null() {
         let fresh10 = &mut (*((*self_0).drawOrders).offset(frame as isize));
-        *fresh10 = std::ptr::null_mut::<c_int>();
+        *fresh10 = core::ptr::null_mut::<c_int>();
     } else {
         let fresh11 = &mut (*((*self_0).drawOrders).offset(frame as isize));
         *fresh11 = _spMalloc(
@@ -5935,7 +5935,7 @@ pub unsafe extern "C" fn _spIkConstraintTimeline_apply(
     let mut mix: c_float = 0.;
     let mut softness: c_float = 0.;
     let mut t: c_float = 0.;
-    let mut constraint: *mut spIkConstraint = std::ptr::null_mut::<spIkConstraint>();
+    let mut constraint: *mut spIkConstraint = core::ptr::null_mut::<spIkConstraint>();
     let mut self_0: *mut spIkConstraintTimeline = timeline.cast::<spIkConstraintTimeline>();
     let mut frames: *mut c_float = (*(*self_0).super_0.super_0.frames).items;
     let mut curves: *mut c_float = (*(*self_0).super_0.curves).items;
@@ -6143,13 +6143,13 @@ pub unsafe extern "C" fn _spTransformConstraintTimeline_apply(
     let mut scaleY: c_float = 0.;
     let mut shearY: c_float = 0.;
     let mut t: c_float = 0.;
-    let mut constraint: *mut spTransformConstraint = std::ptr::null_mut::<spTransformConstraint>();
+    let mut constraint: *mut spTransformConstraint = core::ptr::null_mut::<spTransformConstraint>();
     let mut self_0: *mut spTransformConstraintTimeline =
         timeline.cast::<spTransformConstraintTimeline>();
-    let mut frames: *mut c_float = std::ptr::null_mut::<c_float>();
-    let mut curves: *mut c_float = std::ptr::null_mut::<c_float>();
+    let mut frames: *mut c_float = core::ptr::null_mut::<c_float>();
+    let mut curves: *mut c_float = core::ptr::null_mut::<c_float>();
     let mut data: *mut spTransformConstraintData =
-        std::ptr::null_mut::<spTransformConstraintData>();
+        core::ptr::null_mut::<spTransformConstraintData>();
     constraint =
         *((*skeleton).transformConstraints).offset((*self_0).transformConstraintIndex as isize);
     if (*constraint).active == 0 {
@@ -6601,11 +6601,11 @@ pub unsafe extern "C" fn _spPathConstraintMixTimeline_apply(
     let mut x: c_float = 0.;
     let mut y: c_float = 0.;
     let mut t: c_float = 0.;
-    let mut constraint: *mut spPathConstraint = std::ptr::null_mut::<spPathConstraint>();
+    let mut constraint: *mut spPathConstraint = core::ptr::null_mut::<spPathConstraint>();
     let mut self_0: *mut spPathConstraintMixTimeline =
         timeline.cast::<spPathConstraintMixTimeline>();
-    let mut frames: *mut c_float = std::ptr::null_mut::<c_float>();
-    let mut curves: *mut c_float = std::ptr::null_mut::<c_float>();
+    let mut frames: *mut c_float = core::ptr::null_mut::<c_float>();
+    let mut curves: *mut c_float = core::ptr::null_mut::<c_float>();
     constraint = *((*skeleton).pathConstraints).offset((*self_0).pathConstraintIndex as isize);
     if (*constraint).active == 0 {
         return;
@@ -7025,7 +7025,7 @@ pub unsafe extern "C" fn _spPhysicsConstraintResetTimeline_apply(
 ) {
     let mut self_0: *mut spPhysicsConstraintResetTimeline =
         timeline.cast::<spPhysicsConstraintResetTimeline>();
-    let mut constraint: *mut spPhysicsConstraint = std::ptr::null_mut::<spPhysicsConstraint>();
+    let mut constraint: *mut spPhysicsConstraint = core::ptr::null_mut::<spPhysicsConstraint>();
     if (*self_0).physicsConstraintIndex != -(1 as c_int) {
         constraint =
             *((*skeleton).physicsConstraints).offset((*self_0).physicsConstraintIndex as isize);
@@ -7040,8 +7040,8 @@ pub unsafe extern "C" fn _spPhysicsConstraintResetTimeline_apply(
             skeleton,
             lastTime,
             0x7fffffff as c_int as c_float,
-            std::ptr::null_mut::<*mut spEvent>(),
-            std::ptr::null_mut::<c_int>(),
+            core::ptr::null_mut::<*mut spEvent>(),
+            core::ptr::null_mut::<c_int>(),
             alpha,
             blend,
             direction,
@@ -7298,7 +7298,7 @@ pub unsafe extern "C" fn spAnimationState_disposeStatics() {
     if !SP_EMPTY_ANIMATION.is_null() {
         spAnimation_dispose(SP_EMPTY_ANIMATION);
     }
-    SP_EMPTY_ANIMATION = std::ptr::null_mut::<spAnimation>();
+    SP_EMPTY_ANIMATION = core::ptr::null_mut::<spAnimation>();
 }
 #[no_mangle]
 pub unsafe extern "C" fn _spEventQueue_create(
@@ -7335,7 +7335,7 @@ pub unsafe extern "C" fn _spEventQueue_ensureCapacity(
     mut newElements: c_int,
 ) {
     if (*self_0).objectsCount + newElements > (*self_0).objectsCapacity {
-        let mut newObjects: *mut _spEventQueueItem = std::ptr::null_mut::<_spEventQueueItem>();
+        let mut newObjects: *mut _spEventQueueItem = core::ptr::null_mut::<_spEventQueueItem>();
         (*self_0).objectsCapacity <<= 1 as c_int;
         newObjects = _spCalloc(
             (*self_0).objectsCapacity as size_t,
@@ -7455,7 +7455,7 @@ pub unsafe extern "C" fn _spEventQueue_drain(mut self_0: *mut _spEventQueue) {
             (*((*self_0).objects).offset(i as isize)).type_0 as spEventType;
         let mut entry: *mut spTrackEntry =
             (*((*self_0).objects).offset((i + 1 as c_int) as isize)).entry;
-        let mut event: *mut spEvent = std::ptr::null_mut::<spEvent>();
+        let mut event: *mut spEvent = core::ptr::null_mut::<spEvent>();
         let mut current_block_22: u64;
         match type_0 as c_uint {
             0 | 1 | 3 => {
@@ -7464,7 +7464,7 @@ pub unsafe extern "C" fn _spEventQueue_drain(mut self_0: *mut _spEventQueue) {
                         &mut (*(*self_0).state).super_0,
                         type_0,
                         entry,
-                        std::ptr::null_mut::<spEvent>(),
+                        core::ptr::null_mut::<spEvent>(),
                     );
                 }
                 if ((*(*self_0).state).super_0.listener).is_some() {
@@ -7472,7 +7472,7 @@ pub unsafe extern "C" fn _spEventQueue_drain(mut self_0: *mut _spEventQueue) {
                         &mut (*(*self_0).state).super_0,
                         type_0,
                         entry,
-                        std::ptr::null_mut::<spEvent>(),
+                        core::ptr::null_mut::<spEvent>(),
                     );
                 }
                 current_block_22 = 10043043949733653460;
@@ -7483,7 +7483,7 @@ pub unsafe extern "C" fn _spEventQueue_drain(mut self_0: *mut _spEventQueue) {
                         &mut (*(*self_0).state).super_0,
                         type_0,
                         entry,
-                        std::ptr::null_mut::<spEvent>(),
+                        core::ptr::null_mut::<spEvent>(),
                     );
                 }
                 if ((*(*self_0).state).super_0.listener).is_some() {
@@ -7491,7 +7491,7 @@ pub unsafe extern "C" fn _spEventQueue_drain(mut self_0: *mut _spEventQueue) {
                         &mut (*(*self_0).state).super_0,
                         type_0,
                         entry,
-                        std::ptr::null_mut::<spEvent>(),
+                        core::ptr::null_mut::<spEvent>(),
                     );
                 }
                 current_block_22 = 8217970944285102440;
@@ -7531,7 +7531,7 @@ pub unsafe extern "C" fn _spEventQueue_drain(mut self_0: *mut _spEventQueue) {
                         &mut (*(*self_0).state).super_0,
                         SP_ANIMATION_DISPOSE,
                         entry,
-                        std::ptr::null_mut::<spEvent>(),
+                        core::ptr::null_mut::<spEvent>(),
                     );
                 }
                 if ((*(*self_0).state).super_0.listener).is_some() {
@@ -7539,7 +7539,7 @@ pub unsafe extern "C" fn _spEventQueue_drain(mut self_0: *mut _spEventQueue) {
                         &mut (*(*self_0).state).super_0,
                         SP_ANIMATION_DISPOSE,
                         entry,
-                        std::ptr::null_mut::<spEvent>(),
+                        core::ptr::null_mut::<spEvent>(),
                     );
                 }
                 _spAnimationState_disposeTrackEntry(entry);
@@ -7583,7 +7583,7 @@ pub unsafe extern "C" fn _spAnimationState_disposeTrackEntries(
                     state,
                     SP_ANIMATION_DISPOSE,
                     from,
-                    std::ptr::null_mut::<spEvent>(),
+                    core::ptr::null_mut::<spEvent>(),
                 );
             }
             if ((*state).listener).is_some() {
@@ -7591,7 +7591,7 @@ pub unsafe extern "C" fn _spAnimationState_disposeTrackEntries(
                     state,
                     SP_ANIMATION_DISPOSE,
                     from,
-                    std::ptr::null_mut::<spEvent>(),
+                    core::ptr::null_mut::<spEvent>(),
                 );
             }
             _spAnimationState_disposeTrackEntry(from);
@@ -7602,7 +7602,7 @@ pub unsafe extern "C" fn _spAnimationState_disposeTrackEntries(
                 state,
                 SP_ANIMATION_DISPOSE,
                 entry,
-                std::ptr::null_mut::<spEvent>(),
+                core::ptr::null_mut::<spEvent>(),
             );
         }
         if ((*state).listener).is_some() {
@@ -7610,7 +7610,7 @@ pub unsafe extern "C" fn _spAnimationState_disposeTrackEntries(
                 state,
                 SP_ANIMATION_DISPOSE,
                 entry,
-                std::ptr::null_mut::<spEvent>(),
+                core::ptr::null_mut::<spEvent>(),
             );
         }
         _spAnimationState_disposeTrackEntry(entry);
@@ -7621,13 +7621,13 @@ pub unsafe extern "C" fn _spAnimationState_disposeTrackEntries(
 pub unsafe extern "C" fn spAnimationState_create(
     mut data: *mut spAnimationStateData,
 ) -> *mut spAnimationState {
-    let mut internal: *mut _spAnimationState = std::ptr::null_mut::<_spAnimationState>();
-    let mut self_0: *mut spAnimationState = std::ptr::null_mut::<spAnimationState>();
+    let mut internal: *mut _spAnimationState = core::ptr::null_mut::<_spAnimationState>();
+    let mut self_0: *mut spAnimationState = core::ptr::null_mut::<spAnimationState>();
     if SP_EMPTY_ANIMATION.is_null() {
         SP_EMPTY_ANIMATION = 1 as c_int as *mut spAnimation;
         SP_EMPTY_ANIMATION = spAnimation_create(
             (b"<empty>\0" as *const u8).cast::<c_char>(),
-            std::ptr::null_mut::<spTimelineArray>(),
+            core::ptr::null_mut::<spTimelineArray>(),
             0 as c_int as c_float,
         );
     }
@@ -7689,7 +7689,7 @@ pub unsafe extern "C" fn spAnimationState_update(
     while i < n {
         let mut currentDelta: c_float = 0.;
         let mut current: *mut spTrackEntry = *((*self_0).tracks).offset(i as isize);
-        let mut next: *mut spTrackEntry = std::ptr::null_mut::<spTrackEntry>();
+        let mut next: *mut spTrackEntry = core::ptr::null_mut::<spTrackEntry>();
         if !current.is_null() {
             (*current).animationLast = (*current).nextAnimationLast;
             (*current).trackLast = (*current).nextTrackLast;
@@ -7733,7 +7733,7 @@ pub unsafe extern "C" fn spAnimationState_update(
                         && ((*current).mixingFrom).is_null()
                     {
                         let fresh19 = &mut (*((*self_0).tracks).offset(i as isize));
-                        *fresh19 = std::ptr::null_mut::<spTrackEntry>();
+                        *fresh19 = core::ptr::null_mut::<spTrackEntry>();
                         _spEventQueue_end((*internal).queue, current);
                         spAnimationState_clearNext(self_0, current);
                         current_block_29 = 16559507199688588974;
@@ -7747,9 +7747,9 @@ pub unsafe extern "C" fn spAnimationState_update(
                                 && _spAnimationState_updateMixingFrom(self_0, current, delta) != 0
                             {
                                 let mut from: *mut spTrackEntry = (*current).mixingFrom;
-                                (*current).mixingFrom = std::ptr::null_mut::<spTrackEntry>();
+                                (*current).mixingFrom = core::ptr::null_mut::<spTrackEntry>();
                                 if !from.is_null() {
-                                    (*from).mixingTo = std::ptr::null_mut::<spTrackEntry>();
+                                    (*from).mixingTo = core::ptr::null_mut::<spTrackEntry>();
                                 }
                                 while !from.is_null() {
                                     _spEventQueue_end((*internal).queue, from);
@@ -7803,26 +7803,26 @@ pub unsafe extern "C" fn spAnimationState_apply(
     mut skeleton: *mut spSkeleton,
 ) -> c_int {
     let mut internal: *mut _spAnimationState = self_0.cast::<_spAnimationState>();
-    let mut current: *mut spTrackEntry = std::ptr::null_mut::<spTrackEntry>();
+    let mut current: *mut spTrackEntry = core::ptr::null_mut::<spTrackEntry>();
     let mut i: c_int = 0;
     let mut ii: c_int = 0;
     let mut n: c_int = 0;
     let mut animationLast: c_float = 0.;
     let mut animationTime: c_float = 0.;
     let mut timelineCount: c_int = 0;
-    let mut timelines: *mut *mut spTimeline = std::ptr::null_mut::<*mut spTimeline>();
+    let mut timelines: *mut *mut spTimeline = core::ptr::null_mut::<*mut spTimeline>();
     let mut firstFrame: c_int = 0;
     let mut shortestRotation: c_int = 0;
-    let mut timelinesRotation: *mut c_float = std::ptr::null_mut::<c_float>();
-    let mut timeline: *mut spTimeline = std::ptr::null_mut::<spTimeline>();
+    let mut timelinesRotation: *mut c_float = core::ptr::null_mut::<c_float>();
+    let mut timeline: *mut spTimeline = core::ptr::null_mut::<spTimeline>();
     let mut applied: c_int = 0 as c_int;
     let mut blend: spMixBlend = SP_MIX_BLEND_SETUP;
     let mut timelineBlend: spMixBlend = SP_MIX_BLEND_SETUP;
     let mut setupState: c_int = 0 as c_int;
-    let mut slots: *mut *mut spSlot = std::ptr::null_mut::<*mut spSlot>();
-    let mut slot: *mut spSlot = std::ptr::null_mut::<spSlot>();
-    let mut attachmentName: *const c_char = std::ptr::null::<c_char>();
-    let mut applyEvents: *mut *mut spEvent = std::ptr::null_mut::<*mut spEvent>();
+    let mut slots: *mut *mut spSlot = core::ptr::null_mut::<*mut spSlot>();
+    let mut slot: *mut spSlot = core::ptr::null_mut::<spSlot>();
+    let mut attachmentName: *const c_char = core::ptr::null::<c_char>();
+    let mut applyEvents: *mut *mut spEvent = core::ptr::null_mut::<*mut spEvent>();
     let mut applyTime: c_float = 0.;
     if (*internal).animationsChanged != 0 {
         _spAnimationState_animationsChanged(self_0);
@@ -7853,7 +7853,7 @@ pub unsafe extern "C" fn spAnimationState_apply(
             applyTime = animationTime;
             if (*current).reverse != 0 {
                 applyTime = (*(*current).animation).duration - applyTime;
-                applyEvents = std::ptr::null_mut::<*mut spEvent>();
+                applyEvents = core::ptr::null_mut::<*mut spEvent>();
             }
             timelines = (*(*(*current).animation).timelines).items;
             if i == 0 as c_int && alpha == 1 as c_int as c_float
@@ -7963,7 +7963,7 @@ pub unsafe extern "C" fn spAnimationState_apply(
             spSlot_setAttachment(
                 slot,
                 if attachmentName.is_null() {
-                    std::ptr::null_mut::<spAttachment>()
+                    core::ptr::null_mut::<spAttachment>()
                 } else {
                     spSkeleton_getAttachmentForSlotIndex(
                         skeleton,
@@ -7988,24 +7988,24 @@ pub unsafe extern "C" fn _spAnimationState_applyMixingFrom(
 ) -> c_float {
     let mut internal: *mut _spAnimationState = self_0.cast::<_spAnimationState>();
     let mut mix: c_float = 0.;
-    let mut events: *mut *mut spEvent = std::ptr::null_mut::<*mut spEvent>();
+    let mut events: *mut *mut spEvent = core::ptr::null_mut::<*mut spEvent>();
     let mut attachments: c_int = 0;
     let mut drawOrder: c_int = 0;
     let mut animationLast: c_float = 0.;
     let mut animationTime: c_float = 0.;
     let mut timelineCount: c_int = 0;
-    let mut timelines: *mut *mut spTimeline = std::ptr::null_mut::<*mut spTimeline>();
-    let mut timelineMode: *mut spIntArray = std::ptr::null_mut::<spIntArray>();
-    let mut timelineHoldMix: *mut spTrackEntryArray = std::ptr::null_mut::<spTrackEntryArray>();
+    let mut timelines: *mut *mut spTimeline = core::ptr::null_mut::<*mut spTimeline>();
+    let mut timelineMode: *mut spIntArray = core::ptr::null_mut::<spIntArray>();
+    let mut timelineHoldMix: *mut spTrackEntryArray = core::ptr::null_mut::<spTrackEntryArray>();
     let mut timelineBlend: spMixBlend = SP_MIX_BLEND_SETUP;
     let mut alphaHold: c_float = 0.;
     let mut alphaMix: c_float = 0.;
     let mut alpha: c_float = 0.;
     let mut firstFrame: c_int = 0;
     let mut shortestRotation: c_int = 0;
-    let mut timelinesRotation: *mut c_float = std::ptr::null_mut::<c_float>();
+    let mut timelinesRotation: *mut c_float = core::ptr::null_mut::<c_float>();
     let mut i: c_int = 0;
-    let mut holdMix: *mut spTrackEntry = std::ptr::null_mut::<spTrackEntry>();
+    let mut holdMix: *mut spTrackEntry = core::ptr::null_mut::<spTrackEntry>();
     let mut applyTime: c_float = 0.;
     let mut from: *mut spTrackEntry = (*to).mixingFrom;
     if !((*from).mixingFrom).is_null() {
@@ -8034,7 +8034,7 @@ pub unsafe extern "C" fn _spAnimationState_applyMixingFrom(
     animationLast = (*from).animationLast;
     animationTime = spTrackEntry_getAnimationTime(from);
     applyTime = animationTime;
-    events = std::ptr::null_mut::<*mut spEvent>();
+    events = core::ptr::null_mut::<*mut spEvent>();
     if (*from).reverse != 0 {
         applyTime = (*(*from).animation).duration - applyTime;
     } else if mix < (*from).eventThreshold {
@@ -8189,7 +8189,7 @@ unsafe extern "C" fn _spAnimationState_setAttachment(
     spSlot_setAttachment(
         slot,
         if attachmentName.is_null() {
-            std::ptr::null_mut::<spAttachment>()
+            core::ptr::null_mut::<spAttachment>()
         } else {
             spSkeleton_getAttachmentForSlotIndex(skeleton, (*(*slot).data).index, attachmentName)
         },
@@ -8223,9 +8223,9 @@ pub unsafe extern "C" fn _spAnimationState_applyAttachmentTimeline(
     mut attachments: c_int,
 ) {
     let mut attachmentTimeline: *mut spAttachmentTimeline =
-        std::ptr::null_mut::<spAttachmentTimeline>();
-    let mut slot: *mut spSlot = std::ptr::null_mut::<spSlot>();
-    let mut frames: *mut c_float = std::ptr::null_mut::<c_float>();
+        core::ptr::null_mut::<spAttachmentTimeline>();
+    let mut slot: *mut spSlot = core::ptr::null_mut::<spSlot>();
+    let mut frames: *mut c_float = core::ptr::null_mut::<c_float>();
     attachmentTimeline = timeline.cast::<spAttachmentTimeline>();
     slot = *((*skeleton).slots).offset((*attachmentTimeline).slotIndex as isize);
     if (*(*slot).bone).active == 0 {
@@ -8273,9 +8273,9 @@ pub unsafe extern "C" fn _spAnimationState_applyRotateTimeline(
     mut i: c_int,
     mut firstFrame: c_int,
 ) {
-    let mut rotateTimeline: *mut spRotateTimeline = std::ptr::null_mut::<spRotateTimeline>();
-    let mut frames: *mut c_float = std::ptr::null_mut::<c_float>();
-    let mut bone: *mut spBone = std::ptr::null_mut::<spBone>();
+    let mut rotateTimeline: *mut spRotateTimeline = core::ptr::null_mut::<spRotateTimeline>();
+    let mut frames: *mut c_float = core::ptr::null_mut::<c_float>();
+    let mut bone: *mut spBone = core::ptr::null_mut::<spBone>();
     let mut r1: c_float = 0.;
     let mut r2: c_float = 0.;
     let mut total: c_float = 0.;
@@ -8291,8 +8291,8 @@ pub unsafe extern "C" fn _spAnimationState_applyRotateTimeline(
             skeleton,
             0 as c_int as c_float,
             time,
-            std::ptr::null_mut::<*mut spEvent>(),
-            std::ptr::null_mut::<c_int>(),
+            core::ptr::null_mut::<*mut spEvent>(),
+            core::ptr::null_mut::<c_int>(),
             1 as c_int as c_float,
             blend,
             SP_MIX_DIRECTION_IN,
@@ -8417,8 +8417,8 @@ pub unsafe extern "C" fn _spAnimationState_queueEvents(
     mut entry: *mut spTrackEntry,
     mut animationTime: c_float,
 ) {
-    let mut events: *mut *mut spEvent = std::ptr::null_mut::<*mut spEvent>();
-    let mut event: *mut spEvent = std::ptr::null_mut::<spEvent>();
+    let mut events: *mut *mut spEvent = core::ptr::null_mut::<*mut spEvent>();
+    let mut event: *mut spEvent = core::ptr::null_mut::<spEvent>();
     let mut internal: *mut _spAnimationState = self_0.cast::<_spAnimationState>();
     let mut i: c_int = 0;
     let mut n: c_int = 0;
@@ -8486,9 +8486,9 @@ pub unsafe extern "C" fn spAnimationState_clearTrack(
     mut self_0: *mut spAnimationState,
     mut trackIndex: c_int,
 ) {
-    let mut current: *mut spTrackEntry = std::ptr::null_mut::<spTrackEntry>();
-    let mut entry: *mut spTrackEntry = std::ptr::null_mut::<spTrackEntry>();
-    let mut from: *mut spTrackEntry = std::ptr::null_mut::<spTrackEntry>();
+    let mut current: *mut spTrackEntry = core::ptr::null_mut::<spTrackEntry>();
+    let mut entry: *mut spTrackEntry = core::ptr::null_mut::<spTrackEntry>();
+    let mut from: *mut spTrackEntry = core::ptr::null_mut::<spTrackEntry>();
     let mut internal: *mut _spAnimationState = self_0.cast::<_spAnimationState>();
     if trackIndex >= (*self_0).tracksCount {
         return;
@@ -8506,12 +8506,12 @@ pub unsafe extern "C" fn spAnimationState_clearTrack(
             break;
         }
         _spEventQueue_end((*internal).queue, from);
-        (*entry).mixingFrom = std::ptr::null_mut::<spTrackEntry>();
-        (*entry).mixingTo = std::ptr::null_mut::<spTrackEntry>();
+        (*entry).mixingFrom = core::ptr::null_mut::<spTrackEntry>();
+        (*entry).mixingTo = core::ptr::null_mut::<spTrackEntry>();
         entry = from;
     }
     let fresh20 = &mut (*((*self_0).tracks).offset((*current).trackIndex as isize));
-    *fresh20 = std::ptr::null_mut::<spTrackEntry>();
+    *fresh20 = core::ptr::null_mut::<spTrackEntry>();
     _spEventQueue_drain((*internal).queue);
 }
 #[no_mangle]
@@ -8525,7 +8525,7 @@ pub unsafe extern "C" fn _spAnimationState_setCurrent(
     let mut from: *mut spTrackEntry = _spAnimationState_expandToIndex(self_0, index);
     let fresh21 = &mut (*((*self_0).tracks).offset(index as isize));
     *fresh21 = current;
-    (*current).previous = std::ptr::null_mut::<spTrackEntry>();
+    (*current).previous = core::ptr::null_mut::<spTrackEntry>();
     if !from.is_null() {
         if interrupt != 0 {
             _spEventQueue_interrupt((*internal).queue, from);
@@ -8563,7 +8563,7 @@ pub unsafe extern "C" fn spAnimationState_setAnimation(
     mut animation: *mut spAnimation,
     mut loop_0: c_int,
 ) -> *mut spTrackEntry {
-    let mut entry: *mut spTrackEntry = std::ptr::null_mut::<spTrackEntry>();
+    let mut entry: *mut spTrackEntry = core::ptr::null_mut::<spTrackEntry>();
     let mut internal: *mut _spAnimationState = self_0.cast::<_spAnimationState>();
     let mut interrupt: c_int = 1 as c_int;
     let mut current: *mut spTrackEntry = _spAnimationState_expandToIndex(self_0, trackIndex);
@@ -8605,7 +8605,7 @@ pub unsafe extern "C" fn spAnimationState_addAnimation(
     mut loop_0: c_int,
     mut delay: c_float,
 ) -> *mut spTrackEntry {
-    let mut entry: *mut spTrackEntry = std::ptr::null_mut::<spTrackEntry>();
+    let mut entry: *mut spTrackEntry = core::ptr::null_mut::<spTrackEntry>();
     let mut internal: *mut _spAnimationState = self_0.cast::<_spAnimationState>();
     let mut last: *mut spTrackEntry = _spAnimationState_expandToIndex(self_0, trackIndex);
     if !last.is_null() {
@@ -8663,7 +8663,7 @@ pub unsafe extern "C" fn spAnimationState_setEmptyAnimations(
     let mut i: c_int = 0;
     let mut n: c_int = 0;
     let mut oldDrainDisabled: c_int = 0;
-    let mut current: *mut spTrackEntry = std::ptr::null_mut::<spTrackEntry>();
+    let mut current: *mut spTrackEntry = core::ptr::null_mut::<spTrackEntry>();
     let mut internal: *mut _spAnimationState = self_0.cast::<_spAnimationState>();
     oldDrainDisabled = (*(*internal).queue).drainDisabled;
     (*(*internal).queue).drainDisabled = 1 as c_int;
@@ -8684,7 +8684,7 @@ pub unsafe extern "C" fn _spAnimationState_expandToIndex(
     mut self_0: *mut spAnimationState,
     mut index: c_int,
 ) -> *mut spTrackEntry {
-    let mut newTracks: *mut *mut spTrackEntry = std::ptr::null_mut::<*mut spTrackEntry>();
+    let mut newTracks: *mut *mut spTrackEntry = core::ptr::null_mut::<*mut spTrackEntry>();
     if index < (*self_0).tracksCount {
         return *((*self_0).tracks).offset(index as isize);
     }
@@ -8704,7 +8704,7 @@ pub unsafe extern "C" fn _spAnimationState_expandToIndex(
     _spFree((*self_0).tracks.cast::<c_void>());
     (*self_0).tracks = newTracks;
     (*self_0).tracksCount = index + 1 as c_int;
-    std::ptr::null_mut::<spTrackEntry>()
+    core::ptr::null_mut::<spTrackEntry>()
 }
 #[no_mangle]
 pub unsafe extern "C" fn _spAnimationState_trackEntry(
@@ -8727,8 +8727,8 @@ pub unsafe extern "C" fn _spAnimationState_trackEntry(
     (*entry).holdPrevious = 0 as c_int;
     (*entry).reverse = 0 as c_int;
     (*entry).shortestRotation = 0 as c_int;
-    (*entry).previous = std::ptr::null_mut::<spTrackEntry>();
-    (*entry).next = std::ptr::null_mut::<spTrackEntry>();
+    (*entry).previous = core::ptr::null_mut::<spTrackEntry>();
+    (*entry).next = core::ptr::null_mut::<spTrackEntry>();
     (*entry).eventThreshold = 0 as c_int as c_float;
     (*entry).mixAttachmentThreshold = 0 as c_int as c_float;
     (*entry).alphaAttachmentThreshold = 0 as c_int as c_float;
@@ -8768,14 +8768,14 @@ pub unsafe extern "C" fn spAnimationState_clearNext(
         _spEventQueue_dispose((*internal).queue, next);
         next = (*next).next;
     }
-    (*entry).next = std::ptr::null_mut::<spTrackEntry>();
+    (*entry).next = core::ptr::null_mut::<spTrackEntry>();
 }
 #[no_mangle]
 pub unsafe extern "C" fn _spAnimationState_animationsChanged(mut self_0: *mut spAnimationState) {
     let mut internal: *mut _spAnimationState = self_0.cast::<_spAnimationState>();
     let mut i: c_int = 0;
     let mut n: c_int = 0;
-    let mut entry: *mut spTrackEntry = std::ptr::null_mut::<spTrackEntry>();
+    let mut entry: *mut spTrackEntry = core::ptr::null_mut::<spTrackEntry>();
     (*internal).animationsChanged = 0 as c_int;
     (*internal).propertyIDsCount = 0 as c_int;
     i = 0 as c_int;
@@ -8890,7 +8890,7 @@ pub unsafe extern "C" fn spAnimationState_getCurrent(
     mut trackIndex: c_int,
 ) -> *mut spTrackEntry {
     if trackIndex >= (*self_0).tracksCount {
-        return std::ptr::null_mut::<spTrackEntry>();
+        return core::ptr::null_mut::<spTrackEntry>();
     }
     *((*self_0).tracks).offset(trackIndex as isize)
 }
@@ -8919,7 +8919,7 @@ pub unsafe extern "C" fn spTrackEntry_getAnimationTime(mut entry: *mut spTrackEn
 #[no_mangle]
 pub unsafe extern "C" fn spTrackEntry_resetRotationDirections(mut entry: *mut spTrackEntry) {
     _spFree((*entry).timelinesRotation.cast::<c_void>());
-    (*entry).timelinesRotation = std::ptr::null_mut::<c_float>();
+    (*entry).timelinesRotation = core::ptr::null_mut::<c_float>();
     (*entry).timelinesRotationCount = 0 as c_int;
 }
 #[no_mangle]
@@ -8956,12 +8956,12 @@ pub unsafe extern "C" fn _spTrackEntry_computeHold(
     mut entry: *mut spTrackEntry,
     mut state: *mut spAnimationState,
 ) {
-    let mut to: *mut spTrackEntry = std::ptr::null_mut::<spTrackEntry>();
-    let mut timelines: *mut *mut spTimeline = std::ptr::null_mut::<*mut spTimeline>();
+    let mut to: *mut spTrackEntry = core::ptr::null_mut::<spTrackEntry>();
+    let mut timelines: *mut *mut spTimeline = core::ptr::null_mut::<*mut spTimeline>();
     let mut timelinesCount: c_int = 0;
-    let mut timelineMode: *mut c_int = std::ptr::null_mut::<c_int>();
-    let mut timelineHoldMix: *mut *mut spTrackEntry = std::ptr::null_mut::<*mut spTrackEntry>();
-    let mut next: *mut spTrackEntry = std::ptr::null_mut::<spTrackEntry>();
+    let mut timelineMode: *mut c_int = core::ptr::null_mut::<c_int>();
+    let mut timelineHoldMix: *mut *mut spTrackEntry = core::ptr::null_mut::<*mut spTrackEntry>();
+    let mut next: *mut spTrackEntry = core::ptr::null_mut::<spTrackEntry>();
     let mut i: c_int = 0;
     to = (*entry).mixingTo;
     timelines = (*(*(*entry).animation).timelines).items;
@@ -9072,9 +9072,9 @@ pub unsafe extern "C" fn spAnimationStateData_create(
 }
 #[no_mangle]
 pub unsafe extern "C" fn spAnimationStateData_dispose(mut self_0: *mut spAnimationStateData) {
-    let mut toEntry: *mut _ToEntry = std::ptr::null_mut::<_ToEntry>();
-    let mut nextToEntry: *mut _ToEntry = std::ptr::null_mut::<_ToEntry>();
-    let mut nextFromEntry: *mut _FromEntry = std::ptr::null_mut::<_FromEntry>();
+    let mut toEntry: *mut _ToEntry = core::ptr::null_mut::<_ToEntry>();
+    let mut nextToEntry: *mut _ToEntry = core::ptr::null_mut::<_ToEntry>();
+    let mut nextFromEntry: *mut _FromEntry = core::ptr::null_mut::<_FromEntry>();
     let mut fromEntry: *mut _FromEntry = (*self_0).entries as *mut _FromEntry;
     while !fromEntry.is_null() {
         toEntry = (*fromEntry).toEntries;
@@ -9096,7 +9096,7 @@ pub unsafe extern "C" fn spAnimationStateData_setMixByName(
     mut toName: *const c_char,
     mut duration: c_float,
 ) {
-    let mut to: *mut spAnimation = std::ptr::null_mut::<spAnimation>();
+    let mut to: *mut spAnimation = core::ptr::null_mut::<spAnimation>();
     let mut from: *mut spAnimation = spSkeletonData_findAnimation((*self_0).skeletonData, fromName);
     if from.is_null() {
         return;
@@ -9114,7 +9114,7 @@ pub unsafe extern "C" fn spAnimationStateData_setMix(
     mut to: *mut spAnimation,
     mut duration: c_float,
 ) {
-    let mut toEntry: *mut _ToEntry = std::ptr::null_mut::<_ToEntry>();
+    let mut toEntry: *mut _ToEntry = core::ptr::null_mut::<_ToEntry>();
     let mut fromEntry: *mut _FromEntry = (*self_0).entries as *mut _FromEntry;
     while !fromEntry.is_null() {
         if (*fromEntry).animation == from {
@@ -10350,8 +10350,8 @@ unsafe extern "C" fn ss_substr(
     mut e: c_int,
 ) -> SimpleString {
     let mut result: SimpleString = SimpleString {
-        start: std::ptr::null_mut::<c_char>(),
-        end: std::ptr::null_mut::<c_char>(),
+        start: core::ptr::null_mut::<c_char>(),
+        end: core::ptr::null_mut::<c_char>(),
         length: 0,
     };
     e += s;
@@ -10362,8 +10362,8 @@ unsafe extern "C" fn ss_substr(
 }
 unsafe extern "C" fn ss_substr2(mut self_0: *mut SimpleString, mut s: c_int) -> SimpleString {
     let mut result: SimpleString = SimpleString {
-        start: std::ptr::null_mut::<c_char>(),
-        end: std::ptr::null_mut::<c_char>(),
+        start: core::ptr::null_mut::<c_char>(),
+        end: core::ptr::null_mut::<c_char>(),
         length: 0,
     };
     result.start = ((*self_0).start).offset(s as isize);
@@ -10407,7 +10407,7 @@ unsafe extern "C" fn ss_toInt(mut self_0: *mut SimpleString) -> c_int {
 }
 unsafe extern "C" fn ai_readLine(mut self_0: *mut AtlasInput) -> *mut SimpleString {
     if (*self_0).index >= (*self_0).end.cast_mut() {
-        return std::ptr::null_mut::<SimpleString>();
+        return core::ptr::null_mut::<SimpleString>();
     }
     (*self_0).line.start = (*self_0).index;
     while (*self_0).index < (*self_0).end.cast_mut() && *(*self_0).index as c_int != '\n' as i32 {
@@ -10430,8 +10430,8 @@ unsafe extern "C" fn ai_readEntry(
     let mut i: c_int = 0;
     let mut lastMatch: c_int = 0;
     let mut substr: SimpleString = SimpleString {
-        start: std::ptr::null_mut::<c_char>(),
-        end: std::ptr::null_mut::<c_char>(),
+        start: core::ptr::null_mut::<c_char>(),
+        end: core::ptr::null_mut::<c_char>(),
         length: 0,
     };
     if line.is_null() {
@@ -10508,27 +10508,27 @@ pub unsafe extern "C" fn spAtlas_create(
     mut dir: *const c_char,
     mut rendererObject: *mut c_void,
 ) -> *mut spAtlas {
-    let mut self_0: *mut spAtlas = std::ptr::null_mut::<spAtlas>();
+    let mut self_0: *mut spAtlas = core::ptr::null_mut::<spAtlas>();
     let mut reader: AtlasInput = AtlasInput {
-        start: std::ptr::null::<c_char>(),
-        end: std::ptr::null::<c_char>(),
-        index: std::ptr::null_mut::<c_char>(),
+        start: core::ptr::null::<c_char>(),
+        end: core::ptr::null::<c_char>(),
+        index: core::ptr::null_mut::<c_char>(),
         length: 0,
         line: SimpleString {
-            start: std::ptr::null_mut::<c_char>(),
-            end: std::ptr::null_mut::<c_char>(),
+            start: core::ptr::null_mut::<c_char>(),
+            end: core::ptr::null_mut::<c_char>(),
             length: 0,
         },
     };
-    let mut line: *mut SimpleString = std::ptr::null_mut::<SimpleString>();
+    let mut line: *mut SimpleString = core::ptr::null_mut::<SimpleString>();
     let mut entry: [SimpleString; 5] = [SimpleString {
-        start: std::ptr::null_mut::<c_char>(),
-        end: std::ptr::null_mut::<c_char>(),
+        start: core::ptr::null_mut::<c_char>(),
+        end: core::ptr::null_mut::<c_char>(),
         length: 0,
     }; 5];
-    let mut page: *mut spAtlasPage = std::ptr::null_mut::<spAtlasPage>();
-    let mut lastPage: *mut spAtlasPage = std::ptr::null_mut::<spAtlasPage>();
-    let mut lastRegion: *mut spAtlasRegion = std::ptr::null_mut::<spAtlasRegion>();
+    let mut page: *mut spAtlasPage = core::ptr::null_mut::<spAtlasPage>();
+    let mut lastPage: *mut spAtlasPage = core::ptr::null_mut::<spAtlasPage>();
+    let mut lastRegion: *mut spAtlasRegion = core::ptr::null_mut::<spAtlasRegion>();
     let mut count: c_int = 0;
     let mut dirLength: c_int = spine_strlen(dir) as c_int;
     let mut needsSlash: c_int = (dirLength > 0 as c_int
@@ -10565,7 +10565,7 @@ pub unsafe extern "C" fn spAtlas_create(
             break;
         }
         if (*ss_trim(line)).length == 0 as c_int {
-            page = std::ptr::null_mut::<spAtlasPage>();
+            page = core::ptr::null_mut::<spAtlasPage>();
             line = ai_readLine(&mut reader);
         } else if page.is_null() {
             let mut name: *mut c_char = ss_copy(line);
@@ -10772,7 +10772,7 @@ pub unsafe extern "C" fn spAtlas_create(
                 } else {
                     let mut i: c_int = 0 as c_int;
                     let mut keyValue: spKeyValue = spKeyValue {
-                        name: std::ptr::null_mut::<c_char>(),
+                        name: core::ptr::null_mut::<c_char>(),
                         values: [0.; 5],
                     };
                     keyValue.name = ss_copy(&mut *entry.as_mut_ptr().offset(0 as c_int as isize));
@@ -10815,10 +10815,10 @@ pub unsafe extern "C" fn spAtlas_createFromFile(
     mut rendererObject: *mut c_void,
 ) -> *mut spAtlas {
     let mut dirLength: c_int = 0;
-    let mut dir: *mut c_char = std::ptr::null_mut::<c_char>();
+    let mut dir: *mut c_char = core::ptr::null_mut::<c_char>();
     let mut length: c_int = 0;
-    let mut data: *const c_char = std::ptr::null::<c_char>();
-    let mut atlas: *mut spAtlas = std::ptr::null_mut::<spAtlas>();
+    let mut data: *const c_char = core::ptr::null::<c_char>();
+    let mut atlas: *mut spAtlas = core::ptr::null_mut::<spAtlas>();
     let mut lastForwardSlash: *const c_char = spine_strrchr(path, '/' as i32);
     let mut lastBackwardSlash: *const c_char = spine_strrchr(path, '\\' as i32);
     let mut lastSlash: *const c_char = if lastForwardSlash > lastBackwardSlash {
@@ -10857,8 +10857,8 @@ pub unsafe extern "C" fn spAtlas_createFromFile(
 }
 #[no_mangle]
 pub unsafe extern "C" fn spAtlas_dispose(mut self_0: *mut spAtlas) {
-    let mut region: *mut spAtlasRegion = std::ptr::null_mut::<spAtlasRegion>();
-    let mut nextRegion: *mut spAtlasRegion = std::ptr::null_mut::<spAtlasRegion>();
+    let mut region: *mut spAtlasRegion = core::ptr::null_mut::<spAtlasRegion>();
+    let mut nextRegion: *mut spAtlasRegion = core::ptr::null_mut::<spAtlasRegion>();
     let mut page: *mut spAtlasPage = (*self_0).pages;
     while !page.is_null() {
         let mut nextPage: *mut spAtlasPage = (*page).next;
@@ -10885,7 +10885,7 @@ pub unsafe extern "C" fn spAtlas_findRegion(
         }
         region = (*region).next;
     }
-    std::ptr::null_mut::<spAtlasRegion>()
+    core::ptr::null_mut::<spAtlasRegion>()
 }
 unsafe extern "C" fn loadSequence(
     mut atlas: *mut spAtlas,
@@ -10945,7 +10945,7 @@ pub unsafe extern "C" fn _spAtlasAttachmentLoader_createAttachment(
                             .cast::<c_char>(),
                         path,
                     );
-                    return std::ptr::null_mut::<spAttachment>();
+                    return core::ptr::null_mut::<spAttachment>();
                 }
             } else {
                 let mut region: *mut spAtlasRegion = spAtlas_findRegion((*self_0).atlas, path);
@@ -10956,7 +10956,7 @@ pub unsafe extern "C" fn _spAtlasAttachmentLoader_createAttachment(
                         (b"Region not found: \0" as *const u8).cast::<c_char>(),
                         path,
                     );
-                    return std::ptr::null_mut::<spAttachment>();
+                    return core::ptr::null_mut::<spAttachment>();
                 }
                 (*attachment).rendererObject = region.cast::<c_void>();
                 (*attachment).region = &mut (*region).super_0;
@@ -10974,7 +10974,7 @@ pub unsafe extern "C" fn _spAtlasAttachmentLoader_createAttachment(
                             .cast::<c_char>(),
                         path,
                     );
-                    return std::ptr::null_mut::<spAttachment>();
+                    return core::ptr::null_mut::<spAttachment>();
                 }
             } else {
                 let mut region_0: *mut spAtlasRegion = spAtlas_findRegion((*self_0).atlas, path);
@@ -10984,7 +10984,7 @@ pub unsafe extern "C" fn _spAtlasAttachmentLoader_createAttachment(
                         (b"Region not found: \0" as *const u8).cast::<c_char>(),
                         path,
                     );
-                    return std::ptr::null_mut::<spAttachment>();
+                    return core::ptr::null_mut::<spAttachment>();
                 }
                 (*attachment_0).rendererObject = region_0.cast::<c_void>();
                 (*attachment_0).region = &mut (*region_0).super_0;
@@ -11024,7 +11024,7 @@ pub unsafe extern "C" fn _spAtlasAttachmentLoader_createAttachment(
         }
         _ => {
             _spAttachmentLoader_setUnknownTypeError(loader, type_0);
-            std::ptr::null_mut::<spAttachment>()
+            core::ptr::null_mut::<spAttachment>()
         }
     }
 }
@@ -11170,8 +11170,8 @@ pub unsafe extern "C" fn spAttachmentLoader_createAttachment(
 ) -> *mut spAttachment {
     _spFree((*self_0).error1.cast::<c_void>());
     _spFree((*self_0).error2.cast::<c_void>());
-    (*self_0).error1 = std::ptr::null_mut::<c_char>();
-    (*self_0).error2 = std::ptr::null_mut::<c_char>();
+    (*self_0).error1 = core::ptr::null_mut::<c_char>();
+    (*self_0).error2 = core::ptr::null_mut::<c_char>();
     ((*((*self_0).vtable as *mut _spAttachmentLoaderVtable)).createAttachment)
         .expect("non-null function pointer")(self_0, skin, type_0, name, path, sequence)
 }
@@ -11728,7 +11728,7 @@ pub unsafe extern "C" fn spBoneData_create(
     (*self_0).scaleX = 1 as c_int as c_float;
     (*self_0).scaleY = 1 as c_int as c_float;
     (*self_0).inherit = SP_INHERIT_NORMAL;
-    (*self_0).icon = std::ptr::null::<c_char>();
+    (*self_0).icon = core::ptr::null::<c_char>();
     (*self_0).visible = -(1 as c_int);
     self_0
 }
@@ -11815,7 +11815,7 @@ pub unsafe extern "C" fn spClippingAttachment_create(
                 as unsafe extern "C" fn(*mut spAttachment) -> *mut spAttachment,
         ),
     );
-    (*self_0).endSlot = std::ptr::null_mut::<spSlotData>();
+    (*self_0).endSlot = core::ptr::null_mut::<spSlotData>();
     self_0
 }
 #[no_mangle]
@@ -12837,7 +12837,7 @@ unsafe extern "C" fn Json_new() -> *mut Json {
 }
 #[no_mangle]
 pub unsafe extern "C" fn Json_dispose(mut c: *mut Json) {
-    let mut next: *mut Json = std::ptr::null_mut::<Json>();
+    let mut next: *mut Json = core::ptr::null_mut::<Json>();
     while !c.is_null() {
         next = (*c).next;
         if !((*c).child).is_null() {
@@ -12906,7 +12906,7 @@ unsafe extern "C" fn parse_number(mut item: *mut Json, mut num: *const c_char) -
         ptr
     } else {
         ep = num;
-        std::ptr::null::<c_char>()
+        core::ptr::null::<c_char>()
     }
 }
 static mut firstByteMark: [c_uchar; 7] = [
@@ -12920,14 +12920,14 @@ static mut firstByteMark: [c_uchar; 7] = [
 ];
 unsafe extern "C" fn parse_string(mut item: *mut Json, mut str: *const c_char) -> *const c_char {
     let mut ptr: *const c_char = str.offset(1 as c_int as isize);
-    let mut ptr2: *mut c_char = std::ptr::null_mut::<c_char>();
-    let mut out: *mut c_char = std::ptr::null_mut::<c_char>();
+    let mut ptr2: *mut c_char = core::ptr::null_mut::<c_char>();
+    let mut out: *mut c_char = core::ptr::null_mut::<c_char>();
     let mut len: c_int = 0 as c_int;
     let mut uc: c_uint = 0;
     let mut uc2: c_uint = 0;
     if *str as c_int != '"' as i32 {
         ep = str;
-        return std::ptr::null::<c_char>();
+        return core::ptr::null::<c_char>();
     }
     while *ptr as c_int != '"' as i32 && *ptr as c_int != 0 && {
         len += 1;
@@ -12946,7 +12946,7 @@ unsafe extern "C" fn parse_string(mut item: *mut Json, mut str: *const c_char) -
     )
     .cast::<c_char>();
     if out.is_null() {
-        return std::ptr::null::<c_char>();
+        return core::ptr::null::<c_char>();
     }
     ptr = str.offset(1 as c_int as isize);
     ptr2 = out;
@@ -13112,7 +13112,7 @@ unsafe extern "C" fn parse_string(mut item: *mut Json, mut str: *const c_char) -
 }
 unsafe extern "C" fn skip(mut in_0: *const c_char) -> *const c_char {
     if in_0.is_null() {
-        return std::ptr::null::<c_char>();
+        return core::ptr::null::<c_char>();
     }
     while *in_0 as c_int != 0 && *in_0 as c_uchar as c_int <= 32 as c_int {
         in_0 = in_0.offset(1);
@@ -13121,19 +13121,19 @@ unsafe extern "C" fn skip(mut in_0: *const c_char) -> *const c_char {
 }
 #[no_mangle]
 pub unsafe extern "C" fn Json_create(mut value: *const c_char) -> *mut Json {
-    let mut c: *mut Json = std::ptr::null_mut::<Json>();
-    ep = std::ptr::null::<c_char>();
+    let mut c: *mut Json = core::ptr::null_mut::<Json>();
+    ep = core::ptr::null::<c_char>();
     if value.is_null() {
-        return std::ptr::null_mut::<Json>();
+        return core::ptr::null_mut::<Json>();
     }
     c = Json_new();
     if c.is_null() {
-        return std::ptr::null_mut::<Json>();
+        return core::ptr::null_mut::<Json>();
     }
     value = parse_value(c, skip(value));
     if value.is_null() {
         Json_dispose(c);
-        return std::ptr::null_mut::<Json>();
+        return core::ptr::null_mut::<Json>();
     }
     c
 }
@@ -13182,10 +13182,10 @@ unsafe extern "C" fn parse_value(mut item: *mut Json, mut value: *const c_char)
         _ => {}
     }
     ep = value;
-    std::ptr::null::<c_char>()
+    core::ptr::null::<c_char>()
 }
 unsafe extern "C" fn parse_array(mut item: *mut Json, mut value: *const c_char) -> *const c_char {
-    let mut child: *mut Json = std::ptr::null_mut::<Json>();
+    let mut child: *mut Json = core::ptr::null_mut::<Json>();
     (*item).type_0 = 5 as c_int;
     value = skip(value.offset(1 as c_int as isize));
     if *value as c_int == ']' as i32 {
@@ -13194,23 +13194,23 @@ unsafe extern "C" fn parse_array(mut item: *mut Json, mut value: *const c_char)
     child = Json_new();
     (*item).child = child;
     if ((*item).child).is_null() {
-        return std::ptr::null::<c_char>();
+        return core::ptr::null::<c_char>();
     }
     value = skip(parse_value(child, skip(value)));
     if value.is_null() {
-        return std::ptr::null::<c_char>();
+        return core::ptr::null::<c_char>();
     }
     (*item).size = 1 as c_int;
     while *value as c_int == ',' as i32 {
         let mut new_item: *mut Json = Json_new();
         if new_item.is_null() {
-            return std::ptr::null::<c_char>();
+            return core::ptr::null::<c_char>();
         }
         (*child).next = new_item;
         child = new_item;
         value = skip(parse_value(child, skip(value.offset(1 as c_int as isize))));
         if value.is_null() {
-            return std::ptr::null::<c_char>();
+            return core::ptr::null::<c_char>();
         }
         (*item).size += 1;
     }
@@ -13218,10 +13218,10 @@ unsafe extern "C" fn parse_array(mut item: *mut Json, mut value: *const c_char)
         return value.offset(1 as c_int as isize);
     }
     ep = value;
-    std::ptr::null::<c_char>()
+    core::ptr::null::<c_char>()
 }
 unsafe extern "C" fn parse_object(mut item: *mut Json, mut value: *const c_char) -> *const c_char {
-    let mut child: *mut Json = std::ptr::null_mut::<Json>();
+    let mut child: *mut Json = core::ptr::null_mut::<Json>();
     (*item).type_0 = 6 as c_int;
     value = skip(value.offset(1 as c_int as isize));
     if *value as c_int == '}' as i32 {
@@ -13230,43 +13230,43 @@ unsafe extern "C" fn parse_object(mut item: *mut Json, mut value: *const c_char)
     child = Json_new();
     (*item).child = child;
     if ((*item).child).is_null() {
-        return std::ptr::null::<c_char>();
+        return core::ptr::null::<c_char>();
     }
     value = skip(parse_string(child, skip(value)));
     if value.is_null() {
-        return std::ptr::null::<c_char>();
+        return core::ptr::null::<c_char>();
     }
     (*child).name = (*child).valueString;
-    (*child).valueString = std::ptr::null::<c_char>();
+    (*child).valueString = core::ptr::null::<c_char>();
     if *value as c_int != ':' as i32 {
         ep = value;
-        return std::ptr::null::<c_char>();
+        return core::ptr::null::<c_char>();
     }
     value = skip(parse_value(child, skip(value.offset(1 as c_int as isize))));
     if value.is_null() {
-        return std::ptr::null::<c_char>();
+        return core::ptr::null::<c_char>();
     }
     (*item).size = 1 as c_int;
     while *value as c_int == ',' as i32 {
         let mut new_item: *mut Json = Json_new();
         if new_item.is_null() {
-            return std::ptr::null::<c_char>();
+            return core::ptr::null::<c_char>();
         }
         (*child).next = new_item;
         child = new_item;
         value = skip(parse_string(child, skip(value.offset(1 as c_int as isize))));
         if value.is_null() {
-            return std::ptr::null::<c_char>();
+            return core::ptr::null::<c_char>();
         }
         (*child).name = (*child).valueString;
-        (*child).valueString = std::ptr::null::<c_char>();
+        (*child).valueString = core::ptr::null::<c_char>();
         if *value as c_int != ':' as i32 {
             ep = value;
-            return std::ptr::null::<c_char>();
+            return core::ptr::null::<c_char>();
         }
         value = skip(parse_value(child, skip(value.offset(1 as c_int as isize))));
         if value.is_null() {
-            return std::ptr::null::<c_char>();
+            return core::ptr::null::<c_char>();
         }
         (*item).size += 1;
     }
@@ -13274,7 +13274,7 @@ unsafe extern "C" fn parse_object(mut item: *mut Json, mut value: *const c_char)
         return value.offset(1 as c_int as isize);
     }
     ep = value;
-    std::ptr::null::<c_char>()
+    core::ptr::null::<c_char>()
 }
 #[no_mangle]
 pub unsafe extern "C" fn Json_getItem(
@@ -13359,7 +13359,7 @@ pub unsafe extern "C" fn _spMeshAttachment_dispose(mut attachment: *mut spAttach
 pub unsafe extern "C" fn _spMeshAttachment_copy(
     mut attachment: *mut spAttachment,
 ) -> *mut spAttachment {
-    let mut copy: *mut spMeshAttachment = std::ptr::null_mut::<spMeshAttachment>();
+    let mut copy: *mut spMeshAttachment = core::ptr::null_mut::<spMeshAttachment>();
     let mut self_0: *mut spMeshAttachment = attachment.cast::<spMeshAttachment>();
     if !((*self_0).parentMesh).is_null() {
         return &mut (*(spMeshAttachment_newLinkedMesh
@@ -13375,7 +13375,7 @@ pub unsafe extern "C" fn _spMeshAttachment_copy(
     (*copy).sequence = if !((*self_0).sequence).is_null() {
         spSequence_copy((*self_0).sequence)
     } else {
-        std::ptr::null_mut::<spSequence>()
+        core::ptr::null_mut::<spSequence>()
     };
     (*copy).path = _spMalloc(
         (::core::mem::size_of::<c_char>() as c_ulong)
@@ -13510,7 +13510,7 @@ pub unsafe extern "C" fn spMeshAttachment_create(mut name: *const c_char) -> *mu
 pub unsafe extern "C" fn spMeshAttachment_updateRegion(mut self_0: *mut spMeshAttachment) {
     let mut i: c_int = 0;
     let mut n: c_int = 0;
-    let mut uvs: *mut c_float = std::ptr::null_mut::<c_float>();
+    let mut uvs: *mut c_float = core::ptr::null_mut::<c_float>();
     let mut u: c_float = 0.;
     let mut v: c_float = 0.;
     let mut width: c_float = 0.;
@@ -13730,15 +13730,15 @@ pub unsafe extern "C" fn spPathConstraint_create(
     (*self_0).mixX = (*data).mixX;
     (*self_0).mixY = (*data).mixY;
     (*self_0).spacesCount = 0 as c_int;
-    (*self_0).spaces = std::ptr::null_mut::<c_float>();
+    (*self_0).spaces = core::ptr::null_mut::<c_float>();
     (*self_0).positionsCount = 0 as c_int;
-    (*self_0).positions = std::ptr::null_mut::<c_float>();
+    (*self_0).positions = core::ptr::null_mut::<c_float>();
     (*self_0).worldCount = 0 as c_int;
-    (*self_0).world = std::ptr::null_mut::<c_float>();
+    (*self_0).world = core::ptr::null_mut::<c_float>();
     (*self_0).curvesCount = 0 as c_int;
-    (*self_0).curves = std::ptr::null_mut::<c_float>();
+    (*self_0).curves = core::ptr::null_mut::<c_float>();
     (*self_0).lengthsCount = 0 as c_int;
-    (*self_0).lengths = std::ptr::null_mut::<c_float>();
+    (*self_0).lengths = core::ptr::null_mut::<c_float>();
     self_0
 }
 #[no_mangle]
@@ -13772,9 +13772,9 @@ pub unsafe extern "C" fn spPathConstraint_update(mut self_0: *mut spPathConstrai
     let mut dy: c_float = 0.;
     let mut s: c_float = 0.;
     let mut sum: c_float = 0.;
-    let mut spaces: *mut c_float = std::ptr::null_mut::<c_float>();
-    let mut lengths: *mut c_float = std::ptr::null_mut::<c_float>();
-    let mut positions: *mut c_float = std::ptr::null_mut::<c_float>();
+    let mut spaces: *mut c_float = core::ptr::null_mut::<c_float>();
+    let mut lengths: *mut c_float = core::ptr::null_mut::<c_float>();
+    let mut positions: *mut c_float = core::ptr::null_mut::<c_float>();
     let mut spacing: c_float = 0.;
     let mut boneX: c_float = 0.;
     let mut boneY: c_float = 0.;
@@ -13798,7 +13798,7 @@ pub unsafe extern "C" fn spPathConstraint_update(mut self_0: *mut spPathConstrai
         boneCount + 1 as c_int
     };
     let mut bones: *mut *mut spBone = (*self_0).bones;
-    let mut pa: *mut spBone = std::ptr::null_mut::<spBone>();
+    let mut pa: *mut spBone = core::ptr::null_mut::<spBone>();
     if mixRotate == 0 as c_int as c_float
         && mixX == 0 as c_int as c_float
         && mixY == 0 as c_int as c_float
@@ -13824,7 +13824,7 @@ pub unsafe extern "C" fn spPathConstraint_update(mut self_0: *mut spPathConstrai
     }
     spaces = (*self_0).spaces;
     *spaces.offset(0 as c_int as isize) = 0 as c_int as c_float;
-    lengths = std::ptr::null_mut::<c_float>();
+    lengths = core::ptr::null_mut::<c_float>();
     spacing = (*self_0).spacing;
     if scale != 0 {
         if (*self_0).lengthsCount != boneCount {
@@ -14120,9 +14120,9 @@ pub unsafe extern "C" fn spPathConstraint_computeWorldPositions(
     let mut verticesLength: c_int = 0;
     let mut curveCount: c_int = 0;
     let mut prevCurve: c_int = 0;
-    let mut out: *mut c_float = std::ptr::null_mut::<c_float>();
-    let mut curves: *mut c_float = std::ptr::null_mut::<c_float>();
-    let mut segments: *mut c_float = std::ptr::null_mut::<c_float>();
+    let mut out: *mut c_float = core::ptr::null_mut::<c_float>();
+    let mut curves: *mut c_float = core::ptr::null_mut::<c_float>();
+    let mut segments: *mut c_float = core::ptr::null_mut::<c_float>();
     let mut tmpx: c_float = 0.;
     let mut tmpy: c_float = 0.;
     let mut dddfx: c_float = 0.;
@@ -14146,7 +14146,7 @@ pub unsafe extern "C" fn spPathConstraint_computeWorldPositions(
     let mut target: *mut spSlot = (*self_0).target;
     let mut position: c_float = (*self_0).position;
     let mut spaces: *mut c_float = (*self_0).spaces;
-    let mut world: *mut c_float = std::ptr::null_mut::<c_float>();
+    let mut world: *mut c_float = core::ptr::null_mut::<c_float>();
     if (*self_0).positionsCount != spacesCount * 3 as c_int + 2 as c_int {
         if !((*self_0).positions).is_null() {
             _spFree((*self_0).positions.cast::<c_void>());
@@ -14978,7 +14978,7 @@ pub unsafe extern "C" fn spPhysicsConstraintData_create(
     )
     .cast::<c_char>();
     spine_strcpy((*self_0).name, name);
-    (*self_0).bone = std::ptr::null_mut::<spBoneData>();
+    (*self_0).bone = core::ptr::null_mut::<spBoneData>();
     (*self_0).x = 0 as c_int as c_float;
     (*self_0).y = 0 as c_int as c_float;
     (*self_0).rotate = 0 as c_int as c_float;
@@ -15116,7 +15116,7 @@ pub unsafe extern "C" fn _spRegionAttachment_copy(
     (*copy).sequence = if !((*self_0).sequence).is_null() {
         spSequence_copy((*self_0).sequence)
     } else {
-        std::ptr::null_mut::<spSequence>()
+        core::ptr::null_mut::<spSequence>()
     };
     &mut (*copy).super_0
 }
@@ -15478,7 +15478,7 @@ pub unsafe extern "C" fn spSequence_apply(
     mut attachment: *mut spAttachment,
 ) {
     let mut index: c_int = (*slot).sequenceIndex;
-    let mut region: *mut spTextureRegion = std::ptr::null_mut::<spTextureRegion>();
+    let mut region: *mut spTextureRegion = core::ptr::null_mut::<spTextureRegion>();
     if index == -(1 as c_int) {
         index = (*self_0).setupIndex;
     }
@@ -15556,7 +15556,7 @@ pub unsafe extern "C" fn spSequence_getPath(
 #[no_mangle]
 pub unsafe extern "C" fn spSkeleton_create(mut data: *mut spSkeletonData) -> *mut spSkeleton {
     let mut i: c_int = 0;
-    let mut childrenCounts: *mut c_int = std::ptr::null_mut::<c_int>();
+    let mut childrenCounts: *mut c_int = core::ptr::null_mut::<c_int>();
     let mut internal: *mut _spSkeleton = _spCalloc(
         1 as c_int as size_t,
         ::core::mem::size_of::<_spSkeleton>() as c_ulong,
@@ -15566,7 +15566,7 @@ pub unsafe extern "C" fn spSkeleton_create(mut data: *mut spSkeletonData) -> *mu
     .cast::<_spSkeleton>();
     let mut self_0: *mut spSkeleton = &mut (*internal).super_0;
     (*self_0).data = data;
-    (*self_0).skin = std::ptr::null_mut::<spSkin>();
+    (*self_0).skin = core::ptr::null_mut::<spSkin>();
     spColor_setFromFloats(
         &mut (*self_0).color,
         1 as c_int as c_float,
@@ -15595,9 +15595,9 @@ pub unsafe extern "C" fn spSkeleton_create(mut data: *mut spSkeletonData) -> *mu
     i = 0 as c_int;
     while i < (*self_0).bonesCount {
         let mut boneData: *mut spBoneData = *((*(*self_0).data).bones).offset(i as isize);
-        let mut newBone: *mut spBone = std::ptr::null_mut::<spBone>();
+        let mut newBone: *mut spBone = core::ptr::null_mut::<spBone>();
         if ((*boneData).parent).is_null() {
-            newBone = spBone_create(boneData, self_0, std::ptr::null_mut::<spBone>());
+            newBone = spBone_create(boneData, self_0, core::ptr::null_mut::<spBone>());
         } else {
             let mut parent: *mut spBone =
                 *((*self_0).bones).offset((*(*boneData).parent).index as isize);
@@ -15637,7 +15637,7 @@ pub unsafe extern "C" fn spSkeleton_create(mut data: *mut spSkeletonData) -> *mu
     (*self_0).root = if (*self_0).bonesCount > 0 as c_int {
         *((*self_0).bones).offset(0 as c_int as isize)
     } else {
-        std::ptr::null_mut::<spBone>()
+        core::ptr::null_mut::<spBone>()
     };
     (*self_0).slotsCount = (*data).slotsCount;
     (*self_0).slots = _spMalloc(
@@ -15800,7 +15800,7 @@ unsafe extern "C" fn _addToUpdateCache(
     mut type_0: _spUpdateType,
     mut object: *mut c_void,
 ) {
-    let mut update: *mut _spUpdate = std::ptr::null_mut::<_spUpdate>();
+    let mut update: *mut _spUpdate = core::ptr::null_mut::<_spUpdate>();
     if (*internal).updateCacheCount == (*internal).updateCacheCapacity {
         (*internal).updateCacheCapacity *= 2 as c_int;
         (*internal).updateCache = _spRealloc(
@@ -15831,7 +15831,7 @@ unsafe extern "C" fn _sortPathConstraintAttachmentBones(
     mut slotBone: *mut spBone,
 ) {
     let mut pathAttachment: *mut spPathAttachment = attachment.cast::<spPathAttachment>();
-    let mut pathBones: *mut c_int = std::ptr::null_mut::<c_int>();
+    let mut pathBones: *mut c_int = core::ptr::null_mut::<c_int>();
     let mut pathBonesCount: c_int = 0;
     if (*pathAttachment).super_0.super_0.type_0 as c_uint != SP_ATTACHMENT_PATH as c_int as c_uint {
         return;
@@ -15895,8 +15895,8 @@ unsafe extern "C" fn _sortIkConstraint(
     mut constraint: *mut spIkConstraint,
 ) {
     let mut target: *mut spBone = (*constraint).target;
-    let mut constrained: *mut *mut spBone = std::ptr::null_mut::<*mut spBone>();
-    let mut parent: *mut spBone = std::ptr::null_mut::<spBone>();
+    let mut constrained: *mut *mut spBone = core::ptr::null_mut::<*mut spBone>();
+    let mut parent: *mut spBone = core::ptr::null_mut::<spBone>();
     (*constraint).active = ((*(*constraint).target).active != 0
         && ((*(*constraint).data).skinRequired == 0
             || !((*internal).super_0.skin).is_null()
@@ -15941,8 +15941,8 @@ unsafe extern "C" fn _sortPathConstraint(
     let mut i: c_int = 0;
     let mut n: c_int = 0;
     let mut boneCount: c_int = 0;
-    let mut attachment: *mut spAttachment = std::ptr::null_mut::<spAttachment>();
-    let mut constrained: *mut *mut spBone = std::ptr::null_mut::<*mut spBone>();
+    let mut attachment: *mut spAttachment = core::ptr::null_mut::<spAttachment>();
+    let mut constrained: *mut *mut spBone = core::ptr::null_mut::<*mut spBone>();
     let mut skeleton: *mut spSkeleton = internal.cast::<spSkeleton>();
     (*constraint).active = ((*(*(*constraint).target).bone).active != 0
         && ((*(*constraint).data).skinRequired == 0
@@ -16016,8 +16016,8 @@ unsafe extern "C" fn _sortTransformConstraint(
 ) {
     let mut i: c_int = 0;
     let mut boneCount: c_int = 0;
-    let mut constrained: *mut *mut spBone = std::ptr::null_mut::<*mut spBone>();
-    let mut child: *mut spBone = std::ptr::null_mut::<spBone>();
+    let mut constrained: *mut *mut spBone = core::ptr::null_mut::<*mut spBone>();
+    let mut child: *mut spBone = core::ptr::null_mut::<spBone>();
     (*constraint).active = ((*(*constraint).target).active != 0
         && ((*(*constraint).data).skinRequired == 0
             || !((*internal).super_0.skin).is_null()
@@ -16093,14 +16093,14 @@ unsafe extern "C" fn _sortPhysicsConstraint(
 pub unsafe extern "C" fn spSkeleton_updateCache(mut self_0: *mut spSkeleton) {
     let mut i: c_int = 0;
     let mut ii: c_int = 0;
-    let mut bones: *mut *mut spBone = std::ptr::null_mut::<*mut spBone>();
-    let mut ikConstraints: *mut *mut spIkConstraint = std::ptr::null_mut::<*mut spIkConstraint>();
+    let mut bones: *mut *mut spBone = core::ptr::null_mut::<*mut spBone>();
+    let mut ikConstraints: *mut *mut spIkConstraint = core::ptr::null_mut::<*mut spIkConstraint>();
     let mut pathConstraints: *mut *mut spPathConstraint =
-        std::ptr::null_mut::<*mut spPathConstraint>();
+        core::ptr::null_mut::<*mut spPathConstraint>();
     let mut transformConstraints: *mut *mut spTransformConstraint =
-        std::ptr::null_mut::<*mut spTransformConstraint>();
+        core::ptr::null_mut::<*mut spTransformConstraint>();
     let mut physicsConstraints: *mut *mut spPhysicsConstraint =
-        std::ptr::null_mut::<*mut spPhysicsConstraint>();
+        core::ptr::null_mut::<*mut spPhysicsConstraint>();
     let mut ikCount: c_int = 0;
     let mut transformCount: c_int = 0;
     let mut pathCount: c_int = 0;
@@ -16385,7 +16385,7 @@ pub unsafe extern "C" fn spSkeleton_findBone(
         }
         i += 1;
     }
-    std::ptr::null_mut::<spBone>()
+    core::ptr::null_mut::<spBone>()
 }
 #[no_mangle]
 pub unsafe extern "C" fn spSkeleton_findSlot(
@@ -16404,16 +16404,16 @@ pub unsafe extern "C" fn spSkeleton_findSlot(
         }
         i += 1;
     }
-    std::ptr::null_mut::<spSlot>()
+    core::ptr::null_mut::<spSlot>()
 }
 #[no_mangle]
 pub unsafe extern "C" fn spSkeleton_setSkinByName(
     mut self_0: *mut spSkeleton,
     mut skinName: *const c_char,
 ) -> c_int {
-    let mut skin: *mut spSkin = std::ptr::null_mut::<spSkin>();
+    let mut skin: *mut spSkin = core::ptr::null_mut::<spSkin>();
     if skinName.is_null() {
-        spSkeleton_setSkin(self_0, std::ptr::null_mut::<spSkin>());
+        spSkeleton_setSkin(self_0, core::ptr::null_mut::<spSkin>());
         return 1 as c_int;
     }
     skin = spSkeletonData_findSkin((*self_0).data, skinName);
@@ -16466,7 +16466,7 @@ pub unsafe extern "C" fn spSkeleton_getAttachmentForSlotIndex(
     mut attachmentName: *const c_char,
 ) -> *mut spAttachment {
     if slotIndex == -(1 as c_int) {
-        return std::ptr::null_mut::<spAttachment>();
+        return core::ptr::null_mut::<spAttachment>();
     }
     if !((*self_0).skin).is_null() {
         let mut attachment: *mut spAttachment =
@@ -16482,7 +16482,7 @@ pub unsafe extern "C" fn spSkeleton_getAttachmentForSlotIndex(
             return attachment_0;
         }
     }
-    std::ptr::null_mut::<spAttachment>()
+    core::ptr::null_mut::<spAttachment>()
 }
 #[no_mangle]
 pub unsafe extern "C" fn spSkeleton_setAttachment(
@@ -16496,7 +16496,7 @@ pub unsafe extern "C" fn spSkeleton_setAttachment(
         let mut slot: *mut spSlot = *((*self_0).slots).offset(i as isize);
         if spine_strcmp((*(*slot).data).name, slotName) == 0 as c_int {
             if attachmentName.is_null() {
-                spSlot_setAttachment(slot, std::ptr::null_mut::<spAttachment>());
+                spSlot_setAttachment(slot, core::ptr::null_mut::<spAttachment>());
             } else {
                 let mut attachment: *mut spAttachment =
                     spSkeleton_getAttachmentForSlotIndex(self_0, i, attachmentName);
@@ -16528,7 +16528,7 @@ pub unsafe extern "C" fn spSkeleton_findIkConstraint(
         }
         i += 1;
     }
-    std::ptr::null_mut::<spIkConstraint>()
+    core::ptr::null_mut::<spIkConstraint>()
 }
 #[no_mangle]
 pub unsafe extern "C" fn spSkeleton_findTransformConstraint(
@@ -16547,7 +16547,7 @@ pub unsafe extern "C" fn spSkeleton_findTransformConstraint(
         }
         i += 1;
     }
-    std::ptr::null_mut::<spTransformConstraint>()
+    core::ptr::null_mut::<spTransformConstraint>()
 }
 #[no_mangle]
 pub unsafe extern "C" fn spSkeleton_findPathConstraint(
@@ -16566,7 +16566,7 @@ pub unsafe extern "C" fn spSkeleton_findPathConstraint(
         }
         i += 1;
     }
-    std::ptr::null_mut::<spPathConstraint>()
+    core::ptr::null_mut::<spPathConstraint>()
 }
 #[no_mangle]
 pub unsafe extern "C" fn spSkeleton_findPhysicsConstraint(
@@ -16585,7 +16585,7 @@ pub unsafe extern "C" fn spSkeleton_findPhysicsConstraint(
         }
         i += 1;
     }
-    std::ptr::null_mut::<spPhysicsConstraint>()
+    core::ptr::null_mut::<spPhysicsConstraint>()
 }
 #[no_mangle]
 pub unsafe extern "C" fn spSkeleton_physicsTranslate(
@@ -16637,7 +16637,7 @@ unsafe extern "C" fn string_substring(
     mut end: c_int,
 ) -> *mut c_char {
     if str.is_null() || start > end || start < 0 as c_int {
-        return std::ptr::null_mut::<c_char>();
+        return core::ptr::null_mut::<c_char>();
     }
     let mut len: c_int = end - start;
     let mut substr: *mut c_char = _spMalloc(
@@ -16647,7 +16647,7 @@ unsafe extern "C" fn string_substring(
     )
     .cast::<c_char>();
     if substr.is_null() {
-        return std::ptr::null_mut::<c_char>();
+        return core::ptr::null_mut::<c_char>();
     }
     spine_strncpy(substr, str.offset(start as isize), len as size_t);
     *substr.offset(len as isize) = '\0' as i32 as c_char;
@@ -16679,7 +16679,7 @@ unsafe extern "C" fn string_starts_with_binary(
 }
 unsafe extern "C" fn string_copy(mut str: *const c_char) -> *mut c_char {
     if str.is_null() {
-        return std::ptr::null_mut::<c_char>();
+        return core::ptr::null_mut::<c_char>();
     }
     let mut len: c_int = spine_strlen(str) as c_int;
     let mut tmp: *mut c_char = spine_malloc((len + 1 as c_int) as size_t).cast::<c_char>();
@@ -16802,9 +16802,9 @@ pub unsafe extern "C" fn readFloat(mut input: *mut _dataInput) -> c_float {
 #[no_mangle]
 pub unsafe extern "C" fn readString(mut input: *mut _dataInput) -> *mut c_char {
     let mut length: c_int = readVarint(input, 1 as c_int);
-    let mut string: *mut c_char = std::ptr::null_mut::<c_char>();
+    let mut string: *mut c_char = core::ptr::null_mut::<c_char>();
     if length == 0 as c_int {
-        return std::ptr::null_mut::<c_char>();
+        return core::ptr::null_mut::<c_char>();
     }
     string = _spMalloc(
         (::core::mem::size_of::<c_char>() as c_ulong).wrapping_mul(length as c_ulong),
@@ -16827,7 +16827,7 @@ unsafe extern "C" fn readStringRef(
 ) -> *mut c_char {
     let mut index: c_int = readVarint(input, 1 as c_int);
     if index == 0 as c_int {
-        std::ptr::null_mut::<c_char>()
+        core::ptr::null_mut::<c_char>()
     } else {
         *((*skeletonData).strings).offset((index - 1 as c_int) as isize)
     }
@@ -17010,11 +17010,11 @@ unsafe extern "C" fn _spSkeletonBinary_addLinkedMesh(
     mut parent: *const c_char,
     mut inheritDeform: c_int,
 ) {
-    let mut linkedMesh: *mut _spLinkedMeshBinary = std::ptr::null_mut::<_spLinkedMeshBinary>();
+    let mut linkedMesh: *mut _spLinkedMeshBinary = core::ptr::null_mut::<_spLinkedMeshBinary>();
     let mut internal: *mut _spSkeletonBinary = self_0.cast::<_spSkeletonBinary>();
     if (*internal).linkedMeshCount == (*internal).linkedMeshCapacity {
         let mut linkedMeshes: *mut _spLinkedMeshBinary =
-            std::ptr::null_mut::<_spLinkedMeshBinary>();
+            core::ptr::null_mut::<_spLinkedMeshBinary>();
         (*internal).linkedMeshCapacity *= 2 as c_int;
         if (*internal).linkedMeshCapacity < 8 as c_int {
             (*internal).linkedMeshCapacity = 8 as c_int;
@@ -17062,7 +17062,7 @@ unsafe extern "C" fn _spSkeletonBinary_readAnimation(
     let mut bezier: c_int = 0;
     let mut drawOrderCount: c_int = 0;
     let mut eventCount: c_int = 0;
-    let mut animation: *mut spAnimation = std::ptr::null_mut::<spAnimation>();
+    let mut animation: *mut spAnimation = core::ptr::null_mut::<spAnimation>();
     let mut scale: c_float = (*self_0).scale;
     let mut _numTimelines: c_int = readVarint(input, 1 as c_int);
     i = 0 as c_int;
@@ -17626,7 +17626,7 @@ unsafe extern "C" fn _spSkeletonBinary_readAnimation(
                     }
                     spTimelineArray_add(timelines, &mut (*timeline_4).super_0.super_0);
                 }
-                _ => return std::ptr::null_mut::<spAnimation>(),
+                _ => return core::ptr::null_mut::<spAnimation>(),
             }
             ii += 1;
         }
@@ -17857,7 +17857,7 @@ unsafe extern "C" fn _spSkeletonBinary_readAnimation(
                             (b"Invalid timeline type for a bone: \0" as *const u8).cast::<c_char>(),
                             (**((*skeletonData).bones).offset(boneIndex as isize)).name,
                         );
-                        return std::ptr::null_mut::<spAnimation>();
+                        return core::ptr::null_mut::<spAnimation>();
                     }
                 }
             }
@@ -18482,19 +18482,19 @@ unsafe extern "C" fn _spSkeletonBinary_readAnimation(
                         (b"Attachment not found: \0" as *const u8).cast::<c_char>(),
                         attachmentName_0,
                     );
-                    return std::ptr::null_mut::<spAnimation>();
+                    return core::ptr::null_mut::<spAnimation>();
                 }
                 timelineType_1 = readByte(input) as c_uint;
                 frameCount_5 = readVarint(input, 1 as c_int);
                 frameLast_3 = frameCount_5 - 1 as c_int;
                 match timelineType_1 {
                     0 => {
-                        let mut tempDeform: *mut c_float = std::ptr::null_mut::<c_float>();
+                        let mut tempDeform: *mut c_float = core::ptr::null_mut::<c_float>();
                         let mut weighted: c_int = 0;
                         let mut deformLength: c_int = 0;
                         let mut timeline_10: *mut spDeformTimeline =
-                            std::ptr::null_mut::<spDeformTimeline>();
-                        weighted = ((*attachment).bones != std::ptr::null_mut::<c_int>()) as c_int;
+                            core::ptr::null_mut::<spDeformTimeline>();
+                        weighted = ((*attachment).bones != core::ptr::null_mut::<c_int>()) as c_int;
                         deformLength = if weighted != 0 {
                             (*attachment).verticesCount / 3 as c_int * 2 as c_int
                         } else {
@@ -18519,7 +18519,7 @@ unsafe extern "C" fn _spSkeletonBinary_readAnimation(
                         frame = 0 as c_int;
                         bezier = 0 as c_int;
                         loop {
-                            let mut deform: *mut c_float = std::ptr::null_mut::<c_float>();
+                            let mut deform: *mut c_float = core::ptr::null_mut::<c_float>();
                             let mut end: c_int = readVarint(input, 1 as c_int);
                             if end == 0 {
                                 if weighted != 0 {
@@ -18802,7 +18802,7 @@ unsafe extern "C" fn _readVerticesBinary(
     *verticesLength = vertexCount << 1 as c_int;
     if weighted == 0 {
         *vertices = _readFloatArray(input, *verticesLength, scale);
-        *bones = std::ptr::null_mut::<c_int>();
+        *bones = core::ptr::null_mut::<c_int>();
         *bonesCount = 0 as c_int;
         return *verticesLength;
     }
@@ -18902,7 +18902,7 @@ pub unsafe extern "C" fn spSkeletonBinary_readAttachment(
             let mut sequence: *mut spSequence = if flags & 64 as c_int != 0 as c_int {
                 readSequenceBinary(input)
             } else {
-                std::ptr::null_mut::<spSequence>()
+                core::ptr::null_mut::<spSequence>()
             };
             let mut rotation: c_float = if flags & 128 as c_int != 0 as c_int {
                 readFloat(input)
@@ -18949,12 +18949,12 @@ pub unsafe extern "C" fn spSkeletonBinary_readAttachment(
                 skin,
                 type_0,
                 name,
-                std::ptr::null::<c_char>(),
-                std::ptr::null_mut::<spSequence>(),
+                core::ptr::null::<c_char>(),
+                core::ptr::null_mut::<spSequence>(),
             )
             .cast::<spBoundingBoxAttachment>();
             if box_0.is_null() {
-                return std::ptr::null_mut::<spAttachment>();
+                return core::ptr::null_mut::<spAttachment>();
             }
             _readVerticesBinary(
                 input,
@@ -18982,18 +18982,18 @@ pub unsafe extern "C" fn spSkeletonBinary_readAttachment(
             return &mut (*box_0).super_0.super_0;
         }
         2 => {
-            let mut uvs: *mut c_float = std::ptr::null_mut::<c_float>();
+            let mut uvs: *mut c_float = core::ptr::null_mut::<c_float>();
             let mut uvsCount: c_int = 0 as c_int;
-            let mut triangles: *mut c_ushort = std::ptr::null_mut::<c_ushort>();
+            let mut triangles: *mut c_ushort = core::ptr::null_mut::<c_ushort>();
             let mut trianglesCount: c_int = 0 as c_int;
-            let mut vertices: *mut c_float = std::ptr::null_mut::<c_float>();
+            let mut vertices: *mut c_float = core::ptr::null_mut::<c_float>();
             let mut verticesCount: c_int = 0 as c_int;
-            let mut bones: *mut c_int = std::ptr::null_mut::<c_int>();
+            let mut bones: *mut c_int = core::ptr::null_mut::<c_int>();
             let mut bonesCount: c_int = 0 as c_int;
             let mut hullLength: c_int = 0 as c_int;
             let mut width_0: c_float = 0 as c_int as c_float;
             let mut height_0: c_float = 0 as c_int as c_float;
-            let mut edges: *mut c_ushort = std::ptr::null_mut::<c_ushort>();
+            let mut edges: *mut c_ushort = core::ptr::null_mut::<c_ushort>();
             let mut edgesCount: c_int = 0 as c_int;
             let mut path_0: *mut c_char = if flags & 16 as c_int != 0 as c_int {
                 readStringRef(input, skeletonData)
@@ -19026,7 +19026,7 @@ pub unsafe extern "C" fn spSkeletonBinary_readAttachment(
             let mut sequence_0: *mut spSequence = if flags & 64 as c_int != 0 as c_int {
                 readSequenceBinary(input)
             } else {
-                std::ptr::null_mut::<spSequence>()
+                core::ptr::null_mut::<spSequence>()
             };
             hullLength = readVarint(input, 1 as c_int);
             let mut verticesLength: c_int = _readVerticesBinary(
@@ -19057,7 +19057,7 @@ pub unsafe extern "C" fn spSkeletonBinary_readAttachment(
                 sequence_0,
             );
             if attachment.is_null() {
-                return std::ptr::null_mut::<spAttachment>();
+                return core::ptr::null_mut::<spAttachment>();
             }
             let mut mesh: *mut spMeshAttachment = attachment.cast::<spMeshAttachment>();
             (*mesh).path = path_0;
@@ -19114,7 +19114,7 @@ pub unsafe extern "C" fn spSkeletonBinary_readAttachment(
             let mut sequence_1: *mut spSequence = if flags & 64 as c_int != 0 as c_int {
                 readSequenceBinary(input)
             } else {
-                std::ptr::null_mut::<spSequence>()
+                core::ptr::null_mut::<spSequence>()
             };
             let mut inheritTimelines: c_int = (flags & 128 as c_int != 0 as c_int) as c_int;
             let mut skinIndex: c_int = readVarint(input, 1 as c_int);
@@ -19133,14 +19133,14 @@ pub unsafe extern "C" fn spSkeletonBinary_readAttachment(
                 path_1,
                 sequence_1,
             );
-            let mut mesh_0: *mut spMeshAttachment = std::ptr::null_mut::<spMeshAttachment>();
+            let mut mesh_0: *mut spMeshAttachment = core::ptr::null_mut::<spMeshAttachment>();
             if attachment_0.is_null() {
-                return std::ptr::null_mut::<spAttachment>();
+                return core::ptr::null_mut::<spAttachment>();
             }
             mesh_0 = attachment_0.cast::<spMeshAttachment>();
             (*mesh_0).path = path_1;
             if !((*mesh_0).path).is_null() {
-                let mut tmp: *mut c_char = std::ptr::null_mut::<c_char>();
+                let mut tmp: *mut c_char = core::ptr::null_mut::<c_char>();
                 tmp = _spMalloc(
                     (::core::mem::size_of::<c_char>() as c_ulong).wrapping_mul(
                         (spine_strlen((*mesh_0).path)).wrapping_add(1 as c_int as c_ulong),
@@ -19172,12 +19172,12 @@ pub unsafe extern "C" fn spSkeletonBinary_readAttachment(
                 skin,
                 type_0,
                 name,
-                std::ptr::null::<c_char>(),
-                std::ptr::null_mut::<spSequence>(),
+                core::ptr::null::<c_char>(),
+                core::ptr::null_mut::<spSequence>(),
             );
-            let mut path_2: *mut spPathAttachment = std::ptr::null_mut::<spPathAttachment>();
+            let mut path_2: *mut spPathAttachment = core::ptr::null_mut::<spPathAttachment>();
             if attachment_1.is_null() {
-                return std::ptr::null_mut::<spAttachment>();
+                return core::ptr::null_mut::<spAttachment>();
             }
             path_2 = attachment_1.cast::<spPathAttachment>();
             (*path_2).closed = (flags & 16 as c_int != 0 as c_int) as c_int;
@@ -19223,12 +19223,12 @@ pub unsafe extern "C" fn spSkeletonBinary_readAttachment(
                 skin,
                 type_0,
                 name,
-                std::ptr::null::<c_char>(),
-                std::ptr::null_mut::<spSequence>(),
+                core::ptr::null::<c_char>(),
+                core::ptr::null_mut::<spSequence>(),
             );
-            let mut point: *mut spPointAttachment = std::ptr::null_mut::<spPointAttachment>();
+            let mut point: *mut spPointAttachment = core::ptr::null_mut::<spPointAttachment>();
             if attachment_2.is_null() {
-                return std::ptr::null_mut::<spAttachment>();
+                return core::ptr::null_mut::<spAttachment>();
             }
             point = attachment_2.cast::<spPointAttachment>();
             (*point).rotation = readFloat(input);
@@ -19253,12 +19253,12 @@ pub unsafe extern "C" fn spSkeletonBinary_readAttachment(
                 skin,
                 type_0,
                 name,
-                std::ptr::null::<c_char>(),
-                std::ptr::null_mut::<spSequence>(),
+                core::ptr::null::<c_char>(),
+                core::ptr::null_mut::<spSequence>(),
             );
-            let mut clip: *mut spClippingAttachment = std::ptr::null_mut::<spClippingAttachment>();
+            let mut clip: *mut spClippingAttachment = core::ptr::null_mut::<spClippingAttachment>();
             if attachment_3.is_null() {
-                return std::ptr::null_mut::<spAttachment>();
+                return core::ptr::null_mut::<spAttachment>();
             }
             clip = attachment_3.cast::<spClippingAttachment>();
             let mut verticesLength_1: c_int = _readVerticesBinary(
@@ -19286,7 +19286,7 @@ pub unsafe extern "C" fn spSkeletonBinary_readAttachment(
         }
         _ => {}
     }
-    std::ptr::null_mut::<spAttachment>()
+    core::ptr::null_mut::<spAttachment>()
 }
 #[no_mangle]
 pub unsafe extern "C" fn spSkeletonBinary_readSkin(
@@ -19296,7 +19296,7 @@ pub unsafe extern "C" fn spSkeletonBinary_readSkin(
     mut skeletonData: *mut spSkeletonData,
     mut nonessential: c_int,
 ) -> *mut spSkin {
-    let mut skin: *mut spSkin = std::ptr::null_mut::<spSkin>();
+    let mut skin: *mut spSkin = core::ptr::null_mut::<spSkin>();
     let mut i: c_int = 0;
     let mut n: c_int = 0;
     let mut ii: c_int = 0;
@@ -19305,7 +19305,7 @@ pub unsafe extern "C" fn spSkeletonBinary_readSkin(
     if defaultSkin != 0 {
         slotCount = readVarint(input, 1 as c_int);
         if slotCount == 0 as c_int {
-            return std::ptr::null_mut::<spSkin>();
+            return core::ptr::null_mut::<spSkin>();
         }
         skin = spSkin_create((b"default\0" as *const u8).cast::<c_char>());
     } else {
@@ -19387,7 +19387,7 @@ pub unsafe extern "C" fn spSkeletonBinary_readSkin(
                 nonessential,
             );
             if attachment.is_null() {
-                return std::ptr::null_mut::<spSkin>();
+                return core::ptr::null_mut::<spSkin>();
             }
             spSkin_setAttachment(skin, slotIndex, name_0, attachment);
             ii += 1;
@@ -19402,7 +19402,7 @@ pub unsafe extern "C" fn spSkeletonBinary_readSkeletonDataFile(
     mut path: *const c_char,
 ) -> *mut spSkeletonData {
     let mut length: c_int = 0;
-    let mut skeletonData: *mut spSkeletonData = std::ptr::null_mut::<spSkeletonData>();
+    let mut skeletonData: *mut spSkeletonData = core::ptr::null_mut::<spSkeletonData>();
     let mut binary: *const c_char = _spUtil_readFile(path, &mut length);
     if length == 0 as c_int || binary.is_null() {
         _spSkeletonBinary_setError(
@@ -19410,7 +19410,7 @@ pub unsafe extern "C" fn spSkeletonBinary_readSkeletonDataFile(
             (b"Unable to read skeleton file: \0" as *const u8).cast::<c_char>(),
             path,
         );
-        return std::ptr::null_mut::<spSkeletonData>();
+        return core::ptr::null_mut::<spSkeletonData>();
     }
     skeletonData = spSkeletonBinary_readSkeletonData(self_0, binary as *mut c_uchar, length);
     _spFree(binary as *mut c_void);
@@ -19429,7 +19429,7 @@ pub unsafe extern "C" fn spSkeletonBinary_readSkeletonData(
     let mut buffer: [c_char; 32] = [0; 32];
     let mut lowHash: c_int = 0;
     let mut highHash: c_int = 0;
-    let mut skeletonData: *mut spSkeletonData = std::ptr::null_mut::<spSkeletonData>();
+    let mut skeletonData: *mut spSkeletonData = core::ptr::null_mut::<spSkeletonData>();
     let mut internal: *mut _spSkeletonBinary = self_0.cast::<_spSkeletonBinary>();
     let mut input: *mut _dataInput = _spCalloc(
         1 as c_int as size_t,
@@ -19441,7 +19441,7 @@ pub unsafe extern "C" fn spSkeletonBinary_readSkeletonData(
     (*input).cursor = binary;
     (*input).end = binary.offset(length as isize);
     _spFree((*self_0).error.cast::<c_void>());
-    (*self_0).error = std::ptr::null_mut::<c_char>();
+    (*self_0).error = core::ptr::null_mut::<c_char>();
     (*internal).linkedMeshCount = 0 as c_int;
     skeletonData = spSkeletonData_create();
     lowHash = readInt(input);
@@ -19465,7 +19465,7 @@ pub unsafe extern "C" fn spSkeletonBinary_readSkeletonData(
     (*skeletonData).version = readString(input);
     if spine_strlen((*skeletonData).version) == 0 {
         _spFree((*skeletonData).version.cast::<c_void>());
-        (*skeletonData).version = std::ptr::null_mut::<c_char>();
+        (*skeletonData).version = core::ptr::null_mut::<c_char>();
     } else if string_starts_with_binary(
         (*skeletonData).version,
         (b"4.2\0" as *const u8).cast::<c_char>(),
@@ -19480,8 +19480,8 @@ pub unsafe extern "C" fn spSkeletonBinary_readSkeletonData(
             (*skeletonData).version,
             (b"4.2\0" as *const u8).cast::<c_char>(),
         );
-        _spSkeletonBinary_setError(self_0, errorMsg.as_mut_ptr(), std::ptr::null::<c_char>());
-        return std::ptr::null_mut::<spSkeletonData>();
+        _spSkeletonBinary_setError(self_0, errorMsg.as_mut_ptr(), core::ptr::null::<c_char>());
+        return core::ptr::null_mut::<spSkeletonData>();
     }
     (*skeletonData).x = readFloat(input);
     (*skeletonData).y = readFloat(input);
@@ -19494,12 +19494,12 @@ pub unsafe extern "C" fn spSkeletonBinary_readSkeletonData(
         (*skeletonData).imagesPath = readString(input);
         if spine_strlen((*skeletonData).imagesPath) == 0 {
             _spFree((*skeletonData).imagesPath as *mut c_void);
-            (*skeletonData).imagesPath = std::ptr::null::<c_char>();
+            (*skeletonData).imagesPath = core::ptr::null::<c_char>();
         }
         (*skeletonData).audioPath = readString(input);
         if spine_strlen((*skeletonData).audioPath) == 0 {
             _spFree((*skeletonData).audioPath as *mut c_void);
-            (*skeletonData).audioPath = std::ptr::null::<c_char>();
+            (*skeletonData).audioPath = core::ptr::null::<c_char>();
         }
     }
     n = readVarint(input, 1 as c_int);
@@ -19529,7 +19529,7 @@ pub unsafe extern "C" fn spSkeletonBinary_readSkeletonData(
     while i < (*skeletonData).bonesCount {
         let mut name: *const c_char = readString(input);
         let mut parent: *mut spBoneData = if i == 0 as c_int {
-            std::ptr::null_mut::<spBoneData>()
+            core::ptr::null_mut::<spBoneData>()
         } else {
             *((*skeletonData).bones).offset(readVarint(input, 1 as c_int) as isize)
         };
@@ -19571,7 +19571,7 @@ pub unsafe extern "C" fn spSkeletonBinary_readSkeletonData(
     i = 0 as c_int;
     while i < (*skeletonData).slotsCount {
         let mut slotName: *mut c_char = readString(input);
-        let mut pathName: *mut c_char = std::ptr::null_mut::<c_char>();
+        let mut pathName: *mut c_char = core::ptr::null_mut::<c_char>();
         if nonessential != 0 {
             let mut slash: c_int = string_lastIndexOf(slotName, '/' as i32 as c_char);
             if slash != -(1 as c_int) {
@@ -19620,7 +19620,7 @@ pub unsafe extern "C" fn spSkeletonBinary_readSkeletonData(
             .cast::<c_char>();
             spine_strcpy((*slotData).attachmentName, attachmentName);
         } else {
-            (*slotData).attachmentName = std::ptr::null_mut::<c_char>();
+            (*slotData).attachmentName = core::ptr::null_mut::<c_char>();
         }
         (*slotData).blendMode = readVarint(input, 1 as c_int) as spBlendMode;
         if nonessential != 0 {
@@ -19900,7 +19900,7 @@ pub unsafe extern "C" fn spSkeletonBinary_readSkeletonData(
             (*(*self_0).attachmentLoader).error1,
             (*(*self_0).attachmentLoader).error2,
         );
-        return std::ptr::null_mut::<spSkeletonData>();
+        return core::ptr::null_mut::<spSkeletonData>();
     }
     (*skeletonData).skinsCount = readVarint(input, 1 as c_int);
     if !((*skeletonData).defaultSkin).is_null() {
@@ -19932,7 +19932,7 @@ pub unsafe extern "C" fn spSkeletonBinary_readSkeletonData(
                 (*(*self_0).attachmentLoader).error1,
                 (*(*self_0).attachmentLoader).error2,
             );
-            return std::ptr::null_mut::<spSkeletonData>();
+            return core::ptr::null_mut::<spSkeletonData>();
         }
         let fresh125 = &mut (*((*skeletonData).skins).offset(i as isize));
         *fresh125 = skin;
@@ -19952,7 +19952,7 @@ pub unsafe extern "C" fn spSkeletonBinary_readSkeletonData(
                 (b"Skin not found\0" as *const u8).cast::<c_char>(),
                 (b"\0" as *const u8).cast::<c_char>(),
             );
-            return std::ptr::null_mut::<spSkeletonData>();
+            return core::ptr::null_mut::<spSkeletonData>();
         }
         let mut parent_0: *mut spAttachment =
             spSkin_getAttachment(skin_0, (*linkedMesh).slotIndex, (*linkedMesh).parent);
@@ -19964,7 +19964,7 @@ pub unsafe extern "C" fn spSkeletonBinary_readSkeletonData(
                 (b"Parent mesh not found: \0" as *const u8).cast::<c_char>(),
                 (*linkedMesh).parent,
             );
-            return std::ptr::null_mut::<spSkeletonData>();
+            return core::ptr::null_mut::<spSkeletonData>();
         }
         (*(*linkedMesh).mesh).super_0.timelineAttachment = if (*linkedMesh).inheritTimeline != 0 {
             parent_0
@@ -20028,7 +20028,7 @@ pub unsafe extern "C" fn spSkeletonBinary_readSkeletonData(
                 (b"Animation corrupted: \0" as *const u8).cast::<c_char>(),
                 name_5,
             );
-            return std::ptr::null_mut::<spSkeletonData>();
+            return core::ptr::null_mut::<spSkeletonData>();
         }
         let fresh127 = &mut (*((*skeletonData).animations).offset(i as isize));
         *fresh127 = animation;
@@ -20160,7 +20160,7 @@ pub unsafe extern "C" fn spSkeletonBounds_update(
     let mut i: c_int = 0;
     let mut internal: *mut _spSkeletonBounds = self_0.cast::<_spSkeletonBounds>();
     if (*internal).capacity < (*skeleton).slotsCount {
-        let mut newPolygons: *mut *mut spPolygon = std::ptr::null_mut::<*mut spPolygon>();
+        let mut newPolygons: *mut *mut spPolygon = core::ptr::null_mut::<*mut spPolygon>();
         _spFree((*self_0).boundingBoxes.cast::<c_void>());
         (*self_0).boundingBoxes = _spMalloc(
             (::core::mem::size_of::<*mut spBoundingBoxAttachment>() as c_ulong)
@@ -20193,10 +20193,10 @@ pub unsafe extern "C" fn spSkeletonBounds_update(
     (*self_0).count = 0 as c_int;
     i = 0 as c_int;
     while i < (*skeleton).slotsCount {
-        let mut polygon: *mut spPolygon = std::ptr::null_mut::<spPolygon>();
+        let mut polygon: *mut spPolygon = core::ptr::null_mut::<spPolygon>();
         let mut boundingBox: *mut spBoundingBoxAttachment =
-            std::ptr::null_mut::<spBoundingBoxAttachment>();
-        let mut attachment: *mut spAttachment = std::ptr::null_mut::<spAttachment>();
+            core::ptr::null_mut::<spBoundingBoxAttachment>();
+        let mut attachment: *mut spAttachment = core::ptr::null_mut::<spAttachment>();
         let mut slot: *mut spSlot = *((*skeleton).slots).offset(i as isize);
         if (*(*slot).bone).active != 0 {
             attachment = (*slot).attachment;
@@ -20324,7 +20324,7 @@ pub unsafe extern "C" fn spSkeletonBounds_containsPoint(
         }
         i += 1;
     }
-    std::ptr::null_mut::<spBoundingBoxAttachment>()
+    core::ptr::null_mut::<spBoundingBoxAttachment>()
 }
 #[no_mangle]
 pub unsafe extern "C" fn spSkeletonBounds_intersectsSegment(
@@ -20344,7 +20344,7 @@ pub unsafe extern "C" fn spSkeletonBounds_intersectsSegment(
         }
         i += 1;
     }
-    std::ptr::null_mut::<spBoundingBoxAttachment>()
+    core::ptr::null_mut::<spBoundingBoxAttachment>()
 }
 #[no_mangle]
 pub unsafe extern "C" fn spSkeletonBounds_getPolygon(
@@ -20359,7 +20359,7 @@ pub unsafe extern "C" fn spSkeletonBounds_getPolygon(
         }
         i += 1;
     }
-    std::ptr::null_mut::<spPolygon>()
+    core::ptr::null_mut::<spPolygon>()
 }
 #[no_mangle]
 pub unsafe extern "C" fn spSkeletonClipping_create() -> *mut spSkeletonClipping {
@@ -20440,7 +20440,7 @@ pub unsafe extern "C" fn spSkeletonClipping_clipStart(
 ) -> c_int {
     let mut i: c_int = 0;
     let mut n: c_int = 0;
-    let mut vertices: *mut c_float = std::ptr::null_mut::<c_float>();
+    let mut vertices: *mut c_float = core::ptr::null_mut::<c_float>();
     if !((*self_0).clipAttachment).is_null() {
         return 0 as c_int;
     }
@@ -20489,8 +20489,8 @@ pub unsafe extern "C" fn spSkeletonClipping_clipEnd2(mut self_0: *mut spSkeleton
     if ((*self_0).clipAttachment).is_null() {
         return;
     }
-    (*self_0).clipAttachment = std::ptr::null_mut::<spClippingAttachment>();
-    (*self_0).clippingPolygons = std::ptr::null_mut::<spArrayFloatArray>();
+    (*self_0).clipAttachment = core::ptr::null_mut::<spClippingAttachment>();
+    (*self_0).clippingPolygons = core::ptr::null_mut::<spArrayFloatArray>();
     spFloatArray_clear((*self_0).clippedVertices);
     spFloatArray_clear((*self_0).clippedUVs);
     spUnsignedShortArray_clear((*self_0).clippedTriangles);
@@ -20500,7 +20500,7 @@ pub unsafe extern "C" fn spSkeletonClipping_clipEnd2(mut self_0: *mut spSkeleton
 pub unsafe extern "C" fn spSkeletonClipping_isClipping(
     mut self_0: *mut spSkeletonClipping,
 ) -> c_int {
-    ((*self_0).clipAttachment != std::ptr::null_mut::<spClippingAttachment>()) as c_int
+    ((*self_0).clipAttachment != core::ptr::null_mut::<spClippingAttachment>()) as c_int
 }
 #[no_mangle]
 pub unsafe extern "C" fn _clip(
@@ -20517,9 +20517,9 @@ pub unsafe extern "C" fn _clip(
     let mut i: c_int = 0;
     let mut originalOutput: *mut spFloatArray = output;
     let mut clipped: c_int = 0 as c_int;
-    let mut clippingVertices: *mut c_float = std::ptr::null_mut::<c_float>();
+    let mut clippingVertices: *mut c_float = core::ptr::null_mut::<c_float>();
     let mut clippingVerticesLast: c_int = 0;
-    let mut input: *mut spFloatArray = std::ptr::null_mut::<spFloatArray>();
+    let mut input: *mut spFloatArray = core::ptr::null_mut::<spFloatArray>();
     if (*clippingArea).size % 4 as c_int >= 2 as c_int {
         input = output;
         output = (*self_0).scratch;
@@ -20541,7 +20541,7 @@ pub unsafe extern "C" fn _clip(
     i = 0 as c_int;
     loop {
         let mut ii: c_int = 0;
-        let mut temp: *mut spFloatArray = std::ptr::null_mut::<spFloatArray>();
+        let mut temp: *mut spFloatArray = core::ptr::null_mut::<spFloatArray>();
         let mut edgeX: c_float = *clippingVertices.offset(i as isize);
         let mut edgeY: c_float = *clippingVertices.offset((i + 1 as c_int) as isize);
         let mut edgeX2: c_float = *clippingVertices.offset((i + 2 as c_int) as isize);
@@ -20711,11 +20711,11 @@ pub unsafe extern "C" fn spSkeletonClipping_clipTriangles(
                 let mut d2: c_float = 0.;
                 let mut d4: c_float = 0.;
                 let mut d: c_float = 0.;
-                let mut clippedTrianglesItems: *mut c_ushort = std::ptr::null_mut::<c_ushort>();
+                let mut clippedTrianglesItems: *mut c_ushort = core::ptr::null_mut::<c_ushort>();
                 let mut clipOutputCount: c_int = 0;
-                let mut clipOutputItems: *mut c_float = std::ptr::null_mut::<c_float>();
-                let mut clippedVerticesItems: *mut c_float = std::ptr::null_mut::<c_float>();
-                let mut clippedUVsItems: *mut c_float = std::ptr::null_mut::<c_float>();
+                let mut clipOutputItems: *mut c_float = core::ptr::null_mut::<c_float>();
+                let mut clippedVerticesItems: *mut c_float = core::ptr::null_mut::<c_float>();
+                let mut clippedUVsItems: *mut c_float = core::ptr::null_mut::<c_float>();
                 let mut clipOutputLength: c_int = (*clipOutput).size;
                 if clipOutputLength != 0 as c_int {
                     d0 = y2 - y3;
@@ -20776,7 +20776,7 @@ pub unsafe extern "C" fn spSkeletonClipping_clipTriangles(
                 }
                 p += 1;
             } else {
-                let mut clippedTrianglesItems_0: *mut c_ushort = std::ptr::null_mut::<c_ushort>();
+                let mut clippedTrianglesItems_0: *mut c_ushort = core::ptr::null_mut::<c_ushort>();
                 let mut clippedVerticesItems_0: *mut c_float =
                     (*spFloatArray_setSize(clippedVertices, s + ((3 as c_int) << 1 as c_int)))
                         .items;
@@ -20902,7 +20902,7 @@ pub unsafe extern "C" fn spSkeletonData_findBone(
         }
         i += 1;
     }
-    std::ptr::null_mut::<spBoneData>()
+    core::ptr::null_mut::<spBoneData>()
 }
 #[no_mangle]
 pub unsafe extern "C" fn spSkeletonData_findSlot(
@@ -20917,7 +20917,7 @@ pub unsafe extern "C" fn spSkeletonData_findSlot(
         }
         i += 1;
     }
-    std::ptr::null_mut::<spSlotData>()
+    core::ptr::null_mut::<spSlotData>()
 }
 #[no_mangle]
 pub unsafe extern "C" fn spSkeletonData_findSkin(
@@ -20932,7 +20932,7 @@ pub unsafe extern "C" fn spSkeletonData_findSkin(
         }
         i += 1;
     }
-    std::ptr::null_mut::<spSkin>()
+    core::ptr::null_mut::<spSkin>()
 }
 #[no_mangle]
 pub unsafe extern "C" fn spSkeletonData_findEvent(
@@ -20947,7 +20947,7 @@ pub unsafe extern "C" fn spSkeletonData_findEvent(
         }
         i += 1;
     }
-    std::ptr::null_mut::<spEventData>()
+    core::ptr::null_mut::<spEventData>()
 }
 #[no_mangle]
 pub unsafe extern "C" fn spSkeletonData_findAnimation(
@@ -20966,7 +20966,7 @@ pub unsafe extern "C" fn spSkeletonData_findAnimation(
         }
         i += 1;
     }
-    std::ptr::null_mut::<spAnimation>()
+    core::ptr::null_mut::<spAnimation>()
 }
 #[no_mangle]
 pub unsafe extern "C" fn spSkeletonData_findIkConstraint(
@@ -20985,7 +20985,7 @@ pub unsafe extern "C" fn spSkeletonData_findIkConstraint(
         }
         i += 1;
     }
-    std::ptr::null_mut::<spIkConstraintData>()
+    core::ptr::null_mut::<spIkConstraintData>()
 }
 #[no_mangle]
 pub unsafe extern "C" fn spSkeletonData_findTransformConstraint(
@@ -21004,7 +21004,7 @@ pub unsafe extern "C" fn spSkeletonData_findTransformConstraint(
         }
         i += 1;
     }
-    std::ptr::null_mut::<spTransformConstraintData>()
+    core::ptr::null_mut::<spTransformConstraintData>()
 }
 #[no_mangle]
 pub unsafe extern "C" fn spSkeletonData_findPathConstraint(
@@ -21023,7 +21023,7 @@ pub unsafe extern "C" fn spSkeletonData_findPathConstraint(
         }
         i += 1;
     }
-    std::ptr::null_mut::<spPathConstraintData>()
+    core::ptr::null_mut::<spPathConstraintData>()
 }
 #[no_mangle]
 pub unsafe extern "C" fn spSkeletonData_findPhysicsConstraint(
@@ -21042,7 +21042,7 @@ pub unsafe extern "C" fn spSkeletonData_findPhysicsConstraint(
         }
         i += 1;
     }
-    std::ptr::null_mut::<spPhysicsConstraintData>()
+    core::ptr::null_mut::<spPhysicsConstraintData>()
 }
 #[no_mangle]
 pub unsafe extern "C" fn spSkeletonJson_createWithLoader(
@@ -21112,7 +21112,7 @@ pub unsafe extern "C" fn _spSkeletonJson_setError(
 }
 unsafe extern "C" fn toColor(mut value: *const c_char, mut index: c_int) -> c_float {
     let mut digits: [c_char; 3] = [0; 3];
-    let mut error: *mut c_char = std::ptr::null_mut::<c_char>();
+    let mut error: *mut c_char = core::ptr::null_mut::<c_char>();
     let mut color: c_int = 0;
     if index as size_t >= (spine_strlen(value)).wrapping_div(2 as c_int as c_ulong) {
         return -(1 as c_int) as c_float;
@@ -21228,8 +21228,8 @@ unsafe extern "C" fn readTimelineJson(
     let mut bezier: c_int = 0 as c_int;
     frame = 0 as c_int;
     loop {
-        let mut nextMap: *mut Json = std::ptr::null_mut::<Json>();
-        let mut curve: *mut Json = std::ptr::null_mut::<Json>();
+        let mut nextMap: *mut Json = core::ptr::null_mut::<Json>();
+        let mut curve: *mut Json = core::ptr::null_mut::<Json>();
         let mut time2: c_float = 0.;
         let mut value2: c_float = 0.;
         spCurveTimeline1_setFrame(timeline, frame, time, value);
@@ -21279,8 +21279,8 @@ unsafe extern "C" fn readTimeline2Json(
     let mut bezier: c_int = 0 as c_int;
     frame = 0 as c_int;
     loop {
-        let mut nextMap: *mut Json = std::ptr::null_mut::<Json>();
-        let mut curve: *mut Json = std::ptr::null_mut::<Json>();
+        let mut nextMap: *mut Json = core::ptr::null_mut::<Json>();
+        let mut curve: *mut Json = core::ptr::null_mut::<Json>();
         let mut time2: c_float = 0.;
         let mut nvalue1: c_float = 0.;
         let mut nvalue2: c_float = 0.;
@@ -21314,9 +21314,9 @@ unsafe extern "C" fn readTimeline2Json(
     &mut (*timeline).super_0
 }
 unsafe extern "C" fn readSequenceJson(mut item: *mut Json) -> *mut spSequence {
-    let mut sequence: *mut spSequence = std::ptr::null_mut::<spSequence>();
+    let mut sequence: *mut spSequence = core::ptr::null_mut::<spSequence>();
     if item.is_null() {
-        return std::ptr::null_mut::<spSequence>();
+        return core::ptr::null_mut::<spSequence>();
     }
     sequence = spSequence_create(Json_getInt(
         item,
@@ -21344,10 +21344,10 @@ unsafe extern "C" fn _spSkeletonJson_addLinkedMesh(
     mut parent: *const c_char,
     mut inheritDeform: c_int,
 ) {
-    let mut linkedMesh: *mut _spLinkedMeshJson = std::ptr::null_mut::<_spLinkedMeshJson>();
+    let mut linkedMesh: *mut _spLinkedMeshJson = core::ptr::null_mut::<_spLinkedMeshJson>();
     let mut internal: *mut _spSkeletonJson = self_0.cast::<_spSkeletonJson>();
     if (*internal).linkedMeshCount == (*internal).linkedMeshCapacity {
-        let mut linkedMeshes: *mut _spLinkedMeshJson = std::ptr::null_mut::<_spLinkedMeshJson>();
+        let mut linkedMeshes: *mut _spLinkedMeshJson = core::ptr::null_mut::<_spLinkedMeshJson>();
         (*internal).linkedMeshCapacity *= 2 as c_int;
         if (*internal).linkedMeshCapacity < 8 as c_int {
             (*internal).linkedMeshCapacity = 8 as c_int;
@@ -21401,7 +21401,7 @@ unsafe extern "C" fn findSlotIndex(
     cleanUpTimelines(timelines);
     _spSkeletonJson_setError(
         json,
-        std::ptr::null_mut::<Json>(),
+        core::ptr::null_mut::<Json>(),
         (b"Slot not found: \0" as *const u8).cast::<c_char>(),
         slotName,
     );
@@ -21427,7 +21427,7 @@ pub unsafe extern "C" fn findIkConstraintIndex(
     cleanUpTimelines(timelines);
     _spSkeletonJson_setError(
         json,
-        std::ptr::null_mut::<Json>(),
+        core::ptr::null_mut::<Json>(),
         (b"IK constraint not found: \0" as *const u8).cast::<c_char>(),
         (*constraint).name,
     );
@@ -21453,7 +21453,7 @@ pub unsafe extern "C" fn findTransformConstraintIndex(
     cleanUpTimelines(timelines);
     _spSkeletonJson_setError(
         json,
-        std::ptr::null_mut::<Json>(),
+        core::ptr::null_mut::<Json>(),
         (b"Transform constraint not found: \0" as *const u8).cast::<c_char>(),
         (*constraint).name,
     );
@@ -21479,7 +21479,7 @@ pub unsafe extern "C" fn findPathConstraintIndex(
     cleanUpTimelines(timelines);
     _spSkeletonJson_setError(
         json,
-        std::ptr::null_mut::<Json>(),
+        core::ptr::null_mut::<Json>(),
         (b"Path constraint not found: \0" as *const u8).cast::<c_char>(),
         (*constraint).name,
     );
@@ -21505,7 +21505,7 @@ pub unsafe extern "C" fn findPhysicsConstraintIndex(
     cleanUpTimelines(timelines);
     _spSkeletonJson_setError(
         json,
-        std::ptr::null_mut::<Json>(),
+        core::ptr::null_mut::<Json>(),
         (b"Physics constraint not found: \0" as *const u8).cast::<c_char>(),
         (*constraint).name,
     );
@@ -21531,14 +21531,14 @@ unsafe extern "C" fn _spSkeletonJson_readAnimation(
     let mut drawOrderJson: *mut Json =
         Json_getItem(root, (b"drawOrder\0" as *const u8).cast::<c_char>());
     let mut events: *mut Json = Json_getItem(root, (b"events\0" as *const u8).cast::<c_char>());
-    let mut boneMap: *mut Json = std::ptr::null_mut::<Json>();
-    let mut slotMap: *mut Json = std::ptr::null_mut::<Json>();
-    let mut keyMap: *mut Json = std::ptr::null_mut::<Json>();
-    let mut nextMap: *mut Json = std::ptr::null_mut::<Json>();
-    let mut curve: *mut Json = std::ptr::null_mut::<Json>();
-    let mut timelineMap: *mut Json = std::ptr::null_mut::<Json>();
-    let mut attachmentsMap: *mut Json = std::ptr::null_mut::<Json>();
-    let mut constraintMap: *mut Json = std::ptr::null_mut::<Json>();
+    let mut boneMap: *mut Json = core::ptr::null_mut::<Json>();
+    let mut slotMap: *mut Json = core::ptr::null_mut::<Json>();
+    let mut keyMap: *mut Json = core::ptr::null_mut::<Json>();
+    let mut nextMap: *mut Json = core::ptr::null_mut::<Json>();
+    let mut curve: *mut Json = core::ptr::null_mut::<Json>();
+    let mut timelineMap: *mut Json = core::ptr::null_mut::<Json>();
+    let mut attachmentsMap: *mut Json = core::ptr::null_mut::<Json>();
+    let mut constraintMap: *mut Json = core::ptr::null_mut::<Json>();
     let mut frame: c_int = 0;
     let mut bezier: c_int = 0;
     let mut i: c_int = 0;
@@ -21570,12 +21570,12 @@ unsafe extern "C" fn _spSkeletonJson_readAnimation(
     slotMap = if !slots.is_null() {
         (*slots).child
     } else {
-        std::ptr::null_mut::<Json>()
+        core::ptr::null_mut::<Json>()
     };
     while !slotMap.is_null() {
         let mut slotIndex: c_int = findSlotIndex(self_0, skeletonData, (*slotMap).name, timelines);
         if slotIndex == -(1 as c_int) {
-            return std::ptr::null_mut::<spAnimation>();
+            return core::ptr::null_mut::<spAnimation>();
         }
         timelineMap = (*slotMap).child;
         while !timelineMap.is_null() {
@@ -21604,7 +21604,7 @@ unsafe extern "C" fn _spSkeletonJson_readAnimation(
                             (*Json_getItem(keyMap, (b"name\0" as *const u8).cast::<c_char>()))
                                 .valueString
                         } else {
-                            std::ptr::null::<c_char>()
+                            core::ptr::null::<c_char>()
                         },
                     );
                     keyMap = (*keyMap).next;
@@ -21630,7 +21630,7 @@ unsafe extern "C" fn _spSkeletonJson_readAnimation(
                     Json_getString(
                         keyMap,
                         (b"color\0" as *const u8).cast::<c_char>(),
-                        std::ptr::null::<c_char>(),
+                        core::ptr::null::<c_char>(),
                     ),
                     1 as c_int,
                 );
@@ -21655,7 +21655,7 @@ unsafe extern "C" fn _spSkeletonJson_readAnimation(
                         Json_getString(
                             nextMap,
                             (b"color\0" as *const u8).cast::<c_char>(),
-                            std::ptr::null::<c_char>(),
+                            core::ptr::null::<c_char>(),
                         ),
                         1 as c_int,
                     );
@@ -21735,7 +21735,7 @@ unsafe extern "C" fn _spSkeletonJson_readAnimation(
                     Json_getString(
                         keyMap,
                         (b"color\0" as *const u8).cast::<c_char>(),
-                        std::ptr::null::<c_char>(),
+                        core::ptr::null::<c_char>(),
                     ),
                     1 as c_int,
                 );
@@ -21758,7 +21758,7 @@ unsafe extern "C" fn _spSkeletonJson_readAnimation(
                         Json_getString(
                             nextMap,
                             (b"color\0" as *const u8).cast::<c_char>(),
-                            std::ptr::null::<c_char>(),
+                            core::ptr::null::<c_char>(),
                         ),
                         1 as c_int,
                     );
@@ -21844,7 +21844,7 @@ unsafe extern "C" fn _spSkeletonJson_readAnimation(
                     Json_getString(
                         keyMap,
                         (b"light\0" as *const u8).cast::<c_char>(),
-                        std::ptr::null::<c_char>(),
+                        core::ptr::null::<c_char>(),
                     ),
                     1 as c_int,
                 );
@@ -21853,7 +21853,7 @@ unsafe extern "C" fn _spSkeletonJson_readAnimation(
                     Json_getString(
                         keyMap,
                         (b"dark\0" as *const u8).cast::<c_char>(),
-                        std::ptr::null::<c_char>(),
+                        core::ptr::null::<c_char>(),
                     ),
                     0 as c_int,
                 );
@@ -21879,7 +21879,7 @@ unsafe extern "C" fn _spSkeletonJson_readAnimation(
                         Json_getString(
                             nextMap,
                             (b"light\0" as *const u8).cast::<c_char>(),
-                            std::ptr::null::<c_char>(),
+                            core::ptr::null::<c_char>(),
                         ),
                         1 as c_int,
                     );
@@ -21888,7 +21888,7 @@ unsafe extern "C" fn _spSkeletonJson_readAnimation(
                         Json_getString(
                             nextMap,
                             (b"dark\0" as *const u8).cast::<c_char>(),
-                            std::ptr::null::<c_char>(),
+                            core::ptr::null::<c_char>(),
                         ),
                         0 as c_int,
                     );
@@ -22005,7 +22005,7 @@ unsafe extern "C" fn _spSkeletonJson_readAnimation(
                     Json_getString(
                         keyMap,
                         (b"light\0" as *const u8).cast::<c_char>(),
-                        std::ptr::null::<c_char>(),
+                        core::ptr::null::<c_char>(),
                     ),
                     0 as c_int,
                 );
@@ -22014,7 +22014,7 @@ unsafe extern "C" fn _spSkeletonJson_readAnimation(
                     Json_getString(
                         keyMap,
                         (b"dark\0" as *const u8).cast::<c_char>(),
-                        std::ptr::null::<c_char>(),
+                        core::ptr::null::<c_char>(),
                     ),
                     0 as c_int,
                 );
@@ -22040,7 +22040,7 @@ unsafe extern "C" fn _spSkeletonJson_readAnimation(
                         Json_getString(
                             nextMap,
                             (b"light\0" as *const u8).cast::<c_char>(),
-                            std::ptr::null::<c_char>(),
+                            core::ptr::null::<c_char>(),
                         ),
                         0 as c_int,
                     );
@@ -22049,7 +22049,7 @@ unsafe extern "C" fn _spSkeletonJson_readAnimation(
                         Json_getString(
                             nextMap,
                             (b"dark\0" as *const u8).cast::<c_char>(),
-                            std::ptr::null::<c_char>(),
+                            core::ptr::null::<c_char>(),
                         ),
                         0 as c_int,
                     );
@@ -22139,11 +22139,11 @@ unsafe extern "C" fn _spSkeletonJson_readAnimation(
                 cleanUpTimelines(timelines);
                 _spSkeletonJson_setError(
                     self_0,
-                    std::ptr::null_mut::<Json>(),
+                    core::ptr::null_mut::<Json>(),
                     (b"Invalid timeline type for a slot: \0" as *const u8).cast::<c_char>(),
                     (*timelineMap).name,
                 );
-                return std::ptr::null_mut::<spAnimation>();
+                return core::ptr::null_mut::<spAnimation>();
             }
             timelineMap = (*timelineMap).next;
         }
@@ -22152,7 +22152,7 @@ unsafe extern "C" fn _spSkeletonJson_readAnimation(
     boneMap = if !bones.is_null() {
         (*bones).child
     } else {
-        std::ptr::null_mut::<Json>()
+        core::ptr::null_mut::<Json>()
     };
     while !boneMap.is_null() {
         let mut boneIndex: c_int = -(1 as c_int);
@@ -22173,11 +22173,11 @@ unsafe extern "C" fn _spSkeletonJson_readAnimation(
             cleanUpTimelines(timelines);
             _spSkeletonJson_setError(
                 self_0,
-                std::ptr::null_mut::<Json>(),
+                core::ptr::null_mut::<Json>(),
                 (b"Bone not found: \0" as *const u8).cast::<c_char>(),
                 (*boneMap).name,
             );
-            return std::ptr::null_mut::<spAnimation>();
+            return core::ptr::null_mut::<spAnimation>();
         }
         timelineMap = (*boneMap).child;
         while !timelineMap.is_null() {
@@ -22416,11 +22416,11 @@ unsafe extern "C" fn _spSkeletonJson_readAnimation(
                     cleanUpTimelines(timelines);
                     _spSkeletonJson_setError(
                         self_0,
-                        std::ptr::null_mut::<Json>(),
+                        core::ptr::null_mut::<Json>(),
                         (b"Invalid timeline type for a bone: \0" as *const u8).cast::<c_char>(),
                         (*timelineMap).name,
                     );
-                    return std::ptr::null_mut::<spAnimation>();
+                    return core::ptr::null_mut::<spAnimation>();
                 }
             }
             timelineMap = (*timelineMap).next;
@@ -22430,12 +22430,12 @@ unsafe extern "C" fn _spSkeletonJson_readAnimation(
     constraintMap = if !ik.is_null() {
         (*ik).child
     } else {
-        std::ptr::null_mut::<Json>()
+        core::ptr::null_mut::<Json>()
     };
     while !constraintMap.is_null() {
-        let mut constraint: *mut spIkConstraintData = std::ptr::null_mut::<spIkConstraintData>();
+        let mut constraint: *mut spIkConstraintData = core::ptr::null_mut::<spIkConstraintData>();
         let mut timeline_14: *mut spIkConstraintTimeline =
-            std::ptr::null_mut::<spIkConstraintTimeline>();
+            core::ptr::null_mut::<spIkConstraintTimeline>();
         let mut constraintIndex: c_int = 0;
         let mut time_4: c_float = 0.;
         let mut mix: c_float = 0.;
@@ -22445,7 +22445,7 @@ unsafe extern "C" fn _spSkeletonJson_readAnimation(
             constraint = spSkeletonData_findIkConstraint(skeletonData, (*constraintMap).name);
             constraintIndex = findIkConstraintIndex(self_0, skeletonData, constraint, timelines);
             if constraintIndex == -(1 as c_int) {
-                return std::ptr::null_mut::<spAnimation>();
+                return core::ptr::null_mut::<spAnimation>();
             }
             timeline_14 = spIkConstraintTimeline_create(
                 (*constraintMap).size,
@@ -22570,13 +22570,13 @@ unsafe extern "C" fn _spSkeletonJson_readAnimation(
     constraintMap = if !transform.is_null() {
         (*transform).child
     } else {
-        std::ptr::null_mut::<Json>()
+        core::ptr::null_mut::<Json>()
     };
     while !constraintMap.is_null() {
         let mut constraint_0: *mut spTransformConstraintData =
-            std::ptr::null_mut::<spTransformConstraintData>();
+            core::ptr::null_mut::<spTransformConstraintData>();
         let mut timeline_15: *mut spTransformConstraintTimeline =
-            std::ptr::null_mut::<spTransformConstraintTimeline>();
+            core::ptr::null_mut::<spTransformConstraintTimeline>();
         let mut constraintIndex_0: c_int = 0;
         let mut time_5: c_float = 0.;
         let mut mixRotate: c_float = 0.;
@@ -22592,7 +22592,7 @@ unsafe extern "C" fn _spSkeletonJson_readAnimation(
             constraintIndex_0 =
                 findTransformConstraintIndex(self_0, skeletonData, constraint_0, timelines);
             if constraintIndex_0 == -(1 as c_int) {
-                return std::ptr::null_mut::<spAnimation>();
+                return core::ptr::null_mut::<spAnimation>();
             }
             timeline_15 = spTransformConstraintTimeline_create(
                 (*constraintMap).size,
@@ -22778,7 +22778,7 @@ unsafe extern "C" fn _spSkeletonJson_readAnimation(
     constraintMap = if !paths.is_null() {
         (*paths).child
     } else {
-        std::ptr::null_mut::<Json>()
+        core::ptr::null_mut::<Json>()
     };
     while !constraintMap.is_null() {
         let mut constraint_1: *mut spPathConstraintData =
@@ -22786,11 +22786,11 @@ unsafe extern "C" fn _spSkeletonJson_readAnimation(
         let mut constraintIndex_1: c_int =
             findPathConstraintIndex(self_0, skeletonData, constraint_1, timelines);
         if constraintIndex_1 == -(1 as c_int) {
-            return std::ptr::null_mut::<spAnimation>();
+            return core::ptr::null_mut::<spAnimation>();
         }
         timelineMap = (*constraintMap).child;
         while !timelineMap.is_null() {
-            let mut timelineName: *const c_char = std::ptr::null::<c_char>();
+            let mut timelineName: *const c_char = core::ptr::null::<c_char>();
             let mut frames_1: c_int = 0;
             keyMap = (*timelineMap).child;
             if !keyMap.is_null() {
@@ -22974,7 +22974,7 @@ unsafe extern "C" fn _spSkeletonJson_readAnimation(
     constraintMap = if !physics.is_null() {
         (*physics).child
     } else {
-        std::ptr::null_mut::<Json>()
+        core::ptr::null_mut::<Json>()
     };
     while !constraintMap.is_null() {
         let mut index: c_int = -(1 as c_int);
@@ -22985,7 +22985,7 @@ unsafe extern "C" fn _spSkeletonJson_readAnimation(
                 spSkeletonData_findPhysicsConstraint(skeletonData, (*constraintMap).name);
             index = findPhysicsConstraintIndex(self_0, skeletonData, constraint_2, timelines);
             if index == -(1 as c_int) {
-                return std::ptr::null_mut::<spAnimation>();
+                return core::ptr::null_mut::<spAnimation>();
             }
         }
         let mut current_block_322: u64;
@@ -23017,7 +23017,7 @@ unsafe extern "C" fn _spSkeletonJson_readAnimation(
                     spTimelineArray_add(timelines, &mut (*timeline_19).super_0);
                 } else {
                     let mut timeline_20: *mut spPhysicsConstraintTimeline =
-                        std::ptr::null_mut::<spPhysicsConstraintTimeline>();
+                        core::ptr::null_mut::<spPhysicsConstraintTimeline>();
                     if spine_strcmp(timelineName_0, (b"inertia\0" as *const u8).cast::<c_char>())
                         == 0 as c_int
                     {
@@ -23124,17 +23124,17 @@ unsafe extern "C" fn _spSkeletonJson_readAnimation(
     attachmentsMap = if !attachmentsJson.is_null() {
         (*attachmentsJson).child
     } else {
-        std::ptr::null_mut::<Json>()
+        core::ptr::null_mut::<Json>()
     };
     while !attachmentsMap.is_null() {
         let mut skin: *mut spSkin = spSkeletonData_findSkin(skeletonData, (*attachmentsMap).name);
         slotMap = (*attachmentsMap).child;
         while !slotMap.is_null() {
-            let mut attachmentMap: *mut Json = std::ptr::null_mut::<Json>();
+            let mut attachmentMap: *mut Json = core::ptr::null_mut::<Json>();
             let mut slotIndex_0: c_int =
                 findSlotIndex(self_0, skeletonData, (*slotMap).name, timelines);
             if slotIndex_0 == -(1 as c_int) {
-                return std::ptr::null_mut::<spAnimation>();
+                return core::ptr::null_mut::<spAnimation>();
             }
             attachmentMap = (*slotMap).child;
             while !attachmentMap.is_null() {
@@ -23144,16 +23144,16 @@ unsafe extern "C" fn _spSkeletonJson_readAnimation(
                     cleanUpTimelines(timelines);
                     _spSkeletonJson_setError(
                         self_0,
-                        std::ptr::null_mut::<Json>(),
+                        core::ptr::null_mut::<Json>(),
                         (b"Attachment not found: \0" as *const u8).cast::<c_char>(),
                         (*attachmentMap).name,
                     );
-                    return std::ptr::null_mut::<spAnimation>();
+                    return core::ptr::null_mut::<spAnimation>();
                 }
                 timelineMap = (*attachmentMap).child;
                 while !timelineMap.is_null() {
                     let mut frames_3: c_int = 0;
-                    let mut timelineName_1: *const c_char = std::ptr::null::<c_char>();
+                    let mut timelineName_1: *const c_char = core::ptr::null::<c_char>();
                     keyMap = (*timelineMap).child;
                     if !keyMap.is_null() {
                         frames_3 = (*timelineMap).size;
@@ -23161,16 +23161,16 @@ unsafe extern "C" fn _spSkeletonJson_readAnimation(
                         if spine_strcmp((b"deform\0" as *const u8).cast::<c_char>(), timelineName_1)
                             == 0
                         {
-                            let mut tempDeform: *mut c_float = std::ptr::null_mut::<c_float>();
+                            let mut tempDeform: *mut c_float = core::ptr::null_mut::<c_float>();
                             let mut vertexAttachment: *mut spVertexAttachment =
-                                std::ptr::null_mut::<spVertexAttachment>();
+                                core::ptr::null_mut::<spVertexAttachment>();
                             let mut weighted: c_int = 0;
                             let mut deformLength: c_int = 0;
                             let mut timeline_21: *mut spDeformTimeline =
-                                std::ptr::null_mut::<spDeformTimeline>();
+                                core::ptr::null_mut::<spDeformTimeline>();
                             let mut time_7: c_float = 0.;
                             vertexAttachment = baseAttachment.cast::<spVertexAttachment>();
-                            weighted = ((*vertexAttachment).bones != std::ptr::null_mut::<c_int>())
+                            weighted = ((*vertexAttachment).bones != core::ptr::null_mut::<c_int>())
                                 as c_int;
                             deformLength = if weighted != 0 {
                                 (*vertexAttachment).verticesCount / 3 as c_int * 2 as c_int
@@ -23203,7 +23203,7 @@ unsafe extern "C" fn _spSkeletonJson_readAnimation(
                                     keyMap,
                                     (b"vertices\0" as *const u8).cast::<c_char>(),
                                 );
-                                let mut deform: *mut c_float = std::ptr::null_mut::<c_float>();
+                                let mut deform: *mut c_float = core::ptr::null_mut::<c_float>();
                                 let mut time2_6: c_float = 0.;
                                 if vertices.is_null() {
                                     if weighted != 0 {
@@ -23224,7 +23224,7 @@ unsafe extern "C" fn _spSkeletonJson_readAnimation(
                                         (b"offset\0" as *const u8).cast::<c_char>(),
                                         0 as c_int,
                                     );
-                                    let mut vertex: *mut Json = std::ptr::null_mut::<Json>();
+                                    let mut vertex: *mut Json = core::ptr::null_mut::<Json>();
                                     deform = tempDeform;
                                     spine_memset(
                                         deform.cast::<c_void>(),
@@ -23404,11 +23404,11 @@ unsafe extern "C" fn _spSkeletonJson_readAnimation(
         frame = 0 as c_int;
         while !keyMap.is_null() {
             let mut ii: c_int = 0;
-            let mut drawOrder: *mut c_int = std::ptr::null_mut::<c_int>();
+            let mut drawOrder: *mut c_int = core::ptr::null_mut::<c_int>();
             let mut offsets: *mut Json =
                 Json_getItem(keyMap, (b"offsets\0" as *const u8).cast::<c_char>());
             if !offsets.is_null() {
-                let mut offsetMap: *mut Json = std::ptr::null_mut::<Json>();
+                let mut offsetMap: *mut Json = core::ptr::null_mut::<Json>();
                 let mut unchanged: *mut c_int = _spMalloc(
                     (::core::mem::size_of::<c_int>() as c_ulong)
                         .wrapping_mul(((*skeletonData).slotsCount - (*offsets).size) as c_ulong),
@@ -23438,12 +23438,12 @@ unsafe extern "C" fn _spSkeletonJson_readAnimation(
                         Json_getString(
                             offsetMap,
                             (b"slot\0" as *const u8).cast::<c_char>(),
-                            std::ptr::null::<c_char>(),
+                            core::ptr::null::<c_char>(),
                         ),
                         timelines,
                     );
                     if slotIndex_1 == -(1 as c_int) {
-                        return std::ptr::null_mut::<spAnimation>();
+                        return core::ptr::null_mut::<spAnimation>();
                     }
                     while originalIndex != slotIndex_1 {
                         let fresh131 = originalIndex;
@@ -23501,29 +23501,29 @@ unsafe extern "C" fn _spSkeletonJson_readAnimation(
         keyMap = (*events).child;
         frame = 0 as c_int;
         while !keyMap.is_null() {
-            let mut event: *mut spEvent = std::ptr::null_mut::<spEvent>();
-            let mut stringValue: *const c_char = std::ptr::null::<c_char>();
+            let mut event: *mut spEvent = core::ptr::null_mut::<spEvent>();
+            let mut stringValue: *const c_char = core::ptr::null::<c_char>();
             let mut eventData: *mut spEventData = spSkeletonData_findEvent(
                 skeletonData,
                 Json_getString(
                     keyMap,
                     (b"name\0" as *const u8).cast::<c_char>(),
-                    std::ptr::null::<c_char>(),
+                    core::ptr::null::<c_char>(),
                 ),
             );
             if eventData.is_null() {
                 cleanUpTimelines(timelines);
                 _spSkeletonJson_setError(
                     self_0,
-                    std::ptr::null_mut::<Json>(),
+                    core::ptr::null_mut::<Json>(),
                     (b"Event not found: \0" as *const u8).cast::<c_char>(),
                     Json_getString(
                         keyMap,
                         (b"name\0" as *const u8).cast::<c_char>(),
-                        std::ptr::null::<c_char>(),
+                        core::ptr::null::<c_char>(),
                     ),
                 );
-                return std::ptr::null_mut::<spAnimation>();
+                return core::ptr::null_mut::<spAnimation>();
             }
             event = spEvent_create(
                 Json_getFloat(
@@ -23563,12 +23563,12 @@ unsafe extern "C" fn _spSkeletonJson_readAnimation(
                 (*event).volume = Json_getFloat(
                     keyMap,
                     (b"volume\0" as *const u8).cast::<c_char>(),
-                    1 as c_int as c_float,
+                    (*eventData).volume,
                 );
                 (*event).balance = Json_getFloat(
                     keyMap,
-                    (b"volume\0" as *const u8).cast::<c_char>(),
-                    0 as c_int as c_float,
+                    (b"balance\0" as *const u8).cast::<c_char>(),
+                    (*eventData).balance,
                 );
             }
             spEventTimeline_setFrame(timeline_24, frame, event);
@@ -23596,14 +23596,14 @@ unsafe extern "C" fn _readVerticesJson(
     mut attachment: *mut spVertexAttachment,
     mut verticesLength: c_int,
 ) {
-    let mut entry: *mut Json = std::ptr::null_mut::<Json>();
-    let mut vertices: *mut c_float = std::ptr::null_mut::<c_float>();
+    let mut entry: *mut Json = core::ptr::null_mut::<Json>();
+    let mut vertices: *mut c_float = core::ptr::null_mut::<c_float>();
     let mut i: c_int = 0;
     let mut n: c_int = 0;
     let mut nn: c_int = 0;
     let mut entrySize: c_int = 0;
-    let mut weights: *mut spFloatArray = std::ptr::null_mut::<spFloatArray>();
-    let mut bones: *mut spIntArray = std::ptr::null_mut::<spIntArray>();
+    let mut weights: *mut spFloatArray = core::ptr::null_mut::<spFloatArray>();
+    let mut bones: *mut spIntArray = core::ptr::null_mut::<spIntArray>();
     (*attachment).worldVerticesLength = verticesLength;
     entry = Json_getItem(attachmentMap, (b"vertices\0" as *const u8).cast::<c_char>());
     entrySize = (*entry).size;
@@ -23631,7 +23631,7 @@ unsafe extern "C" fn _readVerticesJson(
         (*attachment).verticesCount = verticesLength;
         (*attachment).vertices = vertices;
         (*attachment).bonesCount = 0 as c_int;
-        (*attachment).bones = std::ptr::null_mut::<c_int>();
+        (*attachment).bones = core::ptr::null_mut::<c_int>();
         return;
     }
     weights = spFloatArray_create(verticesLength * 3 as c_int * 3 as c_int);
@@ -23672,16 +23672,16 @@ pub unsafe extern "C" fn spSkeletonJson_readSkeletonDataFile(
     mut path: *const c_char,
 ) -> *mut spSkeletonData {
     let mut length: c_int = 0;
-    let mut skeletonData: *mut spSkeletonData = std::ptr::null_mut::<spSkeletonData>();
+    let mut skeletonData: *mut spSkeletonData = core::ptr::null_mut::<spSkeletonData>();
     let mut json: *const c_char = _spUtil_readFile(path, &mut length);
     if length == 0 as c_int || json.is_null() {
         _spSkeletonJson_setError(
             self_0,
-            std::ptr::null_mut::<Json>(),
+            core::ptr::null_mut::<Json>(),
             (b"Unable to read skeleton file: \0" as *const u8).cast::<c_char>(),
             path,
         );
-        return std::ptr::null_mut::<spSkeletonData>();
+        return core::ptr::null_mut::<spSkeletonData>();
     }
     skeletonData = spSkeletonJson_readSkeletonData(self_0, json);
     _spFree(json as *mut c_void);
@@ -23718,32 +23718,32 @@ pub unsafe extern "C" fn spSkeletonJson_readSkeletonData(
 ) -> *mut spSkeletonData {
     let mut i: c_int = 0;
     let mut ii: c_int = 0;
-    let mut skeletonData: *mut spSkeletonData = std::ptr::null_mut::<spSkeletonData>();
-    let mut root: *mut Json = std::ptr::null_mut::<Json>();
-    let mut skeleton: *mut Json = std::ptr::null_mut::<Json>();
-    let mut bones: *mut Json = std::ptr::null_mut::<Json>();
-    let mut boneMap: *mut Json = std::ptr::null_mut::<Json>();
-    let mut ik: *mut Json = std::ptr::null_mut::<Json>();
-    let mut transform: *mut Json = std::ptr::null_mut::<Json>();
-    let mut pathJson: *mut Json = std::ptr::null_mut::<Json>();
-    let mut physics: *mut Json = std::ptr::null_mut::<Json>();
-    let mut slots: *mut Json = std::ptr::null_mut::<Json>();
-    let mut skins: *mut Json = std::ptr::null_mut::<Json>();
-    let mut animations: *mut Json = std::ptr::null_mut::<Json>();
-    let mut events: *mut Json = std::ptr::null_mut::<Json>();
+    let mut skeletonData: *mut spSkeletonData = core::ptr::null_mut::<spSkeletonData>();
+    let mut root: *mut Json = core::ptr::null_mut::<Json>();
+    let mut skeleton: *mut Json = core::ptr::null_mut::<Json>();
+    let mut bones: *mut Json = core::ptr::null_mut::<Json>();
+    let mut boneMap: *mut Json = core::ptr::null_mut::<Json>();
+    let mut ik: *mut Json = core::ptr::null_mut::<Json>();
+    let mut transform: *mut Json = core::ptr::null_mut::<Json>();
+    let mut pathJson: *mut Json = core::ptr::null_mut::<Json>();
+    let mut physics: *mut Json = core::ptr::null_mut::<Json>();
+    let mut slots: *mut Json = core::ptr::null_mut::<Json>();
+    let mut skins: *mut Json = core::ptr::null_mut::<Json>();
+    let mut animations: *mut Json = core::ptr::null_mut::<Json>();
+    let mut events: *mut Json = core::ptr::null_mut::<Json>();
     let mut internal: *mut _spSkeletonJson = self_0.cast::<_spSkeletonJson>();
     _spFree((*self_0).error.cast::<c_void>());
-    (*self_0).error = std::ptr::null_mut::<c_char>();
+    (*self_0).error = core::ptr::null_mut::<c_char>();
     (*internal).linkedMeshCount = 0 as c_int;
     root = Json_create(json);
     if root.is_null() {
         _spSkeletonJson_setError(
             self_0,
-            std::ptr::null_mut::<Json>(),
+            core::ptr::null_mut::<Json>(),
             (b"Invalid skeleton JSON: \0" as *const u8).cast::<c_char>(),
             Json_getError(),
         );
-        return std::ptr::null_mut::<spSkeletonData>();
+        return core::ptr::null_mut::<spSkeletonData>();
     }
     skeletonData = spSkeletonData_create();
     skeleton = Json_getItem(root, (b"skeleton\0" as *const u8).cast::<c_char>());
@@ -23806,11 +23806,11 @@ pub unsafe extern "C" fn spSkeletonJson_readSkeletonData(
             );
             _spSkeletonJson_setError(
                 self_0,
-                std::ptr::null_mut::<Json>(),
+                core::ptr::null_mut::<Json>(),
                 errorMsg.as_mut_ptr(),
-                std::ptr::null::<c_char>(),
+                core::ptr::null::<c_char>(),
             );
-            return std::ptr::null_mut::<spSkeletonData>();
+            return core::ptr::null_mut::<spSkeletonData>();
         }
         (*skeletonData).x = Json_getFloat(
             skeleton,
@@ -23845,10 +23845,10 @@ pub unsafe extern "C" fn spSkeletonJson_readSkeletonData(
         (*skeletonData).imagesPath = Json_getString(
             skeleton,
             (b"images\0" as *const u8).cast::<c_char>(),
-            std::ptr::null::<c_char>(),
+            core::ptr::null::<c_char>(),
         );
         if !((*skeletonData).imagesPath).is_null() {
-            let mut tmp: *mut c_char = std::ptr::null_mut::<c_char>();
+            let mut tmp: *mut c_char = core::ptr::null_mut::<c_char>();
             tmp = _spMalloc(
                 (::core::mem::size_of::<c_char>() as c_ulong).wrapping_mul(
                     (spine_strlen((*skeletonData).imagesPath)).wrapping_add(1 as c_int as c_ulong),
@@ -23863,10 +23863,10 @@ pub unsafe extern "C" fn spSkeletonJson_readSkeletonData(
         (*skeletonData).audioPath = Json_getString(
             skeleton,
             (b"audio\0" as *const u8).cast::<c_char>(),
-            std::ptr::null::<c_char>(),
+            core::ptr::null::<c_char>(),
         );
         if !((*skeletonData).audioPath).is_null() {
-            let mut tmp_0: *mut c_char = std::ptr::null_mut::<c_char>();
+            let mut tmp_0: *mut c_char = core::ptr::null_mut::<c_char>();
             tmp_0 = _spMalloc(
                 (::core::mem::size_of::<c_char>() as c_ulong).wrapping_mul(
                     (spine_strlen((*skeletonData).audioPath)).wrapping_add(1 as c_int as c_ulong),
@@ -23890,14 +23890,14 @@ pub unsafe extern "C" fn spSkeletonJson_readSkeletonData(
     boneMap = (*bones).child;
     i = 0 as c_int;
     while !boneMap.is_null() {
-        let mut data: *mut spBoneData = std::ptr::null_mut::<spBoneData>();
-        let mut inherit: *const c_char = std::ptr::null::<c_char>();
-        let mut color: *const c_char = std::ptr::null::<c_char>();
-        let mut parent: *mut spBoneData = std::ptr::null_mut::<spBoneData>();
+        let mut data: *mut spBoneData = core::ptr::null_mut::<spBoneData>();
+        let mut inherit: *const c_char = core::ptr::null::<c_char>();
+        let mut color: *const c_char = core::ptr::null::<c_char>();
+        let mut parent: *mut spBoneData = core::ptr::null_mut::<spBoneData>();
         let mut parentName: *const c_char = Json_getString(
             boneMap,
             (b"parent\0" as *const u8).cast::<c_char>(),
-            std::ptr::null::<c_char>(),
+            core::ptr::null::<c_char>(),
         );
         if !parentName.is_null() {
             parent = spSkeletonData_findBone(skeletonData, parentName);
@@ -23909,7 +23909,7 @@ pub unsafe extern "C" fn spSkeletonJson_readSkeletonData(
                     (b"Parent bone not found: \0" as *const u8).cast::<c_char>(),
                     parentName,
                 );
-                return std::ptr::null_mut::<spSkeletonData>();
+                return core::ptr::null_mut::<spSkeletonData>();
             }
         }
         data = spBoneData_create(
@@ -23917,7 +23917,7 @@ pub unsafe extern "C" fn spSkeletonJson_readSkeletonData(
             Json_getString(
                 boneMap,
                 (b"name\0" as *const u8).cast::<c_char>(),
-                std::ptr::null::<c_char>(),
+                core::ptr::null::<c_char>(),
             ),
             parent,
         );
@@ -24004,7 +24004,7 @@ pub unsafe extern "C" fn spSkeletonJson_readSkeletonData(
         color = Json_getString(
             boneMap,
             (b"color\0" as *const u8).cast::<c_char>(),
-            std::ptr::null::<c_char>(),
+            core::ptr::null::<c_char>(),
         );
         if !color.is_null() {
             toColor2(&mut (*data).color, color, -(1 as c_int));
@@ -24015,7 +24015,7 @@ pub unsafe extern "C" fn spSkeletonJson_readSkeletonData(
             (b"\0" as *const u8).cast::<c_char>(),
         );
         if !((*data).icon).is_null() {
-            let mut tmp_1: *mut c_char = std::ptr::null_mut::<c_char>();
+            let mut tmp_1: *mut c_char = core::ptr::null_mut::<c_char>();
             tmp_1 = _spMalloc(
                 (::core::mem::size_of::<c_char>() as c_ulong)
                     .wrapping_mul((spine_strlen((*data).icon)).wrapping_add(1 as c_int as c_ulong)),
@@ -24044,7 +24044,7 @@ pub unsafe extern "C" fn spSkeletonJson_readSkeletonData(
     }
     slots = Json_getItem(root, (b"slots\0" as *const u8).cast::<c_char>());
     if !slots.is_null() {
-        let mut slotMap: *mut Json = std::ptr::null_mut::<Json>();
+        let mut slotMap: *mut Json = core::ptr::null_mut::<Json>();
         (*skeletonData).slotsCount = (*slots).size;
         (*skeletonData).slots = _spMalloc(
             (::core::mem::size_of::<*mut spSlotData>() as c_ulong)
@@ -24056,14 +24056,14 @@ pub unsafe extern "C" fn spSkeletonJson_readSkeletonData(
         slotMap = (*slots).child;
         i = 0 as c_int;
         while !slotMap.is_null() {
-            let mut data_0: *mut spSlotData = std::ptr::null_mut::<spSlotData>();
-            let mut color_0: *const c_char = std::ptr::null::<c_char>();
-            let mut dark: *const c_char = std::ptr::null::<c_char>();
-            let mut item: *mut Json = std::ptr::null_mut::<Json>();
+            let mut data_0: *mut spSlotData = core::ptr::null_mut::<spSlotData>();
+            let mut color_0: *const c_char = core::ptr::null::<c_char>();
+            let mut dark: *const c_char = core::ptr::null::<c_char>();
+            let mut item: *mut Json = core::ptr::null_mut::<Json>();
             let mut boneName: *const c_char = Json_getString(
                 slotMap,
                 (b"bone\0" as *const u8).cast::<c_char>(),
-                std::ptr::null::<c_char>(),
+                core::ptr::null::<c_char>(),
             );
             let mut boneData: *mut spBoneData = spSkeletonData_findBone(skeletonData, boneName);
             if boneData.is_null() {
@@ -24074,13 +24074,13 @@ pub unsafe extern "C" fn spSkeletonJson_readSkeletonData(
                     (b"Slot bone not found: \0" as *const u8).cast::<c_char>(),
                     boneName,
                 );
-                return std::ptr::null_mut::<spSkeletonData>();
+                return core::ptr::null_mut::<spSkeletonData>();
             }
-            let mut pathName: *mut c_char = std::ptr::null_mut::<c_char>();
+            let mut pathName: *mut c_char = core::ptr::null_mut::<c_char>();
             let mut slotName: *mut c_char = Json_getString(
                 slotMap,
                 (b"name\0" as *const u8).cast::<c_char>(),
-                std::ptr::null::<c_char>(),
+                core::ptr::null::<c_char>(),
             )
             .cast_mut();
             let slash: c_int = string_lastIndexOf(slotName, '/' as i32 as c_char);
@@ -24096,7 +24096,7 @@ pub unsafe extern "C" fn spSkeletonJson_readSkeletonData(
             color_0 = Json_getString(
                 slotMap,
                 (b"color\0" as *const u8).cast::<c_char>(),
-                std::ptr::null::<c_char>(),
+                core::ptr::null::<c_char>(),
             );
             if !color_0.is_null() {
                 spColor_setFromFloats(
@@ -24110,7 +24110,7 @@ pub unsafe extern "C" fn spSkeletonJson_readSkeletonData(
             dark = Json_getString(
                 slotMap,
                 (b"dark\0" as *const u8).cast::<c_char>(),
-                std::ptr::null::<c_char>(),
+                core::ptr::null::<c_char>(),
             );
             if !dark.is_null() {
                 (*data_0).darkColor = spColor_create();
@@ -24162,7 +24162,7 @@ pub unsafe extern "C" fn spSkeletonJson_readSkeletonData(
     }
     ik = Json_getItem(root, (b"ik\0" as *const u8).cast::<c_char>());
     if !ik.is_null() {
-        let mut constraintMap: *mut Json = std::ptr::null_mut::<Json>();
+        let mut constraintMap: *mut Json = core::ptr::null_mut::<Json>();
         (*skeletonData).ikConstraintsCount = (*ik).size;
         (*skeletonData).ikConstraints = _spMalloc(
             (::core::mem::size_of::<*mut spIkConstraintData>() as c_ulong)
@@ -24174,11 +24174,11 @@ pub unsafe extern "C" fn spSkeletonJson_readSkeletonData(
         constraintMap = (*ik).child;
         i = 0 as c_int;
         while !constraintMap.is_null() {
-            let mut targetName: *const c_char = std::ptr::null::<c_char>();
+            let mut targetName: *const c_char = core::ptr::null::<c_char>();
             let mut data_1: *mut spIkConstraintData = spIkConstraintData_create(Json_getString(
                 constraintMap,
                 (b"name\0" as *const u8).cast::<c_char>(),
-                std::ptr::null::<c_char>(),
+                core::ptr::null::<c_char>(),
             ));
             (*data_1).order = Json_getInt(
                 constraintMap,
@@ -24217,7 +24217,7 @@ pub unsafe extern "C" fn spSkeletonJson_readSkeletonData(
                         (b"IK bone not found: \0" as *const u8).cast::<c_char>(),
                         (*boneMap).valueString,
                     );
-                    return std::ptr::null_mut::<spSkeletonData>();
+                    return core::ptr::null_mut::<spSkeletonData>();
                 }
                 boneMap = (*boneMap).next;
                 ii += 1;
@@ -24225,7 +24225,7 @@ pub unsafe extern "C" fn spSkeletonJson_readSkeletonData(
             targetName = Json_getString(
                 constraintMap,
                 (b"target\0" as *const u8).cast::<c_char>(),
-                std::ptr::null::<c_char>(),
+                core::ptr::null::<c_char>(),
             );
             (*data_1).target = spSkeletonData_findBone(skeletonData, targetName);
             if ((*data_1).target).is_null() {
@@ -24236,7 +24236,7 @@ pub unsafe extern "C" fn spSkeletonJson_readSkeletonData(
                     (b"Target bone not found: \0" as *const u8).cast::<c_char>(),
                     targetName,
                 );
-                return std::ptr::null_mut::<spSkeletonData>();
+                return core::ptr::null_mut::<spSkeletonData>();
             }
             (*data_1).bendDirection = if Json_getInt(
                 constraintMap,
@@ -24296,7 +24296,7 @@ pub unsafe extern "C" fn spSkeletonJson_readSkeletonData(
     }
     transform = Json_getItem(root, (b"transform\0" as *const u8).cast::<c_char>());
     if !transform.is_null() {
-        let mut constraintMap_0: *mut Json = std::ptr::null_mut::<Json>();
+        let mut constraintMap_0: *mut Json = core::ptr::null_mut::<Json>();
         (*skeletonData).transformConstraintsCount = (*transform).size;
         (*skeletonData).transformConstraints = _spMalloc(
             (::core::mem::size_of::<*mut spTransformConstraintData>() as c_ulong)
@@ -24308,12 +24308,12 @@ pub unsafe extern "C" fn spSkeletonJson_readSkeletonData(
         constraintMap_0 = (*transform).child;
         i = 0 as c_int;
         while !constraintMap_0.is_null() {
-            let mut name: *const c_char = std::ptr::null::<c_char>();
+            let mut name: *const c_char = core::ptr::null::<c_char>();
             let mut data_2: *mut spTransformConstraintData =
                 spTransformConstraintData_create(Json_getString(
                     constraintMap_0,
                     (b"name\0" as *const u8).cast::<c_char>(),
-                    std::ptr::null::<c_char>(),
+                    core::ptr::null::<c_char>(),
                 ));
             (*data_2).order = Json_getInt(
                 constraintMap_0,
@@ -24352,7 +24352,7 @@ pub unsafe extern "C" fn spSkeletonJson_readSkeletonData(
                         (b"Transform bone not found: \0" as *const u8).cast::<c_char>(),
                         (*boneMap).valueString,
                     );
-                    return std::ptr::null_mut::<spSkeletonData>();
+                    return core::ptr::null_mut::<spSkeletonData>();
                 }
                 boneMap = (*boneMap).next;
                 ii += 1;
@@ -24360,7 +24360,7 @@ pub unsafe extern "C" fn spSkeletonJson_readSkeletonData(
             name = Json_getString(
                 constraintMap_0,
                 (b"target\0" as *const u8).cast::<c_char>(),
-                std::ptr::null::<c_char>(),
+                core::ptr::null::<c_char>(),
             );
             (*data_2).target = spSkeletonData_findBone(skeletonData, name);
             if ((*data_2).target).is_null() {
@@ -24371,7 +24371,7 @@ pub unsafe extern "C" fn spSkeletonJson_readSkeletonData(
                     (b"Target bone not found: \0" as *const u8).cast::<c_char>(),
                     name,
                 );
-                return std::ptr::null_mut::<spSkeletonData>();
+                return core::ptr::null_mut::<spSkeletonData>();
             }
             (*data_2).local = Json_getInt(
                 constraintMap_0,
@@ -24451,7 +24451,7 @@ pub unsafe extern "C" fn spSkeletonJson_readSkeletonData(
     }
     pathJson = Json_getItem(root, (b"path\0" as *const u8).cast::<c_char>());
     if !pathJson.is_null() {
-        let mut constraintMap_1: *mut Json = std::ptr::null_mut::<Json>();
+        let mut constraintMap_1: *mut Json = core::ptr::null_mut::<Json>();
         (*skeletonData).pathConstraintsCount = (*pathJson).size;
         (*skeletonData).pathConstraints = _spMalloc(
             (::core::mem::size_of::<*mut spPathConstraintData>() as c_ulong)
@@ -24463,13 +24463,13 @@ pub unsafe extern "C" fn spSkeletonJson_readSkeletonData(
         constraintMap_1 = (*pathJson).child;
         i = 0 as c_int;
         while !constraintMap_1.is_null() {
-            let mut name_0: *const c_char = std::ptr::null::<c_char>();
-            let mut item_0: *const c_char = std::ptr::null::<c_char>();
+            let mut name_0: *const c_char = core::ptr::null::<c_char>();
+            let mut item_0: *const c_char = core::ptr::null::<c_char>();
             let mut data_3: *mut spPathConstraintData =
                 spPathConstraintData_create(Json_getString(
                     constraintMap_1,
                     (b"name\0" as *const u8).cast::<c_char>(),
-                    std::ptr::null::<c_char>(),
+                    core::ptr::null::<c_char>(),
                 ));
             (*data_3).order = Json_getInt(
                 constraintMap_1,
@@ -24508,7 +24508,7 @@ pub unsafe extern "C" fn spSkeletonJson_readSkeletonData(
                         (b"Path bone not found: \0" as *const u8).cast::<c_char>(),
                         (*boneMap).valueString,
                     );
-                    return std::ptr::null_mut::<spSkeletonData>();
+                    return core::ptr::null_mut::<spSkeletonData>();
                 }
                 boneMap = (*boneMap).next;
                 ii += 1;
@@ -24516,7 +24516,7 @@ pub unsafe extern "C" fn spSkeletonJson_readSkeletonData(
             name_0 = Json_getString(
                 constraintMap_1,
                 (b"target\0" as *const u8).cast::<c_char>(),
-                std::ptr::null::<c_char>(),
+                core::ptr::null::<c_char>(),
             );
             (*data_3).target = spSkeletonData_findSlot(skeletonData, name_0);
             if ((*data_3).target).is_null() {
@@ -24527,7 +24527,7 @@ pub unsafe extern "C" fn spSkeletonJson_readSkeletonData(
                     (b"Target slot not found: \0" as *const u8).cast::<c_char>(),
                     name_0,
                 );
-                return std::ptr::null_mut::<spSkeletonData>();
+                return core::ptr::null_mut::<spSkeletonData>();
             }
             item_0 = Json_getString(
                 constraintMap_1,
@@ -24619,7 +24619,7 @@ pub unsafe extern "C" fn spSkeletonJson_readSkeletonData(
     }
     physics = Json_getItem(root, (b"physics\0" as *const u8).cast::<c_char>());
     if !physics.is_null() {
-        let mut constraintMap_2: *mut Json = std::ptr::null_mut::<Json>();
+        let mut constraintMap_2: *mut Json = core::ptr::null_mut::<Json>();
         (*skeletonData).physicsConstraintsCount = (*physics).size;
         (*skeletonData).physicsConstraints = _spMalloc(
             (::core::mem::size_of::<*mut spPhysicsConstraintData>() as c_ulong)
@@ -24631,12 +24631,12 @@ pub unsafe extern "C" fn spSkeletonJson_readSkeletonData(
         constraintMap_2 = (*physics).child;
         i = 0 as c_int;
         while !constraintMap_2.is_null() {
-            let mut name_1: *const c_char = std::ptr::null::<c_char>();
+            let mut name_1: *const c_char = core::ptr::null::<c_char>();
             let mut data_4: *mut spPhysicsConstraintData =
                 spPhysicsConstraintData_create(Json_getString(
                     constraintMap_2,
                     (b"name\0" as *const u8).cast::<c_char>(),
-                    std::ptr::null::<c_char>(),
+                    core::ptr::null::<c_char>(),
                 ));
             (*data_4).order = Json_getInt(
                 constraintMap_2,
@@ -24651,7 +24651,7 @@ pub unsafe extern "C" fn spSkeletonJson_readSkeletonData(
             name_1 = Json_getString(
                 constraintMap_2,
                 (b"bone\0" as *const u8).cast::<c_char>(),
-                std::ptr::null::<c_char>(),
+                core::ptr::null::<c_char>(),
             );
             (*data_4).bone = spSkeletonData_findBone(skeletonData, name_1);
             if ((*data_4).bone).is_null() {
@@ -24662,7 +24662,7 @@ pub unsafe extern "C" fn spSkeletonJson_readSkeletonData(
                     (b"Physics bone not found: \0" as *const u8).cast::<c_char>(),
                     name_1,
                 );
-                return std::ptr::null_mut::<spSkeletonData>();
+                return core::ptr::null_mut::<spSkeletonData>();
             }
             (*data_4).x = Json_getFloat(
                 constraintMap_2,
@@ -24779,7 +24779,7 @@ pub unsafe extern "C" fn spSkeletonJson_readSkeletonData(
     }
     skins = Json_getItem(root, (b"skins\0" as *const u8).cast::<c_char>());
     if !skins.is_null() {
-        let mut skinMap: *mut Json = std::ptr::null_mut::<Json>();
+        let mut skinMap: *mut Json = core::ptr::null_mut::<Json>();
         (*skeletonData).skins = _spMalloc(
             (::core::mem::size_of::<*mut spSkin>() as c_ulong)
                 .wrapping_mul((*skins).size as c_ulong),
@@ -24790,9 +24790,9 @@ pub unsafe extern "C" fn spSkeletonJson_readSkeletonData(
         skinMap = (*skins).child;
         i = 0 as c_int;
         while !skinMap.is_null() {
-            let mut attachmentsMap: *mut Json = std::ptr::null_mut::<Json>();
-            let mut curves: *mut Json = std::ptr::null_mut::<Json>();
-            let mut skinPart: *mut Json = std::ptr::null_mut::<Json>();
+            let mut attachmentsMap: *mut Json = core::ptr::null_mut::<Json>();
+            let mut curves: *mut Json = core::ptr::null_mut::<Json>();
+            let mut skinPart: *mut Json = core::ptr::null_mut::<Json>();
             let mut skin: *mut spSkin = spSkin_create(Json_getString(
                 skinMap,
                 (b"name\0" as *const u8).cast::<c_char>(),
@@ -24812,7 +24812,7 @@ pub unsafe extern "C" fn spSkeletonJson_readSkeletonData(
                             (b"Skin bone constraint not found: \0" as *const u8).cast::<c_char>(),
                             (*skinPart).valueString,
                         );
-                        return std::ptr::null_mut::<spSkeletonData>();
+                        return core::ptr::null_mut::<spSkeletonData>();
                     }
                     spBoneDataArray_add((*skin).bones, bone);
                     skinPart = (*skinPart).next;
@@ -24832,7 +24832,7 @@ pub unsafe extern "C" fn spSkeletonJson_readSkeletonData(
                             (b"Skin IK constraint not found: \0" as *const u8).cast::<c_char>(),
                             (*skinPart).valueString,
                         );
-                        return std::ptr::null_mut::<spSkeletonData>();
+                        return core::ptr::null_mut::<spSkeletonData>();
                     }
                     spIkConstraintDataArray_add((*skin).ikConstraints, constraint);
                     skinPart = (*skinPart).next;
@@ -24852,7 +24852,7 @@ pub unsafe extern "C" fn spSkeletonJson_readSkeletonData(
                             (b"Skin path constraint not found: \0" as *const u8).cast::<c_char>(),
                             (*skinPart).valueString,
                         );
-                        return std::ptr::null_mut::<spSkeletonData>();
+                        return core::ptr::null_mut::<spSkeletonData>();
                     }
                     spPathConstraintDataArray_add((*skin).pathConstraints, constraint_0);
                     skinPart = (*skinPart).next;
@@ -24876,7 +24876,7 @@ pub unsafe extern "C" fn spSkeletonJson_readSkeletonData(
                                 .cast::<c_char>(),
                             (*skinPart).valueString,
                         );
-                        return std::ptr::null_mut::<spSkeletonData>();
+                        return core::ptr::null_mut::<spSkeletonData>();
                     }
                     spTransformConstraintDataArray_add((*skin).transformConstraints, constraint_1);
                     skinPart = (*skinPart).next;
@@ -24897,7 +24897,7 @@ pub unsafe extern "C" fn spSkeletonJson_readSkeletonData(
                                 .cast::<c_char>(),
                             (*skinPart).valueString,
                         );
-                        return std::ptr::null_mut::<spSkeletonData>();
+                        return core::ptr::null_mut::<spSkeletonData>();
                     }
                     spPhysicsConstraintDataArray_add((*skin).physicsConstraints, constraint_2);
                     skinPart = (*skinPart).next;
@@ -24918,11 +24918,11 @@ pub unsafe extern "C" fn spSkeletonJson_readSkeletonData(
                 while !attachmentsMap.is_null() {
                     let mut slot: *mut spSlotData =
                         spSkeletonData_findSlot(skeletonData, (*attachmentsMap).name);
-                    let mut attachmentMap: *mut Json = std::ptr::null_mut::<Json>();
+                    let mut attachmentMap: *mut Json = core::ptr::null_mut::<Json>();
                     attachmentMap = (*attachmentsMap).child;
                     while !attachmentMap.is_null() {
                         let mut attachment: *mut spAttachment =
-                            std::ptr::null_mut::<spAttachment>();
+                            core::ptr::null_mut::<spAttachment>();
                         let mut skinAttachmentName: *const c_char = (*attachmentMap).name;
                         let mut attachmentName: *const c_char = Json_getString(
                             attachmentMap,
@@ -24934,9 +24934,9 @@ pub unsafe extern "C" fn spSkeletonJson_readSkeletonData(
                             (b"path\0" as *const u8).cast::<c_char>(),
                             attachmentName,
                         );
-                        let mut color_1: *const c_char = std::ptr::null::<c_char>();
-                        let mut entry: *mut Json = std::ptr::null_mut::<Json>();
-                        let mut sequence: *mut spSequence = std::ptr::null_mut::<spSequence>();
+                        let mut color_1: *const c_char = core::ptr::null::<c_char>();
+                        let mut entry: *mut Json = core::ptr::null_mut::<Json>();
+                        let mut sequence: *mut spSequence = core::ptr::null_mut::<spSequence>();
                         let mut typeString: *const c_char = Json_getString(
                             attachmentMap,
                             (b"type\0" as *const u8).cast::<c_char>(),
@@ -24991,7 +24991,7 @@ pub unsafe extern "C" fn spSkeletonJson_readSkeletonData(
                                 (b"Unknown attachment type: \0" as *const u8).cast::<c_char>(),
                                 typeString,
                             );
-                            return std::ptr::null_mut::<spSkeletonData>();
+                            return core::ptr::null_mut::<spSkeletonData>();
                         }
                         sequence = readSequenceJson(Json_getItem(
                             attachmentMap,
@@ -25014,7 +25014,7 @@ pub unsafe extern "C" fn spSkeletonJson_readSkeletonData(
                                     (*(*self_0).attachmentLoader).error1,
                                     (*(*self_0).attachmentLoader).error2,
                                 );
-                                return std::ptr::null_mut::<spSkeletonData>();
+                                return core::ptr::null_mut::<spSkeletonData>();
                             }
                         } else {
                             match (*attachment).type_0 as c_uint {
@@ -25073,7 +25073,7 @@ pub unsafe extern "C" fn spSkeletonJson_readSkeletonData(
                                     color_1 = Json_getString(
                                         attachmentMap,
                                         (b"color\0" as *const u8).cast::<c_char>(),
-                                        std::ptr::null::<c_char>(),
+                                        core::ptr::null::<c_char>(),
                                     );
                                     if !color_1.is_null() {
                                         spColor_setFromFloats(
@@ -25108,7 +25108,7 @@ pub unsafe extern "C" fn spSkeletonJson_readSkeletonData(
                                     color_1 = Json_getString(
                                         attachmentMap,
                                         (b"color\0" as *const u8).cast::<c_char>(),
-                                        std::ptr::null::<c_char>(),
+                                        core::ptr::null::<c_char>(),
                                     );
                                     if !color_1.is_null() {
                                         spColor_setFromFloats(
@@ -25228,7 +25228,7 @@ pub unsafe extern "C" fn spSkeletonJson_readSkeletonData(
                                             Json_getString(
                                                 attachmentMap,
                                                 (b"skin\0" as *const u8).cast::<c_char>(),
-                                                std::ptr::null::<c_char>(),
+                                                core::ptr::null::<c_char>(),
                                             ),
                                             (*slot).index,
                                             (*entry).valueString,
@@ -25254,7 +25254,7 @@ pub unsafe extern "C" fn spSkeletonJson_readSkeletonData(
                                     color_1 = Json_getString(
                                         attachmentMap,
                                         (b"color\0" as *const u8).cast::<c_char>(),
-                                        std::ptr::null::<c_char>(),
+                                        core::ptr::null::<c_char>(),
                                     );
                                     if !color_1.is_null() {
                                         spColor_setFromFloats(
@@ -25320,7 +25320,7 @@ pub unsafe extern "C" fn spSkeletonJson_readSkeletonData(
                                     color_1 = Json_getString(
                                         attachmentMap,
                                         (b"color\0" as *const u8).cast::<c_char>(),
-                                        std::ptr::null::<c_char>(),
+                                        core::ptr::null::<c_char>(),
                                     );
                                     if !color_1.is_null() {
                                         spColor_setFromFloats(
@@ -25353,7 +25353,7 @@ pub unsafe extern "C" fn spSkeletonJson_readSkeletonData(
                                     color_1 = Json_getString(
                                         attachmentMap,
                                         (b"color\0" as *const u8).cast::<c_char>(),
-                                        std::ptr::null::<c_char>(),
+                                        core::ptr::null::<c_char>(),
                                     );
                                     if !color_1.is_null() {
                                         spColor_setFromFloats(
@@ -25372,7 +25372,7 @@ pub unsafe extern "C" fn spSkeletonJson_readSkeletonData(
                                     let mut end: *const c_char = Json_getString(
                                         attachmentMap,
                                         (b"end\0" as *const u8).cast::<c_char>(),
-                                        std::ptr::null::<c_char>(),
+                                        core::ptr::null::<c_char>(),
                                     );
                                     if !end.is_null() {
                                         let mut endSlot: *mut spSlotData =
@@ -25393,7 +25393,7 @@ pub unsafe extern "C" fn spSkeletonJson_readSkeletonData(
                                     color_1 = Json_getString(
                                         attachmentMap,
                                         (b"color\0" as *const u8).cast::<c_char>(),
-                                        std::ptr::null::<c_char>(),
+                                        core::ptr::null::<c_char>(),
                                     );
                                     if !color_1.is_null() {
                                         spColor_setFromFloats(
@@ -25429,7 +25429,7 @@ pub unsafe extern "C" fn spSkeletonJson_readSkeletonData(
     }
     i = 0 as c_int;
     while i < (*internal).linkedMeshCount {
-        let mut parent_0: *mut spAttachment = std::ptr::null_mut::<spAttachment>();
+        let mut parent_0: *mut spAttachment = core::ptr::null_mut::<spAttachment>();
         let mut linkedMesh: *mut _spLinkedMeshJson = ((*internal).linkedMeshes).offset(i as isize);
         let mut skin_0: *mut spSkin = if ((*linkedMesh).skin).is_null() {
             (*skeletonData).defaultSkin
@@ -25440,22 +25440,22 @@ pub unsafe extern "C" fn spSkeletonJson_readSkeletonData(
             spSkeletonData_dispose(skeletonData);
             _spSkeletonJson_setError(
                 self_0,
-                std::ptr::null_mut::<Json>(),
+                core::ptr::null_mut::<Json>(),
                 (b"Skin not found: \0" as *const u8).cast::<c_char>(),
                 (*linkedMesh).skin,
             );
-            return std::ptr::null_mut::<spSkeletonData>();
+            return core::ptr::null_mut::<spSkeletonData>();
         }
         parent_0 = spSkin_getAttachment(skin_0, (*linkedMesh).slotIndex, (*linkedMesh).parent);
         if parent_0.is_null() {
             spSkeletonData_dispose(skeletonData);
             _spSkeletonJson_setError(
                 self_0,
-                std::ptr::null_mut::<Json>(),
+                core::ptr::null_mut::<Json>(),
                 (b"Parent mesh not found: \0" as *const u8).cast::<c_char>(),
                 (*linkedMesh).parent,
             );
-            return std::ptr::null_mut::<spSkeletonData>();
+            return core::ptr::null_mut::<spSkeletonData>();
         }
         (*(*linkedMesh).mesh).super_0.timelineAttachment = if (*linkedMesh).inheritTimeline != 0 {
             parent_0
@@ -25474,9 +25474,9 @@ pub unsafe extern "C" fn spSkeletonJson_readSkeletonData(
     }
     events = Json_getItem(root, (b"events\0" as *const u8).cast::<c_char>());
     if !events.is_null() {
-        let mut eventMap: *mut Json = std::ptr::null_mut::<Json>();
-        let mut stringValue: *const c_char = std::ptr::null::<c_char>();
-        let mut audioPath: *const c_char = std::ptr::null::<c_char>();
+        let mut eventMap: *mut Json = core::ptr::null_mut::<Json>();
+        let mut stringValue: *const c_char = core::ptr::null::<c_char>();
+        let mut audioPath: *const c_char = core::ptr::null::<c_char>();
         (*skeletonData).eventsCount = (*events).size;
         (*skeletonData).events = _spMalloc(
             (::core::mem::size_of::<*mut spEventData>() as c_ulong)
@@ -25502,7 +25502,7 @@ pub unsafe extern "C" fn spSkeletonJson_readSkeletonData(
             stringValue = Json_getString(
                 eventMap,
                 (b"string\0" as *const u8).cast::<c_char>(),
-                std::ptr::null::<c_char>(),
+                core::ptr::null::<c_char>(),
             );
             if !stringValue.is_null() {
                 (*eventData).stringValue = _spMalloc(
@@ -25518,7 +25518,7 @@ pub unsafe extern "C" fn spSkeletonJson_readSkeletonData(
             audioPath = Json_getString(
                 eventMap,
                 (b"audio\0" as *const u8).cast::<c_char>(),
-                std::ptr::null::<c_char>(),
+                core::ptr::null::<c_char>(),
             );
             if !audioPath.is_null() {
                 (*eventData).audioPath = _spMalloc(
@@ -25549,7 +25549,7 @@ pub unsafe extern "C" fn spSkeletonJson_readSkeletonData(
     }
     animations = Json_getItem(root, (b"animations\0" as *const u8).cast::<c_char>());
     if !animations.is_null() {
-        let mut animationMap: *mut Json = std::ptr::null_mut::<Json>();
+        let mut animationMap: *mut Json = core::ptr::null_mut::<Json>();
         (*skeletonData).animations = _spMalloc(
             (::core::mem::size_of::<*mut spAnimation>() as c_ulong)
                 .wrapping_mul((*animations).size as c_ulong),
@@ -25563,7 +25563,7 @@ pub unsafe extern "C" fn spSkeletonJson_readSkeletonData(
                 _spSkeletonJson_readAnimation(self_0, animationMap, skeletonData);
             if animation.is_null() {
                 spSkeletonData_dispose(skeletonData);
-                return std::ptr::null_mut::<spSkeletonData>();
+                return core::ptr::null_mut::<spSkeletonData>();
             }
             let fresh148 = (*skeletonData).animationsCount;
             (*skeletonData).animationsCount += 1;
@@ -26516,7 +26516,7 @@ pub unsafe extern "C" fn spSkin_setAttachment(
     mut name: *const c_char,
     mut attachment: *mut spAttachment,
 ) {
-    let mut existingEntry: *mut _SkinHashTableEntry = std::ptr::null_mut::<_SkinHashTableEntry>();
+    let mut existingEntry: *mut _SkinHashTableEntry = core::ptr::null_mut::<_SkinHashTableEntry>();
     let mut hashEntry: *mut _SkinHashTableEntry = (*self_0.cast::<_spSkin>()).entriesHashTable
         [(slotIndex as c_uint).wrapping_rem(100 as c_int as c_uint) as usize];
     while !hashEntry.is_null() {
@@ -26567,7 +26567,7 @@ pub unsafe extern "C" fn spSkin_getAttachment(
         }
         hashEntry = (*hashEntry).next;
     }
-    std::ptr::null_mut::<spAttachment>()
+    core::ptr::null_mut::<spAttachment>()
 }
 #[no_mangle]
 pub unsafe extern "C" fn spSkin_getAttachmentName(
@@ -26586,7 +26586,7 @@ pub unsafe extern "C" fn spSkin_getAttachmentName(
         }
         entry = (*entry).next;
     }
-    std::ptr::null::<c_char>()
+    core::ptr::null::<c_char>()
 }
 #[no_mangle]
 pub unsafe extern "C" fn spSkin_attachAll(
@@ -26610,7 +26610,7 @@ pub unsafe extern "C" fn spSkin_attachAll(
 #[no_mangle]
 pub unsafe extern "C" fn spSkin_addSkin(mut self_0: *mut spSkin, mut other: *const spSkin) {
     let mut i: c_int = 0 as c_int;
-    let mut entry: *mut spSkinEntry = std::ptr::null_mut::<spSkinEntry>();
+    let mut entry: *mut spSkinEntry = core::ptr::null_mut::<spSkinEntry>();
     i = 0 as c_int;
     while i < (*(*other).bones).size {
         if spBoneDataArray_contains(
@@ -26695,7 +26695,7 @@ pub unsafe extern "C" fn spSkin_addSkin(mut self_0: *mut spSkin, mut other: *con
 #[no_mangle]
 pub unsafe extern "C" fn spSkin_copySkin(mut self_0: *mut spSkin, mut other: *const spSkin) {
     let mut i: c_int = 0 as c_int;
-    let mut entry: *mut spSkinEntry = std::ptr::null_mut::<spSkinEntry>();
+    let mut entry: *mut spSkinEntry = core::ptr::null_mut::<spSkinEntry>();
     i = 0 as c_int;
     while i < (*(*other).bones).size {
         if spBoneDataArray_contains(
@@ -26781,7 +26781,7 @@ pub unsafe extern "C" fn spSkin_copySkin(mut self_0: *mut spSkin, mut other: *co
             let mut attachment_0: *mut spAttachment = if !((*entry).attachment).is_null() {
                 spAttachment_copy((*entry).attachment)
             } else {
-                std::ptr::null_mut::<spAttachment>()
+                core::ptr::null_mut::<spAttachment>()
             };
             spSkin_setAttachment(self_0, (*entry).slotIndex, (*entry).name, attachment_0);
         }
@@ -26801,7 +26801,7 @@ pub unsafe extern "C" fn spSkin_clear(mut self_0: *mut spSkin) {
         entry = nextEntry;
     }
     let fresh162 = &mut (*self_0.cast::<_spSkin>()).entries;
-    *fresh162 = std::ptr::null_mut::<_Entry>();
+    *fresh162 = core::ptr::null_mut::<_Entry>();
     let mut currentHashtableEntry: *mut *mut _SkinHashTableEntry =
         ((*self_0.cast::<_spSkin>()).entriesHashTable).as_mut_ptr();
     let mut i: c_int = 0;
@@ -26814,7 +26814,7 @@ pub unsafe extern "C" fn spSkin_clear(mut self_0: *mut spSkin) {
             hashtableEntry = nextEntry_0;
         }
         let fresh163 = &mut (*self_0.cast::<_spSkin>()).entriesHashTable[i as usize];
-        *fresh163 = std::ptr::null_mut::<_SkinHashTableEntry>();
+        *fresh163 = core::ptr::null_mut::<_SkinHashTableEntry>();
         i += 1;
         currentHashtableEntry = currentHashtableEntry.offset(1);
     }
@@ -26846,7 +26846,7 @@ pub unsafe extern "C" fn spSlot_create(
         1 as c_int as c_float,
     );
     (*self_0).darkColor = if ((*data).darkColor).is_null() {
-        std::ptr::null_mut::<spColor>()
+        core::ptr::null_mut::<spColor>()
     } else {
         spColor_create()
     };
@@ -26893,14 +26893,14 @@ pub unsafe extern "C" fn spSlot_setToSetupPose(mut self_0: *mut spSlot) {
         spColor_setFromColor((*self_0).darkColor, (*(*self_0).data).darkColor);
     }
     if ((*(*self_0).data).attachmentName).is_null() {
-        spSlot_setAttachment(self_0, std::ptr::null_mut::<spAttachment>());
+        spSlot_setAttachment(self_0, core::ptr::null_mut::<spAttachment>());
     } else {
         let mut attachment: *mut spAttachment = spSkeleton_getAttachmentForSlotIndex(
             (*(*self_0).bone).skeleton,
             (*(*self_0).data).index,
             (*(*self_0).data).attachmentName,
         );
-        (*self_0).attachment = std::ptr::null_mut::<spAttachment>();
+        (*self_0).attachment = core::ptr::null_mut::<spAttachment>();
         spSlot_setAttachment(self_0, attachment);
     };
 }
@@ -26960,7 +26960,7 @@ pub unsafe extern "C" fn spSlotData_setAttachmentName(
         .cast::<c_char>();
         spine_strcpy((*self_0).attachmentName, attachmentName);
     } else {
-        (*self_0).attachmentName = std::ptr::null_mut::<c_char>();
+        (*self_0).attachmentName = core::ptr::null_mut::<c_char>();
     };
 }
 #[no_mangle]
@@ -27552,10 +27552,10 @@ pub unsafe extern "C" fn spTriangulator_triangulate(
     let mut n: c_int = 0;
     let mut ii: c_int = 0;
     let mut indicesArray: *mut spShortArray = (*self_0).indicesArray;
-    let mut indices: *mut c_short = std::ptr::null_mut::<c_short>();
-    let mut isConcaveArray: *mut spIntArray = std::ptr::null_mut::<spIntArray>();
-    let mut isConcave: *mut c_int = std::ptr::null_mut::<c_int>();
-    let mut triangles: *mut spShortArray = std::ptr::null_mut::<spShortArray>();
+    let mut indices: *mut c_short = core::ptr::null_mut::<c_short>();
+    let mut isConcaveArray: *mut spIntArray = core::ptr::null_mut::<spIntArray>();
+    let mut isConcave: *mut c_int = core::ptr::null_mut::<c_int>();
+    let mut triangles: *mut spShortArray = core::ptr::null_mut::<spShortArray>();
     spShortArray_clear(indicesArray);
     indices = (*spShortArray_setSize(indicesArray, vertexCount)).items;
     i = 0 as c_int;
@@ -27669,12 +27669,12 @@ pub unsafe extern "C" fn spTriangulator_decompose(
     let mut vertices: *mut c_float = (*verticesArray).items;
     let mut convexPolygons: *mut spArrayFloatArray = (*self_0).convexPolygons;
     let mut convexPolygonsIndices: *mut spArrayShortArray =
-        std::ptr::null_mut::<spArrayShortArray>();
-    let mut polygonIndices: *mut spShortArray = std::ptr::null_mut::<spShortArray>();
-    let mut polygon: *mut spFloatArray = std::ptr::null_mut::<spFloatArray>();
+        core::ptr::null_mut::<spArrayShortArray>();
+    let mut polygonIndices: *mut spShortArray = core::ptr::null_mut::<spShortArray>();
+    let mut polygon: *mut spFloatArray = core::ptr::null_mut::<spFloatArray>();
     let mut fanBaseIndex: c_int = 0;
     let mut lastWinding: c_int = 0;
-    let mut trianglesItems: *mut c_short = std::ptr::null_mut::<c_short>();
+    let mut trianglesItems: *mut c_short = core::ptr::null_mut::<c_short>();
     let mut i: c_int = 0;
     let mut n: c_int = 0;
     _freeAllPolygons(self_0, convexPolygons);
@@ -27766,7 +27766,7 @@ pub unsafe extern "C" fn spTriangulator_decompose(
         let mut firstIndex: c_int = 0;
         let mut lastIndex: c_int = 0;
         let mut o_0: c_int = 0;
-        let mut p_0: *mut c_float = std::ptr::null_mut::<c_float>();
+        let mut p_0: *mut c_float = core::ptr::null_mut::<c_float>();
         let mut prevPrevX: c_float = 0.;
         let mut prevPrevY: c_float = 0.;
         let mut prevX: c_float = 0.;
@@ -27797,11 +27797,11 @@ pub unsafe extern "C" fn spTriangulator_decompose(
             winding = _winding(prevPrevX, prevPrevY, prevX, prevY, firstX, firstY);
             ii = 0 as c_int;
             while ii < n {
-                let mut otherIndices: *mut spShortArray = std::ptr::null_mut::<spShortArray>();
+                let mut otherIndices: *mut spShortArray = core::ptr::null_mut::<spShortArray>();
                 let mut otherFirstIndex: c_int = 0;
                 let mut otherSecondIndex: c_int = 0;
                 let mut otherLastIndex: c_int = 0;
-                let mut otherPoly: *mut spFloatArray = std::ptr::null_mut::<spFloatArray>();
+                let mut otherPoly: *mut spFloatArray = core::ptr::null_mut::<spFloatArray>();
                 let mut x3_0: c_float = 0.;
                 let mut y3_0: c_float = 0.;
                 let mut winding1_0: c_int = 0;
@@ -27881,11 +27881,11 @@ pub unsafe extern "C" fn spVertexAttachment_computeWorldVertices(
     mut offset: c_int,
     mut stride: c_int,
 ) {
-    let mut skeleton: *mut spSkeleton = std::ptr::null_mut::<spSkeleton>();
+    let mut skeleton: *mut spSkeleton = core::ptr::null_mut::<spSkeleton>();
     let mut deformLength: c_int = 0;
-    let mut deformArray: *mut c_float = std::ptr::null_mut::<c_float>();
-    let mut vertices: *mut c_float = std::ptr::null_mut::<c_float>();
-    let mut bones: *mut c_int = std::ptr::null_mut::<c_int>();
+    let mut deformArray: *mut c_float = core::ptr::null_mut::<c_float>();
+    let mut vertices: *mut c_float = core::ptr::null_mut::<c_float>();
+    let mut bones: *mut c_int = core::ptr::null_mut::<c_int>();
     if (*self_0).super_0.type_0 as c_uint == SP_ATTACHMENT_MESH as c_int as c_uint
         || (*self_0).super_0.type_0 as c_uint == SP_ATTACHMENT_LINKED_MESH as c_int as c_uint
     {
@@ -27901,7 +27901,7 @@ pub unsafe extern "C" fn spVertexAttachment_computeWorldVertices(
     vertices = (*self_0).vertices;
     bones = (*self_0).bones;
     if bones.is_null() {
-        let mut bone: *mut spBone = std::ptr::null_mut::<spBone>();
+        let mut bone: *mut spBone = core::ptr::null_mut::<spBone>();
         let mut v: c_int = 0;
         let mut w: c_int = 0;
         let mut x: c_float = 0.;
@@ -27926,7 +27926,7 @@ pub unsafe extern "C" fn spVertexAttachment_computeWorldVertices(
         let mut v_0: c_int = 0 as c_int;
         let mut skip_0: c_int = 0 as c_int;
         let mut i: c_int = 0;
-        let mut skeletonBones: *mut *mut spBone = std::ptr::null_mut::<*mut spBone>();
+        let mut skeletonBones: *mut *mut spBone = core::ptr::null_mut::<*mut spBone>();
         i = 0 as c_int;
         while i < start {
             let mut n: c_int = *bones.offset(v_0 as isize);
@@ -28021,7 +28021,7 @@ pub unsafe extern "C" fn spVertexAttachment_copyTo(
         (*to).bonesCount = 0 as c_int;
         if !((*to).bones).is_null() {
             _spFree((*to).bones.cast::<c_void>());
-            (*to).bones = std::ptr::null_mut::<c_int>();
+            (*to).bones = core::ptr::null_mut::<c_int>();
         }
     }
     if (*from).verticesCount != 0 {
@@ -28043,7 +28043,7 @@ pub unsafe extern "C" fn spVertexAttachment_copyTo(
         (*to).verticesCount = 0 as c_int;
         if !((*to).vertices).is_null() {
             _spFree((*to).vertices.cast::<c_void>());
-            (*to).vertices = std::ptr::null_mut::<c_float>();
+            (*to).vertices = core::ptr::null_mut::<c_float>();
         }
     }
     (*to).worldVerticesLength = (*from).worldVerticesLength;
@@ -28132,10 +28132,10 @@ pub unsafe extern "C" fn _spReadFile(
     mut path: *const c_char,
     mut length: *mut c_int,
 ) -> *mut c_char {
-    let mut data: *mut c_char = std::ptr::null_mut::<c_char>();
+    let mut data: *mut c_char = core::ptr::null_mut::<c_char>();
     let mut file: *mut FILE = spine_fopen(path, (b"rb\0" as *const u8).cast::<c_char>());
     if file.is_null() {
-        return std::ptr::null_mut::<c_char>();
+        return core::ptr::null_mut::<c_char>();
     }
     spine_fseek(file, 0 as c_int as c_long, 2 as c_int);
     *length = spine_ftell(file) as c_int;
@@ -35,11 +35,13 @@
 #![allow(clippy::missing_const_for_fn)]
 
 use crate::c::FILE;
-use std::alloc::Layout;
-use std::any::Any;
-use std::collections::HashMap;
-use std::ffi::{CStr, CString};
-use std::sync::{Arc, Mutex, Once};
+use alloc::{alloc::Layout, collections::BTreeMap, ffi::CString};
+use core::{any::Any, ffi::CStr};
+
+#[cfg(not(feature = "std"))]
+use crate::math::Float;
+use crate::prelude::*;
+use crate::sync::{lock, Mutex};
 
 pub mod types {
     #[allow(non_camel_case_types)]
@@ -57,7 +59,7 @@ pub mod types {
     #[allow(non_camel_case_types)]
     pub type c_schar = i8;
     #[allow(non_camel_case_types)]
-    pub type c_char = std::ffi::c_char;
+    pub type c_char = core::ffi::c_char;
     #[allow(non_camel_case_types)]
     pub type c_uchar = u8;
     #[allow(non_camel_case_types)]
@@ -77,42 +79,35 @@ use self::types::*;
 #[allow(non_camel_case_types)]
 type size_t = c_ulong;
 
+/// Allocation layouts by address.
 #[derive(Default)]
 struct Allocator {
-    allocations: HashMap<*const c_void, Layout>,
+    allocations: BTreeMap<usize, Layout>,
 }
 
-impl Allocator {
-    fn singleton() -> Arc<Mutex<Allocator>> {
-        static START: Once = Once::new();
-        static mut INSTANCE: Option<Arc<Mutex<Allocator>>> = None;
-        START.call_once(|| unsafe {
-            INSTANCE = Some(Arc::new(Mutex::new(Allocator::default())));
-        });
-        unsafe {
-            let singleton = INSTANCE.as_ref().unwrap();
-            singleton.clone()
-        }
-    }
+static ALLOCATOR: Mutex<Allocator> = Mutex::new(Allocator {
+    allocations: BTreeMap::new(),
+});
 
+impl Allocator {
     pub fn malloc(&mut self, size: usize) -> *mut c_void {
         if size > 0 {
-            let layout = std::alloc::Layout::array::<u8>(size)
+            let layout = alloc::alloc::Layout::array::<u8>(size)
                 .unwrap()
                 .align_to(8)
                 .unwrap();
-            let ptr = unsafe { std::alloc::alloc(layout) };
-            self.allocations.insert(ptr as *const c_void, layout);
+            let ptr = unsafe { alloc::alloc::alloc(layout) };
+            self.allocations.insert(ptr.addr(), layout);
             ptr.cast::<c_void>()
         } else {
-            std::ptr::null_mut()
+            core::ptr::null_mut()
         }
     }
 
     pub unsafe fn realloc(&mut self, ptr: *const c_void, size: usize) -> *mut c_void {
         let new_memory = self.malloc(size);
         if !new_memory.is_null() {
-            let layout = self.allocations.get(&ptr).unwrap();
+            let layout = self.allocations.get(&ptr.addr()).unwrap();
             spine_memcpy(new_memory, ptr, layout.size() as size_t);
         }
         self.free(ptr);
@@ -121,13 +116,13 @@ impl Allocator {
 
     #[allow(dead_code)]
     pub unsafe fn size(&mut self, ptr: *const c_void) -> usize {
-        self.allocations.get(&ptr).unwrap().size()
+        self.allocations.get(&ptr.addr()).unwrap().size()
     }
 
     pub unsafe fn free(&mut self, ptr: *const c_void) {
         if !ptr.is_null() {
-            let layout = self.allocations.remove(&ptr).unwrap();
-            unsafe { std::alloc::dealloc(ptr as *mut u8, layout) };
+            let layout = self.allocations.remove(&ptr.addr()).unwrap();
+            unsafe { alloc::alloc::dealloc(ptr as *mut u8, layout) };
         }
     }
 
@@ -803,7 +798,7 @@ pub unsafe extern "C" fn spine_strtoul(
 
 #[no_mangle]
 pub unsafe extern "C" fn spine_strrchr(mut p: *const c_char, ch: c_int) -> *mut c_char {
-    let mut save: *mut c_char = std::ptr::null_mut::<c_char>();
+    let mut save: *mut c_char = core::ptr::null_mut::<c_char>();
     loop {
         if *p as c_int == ch {
             save = p.cast_mut();
@@ -862,28 +857,22 @@ extern "C" fn spine_fmodf(x: c_float, y: c_float) -> c_float {
 
 #[no_mangle]
 unsafe extern "C" fn spine_malloc(size: size_t) -> *mut c_void {
-    let singleton = Allocator::singleton();
-    let mut allocator = singleton.lock().unwrap();
-    allocator.malloc(size as usize)
+    lock(&ALLOCATOR).malloc(size as usize)
 }
 
 #[no_mangle]
 unsafe extern "C" fn spine_realloc(ptr: *mut c_void, size: size_t) -> *mut c_void {
     if !ptr.is_null() {
-        let singleton = Allocator::singleton();
-        let mut allocator = singleton.lock().unwrap();
-        allocator.realloc(ptr, size as usize)
+        lock(&ALLOCATOR).realloc(ptr, size as usize)
     } else {
-        std::ptr::null_mut()
+        core::ptr::null_mut()
     }
 }
 
 #[no_mangle]
 unsafe extern "C" fn spine_free(ptr: *mut c_void) {
     if !ptr.is_null() && ptr as usize != 1 {
-        let singleton = Allocator::singleton();
-        let mut allocator = singleton.lock().unwrap();
-        allocator.free(ptr);
+        lock(&ALLOCATOR).free(ptr);
     }
 }
 
@@ -1197,7 +1186,10 @@ fn fmt(format: &str, args: &[Box<dyn Any>]) -> String {
 
 pub(crate) fn printf(c_format: *const c_char, args: &[Box<dyn Any>]) {
     let format = unsafe { CStr::from_ptr(c_format).to_str().unwrap().to_owned() };
+    #[cfg(feature = "std")]
     print!("{}", fmt(&format, args));
+    #[cfg(not(feature = "std"))]
+    let _ = fmt(&format, args);
 }
 
 pub(crate) fn snprintf(
@@ -1228,7 +1220,7 @@ macro_rules! spine_printf {
     };
     ($format:expr, $($arg:expr),+ $(,)? ) => {
         crate::c::wasm::printf($format, &[
-            $(Box::new($arg)),+
+            $(alloc::boxed::Box::new($arg)),+
         ]);
     };
 }
@@ -1239,7 +1231,7 @@ macro_rules! spine_snprintf {
     };
     ($str:expr, $len:expr, $format:expr, $($arg:expr),+ $(,)? ) => {
         crate::c::wasm::snprintf($str, $len, $format, &[
-            $(Box::new($arg)),+
+            $(alloc::boxed::Box::new($arg)),+
         ]);
     };
 }
@@ -1352,13 +1344,13 @@ mod tests {
     fn strtol() {
         unsafe {
             let str = CString::new("1234 hello world").unwrap();
-            let mut endptr: *mut super::c_char = std::ptr::null_mut();
+            let mut endptr: *mut super::c_char = core::ptr::null_mut();
             let value = spine_strtol(str.as_ptr(), &mut endptr, 10);
             assert_eq!(value, 1234);
             assert_eq!(endptr.cast_const(), str.as_ptr().offset(4));
 
             let str = CString::new("hello world").unwrap();
-            let mut endptr: *mut super::c_char = std::ptr::null_mut();
+            let mut endptr: *mut super::c_char = core::ptr::null_mut();
             let value = spine_strtol(str.as_ptr(), &mut endptr, 10);
             assert_eq!(value, 0);
             assert_eq!(endptr.cast_const(), str.as_ptr().offset(0));
@@ -1369,13 +1361,13 @@ mod tests {
     fn strtoul() {
         unsafe {
             let str = CString::new("1234 hello world").unwrap();
-            let mut endptr: *mut super::c_char = std::ptr::null_mut();
+            let mut endptr: *mut super::c_char = core::ptr::null_mut();
             let value = spine_strtoul(str.as_ptr(), &mut endptr, 10);
             assert_eq!(value, 1234);
             assert_eq!(endptr.cast_const(), str.as_ptr().offset(4));
 
             let str = CString::new("hello world").unwrap();
-            let mut endptr: *mut super::c_char = std::ptr::null_mut();
+            let mut endptr: *mut super::c_char = core::ptr::null_mut();
             let value = spine_strtoul(str.as_ptr(), &mut endptr, 10);
             assert_eq!(value, 0);
             assert_eq!(endptr.cast_const(), str.as_ptr().offset(0));
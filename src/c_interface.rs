@@ -2,13 +2,25 @@
 
 #![allow(clippy::all)]
 
-use std::{
-    ffi::{CStr, CString},
+use crate::prelude::*;
+use alloc::ffi::CString;
+use core::{
+    ffi::CStr,
     hash::Hash,
     marker::PhantomData,
     ops::{Deref, DerefMut},
 };
 
+/// Panics with a descriptive message if `condition` is false, when the `strict-ffi` feature is
+/// enabled in a build with debug assertions. Otherwise the check is compiled out.
+macro_rules! ffi_check {
+    ($condition:expr, $($message:tt)+) => {
+        if cfg!(all(feature = "strict-ffi", debug_assertions)) && !$condition {
+            panic!("strict-ffi: {}", format_args!($($message)+));
+        }
+    };
+}
+
 /// Create a type from its underlying [`spine-c`](`crate::c`) pointer type.
 pub trait NewFromPtr<C> {
     unsafe fn new_from_ptr(c_ptr: *mut C) -> Self;
@@ -47,10 +59,10 @@ impl<'a, P, T> Deref for CTmpRef<'a, P, T> {
     }
 }
 
-impl<'a, P, T: std::fmt::Debug> std::fmt::Debug for CTmpRef<'a, P, T> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl<'a, P, T: core::fmt::Debug> core::fmt::Debug for CTmpRef<'a, P, T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.write_str("CTmpRef(")?;
-        std::fmt::Debug::fmt(&**self, f)?;
+        core::fmt::Debug::fmt(&**self, f)?;
         f.write_str(")")
     }
 }
@@ -127,10 +139,10 @@ impl<'a, P, T> DerefMut for CTmpMut<'a, P, T> {
     }
 }
 
-impl<'a, P, T: std::fmt::Debug> std::fmt::Debug for CTmpMut<'a, P, T> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl<'a, P, T: core::fmt::Debug> core::fmt::Debug for CTmpMut<'a, P, T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.write_str("CTmpMut(")?;
-        std::fmt::Debug::fmt(&**self, f)?;
+        core::fmt::Debug::fmt(&**self, f)?;
         f.write_str(")")
     }
 }
@@ -154,6 +166,12 @@ where
 {
     #[must_use]
     pub(crate) fn new(parent: &'a P, items: *mut *mut C, count: usize) -> Self {
+        ffi_check!(
+            count == 0 || !items.is_null(),
+            "null {} array with {} items",
+            core::any::type_name::<C>(),
+            count
+        );
         Self {
             _parent: parent,
             items,
@@ -172,7 +190,14 @@ where
 
     fn next(&mut self) -> Option<Self::Item> {
         if self.index < self.count {
-            let item = unsafe { T::new_from_ptr(*self.items.offset(self.index as isize)) };
+            let ptr = unsafe { *self.items.offset(self.index as isize) };
+            ffi_check!(
+                !ptr.is_null(),
+                "null {} pointer at index {}",
+                core::any::type_name::<C>(),
+                self.index
+            );
+            let item = unsafe { T::new_from_ptr(ptr) };
             self.index += 1;
             Some(CTmpRef::new(self._parent, item))
         } else {
@@ -199,6 +224,12 @@ where
 {
     #[must_use]
     pub(crate) fn new(parent: &'a mut P, items: *mut *mut C, count: usize) -> Self {
+        ffi_check!(
+            count == 0 || !items.is_null(),
+            "null {} array with {} items",
+            core::any::type_name::<C>(),
+            count
+        );
         Self {
             _parent: parent,
             items,
@@ -217,7 +248,14 @@ where
 
     fn next(&mut self) -> Option<Self::Item> {
         if self.index < self.count {
-            let item = unsafe { T::new_from_ptr(*self.items.offset(self.index as isize)) };
+            let ptr = unsafe { *self.items.offset(self.index as isize) };
+            ffi_check!(
+                !ptr.is_null(),
+                "null {} pointer at index {}",
+                core::any::type_name::<C>(),
+                self.index
+            );
+            let item = unsafe { T::new_from_ptr(ptr) };
             self.index += 1;
             Some(CTmpMut::new_weak(self._parent, item))
         } else {
@@ -244,6 +282,12 @@ where
 {
     #[must_use]
     pub(crate) fn new(parent: &'a P, items: *mut *mut C, count: usize) -> Self {
+        ffi_check!(
+            count == 0 || !items.is_null(),
+            "null {} array with {} items",
+            core::any::type_name::<C>(),
+            count
+        );
         Self {
             _parent: parent,
             items,
@@ -318,6 +362,12 @@ where
 {
     #[must_use]
     pub(crate) fn new(parent: &'a mut P, items: *mut *mut C, count: usize) -> Self {
+        ffi_check!(
+            count == 0 || !items.is_null(),
+            "null {} array with {} items",
+            core::any::type_name::<C>(),
+            count
+        );
         Self {
             _parent: parent,
             items,
@@ -334,14 +384,14 @@ pub struct SyncPtr<T>(pub *mut T);
 unsafe impl<T> Send for SyncPtr<T> {}
 unsafe impl<T> Sync for SyncPtr<T> {}
 
-impl<T> std::fmt::Debug for SyncPtr<T> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl<T> core::fmt::Debug for SyncPtr<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.write_str(&format!("{:?}", &self.0))
     }
 }
 
 impl<T> Hash for SyncPtr<T> {
-    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
         self.0.hash(state);
     }
 }
@@ -355,13 +405,13 @@ impl<T> PartialEq for SyncPtr<T> {
 impl<T> Eq for SyncPtr<T> {}
 
 impl<T> PartialOrd for SyncPtr<T> {
-    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
         self.0.partial_cmp(&other.0)
     }
 }
 
 impl<T> Ord for SyncPtr<T> {
-    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
         self.0.cmp(&other.0)
     }
 }
@@ -380,6 +430,11 @@ macro_rules! c_ptr {
         #[must_use]
         #[allow(dead_code, clippy::missing_const_for_fn)]
         pub(crate) unsafe fn c_ptr_ref(&self) -> &$c_type {
+            ffi_check!(
+                !self.$member.0.is_null(),
+                "null {} pointer",
+                stringify!($c_type)
+            );
             &*self.$member.0
         }
 
@@ -387,6 +442,11 @@ macro_rules! c_ptr {
         #[must_use]
         #[allow(dead_code, clippy::mut_from_ref, clippy::missing_const_for_fn)]
         pub(crate) unsafe fn c_ptr_mut(&self) -> &mut $c_type {
+            ffi_check!(
+                !self.$member.0.is_null(),
+                "null {} pointer",
+                stringify!($c_type)
+            );
             &mut *self.$member.0
         }
     };
@@ -454,7 +514,7 @@ macro_rules! c_accessor_string {
         pub fn $rust(&self) -> &str {
             unsafe {
                 if !self.c_ptr_ref().$c.is_null() {
-                    crate::c_interface::from_c_str(std::ffi::CStr::from_ptr(self.c_ptr_ref().$c))
+                    crate::c_interface::from_c_str(core::ffi::CStr::from_ptr(self.c_ptr_ref().$c))
                 } else {
                     ""
                 }
@@ -470,7 +530,7 @@ macro_rules! c_accessor_string_mut {
         pub fn $rust(&self) -> &str {
             unsafe {
                 if !self.c_ptr_ref().$c.is_null() {
-                    crate::c_interface::from_c_str(std::ffi::CStr::from_ptr(self.c_ptr_ref().$c))
+                    crate::c_interface::from_c_str(core::ffi::CStr::from_ptr(self.c_ptr_ref().$c))
                 } else {
                     ""
                 }
@@ -479,10 +539,10 @@ macro_rules! c_accessor_string_mut {
 
         /// # Errors
         ///
-        /// Returns [`std::ffi::NulError`] if an interior nul byte is found.
+        /// Returns [`NulError`](`alloc::ffi::NulError`) if an interior nul byte is found.
         $(#[$($attrss)*])*
-        pub fn $rust_set(&mut self, value: String) -> Result<(), std::ffi::NulError> {
-            let c_str = std::ffi::CString::new(value)?;
+        pub fn $rust_set(&mut self, value: String) -> Result<(), alloc::ffi::NulError> {
+            let c_str = alloc::ffi::CString::new(value)?;
             unsafe {
                 self.c_ptr_mut().$c = c_str.into_raw();
             }
@@ -499,7 +559,7 @@ macro_rules! c_accessor_string_optional {
         pub fn $rust(&self) -> Option<&str> {
             unsafe {
                 if !self.c_ptr_ref().$c.is_null() {
-                    Some(crate::c_interface::from_c_str(std::ffi::CStr::from_ptr(self.c_ptr_ref().$c)))
+                    Some(crate::c_interface::from_c_str(core::ffi::CStr::from_ptr(self.c_ptr_ref().$c)))
                 } else {
                     None
                 }
@@ -587,7 +647,7 @@ macro_rules! c_accessor_enum {
     };
 }
 
-/*macro_rules! c_accessor_enum_mut {
+macro_rules! c_accessor_enum_mut {
     ($(#[$($attrss1:tt)*])* $rust:ident, $(#[$($attrss2:tt)*])* $rust_set:ident, $c:ident, $type:ty) => {
         c_accessor_enum!(
             $(#[$($attrss1)*])*
@@ -596,13 +656,13 @@ macro_rules! c_accessor_enum {
             $type
         );
         $(#[$($attrss2)*])*
-        pub fn $rust_set(&self, value: $type) {
+        pub fn $rust_set(&mut self, value: $type) {
             unsafe {
-                (*self.c_ptr()).$c = value as u32;
+                self.c_ptr_mut().$c = value as _;
             }
         }
     };
-}*/
+}
 
 macro_rules! c_accessor_renderer_object {
     () => {
@@ -620,10 +680,16 @@ macro_rules! c_accessor_tmp_ptr {
         $(#[$($attrss)*])*
         #[must_use]
         pub fn $rust(&self) -> crate::c_interface::CTmpRef<Self, $type> {
+            let ptr = unsafe { self.c_ptr_ref().$c };
+            ffi_check!(
+                !ptr.is_null(),
+                "null {} pointer in {}::{}",
+                stringify!($c_type),
+                core::any::type_name::<Self>(),
+                stringify!($c)
+            );
             crate::c_interface::CTmpRef::new(self, unsafe {
-                <$type as crate::c_interface::NewFromPtr<$c_type>>::new_from_ptr(
-                    self.c_ptr_ref().$c,
-                )
+                <$type as crate::c_interface::NewFromPtr<$c_type>>::new_from_ptr(ptr)
             })
         }
     };
@@ -641,15 +707,58 @@ macro_rules! c_accessor_tmp_ptr_mut {
         $(#[$($attrss2)*])*
         #[must_use]
         pub fn $rust_mut(&mut self) -> crate::c_interface::CTmpMut<Self, $type> {
+            let ptr = unsafe { self.c_ptr_ref().$c };
+            ffi_check!(
+                !ptr.is_null(),
+                "null {} pointer in {}::{}",
+                stringify!($c_type),
+                core::any::type_name::<Self>(),
+                stringify!($c)
+            );
             crate::c_interface::CTmpMut::new(self, unsafe {
-                <$type as crate::c_interface::NewFromPtr<$c_type>>::new_from_ptr(
-                    self.c_ptr_ref().$c,
-                )
+                <$type as crate::c_interface::NewFromPtr<$c_type>>::new_from_ptr(ptr)
             })
         }
     };
 }
 
+/// Like [`c_accessor_tmp_ptr_mut`], for the setup pose data of an object in a skeleton. The
+/// mutable accessor returns [`SpineError::Frozen`](`crate::SpineError::Frozen`) if
+/// `$check_frozen(&self)` finds the [`SkeletonData`](`crate::SkeletonData`) it belongs to frozen.
+macro_rules! c_accessor_tmp_ptr_data_mut {
+    ($(#[$($attrss1:tt)*])* $rust:ident, $(#[$($attrss2:tt)*])* $rust_mut:ident, $c:ident, $type:ty, $c_type:ident, $check_frozen:path) => {
+        c_accessor_tmp_ptr!(
+            $(#[$($attrss1)*])*
+            $rust,
+            $c,
+            $type,
+            $c_type
+        );
+        $(#[$($attrss2)*])*
+        ///
+        /// # Errors
+        ///
+        /// Returns [`SpineError::Frozen`](`crate::SpineError::Frozen`) if the skeleton data has
+        /// been frozen with [`SkeletonData::freeze`](`crate::SkeletonData::freeze`).
+        pub fn $rust_mut(
+            &mut self,
+        ) -> Result<crate::c_interface::CTmpMut<Self, $type>, crate::SpineError> {
+            $check_frozen(self)?;
+            let ptr = unsafe { self.c_ptr_ref().$c };
+            ffi_check!(
+                !ptr.is_null(),
+                "null {} pointer in {}::{}",
+                stringify!($c_type),
+                core::any::type_name::<Self>(),
+                stringify!($c)
+            );
+            Ok(crate::c_interface::CTmpMut::new(self, unsafe {
+                <$type as crate::c_interface::NewFromPtr<$c_type>>::new_from_ptr(ptr)
+            }))
+        }
+    };
+}
+
 macro_rules! c_accessor_tmp_ptr_optional{
     ($(#[$($attrss:tt)*])* $rust:ident, $c:ident, $type:ty, $c_type:ident) => {
         $(#[$($attrss)*])*
@@ -743,10 +852,26 @@ macro_rules! c_accessor_array {
             index: usize,
         ) -> Option<crate::c_interface::CTmpRef<Self, $type>> {
             if index < self.$count_fn() {
+                let items = unsafe { self.c_ptr_ref().$c };
+                ffi_check!(
+                    !items.is_null(),
+                    "null {} array in {}::{} with {} items",
+                    stringify!($c_type),
+                    core::any::type_name::<Self>(),
+                    stringify!($c),
+                    self.$count_fn()
+                );
+                let ptr = unsafe { *items.add(index) };
+                ffi_check!(
+                    !ptr.is_null(),
+                    "null {} pointer at index {} of {}::{}",
+                    stringify!($c_type),
+                    index,
+                    core::any::type_name::<Self>(),
+                    stringify!($c)
+                );
                 Some(crate::c_interface::CTmpRef::new(self, unsafe {
-                    <$type as crate::c_interface::NewFromPtr<$c_type>>::new_from_ptr(
-                        *self.c_ptr_ref().$c.add(index),
-                    )
+                    <$type as crate::c_interface::NewFromPtr<$c_type>>::new_from_ptr(ptr)
                 }))
             } else {
                 None
@@ -788,10 +913,26 @@ macro_rules! c_accessor_array_mut {
             index: usize,
         ) -> Option<crate::c_interface::CTmpMut<Self, $type>> {
             if index < self.$count_fn() {
+                let items = unsafe { self.c_ptr_mut().$c };
+                ffi_check!(
+                    !items.is_null(),
+                    "null {} array in {}::{} with {} items",
+                    stringify!($c_type),
+                    core::any::type_name::<Self>(),
+                    stringify!($c),
+                    self.$count_fn()
+                );
+                let ptr = unsafe { *items.add(index) };
+                ffi_check!(
+                    !ptr.is_null(),
+                    "null {} pointer at index {} of {}::{}",
+                    stringify!($c_type),
+                    index,
+                    core::any::type_name::<Self>(),
+                    stringify!($c)
+                );
                 Some(crate::c_interface::CTmpMut::new(self, unsafe {
-                    <$type as crate::c_interface::NewFromPtr<$c_type>>::new_from_ptr(
-                        *self.c_ptr_mut().$c.add(index),
-                    )
+                    <$type as crate::c_interface::NewFromPtr<$c_type>>::new_from_ptr(ptr)
                 }))
             } else {
                 None
@@ -876,9 +1017,21 @@ macro_rules! c_accessor_slice_for {
         pub fn $rust(&self) -> $type {
             #[allow(unused_unsafe)]
             unsafe {
-                std::slice::from_raw_parts(self.$for().$c, self.$for().$len as usize)
-                    .try_into()
-                    .unwrap()
+                let ptr = self.$for().$c;
+                if ptr.is_null() {
+                    &[]
+                } else {
+                    ffi_check!(
+                        self.$for().$len >= 0,
+                        "negative length {} for {}::{}",
+                        self.$for().$len,
+                        core::any::type_name::<Self>(),
+                        stringify!($c)
+                    );
+                    core::slice::from_raw_parts(ptr, self.$for().$len as usize)
+                        .try_into()
+                        .unwrap()
+                }
             }
         }
     };
@@ -894,7 +1047,7 @@ macro_rules! c_accessor_fixed_slice_optional {
             unsafe {
                 let ptr = self.c_ptr_ref().$c;
                 if !ptr.is_null() {
-                    Some(std::slice::from_raw_parts(ptr, $len).try_into().unwrap())
+                    Some(core::slice::from_raw_parts(ptr, $len).try_into().unwrap())
                 } else {
                     None
                 }
@@ -909,7 +1062,7 @@ macro_rules! c_attachment_accessors {
         #[must_use]
         pub fn name(&self) -> &str {
             unsafe {
-                crate::c_interface::from_c_str(std::ffi::CStr::from_ptr(self.attachment().name))
+                crate::c_interface::from_c_str(core::ffi::CStr::from_ptr(self.attachment().name))
             }
         }
 
@@ -923,11 +1076,53 @@ macro_rules! c_attachment_accessors {
 
 macro_rules! c_vertex_attachment_accessors {
     () => {
+        /// Computes the world positions of `count` vertex floats starting at `start`, writing
+        /// them to `world_vertices` at `offset`, `stride` floats apart. The slot's deform buffer
+        /// and the world transforms of the bones are used, so
+        /// [`Skeleton::update_world_transform`](`crate::Skeleton::update_world_transform`) must
+        /// be called first.
+        ///
+        /// # Errors
+        ///
+        /// Returns [`SpineError::IncompatibleAttachment`](`crate::SpineError::IncompatibleAttachment`)
+        /// if the slot's deform buffer is too small for this attachment, or if this attachment is
+        /// weighted to bones the slot's skeleton doesn't have.
+        ///
+        /// # Panics
+        ///
+        /// Panics if `start` or `count` are negative or odd, if they exceed
+        /// [`world_vertices_length`](`Self::world_vertices_length`), or if `world_vertices` is too
+        /// small to hold the result.
+        #[inline]
+        pub fn compute_world_vertices(
+            &self,
+            slot: &crate::slot::Slot,
+            start: i32,
+            count: i32,
+            world_vertices: &mut [f32],
+            offset: i32,
+            stride: i32,
+        ) -> Result<(), crate::error::SpineError> {
+            crate::attachment::compute_world_vertices_checked(
+                core::ptr::from_ref(self.vertex_attachment()).cast_mut(),
+                slot,
+                start,
+                count,
+                world_vertices,
+                offset,
+                stride,
+            )
+        }
+
+        /// Computes world vertices without checking the arguments, see
+        /// [`compute_world_vertices`](`Self::compute_world_vertices`).
+        ///
         /// # Safety
         ///
-        /// The slot passed in must be the same slot this attachment originated from.
+        /// The slot passed in must be the same slot this attachment originated from, and
+        /// `world_vertices` must be large enough to hold the result.
         #[inline]
-        pub unsafe fn compute_world_vertices(
+        pub unsafe fn compute_world_vertices_unchecked(
             &self,
             slot: &crate::slot::Slot,
             start: i32,
@@ -937,8 +1132,7 @@ macro_rules! c_vertex_attachment_accessors {
             stride: i32,
         ) {
             crate::c::spVertexAttachment_computeWorldVertices(
-                self.vertex_attachment() as *const crate::c::spVertexAttachment
-                    as *mut crate::c::spVertexAttachment,
+                core::ptr::from_ref(self.vertex_attachment()).cast_mut(),
                 slot.c_ptr(),
                 start,
                 count,
@@ -978,7 +1172,7 @@ macro_rules! c_vertex_attachment_accessors_mint {
         #[must_use]
         pub fn vertices2(&self) -> &[mint::Vector2<f32>] {
             unsafe {
-                std::slice::from_raw_parts(
+                core::slice::from_raw_parts(
                     self.vertex_attachment()
                         .vertices
                         .cast::<mint::Vector2<f32>>(),
@@ -1051,80 +1245,6 @@ macro_rules! c_handle_decl {
     };
 }
 
-macro_rules! c_handle_indexed_decl {
-    ($(#[$($attrss:tt)*])* $name:ident, $type:ty, $parent:ty, $c_type:ident, $c_parent:ident) => {
-        $(#[$($attrss)*])*
-        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-        pub struct $name {
-            index: i32,
-            c_item: crate::c_interface::SyncPtr<$c_type>,
-            c_parent: crate::c_interface::SyncPtr<$c_parent>,
-        }
-
-        impl $name {
-            #[must_use]
-            pub(crate) const fn new(
-                index: i32,
-                c_item: *const $c_type,
-                c_parent: *const $c_parent,
-            ) -> Self {
-                Self {
-                    index,
-                    c_item: SyncPtr(c_item as *mut $c_type),
-                    c_parent: SyncPtr(c_parent as *mut $c_parent),
-                }
-            }
-
-            /// Safely acquired the item, verifying its existence using its parent.
-            #[must_use]
-            pub fn get<'a>(
-                &self,
-                parent: &'a $parent,
-            ) -> Option<crate::c_interface::CTmpRef<'a, $parent, $type>> {
-                if parent.c_ptr() == self.c_parent.0 {
-                    if <$type>::handle_valid(self) {
-                        Some(crate::c_interface::CTmpRef::new(parent, unsafe {
-                            <$type>::new_from_ptr(self.c_item.0)
-                        }))
-                    } else {
-                        None
-                    }
-                } else {
-                    None
-                }
-            }
-
-            /// Safely acquired the item, verifying its existence using its parent.
-            #[must_use]
-            pub fn get_mut<'a>(
-                &self,
-                parent: &'a mut $parent,
-            ) -> Option<crate::c_interface::CTmpMut<'a, $parent, $type>> {
-                if parent.c_ptr() == self.c_parent.0 {
-                    if <$type>::handle_valid(self) {
-                        Some(crate::c_interface::CTmpMut::new(parent, unsafe {
-                            <$type>::new_from_ptr(self.c_item.0)
-                        }))
-                    } else {
-                        None
-                    }
-                } else {
-                    None
-                }
-            }
-
-            /// # Safety
-            ///
-            /// Acquire the item without any checks. This is a direct pointer access which is fast
-            /// but will segfault if the data has been disposed of already.
-            #[must_use]
-            pub unsafe fn get_unchecked(&self) -> $type {
-                <$type>::new_from_ptr(self.c_item.0)
-            }
-        }
-    };
-}
-
 /// Used to isolate this `unwrap()` in one place in the codebase. It is necessary to avoid proceeding
 /// with corrupt data, but the panic (ideally) never happens.
 pub(crate) fn to_c_str(rust_string: &str) -> CString {
@@ -0,0 +1,216 @@
+use crate::prelude::*;
+use crate::{
+    animation::TimelineType,
+    probe::{SkeletonFormat, RUNTIME_VERSION},
+    AttachmentType,
+};
+
+/// A type of constraint, see [`Capabilities::constraint_types`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ConstraintType {
+    /// An [`IkConstraint`](`crate::IkConstraint`).
+    Ik,
+    /// A [`TransformConstraint`](`crate::TransformConstraint`).
+    Transform,
+    /// A [`PathConstraint`](`crate::PathConstraint`).
+    Path,
+    /// A [`PhysicsConstraint`](`crate::PhysicsConstraint`).
+    Physics,
+}
+
+/// What this build of the runtime supports, see [`capabilities`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Capabilities {
+    /// The version of this crate, such as `0.8.0`.
+    pub crate_version: &'static str,
+    /// The version of Spine whose exports can be loaded, see
+    /// [`probe::RUNTIME_VERSION`](`crate::probe::RUNTIME_VERSION`).
+    pub spine_version: &'static str,
+    /// The formats skeleton data can be loaded from.
+    pub skeleton_formats: &'static [SkeletonFormat],
+    /// The constraints which are applied when updating world transforms.
+    pub constraint_types: &'static [ConstraintType],
+    /// The attachments which can be loaded.
+    pub attachment_types: &'static [AttachmentType],
+    /// The timelines which are applied by animations.
+    pub timeline_types: &'static [TimelineType],
+    /// Whether region and mesh attachments can play image sequences.
+    pub sequences: bool,
+    /// Whether physics constraints are simulated, see [`Physics`](`crate::Physics`).
+    pub physics: bool,
+    /// Whether clipping attachments are applied when rendering, see
+    /// [`SkeletonClipping`](`crate::SkeletonClipping`).
+    pub clipping: bool,
+    /// Whether slots can use two color tinting, see
+    /// [`SkeletonData::uses_two_color_tint`](`crate::SkeletonData::uses_two_color_tint`).
+    pub two_color_tint: bool,
+    /// The Cargo features this crate was built with, such as `"draw_functions"`.
+    pub features: Vec<&'static str>,
+}
+
+impl Capabilities {
+    /// Returns `true` if this crate was built with the Cargo feature.
+    #[must_use]
+    pub fn has_feature(&self, feature: &str) -> bool {
+        self.features.contains(&feature)
+    }
+}
+
+/// Every Cargo feature of this crate, and whether it is enabled.
+const FEATURES: [(bool, &str); 14] = [
+    (cfg!(feature = "std"), "std"),
+    (cfg!(feature = "libm"), "libm"),
+    (cfg!(feature = "libc"), "libc"),
+    (cfg!(feature = "draw_functions"), "draw_functions"),
+    (cfg!(feature = "mint"), "mint"),
+    (cfg!(feature = "glam"), "glam"),
+    (cfg!(feature = "parallel"), "parallel"),
+    (cfg!(feature = "strict-ffi"), "strict-ffi"),
+    (cfg!(feature = "debug_backtraces"), "debug_backtraces"),
+    (cfg!(feature = "bevy"), "bevy"),
+    (cfg!(feature = "wgpu-renderer"), "wgpu-renderer"),
+    (cfg!(feature = "compat"), "compat"),
+    (cfg!(feature = "profile"), "profile"),
+    (cfg!(feature = "bench"), "bench"),
+];
+
+/// Describes what this build of the runtime supports, so middleware which works with several
+/// versions of this crate can detect features at runtime instead of relying on the crate
+/// version.
+///
+/// ```
+/// use rusty_spine::{AttachmentType, ConstraintType};
+///
+/// let capabilities = rusty_spine::capabilities();
+/// if capabilities.constraint_types.contains(&ConstraintType::Physics) {
+///     println!("physics supported");
+/// }
+/// if capabilities.has_feature("draw_functions") {
+///     println!("renderables can be generated");
+/// }
+/// assert!(capabilities.attachment_types.contains(&AttachmentType::Mesh));
+/// ```
+#[must_use]
+pub fn capabilities() -> Capabilities {
+    let features = FEATURES
+        .iter()
+        .filter(|(enabled, _)| *enabled)
+        .map(|(_, feature)| *feature)
+        .collect();
+    Capabilities {
+        crate_version: env!("CARGO_PKG_VERSION"),
+        spine_version: RUNTIME_VERSION,
+        skeleton_formats: &[SkeletonFormat::Json, SkeletonFormat::Binary],
+        constraint_types: &[
+            ConstraintType::Ik,
+            ConstraintType::Transform,
+            ConstraintType::Path,
+            ConstraintType::Physics,
+        ],
+        attachment_types: &[
+            AttachmentType::Region,
+            AttachmentType::BoundingBox,
+            AttachmentType::Mesh,
+            AttachmentType::LinkedMesh,
+            AttachmentType::Path,
+            AttachmentType::Point,
+            AttachmentType::Clipping,
+        ],
+        timeline_types: &[
+            TimelineType::Attachment,
+            TimelineType::Alpha,
+            TimelineType::PathConstraintPosition,
+            TimelineType::PathConstraintSpacing,
+            TimelineType::Rotate,
+            TimelineType::ScaleX,
+            TimelineType::ScaleY,
+            TimelineType::ShearX,
+            TimelineType::ShearY,
+            TimelineType::TranslateX,
+            TimelineType::TranslateY,
+            TimelineType::Scale,
+            TimelineType::Shear,
+            TimelineType::Translate,
+            TimelineType::Deform,
+            TimelineType::Sequence,
+            TimelineType::Inherit,
+            TimelineType::IkConstraint,
+            TimelineType::PathConstraintMix,
+            TimelineType::PhysicsConstraintInertia,
+            TimelineType::PhysicsConstraintStrength,
+            TimelineType::PhysicsConstraintDamping,
+            TimelineType::PhysicsConstraintMass,
+            TimelineType::PhysicsConstraintWind,
+            TimelineType::PhysicsConstraintGravity,
+            TimelineType::PhysicsConstraintMix,
+            TimelineType::PhysicsConstraintReset,
+            TimelineType::Rgb2,
+            TimelineType::Rgba2,
+            TimelineType::Rgba,
+            TimelineType::Rgb,
+            TimelineType::TransformConstraint,
+            TimelineType::DrawOrder,
+            TimelineType::Event,
+        ],
+        sequences: true,
+        physics: true,
+        clipping: true,
+        two_color_tint: true,
+        features,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{animation::TimelineType, test::TestAsset};
+
+    use super::*;
+
+    /// Every timeline in the test assets is a supported type.
+    #[test]
+    fn capabilities_cover_assets() {
+        let capabilities = capabilities();
+        assert!(capabilities.has_feature("draw_functions") == cfg!(feature = "draw_functions"));
+        assert!(!capabilities.timeline_types.contains(&TimelineType::Unknown));
+        for asset in TestAsset::all() {
+            let skeleton_data = asset.skeleton_data(true);
+            for animation in skeleton_data.animations() {
+                for timeline in animation.timelines() {
+                    assert!(capabilities
+                        .timeline_types
+                        .contains(&timeline.timeline_type()));
+                }
+            }
+            for skin_attachment in skeleton_data.attachments() {
+                assert!(capabilities
+                    .attachment_types
+                    .contains(&skin_attachment.attachment.attachment_type()));
+            }
+        }
+    }
+
+    /// Every feature in Cargo.toml is reported.
+    #[test]
+    fn capabilities_cover_cargo_features() {
+        let manifest = include_str!("../Cargo.toml");
+        let features_section = manifest
+            .split("\n[features]\n")
+            .nth(1)
+            .unwrap()
+            .split("\n[")
+            .next()
+            .unwrap();
+        let mut cargo_features = features_section
+            .lines()
+            .filter_map(|line| line.split_once(" = "))
+            .map(|(feature, _)| feature)
+            .filter(|feature| *feature != "default")
+            .collect::<Vec<_>>();
+        cargo_features.sort_unstable();
+        let mut features = FEATURES.map(|(_, feature)| feature);
+        features.sort_unstable();
+        assert_eq!(features.as_slice(), cargo_features.as_slice());
+    }
+}
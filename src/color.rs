@@ -1,6 +1,8 @@
-use std::ops::{Mul, MulAssign};
+use core::ops::{Mul, MulAssign};
 
 use crate::c::c_float;
+#[cfg(not(feature = "std"))]
+use crate::math::Float;
 
 /// RGBA F32 color that is byte-compatible with the Spine runtime.
 #[derive(Copy, Clone, Debug, Default, PartialEq)]
@@ -18,6 +20,25 @@ impl Color {
         Self { r, g, b, a }
     }
 
+    /// Create a color from a hue in degrees, and saturation, value, and alpha in `0..=1`. Hues
+    /// outside of `0..360` wrap around.
+    #[must_use]
+    pub fn new_hsva(h: f32, s: f32, v: f32, a: f32) -> Self {
+        let h = h.rem_euclid(360.) / 60.;
+        let chroma = v * s;
+        let x = chroma * (1. - (h % 2. - 1.).abs());
+        let (r, g, b) = match h as u32 {
+            0 => (chroma, x, 0.),
+            1 => (x, chroma, 0.),
+            2 => (0., chroma, x),
+            3 => (0., x, chroma),
+            4 => (x, 0., chroma),
+            _ => (chroma, 0., x),
+        };
+        let m = v - chroma;
+        Self::new_rgba(r + m, g + m, b + m, a)
+    }
+
     pub fn set_r(&mut self, r: c_float) -> &mut Self {
         self.r = r;
         self
@@ -98,9 +119,9 @@ impl Color {
 
     pub fn clamp(&mut self) -> &mut Self {
         self.r = self.r.clamp(0., 1.);
-        self.g = self.r.clamp(0., 1.);
-        self.b = self.r.clamp(0., 1.);
-        self.a = self.r.clamp(0., 1.);
+        self.g = self.g.clamp(0., 1.);
+        self.b = self.b.clamp(0., 1.);
+        self.a = self.a.clamp(0., 1.);
         self
     }
 
@@ -110,6 +131,41 @@ impl Color {
         self.b *= self.a;
     }
 
+    /// The color as 8-bit channels, clamped to `0..=1` and rounded to the nearest value. Rounding
+    /// preserves `r, g, b <= a`, so premultiplied colors stay premultiplied once packed.
+    #[must_use]
+    pub fn to_rgba8(&self) -> [u8; 4] {
+        let pack = |x: f32| (x.clamp(0., 1.) * 255.).round() as u8;
+        [pack(self.r), pack(self.g), pack(self.b), pack(self.a)]
+    }
+
+    /// Linearly interpolate each channel from this color to `other`, where a `t` of `0` is this
+    /// color and `1` is `other`.
+    #[must_use]
+    pub fn lerp(&self, other: Color, t: f32) -> Color {
+        Color {
+            r: self.r + (other.r - self.r) * t,
+            g: self.g + (other.g - self.g) * t,
+            b: self.b + (other.b - self.b) * t,
+            a: self.a + (other.a - self.a) * t,
+        }
+    }
+
+    /// Convert the color from sRGB to linear, leaving alpha unchanged. Spine colors are sRGB, so
+    /// this is needed when drawing to a linear render target. Same as
+    /// [`nonlinear_to_linear`](`Self::nonlinear_to_linear`).
+    #[must_use]
+    pub fn to_linear(&self) -> Color {
+        self.nonlinear_to_linear()
+    }
+
+    /// Convert the color from linear to sRGB, leaving alpha unchanged. Same as
+    /// [`linear_to_nonlinear`](`Self::linear_to_nonlinear`).
+    #[must_use]
+    pub fn to_srgb(&self) -> Color {
+        self.linear_to_nonlinear()
+    }
+
     #[must_use]
     pub fn linear_to_nonlinear(&self) -> Color {
         Color {
@@ -186,6 +242,32 @@ impl From<[f32; 4]> for Color {
     }
 }
 
+impl From<Color> for [f32; 4] {
+    fn from(value: Color) -> Self {
+        [value.r, value.g, value.b, value.a]
+    }
+}
+
+impl From<Color> for [u8; 4] {
+    fn from(value: Color) -> Self {
+        value.to_rgba8()
+    }
+}
+
+#[cfg(feature = "glam")]
+impl From<glam::Vec4> for Color {
+    fn from(value: glam::Vec4) -> Self {
+        Self::from(value.to_array())
+    }
+}
+
+#[cfg(feature = "glam")]
+impl From<Color> for glam::Vec4 {
+    fn from(value: Color) -> Self {
+        Self::from_array(value.into())
+    }
+}
+
 fn linear_to_nonlinear(x: f32) -> f32 {
     if x <= 0.0 {
         x
@@ -205,3 +287,63 @@ fn nonlinear_to_linear(x: f32) -> f32 {
         ((x + 0.055) / 1.055).powf(2.4)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(a: Color, b: Color) {
+        let a: [f32; 4] = a.into();
+        let b: [f32; 4] = b.into();
+        for (a, b) in a.iter().zip(b) {
+            assert!((a - b).abs() < 1e-5, "{a} != {b}");
+        }
+    }
+
+    #[test]
+    fn hsva() {
+        assert_close(
+            Color::new_hsva(0., 1., 1., 1.),
+            Color::new_rgba(1., 0., 0., 1.),
+        );
+        assert_close(
+            Color::new_hsva(120., 1., 1., 0.5),
+            Color::new_rgba(0., 1., 0., 0.5),
+        );
+        assert_close(
+            Color::new_hsva(-120., 1., 1., 1.),
+            Color::new_rgba(0., 0., 1., 1.),
+        );
+        assert_close(
+            Color::new_hsva(30., 0.5, 0.8, 1.),
+            Color::new_rgba(0.8, 0.6, 0.4, 1.),
+        );
+        assert_close(
+            Color::new_hsva(200., 0., 0.25, 1.),
+            Color::new_rgba(0.25, 0.25, 0.25, 1.),
+        );
+    }
+
+    #[test]
+    fn conversions() {
+        let color = Color::from([0.2, 0.4, 0.6, 0.8]);
+        assert_close(color.to_linear().to_srgb(), color);
+        assert_close(
+            color.lerp(Color::new_rgba(1., 1., 1., 1.), 0.5),
+            Color::new_rgba(0.6, 0.7, 0.8, 0.9),
+        );
+        assert_eq!(<[u8; 4]>::from(color), [51, 102, 153, 204]);
+        let mut color = Color::new_rgba(2., -1., 0.5, 1.5);
+        color.clamp();
+        assert_eq!(color, Color::new_rgba(1., 0., 0.5, 1.));
+    }
+
+    #[cfg(feature = "glam")]
+    #[test]
+    fn glam_conversions() {
+        let color = Color::new_rgba(0.1, 0.2, 0.3, 0.4);
+        let vec4 = glam::Vec4::from(color);
+        assert_eq!(vec4, glam::Vec4::new(0.1, 0.2, 0.3, 0.4));
+        assert_eq!(Color::from(vec4), color);
+    }
+}
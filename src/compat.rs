@@ -0,0 +1,594 @@
+//! Loading skeletons exported by older versions of Spine, available with the `compat` feature.
+//!
+//! The runtime only reads data exported by Spine 4.2. JSON exported by Spine 3.8 can be upgraded
+//! to the 4.2 format with [`upgrade_json`], or read directly with
+//! [`SkeletonJson::read_legacy_skeleton_data`]:
+//!
+//! ```
+//! # #[path="./test.rs"]
+//! # mod test;
+//! # use std::sync::Arc;
+//! use rusty_spine::{compat, SkeletonJson};
+//!
+//! # let atlas = Arc::new(test::TestAsset::spineboy().atlas());
+//! # let json = br#"{"skeleton":{"spine":"3.8.99"},"bones":[{"name":"root"}]}"#;
+//! let skeleton_json = SkeletonJson::new(atlas);
+//! let skeleton_data = match compat::json_version(json)?.as_deref() {
+//!     Some(version) if version.starts_with("3.8") => {
+//!         skeleton_json.read_legacy_skeleton_data(json)?
+//!     }
+//!     _ => skeleton_json.read_skeleton_data(json)?,
+//! };
+//! # Ok::<(), rusty_spine::SpineError>(())
+//! ```
+//!
+//! The upgrade renames keys and converts curves and timelines to their 4.2 equivalents, so the
+//! skeleton looks and animates the same as in 3.8. Anything the upgrade doesn't understand is
+//! reported in a [`SpineError::UnsupportedLegacyData`] which lists every blocker at once, so the
+//! assets that need to be re-exported from the editor can be found quickly.
+//!
+//! Binary `.skel` files and exports from other versions before 4.2 are not supported.
+
+use crate::prelude::*;
+use crate::{json::JsonValue, SkeletonData, SkeletonJson, SpineError};
+
+/// The Spine version in the `skeleton.spine` field of JSON skeleton data, or [`None`] if it has
+/// no version.
+///
+/// # Errors
+///
+/// Returns [`SpineError::ParsingFailed`] if the data is not valid JSON.
+pub fn json_version(json: &[u8]) -> Result<Option<String>, SpineError> {
+    let root = parse(json)?;
+    Ok(version(&root).map(str::to_owned))
+}
+
+/// Upgrade JSON skeleton data exported by Spine 3.8 to the format read by this runtime. The
+/// upgraded data reports its version as `4.2 (3.8.xx)`, keeping the original version.
+///
+/// # Errors
+///
+/// Returns [`SpineError::ParsingFailed`] if the data is not valid JSON. Returns
+/// [`SpineError::UnsupportedLegacyData`] if the data was not exported by Spine 3.8, or if it
+/// contains anything which can't be upgraded.
+pub fn upgrade_json(json: &[u8]) -> Result<Vec<u8>, SpineError> {
+    let mut root = parse(json)?;
+    let version = version(&root).unwrap_or_default().to_owned();
+    if !version.starts_with("3.8") {
+        return Err(SpineError::UnsupportedLegacyData {
+            version: version.clone(),
+            unsupported: vec![format!(
+                "skeleton.spine: only Spine 3.8 exports can be upgraded, found `{version}`"
+            )],
+        });
+    }
+    let mut upgrade = Upgrade::default();
+    upgrade.skeleton(&mut root);
+    if !upgrade.unsupported.is_empty() {
+        return Err(SpineError::UnsupportedLegacyData {
+            version,
+            unsupported: upgrade.unsupported,
+        });
+    }
+    if let Some(skeleton) = root.get_mut("skeleton") {
+        skeleton.insert("spine", JsonValue::String(format!("4.2 ({version})")));
+    }
+    let mut out = String::new();
+    root.write(&mut out);
+    Ok(out.into_bytes())
+}
+
+impl SkeletonJson {
+    /// Read JSON skeleton data exported by Spine 3.8, see [`upgrade_json`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SpineError::UnsupportedLegacyData`] if the data can't be upgraded, and
+    /// [`SpineError::ParsingFailed`] if it is not valid JSON or the upgraded data fails to load.
+    pub fn read_legacy_skeleton_data(&self, json: &[u8]) -> Result<SkeletonData, SpineError> {
+        self.read_skeleton_data(&upgrade_json(json)?)
+    }
+}
+
+fn parse(json: &[u8]) -> Result<JsonValue, SpineError> {
+    core::str::from_utf8(json)
+        .map_err(|error| error.to_string())
+        .and_then(JsonValue::parse)
+        .map_err(|reason| SpineError::new_skeleton_parsing_failed(&reason, None))
+}
+
+fn version(root: &JsonValue) -> Option<&str> {
+    root.get("skeleton")?.get("spine")?.as_str()
+}
+
+/// Renames the member `from` of an object to each name in `to`.
+fn rename(object: &mut JsonValue, from: &str, to: &[&str]) {
+    if let Some(value) = object.remove(from) {
+        for name in to {
+            object.insert(name, value.clone());
+        }
+    }
+}
+
+fn number(object: &JsonValue, key: &str, default: f64) -> f64 {
+    object
+        .get(key)
+        .and_then(JsonValue::as_f64)
+        .unwrap_or(default)
+}
+
+/// The channels of a hex color, or [`None`] if it is not valid.
+fn color_channels(hex: &str) -> Option<Vec<f64>> {
+    hex.as_bytes()
+        .chunks(2)
+        .map(|channel| {
+            let channel = core::str::from_utf8(channel)
+                .ok()
+                .filter(|_| channel.len() == 2)?;
+            Some(f64::from(u8::from_str_radix(channel, 16).ok()?) / 255.)
+        })
+        .collect()
+}
+
+/// The setup mixes of transform constraints, and their 4.2 names.
+const TRANSFORM_MIXES: [(&str, &[&str]); 4] = [
+    ("rotateMix", &["mixRotate"]),
+    ("translateMix", &["mixX", "mixY"]),
+    ("scaleMix", &["mixScaleX", "mixScaleY"]),
+    ("shearMix", &["mixShearY"]),
+];
+
+/// The setup mixes of path constraints, and their 4.2 names.
+const PATH_MIXES: [(&str, &[&str]); 2] = [
+    ("rotateMix", &["mixRotate"]),
+    ("translateMix", &["mixX", "mixY"]),
+];
+
+const ATTACHMENT_TYPES: [&str; 7] = [
+    "region",
+    "mesh",
+    "linkedmesh",
+    "boundingbox",
+    "path",
+    "point",
+    "clipping",
+];
+
+#[derive(Default)]
+struct Upgrade {
+    unsupported: Vec<String>,
+}
+
+impl Upgrade {
+    fn unsupported(&mut self, path: &str, what: &str) {
+        self.unsupported.push(format!("{path}: {what}"));
+    }
+
+    fn skeleton(&mut self, root: &mut JsonValue) {
+        for bone in array_mut(root, "bones") {
+            rename(bone, "transform", &["inherit"]);
+        }
+        for constraint in array_mut(root, "transform") {
+            for (from, to) in TRANSFORM_MIXES {
+                rename(constraint, from, to);
+            }
+        }
+        for constraint in array_mut(root, "path") {
+            for (from, to) in PATH_MIXES {
+                rename(constraint, from, to);
+            }
+        }
+        match root.get_mut("skins") {
+            Some(JsonValue::Array(skins)) => {
+                for skin in skins {
+                    let skin_name = skin
+                        .get("name")
+                        .and_then(JsonValue::as_str)
+                        .unwrap_or_default()
+                        .to_owned();
+                    for (slot_name, slot) in object_mut(skin, "attachments") {
+                        for (name, attachment) in slot.as_object_mut().into_iter().flatten() {
+                            self.attachment(
+                                &format!("skins.{skin_name}.{slot_name}.{name}"),
+                                attachment,
+                            );
+                        }
+                    }
+                }
+            }
+            Some(_) => self.unsupported(
+                "skins",
+                "skins stored as an object, which was replaced by an array in Spine 3.8",
+            ),
+            None => {}
+        }
+        for (name, animation) in object_mut(root, "animations") {
+            self.animation(&format!("animations.{name}"), animation);
+        }
+    }
+
+    fn attachment(&mut self, path: &str, attachment: &mut JsonValue) {
+        let attachment_type = attachment
+            .get("type")
+            .and_then(JsonValue::as_str)
+            .unwrap_or("region");
+        if !ATTACHMENT_TYPES.contains(&attachment_type) {
+            let what = format!("unknown attachment type `{attachment_type}`");
+            self.unsupported(path, &what);
+        } else if attachment_type == "linkedmesh" {
+            rename(attachment, "deform", &["timelines"]);
+        }
+    }
+
+    fn animation(&mut self, path: &str, animation: &mut JsonValue) {
+        let Some(members) = animation.as_object_mut() else {
+            return self.unsupported(path, "expected an object");
+        };
+        let mut deform = None;
+        for (key, timelines) in members.iter_mut() {
+            let path = format!("{path}.{key}");
+            match key.as_str() {
+                "bones" => {
+                    for (bone, timelines) in timelines.as_object_mut().into_iter().flatten() {
+                        for (name, keys) in timelines.as_object_mut().into_iter().flatten() {
+                            self.bone_timeline(&format!("{path}.{bone}.{name}"), name, keys);
+                        }
+                    }
+                }
+                "slots" => {
+                    for (slot, timelines) in timelines.as_object_mut().into_iter().flatten() {
+                        for (name, keys) in timelines.as_object_mut().into_iter().flatten() {
+                            let path = format!("{path}.{slot}.{name}");
+                            if let Some(upgraded_name) = self.slot_timeline(&path, name, keys) {
+                                *name = upgraded_name.to_owned();
+                            }
+                        }
+                    }
+                }
+                "ik" => {
+                    for (_, keys) in timelines.as_object_mut().into_iter().flatten() {
+                        convert_curves(keys, |key| {
+                            vec![number(key, "mix", 1.), number(key, "softness", 0.)]
+                        });
+                    }
+                }
+                "transform" => {
+                    for (_, keys) in timelines.as_object_mut().into_iter().flatten() {
+                        convert_curves(keys, |key| {
+                            let translate = number(key, "translateMix", 1.);
+                            let scale = number(key, "scaleMix", 1.);
+                            vec![
+                                number(key, "rotateMix", 1.),
+                                translate,
+                                translate,
+                                scale,
+                                scale,
+                                number(key, "shearMix", 1.),
+                            ]
+                        });
+                        rename_keys(keys, &TRANSFORM_MIXES);
+                    }
+                }
+                "path" => {
+                    for (constraint, timelines) in timelines.as_object_mut().into_iter().flatten() {
+                        for (name, keys) in timelines.as_object_mut().into_iter().flatten() {
+                            self.path_timeline(&format!("{path}.{constraint}.{name}"), name, keys);
+                        }
+                    }
+                }
+                "deform" => deform = Some(core::mem::replace(timelines, JsonValue::Null)),
+                "drawOrder" | "draworder" | "events" => {}
+                _ => self.unsupported(&path, "unknown timeline type"),
+            }
+        }
+        if let Some(deform) = deform {
+            animation.remove("deform");
+            animation.insert("attachments", self.deform_timelines(path, deform));
+        }
+    }
+
+    fn bone_timeline(&mut self, path: &str, name: &str, keys: &mut JsonValue) {
+        match name {
+            "rotate" => {
+                convert_curves(keys, |key| vec![number(key, "angle", 0.)]);
+                rename_keys(keys, &[("angle", &["value"])]);
+            }
+            "translate" | "shear" => {
+                convert_curves(keys, |key| vec![number(key, "x", 0.), number(key, "y", 0.)]);
+            }
+            "scale" => {
+                convert_curves(keys, |key| vec![number(key, "x", 1.), number(key, "y", 1.)]);
+            }
+            _ => self.unsupported(path, "unknown bone timeline"),
+        }
+    }
+
+    /// Upgrades a slot timeline, returning its new name if it was renamed.
+    fn slot_timeline(
+        &mut self,
+        path: &str,
+        name: &str,
+        keys: &mut JsonValue,
+    ) -> Option<&'static str> {
+        let channels = |key: &JsonValue, color_key: &str, default: &str| {
+            key.get(color_key)
+                .and_then(JsonValue::as_str)
+                .and_then(color_channels)
+                .unwrap_or_else(|| color_channels(default).unwrap_or_default())
+        };
+        match name {
+            "attachment" => None,
+            "color" => {
+                convert_curves(keys, |key| channels(key, "color", "ffffffff"));
+                Some("rgba")
+            }
+            "twoColor" => {
+                convert_curves(keys, |key| {
+                    let mut values = channels(key, "light", "ffffffff");
+                    values.extend(channels(key, "dark", "ffffff"));
+                    values
+                });
+                Some("rgba2")
+            }
+            _ => {
+                self.unsupported(path, "unknown slot timeline");
+                None
+            }
+        }
+    }
+
+    fn path_timeline(&mut self, path: &str, name: &str, keys: &mut JsonValue) {
+        match name {
+            "position" | "spacing" => {
+                convert_curves(keys, |key| vec![number(key, name, 0.)]);
+                rename_keys(keys, &[(name, &["value"])]);
+            }
+            "mix" => {
+                convert_curves(keys, |key| {
+                    let translate = number(key, "translateMix", 1.);
+                    vec![number(key, "rotateMix", 1.), translate, translate]
+                });
+                rename_keys(keys, &PATH_MIXES);
+            }
+            _ => self.unsupported(path, "unknown path constraint timeline"),
+        }
+    }
+
+    /// Moves deform timelines, stored by skin, slot, and attachment, to the attachment timelines
+    /// which replaced them.
+    fn deform_timelines(&mut self, path: &str, mut deform: JsonValue) -> JsonValue {
+        for (_, slots) in deform.as_object_mut().into_iter().flatten() {
+            for (_, attachments) in slots.as_object_mut().into_iter().flatten() {
+                for (_, keys) in attachments.as_object_mut().into_iter().flatten() {
+                    if keys.as_array_mut().is_none() {
+                        self.unsupported(&format!("{path}.deform"), "expected an array of keys");
+                        continue;
+                    }
+                    convert_deform_curves(keys);
+                    *keys = JsonValue::Object(vec![(
+                        "deform".to_owned(),
+                        core::mem::replace(keys, JsonValue::Null),
+                    )]);
+                }
+            }
+        }
+        deform
+    }
+}
+
+fn array_mut<'a>(object: &'a mut JsonValue, key: &str) -> impl Iterator<Item = &'a mut JsonValue> {
+    object
+        .get_mut(key)
+        .and_then(JsonValue::as_array_mut)
+        .into_iter()
+        .flatten()
+}
+
+fn object_mut<'a>(
+    object: &'a mut JsonValue,
+    key: &str,
+) -> impl Iterator<Item = &'a mut (String, JsonValue)> {
+    object
+        .get_mut(key)
+        .and_then(JsonValue::as_object_mut)
+        .into_iter()
+        .flatten()
+}
+
+fn rename_keys(keys: &mut JsonValue, renames: &[(&str, &[&str])]) {
+    for key in keys.as_array_mut().into_iter().flatten() {
+        for (from, to) in renames {
+            rename(key, from, to);
+        }
+    }
+}
+
+/// Converts the Bezier curves of each key, which Spine 3.8 stores as `curve`, `c2`, `c3`, and
+/// `c4` relative to the key and the next key, to the curves of 4.2, which store each value's
+/// control points as absolute times and values.
+fn convert_curves(keys: &mut JsonValue, values: impl Fn(&JsonValue) -> Vec<f64>) {
+    let Some(keys) = keys.as_array_mut() else {
+        return;
+    };
+    let values: Vec<Vec<f64>> = keys.iter().map(&values).collect();
+    convert_curves_with(keys, |index| {
+        (values[index].clone(), values[index + 1].clone())
+    });
+}
+
+/// Converts curves as with [`convert_curves`], for deform timelines which interpolate from 0 to 1
+/// between each pair of keys.
+fn convert_deform_curves(keys: &mut JsonValue) {
+    if let Some(keys) = keys.as_array_mut() {
+        convert_curves_with(keys, |_| (vec![0.], vec![1.]));
+    }
+}
+
+fn convert_curves_with(keys: &mut [JsonValue], values: impl Fn(usize) -> (Vec<f64>, Vec<f64>)) {
+    let times: Vec<f64> = keys.iter().map(|key| number(key, "time", 0.)).collect();
+    let keys_count = keys.len();
+    for (index, key) in keys.iter_mut().enumerate() {
+        let c2 = number(key, "c2", 0.);
+        let c3 = number(key, "c3", 1.);
+        let c4 = number(key, "c4", 1.);
+        for control in ["c2", "c3", "c4"] {
+            key.remove(control);
+        }
+        let Some(c1) = key.get("curve").and_then(JsonValue::as_f64) else {
+            continue;
+        };
+        if index + 1 == keys_count {
+            key.remove("curve");
+            continue;
+        }
+        let (time, next_time) = (times[index], times[index + 1]);
+        let (values, next_values) = values(index);
+        let mut curve = vec![];
+        for (value, next_value) in values.iter().zip(&next_values) {
+            curve.extend([
+                time + c1 * (next_time - time),
+                value + c2 * (next_value - value),
+                time + c3 * (next_time - time),
+                value + c4 * (next_value - value),
+            ]);
+        }
+        key.insert(
+            "curve",
+            JsonValue::Array(curve.into_iter().map(JsonValue::Number).collect()),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::sync::Arc;
+
+    use crate::{json::JsonValue, test::TestAsset, SkeletonJson, SpineError};
+
+    use super::{json_version, upgrade_json};
+
+    const SKELETON_38: &str = r#"{
+        "skeleton": { "hash": "x", "spine": "3.8.99", "width": 100, "height": 100 },
+        "bones": [
+            { "name": "root" },
+            { "name": "arm", "parent": "root", "length": 50, "transform": "noScale" },
+            { "name": "target", "parent": "root", "x": 50 }
+        ],
+        "slots": [ { "name": "arm", "bone": "arm", "attachment": "arm" } ],
+        "transform": [
+            { "name": "follow", "bones": [ "arm" ], "target": "target", "translateMix": 0.5 }
+        ],
+        "skins": [
+            {
+                "name": "default",
+                "attachments": {
+                    "arm": {
+                        "arm": {
+                            "type": "mesh",
+                            "path": "crosshair",
+                            "uvs": [ 0, 0, 1, 0, 1, 1 ],
+                            "triangles": [ 0, 1, 2 ],
+                            "vertices": [ 0, 0, 10, 0, 10, 10 ],
+                            "hull": 3
+                        }
+                    }
+                }
+            }
+        ],
+        "animations": {
+            "wave": {
+                "bones": {
+                    "arm": {
+                        "rotate": [
+                            { "angle": 0, "curve": 0.25, "c3": 0.75 },
+                            { "time": 1, "angle": 90 }
+                        ],
+                        "scale": [ { "x": 2, "curve": "stepped" }, { "time": 1 } ]
+                    }
+                },
+                "slots": {
+                    "arm": {
+                        "color": [ { "color": "ff0000ff" }, { "time": 1, "color": "ffffffff" } ]
+                    }
+                },
+                "transform": {
+                    "follow": [ { "translateMix": 0.5, "curve": 0.5 }, { "time": 1 } ]
+                },
+                "deform": {
+                    "default": {
+                        "arm": { "arm": [ { "curve": 0.1 }, { "time": 1, "vertices": [ 1, 1 ] } ] }
+                    }
+                }
+            }
+        }
+    }"#;
+
+    #[test]
+    fn upgrade_38() {
+        let json = SKELETON_38.as_bytes();
+        assert_eq!(json_version(json).unwrap().as_deref(), Some("3.8.99"));
+        let upgraded = upgrade_json(json).unwrap();
+        let root = JsonValue::parse(core::str::from_utf8(&upgraded).unwrap()).unwrap();
+        let wave = root.get("animations").unwrap().get("wave").unwrap();
+        let rotate = wave
+            .get("bones")
+            .and_then(|bones| bones.get("arm"))
+            .and_then(|arm| arm.get("rotate"))
+            .unwrap();
+        let JsonValue::Array(rotate) = rotate else {
+            panic!("rotate keys should be an array");
+        };
+        assert_eq!(rotate[1].get("value"), Some(&JsonValue::Number(90.)));
+        let curve: Vec<f64> = match rotate[0].get("curve") {
+            Some(JsonValue::Array(curve)) => curve.iter().filter_map(JsonValue::as_f64).collect(),
+            _ => panic!("curve should be converted"),
+        };
+        assert_eq!(curve, [0.25, 0., 0.75, 90.]);
+        assert!(wave.get("deform").is_none());
+
+        let atlas = Arc::new(TestAsset::spineboy().atlas());
+        let skeleton_data = SkeletonJson::new(atlas)
+            .read_legacy_skeleton_data(json)
+            .unwrap();
+        assert_eq!(skeleton_data.version(), Some("4.2 (3.8.99)"));
+        assert_eq!(skeleton_data.bones_count(), 3);
+        let constraint = skeleton_data.transform_constraints().next().unwrap();
+        assert_eq!(constraint.mix_x(), 0.5);
+        assert_eq!(constraint.mix_y(), 0.5);
+        assert_eq!(constraint.mix_rotate(), 1.);
+        let wave = skeleton_data.find_animation("wave").unwrap();
+        assert_eq!(wave.timelines_count(), 5);
+    }
+
+    #[test]
+    fn unsupported() {
+        let json = SKELETON_38
+            .replace(r#""type": "mesh""#, r#""type": "unknown""#)
+            .replace(r#""rotate": ["#, r#""bend": ["#);
+        let Err(SpineError::UnsupportedLegacyData {
+            version,
+            unsupported,
+        }) = upgrade_json(json.as_bytes())
+        else {
+            panic!("expected unsupported legacy data");
+        };
+        assert_eq!(version, "3.8.99");
+        assert_eq!(
+            unsupported,
+            [
+                "skins.default.arm.arm: unknown attachment type `unknown`",
+                "animations.wave.bones.arm.bend: unknown bone timeline",
+            ]
+        );
+
+        let json = SKELETON_38.replace("3.8.99", "3.7.94");
+        assert!(matches!(
+            upgrade_json(json.as_bytes()),
+            Err(SpineError::UnsupportedLegacyData { .. })
+        ));
+        assert!(matches!(
+            upgrade_json(b"{"),
+            Err(SpineError::ParsingFailed { .. })
+        ));
+    }
+}
@@ -0,0 +1,204 @@
+use crate::prelude::*;
+use crate::Bounds;
+
+use super::{SkeletonCombinedRenderable, SkeletonController, SkeletonRenderable};
+
+/// Tracks whether anything which affects the controller's renderables changed between updates.
+#[derive(Debug, Default)]
+pub(super) struct ChangeTracker {
+    /// Incremented whenever a change is detected, see [`SkeletonController::change_stamp`].
+    stamp: u64,
+    /// Whether the last update changed anything, see [`SkeletonController::changed`].
+    changed: bool,
+    /// Everything which affects the renderables as of the last update, compared bit for bit.
+    signature: Vec<u64>,
+    /// The stamp as of the last call to [`SkeletonController::renderables_if_changed`].
+    renderables_stamp: Option<u64>,
+    /// The stamp as of the last call to
+    /// [`SkeletonController::combined_renderables_if_changed`].
+    combined_renderables_stamp: Option<u64>,
+    /// The bounds as of the stamp they were computed at, see [`SkeletonController::bounds`].
+    bounds: Option<(u64, Option<Bounds>)>,
+}
+
+impl SkeletonController {
+    /// A number which is incremented every time the controller's renderables may have changed,
+    /// so renderers can tell whether cached vertex buffers are still valid.
+    ///
+    /// ```
+    /// # #[path="../test.rs"]
+    /// # mod test;
+    /// # use rusty_spine::{controller::SkeletonController, Physics};
+    /// # let (skeleton_data, animation_state_data) = test::TestAsset::spineboy().instance_data(true);
+    /// let mut controller = SkeletonController::new(skeleton_data, animation_state_data);
+    /// let mut cached = None;
+    /// for _ in 0..10 {
+    ///     controller.update(0.016, Physics::Update);
+    ///     let stamp = controller.change_stamp();
+    ///     if cached.as_ref().is_none_or(|(cached_stamp, _)| *cached_stamp != stamp) {
+    ///         cached = Some((stamp, controller.combined_renderables()));
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// Changes are detected by [`update`](`Self::update`) and
+    /// [`update_to_time`](`Self::update_to_time`), which compare the world transforms of every
+    /// bone, the color, attachment, and deform of every slot, and the draw order against the
+    /// previous update. After modifying the skeleton, settings, or render rules directly, call
+    /// [`mark_changed`](`Self::mark_changed`).
+    #[must_use]
+    pub const fn change_stamp(&self) -> u64 {
+        self.changes.stamp
+    }
+
+    /// Returns `true` if the last update changed the skeleton's renderables.
+    #[must_use]
+    pub const fn changed(&self) -> bool {
+        self.changes.changed
+    }
+
+    /// Marks the renderables as changed, for changes made outside of
+    /// [`update`](`Self::update`).
+    pub fn mark_changed(&mut self) {
+        self.changes.stamp += 1;
+        self.changes.changed = true;
+        self.changes.signature.clear();
+    }
+
+    /// The same as [`renderables`](`Self::renderables`), but returns [`None`] if nothing changed
+    /// since the last call to this function, so the previous renderables can be reused.
+    pub fn renderables_if_changed(&mut self) -> Option<Vec<SkeletonRenderable>> {
+        let stamp = Some(self.changes.stamp);
+        if self.changes.renderables_stamp == stamp {
+            return None;
+        }
+        self.changes.renderables_stamp = stamp;
+        Some(self.renderables())
+    }
+
+    /// The same as [`combined_renderables`](`Self::combined_renderables`), but returns [`None`]
+    /// if nothing changed since the last call to this function, so the previous renderables can
+    /// be reused.
+    pub fn combined_renderables_if_changed(&mut self) -> Option<Vec<SkeletonCombinedRenderable>> {
+        let stamp = Some(self.changes.stamp);
+        if self.changes.combined_renderables_stamp == stamp {
+            return None;
+        }
+        self.changes.combined_renderables_stamp = stamp;
+        Some(self.combined_renderables())
+    }
+
+    /// The bounding box of the skeleton's attachments in world space, see
+    /// [`Skeleton::bounds`](`crate::Skeleton::bounds`). The bounds are cached until the
+    /// [`change_stamp`](`Self::change_stamp`) changes, so calling this many times per frame, such
+    /// as for culling against several cameras, only computes them once.
+    pub fn bounds(&mut self) -> Option<Bounds> {
+        match self.changes.bounds {
+            Some((stamp, bounds)) if stamp == self.changes.stamp => bounds,
+            _ => {
+                let bounds = self.skeleton.bounds();
+                self.changes.bounds = Some((self.changes.stamp, bounds));
+                bounds
+            }
+        }
+    }
+
+    /// Compares the skeleton against the previous update and increments the change stamp if
+    /// anything which affects the renderables is different.
+    pub(super) fn detect_changes(&mut self) {
+        let mut signature = Vec::with_capacity(self.changes.signature.len());
+        let skeleton = &self.skeleton;
+        let color = skeleton.color();
+        signature.extend([color.r, color.g, color.b, color.a].map(|v| u64::from(v.to_bits())));
+        for bone in skeleton.bones() {
+            signature.extend(
+                [
+                    bone.a(),
+                    bone.b(),
+                    bone.c(),
+                    bone.d(),
+                    bone.world_x(),
+                    bone.world_y(),
+                ]
+                .map(|v| u64::from(v.to_bits())),
+            );
+        }
+        for slot in skeleton.draw_order() {
+            let color = slot.color();
+            let dark_color = slot.dark_color().unwrap_or_default();
+            signature.push(slot.data().index() as u64);
+            signature.push(unsafe { slot.c_ptr_ref().attachment } as usize as u64);
+            signature.push(slot.sequence_index() as u64);
+            signature.extend(
+                [
+                    color.r,
+                    color.g,
+                    color.b,
+                    color.a,
+                    dark_color.r,
+                    dark_color.g,
+                    dark_color.b,
+                ]
+                .map(|v| u64::from(v.to_bits())),
+            );
+            signature.push(slot.deform_count() as u64);
+            signature.extend(slot.deform_slice().iter().map(|v| u64::from(v.to_bits())));
+        }
+        self.changes.changed = signature != self.changes.signature;
+        if self.changes.changed {
+            self.changes.stamp += 1;
+            self.changes.signature = signature;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{controller::SkeletonController, test::TestAsset, Physics};
+
+    #[test]
+    fn renderables_if_changed() {
+        let (skeleton_data, animation_state_data) = TestAsset::spineboy().instance_data(true);
+        let mut controller = SkeletonController::new(skeleton_data, animation_state_data);
+        controller.update(0.1, Physics::Update);
+        assert!(controller.changed());
+        assert!(controller.combined_renderables_if_changed().is_some());
+        assert!(controller.combined_renderables_if_changed().is_none());
+        assert!(controller.renderables_if_changed().is_some());
+
+        let stamp = controller.change_stamp();
+        controller.update(0.1, Physics::Update);
+        assert!(!controller.changed());
+        assert_eq!(controller.change_stamp(), stamp);
+        assert!(controller.combined_renderables_if_changed().is_none());
+
+        let _ = controller
+            .animation_state
+            .set_animation_by_name(0, "run", true);
+        controller.update(0.1, Physics::Update);
+        assert!(controller.changed());
+        assert_eq!(controller.change_stamp(), stamp + 1);
+        assert!(controller.combined_renderables_if_changed().is_some());
+
+        controller.mark_changed();
+        assert!(controller.renderables_if_changed().is_some());
+    }
+
+    #[test]
+    fn bounds() {
+        let (skeleton_data, animation_state_data) = TestAsset::spineboy().instance_data(true);
+        let mut controller = SkeletonController::new(skeleton_data, animation_state_data);
+        controller.update(0.1, Physics::Update);
+        let bounds = controller.bounds();
+        assert!(bounds.is_some());
+        assert_eq!(bounds, controller.skeleton.bounds());
+
+        // Cached until the skeleton changes
+        controller.skeleton.set_x(100.);
+        controller.skeleton.update_world_transform(Physics::None);
+        assert_eq!(controller.bounds(), bounds);
+        controller.mark_changed();
+        assert_ne!(controller.bounds(), bounds);
+        assert_eq!(controller.bounds(), controller.skeleton.bounds());
+    }
+}
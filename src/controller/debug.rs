@@ -0,0 +1,176 @@
+use crate::prelude::*;
+use crate::{color::Color, error::SpineError};
+
+use super::SkeletonController;
+
+/// A color and optional label attached to a bone with [`SkeletonController::tag_bone`], used to
+/// highlight the bone in [`SkeletonController::debug_bones`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct BoneTag {
+    pub color: Color,
+    pub label: Option<String>,
+}
+
+impl BoneTag {
+    #[must_use]
+    pub const fn new(color: Color) -> Self {
+        Self { color, label: None }
+    }
+
+    #[must_use]
+    pub fn with_label(self, label: &str) -> Self {
+        Self {
+            label: Some(label.to_owned()),
+            ..self
+        }
+    }
+}
+
+/// A line from a bone's origin to its tip in world space, for drawing skeleton overlays. See
+/// [`SkeletonController::debug_bones`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct DebugBone {
+    /// The index of the bone in the skeleton.
+    pub bone_index: usize,
+    /// The world position of the bone's origin.
+    pub start: [f32; 2],
+    /// The world position of the bone's tip, which is the same as `start` for bones with a length
+    /// of 0.
+    pub end: [f32; 2],
+    /// The color of the bone's tag, or [`DebugBone::UNTAGGED_COLOR`] if it is not tagged.
+    pub color: Color,
+    /// The label of the bone's tag.
+    pub label: Option<String>,
+    /// Whether the bone was tagged with [`SkeletonController::tag_bone`].
+    pub tagged: bool,
+}
+
+impl DebugBone {
+    /// The color of bones without a tag.
+    pub const UNTAGGED_COLOR: Color = Color::new_rgba(1., 0., 0., 1.);
+}
+
+impl SkeletonController {
+    /// Tag a bone with a color and label for [`debug_bones`](`Self::debug_bones`), replacing any
+    /// previous tag, so tools can highlight bones during development.
+    ///
+    /// ```
+    /// # #[path="../test.rs"]
+    /// # mod test;
+    /// # use rusty_spine::{controller::{BoneTag, SkeletonController}, Color, Physics};
+    /// # let (skeleton_data, animation_state_data) = test::TestAsset::spineboy().instance_data(true);
+    /// let mut controller = SkeletonController::new(skeleton_data, animation_state_data);
+    /// controller.tag_bone(
+    ///     "rear-upper-arm",
+    ///     BoneTag::new(Color::new_rgba(0., 1., 0., 1.)).with_label("IK"),
+    /// )?;
+    /// controller.update(0.016, Physics::Update);
+    /// for debug_bone in controller.debug_bones() {
+    ///     // draw a line from `debug_bone.start` to `debug_bone.end` in `debug_bone.color`
+    /// }
+    /// # Ok::<(), rusty_spine::SpineError>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SpineError::NotFound`] if the bone does not exist.
+    pub fn tag_bone(&mut self, bone_name: &str, tag: BoneTag) -> Result<(), SpineError> {
+        let bone_index = self.bone_index(bone_name)?;
+        self.bone_tags.insert(bone_index, tag);
+        Ok(())
+    }
+
+    /// Remove a bone's tag, returning it if there was one.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SpineError::NotFound`] if the bone does not exist.
+    pub fn untag_bone(&mut self, bone_name: &str) -> Result<Option<BoneTag>, SpineError> {
+        let bone_index = self.bone_index(bone_name)?;
+        Ok(self.bone_tags.remove(&bone_index))
+    }
+
+    /// Remove the tags of every bone.
+    pub fn clear_bone_tags(&mut self) {
+        self.bone_tags.clear();
+    }
+
+    /// The tag set for a bone with [`tag_bone`](`Self::tag_bone`).
+    #[must_use]
+    pub fn bone_tag(&self, bone_name: &str) -> Option<&BoneTag> {
+        self.bone_index(bone_name)
+            .ok()
+            .and_then(|bone_index| self.bone_tags.get(&bone_index))
+    }
+
+    /// A line for each active bone in the skeleton's current pose, colored and labeled by the
+    /// bone's tag. Bones are returned in the same order as
+    /// [`Skeleton::bones`](`crate::Skeleton::bones`).
+    #[must_use]
+    pub fn debug_bones(&self) -> Vec<DebugBone> {
+        self.skeleton
+            .bones()
+            .enumerate()
+            .filter(|(_, bone)| bone.active())
+            .map(|(bone_index, bone)| {
+                let tag = self.bone_tags.get(&bone_index);
+                let (end_x, end_y) = bone.local_to_world(bone.data().length(), 0.);
+                DebugBone {
+                    bone_index,
+                    start: [bone.world_x(), bone.world_y()],
+                    end: [end_x, end_y],
+                    color: tag.map_or(DebugBone::UNTAGGED_COLOR, |tag| tag.color),
+                    label: tag.and_then(|tag| tag.label.clone()),
+                    tagged: tag.is_some(),
+                }
+            })
+            .collect()
+    }
+
+    pub(super) fn bone_index(&self, bone_name: &str) -> Result<usize, SpineError> {
+        self.skeleton
+            .find_bone(bone_name)
+            .map(|bone| bone.data().index())
+            .ok_or_else(|| SpineError::new_not_found("Bone", bone_name))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        controller::{BoneTag, DebugBone, SkeletonController},
+        test::TestAsset,
+        Color, Physics,
+    };
+
+    #[test]
+    fn debug_bones() {
+        let (skeleton_data, animation_state_data) = TestAsset::spineboy().instance_data(true);
+        let mut controller = SkeletonController::new(skeleton_data, animation_state_data);
+        let green = Color::new_rgba(0., 1., 0., 1.);
+        controller
+            .tag_bone("head", BoneTag::new(green).with_label("look-at"))
+            .unwrap();
+        assert!(controller.tag_bone("missing", BoneTag::new(green)).is_err());
+        controller.update(0.016, Physics::Update);
+
+        let head_index = controller
+            .skeleton
+            .find_bone("head")
+            .unwrap()
+            .data()
+            .index();
+        let debug_bones = controller.debug_bones();
+        assert_eq!(debug_bones.len(), controller.skeleton.bones_count());
+        let head = &debug_bones[head_index];
+        assert!(head.tagged);
+        assert_eq!(head.color, green);
+        assert_eq!(head.label.as_deref(), Some("look-at"));
+        assert_ne!(head.start, head.end);
+        assert_eq!(debug_bones[0].color, DebugBone::UNTAGGED_COLOR);
+
+        assert!(controller.untag_bone("head").unwrap().is_some());
+        assert!(controller.bone_tag("head").is_none());
+        assert!(!controller.debug_bones()[head_index].tagged);
+    }
+}
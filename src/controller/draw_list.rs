@@ -0,0 +1,161 @@
+use crate::prelude::*;
+use crate::{
+    atlas::AtlasPage, c::spAtlasPage, c_interface::NewFromPtr, draw::BlendFactors, BlendMode,
+};
+
+use super::{SkeletonController, SkeletonRenderable};
+
+/// A batch of triangles drawn with a single texture and blend state, returned by
+/// [`SkeletonController::draw_list`].
+#[derive(Debug, Clone)]
+pub struct DrawCall<T> {
+    /// The texture to draw with, as returned by the `resolve_texture` closure.
+    pub texture: T,
+    pub vertices: Vec<[f32; 2]>,
+    pub uvs: Vec<[f32; 2]>,
+    /// The color of each vertex.
+    pub colors: Vec<[f32; 4]>,
+    /// The dark color of each vertex for two color tinting, see
+    /// [`SkeletonController::uses_two_color_tint`].
+    pub dark_colors: Vec<[f32; 4]>,
+    pub indices: Vec<u16>,
+    pub blend_mode: BlendMode,
+    pub premultiplied_alpha: bool,
+    pub tag: Option<String>,
+}
+
+impl<T> DrawCall<T> {
+    /// The blend state to draw with, from `blend_mode` and `premultiplied_alpha`. See
+    /// [`BlendMode::blend_factors`].
+    #[must_use]
+    pub const fn blend_factors(&self) -> BlendFactors {
+        self.blend_mode.blend_factors(self.premultiplied_alpha)
+    }
+
+    fn extend(&mut self, renderable: SkeletonRenderable) {
+        let vertices_count = self.vertices.len() + renderable.vertices.len();
+        let index_offset = self.vertices.len() as u16;
+        self.indices
+            .extend(renderable.indices.iter().map(|index| index + index_offset));
+        self.vertices.extend(renderable.vertices);
+        self.uvs.extend(renderable.uvs);
+        let color = renderable.color;
+        let dark_color = renderable.dark_color;
+        self.colors
+            .resize(vertices_count, [color.r, color.g, color.b, color.a]);
+        self.dark_colors.resize(
+            vertices_count,
+            [dark_color.r, dark_color.g, dark_color.b, dark_color.a],
+        );
+    }
+}
+
+impl SkeletonController {
+    /// Render the skeleton into draw calls ready to be submitted in order, with the texture of
+    /// each resolved from its atlas page by `resolve_texture`.
+    ///
+    /// This is the simplest way to render a skeleton. Each draw call only needs its texture and
+    /// blend state bound, without handling renderer objects or atlas pages directly. Pairs well
+    /// with textures stored by [`AtlasPage::set_texture`].
+    ///
+    /// ```
+    /// # #[path="../test.rs"]
+    /// # mod test;
+    /// # use rusty_spine::controller::SkeletonController;
+    /// # let (skeleton_data, animation_state_data) = test::TestAsset::spineboy().instance_data(true);
+    /// let mut controller = SkeletonController::new(skeleton_data, animation_state_data);
+    /// for draw_call in controller.draw_list(|page| page.name().to_owned()) {
+    ///     // bind the texture named `draw_call.texture` and the blend state
+    ///     // `draw_call.blend_factors()`, then draw `draw_call.indices`
+    /// }
+    /// ```
+    ///
+    /// Consecutive slots are combined into one draw call while they share an atlas page, blend
+    /// mode, premultiplied alpha, and tag, and their vertices fit in `u16` indices.
+    /// `resolve_texture` is called once per draw call. Slots drawn without an atlas page are
+    /// skipped.
+    pub fn draw_list<T>(&mut self, resolve_texture: impl Fn(&AtlasPage) -> T) -> Vec<DrawCall<T>> {
+        let mut draw_list: Vec<DrawCall<T>> = vec![];
+        let mut current_page: *mut spAtlasPage = core::ptr::null_mut();
+        for renderable in self.renderables() {
+            let Some(page) = self
+                .skeleton
+                .draw_order_at_index(renderable.slot_index)
+                .and_then(|slot| Some(slot.attachment()?.texture_region()?.page.c_ptr()))
+            else {
+                continue;
+            };
+            let can_combine = |draw_call: &&mut DrawCall<T>| {
+                page == current_page
+                    && draw_call.blend_mode == renderable.blend_mode
+                    && draw_call.premultiplied_alpha == renderable.premultiplied_alpha
+                    && draw_call.tag == renderable.tag
+                    && draw_call.vertices.len() + renderable.vertices.len()
+                        <= usize::from(u16::MAX) + 1
+            };
+            if let Some(draw_call) = draw_list.last_mut().filter(can_combine) {
+                draw_call.extend(renderable);
+            } else {
+                current_page = page;
+                let mut draw_call = DrawCall {
+                    texture: resolve_texture(&unsafe { AtlasPage::new_from_ptr(page) }),
+                    vertices: vec![],
+                    uvs: vec![],
+                    colors: vec![],
+                    dark_colors: vec![],
+                    indices: vec![],
+                    blend_mode: renderable.blend_mode,
+                    premultiplied_alpha: renderable.premultiplied_alpha,
+                    tag: renderable.tag.clone(),
+                };
+                draw_call.extend(renderable);
+                draw_list.push(draw_call);
+            }
+        }
+        draw_list
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{controller::SkeletonController, test::TestAsset, BlendMode, Physics};
+
+    #[test]
+    fn draw_list() {
+        let (skeleton_data, animation_state_data) = TestAsset::all()[3].instance_data(true);
+        let mut controller = SkeletonController::new(skeleton_data, animation_state_data);
+        controller
+            .animation_state
+            .set_animation_by_name(0, "flying", true)
+            .unwrap();
+        controller.update(0.016, Physics::Update);
+        let renderables = controller.renderables();
+        let draw_list = controller.draw_list(|page| page.name().to_owned());
+        assert!(!draw_list.is_empty());
+        assert!(draw_list.len() <= renderables.len());
+        assert_eq!(
+            draw_list
+                .iter()
+                .map(|draw_call| draw_call.vertices.len())
+                .sum::<usize>(),
+            renderables
+                .iter()
+                .map(|renderable| renderable.vertices.len())
+                .sum::<usize>()
+        );
+        for draw_call in &draw_list {
+            assert!(draw_call.texture.starts_with("dragon"));
+            assert_eq!(draw_call.vertices.len(), draw_call.uvs.len());
+            assert_eq!(draw_call.vertices.len(), draw_call.colors.len());
+            assert_eq!(draw_call.vertices.len(), draw_call.dark_colors.len());
+            assert!(draw_call
+                .indices
+                .iter()
+                .all(|index| usize::from(*index) < draw_call.vertices.len()));
+            assert_eq!(draw_call.blend_mode, BlendMode::Normal);
+        }
+        for pair in draw_list.windows(2) {
+            assert_ne!(pair[0].texture, pair[1].texture);
+        }
+    }
+}
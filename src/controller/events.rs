@@ -0,0 +1,137 @@
+use crate::OwnedAnimationEvent;
+
+use super::SkeletonController;
+
+/// An event delivered during an update of a [`SkeletonController`], see
+/// [`SkeletonController::drain_events`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct ControllerEvent {
+    /// The update the event was delivered in, see [`SkeletonController::update_step`].
+    pub step: u64,
+    /// The controller's time at the end of the update, see [`SkeletonController::time`].
+    pub time: f32,
+    /// The index of the track the event originated from.
+    pub track_index: usize,
+    pub event: OwnedAnimationEvent,
+}
+
+impl SkeletonController {
+    /// If `true`, events delivered by the animation state are queued with the update they came
+    /// from, so they can be handled after several updates in one frame with
+    /// [`drain_events`](`Self::drain_events`). Disabling the queue discards any events which have
+    /// not been drained. Defaults to `false`.
+    ///
+    /// ```
+    /// # #[path="../test.rs"]
+    /// # mod test;
+    /// # use rusty_spine::{controller::SkeletonController, OwnedAnimationEvent};
+    /// # let (skeleton_data, animation_state_data) = test::TestAsset::spineboy().instance_data(true);
+    /// let mut controller = SkeletonController::new(skeleton_data, animation_state_data);
+    /// controller.set_event_queue(true);
+    /// controller.animation_state.set_animation_by_name(0, "run", true)?;
+    /// controller.update_fixed(0.1, 1. / 60.);
+    /// for controller_event in controller.drain_events() {
+    ///     if let OwnedAnimationEvent::Event { name, .. } = &controller_event.event {
+    ///         println!("{name} in step {}", controller_event.step);
+    ///     }
+    /// }
+    /// # Ok::<(), rusty_spine::SpineError>(())
+    /// ```
+    ///
+    /// The queue uses the animation state's
+    /// [event buffering](`crate::AnimationState::set_event_buffering`), which is enabled along
+    /// with it. Events delivered between updates, such as when setting an animation, are queued
+    /// with the next update.
+    pub fn set_event_queue(&mut self, event_queue: bool) {
+        self.event_queue = event_queue;
+        self.animation_state.set_event_buffering(event_queue);
+        if !event_queue {
+            self.queued_events.clear();
+        }
+    }
+
+    /// If `true`, events are queued until drained, see
+    /// [`set_event_queue`](`Self::set_event_queue`).
+    #[must_use]
+    pub const fn event_queue(&self) -> bool {
+        self.event_queue
+    }
+
+    /// Remove and return all queued events, in the order they were delivered.
+    ///
+    /// See [`set_event_queue`](`Self::set_event_queue`).
+    pub fn drain_events(&mut self) -> impl Iterator<Item = ControllerEvent> + '_ {
+        self.queued_events.drain(..)
+    }
+
+    /// The number of updates so far, counting every call to [`update`](`Self::update`),
+    /// including each step of [`step_fixed`](`Self::step_fixed`), and every call to
+    /// [`update_to_time`](`Self::update_to_time`).
+    #[must_use]
+    pub const fn update_step(&self) -> u64 {
+        self.update_step
+    }
+
+    /// Moves the events buffered by the animation state into the queue, tagged with the current
+    /// update.
+    pub(super) fn queue_events(&mut self) {
+        if !self.event_queue {
+            return;
+        }
+        let (step, time) = (self.update_step, self.time);
+        let events = self
+            .animation_state
+            .drain_events()
+            .map(|event| ControllerEvent {
+                step,
+                time,
+                track_index: event.track_index(),
+                event,
+            });
+        self.queued_events.extend(events);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{controller::SkeletonController, test::TestAsset, OwnedAnimationEvent};
+
+    #[test]
+    fn drain_events() {
+        let (skeleton_data, animation_state_data) = TestAsset::spineboy().instance_data(true);
+        let mut controller = SkeletonController::new(skeleton_data, animation_state_data);
+        controller.set_event_queue(true);
+        controller
+            .animation_state
+            .set_animation_by_name(0, "run", true)
+            .unwrap();
+        assert_eq!(controller.update_fixed(1.05, 0.1), 10);
+        assert_eq!(controller.update_step(), 10);
+
+        let events: Vec<_> = controller.drain_events().collect();
+        assert!(matches!(
+            events[0].event,
+            OwnedAnimationEvent::Start { track_index: 0, .. }
+        ));
+        assert_eq!(events[0].step, 1);
+        let footsteps: Vec<_> = events
+            .iter()
+            .filter(|controller_event| {
+                matches!(&controller_event.event, OwnedAnimationEvent::Event { name, .. } if name == "footstep")
+            })
+            .collect();
+        assert!(footsteps.len() > 1);
+        for pair in footsteps.windows(2) {
+            assert!(pair[0].step < pair[1].step);
+        }
+        for controller_event in &footsteps {
+            assert_eq!(controller_event.track_index, 0);
+            assert!((controller_event.time - controller_event.step as f32 * 0.1).abs() < 0.001);
+        }
+        assert_eq!(controller.drain_events().count(), 0);
+
+        controller.set_event_queue(false);
+        controller.update(1., crate::Physics::Update);
+        assert_eq!(controller.drain_events().count(), 0);
+    }
+}
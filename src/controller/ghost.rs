@@ -0,0 +1,320 @@
+use alloc::collections::VecDeque;
+
+use crate::color::Color;
+use crate::prelude::*;
+
+use super::{SkeletonCombinedRenderable, SkeletonController};
+
+/// Settings for a [`GhostTrail`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct GhostTrailSettings {
+    /// The maximum amount of snapshots retained. Once exceeded, the oldest snapshot is discarded.
+    pub max_snapshots: usize,
+    /// The time (in seconds) between each snapshot.
+    pub interval: f32,
+    /// A tint multiplied into every ghost. The alpha of the newest ghost is `tint.a` and each
+    /// older ghost fades further towards fully transparent.
+    pub tint: Color,
+}
+
+impl Default for GhostTrailSettings {
+    fn default() -> Self {
+        Self {
+            max_snapshots: 5,
+            interval: 0.05,
+            tint: Color::new_rgba(1., 1., 1., 0.5),
+        }
+    }
+}
+
+impl GhostTrailSettings {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[must_use]
+    pub const fn with_max_snapshots(self, max_snapshots: usize) -> Self {
+        Self {
+            max_snapshots,
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub const fn with_interval(self, interval: f32) -> Self {
+        Self { interval, ..self }
+    }
+
+    #[must_use]
+    pub const fn with_tint(self, tint: Color) -> Self {
+        Self { tint, ..self }
+    }
+}
+
+/// Retains previous poses of a [`SkeletonController`] to draw "ghost" or onion-skin motion
+/// trails.
+///
+/// ```
+/// # #[path="../test.rs"]
+/// # mod test;
+/// # use rusty_spine::{controller::{GhostTrail, GhostTrailSettings, SkeletonController}, Physics};
+/// # let (skeleton_data, animation_state_data) = test::TestAsset::spineboy().instance_data(true);
+/// let mut controller = SkeletonController::new(skeleton_data, animation_state_data);
+/// let mut ghost_trail = GhostTrail::new(GhostTrailSettings::new().with_max_snapshots(4));
+/// for _ in 0..10 {
+///     controller.update(0.016, Physics::Update);
+///     ghost_trail.update(0.016, &mut controller);
+///     // draw the ghosts first so they appear behind the skeleton
+///     let ghost_renderables = ghost_trail.renderables();
+///     let renderables = controller.combined_renderables();
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct GhostTrail {
+    pub settings: GhostTrailSettings,
+    snapshots: VecDeque<Vec<SkeletonCombinedRenderable>>,
+    time_since_snapshot: f32,
+}
+
+impl GhostTrail {
+    #[must_use]
+    pub const fn new(settings: GhostTrailSettings) -> Self {
+        Self {
+            settings,
+            snapshots: VecDeque::new(),
+            time_since_snapshot: 0.,
+        }
+    }
+
+    /// Advances the trail by `delta_seconds`, capturing a snapshot of the controller's current
+    /// pose whenever [`GhostTrailSettings::interval`] has elapsed since the last one. At most one
+    /// snapshot is captured per update, and intervals skipped by a long update are dropped.
+    pub fn update(&mut self, delta_seconds: f32, controller: &mut SkeletonController) {
+        self.time_since_snapshot += delta_seconds;
+        if self.snapshots.is_empty() || self.time_since_snapshot >= self.settings.interval {
+            // Keep the time past the interval so snapshots stay evenly spaced when the interval
+            // isn't a multiple of the update time
+            let interval = self.settings.interval;
+            let overshoot = (self.time_since_snapshot - interval).max(0.);
+            self.time_since_snapshot = if interval > 0. {
+                overshoot % interval
+            } else {
+                0.
+            };
+            self.capture(controller);
+        }
+    }
+
+    /// Immediately captures a snapshot of the controller's current pose.
+    pub fn capture(&mut self, controller: &mut SkeletonController) {
+        if self.settings.max_snapshots == 0 {
+            return;
+        }
+        while self.snapshots.len() >= self.settings.max_snapshots {
+            self.snapshots.pop_front();
+        }
+        self.snapshots.push_back(controller.combined_renderables());
+    }
+
+    /// Discards all retained snapshots.
+    pub fn clear(&mut self) {
+        self.snapshots.clear();
+        self.time_since_snapshot = 0.;
+    }
+
+    /// The number of retained snapshots.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.snapshots.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.snapshots.is_empty()
+    }
+
+    /// Renderables for every retained snapshot with the fading tint applied, ordered from the
+    /// oldest to the newest snapshot. They should be drawn in order, before the live skeleton.
+    #[must_use]
+    pub fn renderables(&self) -> Vec<SkeletonCombinedRenderable> {
+        let count = self.snapshots.len();
+        let tint = self.settings.tint;
+        let mut ghost_renderables = vec![];
+        for (age_index, snapshot) in self.snapshots.iter().enumerate() {
+            let fade = (age_index + 1) as f32 / count as f32;
+            let alpha = tint.a * fade;
+            for renderable in snapshot {
+                let mut renderable = renderable.clone();
+                let premultiplied_alpha = renderable.premultiplied_alpha;
+                let tint_color = |color: [f32; 4]| {
+                    let [r, g, b, a] = color;
+                    let rgb_alpha = if premultiplied_alpha { alpha } else { 1. };
+                    [
+                        r * tint.r * rgb_alpha,
+                        g * tint.g * rgb_alpha,
+                        b * tint.b * rgb_alpha,
+                        a * alpha,
+                    ]
+                };
+                let fade_dark_color = |dark_color: [f32; 4]| {
+                    let [r, g, b, a] = dark_color;
+                    if premultiplied_alpha {
+                        [r * alpha, g * alpha, b * alpha, a]
+                    } else {
+                        dark_color
+                    }
+                };
+                for color in &mut renderable.colors {
+                    *color = tint_color(*color);
+                }
+                for dark_color in &mut renderable.dark_colors {
+                    *dark_color = fade_dark_color(*dark_color);
+                }
+                for packed_color in &mut renderable.packed_colors {
+                    *packed_color = Color::from(tint_color(unpack(*packed_color))).to_rgba8();
+                }
+                for packed_dark_color in &mut renderable.packed_dark_colors {
+                    *packed_dark_color =
+                        Color::from(fade_dark_color(unpack(*packed_dark_color))).to_rgba8();
+                }
+                ghost_renderables.push(renderable);
+            }
+        }
+        ghost_renderables
+    }
+}
+
+/// Convert a color packed with [`Color::to_rgba8`] back to floats.
+fn unpack(packed_color: [u8; 4]) -> [f32; 4] {
+    packed_color.map(|channel| f32::from(channel) / 255.)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        controller::{
+            GhostTrail, GhostTrailSettings, SkeletonController, SkeletonControllerSettings,
+            VertexColorFormat,
+        },
+        test::TestAsset,
+        Color, Physics,
+    };
+
+    #[test]
+    fn ghost_trail() {
+        let (skeleton_data, animation_state_data) = TestAsset::spineboy().instance_data(true);
+        let mut controller = SkeletonController::new(skeleton_data, animation_state_data);
+        let _ = controller
+            .animation_state
+            .set_animation_by_name(0, "run", true);
+        let mut ghost_trail = GhostTrail::new(
+            GhostTrailSettings::new()
+                .with_max_snapshots(3)
+                .with_interval(0.1)
+                .with_tint(Color::new_rgba(1., 1., 1., 0.5)),
+        );
+        let mut poses = vec![];
+        for _ in 0..10 {
+            controller.update(0.1, Physics::Update);
+            ghost_trail.update(0.1, &mut controller);
+            poses.push(controller.combined_renderables());
+        }
+        assert_eq!(ghost_trail.len(), 3);
+
+        // ghosts are ordered from the oldest to the newest pose, each fading less than the last
+        let ghost_renderables = ghost_trail.renderables();
+        let expected_poses = &poses[poses.len() - 3..];
+        assert_eq!(
+            ghost_renderables.len(),
+            expected_poses.iter().map(Vec::len).sum::<usize>()
+        );
+        let mut ghost_renderables = ghost_renderables.iter();
+        for (age_index, pose) in expected_poses.iter().enumerate() {
+            let alpha = 0.5 * (age_index + 1) as f32 / 3.;
+            for renderable in pose {
+                let ghost = ghost_renderables.next().unwrap();
+                assert_eq!(ghost.vertices, renderable.vertices);
+                for (ghost_color, color) in ghost.colors.iter().zip(renderable.colors.iter()) {
+                    assert!((ghost_color[3] - color[3] * alpha).abs() < 0.0001);
+                }
+            }
+        }
+
+        ghost_trail.clear();
+        assert!(ghost_trail.is_empty());
+    }
+
+    #[test]
+    fn ghost_trail_packed_colors() {
+        for premultiplied_alpha in [false, true] {
+            let (skeleton_data, animation_state_data) = TestAsset::spineboy().instance_data(true);
+            let mut controller = SkeletonController::new(skeleton_data, animation_state_data)
+                .with_settings(
+                    SkeletonControllerSettings::new()
+                        .with_premultiplied_alpha(premultiplied_alpha)
+                        .with_vertex_color_format(VertexColorFormat::PackedRgba8),
+                );
+            controller.update(0.016, Physics::Update);
+            let mut ghost_trail = GhostTrail::new(
+                GhostTrailSettings::new()
+                    .with_max_snapshots(2)
+                    .with_tint(Color::new_rgba(0.5, 1., 1., 0.5)),
+            );
+            ghost_trail.capture(&mut controller);
+            ghost_trail.capture(&mut controller);
+            let renderables = controller.combined_renderables();
+            let ghost_renderables = ghost_trail.renderables();
+            assert_eq!(ghost_renderables.len(), renderables.len() * 2);
+
+            // the oldest ghost is half as opaque as the newest
+            for (age_index, ghosts) in ghost_renderables.chunks(renderables.len()).enumerate() {
+                let alpha = 0.5 * (age_index + 1) as f32 / 2.;
+                for (ghost, renderable) in ghosts.iter().zip(renderables.iter()) {
+                    assert!(!ghost.packed_colors.is_empty());
+                    assert_eq!(ghost.packed_colors.len(), renderable.packed_colors.len());
+                    for (ghost_color, color) in ghost
+                        .packed_colors
+                        .iter()
+                        .zip(renderable.packed_colors.iter())
+                    {
+                        let rgb_alpha = if premultiplied_alpha { alpha } else { 1. };
+                        let expected = [
+                            f32::from(color[0]) * 0.5 * rgb_alpha,
+                            f32::from(color[1]) * rgb_alpha,
+                            f32::from(color[2]) * rgb_alpha,
+                            f32::from(color[3]) * alpha,
+                        ];
+                        for (ghost_channel, expected_channel) in ghost_color.iter().zip(expected) {
+                            assert!((f32::from(*ghost_channel) - expected_channel).abs() <= 1.);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn ghost_trail_spacing() {
+        let (skeleton_data, animation_state_data) = TestAsset::spineboy().instance_data(true);
+        let mut controller = SkeletonController::new(skeleton_data, animation_state_data);
+        let mut ghost_trail = GhostTrail::new(
+            GhostTrailSettings::new()
+                .with_max_snapshots(100)
+                .with_interval(0.05),
+        );
+
+        // 0.03 doesn't divide 0.05, but a snapshot is still taken every 0.05 seconds on average
+        for _ in 0..34 {
+            ghost_trail.update(0.03, &mut controller);
+        }
+        assert!((20..=21).contains(&ghost_trail.len()));
+
+        // a long update doesn't queue up snapshots for the following updates
+        let len = ghost_trail.len();
+        ghost_trail.update(1., &mut controller);
+        assert_eq!(ghost_trail.len(), len + 1);
+        ghost_trail.update(0.001, &mut controller);
+        assert_eq!(ghost_trail.len(), len + 1);
+    }
+}
@@ -0,0 +1,194 @@
+use core::{cmp::Ordering, ops::Range};
+
+use crate::prelude::*;
+use crate::{c::c_void, slot::Slot, BlendMode};
+
+use super::{SkeletonController, SkeletonRenderable};
+
+/// A draw call in [`InterleavedRenderables`], for one slot of one skeleton.
+#[derive(Debug, Clone)]
+pub struct InterleavedCommand {
+    /// The index of the controller in the list passed to
+    /// [`SkeletonController::interleaved_renderables`].
+    pub skeleton_index: usize,
+    /// The slot this command was drawn from, see [`SkeletonRenderable::slot_index`].
+    pub slot_index: usize,
+    /// The range of [`InterleavedRenderables::indices`] to draw.
+    pub indices: Range<usize>,
+    pub blend_mode: BlendMode,
+    pub premultiplied_alpha: bool,
+    pub tag: Option<String>,
+    pub attachment_renderer_object: Option<*const c_void>,
+}
+
+/// The renderables of many skeletons in one set of buffers, see
+/// [`SkeletonController::interleaved_renderables`].
+#[derive(Debug, Clone, Default)]
+pub struct InterleavedRenderables {
+    /// Vertex positions, with each skeleton's transform applied.
+    pub vertices: Vec<[f32; 2]>,
+    pub uvs: Vec<[f32; 2]>,
+    pub colors: Vec<[f32; 4]>,
+    /// Vertex dark colors for two color tinting, see
+    /// [`SkeletonController::uses_two_color_tint`].
+    pub dark_colors: Vec<[f32; 4]>,
+    /// Triangle indices into `vertices`, in the same order as `commands`.
+    pub indices: Vec<u32>,
+    /// The draw calls, sorted by depth.
+    pub commands: Vec<InterleavedCommand>,
+}
+
+impl SkeletonController {
+    /// Render many skeletons into one set of buffers, with the parts of every skeleton sorted
+    /// together by a depth computed once per renderable from the index of its controller and its
+    /// slot. This allows overlapping characters to interleave, for instance so one character's
+    /// arms are drawn in front of another character they are holding, while its body is behind.
+    ///
+    /// Each controller is paired with a transform applied to its vertices, as
+    /// `[a, b, c, d, x, y]` in the same layout as a [`BoneMatrix`](`super::BoneMatrix`), so
+    /// skeletons can be placed in the world without a draw call each.
+    ///
+    /// ```
+    /// # #[path="../test.rs"]
+    /// # mod test;
+    /// # use rusty_spine::{controller::SkeletonController, Physics};
+    /// # let (skeleton_data, animation_state_data) = test::TestAsset::spineboy().instance_data(true);
+    /// let mut holder = SkeletonController::new(skeleton_data.clone(), animation_state_data.clone());
+    /// let mut held = SkeletonController::new(skeleton_data, animation_state_data);
+    /// holder.update(0.016, Physics::Update);
+    /// held.update(0.016, Physics::Update);
+    /// let renderables = SkeletonController::interleaved_renderables(
+    ///     [
+    ///         (&mut holder, [1., 0., 0., 1., 0., 0.]),
+    ///         (&mut held, [1., 0., 0., 1., 50., 0.]),
+    ///     ],
+    ///     |skeleton_index, slot| {
+    ///         // draw the holder's arms in front of the held skeleton
+    ///         let arm = slot.data().name().contains("arm");
+    ///         (skeleton_index == 0 && arm, slot.data().index())
+    ///     },
+    /// );
+    /// // upload the vertex buffers once
+    /// for command in &renderables.commands {
+    ///     // draw `renderables.indices[command.indices.clone()]`
+    /// }
+    /// ```
+    ///
+    /// Renderables are generated with [`renderables`](`Self::renderables`), using each
+    /// controller's settings and render rules, except that colors are always returned as floats.
+    /// The sort is stable, so renderables with equal depths keep the order of the controllers and
+    /// then the draw order. Depths which cannot be compared (such as `NaN`) are treated as equal.
+    ///
+    /// Consecutive commands with the same blend mode and attachment renderer object have
+    /// contiguous index ranges, so they can be drawn together.
+    pub fn interleaved_renderables<'a, I, K, F>(
+        controllers: I,
+        mut depth: F,
+    ) -> InterleavedRenderables
+    where
+        I: IntoIterator<Item = (&'a mut SkeletonController, [f32; 6])>,
+        K: PartialOrd,
+        F: FnMut(usize, &Slot) -> K,
+    {
+        let mut sorted_renderables: Vec<(K, usize, [f32; 6], SkeletonRenderable)> = vec![];
+        for (skeleton_index, (controller, transform)) in controllers.into_iter().enumerate() {
+            for renderable in controller.renderables() {
+                let Some(slot) = controller
+                    .skeleton
+                    .draw_order_at_index(renderable.slot_index)
+                else {
+                    continue;
+                };
+                let key = depth(skeleton_index, &slot);
+                sorted_renderables.push((key, skeleton_index, transform, renderable));
+            }
+        }
+        sorted_renderables.sort_by(|(a, ..), (b, ..)| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+
+        let mut interleaved = InterleavedRenderables::default();
+        for (_, skeleton_index, [a, b, c, d, x, y], renderable) in sorted_renderables {
+            let first_vertex = interleaved.vertices.len() as u32;
+            let first_index = interleaved.indices.len();
+            interleaved.vertices.extend(
+                renderable
+                    .vertices
+                    .iter()
+                    .map(|[vx, vy]| [a * vx + b * vy + x, c * vx + d * vy + y]),
+            );
+            interleaved.uvs.extend(renderable.uvs);
+            let color = renderable.color;
+            let dark_color = renderable.dark_color;
+            let vertices_end = interleaved.vertices.len();
+            interleaved
+                .colors
+                .resize(vertices_end, [color.r, color.g, color.b, color.a]);
+            interleaved.dark_colors.resize(
+                vertices_end,
+                [dark_color.r, dark_color.g, dark_color.b, dark_color.a],
+            );
+            interleaved.indices.extend(
+                renderable
+                    .indices
+                    .iter()
+                    .map(|index| first_vertex + u32::from(*index)),
+            );
+            interleaved.commands.push(InterleavedCommand {
+                skeleton_index,
+                slot_index: renderable.slot_index,
+                indices: first_index..interleaved.indices.len(),
+                blend_mode: renderable.blend_mode,
+                premultiplied_alpha: renderable.premultiplied_alpha,
+                tag: renderable.tag,
+                attachment_renderer_object: renderable.attachment_renderer_object,
+            });
+        }
+        interleaved
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{controller::SkeletonController, test::TestAsset, Physics};
+
+    #[test]
+    fn interleaved_renderables() {
+        let (skeleton_data, animation_state_data) = TestAsset::spineboy().instance_data(true);
+        let mut first =
+            SkeletonController::new(skeleton_data.clone(), animation_state_data.clone());
+        let mut second = SkeletonController::new(skeleton_data, animation_state_data);
+        first.update(0.016, Physics::Update);
+        second.update(0.016, Physics::Update);
+        let renderables = first.renderables();
+
+        let interleaved = SkeletonController::interleaved_renderables(
+            [
+                (&mut first, [1., 0., 0., 1., 0., 0.]),
+                (&mut second, [2., 0., 0., 2., 100., 0.]),
+            ],
+            |skeleton_index, slot| slot.data().index() * 2 + skeleton_index,
+        );
+        assert_eq!(interleaved.commands.len(), renderables.len() * 2);
+        assert_eq!(interleaved.vertices.len(), interleaved.uvs.len());
+        assert_eq!(interleaved.vertices.len(), interleaved.colors.len());
+        assert_eq!(interleaved.vertices.len(), interleaved.dark_colors.len());
+        assert_eq!(
+            interleaved.commands.last().unwrap().indices.end,
+            interleaved.indices.len()
+        );
+        assert!(interleaved
+            .indices
+            .iter()
+            .all(|index| (*index as usize) < interleaved.vertices.len()));
+        for (pair, renderable) in interleaved.commands.chunks(2).zip(&renderables) {
+            assert_eq!((pair[0].skeleton_index, pair[1].skeleton_index), (0, 1));
+            assert_eq!(pair[0].slot_index, renderable.slot_index);
+            assert_eq!(pair[1].slot_index, renderable.slot_index);
+            let first_vertex =
+                interleaved.vertices[interleaved.indices[pair[0].indices.start] as usize];
+            let second_vertex =
+                interleaved.vertices[interleaved.indices[pair[1].indices.start] as usize];
+            assert!((second_vertex[0] - (first_vertex[0] * 2. + 100.)).abs() < 0.001);
+            assert!((second_vertex[1] - first_vertex[1] * 2.).abs() < 0.001);
+        }
+    }
+}
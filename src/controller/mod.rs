@@ -0,0 +1,1131 @@
+//! Provides [`SkeletonController`], a helper struct for updating and drawing Spine skeletons.
+//!
+//! ```
+//! use std::sync::Arc;
+//!
+//! use rusty_spine::{controller::*, *};
+//!
+//! // Load and initialize the atlas and skeleton
+//! let atlas_path = "assets/spineboy/export/spineboy.atlas";
+//! let json_path = "assets/spineboy/export/spineboy-pro.json";
+//! let atlas = Arc::new(Atlas::new_from_file(atlas_path).unwrap());
+//! let skeleton_json = SkeletonJson::new(atlas);
+//! let skeleton_data = Arc::new(skeleton_json.read_skeleton_data_file(json_path).unwrap());
+//! let animation_state_data = Arc::new(AnimationStateData::new(skeleton_data.clone()));
+//!
+//! // Create the controller
+//! let mut skeleton_controller =
+//!     SkeletonController::new(skeleton_data.clone(), animation_state_data);
+//!
+//! // Update for one frame
+//! skeleton_controller.update(0.016, Physics::Update);
+//!
+//! // Get renderable mesh data
+//! // Note: if slot_index is not important, use the much faster
+//! // `combined_renderables()` method instead
+//! let renderables = skeleton_controller.renderables();
+//! println!("Skeleton:");
+//! println!("");
+//! println!("  Atlas: {}", atlas_path);
+//! println!("  JSON: {}", json_path);
+//! println!("  Version: {}", skeleton_data.version().unwrap_or("??"));
+//! println!("  Hash: {}", skeleton_data.hash());
+//! println!("");
+//! println!("Renderables:");
+//! println!("");
+//! for renderable in renderables.iter() {
+//!     let slot = skeleton_controller
+//!         .skeleton
+//!         .slot_at_index(renderable.slot_index)
+//!         .unwrap();
+//!     println!("  {}", slot.data().name());
+//!     println!("    {} Vertices / UVs", renderable.vertices.len());
+//!     println!("    {} Indices", renderable.indices.len());
+//!     println!("    {:?} Blend Mode", renderable.blend_mode);
+//!     println!("    {:?}", renderable.color);
+//!     println!(
+//!         "    {}",
+//!         if renderable.premultiplied_alpha {
+//!             "Premultiplied Alpha"
+//!         } else {
+//!             "No Premultiplied Alpha"
+//!         }
+//!     );
+//!     println!("");
+//! }
+//! ```
+//!
+//! To draw motion trails of previous poses, see [`GhostTrail`].
+//!
+//! To skin meshes on the GPU, see [`SkeletonController::skinned_mesh_data`].
+//!
+//! To interleave skeleton parts with other sprites, see
+//! [`SkeletonController::sorted_renderables`].
+//!
+//! To interleave overlapping skeletons with each other in one pass, see
+//! [`SkeletonController::interleaved_renderables`].
+//!
+//! To keep crowds of skeletons from animating in sync, see [`SkeletonController::stagger`]. To
+//! dress them in random outfits, see [`SkeletonController::dress_randomly`].
+//!
+//! To drive animations from an external clock, such as an audio track, see
+//! [`SkeletonController::update_to_time`].
+//!
+//! To update deterministically with a fixed time step, see [`SkeletonController::step_fixed`].
+//! To handle the events of several updates in one frame, see
+//! [`SkeletonController::set_event_queue`].
+//!
+//! To store the parts of a controller separately, such as in an ECS, see
+//! [`SkeletonController::into_parts`].
+//!
+//! To spread the updates of many background skeletons across frames, see [`UpdateScheduler`].
+//! To update them across threads, enable the `parallel` feature and see
+//! `SkeletonController::update_all_parallel`.
+//!
+//! To update crowds of one skeleton and render them with instancing, see [`SkeletonPool`].
+//!
+//! To find broken attachments in an export, see [`SkeletonController::warnings`].
+//!
+//! To reuse vertex buffers while a skeleton is idle, see [`SkeletonController::change_stamp`].
+//!
+//! To draw bone overlays with highlighted bones, see [`SkeletonController::debug_bones`]. To
+//! measure the cost of updating and drawing, enable the `profile` feature and see
+//! `SkeletonController::take_frame_stats`.
+//!
+//! To override bones procedurally on top of animations, see
+//! [`SkeletonController::set_bone_override`].
+//!
+//! Renderables are returned in draw order, and their order and grouping only change when the
+//! draw order or the slots' attachments do. To cache GPU state per renderable, key it on
+//! [`SkeletonRenderable::slot_index`] or [`SkeletonCombinedRenderable::first_slot_index`], which
+//! are unique within a frame and the same across frames for the same renderable.
+
+mod changes;
+mod debug;
+mod draw_list;
+mod events;
+mod ghost;
+mod interleave;
+mod overrides;
+mod pages;
+#[cfg(feature = "parallel")]
+mod parallel;
+mod parts;
+mod pool;
+#[cfg(feature = "profile")]
+mod profile;
+mod scheduler;
+mod skinned;
+mod sort;
+mod stagger;
+mod timeline;
+mod warnings;
+
+pub use debug::*;
+pub use draw_list::*;
+pub use events::*;
+pub use ghost::*;
+pub use interleave::*;
+pub use overrides::*;
+pub use parts::*;
+pub use pool::*;
+#[cfg(feature = "profile")]
+pub(crate) use profile::Stopwatch;
+#[cfg(feature = "profile")]
+pub use profile::*;
+pub use scheduler::*;
+pub use skinned::*;
+pub use stagger::*;
+pub use warnings::*;
+
+use alloc::{
+    collections::{BTreeMap, BTreeSet},
+    sync::Arc,
+};
+use core::mem::take;
+
+#[cfg(not(feature = "std"))]
+use crate::math::Float;
+use crate::prelude::*;
+use crate::{
+    animation_state::AnimationState,
+    animation_state_data::AnimationStateData,
+    attachment::Attachment,
+    c::c_void,
+    c_interface::NewFromPtr,
+    color::Color,
+    draw::{
+        BlendFactors, ColorSpace, CullDirection, IndexFormat, MeshBuffers, RenderRules,
+        VertexColorFormat, VertexPositionFormat,
+    },
+    error::SpineError,
+    json::ObjectWriter,
+    skeleton::Skeleton,
+    skeleton_clipping::SkeletonClipping,
+    skeleton_data::SkeletonData,
+    slot::Slot,
+    wardrobe::{Outfit, SkinPool},
+    BlendMode, Physics,
+};
+
+#[cfg(feature = "mint")]
+use mint::Vector2;
+
+#[cfg(doc)]
+use crate::draw::{CombinedDrawer, SimpleDrawer};
+
+#[derive(Debug)]
+pub struct SkeletonController {
+    pub skeleton: Skeleton,
+    pub animation_state: AnimationState,
+    pub clipper: SkeletonClipping,
+    pub settings: SkeletonControllerSettings,
+    /// Rules overriding how attachments are rendered based on their names.
+    pub render_rules: RenderRules,
+    /// Hidden slot indices, mapped to the attachment the slot would show if it were visible.
+    hidden_slots: BTreeMap<usize, Option<Attachment>>,
+    /// The total time this controller has been updated for, see [`SkeletonController::time`].
+    time: f32,
+    /// Time not yet used by a fixed step, see [`SkeletonController::update_fixed`].
+    fixed_time_remainder: f32,
+    /// Warnings not yet drained, see [`SkeletonController::warnings`].
+    warnings: Vec<ControllerWarning>,
+    /// Every warning reported so far, so each is only reported once.
+    reported_warnings: BTreeSet<ControllerWarning>,
+    /// Change detection for the renderables, see [`SkeletonController::change_stamp`].
+    changes: changes::ChangeTracker,
+    /// Bone indices mapped to their tags, see [`SkeletonController::tag_bone`].
+    bone_tags: BTreeMap<usize, BoneTag>,
+    /// Bone indices mapped to their overrides, see [`SkeletonController::set_bone_override`].
+    bone_overrides: BTreeMap<usize, BoneOverride>,
+    /// Set to `true` to queue events, see [`SkeletonController::set_event_queue`].
+    event_queue: bool,
+    /// Events not yet drained, see [`SkeletonController::drain_events`].
+    queued_events: Vec<ControllerEvent>,
+    /// The number of updates so far, see [`SkeletonController::update_step`].
+    update_step: u64,
+    /// See [`SkeletonController::take_frame_stats`].
+    #[cfg(feature = "profile")]
+    frame_stats: FrameStats,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SkeletonControllerSettings {
+    /// Set to `true` if the textures are expected to have premultiplied alpha.
+    pub premultiplied_alpha: bool,
+    /// The cull direction to use for the vertices.
+    pub cull_direction: CullDirection,
+    /// The color space to use for the colors returned in [`SkeletonRenderable`] or  [`SkeletonCombinedRenderable`].
+    pub color_space: ColorSpace,
+    /// Set to `true` to check attachments for problems when generating renderables, see
+    /// [`SkeletonController::warnings`]. Attachments which can't be drawn are skipped instead.
+    pub collect_warnings: bool,
+    /// The format of the colors returned in [`SkeletonRenderable`] and
+    /// [`SkeletonCombinedRenderable`], including dark colors for two color tinting. When packed,
+    /// colors are returned in [`SkeletonRenderable::packed_color`] and
+    /// [`SkeletonCombinedRenderable::packed_colors`] instead of
+    /// [`SkeletonCombinedRenderable::colors`].
+    ///
+    /// Colors are premultiplied if using [`premultiplied_alpha`](`Self::premultiplied_alpha`)
+    /// and converted to [`color_space`](`Self::color_space`) before they are encoded, so the three
+    /// settings together describe the colors a renderer receives.
+    pub vertex_color_format: VertexColorFormat,
+    /// The format of the vertex positions and UVs returned in [`SkeletonCombinedRenderable`].
+    /// When quantized, they are returned in [`SkeletonCombinedRenderable::unorm_vertices`] and
+    /// [`SkeletonCombinedRenderable::unorm_uvs`] instead of
+    /// [`SkeletonCombinedRenderable::vertices`] and [`SkeletonCombinedRenderable::uvs`].
+    pub vertex_position_format: VertexPositionFormat,
+    /// The format of the indices returned in [`SkeletonCombinedRenderable`], see
+    /// [`SkeletonCombinedRenderable::index_format`]. If [`None`], each renderable uses
+    /// [`IndexFormat::U16`] unless it has more than [`IndexFormat::U16_MAX_VERTICES`] vertices.
+    /// If [`IndexFormat::U16`], renderables are split so they all fit.
+    pub index_format: Option<IndexFormat>,
+}
+
+impl Default for SkeletonControllerSettings {
+    fn default() -> Self {
+        Self {
+            premultiplied_alpha: false,
+            cull_direction: CullDirection::Clockwise,
+            color_space: ColorSpace::SRGB,
+            collect_warnings: false,
+            vertex_color_format: VertexColorFormat::Float,
+            vertex_position_format: VertexPositionFormat::Float,
+            index_format: None,
+        }
+    }
+}
+
+impl SkeletonControllerSettings {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[must_use]
+    pub const fn with_premultiplied_alpha(self, premultiplied_alpha: bool) -> Self {
+        Self {
+            premultiplied_alpha,
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub const fn with_cull_direction(self, cull_direction: CullDirection) -> Self {
+        Self {
+            cull_direction,
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub const fn with_color_space(self, color_space: ColorSpace) -> Self {
+        Self {
+            color_space,
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub const fn with_collect_warnings(self, collect_warnings: bool) -> Self {
+        Self {
+            collect_warnings,
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub const fn with_vertex_color_format(self, vertex_color_format: VertexColorFormat) -> Self {
+        Self {
+            vertex_color_format,
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub const fn with_vertex_position_format(
+        self,
+        vertex_position_format: VertexPositionFormat,
+    ) -> Self {
+        Self {
+            vertex_position_format,
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub const fn with_index_format(self, index_format: Option<IndexFormat>) -> Self {
+        Self {
+            index_format,
+            ..self
+        }
+    }
+}
+
+impl SkeletonController {
+    /// Creates a new skeleton and animation state instance with the given data.
+    #[must_use]
+    pub fn new(
+        skeleton_data: Arc<SkeletonData>,
+        animation_state_data: Arc<AnimationStateData>,
+    ) -> Self {
+        Self::from_parts(
+            SpineInstance::new(skeleton_data, animation_state_data),
+            MeshGenerator::new(),
+        )
+    }
+
+    #[must_use]
+    pub fn with_settings(self, settings: SkeletonControllerSettings) -> Self {
+        Self { settings, ..self }
+    }
+
+    /// Updates the animation state, applies to the skeleton, and updates world transforms.
+    pub fn update(&mut self, delta_seconds: f32, physics: Physics) {
+        self.time += delta_seconds;
+        self.update_step += 1;
+        #[cfg(feature = "profile")]
+        let start = Stopwatch::start();
+        self.animation_state.update(delta_seconds);
+        self.reveal_hidden_slots();
+        self.animation_state.apply(&mut self.skeleton);
+        #[cfg(feature = "profile")]
+        let animation_apply = start.elapsed();
+        self.hide_slots();
+        self.apply_bone_overrides();
+        #[cfg(feature = "profile")]
+        let overridden = Stopwatch::start();
+        self.skeleton.update(delta_seconds);
+        self.skeleton.update_world_transform(physics);
+        #[cfg(feature = "profile")]
+        {
+            self.frame_stats.updates += 1;
+            self.frame_stats.animation_apply += animation_apply;
+            self.frame_stats.world_transform += overridden.elapsed();
+        }
+        self.detect_changes();
+        self.queue_events();
+    }
+
+    /// A JSON snapshot of the skeleton and animation state, for attaching to bug reports. See
+    /// [`Skeleton::debug_dump`] and [`AnimationState::debug_dump`].
+    #[must_use]
+    pub fn debug_dump(&self) -> String {
+        let mut json = String::new();
+        let mut root = ObjectWriter::new(&mut json);
+        root.number("time", self.time);
+        self.skeleton.write_debug_dump(root.key("skeleton"));
+        self.animation_state
+            .write_debug_dump(root.key("animationState"));
+        root.end();
+        json
+    }
+
+    /// Set the horizontal scale of the skeleton. See [`set_scale`](`Self::set_scale`).
+    pub fn set_scale_x(&mut self, scale_x: f32) {
+        self.apply_scale(scale_x, self.skeleton.scale_y());
+    }
+
+    /// Set the vertical scale of the skeleton. See [`set_scale`](`Self::set_scale`).
+    pub fn set_scale_y(&mut self, scale_y: f32) {
+        self.apply_scale(self.skeleton.scale_x(), scale_y);
+    }
+
+    /// Applies the skeleton scale, keeping the cull direction and physics consistent with it.
+    fn apply_scale(&mut self, scale_x: f32, scale_y: f32) {
+        let old_scale_x = self.skeleton.scale_x();
+        let old_scale_y = self.skeleton.scale_y();
+        if old_scale_x == scale_x && old_scale_y == scale_y {
+            return;
+        }
+        let flipped_x = old_scale_x.is_sign_negative() != scale_x.is_sign_negative();
+        let flipped_y = old_scale_y.is_sign_negative() != scale_y.is_sign_negative();
+        if flipped_x != flipped_y {
+            // Mirroring on a single axis reverses the winding of every triangle.
+            self.settings.cull_direction = match self.settings.cull_direction {
+                CullDirection::Clockwise => CullDirection::CounterClockwise,
+                CullDirection::CounterClockwise => CullDirection::Clockwise,
+            };
+        }
+        self.skeleton.set_scale_x(scale_x);
+        self.skeleton.set_scale_y(scale_y);
+        if flipped_x || flipped_y {
+            // Physics would otherwise see the mirrored pose as a sudden, violent movement.
+            self.skeleton.update_world_transform(Physics::Reset);
+        } else {
+            self.skeleton.update_world_transform(Physics::Pose);
+        }
+    }
+
+    /// Stop delivering events with any of the given names to the animation state's listeners,
+    /// such as noisy footstep events during a cutscene. See
+    /// [`AnimationState::mute_events`](`crate::AnimationState::mute_events`).
+    ///
+    /// ```
+    /// # #[path="../test.rs"]
+    /// # mod test;
+    /// # use rusty_spine::controller::SkeletonController;
+    /// # let (skeleton_data, animation_state_data) = test::TestAsset::spineboy().instance_data(true);
+    /// let mut controller = SkeletonController::new(skeleton_data, animation_state_data);
+    /// controller.mute_events(&["footstep"]);
+    /// assert!(controller.animation_state.is_event_muted("footstep"));
+    /// ```
+    pub fn mute_events(&mut self, names: &[&str]) {
+        self.animation_state.mute_events(names);
+    }
+
+    /// Deliver events with any of the given names again, see
+    /// [`mute_events`](`Self::mute_events`).
+    pub fn unmute_events(&mut self, names: &[&str]) {
+        self.animation_state.unmute_events(names);
+    }
+
+    /// Dress the skeleton in a random outfit chosen from weighted skins, such as when spawning a
+    /// crowd member, returning the outfit. The same seed always produces the same outfit. See
+    /// [`SkinPool`].
+    ///
+    /// ```
+    /// # #[path="../test.rs"]
+    /// # mod test;
+    /// # use rusty_spine::{controller::SkeletonController, wardrobe::SkinPool};
+    /// # let (skeleton_data, animation_state_data) = test::TestAsset::all()[4].instance_data(true);
+    /// let pool = SkinPool::new()
+    ///     .with_piece("body", "goblin", 3.)
+    ///     .with_piece("body", "goblingirl", 1.);
+    /// let crowd: Vec<SkeletonController> = (0..10)
+    ///     .map(|index| {
+    ///         let mut controller =
+    ///             SkeletonController::new(skeleton_data.clone(), animation_state_data.clone());
+    ///         controller.dress_randomly(&pool, index).unwrap();
+    ///         controller
+    ///     })
+    ///     .collect();
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SpineError::NotFound`] if the outfit can't be dressed, see [`SkinPool::dress`].
+    pub fn dress_randomly(
+        &mut self,
+        skin_pool: &SkinPool,
+        seed: u64,
+    ) -> Result<Outfit, SpineError> {
+        let outfit = skin_pool.dress(&mut self.skeleton, seed)?;
+        // Dressing resets the slots' attachments, which would show hidden slots again, and the
+        // remembered attachments may belong to the previous outfit.
+        self.hide_slots();
+        self.mark_changed();
+        Ok(outfit)
+    }
+
+    /// Show or hide a slot. Hidden slots are not rendered, regardless of any attachment changes
+    /// made by animations. This is different from clearing the slot's attachment, which attachment
+    /// timelines would revert.
+    ///
+    /// ```
+    /// # #[path="../test.rs"]
+    /// # mod test;
+    /// # use rusty_spine::{controller::SkeletonController, Physics};
+    /// # let (skeleton_data, animation_state_data) = test::TestAsset::spineboy().instance_data(true);
+    /// let mut controller = SkeletonController::new(skeleton_data, animation_state_data);
+    /// controller.set_slot_visible("gun", false).unwrap();
+    /// controller.update(0.016, Physics::Update);
+    /// assert!(controller.skeleton.find_slot("gun").unwrap().attachment().is_none());
+    /// ```
+    ///
+    /// While hidden, the attachment the slot would otherwise show is remembered, and it is
+    /// restored when the slot is made visible again.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SpineError::NotFound`] if the slot does not exist.
+    pub fn set_slot_visible(&mut self, slot_name: &str, visible: bool) -> Result<(), SpineError> {
+        let slot_index = self
+            .skeleton
+            .find_slot(slot_name)
+            .ok_or_else(|| SpineError::new_not_found("Slot", slot_name))?
+            .data()
+            .index();
+        if visible {
+            if let Some(attachment) = self.hidden_slots.remove(&slot_index) {
+                if let Some(mut slot) = self.skeleton.slot_at_index_mut(slot_index) {
+                    unsafe { slot.set_attachment(attachment) };
+                }
+            }
+        } else if !self.hidden_slots.contains_key(&slot_index) {
+            let hidden_attachment = self
+                .skeleton
+                .slot_at_index_mut(slot_index)
+                .and_then(|mut slot| hide_slot(&mut slot));
+            self.hidden_slots.insert(slot_index, hidden_attachment);
+        }
+        self.mark_changed();
+        Ok(())
+    }
+
+    /// Returns `false` if the slot was hidden with [`SkeletonController::set_slot_visible`].
+    #[must_use]
+    pub fn is_slot_visible(&self, slot_name: &str) -> bool {
+        self.skeleton
+            .find_slot(slot_name)
+            .is_none_or(|slot| !self.hidden_slots.contains_key(&slot.data().index()))
+    }
+
+    /// Restores the attachments of hidden slots and forgets them.
+    fn show_hidden_slots(&mut self) {
+        for (slot_index, hidden_attachment) in take(&mut self.hidden_slots) {
+            if let (Some(attachment), Some(mut slot)) = (
+                hidden_attachment,
+                self.skeleton.slot_at_index_mut(slot_index),
+            ) {
+                unsafe { slot.set_attachment(Some(attachment)) };
+            }
+        }
+    }
+
+    /// Puts the remembered attachments back on hidden slots which are still cleared, so animations
+    /// apply to the attachments the slots would show if they were visible.
+    fn reveal_hidden_slots(&mut self) {
+        for (slot_index, hidden_attachment) in &self.hidden_slots {
+            let Some(mut slot) = self.skeleton.slot_at_index_mut(*slot_index) else {
+                continue;
+            };
+            if unsafe { slot.c_ptr_ref().attachment.is_null() } {
+                let attachment = hidden_attachment
+                    .as_ref()
+                    .map(|attachment| unsafe { Attachment::new_from_ptr(attachment.c_ptr()) });
+                unsafe { slot.set_attachment(attachment) };
+            }
+        }
+    }
+
+    /// Removes the attachments of hidden slots, remembering them so they can be restored. Slots
+    /// must have been revealed since they were last hidden, otherwise the remembered attachments
+    /// are lost.
+    fn hide_slots(&mut self) {
+        for (slot_index, hidden_attachment) in &mut self.hidden_slots {
+            if let Some(mut slot) = self.skeleton.slot_at_index_mut(*slot_index) {
+                *hidden_attachment = hide_slot(&mut slot);
+            }
+        }
+    }
+
+    /// Returns `true` if the skeleton uses two color tinting, so renderables should be drawn with
+    /// a shader which applies their dark colors, see
+    /// [`SkeletonData::uses_two_color_tint`].
+    ///
+    /// Renderables always include dark colors. Slots without two color tinting have a black dark
+    /// color, which a two color tint shader draws the same as a regular shader.
+    #[must_use]
+    pub fn uses_two_color_tint(&self) -> bool {
+        self.skeleton.data().uses_two_color_tint()
+    }
+
+    /// Render the skeleton using the [`SimpleDrawer`] and returns renderable mesh information.
+    ///
+    /// In most cases, it is preferable to use [`SkeletonController::combined_renderables`] which
+    /// is significantly faster for complex rigs.
+    pub fn renderables(&mut self) -> Vec<SkeletonRenderable> {
+        #[cfg(feature = "profile")]
+        let start = Stopwatch::start();
+        let detached = self.check_attachments();
+        let renderables = parts::renderables(
+            &mut self.skeleton,
+            &mut self.clipper,
+            &self.settings,
+            &self.render_rules,
+        );
+        self.restore_attachments(detached);
+        #[cfg(feature = "profile")]
+        self.profile_render_data(start, &renderables);
+        renderables
+    }
+
+    /// Render the skeleton using the [`CombinedDrawer`] and returns renderable mesh information.
+    pub fn combined_renderables(&mut self) -> Vec<SkeletonCombinedRenderable> {
+        #[cfg(feature = "profile")]
+        let start = Stopwatch::start();
+        let detached = self.check_attachments();
+        let renderables = parts::combined_renderables(
+            &mut self.skeleton,
+            &mut self.clipper,
+            &self.settings,
+            &self.render_rules,
+            &mut MeshBuffers::new(),
+        );
+        self.restore_attachments(detached);
+        #[cfg(feature = "profile")]
+        self.profile_render_data(start, &renderables);
+        renderables
+    }
+
+    /// The same as [`combined_renderables`](`Self::combined_renderables`), but clears and
+    /// refills `buffers` instead of allocating new renderables, so drawing many skeletons every
+    /// frame doesn't allocate once the buffers are large enough.
+    ///
+    /// ```
+    /// # #[path="../test.rs"]
+    /// # mod test;
+    /// # use rusty_spine::{controller::SkeletonController, draw::MeshBuffers, Physics};
+    /// # let (skeleton_data, animation_state_data) = test::TestAsset::spineboy().instance_data(true);
+    /// let mut controller = SkeletonController::new(skeleton_data, animation_state_data);
+    /// let mut buffers = MeshBuffers::new();
+    /// // every frame
+    /// controller.update(1. / 60., Physics::Update);
+    /// controller.combined_renderables_into(&mut buffers);
+    /// for batch in &buffers.batches {
+    ///     let vertices = &buffers.vertices[batch.vertices.clone()];
+    ///     let indices = &buffers.indices[batch.indices.clone()];
+    ///     // draw the vertices with the indices, which start at 0 for each batch
+    /// }
+    /// ```
+    ///
+    /// Vertex attributes are written according to the
+    /// [`vertex_color_format`](`SkeletonControllerSettings::vertex_color_format`) and
+    /// [`vertex_position_format`](`SkeletonControllerSettings::vertex_position_format`)
+    /// settings, leaving the buffers of the other formats empty.
+    pub fn combined_renderables_into(&mut self, buffers: &mut MeshBuffers) {
+        #[cfg(feature = "profile")]
+        let start = Stopwatch::start();
+        let detached = self.check_attachments();
+        parts::combined_renderables_into(
+            &mut self.skeleton,
+            &mut self.clipper,
+            &self.settings,
+            &self.render_rules,
+            buffers,
+        );
+        self.restore_attachments(detached);
+        #[cfg(feature = "profile")]
+        self.profile_render_data(start, &buffers.batches);
+    }
+}
+
+/// Functions available if using the `mint` feature.
+#[cfg(feature = "mint")]
+impl SkeletonController {
+    #[must_use]
+    pub fn scale(&self) -> Vector2<f32> {
+        self.skeleton.scale()
+    }
+
+    /// Set the scale of the skeleton, such as `[-1., 1.]` to flip a character horizontally.
+    ///
+    /// Unlike setting the scale on [`Skeleton`] directly, this also reverses
+    /// [`SkeletonControllerSettings::cull_direction`] when the skeleton is mirrored on a single
+    /// axis, and resets physics constraints whenever an axis is flipped so the mirrored pose is
+    /// not simulated as a sudden movement. World transforms are updated immediately.
+    ///
+    /// ```
+    /// # #[path="../test.rs"]
+    /// # mod test;
+    /// # use rusty_spine::{controller::SkeletonController, draw::CullDirection};
+    /// # let (skeleton_data, animation_state_data) = test::TestAsset::spineboy().instance_data(true);
+    /// let mut controller = SkeletonController::new(skeleton_data, animation_state_data);
+    /// controller.set_scale([-1., 1.]);
+    /// assert_eq!(controller.settings.cull_direction, CullDirection::CounterClockwise);
+    /// ```
+    pub fn set_scale(&mut self, scale: impl Into<Vector2<f32>>) {
+        let scale: Vector2<f32> = scale.into();
+        self.apply_scale(scale.x, scale.y);
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct SkeletonRenderable {
+    /// The index of the slot this renderable was drawn from, which identifies it across frames.
+    pub slot_index: usize,
+    pub vertices: Vec<[f32; 2]>,
+    pub uvs: Vec<[f32; 2]>,
+    pub indices: Vec<u16>,
+    pub color: Color,
+    /// The dark color for two color tinting, see [`SkeletonController::uses_two_color_tint`].
+    pub dark_color: Color,
+    /// The color when using [`VertexColorFormat::PackedRgba8`].
+    pub packed_color: Option<[u8; 4]>,
+    /// The dark color when using [`VertexColorFormat::PackedRgba8`].
+    pub packed_dark_color: Option<[u8; 4]>,
+    pub blend_mode: BlendMode,
+    pub premultiplied_alpha: bool,
+    pub tag: Option<String>,
+    pub attachment_renderer_object: Option<*const c_void>,
+}
+
+impl SkeletonRenderable {
+    /// The blend state to draw this renderable with, from its `blend_mode` and
+    /// `premultiplied_alpha`. See [`BlendMode::blend_factors`].
+    #[must_use]
+    pub const fn blend_factors(&self) -> BlendFactors {
+        self.blend_mode.blend_factors(self.premultiplied_alpha)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct SkeletonCombinedRenderable {
+    pub vertices: Vec<[f32; 2]>,
+    pub uvs: Vec<[f32; 2]>,
+    /// Vertex positions when using [`VertexPositionFormat::Unorm16`], otherwise empty. Each
+    /// position is `vertex_offset + unorm / 65535 * vertex_scale`, which is
+    /// `vertex_offset + normalized * vertex_scale` when read as a normalized vertex attribute.
+    pub unorm_vertices: Vec<[u16; 2]>,
+    /// UVs when using [`VertexPositionFormat::Unorm16`], otherwise empty. Each UV is
+    /// `unorm / 65535`.
+    pub unorm_uvs: Vec<[u16; 2]>,
+    /// The minimum corner of the renderable's bounds, used to decode `unorm_vertices`.
+    pub vertex_offset: [f32; 2],
+    /// The size of the renderable's bounds, used to decode `unorm_vertices`.
+    pub vertex_scale: [f32; 2],
+    /// Indices when using [`IndexFormat::U16`], otherwise empty.
+    pub indices: Vec<u16>,
+    /// Indices when using [`IndexFormat::U32`], otherwise empty.
+    pub indices_u32: Vec<u32>,
+    /// The type of the renderable's indices, which the renderer should bind them as. See
+    /// [`SkeletonControllerSettings::index_format`].
+    pub index_format: IndexFormat,
+    pub colors: Vec<[f32; 4]>,
+    /// Vertex dark colors for two color tinting, see
+    /// [`SkeletonController::uses_two_color_tint`].
+    pub dark_colors: Vec<[f32; 4]>,
+    /// Vertex colors when using [`VertexColorFormat::PackedRgba8`], otherwise empty.
+    pub packed_colors: Vec<[u8; 4]>,
+    /// Vertex dark colors when using [`VertexColorFormat::PackedRgba8`], otherwise empty. Alpha is
+    /// 255 when the renderable uses premultiplied alpha and 0 otherwise, as with `dark_colors`.
+    pub packed_dark_colors: Vec<[u8; 4]>,
+    pub blend_mode: BlendMode,
+    pub premultiplied_alpha: bool,
+    pub tag: Option<String>,
+    pub attachment_renderer_object: Option<*const c_void>,
+    /// The draw order index of the first slot drawn into this renderable, which identifies it
+    /// across frames so long as the draw order and attachments don't change. See
+    /// [`CombinedRenderable::first_slot_index`](`crate::draw::CombinedRenderable::first_slot_index`).
+    pub first_slot_index: usize,
+}
+
+impl SkeletonCombinedRenderable {
+    /// The blend state to draw this renderable with, from its `blend_mode` and
+    /// `premultiplied_alpha`. See [`BlendMode::blend_factors`].
+    #[must_use]
+    pub const fn blend_factors(&self) -> BlendFactors {
+        self.blend_mode.blend_factors(self.premultiplied_alpha)
+    }
+
+    /// Move `vertices` and `uvs` into `unorm_vertices` and `unorm_uvs`, with positions relative to
+    /// the bounds of the vertices.
+    fn quantize(&mut self) {
+        (self.vertex_offset, self.vertex_scale) = quantize(
+            &take(&mut self.vertices),
+            &take(&mut self.uvs),
+            &mut self.unorm_vertices,
+            &mut self.unorm_uvs,
+        );
+    }
+}
+
+/// Clear the attachment of a hidden slot, returning it so it can be restored.
+fn hide_slot(slot: &mut Slot) -> Option<Attachment> {
+    let c_attachment = unsafe { slot.c_ptr_ref().attachment };
+    if c_attachment.is_null() {
+        return None;
+    }
+    let attachment = unsafe { Attachment::new_from_ptr(c_attachment) };
+    unsafe { slot.set_attachment(None) };
+    Some(attachment)
+}
+
+/// Append `vertices` and `uvs` to `unorm_vertices` and `unorm_uvs`, with positions relative to
+/// the bounds of the vertices. Returns the offset and scale of the bounds.
+fn quantize(
+    vertices: &[[f32; 2]],
+    uvs: &[[f32; 2]],
+    unorm_vertices: &mut Vec<[u16; 2]>,
+    unorm_uvs: &mut Vec<[u16; 2]>,
+) -> ([f32; 2], [f32; 2]) {
+    let mut min = [f32::MAX; 2];
+    let mut max = [f32::MIN; 2];
+    for vertex in vertices {
+        for axis in 0..2 {
+            min[axis] = min[axis].min(vertex[axis]);
+            max[axis] = max[axis].max(vertex[axis]);
+        }
+    }
+    if vertices.is_empty() {
+        min = [0.; 2];
+        max = [0.; 2];
+    }
+    let scale = [max[0] - min[0], max[1] - min[1]];
+    let unorm = |value: f32| (value.clamp(0., 1.) * 65535.).round() as u16;
+    let fraction = |value: f32, axis: usize| {
+        if scale[axis] > 0. {
+            (value - min[axis]) / scale[axis]
+        } else {
+            0.
+        }
+    };
+    unorm_vertices.extend(
+        vertices
+            .iter()
+            .map(|vertex| [unorm(fraction(vertex[0], 0)), unorm(fraction(vertex[1], 1))]),
+    );
+    unorm_uvs.extend(uvs.iter().map(|uv| [unorm(uv[0]), unorm(uv[1])]));
+    (min, scale)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        draw::{ColorSpace, MeshBuffers, VertexColorFormat, VertexPositionFormat},
+        test::TestAsset,
+        Color, Physics,
+    };
+
+    use super::{SkeletonController, SkeletonControllerSettings};
+
+    #[test]
+    fn packed_vertex_colors() {
+        for color_space in [ColorSpace::SRGB, ColorSpace::Linear] {
+            let settings = SkeletonControllerSettings::new()
+                .with_premultiplied_alpha(true)
+                .with_color_space(color_space);
+            let (skeleton_data, animation_state_data) = TestAsset::spineboy().instance_data(true);
+            let mut float_controller =
+                SkeletonController::new(skeleton_data.clone(), animation_state_data.clone())
+                    .with_settings(settings.clone());
+            let mut packed_controller =
+                SkeletonController::new(skeleton_data, animation_state_data).with_settings(
+                    settings.with_vertex_color_format(VertexColorFormat::PackedRgba8),
+                );
+            for controller in [&mut float_controller, &mut packed_controller] {
+                controller.skeleton.color_mut().set_a(0.5);
+                controller.update(0.016, Physics::Update);
+            }
+
+            let float_renderables = float_controller.combined_renderables();
+            let packed_renderables = packed_controller.combined_renderables();
+            assert_eq!(float_renderables.len(), packed_renderables.len());
+            for (float, packed) in float_renderables.iter().zip(packed_renderables.iter()) {
+                assert!(float.packed_colors.is_empty());
+                assert!(packed.colors.is_empty() && packed.dark_colors.is_empty());
+                assert_eq!(float.colors.len(), packed.packed_colors.len());
+                for (color, packed_color) in float.colors.iter().zip(packed.packed_colors.iter()) {
+                    assert_eq!(Color::from(*color).to_rgba8(), *packed_color);
+                    assert!(packed_color[..3].iter().all(|c| *c <= packed_color[3]));
+                }
+                assert!(packed.packed_dark_colors.iter().all(|c| c[3] == 255));
+            }
+
+            let float_renderables = float_controller.renderables();
+            let packed_renderables = packed_controller.renderables();
+            assert_eq!(float_renderables.len(), packed_renderables.len());
+            for (float, packed) in float_renderables.iter().zip(packed_renderables.iter()) {
+                assert!(float.packed_color.is_none() && float.packed_dark_color.is_none());
+                assert_eq!(packed.packed_color, Some(float.color.to_rgba8()));
+                assert_eq!(packed.packed_dark_color, Some(float.dark_color.to_rgba8()));
+            }
+        }
+    }
+
+    #[test]
+    fn stable_renderables() {
+        let (skeleton_data, animation_state_data) = TestAsset::spineboy().instance_data(true);
+        let mut controller = SkeletonController::new(skeleton_data, animation_state_data);
+        controller
+            .animation_state
+            .set_animation_by_name(0, "idle", true)
+            .unwrap();
+        let mut previous_ids = None;
+        for _ in 0..10 {
+            controller.update(0.05, Physics::Update);
+            let renderables = controller.combined_renderables();
+            assert!(renderables
+                .iter()
+                .all(|renderable| !renderable.indices.is_empty()));
+            let ids: Vec<usize> = renderables
+                .iter()
+                .map(|renderable| renderable.first_slot_index)
+                .collect();
+            let mut unique_ids = ids.clone();
+            unique_ids.sort_unstable();
+            unique_ids.dedup();
+            assert_eq!(unique_ids.len(), ids.len());
+            if let Some(previous_ids) = previous_ids {
+                assert_eq!(previous_ids, ids);
+            }
+            previous_ids = Some(ids);
+        }
+    }
+
+    #[test]
+    fn two_color_tint() {
+        let (skeleton_data, animation_state_data) = TestAsset::spineboy().instance_data(true);
+        let controller = SkeletonController::new(skeleton_data, animation_state_data);
+        assert!(!controller.uses_two_color_tint());
+
+        let (skeleton_data, animation_state_data) = TestAsset::all()[2].instance_data(true);
+        let controller = SkeletonController::new(skeleton_data, animation_state_data);
+        assert!(controller.uses_two_color_tint());
+    }
+
+    #[test]
+    fn unorm_vertices() {
+        let (skeleton_data, animation_state_data) = TestAsset::spineboy().instance_data(true);
+        let mut float_controller =
+            SkeletonController::new(skeleton_data.clone(), animation_state_data.clone());
+        let mut unorm_controller = SkeletonController::new(skeleton_data, animation_state_data)
+            .with_settings(
+                SkeletonControllerSettings::new()
+                    .with_vertex_position_format(VertexPositionFormat::Unorm16),
+            );
+        for controller in [&mut float_controller, &mut unorm_controller] {
+            controller
+                .animation_state
+                .set_animation_by_name(0, "run", true)
+                .unwrap();
+            controller.update(0.3, Physics::Update);
+        }
+
+        let float_renderables = float_controller.combined_renderables();
+        let unorm_renderables = unorm_controller.combined_renderables();
+        assert_eq!(float_renderables.len(), unorm_renderables.len());
+        for (float, unorm) in float_renderables.iter().zip(unorm_renderables.iter()) {
+            assert!(float.unorm_vertices.is_empty() && float.unorm_uvs.is_empty());
+            assert!(unorm.vertices.is_empty() && unorm.uvs.is_empty());
+            assert_eq!(float.vertices.len(), unorm.unorm_vertices.len());
+            assert_eq!(float.uvs.len(), unorm.unorm_uvs.len());
+            for (vertex, unorm_vertex) in float.vertices.iter().zip(unorm.unorm_vertices.iter()) {
+                for axis in 0..2 {
+                    let decoded = unorm.vertex_offset[axis]
+                        + f32::from(unorm_vertex[axis]) / 65535. * unorm.vertex_scale[axis];
+                    assert!((decoded - vertex[axis]).abs() <= unorm.vertex_scale[axis] / 65535.);
+                }
+            }
+            for (uv, unorm_uv) in float.uvs.iter().zip(unorm.unorm_uvs.iter()) {
+                for axis in 0..2 {
+                    assert!((f32::from(unorm_uv[axis]) / 65535. - uv[axis]).abs() < 1e-4);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn simple_renderables_packed_colors() {
+        let (skeleton_data, animation_state_data) = TestAsset::spineboy().instance_data(true);
+        for (premultiplied_alpha, expected) in
+            [(false, [255, 128, 64, 128]), (true, [128, 64, 32, 128])]
+        {
+            let settings = SkeletonControllerSettings::new()
+                .with_premultiplied_alpha(premultiplied_alpha)
+                .with_color_space(ColorSpace::SRGB)
+                .with_vertex_color_format(VertexColorFormat::PackedRgba8);
+            let mut controller =
+                SkeletonController::new(skeleton_data.clone(), animation_state_data.clone())
+                    .with_settings(settings);
+            *controller.skeleton.color_mut() = Color::new_rgba(1., 0.5, 0.25, 0.5);
+            controller.update(0.016, Physics::Update);
+            let head_index = controller
+                .skeleton
+                .find_slot("head")
+                .unwrap()
+                .data()
+                .index();
+            let renderables = controller.renderables();
+            let head = renderables
+                .iter()
+                .find(|renderable| renderable.slot_index == head_index)
+                .unwrap();
+            assert_eq!(head.packed_color, Some(expected));
+            assert_eq!(head.packed_dark_color, Some(head.dark_color.to_rgba8()));
+        }
+    }
+
+    #[test]
+    fn set_scale() {
+        let (skeleton_data, animation_state_data) = TestAsset::spineboy().instance_data(true);
+        let mut controller = SkeletonController::new(skeleton_data, animation_state_data);
+        let cull_direction = controller.settings.cull_direction;
+
+        // mirroring a single axis reverses the cull direction and updates world transforms
+        controller.set_scale_x(-2.);
+        assert_eq!(controller.skeleton.scale_x(), -2.);
+        assert_ne!(controller.settings.cull_direction, cull_direction);
+        let root = controller.skeleton.find_bone("root").unwrap();
+        assert!((root.a() + 2.).abs() < 0.001);
+
+        // mirroring both axes keeps the original winding
+        controller.set_scale_y(-1.);
+        assert_eq!(controller.settings.cull_direction, cull_direction);
+
+        // scaling without flipping keeps the cull direction
+        controller.set_scale_x(-1.);
+        controller.set_scale_y(-3.);
+        assert_eq!(controller.settings.cull_direction, cull_direction);
+        assert_eq!(controller.skeleton.scale_y(), -3.);
+    }
+
+    #[test]
+    fn hidden_slot_follows_animation() {
+        let (skeleton_data, animation_state_data) = TestAsset::spineboy().instance_data(true);
+        let attachment_name = |controller: &SkeletonController, slot_name: &str| {
+            controller
+                .skeleton
+                .find_slot(slot_name)
+                .unwrap()
+                .attachment()
+                .map(|attachment| attachment.name().to_owned())
+        };
+
+        // the attachment is remembered while no animation keys it, and an attachment keyed
+        // while hidden is restored when shown
+        let mut controller =
+            SkeletonController::new(skeleton_data.clone(), animation_state_data.clone());
+        controller.set_slot_visible("mouth", false).unwrap();
+        controller.update(0.1, Physics::Update);
+        controller.set_slot_visible("mouth", true).unwrap();
+        assert_eq!(
+            attachment_name(&controller, "mouth").as_deref(),
+            Some("mouth-smile")
+        );
+        controller.set_slot_visible("mouth", false).unwrap();
+        controller
+            .animation_state
+            .set_animation_by_name(0, "run", true)
+            .unwrap();
+        controller.update(0.1, Physics::Update);
+        assert_eq!(attachment_name(&controller, "mouth"), None);
+        controller.set_slot_visible("mouth", true).unwrap();
+        assert_eq!(
+            attachment_name(&controller, "mouth").as_deref(),
+            Some("mouth-grind")
+        );
+
+        // an attachment keyed to null while hidden stays cleared when shown
+        let mut controller = SkeletonController::new(skeleton_data, animation_state_data);
+        controller.set_slot_visible("muzzle", false).unwrap();
+        controller
+            .animation_state
+            .set_animation_by_name(0, "shoot", false)
+            .unwrap();
+        controller.update(0.11, Physics::Update);
+        controller.update(0.15, Physics::Update);
+        assert_eq!(attachment_name(&controller, "muzzle"), None);
+        controller.set_slot_visible("muzzle", true).unwrap();
+        assert_eq!(attachment_name(&controller, "muzzle"), None);
+    }
+
+    #[test]
+    fn combined_renderables_into() {
+        let (skeleton_data, animation_state_data) = TestAsset::spineboy().instance_data(true);
+        let mut buffers = MeshBuffers::new();
+        for (color_format, position_format) in [
+            (VertexColorFormat::Float, VertexPositionFormat::Float),
+            (
+                VertexColorFormat::PackedRgba8,
+                VertexPositionFormat::Unorm16,
+            ),
+        ] {
+            let settings = SkeletonControllerSettings::new()
+                .with_vertex_color_format(color_format)
+                .with_vertex_position_format(position_format);
+            let mut controller =
+                SkeletonController::new(skeleton_data.clone(), animation_state_data.clone())
+                    .with_settings(settings);
+            controller
+                .animation_state
+                .set_animation_by_name(0, "portal", false)
+                .unwrap();
+            controller.update(0.5, Physics::Update);
+
+            let renderables = controller.combined_renderables();
+            controller.combined_renderables_into(&mut buffers);
+            assert_eq!(buffers.batches.len(), renderables.len());
+            for (batch, renderable) in buffers.batches.iter().zip(&renderables) {
+                assert_eq!(&buffers.indices[batch.indices.clone()], renderable.indices);
+                assert_eq!(batch.blend_mode, renderable.blend_mode);
+                assert_eq!(batch.vertex_offset, renderable.vertex_offset);
+                assert_eq!(batch.vertex_scale, renderable.vertex_scale);
+                let vertices = batch.vertices.clone();
+                if position_format == VertexPositionFormat::Unorm16 {
+                    assert!(buffers.vertices.is_empty());
+                    assert_eq!(
+                        &buffers.unorm_vertices[vertices.clone()],
+                        renderable.unorm_vertices
+                    );
+                    assert_eq!(&buffers.unorm_uvs[vertices.clone()], renderable.unorm_uvs);
+                } else {
+                    assert_eq!(&buffers.vertices[vertices.clone()], renderable.vertices);
+                    assert_eq!(&buffers.uvs[vertices.clone()], renderable.uvs);
+                }
+                if color_format == VertexColorFormat::PackedRgba8 {
+                    assert!(buffers.colors.is_empty());
+                    assert_eq!(&buffers.packed_colors[vertices], renderable.packed_colors);
+                } else {
+                    assert_eq!(&buffers.colors[vertices], renderable.colors);
+                }
+            }
+
+            let capacity = buffers.indices.capacity();
+            controller.combined_renderables_into(&mut buffers);
+            assert_eq!(buffers.batches.len(), renderables.len());
+            assert_eq!(buffers.indices.capacity(), capacity);
+        }
+    }
+}
@@ -0,0 +1,295 @@
+#[cfg(not(feature = "std"))]
+use crate::math::Float;
+use crate::{error::SpineError, Physics};
+
+use super::SkeletonController;
+
+/// The space the values of a [`BoneOverride`] are in.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum OverrideSpace {
+    /// The bone's local transform, relative to its parent, as set by animations.
+    #[default]
+    Local,
+    /// World coordinates, such as a position under the mouse. Scale is converted using the
+    /// parent's world scale, which is approximate if the parent is rotated relative to its own
+    /// scale axes or sheared.
+    World,
+}
+
+/// A persistent change to a bone's pose, applied on top of animations every update, see
+/// [`SkeletonController::set_bone_override`]. Only the values which are set are overridden.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BoneOverride {
+    pub position: Option<[f32; 2]>,
+    /// The rotation in degrees, counter clockwise.
+    pub rotation: Option<f32>,
+    pub scale: Option<[f32; 2]>,
+    pub space: OverrideSpace,
+    /// How much the override replaces the animated pose, from `0` (not at all) to `1` (fully).
+    pub blend: f32,
+}
+
+impl Default for BoneOverride {
+    fn default() -> Self {
+        Self {
+            position: None,
+            rotation: None,
+            scale: None,
+            space: OverrideSpace::Local,
+            blend: 1.,
+        }
+    }
+}
+
+impl BoneOverride {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[must_use]
+    pub const fn with_position(self, x: f32, y: f32) -> Self {
+        Self {
+            position: Some([x, y]),
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub const fn with_rotation(self, rotation: f32) -> Self {
+        Self {
+            rotation: Some(rotation),
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub const fn with_scale(self, scale_x: f32, scale_y: f32) -> Self {
+        Self {
+            scale: Some([scale_x, scale_y]),
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub const fn with_space(self, space: OverrideSpace) -> Self {
+        Self { space, ..self }
+    }
+
+    #[must_use]
+    pub const fn with_blend(self, blend: f32) -> Self {
+        Self { blend, ..self }
+    }
+}
+
+impl SkeletonController {
+    /// Override a bone's pose after animations are applied, replacing any previous override of
+    /// the bone, so procedural animation such as aiming or looking at a target doesn't need to be
+    /// interleaved with [`update`](`Self::update`) by hand.
+    ///
+    /// ```
+    /// # #[path="../test.rs"]
+    /// # mod test;
+    /// # use rusty_spine::{controller::{BoneOverride, OverrideSpace, SkeletonController}, Physics};
+    /// # let (skeleton_data, animation_state_data) = test::TestAsset::spineboy().instance_data(true);
+    /// # let (mouse_x, mouse_y) = (100., 200.);
+    /// let mut controller = SkeletonController::new(skeleton_data, animation_state_data);
+    /// controller.set_bone_override(
+    ///     "crosshair",
+    ///     BoneOverride::new()
+    ///         .with_position(mouse_x, mouse_y)
+    ///         .with_space(OverrideSpace::World),
+    /// )?;
+    /// controller.set_bone_override("head", BoneOverride::new().with_rotation(20.).with_blend(0.5))?;
+    /// controller.update(0.016, Physics::Update);
+    /// # Ok::<(), rusty_spine::SpineError>(())
+    /// ```
+    ///
+    /// Overrides are applied to the local pose after [`AnimationState::apply`] and before
+    /// [`Skeleton::update_world_transform`], so constraints such as IK still act on the
+    /// overridden bones. World space overrides are converted using the parent bones' world
+    /// transforms for the current frame, which are computed with an extra world transform update
+    /// when there are any.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SpineError::NotFound`] if the bone does not exist.
+    ///
+    /// [`AnimationState::apply`]: crate::AnimationState::apply
+    /// [`Skeleton::update_world_transform`]: crate::Skeleton::update_world_transform
+    pub fn set_bone_override(
+        &mut self,
+        bone_name: &str,
+        bone_override: BoneOverride,
+    ) -> Result<(), SpineError> {
+        let bone_index = self.bone_index(bone_name)?;
+        self.bone_overrides.insert(bone_index, bone_override);
+        Ok(())
+    }
+
+    /// Remove a bone's override, returning it if there was one. The bone returns to its animated
+    /// pose on the next update.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SpineError::NotFound`] if the bone does not exist.
+    pub fn remove_bone_override(
+        &mut self,
+        bone_name: &str,
+    ) -> Result<Option<BoneOverride>, SpineError> {
+        let bone_index = self.bone_index(bone_name)?;
+        Ok(self.bone_overrides.remove(&bone_index))
+    }
+
+    /// Remove the overrides of every bone.
+    pub fn clear_bone_overrides(&mut self) {
+        self.bone_overrides.clear();
+    }
+
+    /// The override set for a bone with [`set_bone_override`](`Self::set_bone_override`).
+    #[must_use]
+    pub fn bone_override(&self, bone_name: &str) -> Option<&BoneOverride> {
+        self.bone_index(bone_name)
+            .ok()
+            .and_then(|bone_index| self.bone_overrides.get(&bone_index))
+    }
+
+    /// Blends the local pose of overridden bones toward their overrides.
+    pub(crate) fn apply_bone_overrides(&mut self) {
+        if self.bone_overrides.is_empty() {
+            return;
+        }
+        if self
+            .bone_overrides
+            .values()
+            .any(|bone_override| bone_override.space == OverrideSpace::World)
+        {
+            // World space overrides need this frame's world transforms of the parent bones.
+            self.skeleton.update_world_transform(Physics::Pose);
+        }
+        let skeleton_scale = (self.skeleton.scale_x().abs(), self.skeleton.scale_y().abs());
+        // Bones are sorted with parents first, so world space overrides of children are converted
+        // using their parents' overridden transforms.
+        for (&bone_index, bone_override) in &self.bone_overrides {
+            let Some(mut bone) = self.skeleton.bone_at_index_mut(bone_index) else {
+                continue;
+            };
+            let blend = bone_override.blend.clamp(0., 1.);
+            let world = bone_override.space == OverrideSpace::World;
+            if let Some([x, y]) = bone_override.position {
+                let (x, y) = if world {
+                    bone.world_to_parent(x, y)
+                } else {
+                    (x, y)
+                };
+                let (animated_x, animated_y) = (bone.x(), bone.y());
+                bone.set_x(animated_x + (x - animated_x) * blend);
+                bone.set_y(animated_y + (y - animated_y) * blend);
+            }
+            if let Some(rotation) = bone_override.rotation {
+                let rotation = if world {
+                    let animated_rotation = bone.rotation();
+                    bone.set_world_rotation(rotation);
+                    let local_rotation = bone.rotation();
+                    bone.set_rotation(animated_rotation);
+                    local_rotation
+                } else {
+                    rotation
+                };
+                let animated_rotation = bone.rotation();
+                let difference = (rotation - animated_rotation + 180.).rem_euclid(360.) - 180.;
+                bone.set_rotation(animated_rotation + difference * blend);
+            }
+            if let Some([scale_x, scale_y]) = bone_override.scale {
+                let (scale_x, scale_y) = if world {
+                    let (parent_scale_x, parent_scale_y) =
+                        bone.parent().map_or(skeleton_scale, |parent| {
+                            (parent.world_scale_x(), parent.world_scale_y())
+                        });
+                    (scale_x / parent_scale_x, scale_y / parent_scale_y)
+                } else {
+                    (scale_x, scale_y)
+                };
+                let (animated_scale_x, animated_scale_y) = (bone.scale_x(), bone.scale_y());
+                bone.set_scale_x(animated_scale_x + (scale_x - animated_scale_x) * blend);
+                bone.set_scale_y(animated_scale_y + (scale_y - animated_scale_y) * blend);
+            }
+            if world {
+                // Children with world space overrides are converted using this bone's new pose.
+                bone.update_world_transform();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        controller::{BoneOverride, OverrideSpace, SkeletonController},
+        test::TestAsset,
+        Physics,
+    };
+
+    #[test]
+    fn bone_overrides() {
+        let (skeleton_data, animation_state_data) = TestAsset::spineboy().instance_data(true);
+        let mut controller = SkeletonController::new(skeleton_data, animation_state_data);
+        controller
+            .animation_state
+            .set_animation_by_name(0, "walk", true)
+            .unwrap();
+        assert!(controller
+            .set_bone_override("missing", BoneOverride::new())
+            .is_err());
+
+        controller
+            .set_bone_override(
+                "crosshair",
+                BoneOverride::new()
+                    .with_position(100., 200.)
+                    .with_space(OverrideSpace::World),
+            )
+            .unwrap();
+        controller
+            .set_bone_override(
+                "front-fist",
+                BoneOverride::new()
+                    .with_rotation(90.)
+                    .with_space(OverrideSpace::World),
+            )
+            .unwrap();
+        controller
+            .set_bone_override(
+                "head",
+                BoneOverride::new().with_rotation(40.).with_blend(0.5),
+            )
+            .unwrap();
+        for _ in 0..10 {
+            controller.update(0.05, Physics::Update);
+            let crosshair = controller.skeleton.find_bone("crosshair").unwrap();
+            assert!((crosshair.world_x() - 100.).abs() < 0.01);
+            assert!((crosshair.world_y() - 200.).abs() < 0.01);
+            let front_fist = controller.skeleton.find_bone("front-fist").unwrap();
+            assert!((front_fist.world_rotation_x() - 90.).abs() < 0.1);
+        }
+
+        let animated_rotation = {
+            let (skeleton_data, animation_state_data) = TestAsset::spineboy().instance_data(true);
+            let mut reference = SkeletonController::new(skeleton_data, animation_state_data);
+            reference
+                .animation_state
+                .set_animation_by_name(0, "walk", true)
+                .unwrap();
+            for _ in 0..10 {
+                reference.update(0.05, Physics::Update);
+            }
+            reference.skeleton.find_bone("head").unwrap().rotation()
+        };
+        let head = controller.skeleton.find_bone("head").unwrap();
+        assert!((head.rotation() - (animated_rotation + 40.) / 2.).abs() < 0.01);
+
+        assert!(controller.remove_bone_override("head").unwrap().is_some());
+        controller.clear_bone_overrides();
+        assert!(controller.bone_override("crosshair").is_none());
+    }
+}
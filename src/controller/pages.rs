@@ -0,0 +1,120 @@
+use alloc::collections::BTreeSet;
+
+use crate::prelude::*;
+use crate::TrackEntry;
+
+use super::SkeletonController;
+
+impl SkeletonController {
+    /// The indices of the atlas pages this skeleton needs to be drawn now and while its current
+    /// animations play, in the order of [`Atlas::pages`](`crate::Atlas::pages`). Pages not in the
+    /// set can be evicted by streaming systems until they are needed again, such as when the
+    /// skin or animations change.
+    ///
+    /// ```
+    /// # #[path="../test.rs"]
+    /// # mod test;
+    /// # use rusty_spine::controller::SkeletonController;
+    /// # let (skeleton_data, animation_state_data) = test::TestAsset::spineboy().instance_data(true);
+    /// let mut controller = SkeletonController::new(skeleton_data, animation_state_data);
+    /// controller.animation_state.set_animation_by_name(0, "run", true)?;
+    /// for page_index in controller.required_pages() {
+    ///     // keep the texture of page `page_index` loaded
+    /// }
+    /// # Ok::<(), rusty_spine::SpineError>(())
+    /// ```
+    ///
+    /// The set includes the pages of:
+    /// - the attachment each slot currently shows,
+    /// - the setup pose attachment of each slot,
+    /// - every attachment keyed by the animations playing, queued, or being mixed from on each
+    ///   track.
+    ///
+    /// Keyed and setup attachments are resolved through the active skin, then the default skin.
+    /// Every frame of an attachment's sequence is included.
+    pub fn required_pages(&mut self) -> BTreeSet<usize> {
+        let mut page_indices: BTreeSet<usize> = self
+            .skeleton
+            .slots()
+            .filter_map(|slot| Some(slot.attachment()?.page_indices()))
+            .flatten()
+            .collect();
+        let mut keyed_names: Vec<(usize, String)> = self
+            .skeleton
+            .data()
+            .slots()
+            .filter_map(|slot_data| {
+                let attachment_name = slot_data.attachment_name()?;
+                Some((slot_data.index(), attachment_name.to_owned()))
+            })
+            .collect();
+        for track in self.animation_state.tracks().flatten() {
+            collect_keyed_names(&track, &mut keyed_names);
+        }
+        for (slot_index, attachment_name) in keyed_names {
+            if let Some(attachment) = self
+                .skeleton
+                .get_attachment_for_slot_index(slot_index, &attachment_name)
+            {
+                page_indices.extend(attachment.page_indices());
+            }
+        }
+        page_indices
+    }
+}
+
+/// Collect the attachment names keyed by a track entry, and the track entries queued after it or
+/// being mixed from.
+fn collect_keyed_names(track_entry: &TrackEntry, keyed_names: &mut Vec<(usize, String)>) {
+    keyed_names.extend(
+        track_entry
+            .animation()
+            .keyed_attachment_names()
+            .into_iter()
+            .map(|(slot_index, name)| (slot_index, name.to_owned())),
+    );
+    if let Some(next) = track_entry.next() {
+        collect_keyed_names(&next, keyed_names);
+    }
+    if let Some(mixing_from) = track_entry.mixing_from() {
+        collect_keyed_names(&mixing_from, keyed_names);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{controller::SkeletonController, test::TestAsset, Physics};
+
+    #[test]
+    fn required_pages() {
+        let (skeleton_data, animation_state_data) = TestAsset::all()[3].instance_data(true);
+        let mut controller = SkeletonController::new(skeleton_data, animation_state_data);
+        controller
+            .animation_state
+            .set_animation_by_name(0, "flying", true)
+            .unwrap();
+        controller.update(0.016, Physics::Update);
+        let wing = controller.skeleton.find_slot("left-wing").unwrap();
+        assert_eq!(wing.attachment().unwrap().page_indices(), [0, 1, 2, 3, 4]);
+        assert_eq!(
+            controller.required_pages().into_iter().collect::<Vec<_>>(),
+            [0, 1, 2, 3, 4]
+        );
+
+        let (skeleton_data, animation_state_data) = TestAsset::spineboy().instance_data(true);
+        let mut controller = SkeletonController::new(skeleton_data.clone(), animation_state_data);
+        controller
+            .animation_state
+            .set_animation_by_name(0, "shoot", false)
+            .unwrap();
+        let shoot = skeleton_data.find_animation("shoot").unwrap();
+        assert!(shoot
+            .keyed_attachment_names()
+            .iter()
+            .any(|(_, name)| name.starts_with("muzzle")));
+        assert_eq!(
+            controller.required_pages().into_iter().collect::<Vec<_>>(),
+            [0]
+        );
+    }
+}
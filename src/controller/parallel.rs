@@ -0,0 +1,80 @@
+use rayon::prelude::*;
+
+use crate::Physics;
+
+use super::SkeletonController;
+
+/// Functions available if using the `parallel` feature.
+impl SkeletonController {
+    /// Update many controllers across threads with [`rayon`], for crowds too large to update on
+    /// one thread. Each controller is updated as with [`update`](`Self::update`).
+    ///
+    /// ```
+    /// # #[path="../test.rs"]
+    /// # mod test;
+    /// # use rusty_spine::{controller::SkeletonController, Physics};
+    /// # let (skeleton_data, animation_state_data) = test::TestAsset::spineboy().instance_data(true);
+    /// let mut crowd: Vec<SkeletonController> = (0..100)
+    ///     .map(|_| SkeletonController::new(skeleton_data.clone(), animation_state_data.clone()))
+    ///     .collect();
+    /// // SAFETY: no listeners are set on these controllers.
+    /// unsafe { SkeletonController::update_all_parallel(&mut crowd, 0.016, Physics::Update) };
+    /// ```
+    ///
+    /// Updating a controller only writes to its own skeleton and animation state, and only reads
+    /// the [`SkeletonData`](`crate::SkeletonData`) and
+    /// [`AnimationStateData`](`crate::AnimationStateData`) it shares with other controllers, so
+    /// controllers can be updated at the same time as long as the shared data is not modified
+    /// during the update. Memory allocated by the Spine runtime goes through a thread safe
+    /// allocator.
+    ///
+    /// # Safety
+    ///
+    /// Listeners set with [`AnimationState::set_listener`](`crate::AnimationState::set_listener`)
+    /// are called on the thread updating their controller, and several controllers are updated at
+    /// the same time. Listeners are not required to be [`Send`] or [`Sync`], so the caller must
+    /// ensure that every listener on these controllers is safe to call from any thread and does
+    /// not share non thread safe state (such as an [`Rc`](`std::rc::Rc`) or a
+    /// [`Cell`](`std::cell::Cell`)) with listeners on other controllers in the slice.
+    pub unsafe fn update_all_parallel(
+        controllers: &mut [SkeletonController],
+        delta_seconds: f32,
+        physics: Physics,
+    ) {
+        controllers
+            .par_iter_mut()
+            .for_each(|controller| controller.update(delta_seconds, physics));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{controller::SkeletonController, test::TestAsset, Physics};
+
+    #[test]
+    fn update_all_parallel() {
+        let (skeleton_data, animation_state_data) = TestAsset::spineboy().instance_data(true);
+        let new_controller = || {
+            let mut controller =
+                SkeletonController::new(skeleton_data.clone(), animation_state_data.clone());
+            controller
+                .animation_state
+                .set_animation_by_name(0, "run", true)
+                .unwrap();
+            controller
+        };
+        let mut reference = new_controller();
+        let mut crowd: Vec<SkeletonController> = (0..32).map(|_| new_controller()).collect();
+        for _ in 0..10 {
+            reference.update(0.016, Physics::Update);
+            // SAFETY: no listeners are set on these controllers.
+            unsafe { SkeletonController::update_all_parallel(&mut crowd, 0.016, Physics::Update) };
+        }
+        let head = reference.skeleton.find_bone("head").unwrap();
+        for controller in &crowd {
+            let crowd_head = controller.skeleton.find_bone("head").unwrap();
+            assert_eq!(crowd_head.world_x(), head.world_x());
+            assert_eq!(crowd_head.world_y(), head.world_y());
+        }
+    }
+}
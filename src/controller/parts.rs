@@ -0,0 +1,395 @@
+use alloc::{
+    collections::{BTreeMap, BTreeSet},
+    sync::Arc,
+};
+use core::mem::take;
+
+use crate::prelude::*;
+use crate::{
+    color::Color,
+    draw::{
+        CombinedDrawer, IndexFormat, MeshBuffers, RenderRules, SimpleDrawer, VertexColorFormat,
+        VertexPositionFormat,
+    },
+    AnimationState, AnimationStateData, Physics, Skeleton, SkeletonClipping, SkeletonData,
+};
+
+#[cfg(feature = "profile")]
+use super::FrameStats;
+use super::{
+    changes::ChangeTracker, SkeletonCombinedRenderable, SkeletonController,
+    SkeletonControllerSettings, SkeletonRenderable,
+};
+
+/// The animated state of a skeleton, which is the part of a [`SkeletonController`] updated every
+/// frame.
+///
+/// Together with [`MeshGenerator`] and [`RenderOutput`], this splits a controller into parts
+/// which can be stored separately, such as in different component storages of an ECS, so
+/// updating and mesh generation can run as separate stages. See
+/// [`SkeletonController::into_parts`].
+#[derive(Debug)]
+pub struct SpineInstance {
+    pub skeleton: Skeleton,
+    pub animation_state: AnimationState,
+}
+
+impl SpineInstance {
+    /// Creates a new skeleton and animation state instance with the given data, in the setup
+    /// pose.
+    #[must_use]
+    pub fn new(
+        skeleton_data: Arc<SkeletonData>,
+        animation_state_data: Arc<AnimationStateData>,
+    ) -> Self {
+        let mut skeleton = Skeleton::new(skeleton_data);
+        skeleton.set_to_setup_pose();
+        skeleton.update_world_transform(Physics::Pose);
+        Self {
+            skeleton,
+            animation_state: AnimationState::new(animation_state_data),
+        }
+    }
+
+    /// Updates the animation state, applies to the skeleton, and updates world transforms.
+    pub fn update(&mut self, delta_seconds: f32, physics: Physics) {
+        self.animation_state.update(delta_seconds);
+        self.animation_state.apply(&mut self.skeleton);
+        self.skeleton.update(delta_seconds);
+        self.skeleton.update_world_transform(physics);
+    }
+}
+
+/// Generates renderables for a [`SpineInstance`], holding the clipping state, settings, and
+/// render rules used by a [`SkeletonController`].
+#[derive(Debug, Default)]
+pub struct MeshGenerator {
+    pub clipper: SkeletonClipping,
+    pub settings: SkeletonControllerSettings,
+    /// Rules overriding how attachments are rendered based on their names.
+    pub render_rules: RenderRules,
+    /// Cleared and refilled by every call to
+    /// [`combined_renderables`](`Self::combined_renderables`), so generating meshes every frame
+    /// doesn't reallocate them.
+    buffers: MeshBuffers,
+}
+
+impl MeshGenerator {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[must_use]
+    pub fn with_settings(self, settings: SkeletonControllerSettings) -> Self {
+        Self { settings, ..self }
+    }
+
+    /// Render a skeleton using the [`SimpleDrawer`], see [`SkeletonController::renderables`].
+    pub fn renderables(&mut self, skeleton: &mut Skeleton) -> Vec<SkeletonRenderable> {
+        renderables(
+            skeleton,
+            &mut self.clipper,
+            &self.settings,
+            &self.render_rules,
+        )
+    }
+
+    /// Render a skeleton using the [`CombinedDrawer`], see
+    /// [`SkeletonController::combined_renderables`].
+    pub fn combined_renderables(
+        &mut self,
+        skeleton: &mut Skeleton,
+    ) -> Vec<SkeletonCombinedRenderable> {
+        combined_renderables(
+            skeleton,
+            &mut self.clipper,
+            &self.settings,
+            &self.render_rules,
+            &mut self.buffers,
+        )
+    }
+
+    /// Render a skeleton using the [`CombinedDrawer`] into reusable buffers, see
+    /// [`SkeletonController::combined_renderables_into`].
+    pub fn combined_renderables_into(
+        &mut self,
+        skeleton: &mut Skeleton,
+        buffers: &mut MeshBuffers,
+    ) {
+        combined_renderables_into(
+            skeleton,
+            &mut self.clipper,
+            &self.settings,
+            &self.render_rules,
+            buffers,
+        );
+    }
+
+    /// Render a skeleton using the [`CombinedDrawer`] into `output`, replacing its previous
+    /// renderables.
+    pub fn generate(&mut self, skeleton: &mut Skeleton, output: &mut RenderOutput) {
+        output.renderables = self.combined_renderables(skeleton);
+    }
+}
+
+/// The renderables generated for a [`SpineInstance`] by a [`MeshGenerator`], stored between
+/// generating them and drawing them.
+#[derive(Debug, Clone, Default)]
+pub struct RenderOutput {
+    pub renderables: Vec<SkeletonCombinedRenderable>,
+}
+
+impl SkeletonController {
+    /// Creates a controller from the parts returned by [`into_parts`](`Self::into_parts`).
+    #[must_use]
+    pub fn from_parts(instance: SpineInstance, mesh_generator: MeshGenerator) -> Self {
+        Self {
+            skeleton: instance.skeleton,
+            animation_state: instance.animation_state,
+            clipper: mesh_generator.clipper,
+            settings: mesh_generator.settings,
+            render_rules: mesh_generator.render_rules,
+            hidden_slots: BTreeMap::new(),
+            time: 0.,
+            fixed_time_remainder: 0.,
+            warnings: vec![],
+            reported_warnings: BTreeSet::new(),
+            changes: ChangeTracker::default(),
+            bone_tags: BTreeMap::new(),
+            bone_overrides: BTreeMap::new(),
+            event_queue: false,
+            queued_events: vec![],
+            update_step: 0,
+            #[cfg(feature = "profile")]
+            frame_stats: FrameStats::default(),
+        }
+    }
+
+    /// Split the controller into its animated state and its mesh generation state, so they can
+    /// be stored and processed separately, such as in an ECS.
+    ///
+    /// ```
+    /// # #[path="../test.rs"]
+    /// # mod test;
+    /// # use rusty_spine::{controller::{RenderOutput, SkeletonController}, Physics};
+    /// # let (skeleton_data, animation_state_data) = test::TestAsset::spineboy().instance_data(true);
+    /// let controller = SkeletonController::new(skeleton_data, animation_state_data);
+    /// let (mut instance, mut mesh_generator) = controller.into_parts();
+    /// let mut output = RenderOutput::default();
+    ///
+    /// // animation stage
+    /// instance.update(0.016, Physics::Update);
+    /// // mesh generation stage
+    /// mesh_generator.generate(&mut instance.skeleton, &mut output);
+    /// // render stage
+    /// for renderable in &output.renderables {
+    ///     // draw `renderable`
+    /// }
+    /// ```
+    ///
+    /// The parts only cover updating and rendering. Hidden slots are shown again, and bone tags,
+    /// bone overrides, queued events, warnings, and change tracking are not kept.
+    #[must_use]
+    pub fn into_parts(mut self) -> (SpineInstance, MeshGenerator) {
+        self.show_hidden_slots();
+        (
+            SpineInstance {
+                skeleton: self.skeleton,
+                animation_state: self.animation_state,
+            },
+            MeshGenerator {
+                clipper: self.clipper,
+                settings: self.settings,
+                render_rules: self.render_rules,
+                buffers: MeshBuffers::new(),
+            },
+        )
+    }
+}
+
+pub(super) fn renderables(
+    skeleton: &mut Skeleton,
+    clipper: &mut SkeletonClipping,
+    settings: &SkeletonControllerSettings,
+    render_rules: &RenderRules,
+) -> Vec<SkeletonRenderable> {
+    let renderables = SimpleDrawer {
+        cull_direction: settings.cull_direction,
+        premultiplied_alpha: settings.premultiplied_alpha,
+        color_space: settings.color_space,
+    }
+    .draw_with_rules(skeleton, Some(clipper), render_rules);
+    let packed = settings.vertex_color_format == VertexColorFormat::PackedRgba8;
+    renderables
+        .into_iter()
+        .map(|mut renderable| SkeletonRenderable {
+            slot_index: renderable.slot_index,
+            vertices: take(&mut renderable.vertices),
+            uvs: take(&mut renderable.uvs),
+            indices: take(&mut renderable.indices),
+            packed_color: packed.then(|| renderable.color.to_rgba8()),
+            packed_dark_color: packed.then(|| renderable.dark_color.to_rgba8()),
+            color: renderable.color,
+            dark_color: renderable.dark_color,
+            blend_mode: renderable.blend_mode,
+            premultiplied_alpha: renderable.premultiplied_alpha,
+            tag: take(&mut renderable.tag),
+            attachment_renderer_object: renderable.attachment_renderer_object,
+        })
+        .collect()
+}
+
+pub(super) fn combined_renderables(
+    skeleton: &mut Skeleton,
+    clipper: &mut SkeletonClipping,
+    settings: &SkeletonControllerSettings,
+    render_rules: &RenderRules,
+    buffers: &mut MeshBuffers,
+) -> Vec<SkeletonCombinedRenderable> {
+    buffers.index_format = settings.index_format;
+    CombinedDrawer {
+        cull_direction: settings.cull_direction,
+        premultiplied_alpha: settings.premultiplied_alpha,
+        color_space: settings.color_space,
+    }
+    .draw_into(skeleton, Some(clipper), render_rules, buffers);
+    let packed = settings.vertex_color_format == VertexColorFormat::PackedRgba8;
+    let quantized = settings.vertex_position_format == VertexPositionFormat::Unorm16;
+    let pack = |colors: &[[f32; 4]]| {
+        colors
+            .iter()
+            .map(|&color| Color::from(color).to_rgba8())
+            .collect()
+    };
+    buffers
+        .batches
+        .iter()
+        .map(|batch| {
+            let vertices = batch.vertices.clone();
+            let (indices, indices_u32) = match batch.index_format {
+                IndexFormat::U16 => (buffers.indices[batch.indices.clone()].to_vec(), vec![]),
+                IndexFormat::U32 => (vec![], buffers.indices_u32[batch.indices.clone()].to_vec()),
+            };
+            let colors = &buffers.colors[vertices.clone()];
+            let dark_colors = &buffers.dark_colors[vertices.clone()];
+            let mut combined_renderable = SkeletonCombinedRenderable {
+                vertices: buffers.vertices[vertices.clone()].to_vec(),
+                uvs: buffers.uvs[vertices].to_vec(),
+                unorm_vertices: vec![],
+                unorm_uvs: vec![],
+                vertex_offset: [0., 0.],
+                vertex_scale: [0., 0.],
+                indices,
+                indices_u32,
+                index_format: batch.index_format,
+                packed_colors: if packed { pack(colors) } else { vec![] },
+                packed_dark_colors: if packed { pack(dark_colors) } else { vec![] },
+                colors: if packed { vec![] } else { colors.to_vec() },
+                dark_colors: if packed { vec![] } else { dark_colors.to_vec() },
+                blend_mode: batch.blend_mode,
+                premultiplied_alpha: batch.premultiplied_alpha,
+                tag: batch.tag.clone(),
+                attachment_renderer_object: batch.attachment_renderer_object,
+                first_slot_index: batch.first_slot_index,
+            };
+            if quantized {
+                combined_renderable.quantize();
+            }
+            combined_renderable
+        })
+        .collect()
+}
+
+pub(super) fn combined_renderables_into(
+    skeleton: &mut Skeleton,
+    clipper: &mut SkeletonClipping,
+    settings: &SkeletonControllerSettings,
+    render_rules: &RenderRules,
+    buffers: &mut MeshBuffers,
+) {
+    buffers.index_format = settings.index_format;
+    CombinedDrawer {
+        cull_direction: settings.cull_direction,
+        premultiplied_alpha: settings.premultiplied_alpha,
+        color_space: settings.color_space,
+    }
+    .draw_into(skeleton, Some(clipper), render_rules, buffers);
+    if settings.vertex_color_format == VertexColorFormat::PackedRgba8 {
+        let pack = |color: [f32; 4]| Color::from(color).to_rgba8();
+        buffers
+            .packed_colors
+            .extend(buffers.colors.drain(..).map(pack));
+        buffers
+            .packed_dark_colors
+            .extend(buffers.dark_colors.drain(..).map(pack));
+    }
+    if settings.vertex_position_format == VertexPositionFormat::Unorm16 {
+        for batch in &mut buffers.batches {
+            (batch.vertex_offset, batch.vertex_scale) = super::quantize(
+                &buffers.vertices[batch.vertices.clone()],
+                &buffers.uvs[batch.vertices.clone()],
+                &mut buffers.unorm_vertices,
+                &mut buffers.unorm_uvs,
+            );
+        }
+        buffers.vertices.clear();
+        buffers.uvs.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        controller::{MeshGenerator, RenderOutput, SkeletonController, SpineInstance},
+        test::TestAsset,
+        Physics,
+    };
+
+    #[test]
+    fn parts_match_controller() {
+        let (skeleton_data, animation_state_data) = TestAsset::spineboy().instance_data(true);
+        let mut controller =
+            SkeletonController::new(skeleton_data.clone(), animation_state_data.clone());
+        let mut instance = SpineInstance::new(skeleton_data, animation_state_data);
+        let mut mesh_generator = MeshGenerator::new();
+        for part in [
+            &mut controller.animation_state,
+            &mut instance.animation_state,
+        ] {
+            part.set_animation_by_name(0, "run", true).unwrap();
+        }
+        controller.update(0.25, Physics::Update);
+        instance.update(0.25, Physics::Update);
+
+        let mut output = RenderOutput::default();
+        for _ in 0..2 {
+            mesh_generator.generate(&mut instance.skeleton, &mut output);
+            let expected = controller.combined_renderables();
+            assert_eq!(output.renderables.len(), expected.len());
+            for (renderable, expected) in output.renderables.iter().zip(&expected) {
+                assert_eq!(renderable.indices, expected.indices);
+                // meshes are padded with unreferenced vertices, which may differ
+                for &index in &renderable.indices {
+                    let index = index as usize;
+                    assert_eq!(renderable.vertices[index], expected.vertices[index]);
+                    assert_eq!(renderable.colors[index], expected.colors[index]);
+                }
+            }
+            // the second frame reuses the mesh generator's buffers
+            controller.update(0.25, Physics::Update);
+            instance.update(0.25, Physics::Update);
+        }
+
+        controller.set_slot_visible("gun", false).unwrap();
+        let (instance, mesh_generator) = controller.into_parts();
+        assert!(instance
+            .skeleton
+            .find_slot("gun")
+            .unwrap()
+            .attachment()
+            .is_some());
+        let controller = SkeletonController::from_parts(instance, mesh_generator);
+        assert!(controller.is_slot_visible("gun"));
+    }
+}
@@ -0,0 +1,306 @@
+use alloc::sync::Arc;
+
+use crate::prelude::*;
+use crate::{AnimationStateData, Physics, SkeletonData};
+
+use super::SkeletonController;
+
+/// A bone's world transform as `[a, b, c, d, world_x, world_y]`, from
+/// [`Bone::a`](crate::Bone::a), [`Bone::b`](crate::Bone::b), [`Bone::c`](crate::Bone::c),
+/// [`Bone::d`](crate::Bone::d), [`Bone::world_x`](crate::Bone::world_x), and
+/// [`Bone::world_y`](crate::Bone::world_y). A local position `(x, y)` is transformed to world
+/// space as `(a * x + b * y + world_x, c * x + d * y + world_y)`.
+pub type BoneMatrix = [f32; 6];
+
+#[derive(Debug)]
+struct PooledInstance {
+    controller: SkeletonController,
+    time_offset: f32,
+    /// The part of `time_offset` the controller has already been advanced by.
+    applied_time_offset: f32,
+}
+
+/// Many instances of one skeleton, sharing the same [`SkeletonData`] and
+/// [`AnimationStateData`], updated together for crowds.
+///
+/// After each [`update`](`Self::update`), the world transform of every bone of every instance is
+/// available in one contiguous array from [`bone_matrices`](`Self::bone_matrices`), ready to be
+/// uploaded for instanced rendering with meshes from
+/// [`SkeletonController::skinned_mesh_data`].
+///
+/// ```
+/// # #[path="../test.rs"]
+/// # mod test;
+/// # use rusty_spine::{controller::SkeletonPool, Physics};
+/// # let (skeleton_data, animation_state_data) = test::TestAsset::spineboy().instance_data(true);
+/// let mut pool = SkeletonPool::new(skeleton_data, animation_state_data);
+/// for index in 0..100 {
+///     let instance = pool.spawn();
+///     pool.get_mut(instance)
+///         .unwrap()
+///         .animation_state
+///         .set_animation_by_name(0, "walk", true)?;
+///     pool.set_time_offset(instance, index as f32 * 0.1);
+/// }
+/// pool.update(0.016, Physics::Update);
+/// let bones_count = pool.bones_count();
+/// for (instance, bone_matrices) in pool.bone_matrices().chunks(bones_count).enumerate() {
+///     // upload `bone_matrices` for `instance`
+/// }
+/// # Ok::<(), rusty_spine::SpineError>(())
+/// ```
+///
+/// Instances are identified by their index, which shifts down for every instance after one that
+/// is [`remove`](`Self::remove`)d.
+#[derive(Debug)]
+pub struct SkeletonPool {
+    skeleton_data: Arc<SkeletonData>,
+    animation_state_data: Arc<AnimationStateData>,
+    instances: Vec<PooledInstance>,
+    bone_matrices: Vec<BoneMatrix>,
+}
+
+impl SkeletonPool {
+    #[must_use]
+    pub const fn new(
+        skeleton_data: Arc<SkeletonData>,
+        animation_state_data: Arc<AnimationStateData>,
+    ) -> Self {
+        Self {
+            skeleton_data,
+            animation_state_data,
+            instances: Vec::new(),
+            bone_matrices: Vec::new(),
+        }
+    }
+
+    /// The skeleton data shared by every instance.
+    #[must_use]
+    pub const fn skeleton_data(&self) -> &Arc<SkeletonData> {
+        &self.skeleton_data
+    }
+
+    /// The animation state data shared by every instance.
+    #[must_use]
+    pub const fn animation_state_data(&self) -> &Arc<AnimationStateData> {
+        &self.animation_state_data
+    }
+
+    /// Adds a new instance in the setup pose, returning its index.
+    pub fn spawn(&mut self) -> usize {
+        let controller = SkeletonController::new(
+            self.skeleton_data.clone(),
+            self.animation_state_data.clone(),
+        );
+        self.instances.push(PooledInstance {
+            controller,
+            time_offset: 0.,
+            applied_time_offset: 0.,
+        });
+        self.write_bone_matrices(self.instances.len() - 1);
+        self.instances.len() - 1
+    }
+
+    /// Removes an instance, returning its controller. The index of every following instance
+    /// decreases by one.
+    pub fn remove(&mut self, index: usize) -> Option<SkeletonController> {
+        if index >= self.instances.len() {
+            return None;
+        }
+        let bones_count = self.bones_count();
+        self.bone_matrices
+            .drain(index * bones_count..(index + 1) * bones_count);
+        Some(self.instances.remove(index).controller)
+    }
+
+    /// The number of instances.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.instances.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.instances.is_empty()
+    }
+
+    #[must_use]
+    pub fn get(&self, index: usize) -> Option<&SkeletonController> {
+        self.instances
+            .get(index)
+            .map(|instance| &instance.controller)
+    }
+
+    #[must_use]
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut SkeletonController> {
+        self.instances
+            .get_mut(index)
+            .map(|instance| &mut instance.controller)
+    }
+
+    /// An iterator over every instance, in index order.
+    pub fn iter(&self) -> impl Iterator<Item = &SkeletonController> {
+        self.instances.iter().map(|instance| &instance.controller)
+    }
+
+    /// A mutable iterator over every instance, in index order.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut SkeletonController> {
+        self.instances
+            .iter_mut()
+            .map(|instance| &mut instance.controller)
+    }
+
+    /// Play an instance `time_offset` seconds ahead of the others, so crowds don't animate in
+    /// sync. The offset is applied on the next [`update`](`Self::update`), and increasing it later
+    /// moves the instance ahead by the difference. Returns `false` if the instance does not exist.
+    ///
+    /// Animations can't be updated backwards, so negative offsets are treated as 0 and
+    /// decreasing an offset doesn't move the instance back.
+    pub fn set_time_offset(&mut self, index: usize, time_offset: f32) -> bool {
+        if let Some(instance) = self.instances.get_mut(index) {
+            instance.time_offset = time_offset.max(0.);
+            true
+        } else {
+            false
+        }
+    }
+
+    /// The time offset of an instance, see [`set_time_offset`](`Self::set_time_offset`).
+    #[must_use]
+    pub fn time_offset(&self, index: usize) -> Option<f32> {
+        self.instances
+            .get(index)
+            .map(|instance| instance.time_offset)
+    }
+
+    /// Updates every instance, then samples their bone matrices, see
+    /// [`SkeletonController::update`].
+    pub fn update(&mut self, delta_seconds: f32, physics: Physics) {
+        for instance in &mut self.instances {
+            let time_offset = (instance.time_offset - instance.applied_time_offset).max(0.);
+            instance.applied_time_offset = instance.applied_time_offset.max(instance.time_offset);
+            instance
+                .controller
+                .update(delta_seconds + time_offset, physics);
+        }
+        for index in 0..self.instances.len() {
+            self.write_bone_matrices(index);
+        }
+    }
+
+    /// The number of bones in each instance, which is the length of each instance's range in
+    /// [`bone_matrices`](`Self::bone_matrices`).
+    #[must_use]
+    pub fn bones_count(&self) -> usize {
+        self.skeleton_data.bones_count()
+    }
+
+    /// The world transform of every bone of every instance as of the last update. The matrices
+    /// of the instance at `index` are at `index * bones_count..(index + 1) * bones_count`, in the
+    /// same order as [`Skeleton::bones`](`crate::Skeleton::bones`). See
+    /// [`bones_count`](`Self::bones_count`).
+    #[must_use]
+    pub fn bone_matrices(&self) -> &[BoneMatrix] {
+        &self.bone_matrices
+    }
+
+    /// The bone matrices of one instance, see [`bone_matrices`](`Self::bone_matrices`).
+    #[must_use]
+    pub fn instance_bone_matrices(&self, index: usize) -> Option<&[BoneMatrix]> {
+        let bones_count = self.bones_count();
+        self.bone_matrices
+            .get(index * bones_count..(index + 1) * bones_count)
+    }
+
+    fn write_bone_matrices(&mut self, index: usize) {
+        let bones_count = self.bones_count();
+        self.bone_matrices
+            .resize(self.instances.len() * bones_count, [0.; 6]);
+        let matrices = &mut self.bone_matrices[index * bones_count..(index + 1) * bones_count];
+        for (matrix, bone) in matrices
+            .iter_mut()
+            .zip(self.instances[index].controller.skeleton.bones())
+        {
+            *matrix = [
+                bone.a(),
+                bone.b(),
+                bone.c(),
+                bone.d(),
+                bone.world_x(),
+                bone.world_y(),
+            ];
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{controller::SkeletonPool, test::TestAsset, Physics};
+
+    #[test]
+    fn pool() {
+        let (skeleton_data, animation_state_data) = TestAsset::spineboy().instance_data(true);
+        let mut pool = SkeletonPool::new(skeleton_data, animation_state_data);
+        for _ in 0..3 {
+            let index = pool.spawn();
+            pool.get_mut(index)
+                .unwrap()
+                .animation_state
+                .set_animation_by_name(0, "walk", true)
+                .unwrap();
+        }
+        assert!(pool.set_time_offset(2, 0.25));
+        assert!(!pool.set_time_offset(3, 0.25));
+        let bones_count = pool.bones_count();
+        assert_eq!(pool.bone_matrices().len(), 3 * bones_count);
+
+        pool.update(0.1, Physics::Update);
+        let track_time = |pool: &SkeletonPool, index: usize| {
+            pool.get(index)
+                .unwrap()
+                .animation_state
+                .track_at_index(0)
+                .unwrap()
+                .track_time()
+        };
+        assert!((track_time(&pool, 0) - 0.1).abs() < 0.0001);
+        assert!((track_time(&pool, 2) - 0.35).abs() < 0.0001);
+        pool.update(0.1, Physics::Update);
+        assert!((track_time(&pool, 2) - 0.45).abs() < 0.0001);
+        // lowering and raising the offset again doesn't reapply it
+        pool.set_time_offset(2, 0.1);
+        pool.update(0.1, Physics::Update);
+        pool.set_time_offset(2, 0.25);
+        pool.update(0.1, Physics::Update);
+        assert!((track_time(&pool, 2) - 0.65).abs() < 0.0001);
+
+        let head_index = pool.skeleton_data().find_bone("head").unwrap().index();
+        let head = pool
+            .get(2)
+            .unwrap()
+            .skeleton
+            .bones()
+            .nth(head_index)
+            .unwrap();
+        let matrix = pool.instance_bone_matrices(2).unwrap()[head_index];
+        assert_eq!(
+            matrix,
+            [
+                head.a(),
+                head.b(),
+                head.c(),
+                head.d(),
+                head.world_x(),
+                head.world_y()
+            ]
+        );
+        assert_eq!(pool.bone_matrices()[2 * bones_count + head_index], matrix);
+        assert_ne!(pool.instance_bone_matrices(0).unwrap()[head_index], matrix);
+
+        assert!(pool.remove(0).is_some());
+        assert_eq!(pool.len(), 2);
+        assert_eq!(pool.bone_matrices().len(), 2 * bones_count);
+        assert_eq!(pool.instance_bone_matrices(1).unwrap()[head_index], matrix);
+        assert!(pool.instance_bone_matrices(2).is_none());
+    }
+}
@@ -0,0 +1,181 @@
+use core::time::Duration;
+#[cfg(not(target_arch = "wasm32"))]
+use std::time::Instant;
+
+use crate::draw::MeshBatch;
+
+use super::{SkeletonCombinedRenderable, SkeletonController, SkeletonRenderable};
+
+/// Time spent and geometry produced by a [`SkeletonController`] since its stats were last taken,
+/// for in-game profilers. See [`SkeletonController::take_frame_stats`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct FrameStats {
+    /// The number of calls to [`SkeletonController::update`].
+    pub updates: u32,
+    /// Time spent updating the animation state and applying it to the skeleton.
+    pub animation_apply: Duration,
+    /// Time spent updating the skeleton's world transforms, including physics.
+    pub world_transform: Duration,
+    /// Time spent clipping attachments while generating render data.
+    pub clipping: Duration,
+    /// Time spent generating render data, excluding [`clipping`](`Self::clipping`).
+    pub render_data: Duration,
+    /// The number of vertices in the render data generated.
+    pub vertices: usize,
+    /// The number of triangles in the render data generated.
+    pub triangles: usize,
+}
+
+impl SkeletonController {
+    /// The stats collected since they were last taken with
+    /// [`take_frame_stats`](`Self::take_frame_stats`).
+    #[must_use]
+    pub const fn frame_stats(&self) -> FrameStats {
+        self.frame_stats
+    }
+
+    /// Return and reset the stats, usually once per frame.
+    ///
+    /// ```
+    /// # #[path="../test.rs"]
+    /// # mod test;
+    /// # use rusty_spine::{controller::SkeletonController, Physics};
+    /// # let (skeleton_data, animation_state_data) = test::TestAsset::spineboy().instance_data(true);
+    /// let mut controller = SkeletonController::new(skeleton_data, animation_state_data);
+    /// controller.update(1. / 60., Physics::Update);
+    /// let renderables = controller.combined_renderables();
+    /// let frame_stats = controller.take_frame_stats();
+    /// println!(
+    ///     "{} triangles in {:?}",
+    ///     frame_stats.triangles,
+    ///     frame_stats.animation_apply
+    ///         + frame_stats.world_transform
+    ///         + frame_stats.clipping
+    ///         + frame_stats.render_data
+    /// );
+    /// ```
+    ///
+    /// Timing uses `std::time::Instant`, which is not supported on `wasm32`, so only the counts
+    /// are collected there and the durations stay zero.
+    pub fn take_frame_stats(&mut self) -> FrameStats {
+        core::mem::take(&mut self.frame_stats)
+    }
+
+    /// Adds the render data generated since `start` to the stats.
+    pub(super) fn profile_render_data<R: ProfiledRenderable>(
+        &mut self,
+        start: Stopwatch,
+        renderables: &[R],
+    ) {
+        let clipping = self.clipper.take_clipping_time();
+        self.frame_stats.clipping += clipping;
+        self.frame_stats.render_data += start.elapsed().saturating_sub(clipping);
+        for renderable in renderables {
+            let (vertices, indices) = renderable.geometry();
+            self.frame_stats.vertices += vertices;
+            self.frame_stats.triangles += indices / 3;
+        }
+    }
+}
+
+/// Measures the time since it was started, which is always zero on `wasm32`, where
+/// `std::time::Instant` panics.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Stopwatch {
+    #[cfg(not(target_arch = "wasm32"))]
+    start: Instant,
+}
+
+impl Stopwatch {
+    pub(crate) fn start() -> Self {
+        Self {
+            #[cfg(not(target_arch = "wasm32"))]
+            start: Instant::now(),
+        }
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    pub(crate) fn elapsed(self) -> Duration {
+        self.start.elapsed()
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    pub(crate) fn elapsed(self) -> Duration {
+        Duration::ZERO
+    }
+}
+
+/// Renderables counted by [`SkeletonController::profile_render_data`].
+pub(super) trait ProfiledRenderable {
+    /// The number of vertices and indices.
+    fn geometry(&self) -> (usize, usize);
+}
+
+impl ProfiledRenderable for SkeletonRenderable {
+    fn geometry(&self) -> (usize, usize) {
+        (self.vertices.len(), self.indices.len())
+    }
+}
+
+impl ProfiledRenderable for SkeletonCombinedRenderable {
+    fn geometry(&self) -> (usize, usize) {
+        (
+            self.vertices.len(),
+            self.indices.len() + self.indices_u32.len(),
+        )
+    }
+}
+
+impl ProfiledRenderable for MeshBatch {
+    fn geometry(&self) -> (usize, usize) {
+        (self.vertices.len(), self.indices.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{controller::SkeletonController, test::TestAsset, Physics};
+
+    #[test]
+    fn frame_stats() {
+        let (skeleton_data, animation_state_data) = TestAsset::spineboy().instance_data(true);
+        let mut controller = SkeletonController::new(skeleton_data, animation_state_data);
+        controller
+            .animation_state
+            .set_animation_by_name(0, "portal", false)
+            .unwrap();
+        controller.update(0.5, Physics::Update);
+        controller.update(0.5, Physics::Update);
+        let renderables = controller.renderables();
+        let frame_stats = controller.frame_stats();
+        assert_eq!(frame_stats.updates, 2);
+        assert!(!frame_stats.animation_apply.is_zero());
+        assert!(!frame_stats.world_transform.is_zero());
+        assert!(!frame_stats.clipping.is_zero());
+        assert_eq!(
+            frame_stats.vertices,
+            renderables
+                .iter()
+                .map(|renderable| renderable.vertices.len())
+                .sum::<usize>()
+        );
+        assert_eq!(
+            frame_stats.triangles,
+            renderables
+                .iter()
+                .map(|renderable| renderable.indices.len() / 3)
+                .sum::<usize>()
+        );
+
+        assert_eq!(controller.take_frame_stats(), frame_stats);
+        assert_eq!(controller.frame_stats().updates, 0);
+        let combined_renderables = controller.combined_renderables();
+        assert_eq!(
+            controller.take_frame_stats().vertices,
+            combined_renderables
+                .iter()
+                .map(|renderable| renderable.vertices.len())
+                .sum::<usize>()
+        );
+    }
+}
@@ -0,0 +1,256 @@
+use alloc::collections::BTreeMap;
+use core::time::Duration;
+#[cfg(all(feature = "std", not(target_arch = "wasm32")))]
+use std::time::Instant;
+
+use crate::Physics;
+
+use super::SkeletonController;
+
+/// How much work an [`UpdateScheduler`] may do per frame.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UpdateBudget {
+    /// Update at most this many controllers per frame.
+    Count(usize),
+    /// Keep updating controllers until this much time has been spent in the frame. Not supported
+    /// on `wasm32`, where `std::time::Instant` panics, or without the `std` feature, in which case
+    /// one controller is updated per frame.
+    Time(Duration),
+}
+
+/// Identifies a controller registered with an [`UpdateScheduler`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ScheduledControllerId(usize);
+
+#[derive(Debug)]
+struct ScheduledController {
+    controller: SkeletonController,
+    /// Time which has passed since this controller was last updated.
+    pending_seconds: f32,
+}
+
+/// Spreads the updates of many controllers across frames within a per-frame budget, so large
+/// numbers of background characters don't cause frame time spikes.
+///
+/// ```
+/// # #[path="../test.rs"]
+/// # mod test;
+/// # use rusty_spine::{controller::{SkeletonController, UpdateBudget, UpdateScheduler}, Physics};
+/// # let (skeleton_data, animation_state_data) = test::TestAsset::spineboy().instance_data(true);
+/// let mut scheduler = UpdateScheduler::new(UpdateBudget::Count(10));
+/// let ids: Vec<_> = (0..100)
+///     .map(|_| {
+///         scheduler.register(SkeletonController::new(
+///             skeleton_data.clone(),
+///             animation_state_data.clone(),
+///         ))
+///     })
+///     .collect();
+/// // Each frame, only 10 of the 100 controllers are updated.
+/// scheduler.update(0.016, Physics::Update);
+/// for id in &ids {
+///     let renderables = scheduler.get_mut(*id).unwrap().combined_renderables();
+/// }
+/// ```
+///
+/// Controllers are updated in turn, and each is updated by all of the time which passed since
+/// its last update, so animations play at the correct speed, just at a lower rate. At least one
+/// controller is updated every frame.
+#[derive(Debug)]
+pub struct UpdateScheduler {
+    pub budget: UpdateBudget,
+    controllers: BTreeMap<usize, ScheduledController>,
+    next_id: usize,
+    /// The id of the next controller to update.
+    cursor: usize,
+}
+
+impl UpdateScheduler {
+    #[must_use]
+    pub const fn new(budget: UpdateBudget) -> Self {
+        Self {
+            budget,
+            controllers: BTreeMap::new(),
+            next_id: 0,
+            cursor: 0,
+        }
+    }
+
+    /// Adds a controller to be updated by this scheduler.
+    pub fn register(&mut self, controller: SkeletonController) -> ScheduledControllerId {
+        let id = self.next_id;
+        self.next_id += 1;
+        self.controllers.insert(
+            id,
+            ScheduledController {
+                controller,
+                pending_seconds: 0.,
+            },
+        );
+        ScheduledControllerId(id)
+    }
+
+    /// Removes a controller from this scheduler, returning it if it was registered. Any pending
+    /// time is not applied.
+    pub fn unregister(&mut self, id: ScheduledControllerId) -> Option<SkeletonController> {
+        self.controllers
+            .remove(&id.0)
+            .map(|scheduled| scheduled.controller)
+    }
+
+    #[must_use]
+    pub fn get(&self, id: ScheduledControllerId) -> Option<&SkeletonController> {
+        self.controllers
+            .get(&id.0)
+            .map(|scheduled| &scheduled.controller)
+    }
+
+    #[must_use]
+    pub fn get_mut(&mut self, id: ScheduledControllerId) -> Option<&mut SkeletonController> {
+        self.controllers
+            .get_mut(&id.0)
+            .map(|scheduled| &mut scheduled.controller)
+    }
+
+    /// Iterates over all registered controllers, in the order they were registered.
+    pub fn iter(&self) -> impl Iterator<Item = (ScheduledControllerId, &SkeletonController)> {
+        self.controllers
+            .iter()
+            .map(|(id, scheduled)| (ScheduledControllerId(*id), &scheduled.controller))
+    }
+
+    /// Iterates mutably over all registered controllers, in the order they were registered.
+    pub fn iter_mut(
+        &mut self,
+    ) -> impl Iterator<Item = (ScheduledControllerId, &mut SkeletonController)> {
+        self.controllers
+            .iter_mut()
+            .map(|(id, scheduled)| (ScheduledControllerId(*id), &mut scheduled.controller))
+    }
+
+    /// The number of registered controllers.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.controllers.len()
+    }
+
+    /// Returns `true` if no controllers are registered.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.controllers.is_empty()
+    }
+
+    /// The time which has passed since the controller was last updated, which will be applied
+    /// the next time it is updated.
+    #[must_use]
+    pub fn pending_seconds(&self, id: ScheduledControllerId) -> Option<f32> {
+        self.controllers
+            .get(&id.0)
+            .map(|scheduled| scheduled.pending_seconds)
+    }
+
+    /// Advances every controller's clock by `delta_seconds`, then updates as many controllers as
+    /// the budget allows. Returns the number of controllers updated.
+    pub fn update(&mut self, delta_seconds: f32, physics: Physics) -> usize {
+        for scheduled in self.controllers.values_mut() {
+            scheduled.pending_seconds += delta_seconds;
+        }
+        #[cfg(all(feature = "std", not(target_arch = "wasm32")))]
+        let start = match self.budget {
+            UpdateBudget::Time(_) => Some(Instant::now()),
+            UpdateBudget::Count(_) => None,
+        };
+        let mut updated = 0;
+        while updated < self.controllers.len() {
+            let within_budget = match self.budget {
+                UpdateBudget::Count(count) => updated < count,
+                #[cfg(all(feature = "std", not(target_arch = "wasm32")))]
+                UpdateBudget::Time(duration) => {
+                    start.is_some_and(|start| start.elapsed() < duration)
+                }
+                #[cfg(any(not(feature = "std"), target_arch = "wasm32"))]
+                UpdateBudget::Time(_) => false,
+            };
+            if updated > 0 && !within_budget {
+                break;
+            }
+            let Some(id) = self
+                .controllers
+                .range(self.cursor..)
+                .chain(self.controllers.iter())
+                .map(|(id, _)| *id)
+                .next()
+            else {
+                break;
+            };
+            let Some(scheduled) = self.controllers.get_mut(&id) else {
+                break;
+            };
+            scheduled
+                .controller
+                .update(scheduled.pending_seconds, physics);
+            scheduled.pending_seconds = 0.;
+            self.cursor = id + 1;
+            updated += 1;
+        }
+        updated
+    }
+
+    /// Immediately updates every controller with pending time, regardless of the budget.
+    pub fn flush(&mut self, physics: Physics) {
+        for scheduled in self.controllers.values_mut() {
+            if scheduled.pending_seconds > 0. {
+                scheduled
+                    .controller
+                    .update(scheduled.pending_seconds, physics);
+                scheduled.pending_seconds = 0.;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        controller::{SkeletonController, UpdateBudget, UpdateScheduler},
+        test::TestAsset,
+        Physics,
+    };
+
+    #[test]
+    fn update_scheduler() {
+        let (skeleton_data, animation_state_data) = TestAsset::spineboy().instance_data(true);
+        let mut scheduler = UpdateScheduler::new(UpdateBudget::Count(2));
+        let ids: Vec<_> = (0..5)
+            .map(|_| {
+                scheduler.register(SkeletonController::new(
+                    skeleton_data.clone(),
+                    animation_state_data.clone(),
+                ))
+            })
+            .collect();
+        assert_eq!(scheduler.update(0.1, Physics::Update), 2);
+        assert_eq!(scheduler.update(0.1, Physics::Update), 2);
+        assert_eq!(scheduler.update(0.1, Physics::Update), 2);
+        let times: Vec<f32> = ids
+            .iter()
+            .map(|id| scheduler.get(*id).unwrap().time())
+            .collect();
+        let expected = [0.3, 0.1, 0.2, 0.2, 0.3];
+        for (time, expected) in times.iter().zip(expected) {
+            assert!((time - expected).abs() < 0.0001, "{times:?}");
+        }
+        for id in &ids {
+            let total =
+                scheduler.get(*id).unwrap().time() + scheduler.pending_seconds(*id).unwrap();
+            assert!((total - 0.3).abs() < 0.0001);
+        }
+
+        assert!(scheduler.unregister(ids[4]).is_some());
+        scheduler.flush(Physics::Update);
+        for id in &ids[..4] {
+            assert_eq!(scheduler.pending_seconds(*id), Some(0.));
+        }
+        assert_eq!(scheduler.len(), 4);
+    }
+}
@@ -0,0 +1,274 @@
+use crate::prelude::*;
+use crate::{
+    c::{c_void, spAtlasRegion},
+    color::Color,
+    BlendMode, Skeleton,
+};
+
+use super::SkeletonController;
+
+/// The maximum amount of bone influences stored per [`SkinnedVertex`]. Vertices weighted to more
+/// bones keep only their strongest influences, renormalized, as with
+/// [`MeshAttachment::skin_influences_packed`](crate::MeshAttachment::skin_influences_packed).
+pub const MAX_SKINNED_INFLUENCES: usize = 4;
+
+/// A vertex of a [`SkinnedMesh`].
+///
+/// The world position of the vertex is the weighted sum of each influence's local position
+/// transformed by its bone's world matrix:
+///
+/// ```text
+/// world = sum(bone_weights[i] * (bone_matrix[bone_indices[i]] * bone_positions[i]))
+/// ```
+///
+/// Where a bone matrix is built from [`Bone::a`](crate::Bone::a), [`Bone::b`](crate::Bone::b),
+/// [`Bone::c`](crate::Bone::c), [`Bone::d`](crate::Bone::d),
+/// [`Bone::world_x`](crate::Bone::world_x), and [`Bone::world_y`](crate::Bone::world_y).
+/// [`Skeleton::bone_world_matrices`] fills a buffer with every bone matrix.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct SkinnedVertex {
+    /// The texture coordinates of this vertex.
+    pub uv: [f32; 2],
+    /// Indices into [`Skeleton::bones`] of the bones influencing this vertex.
+    pub bone_indices: [u16; MAX_SKINNED_INFLUENCES],
+    /// The weight of each influence. Weights sum to 1, unused influences have a weight of 0.
+    pub bone_weights: [f32; MAX_SKINNED_INFLUENCES],
+    /// The position of this vertex in the local space of each influencing bone.
+    pub bone_positions: [[f32; 2]; MAX_SKINNED_INFLUENCES],
+}
+
+/// Static mesh data for a single attachment, ready to be uploaded once and skinned on the GPU
+/// using bone matrices.
+#[derive(Debug, Clone)]
+pub struct SkinnedMesh {
+    /// The index of the slot in [`Skeleton::slots`] this attachment is attached to.
+    pub slot_index: usize,
+    /// The name of the attachment.
+    pub attachment_name: String,
+    /// The vertices of the mesh.
+    pub vertices: Vec<SkinnedVertex>,
+    /// Triangle indices into `vertices`.
+    pub indices: Vec<u16>,
+    /// The attachment's color. The slot and skeleton colors are animated, so they are not included.
+    pub color: Color,
+    /// The blend mode to use when drawing this mesh.
+    pub blend_mode: BlendMode,
+    /// The attachment's renderer object as a raw pointer. Usually represents the texture created
+    /// from [`extension::set_create_texture_cb`](crate::extension::set_create_texture_cb).
+    pub attachment_renderer_object: Option<*const c_void>,
+}
+
+impl SkeletonController {
+    /// Extracts static mesh data for every region and mesh attachment currently attached to the
+    /// skeleton, in draw order, for GPU skinning.
+    ///
+    /// ```
+    /// # #[path="../test.rs"]
+    /// # mod test;
+    /// # use rusty_spine::controller::SkeletonController;
+    /// # let (skeleton_data, animation_state_data) = test::TestAsset::spineboy().instance_data(true);
+    /// let controller = SkeletonController::new(skeleton_data, animation_state_data);
+    /// for skinned_mesh in controller.skinned_mesh_data() {
+    ///     // upload `skinned_mesh.vertices` and `skinned_mesh.indices` once
+    /// }
+    /// let mut bone_matrices = vec![[0.; 16]; controller.skeleton.bones_count()];
+    /// // every frame
+    /// controller.skeleton.bone_world_matrices(&mut bone_matrices);
+    /// // upload `bone_matrices`
+    /// ```
+    ///
+    /// The mesh data is in bind pose and does not include deform (free-form deformation) keys, so
+    /// it only needs to be recreated when attachments change. Clipping attachments are ignored.
+    #[must_use]
+    pub fn skinned_mesh_data(&self) -> Vec<SkinnedMesh> {
+        skinned_meshes(&self.skeleton)
+    }
+}
+
+fn skinned_meshes(skeleton: &Skeleton) -> Vec<SkinnedMesh> {
+    let mut skinned_meshes = vec![];
+    for slot in skeleton.draw_order() {
+        let Some(attachment) = slot.attachment() else {
+            continue;
+        };
+        let slot_index = slot.data().index();
+        let slot_bone_index = slot.bone().data().index() as u16;
+        let blend_mode = slot.data().blend_mode();
+        if let Some(region_attachment) = attachment.as_region() {
+            let offset = region_attachment.offset();
+            let uvs = region_attachment.uvs();
+            let vertices = (0..4)
+                .map(|i| {
+                    let mut vertex = SkinnedVertex {
+                        uv: [uvs[i * 2], uvs[i * 2 + 1]],
+                        ..Default::default()
+                    };
+                    vertex.bone_indices[0] = slot_bone_index;
+                    vertex.bone_weights[0] = 1.;
+                    vertex.bone_positions[0] = [offset[i * 2], offset[i * 2 + 1]];
+                    vertex
+                })
+                .collect();
+            skinned_meshes.push(SkinnedMesh {
+                slot_index,
+                attachment_name: attachment.name().to_owned(),
+                vertices,
+                indices: vec![0, 1, 2, 2, 3, 0],
+                color: region_attachment.color(),
+                blend_mode,
+                attachment_renderer_object: page_renderer_object(unsafe {
+                    region_attachment.c_ptr_ref().rendererObject
+                }),
+            });
+        } else if let Some(mesh_attachment) = attachment.as_mesh() {
+            let uvs = mesh_attachment.uvs_slice();
+            let local_vertices = mesh_attachment.vertices();
+            let vertices = if mesh_attachment.bones().is_empty() {
+                local_vertices
+                    .chunks_exact(2)
+                    .enumerate()
+                    .map(|(vertex_index, position)| {
+                        let mut vertex = SkinnedVertex {
+                            uv: vertex_uv(uvs, vertex_index),
+                            ..Default::default()
+                        };
+                        vertex.bone_indices[0] = slot_bone_index;
+                        vertex.bone_weights[0] = 1.;
+                        vertex.bone_positions[0] = [position[0], position[1]];
+                        vertex
+                    })
+                    .collect()
+            } else {
+                let influences = mesh_attachment.skin_influences_packed(MAX_SKINNED_INFLUENCES);
+                influences
+                    .bone_indices
+                    .chunks_exact(MAX_SKINNED_INFLUENCES)
+                    .zip(influences.weights.chunks_exact(MAX_SKINNED_INFLUENCES))
+                    .zip(influences.positions.chunks_exact(MAX_SKINNED_INFLUENCES))
+                    .enumerate()
+                    .map(
+                        |(vertex_index, ((bone_indices, bone_weights), bone_positions))| {
+                            let mut vertex = SkinnedVertex {
+                                uv: vertex_uv(uvs, vertex_index),
+                                ..Default::default()
+                            };
+                            vertex.bone_indices.copy_from_slice(bone_indices);
+                            vertex.bone_weights.copy_from_slice(bone_weights);
+                            vertex.bone_positions.copy_from_slice(bone_positions);
+                            vertex
+                        },
+                    )
+                    .collect()
+            };
+            let indices = mesh_attachment.triangles_slice().to_vec();
+            skinned_meshes.push(SkinnedMesh {
+                slot_index,
+                attachment_name: attachment.name().to_owned(),
+                vertices,
+                indices,
+                color: mesh_attachment.color(),
+                blend_mode,
+                attachment_renderer_object: page_renderer_object(unsafe {
+                    mesh_attachment.c_ptr_ref().rendererObject
+                }),
+            });
+        }
+    }
+    skinned_meshes
+}
+
+fn vertex_uv(uvs: &[f32], vertex_index: usize) -> [f32; 2] {
+    uvs.get(vertex_index * 2..vertex_index * 2 + 2)
+        .map_or([0., 0.], |uv| [uv[0], uv[1]])
+}
+
+/// The atlas page renderer object for an attachment renderer object created by the default atlas
+/// attachment loader.
+fn page_renderer_object(attachment_renderer_object: *mut c_void) -> Option<*const c_void> {
+    let atlas_region = attachment_renderer_object.cast::<spAtlasRegion>();
+    if atlas_region.is_null() {
+        return None;
+    }
+    let page = unsafe { (*atlas_region).page };
+    if page.is_null() {
+        return None;
+    }
+    let renderer_object = unsafe { (*page).rendererObject };
+    if renderer_object.is_null() {
+        None
+    } else {
+        Some(renderer_object.cast_const())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{controller::SkeletonController, test::TestAsset};
+
+    #[test]
+    fn skinned_mesh_weights_normalized() {
+        for asset in TestAsset::all() {
+            let (skeleton_data, animation_state_data) = asset.instance_data(true);
+            let controller = SkeletonController::new(skeleton_data, animation_state_data);
+            let bones_count = controller.skeleton.bones_count();
+            for skinned_mesh in controller.skinned_mesh_data() {
+                for vertex in &skinned_mesh.vertices {
+                    let total: f32 = vertex.bone_weights.iter().sum();
+                    assert!((total - 1.).abs() < 0.001);
+                    assert!(vertex
+                        .bone_indices
+                        .iter()
+                        .all(|bone_index| (*bone_index as usize) < bones_count));
+                }
+                assert!(skinned_mesh
+                    .indices
+                    .iter()
+                    .all(|index| (*index as usize) < skinned_mesh.vertices.len()));
+            }
+        }
+    }
+
+    #[test]
+    fn skinned_mesh_influences() {
+        let (skeleton_data, animation_state_data) = TestAsset::spineboy().instance_data(true);
+        let controller = SkeletonController::new(skeleton_data, animation_state_data);
+        let skinned_meshes = controller.skinned_mesh_data();
+        let eye = skinned_meshes
+            .iter()
+            .find(|skinned_mesh| skinned_mesh.attachment_name == "eye-indifferent")
+            .unwrap();
+        assert_eq!(eye.vertices.len(), 4);
+
+        // the first vertex of the weighted mesh, as exported in spineboy-pro.json
+        let vertex = &eye.vertices[0];
+        let expected = [
+            ("head-control", [-36.8, -91.35], 0.3),
+            ("head", [73.41, -91.35], 0.7),
+        ];
+        for (bone_name, position, weight) in expected {
+            let bone_index = controller
+                .skeleton
+                .find_bone(bone_name)
+                .unwrap()
+                .data()
+                .index() as u16;
+            let influence = vertex
+                .bone_indices
+                .iter()
+                .zip(vertex.bone_weights)
+                .position(|(index, weight)| *index == bone_index && weight > 0.)
+                .unwrap();
+            assert!((vertex.bone_weights[influence] - weight).abs() < 0.001);
+            assert!((vertex.bone_positions[influence][0] - position[0]).abs() < 0.01);
+            assert!((vertex.bone_positions[influence][1] - position[1]).abs() < 0.01);
+        }
+        assert_eq!(
+            vertex
+                .bone_weights
+                .iter()
+                .filter(|weight| **weight > 0.)
+                .count(),
+            2
+        );
+    }
+}
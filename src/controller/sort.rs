@@ -0,0 +1,68 @@
+use core::cmp::Ordering;
+
+use crate::prelude::*;
+use crate::{attachment::Attachment, c::c_void, slot::Slot};
+
+use super::{SkeletonController, SkeletonRenderable};
+
+impl SkeletonController {
+    /// Render the skeleton using the [`SimpleDrawer`](`crate::draw::SimpleDrawer`) and return the
+    /// renderables sorted by a custom key, computed once per renderable from its slot, attachment,
+    /// and attachment renderer object (usually the atlas page texture).
+    ///
+    /// This allows isometric or top-down games to interleave parts of a skeleton with other world
+    /// sprites, for instance by sorting on the Y position of each part's bone:
+    ///
+    /// ```
+    /// # #[path="../test.rs"]
+    /// # mod test;
+    /// # use rusty_spine::{controller::SkeletonController, Physics};
+    /// # let (skeleton_data, animation_state_data) = test::TestAsset::spineboy().instance_data(true);
+    /// let mut controller = SkeletonController::new(skeleton_data, animation_state_data);
+    /// controller.update(0.016, Physics::Update);
+    /// for (depth, renderable) in controller.sorted_renderables(|slot, _, _| -slot.bone().world_y()) {
+    ///     // submit `renderable` to the world sprite batch at `depth`
+    /// }
+    /// ```
+    ///
+    /// The sort is stable, so renderables with equal keys keep the skeleton's draw order. Keys
+    /// which cannot be compared (such as `NaN`) are treated as equal.
+    pub fn sorted_renderables<K, F>(&mut self, mut sort_key: F) -> Vec<(K, SkeletonRenderable)>
+    where
+        K: PartialOrd,
+        F: FnMut(&Slot, &Attachment, Option<*const c_void>) -> K,
+    {
+        let renderables = self.renderables();
+        let mut sorted_renderables = Vec::with_capacity(renderables.len());
+        for renderable in renderables {
+            let Some(slot) = self.skeleton.draw_order_at_index(renderable.slot_index) else {
+                continue;
+            };
+            let Some(attachment) = slot.attachment() else {
+                continue;
+            };
+            let key = sort_key(&slot, &attachment, renderable.attachment_renderer_object);
+            sorted_renderables.push((key, renderable));
+        }
+        sorted_renderables.sort_by(|(a, _), (b, _)| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+        sorted_renderables
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{controller::SkeletonController, test::TestAsset, Physics};
+
+    #[test]
+    fn sorted_renderables_order() {
+        let (skeleton_data, animation_state_data) = TestAsset::spineboy().instance_data(true);
+        let mut controller = SkeletonController::new(skeleton_data, animation_state_data);
+        controller.update(0.016, Physics::Update);
+        let renderables_count = controller.renderables().len();
+        let sorted_renderables = controller.sorted_renderables(|slot, _, _| slot.bone().world_y());
+        assert_eq!(sorted_renderables.len(), renderables_count);
+        assert!(sorted_renderables
+            .windows(2)
+            .all(|pair| pair[0].0 <= pair[1].0));
+    }
+}
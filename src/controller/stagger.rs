@@ -0,0 +1,120 @@
+use crate::prelude::*;
+use crate::random::Random;
+
+use super::SkeletonController;
+
+/// How [`SkeletonController::stagger`] desynchronizes the animations of many controllers.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Stagger {
+    /// Delay the start of each controller's animation by `interval` seconds more than the
+    /// previous controller. The first controller starts immediately.
+    Delay { interval: f32 },
+    /// Start the controllers at evenly spaced times across the animation.
+    Even,
+    /// Start each controller at a random time within the animation. The same seed always
+    /// produces the same start times.
+    Random { seed: u64 },
+}
+
+impl SkeletonController {
+    /// Desynchronize the current animation on a track across many controllers, so crowds of the
+    /// same skeleton don't play in perfect sync. Controllers without an animation on the track
+    /// are skipped.
+    ///
+    /// ```
+    /// # #[path="../test.rs"]
+    /// # mod test;
+    /// # use rusty_spine::controller::{SkeletonController, Stagger};
+    /// # let (skeleton_data, animation_state_data) = test::TestAsset::spineboy().instance_data(true);
+    /// let mut crowd: Vec<SkeletonController> = (0..10)
+    ///     .map(|_| {
+    ///         let mut controller =
+    ///             SkeletonController::new(skeleton_data.clone(), animation_state_data.clone());
+    ///         controller
+    ///             .animation_state
+    ///             .set_animation_by_name(0, "idle", true)
+    ///             .unwrap();
+    ///         controller
+    ///     })
+    ///     .collect();
+    /// SkeletonController::stagger(&mut crowd, 0, Stagger::Random { seed: 1 });
+    /// ```
+    ///
+    /// Events keyed before each controller's new start time are not fired.
+    pub fn stagger<'a, I>(controllers: I, track_index: usize, stagger: Stagger)
+    where
+        I: IntoIterator<Item = &'a mut SkeletonController>,
+    {
+        let controllers: Vec<&mut SkeletonController> = controllers.into_iter().collect();
+        let controllers_count = controllers.len();
+        let mut random = Random::new(match stagger {
+            Stagger::Random { seed } => seed,
+            _ => 0,
+        });
+        for (index, controller) in controllers.into_iter().enumerate() {
+            let Some(mut track_entry) = controller.animation_state.track_at_index_mut(track_index)
+            else {
+                continue;
+            };
+            match stagger {
+                Stagger::Delay { interval } => {
+                    track_entry.set_delay(interval.max(0.) * index as f32);
+                }
+                Stagger::Even => {
+                    track_entry.skip_to_fraction(index as f32 / controllers_count as f32);
+                }
+                Stagger::Random { .. } => {
+                    track_entry.skip_to_fraction(random.next_f32());
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        controller::{SkeletonController, Stagger},
+        test::TestAsset,
+    };
+
+    #[test]
+    fn stagger() {
+        let (skeleton_data, animation_state_data) = TestAsset::spineboy().instance_data(true);
+        let mut controllers: Vec<SkeletonController> = (0..4)
+            .map(|_| {
+                let mut controller =
+                    SkeletonController::new(skeleton_data.clone(), animation_state_data.clone());
+                let _ = controller
+                    .animation_state
+                    .set_animation_by_name(0, "run", true);
+                controller
+            })
+            .collect();
+        let duration = controllers[0]
+            .animation_state
+            .track_at_index(0)
+            .unwrap()
+            .animation_end();
+
+        SkeletonController::stagger(&mut controllers, 0, Stagger::Even);
+        for (index, controller) in controllers.iter().enumerate() {
+            let track_time = controller
+                .animation_state
+                .track_at_index(0)
+                .unwrap()
+                .track_time();
+            assert!((track_time - duration * index as f32 / 4.).abs() < 0.0001);
+        }
+
+        SkeletonController::stagger(&mut controllers, 0, Stagger::Delay { interval: 0.5 });
+        assert_eq!(
+            controllers[3]
+                .animation_state
+                .track_at_index(0)
+                .unwrap()
+                .delay(),
+            1.5
+        );
+    }
+}
@@ -0,0 +1,204 @@
+#[cfg(not(feature = "std"))]
+use crate::math::Float;
+use crate::{animation_state::TrackEntry, c_interface::NewFromPtr, Physics};
+
+use super::SkeletonController;
+
+impl SkeletonController {
+    /// The total time, in seconds, this controller has been updated for. Used by
+    /// [`update_to_time`](`Self::update_to_time`) to determine how far to advance.
+    #[must_use]
+    pub const fn time(&self) -> f32 {
+        self.time
+    }
+
+    /// Updates the controller `steps` times by exactly `fixed_dt` seconds each. The same number of
+    /// steps from the same state always results in the same pose, regardless of the frame rate,
+    /// for games which need deterministic animation such as with lockstep networking.
+    ///
+    /// ```
+    /// # #[path="../test.rs"]
+    /// # mod test;
+    /// # use rusty_spine::controller::SkeletonController;
+    /// # let (skeleton_data, animation_state_data) = test::TestAsset::spineboy().instance_data(true);
+    /// let mut controller = SkeletonController::new(skeleton_data, animation_state_data);
+    /// controller.animation_state.set_animation_by_name(0, "run", true)?;
+    /// // advance to simulation tick 30
+    /// controller.step_fixed(30, 1. / 60.);
+    /// # Ok::<(), rusty_spine::SpineError>(())
+    /// ```
+    ///
+    /// Physics is updated with every step. To step by the time which passed in a frame, see
+    /// [`update_fixed`](`Self::update_fixed`).
+    pub fn step_fixed(&mut self, steps: u32, fixed_dt: f32) {
+        for _ in 0..steps {
+            self.update(fixed_dt, Physics::Update);
+        }
+    }
+
+    /// Adds `delta_seconds` to the time left over from previous calls, and updates the
+    /// controller by as many whole steps of `fixed_dt` as fit with
+    /// [`step_fixed`](`Self::step_fixed`), returning the number of steps taken. The time left
+    /// over is kept for the next call, see
+    /// [`fixed_time_remainder`](`Self::fixed_time_remainder`).
+    ///
+    /// ```
+    /// # #[path="../test.rs"]
+    /// # mod test;
+    /// # use rusty_spine::controller::SkeletonController;
+    /// # let (skeleton_data, animation_state_data) = test::TestAsset::spineboy().instance_data(true);
+    /// # let frame_delta = || 0.025;
+    /// let mut controller = SkeletonController::new(skeleton_data, animation_state_data);
+    /// let steps = controller.update_fixed(frame_delta(), 1. / 60.);
+    /// ```
+    ///
+    /// If `fixed_dt` is not positive, no steps are taken and the time is discarded.
+    pub fn update_fixed(&mut self, delta_seconds: f32, fixed_dt: f32) -> u32 {
+        if fixed_dt <= 0. {
+            self.fixed_time_remainder = 0.;
+            return 0;
+        }
+        let total_seconds = self.fixed_time_remainder + delta_seconds.max(0.);
+        let steps = (total_seconds / fixed_dt).floor();
+        self.fixed_time_remainder = (total_seconds - steps * fixed_dt).max(0.);
+        let steps = steps as u32;
+        self.step_fixed(steps, fixed_dt);
+        steps
+    }
+
+    /// The time left over from [`update_fixed`](`Self::update_fixed`) which was not enough for
+    /// another step. Divided by the fixed step, this is how far the current frame is between the
+    /// last step and the next, for interpolating rendering.
+    #[must_use]
+    pub const fn fixed_time_remainder(&self) -> f32 {
+        self.fixed_time_remainder
+    }
+
+    /// Updates the controller to an absolute position on an external timeline, such as the
+    /// playback position of an audio track, so a cutscene stays in sync with it regardless of
+    /// frame timing.
+    ///
+    /// ```
+    /// # #[path="../test.rs"]
+    /// # mod test;
+    /// # use rusty_spine::{controller::SkeletonController, Physics};
+    /// # let (skeleton_data, animation_state_data) = test::TestAsset::spineboy().instance_data(true);
+    /// # let audio_position = || 1.5;
+    /// let mut controller = SkeletonController::new(skeleton_data, animation_state_data);
+    /// controller.animation_state.set_animation_by_name(0, "run", true)?;
+    /// controller.update_to_time(audio_position(), Physics::Update);
+    /// # Ok::<(), rusty_spine::SpineError>(())
+    /// ```
+    ///
+    /// Moving forward behaves the same as [`update`](`Self::update`), so events, completions, and
+    /// queued animations are all handled, even across large jumps. Moving backward seeks each
+    /// track (and any tracks it is mixing from) back by the difference without firing events, and
+    /// resets physics since the simulation cannot be rewound. Tracks cannot seek to before they
+    /// started, and queued animations which already started are not restored.
+    pub fn update_to_time(&mut self, time: f32, physics: Physics) {
+        let delta = time - self.time;
+        if delta >= 0. {
+            self.update(delta, physics);
+            return;
+        }
+        for track_index in 0..self.animation_state.tracks_count() {
+            let Some(track_entry) = self.animation_state.track_at_index_mut(track_index) else {
+                continue;
+            };
+            let mut c_track_entry = track_entry.c_ptr();
+            while !c_track_entry.is_null() {
+                let mut track_entry = unsafe { TrackEntry::new_from_ptr(c_track_entry) };
+                track_entry.seek((track_entry.track_time() + delta).max(0.));
+                track_entry.set_mix_time((track_entry.mix_time() + delta).max(0.));
+                c_track_entry = unsafe { (*c_track_entry).mixingFrom };
+            }
+        }
+        self.time = time;
+        self.update_step += 1;
+        self.animation_state.apply(&mut self.skeleton);
+        self.hide_slots();
+        self.apply_bone_overrides();
+        self.skeleton.update_world_transform(Physics::Reset);
+        self.detect_changes();
+        self.queue_events();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::Cell, rc::Rc};
+
+    use crate::{controller::SkeletonController, test::TestAsset, AnimationEvent, Physics};
+
+    #[test]
+    fn update_to_time() {
+        let (skeleton_data, animation_state_data) = TestAsset::spineboy().instance_data(true);
+        let mut controller = SkeletonController::new(skeleton_data, animation_state_data);
+        let events = Rc::new(Cell::new(0));
+        let listener_events = events.clone();
+        controller
+            .animation_state
+            .set_listener(move |_, animation_event| {
+                if let AnimationEvent::Event { .. } = animation_event {
+                    listener_events.set(listener_events.get() + 1);
+                }
+            });
+        let _ = controller
+            .animation_state
+            .set_animation_by_name(0, "run", true);
+
+        controller.update_to_time(0.5, Physics::Update);
+        assert_eq!(controller.time(), 0.5);
+        let track_time = |controller: &SkeletonController| {
+            controller
+                .animation_state
+                .track_at_index(0)
+                .unwrap()
+                .track_time()
+        };
+        assert_eq!(track_time(&controller), 0.5);
+        let forward_events = events.get();
+        assert_eq!(forward_events, 1);
+
+        controller.update_to_time(0.2, Physics::Update);
+        assert_eq!(controller.time(), 0.2);
+        assert!((track_time(&controller) - 0.2).abs() < 0.0001);
+        assert_eq!(events.get(), forward_events);
+
+        controller.update_to_time(0.5, Physics::Update);
+        assert!((track_time(&controller) - 0.5).abs() < 0.0001);
+        assert_eq!(events.get(), forward_events * 2);
+    }
+
+    #[test]
+    fn fixed_steps() {
+        let (skeleton_data, animation_state_data) = TestAsset::spineboy().instance_data(true);
+        let new_controller = || {
+            let mut controller =
+                SkeletonController::new(skeleton_data.clone(), animation_state_data.clone());
+            let _ = controller
+                .animation_state
+                .set_animation_by_name(0, "run", true);
+            controller
+        };
+        let fixed_dt = 1. / 60.;
+        let mut variable = new_controller();
+        let mut steps = 0;
+        for delta_seconds in [0.05, 0.013, 0.1, 0.0021, 0.033, 0.25] {
+            steps += variable.update_fixed(delta_seconds, fixed_dt);
+            assert!(variable.fixed_time_remainder() < fixed_dt);
+        }
+        assert_eq!(steps, 26);
+
+        let mut stepped = new_controller();
+        stepped.step_fixed(steps, fixed_dt);
+        for (bone, stepped_bone) in variable.skeleton.bones().zip(stepped.skeleton.bones()) {
+            assert_eq!(bone.world_x(), stepped_bone.world_x());
+            assert_eq!(bone.world_y(), stepped_bone.world_y());
+            assert_eq!(bone.a(), stepped_bone.a());
+        }
+
+        assert_eq!(stepped.update_fixed(1., 0.), 0);
+        assert_eq!(stepped.fixed_time_remainder(), 0.);
+    }
+}
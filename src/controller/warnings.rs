@@ -0,0 +1,280 @@
+use core::{fmt, ptr::null_mut};
+
+use crate::c::spAttachment;
+use crate::prelude::*;
+
+use super::SkeletonController;
+
+/// A recoverable problem with a skeleton's attachments, found while generating renderables with
+/// [`SkeletonControllerSettings::collect_warnings`](`super::SkeletonControllerSettings::collect_warnings`)
+/// enabled. These usually point to a broken export or a mismatched atlas.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ControllerWarning {
+    /// The attachment has no atlas region to draw with, so it was skipped.
+    MissingRegion { slot: String, attachment: String },
+    /// The slot's deform buffer does not match the number of vertices the mesh expects. If the
+    /// buffer is too small to deform the mesh, the mesh was skipped.
+    DeformCountMismatch {
+        slot: String,
+        attachment: String,
+        expected: usize,
+        actual: usize,
+    },
+    /// The mesh has triangles with no area, either from repeated indices or from vertices
+    /// which lie on the same line in the texture.
+    DegenerateTriangles {
+        slot: String,
+        attachment: String,
+        count: usize,
+    },
+}
+
+impl fmt::Display for ControllerWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingRegion { slot, attachment } => {
+                write!(f, "{slot}/{attachment}: missing atlas region")
+            }
+            Self::DeformCountMismatch {
+                slot,
+                attachment,
+                expected,
+                actual,
+            } => {
+                write!(
+                    f,
+                    "{slot}/{attachment}: deform count is {actual}, expected {expected}"
+                )
+            }
+            Self::DegenerateTriangles {
+                slot,
+                attachment,
+                count,
+            } => {
+                write!(f, "{slot}/{attachment}: {count} degenerate triangles")
+            }
+        }
+    }
+}
+
+impl SkeletonController {
+    /// Warnings collected since the last call to [`drain_warnings`](`Self::drain_warnings`).
+    /// Each distinct warning is only reported once per controller.
+    ///
+    /// ```
+    /// # #[path="../test.rs"]
+    /// # mod test;
+    /// # use rusty_spine::{controller::{SkeletonController, SkeletonControllerSettings}, Physics};
+    /// # let (skeleton_data, animation_state_data) = test::TestAsset::spineboy().instance_data(true);
+    /// let mut controller = SkeletonController::new(skeleton_data, animation_state_data)
+    ///     .with_settings(SkeletonControllerSettings::new().with_collect_warnings(true));
+    /// controller.update(0.016, Physics::Update);
+    /// let renderables = controller.renderables();
+    /// for warning in controller.drain_warnings() {
+    ///     println!("{warning}");
+    /// }
+    /// ```
+    #[must_use]
+    pub fn warnings(&self) -> &[ControllerWarning] {
+        &self.warnings
+    }
+
+    /// Removes and returns the collected warnings. See [`warnings`](`Self::warnings`).
+    pub fn drain_warnings(&mut self) -> impl Iterator<Item = ControllerWarning> + '_ {
+        self.warnings.drain(..)
+    }
+
+    fn warn(&mut self, warning: ControllerWarning) {
+        if self.reported_warnings.insert(warning.clone()) {
+            self.warnings.push(warning);
+        }
+    }
+
+    /// Checks the attachment of every slot for problems, detaching any which can't be drawn
+    /// safely. The detached attachments must be passed to
+    /// [`restore_attachments`](`Self::restore_attachments`) after drawing.
+    pub(super) fn check_attachments(&mut self) -> Vec<(usize, *mut spAttachment)> {
+        let mut detached = vec![];
+        if !self.settings.collect_warnings {
+            return detached;
+        }
+        let mut warnings = vec![];
+        for slot_index in 0..self.skeleton.slots_count() {
+            let Some(slot) = self.skeleton.slot_at_index(slot_index) else {
+                continue;
+            };
+            let Some(attachment) = slot.attachment() else {
+                continue;
+            };
+            let slot_name = slot.data().name().to_owned();
+            let attachment_name = attachment.name().to_owned();
+            let mut skip = false;
+            if let Some(region_attachment) = attachment.as_region() {
+                // Attachments with a sequence are assigned a region as they're drawn
+                if unsafe {
+                    region_attachment.c_ptr_ref().rendererObject.is_null()
+                        && region_attachment.c_ptr_ref().sequence.is_null()
+                } {
+                    warnings.push(ControllerWarning::MissingRegion {
+                        slot: slot_name,
+                        attachment: attachment_name,
+                    });
+                    skip = true;
+                }
+            } else if let Some(mesh_attachment) = attachment.as_mesh() {
+                let region = mesh_attachment.region();
+                if unsafe {
+                    (mesh_attachment.c_ptr_ref().rendererObject.is_null() || region.is_none())
+                        && mesh_attachment.c_ptr_ref().sequence.is_null()
+                } {
+                    warnings.push(ControllerWarning::MissingRegion {
+                        slot: slot_name.clone(),
+                        attachment: attachment_name.clone(),
+                    });
+                    skip = true;
+                }
+                let expected = if mesh_attachment.bones().is_empty() {
+                    mesh_attachment.vertices().len()
+                } else {
+                    mesh_attachment.vertices().len() / 3 * 2
+                };
+                let actual = slot.deform_count();
+                if actual != 0 && actual != expected {
+                    warnings.push(ControllerWarning::DeformCountMismatch {
+                        slot: slot_name.clone(),
+                        attachment: attachment_name.clone(),
+                        expected,
+                        actual,
+                    });
+                    skip |= actual < expected;
+                }
+                if let Some(region) = region {
+                    let size = [region.width() as f32, region.height() as f32];
+                    let region_uvs = unsafe {
+                        core::slice::from_raw_parts(
+                            mesh_attachment.region_uvs(),
+                            mesh_attachment.world_vertices_length() as usize,
+                        )
+                    };
+                    let position = |index: u16| {
+                        let index = index as usize * 2;
+                        [region_uvs[index] * size[0], region_uvs[index + 1] * size[1]]
+                    };
+                    let count = mesh_attachment
+                        .triangles_slice()
+                        .chunks_exact(3)
+                        .filter(|triangle| {
+                            let [a, b, c] = [
+                                position(triangle[0]),
+                                position(triangle[1]),
+                                position(triangle[2]),
+                            ];
+                            let area =
+                                (b[0] - a[0]) * (c[1] - a[1]) - (c[0] - a[0]) * (b[1] - a[1]);
+                            area.abs() <= f32::EPSILON
+                        })
+                        .count();
+                    if count > 0 {
+                        warnings.push(ControllerWarning::DegenerateTriangles {
+                            slot: slot_name,
+                            attachment: attachment_name,
+                            count,
+                        });
+                    }
+                }
+            }
+            if skip {
+                detached.push((slot_index, attachment.c_ptr()));
+            }
+        }
+        for warning in warnings {
+            self.warn(warning);
+        }
+        for (slot_index, _) in &detached {
+            if let Some(slot) = self.skeleton.slot_at_index_mut(*slot_index) {
+                unsafe { slot.c_ptr_mut().attachment = null_mut() };
+            }
+        }
+        detached
+    }
+
+    /// Reattaches the attachments detached by [`check_attachments`](`Self::check_attachments`),
+    /// without resetting the slot's deform buffer.
+    pub(super) fn restore_attachments(&mut self, detached: Vec<(usize, *mut spAttachment)>) {
+        for (slot_index, c_attachment) in detached {
+            if let Some(slot) = self.skeleton.slot_at_index_mut(slot_index) {
+                unsafe { slot.c_ptr_mut().attachment = c_attachment };
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        controller::{ControllerWarning, SkeletonController, SkeletonControllerSettings},
+        test::TestAsset,
+        Physics,
+    };
+
+    #[test]
+    fn no_warnings() {
+        for example_asset in TestAsset::all() {
+            let (skeleton_data, animation_state_data) = example_asset.instance_data(true);
+            let mut controller =
+                SkeletonController::new(skeleton_data.clone(), animation_state_data)
+                    .with_settings(SkeletonControllerSettings::new().with_collect_warnings(true));
+            let animation = skeleton_data.animation_at_index(0).unwrap();
+            let _ = controller
+                .animation_state
+                .set_animation(0, &animation, true);
+            controller.update(0.5, Physics::Update);
+            let _ = controller.combined_renderables();
+            assert!(
+                controller.warnings().is_empty(),
+                "{:?}",
+                controller.warnings()
+            );
+        }
+    }
+
+    #[test]
+    fn deform_count_mismatch() {
+        let (skeleton_data, animation_state_data) = TestAsset::spineboy().instance_data(true);
+        let mut controller = SkeletonController::new(skeleton_data, animation_state_data)
+            .with_settings(SkeletonControllerSettings::new().with_collect_warnings(true));
+        let slot_index = controller
+            .skeleton
+            .slots()
+            .find(|slot| slot.attachment().and_then(|a| a.as_mesh()).is_some())
+            .unwrap()
+            .data()
+            .index();
+        let renderables_count = controller.renderables().len();
+        let mut deform = [0.; 4];
+        unsafe {
+            let slot = controller.skeleton.slot_at_index_mut(slot_index).unwrap();
+            slot.c_ptr_mut().deform = deform.as_mut_ptr();
+            slot.c_ptr_mut().deformCount = 4;
+        }
+        assert_eq!(controller.renderables().len(), renderables_count - 1);
+        assert!(matches!(
+            controller.warnings(),
+            [ControllerWarning::DeformCountMismatch { actual: 4, .. }]
+        ));
+        assert_eq!(controller.drain_warnings().count(), 1);
+        let _ = controller.renderables();
+        assert!(controller.warnings().is_empty());
+        assert!(controller
+            .skeleton
+            .slot_at_index(slot_index)
+            .unwrap()
+            .attachment()
+            .is_some());
+        unsafe {
+            let slot = controller.skeleton.slot_at_index_mut(slot_index).unwrap();
+            slot.c_ptr_mut().deform = core::ptr::null_mut();
+            slot.c_ptr_mut().deformCount = 0;
+        }
+    }
+}
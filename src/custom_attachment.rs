@@ -0,0 +1,213 @@
+use alloc::collections::BTreeMap;
+
+use crate::{
+    attachment::Attachment,
+    c::spAttachment,
+    prelude::*,
+    sync::{lock, Mutex},
+};
+
+/// The highest type id used by Spine's built-in attachment types. Custom types must use higher
+/// ids.
+const BUILT_IN_TYPE_ID_MAX: u32 = 6;
+
+static CUSTOM_ATTACHMENT_TYPES: Mutex<BTreeMap<u32, String>> = Mutex::new(BTreeMap::new());
+
+/// Registers a user-defined attachment type, so attachments of that type created by other native
+/// code can be identified with [`Attachment::as_custom`].
+///
+/// ```
+/// use rusty_spine::{register_custom_attachment_type, Attachment};
+///
+/// const SPRITE_SHEET_ATTACHMENT: u32 = 100;
+/// register_custom_attachment_type(SPRITE_SHEET_ATTACHMENT, "SpriteSheet");
+///
+/// fn draw(attachment: &Attachment) {
+///     if let Some(custom_attachment) = attachment.as_custom() {
+///         if custom_attachment.type_id() == SPRITE_SHEET_ATTACHMENT {
+///             // Cast `custom_attachment.c_ptr()` to the plugin's attachment struct
+///         }
+///     }
+/// }
+/// ```
+///
+/// A custom attachment is a C struct starting with an [`spAttachment`], initialized by
+/// `_spAttachment_init` with the custom type id and `dispose` and `copy` functions which free and
+/// copy the whole struct. Since the attachment disposes itself through these functions, custom
+/// attachments can be carried and dropped safely from Rust like any other [`Attachment`].
+/// Drawers and other helpers in this crate skip attachment types they don't know.
+///
+/// Returns `false` if the type id is already registered.
+///
+/// # Panics
+///
+/// Panics if `type_id` is the id of one of Spine's built-in attachment types (`0` to `6`).
+#[allow(clippy::must_use_candidate)]
+pub fn register_custom_attachment_type(type_id: u32, name: &str) -> bool {
+    assert!(
+        type_id > BUILT_IN_TYPE_ID_MAX,
+        "attachment type id {type_id} is used by a built-in attachment type"
+    );
+    let mut custom_attachment_types = lock(&CUSTOM_ATTACHMENT_TYPES);
+    if custom_attachment_types.contains_key(&type_id) {
+        return false;
+    }
+    custom_attachment_types.insert(type_id, name.to_owned());
+    true
+}
+
+/// The name of a custom attachment type registered with [`register_custom_attachment_type`], or
+/// [`None`] if it is not registered.
+#[must_use]
+pub fn custom_attachment_type_name(type_id: u32) -> Option<String> {
+    lock(&CUSTOM_ATTACHMENT_TYPES).get(&type_id).cloned()
+}
+
+/// An [`Attachment`] of a custom type registered with [`register_custom_attachment_type`].
+#[derive(Debug, Clone)]
+pub struct CustomAttachment {
+    attachment: Attachment,
+    type_id: u32,
+}
+
+impl CustomAttachment {
+    /// The id the attachment type was registered with.
+    #[must_use]
+    pub const fn type_id(&self) -> u32 {
+        self.type_id
+    }
+
+    /// The name the attachment type was registered with.
+    #[must_use]
+    pub fn type_name(&self) -> String {
+        custom_attachment_type_name(self.type_id).unwrap_or_default()
+    }
+
+    /// The attachment's name.
+    #[must_use]
+    pub fn name(&self) -> &str {
+        self.attachment.name()
+    }
+
+    #[must_use]
+    pub const fn attachment(&self) -> &Attachment {
+        &self.attachment
+    }
+
+    /// A pointer to the attachment, which can be cast to the struct of the native code which
+    /// created it.
+    #[must_use]
+    pub const fn c_ptr(&self) -> *mut spAttachment {
+        self.attachment.c_ptr()
+    }
+}
+
+impl Attachment {
+    /// The raw type id of the attachment, which is the value of
+    /// [`attachment_type`](`Self::attachment_type`) for built-in attachment types, or the id of a
+    /// custom attachment type.
+    #[must_use]
+    pub fn type_id(&self) -> u32 {
+        unsafe { self.c_ptr_ref().type_0 }
+    }
+
+    /// Get this attachment as a [`CustomAttachment`], or [`None`] if its type was not registered
+    /// with [`register_custom_attachment_type`].
+    #[must_use]
+    pub fn as_custom(&self) -> Option<CustomAttachment> {
+        let type_id = self.type_id();
+        if type_id > BUILT_IN_TYPE_ID_MAX && custom_attachment_type_name(type_id).is_some() {
+            Some(CustomAttachment {
+                attachment: self.clone(),
+                type_id,
+            })
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::ffi::CString;
+
+    use crate::{
+        c::{_spAttachment_deinit, _spAttachment_init, _spCalloc, _spFree, spAttachment},
+        c_interface::NewFromPtr,
+        Attachment, AttachmentType,
+    };
+
+    use super::*;
+
+    #[repr(C)]
+    struct TestAttachment {
+        attachment: spAttachment,
+        value: i32,
+    }
+
+    extern "C" fn dispose_test_attachment(attachment: *mut spAttachment) {
+        unsafe {
+            _spAttachment_deinit(attachment);
+            _spFree(attachment.cast());
+        }
+    }
+
+    extern "C" fn copy_test_attachment(attachment: *mut spAttachment) -> *mut spAttachment {
+        unsafe {
+            let value = (*attachment.cast::<TestAttachment>()).value;
+            new_test_attachment((*attachment).name, (*attachment).type_0, value)
+        }
+    }
+
+    unsafe fn new_test_attachment(
+        name: *const core::ffi::c_char,
+        type_id: u32,
+        value: i32,
+    ) -> *mut spAttachment {
+        let test_attachment = _spCalloc(
+            1,
+            core::mem::size_of::<TestAttachment>() as _,
+            c"custom_attachment.rs".as_ptr(),
+            line!() as _,
+        )
+        .cast::<TestAttachment>();
+        _spAttachment_init(
+            &mut (*test_attachment).attachment,
+            name,
+            type_id,
+            Some(dispose_test_attachment),
+            Some(copy_test_attachment),
+        );
+        (*test_attachment).value = value;
+        test_attachment.cast()
+    }
+
+    #[test]
+    fn custom_attachment() {
+        assert!(register_custom_attachment_type(1000, "Test"));
+        assert!(!register_custom_attachment_type(1000, "Other"));
+        let name = CString::new("custom").unwrap();
+        let attachment =
+            unsafe { Attachment::new_from_ptr(new_test_attachment(name.as_ptr(), 1000, 42)) };
+        assert_eq!(attachment.attachment_type(), AttachmentType::Unknown);
+        assert_eq!(attachment.type_id(), 1000);
+        assert!(attachment.as_region().is_none());
+        let custom_attachment = attachment.as_custom().unwrap();
+        assert_eq!(custom_attachment.type_name(), "Test");
+        assert_eq!(custom_attachment.name(), "custom");
+        assert_eq!(
+            unsafe { (*custom_attachment.c_ptr().cast::<TestAttachment>()).value },
+            42
+        );
+
+        let unregistered =
+            unsafe { Attachment::new_from_ptr(new_test_attachment(name.as_ptr(), 1001, 0)) };
+        assert!(unregistered.as_custom().is_none());
+    }
+
+    #[test]
+    #[should_panic]
+    fn register_built_in() {
+        register_custom_attachment_type(2, "Mesh");
+    }
+}
@@ -0,0 +1,191 @@
+use crate::prelude::*;
+use crate::{
+    animation_state::{AnimationState, TrackEntry},
+    json::{color_hex, write_string, ObjectWriter},
+    skeleton::Skeleton,
+};
+
+impl Skeleton {
+    /// A JSON snapshot of the skeleton's current state, including the local and world transforms
+    /// of every bone and the color and attachment of every slot, for attaching to bug reports.
+    ///
+    /// ```
+    /// # #[path="./test.rs"]
+    /// # mod test;
+    /// # let (skeleton, animation_state) = test::TestAsset::spineboy().instance(true);
+    /// std::fs::write(
+    ///     std::env::temp_dir().join("skeleton.json"),
+    ///     skeleton.debug_dump(),
+    /// )?;
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    ///
+    /// The format is intended to be read by people and may change between versions.
+    #[must_use]
+    pub fn debug_dump(&self) -> String {
+        let mut json = String::new();
+        self.write_debug_dump(&mut json);
+        json
+    }
+
+    pub(crate) fn write_debug_dump(&self, out: &mut String) {
+        let mut root = ObjectWriter::new(out);
+        if let Some(skin) = self.skin() {
+            root.string("skin", skin.name());
+        }
+        root.number("x", self.x());
+        root.number("y", self.y());
+        root.number("scaleX", self.scale_x());
+        root.number("scaleY", self.scale_y());
+        root.string("color", &color_hex(self.color()));
+        {
+            let out = root.key("bones");
+            out.push('[');
+            for (index, bone) in self.bones().enumerate() {
+                if index > 0 {
+                    out.push(',');
+                }
+                let mut object = ObjectWriter::new(out);
+                object.string("name", bone.data().name());
+                object.boolean("active", bone.active());
+                object.number("x", bone.x());
+                object.number("y", bone.y());
+                object.number("rotation", bone.rotation());
+                object.number("scaleX", bone.scale_x());
+                object.number("scaleY", bone.scale_y());
+                object.number("shearX", bone.shear_x());
+                object.number("shearY", bone.shear_y());
+                object.number("worldX", bone.world_x());
+                object.number("worldY", bone.world_y());
+                object.number("a", bone.a());
+                object.number("b", bone.b());
+                object.number("c", bone.c());
+                object.number("d", bone.d());
+                object.end();
+            }
+            out.push(']');
+        }
+        {
+            let out = root.key("slots");
+            out.push('[');
+            for (index, slot) in self.slots().enumerate() {
+                if index > 0 {
+                    out.push(',');
+                }
+                let mut object = ObjectWriter::new(out);
+                object.string("name", slot.data().name());
+                object.string("bone", slot.bone().data().name());
+                object.string("color", &color_hex(slot.color()));
+                if let Some(dark_color) = slot.dark_color() {
+                    object.string("darkColor", &color_hex(dark_color));
+                }
+                if let Some(attachment) = slot.attachment() {
+                    object.string("attachment", attachment.name());
+                }
+                object.end();
+            }
+            out.push(']');
+        }
+        {
+            let out = root.key("drawOrder");
+            out.push('[');
+            for (index, slot) in self.draw_order().enumerate() {
+                if index > 0 {
+                    out.push(',');
+                }
+                write_string(out, slot.data().name());
+            }
+            out.push(']');
+        }
+        root.end();
+    }
+}
+
+impl AnimationState {
+    /// A JSON snapshot of the animation state's current state, including every track entry and
+    /// the entries it is mixing from, for attaching to bug reports. See
+    /// [`Skeleton::debug_dump`].
+    #[must_use]
+    pub fn debug_dump(&self) -> String {
+        let mut json = String::new();
+        self.write_debug_dump(&mut json);
+        json
+    }
+
+    pub(crate) fn write_debug_dump(&self, out: &mut String) {
+        let mut root = ObjectWriter::new(out);
+        root.number("timeScale", self.timescale());
+        {
+            let out = root.key("tracks");
+            out.push('[');
+            for track_index in 0..self.tracks_count() {
+                if track_index > 0 {
+                    out.push(',');
+                }
+                match self.track_at_index(track_index) {
+                    Some(track_entry) => write_track_entry(out, &track_entry),
+                    None => out.push_str("null"),
+                }
+            }
+            out.push(']');
+        }
+        root.end();
+    }
+}
+
+fn write_track_entry(out: &mut String, track_entry: &TrackEntry) {
+    let mut object = ObjectWriter::new(out);
+    object.number("trackIndex", track_entry.track_index() as f32);
+    object.string("animation", track_entry.animation().name());
+    object.boolean("loop", track_entry.looping());
+    object.boolean("reverse", track_entry.reverse());
+    object.number("trackTime", track_entry.track_time());
+    object.number("trackEnd", track_entry.track_end());
+    object.number("animationStart", track_entry.animation_start());
+    object.number("animationEnd", track_entry.animation_end());
+    object.number("animationLast", track_entry.animation_last());
+    object.number("animationTime", track_entry.animation_time());
+    object.number("delay", track_entry.delay());
+    object.number("timeScale", track_entry.timescale());
+    object.number("alpha", track_entry.alpha());
+    object.number("mixTime", track_entry.mix_time());
+    object.number("mixDuration", track_entry.mix_duration());
+    if let Some(mixing_from) = track_entry.mixing_from() {
+        write_track_entry(object.key("mixingFrom"), &mixing_from);
+    }
+    object.end();
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{test::TestAsset, Physics};
+
+    #[test]
+    fn debug_dump() {
+        let (mut skeleton, mut animation_state) = TestAsset::spineboy().instance(true);
+        let _ = animation_state.set_animation_by_name(0, "walk", true);
+        animation_state.update(0.1);
+        animation_state.apply(&mut skeleton);
+        animation_state
+            .set_animation_by_name(0, "run", true)
+            .unwrap()
+            .set_mix_duration(0.5);
+        animation_state.update(0.1);
+        animation_state.apply(&mut skeleton);
+        skeleton.update_world_transform(Physics::Update);
+
+        let skeleton_json = skeleton.debug_dump();
+        assert!(skeleton_json.starts_with(r#"{"x":0,"y":0,"scaleX":1,"scaleY":1,"#));
+        assert!(skeleton_json.contains(r#"{"name":"root","active":true,"x":0,"y":0,"#));
+        assert!(skeleton_json.contains(r#""attachment":"head""#));
+        assert!(skeleton_json.ends_with("]}"));
+
+        let animation_state_json = animation_state.debug_dump();
+        assert!(animation_state_json.starts_with(
+            r#"{"timeScale":1,"tracks":[{"trackIndex":0,"animation":"run","loop":true,"#
+        ));
+        assert!(
+            animation_state_json.contains(r#""mixingFrom":{"trackIndex":0,"animation":"walk","#)
+        );
+    }
+}
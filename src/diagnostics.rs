@@ -0,0 +1,210 @@
+//! Diagnostics for finding leaked Spine objects.
+//!
+//! Once tracking is turned on with [`set_enabled`], every C object created and owned by this crate
+//! is recorded in a global registry until it is disposed. Tracking is off by default, so creating
+//! and dropping objects doesn't take a global lock unless diagnostics are in use. Listing the
+//! registry at a point where no Spine objects are expected to be alive (for instance, after
+//! unloading a level) is a quick way to find forgotten [`Arc`](alloc::sync::Arc) clones keeping
+//! [`SkeletonData`](crate::SkeletonData) or [`Atlas`](crate::Atlas) alive, or atlas page textures
+//! whose dispose callback never fired.
+//!
+//! ```
+//! # #[path="./test.rs"]
+//! # mod test;
+//! use rusty_spine::diagnostics::{live_objects, set_enabled, LiveObjectKind};
+//!
+//! set_enabled(true);
+//! # let (skeleton, animation_state) = test::TestAsset::spineboy().instance(true);
+//! for object in live_objects() {
+//!     if object.kind == LiveObjectKind::Skeleton {
+//!         println!("skeleton still alive at {:#x}", object.address);
+//!         if let Some(backtrace) = &object.backtrace {
+//!             println!("{backtrace}");
+//!         }
+//!     }
+//! }
+//! ```
+//!
+//! Objects created before tracking was enabled are not listed. Creation backtraces are only
+//! captured when the `debug_backtraces` feature is enabled, because capturing them is slow.
+
+use alloc::collections::BTreeMap;
+use core::{
+    fmt,
+    sync::atomic::{AtomicBool, Ordering},
+};
+
+use crate::{
+    prelude::*,
+    sync::{lock, Mutex},
+};
+
+/// The type of a tracked C object.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum LiveObjectKind {
+    /// An [`Atlas`](crate::Atlas).
+    Atlas,
+    /// A texture created for an [`AtlasPage`](crate::atlas::AtlasPage) through
+    /// [`set_create_texture_cb`](crate::extension::set_create_texture_cb) and not yet passed to
+    /// the dispose callback.
+    AtlasPageTexture,
+    /// A [`SkeletonData`](crate::SkeletonData).
+    SkeletonData,
+    /// An [`AnimationStateData`](crate::AnimationStateData).
+    AnimationStateData,
+    /// A [`Skeleton`](crate::Skeleton).
+    Skeleton,
+    /// An [`AnimationState`](crate::AnimationState).
+    AnimationState,
+}
+
+impl fmt::Display for LiveObjectKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Self::Atlas => "Atlas",
+            Self::AtlasPageTexture => "AtlasPageTexture",
+            Self::SkeletonData => "SkeletonData",
+            Self::AnimationStateData => "AnimationStateData",
+            Self::Skeleton => "Skeleton",
+            Self::AnimationState => "AnimationState",
+        };
+        write!(f, "{name}")
+    }
+}
+
+/// A C object created by this crate that has not been disposed yet.
+#[derive(Debug, Clone)]
+pub struct LiveObject {
+    /// The type of the object.
+    pub kind: LiveObjectKind,
+    /// An identifier unique to this object, which unlike the address is never reused.
+    pub id: u64,
+    /// The address of the underlying C object.
+    pub address: usize,
+    /// The backtrace captured when the object was created. Always [`None`] unless the
+    /// `debug_backtraces` feature is enabled.
+    pub backtrace: Option<String>,
+}
+
+struct TrackedObject {
+    id: u64,
+    backtrace: Option<String>,
+}
+
+struct Registry {
+    next_id: u64,
+    objects: BTreeMap<(LiveObjectKind, usize), TrackedObject>,
+}
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+static REGISTRY: Mutex<Registry> = Mutex::new(Registry {
+    next_id: 0,
+    objects: BTreeMap::new(),
+});
+
+/// Turn tracking of live objects on or off. Turning it off forgets all tracked objects.
+pub fn set_enabled(enabled: bool) {
+    let mut registry = lock(&REGISTRY);
+    ENABLED.store(enabled, Ordering::Release);
+    if !enabled {
+        registry.objects.clear();
+    }
+}
+
+/// Whether live objects are currently being tracked.
+#[must_use]
+pub fn is_enabled() -> bool {
+    ENABLED.load(Ordering::Acquire)
+}
+
+/// All tracked C objects which are currently alive, sorted by kind.
+#[must_use]
+pub fn live_objects() -> Vec<LiveObject> {
+    lock(&REGISTRY)
+        .objects
+        .iter()
+        .map(|(&(kind, address), object)| LiveObject {
+            kind,
+            id: object.id,
+            address,
+            backtrace: object.backtrace.clone(),
+        })
+        .collect()
+}
+
+/// The number of tracked C objects of the given kind which are currently alive.
+#[must_use]
+pub fn live_object_count(kind: LiveObjectKind) -> usize {
+    lock(&REGISTRY)
+        .objects
+        .keys()
+        .filter(|(object_kind, _)| *object_kind == kind)
+        .count()
+}
+
+pub(crate) fn track<T>(kind: LiveObjectKind, c_ptr: *const T) {
+    if c_ptr.is_null() || !is_enabled() {
+        return;
+    }
+    #[cfg(feature = "debug_backtraces")]
+    let backtrace = Some(std::backtrace::Backtrace::force_capture().to_string());
+    #[cfg(not(feature = "debug_backtraces"))]
+    let backtrace = None;
+    let mut registry = lock(&REGISTRY);
+    if !is_enabled() {
+        return;
+    }
+    let id = registry.next_id;
+    registry.next_id += 1;
+    registry
+        .objects
+        .insert((kind, c_ptr as usize), TrackedObject { id, backtrace });
+}
+
+pub(crate) fn untrack<T>(kind: LiveObjectKind, c_ptr: *const T) {
+    if !is_enabled() {
+        return;
+    }
+    lock(&REGISTRY).objects.remove(&(kind, c_ptr as usize));
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test::TestAsset;
+
+    use super::*;
+
+    /// Disables tracking when dropped, so a failed test doesn't leave it enabled for others.
+    struct DisableOnDrop;
+
+    impl Drop for DisableOnDrop {
+        fn drop(&mut self) {
+            set_enabled(false);
+        }
+    }
+
+    #[test]
+    fn track_skeleton_lifetime() {
+        set_enabled(true);
+        let _disable = DisableOnDrop;
+        let (skeleton, animation_state) = TestAsset::spineboy().instance(true);
+        let id_of = |kind: LiveObjectKind, address: usize| {
+            live_objects()
+                .iter()
+                .find(|object| object.kind == kind && object.address == address)
+                .map(|object| object.id)
+        };
+        let skeleton_id = id_of(LiveObjectKind::Skeleton, skeleton.c_ptr() as usize).unwrap();
+        let animation_state_id = id_of(
+            LiveObjectKind::AnimationState,
+            animation_state.c_ptr() as usize,
+        )
+        .unwrap();
+        drop(skeleton);
+        drop(animation_state);
+        let is_live = |id: u64| live_objects().iter().any(|object| object.id == id);
+        assert!(!is_live(skeleton_id));
+        assert!(!is_live(animation_state_id));
+    }
+}
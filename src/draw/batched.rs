@@ -0,0 +1,256 @@
+use core::{mem::take, ops::Range};
+
+use crate::prelude::*;
+use crate::{c::c_void, BlendMode, Skeleton, SkeletonClipping};
+
+use super::{ColorSpace, CombinedDrawer, CullDirection, IndexFormat, MeshBuffers, RenderRules};
+
+#[allow(unused_imports)]
+use crate::extension;
+
+/// A single draw call within a [`BatchedRenderable`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DrawBatch {
+    /// The range of [`BatchedRenderable::indices`] or [`BatchedRenderable::indices_u32`] drawn by
+    /// this batch, depending on its [`index_format`](`Self::index_format`).
+    pub index_range: Range<usize>,
+    /// The type of this batch's indices, which the renderer should bind them as.
+    pub index_format: IndexFormat,
+    /// The range of vertices referenced by this batch. Indices in this batch are relative to the
+    /// start of this range, which is the base vertex of the draw call.
+    pub vertex_range: Range<usize>,
+    /// The blend mode to use when drawing this batch.
+    pub blend_mode: BlendMode,
+    /// Whether the colors in this batch were premultiplied, and the texture is expected to have
+    /// premultiplied alpha.
+    pub premultiplied_alpha: bool,
+    /// The tag set by a matching [`RenderRules`] rule, if any.
+    pub tag: Option<String>,
+    /// The attachment's renderer object as a raw pointer. Usually represents the texture created
+    /// from [`extension::set_create_texture_cb`].
+    pub attachment_renderer_object: Option<*const c_void>,
+}
+
+/// Renderables generated from [`BatchedDrawer::draw`].
+///
+/// All vertex attributes share one set of buffers so they can be uploaded at once, and each
+/// [`DrawBatch`] describes a draw call into those buffers.
+#[derive(Debug, Clone, Default)]
+pub struct BatchedRenderable {
+    /// A list of vertex attributes for all batches.
+    pub vertices: Vec<[f32; 2]>,
+    /// A list of UV attributes for all batches.
+    pub uvs: Vec<[f32; 2]>,
+    /// A list of color attributes for all batches.
+    pub colors: Vec<[f32; 4]>,
+    /// A list of dark color attributes for all batches.
+    /// See the [Spine User Guide](http://en.esotericsoftware.com/spine-slots#Tint-black).
+    pub dark_colors: Vec<[f32; 4]>,
+    /// A list of indices for batches using [`IndexFormat::U16`], relative to the base vertex of
+    /// their batch.
+    pub indices: Vec<u16>,
+    /// A list of indices for batches using [`IndexFormat::U32`], relative to the base vertex of
+    /// their batch.
+    pub indices_u32: Vec<u32>,
+    /// The draw calls, in the order they should be drawn.
+    pub batches: Vec<DrawBatch>,
+}
+
+/// A drawer which merges all attachments into a single set of buffers, split into draw calls
+/// wherever the texture or blend mode changes.
+///
+/// Assumes use of the default atlas attachment loader.
+///
+/// See [`BatchedDrawer::draw`]
+pub struct BatchedDrawer {
+    /// The cull direction to use for the vertices.
+    pub cull_direction: CullDirection,
+    /// Set to `true` if the textures are expected to have premultiplied alpha.
+    pub premultiplied_alpha: bool,
+    /// The color space to use for the colors returned in [`BatchedRenderable`].
+    pub color_space: ColorSpace,
+}
+
+impl BatchedDrawer {
+    /// This function returns a [`BatchedRenderable`] containing one set of vertex and index
+    /// buffers for the entire skeleton, along with the list of draw calls needed to render it.
+    /// Consecutive attachments sharing a blend mode and renderer object are merged into the same
+    /// draw call, the same as [`CombinedDrawer`]. If a [`SkeletonClipping`] is provided, meshes
+    /// will be properly clipped. The batches are expected to be drawn in the order provided with
+    /// the first batch being drawn behind all the others.
+    ///
+    /// ```
+    /// # #[path="../test.rs"]
+    /// # mod test;
+    /// use rusty_spine::draw::{BatchedDrawer, ColorSpace, CullDirection};
+    ///
+    /// # let (mut skeleton, _) = test::TestAsset::spineboy().instance(true);
+    /// let drawer = BatchedDrawer {
+    ///     cull_direction: CullDirection::Clockwise,
+    ///     premultiplied_alpha: false,
+    ///     color_space: ColorSpace::SRGB,
+    /// };
+    /// let renderable = drawer.draw(&mut skeleton, None);
+    /// // upload `renderable.vertices`, `renderable.indices`, etc. once, then:
+    /// for batch in &renderable.batches {
+    ///     // bind the texture and blend mode, then draw `batch.index_range` of the indices in
+    ///     // `batch.index_format` using `batch.vertex_range.start` as the base vertex
+    /// }
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if not using the default attachment loader with valid atlas regions.
+    pub fn draw(
+        &self,
+        skeleton: &mut Skeleton,
+        clipper: Option<&mut SkeletonClipping>,
+    ) -> BatchedRenderable {
+        self.draw_with_rules(skeleton, clipper, &RenderRules::new())
+    }
+
+    /// The same as [`draw`](`Self::draw`), but with the blend mode, premultiplied alpha, and tag
+    /// of each attachment overridden by any matching [`RenderRules`], which also split batches.
+    ///
+    /// # Panics
+    ///
+    /// Panics if not using the default attachment loader with valid atlas regions.
+    pub fn draw_with_rules(
+        &self,
+        skeleton: &mut Skeleton,
+        clipper: Option<&mut SkeletonClipping>,
+        rules: &RenderRules,
+    ) -> BatchedRenderable {
+        let mut buffers = MeshBuffers::new();
+        CombinedDrawer {
+            cull_direction: self.cull_direction,
+            premultiplied_alpha: self.premultiplied_alpha,
+            color_space: self.color_space,
+        }
+        .draw_into(skeleton, clipper, rules, &mut buffers);
+        BatchedRenderable {
+            batches: buffers
+                .batches
+                .drain(..)
+                .map(|batch| DrawBatch {
+                    index_range: batch.indices,
+                    index_format: batch.index_format,
+                    vertex_range: batch.vertices,
+                    blend_mode: batch.blend_mode,
+                    premultiplied_alpha: batch.premultiplied_alpha,
+                    tag: batch.tag,
+                    attachment_renderer_object: batch.attachment_renderer_object,
+                })
+                .collect(),
+            vertices: take(&mut buffers.vertices),
+            uvs: take(&mut buffers.uvs),
+            colors: take(&mut buffers.colors),
+            dark_colors: take(&mut buffers.dark_colors),
+            indices: take(&mut buffers.indices),
+            indices_u32: take(&mut buffers.indices_u32),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use alloc::sync::Arc;
+
+    use crate::{test::TestAsset, Physics, SkeletonJson};
+
+    use super::*;
+
+    /// Ensure the batches cover the buffers exactly and reference valid vertices.
+    #[test]
+    fn batched_drawer() {
+        for example_asset in TestAsset::all() {
+            let (mut skeleton, _) = example_asset.instance(true);
+            let drawer = BatchedDrawer {
+                cull_direction: CullDirection::Clockwise,
+                premultiplied_alpha: false,
+                color_space: ColorSpace::Linear,
+            };
+            let mut clipper = SkeletonClipping::new();
+            let renderable = drawer.draw(&mut skeleton, Some(&mut clipper));
+            assert!(!renderable.batches.is_empty());
+            let mut index_end = 0;
+            let mut vertex_end = 0;
+            for batch in &renderable.batches {
+                assert_eq!(batch.index_range.start, index_end);
+                assert_eq!(batch.vertex_range.start, vertex_end);
+                index_end = batch.index_range.end;
+                vertex_end = batch.vertex_range.end;
+                assert_eq!(batch.index_format, IndexFormat::U16);
+                assert!(renderable.indices[batch.index_range.clone()]
+                    .iter()
+                    .all(|index| (*index as usize) < batch.vertex_range.len()));
+            }
+            assert_eq!(index_end, renderable.indices.len());
+            assert!(renderable.indices_u32.is_empty());
+            assert_eq!(vertex_end, renderable.vertices.len());
+        }
+    }
+
+    /// Ensure batches with too many vertices for `u16` indices are drawn with `u32` indices.
+    #[test]
+    fn batched_drawer_u32_indices() {
+        // two meshes which fit `u16` indices on their own, but not when batched together
+        let mesh_vertices_count = IndexFormat::U16_MAX_VERTICES / 4 + 1;
+        let numbers = |values: &mut dyn Iterator<Item = usize>| {
+            values
+                .map(|value| value.to_string())
+                .collect::<Vec<_>>()
+                .join(",")
+        };
+        let mesh = format!(
+            concat!(
+                r#"{{"type":"mesh","path":"front-fist-closed","hull":3,"#,
+                r#""uvs":[{}],"vertices":[{}],"triangles":[0,1,{}]}}"#
+            ),
+            numbers(&mut (0..mesh_vertices_count * 2).map(|index| index % 2)),
+            numbers(&mut (0..mesh_vertices_count * 2)),
+            mesh_vertices_count - 1,
+        );
+        let json = format!(
+            concat!(
+                r#"{{"skeleton":{{"spine":"4.2.00"}},"bones":[{{"name":"root"}}],"slots":["#,
+                r#"{{"name":"a","bone":"root","attachment":"mesh"}},"#,
+                r#"{{"name":"b","bone":"root","attachment":"mesh"}}],"#,
+                r#""skins":[{{"name":"default","attachments":"#,
+                r#"{{"a":{{"mesh":{mesh}}},"b":{{"mesh":{mesh}}}}}}}]}}"#
+            ),
+            mesh = mesh
+        );
+        let skeleton_data = SkeletonJson::new(Arc::new(TestAsset::spineboy().atlas()))
+            .read_skeleton_data(json.as_bytes())
+            .unwrap();
+        let mut skeleton = Skeleton::new(Arc::new(skeleton_data));
+        skeleton.update_world_transform(Physics::None);
+        let drawer = BatchedDrawer {
+            cull_direction: CullDirection::Clockwise,
+            premultiplied_alpha: false,
+            color_space: ColorSpace::Linear,
+        };
+        let renderable = drawer.draw(&mut skeleton, None);
+        assert!(renderable.vertices.len() > IndexFormat::U16_MAX_VERTICES);
+        assert_eq!(renderable.batches.len(), 1);
+        let batch = &renderable.batches[0];
+        assert_eq!(batch.index_format, IndexFormat::U32);
+        assert_eq!(batch.vertex_range, 0..renderable.vertices.len());
+        assert_eq!(batch.index_range, 0..6);
+        assert!(renderable.indices.is_empty());
+        let last_index = mesh_vertices_count as u32 - 1;
+        let second_mesh_start = renderable.vertices.len() as u32 / 2;
+        assert_eq!(
+            renderable.indices_u32,
+            [
+                0,
+                1,
+                last_index,
+                second_mesh_start,
+                second_mesh_start + 1,
+                second_mesh_start + last_index
+            ]
+        );
+    }
+}
@@ -0,0 +1,136 @@
+use crate::BlendMode;
+
+/// A factor the source or destination of a blend is multiplied by, see [`BlendFactors`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BlendFactor {
+    Zero,
+    One,
+    SourceColor,
+    OneMinusSourceColor,
+    SourceAlpha,
+    OneMinusSourceAlpha,
+    DestinationColor,
+    OneMinusDestinationColor,
+    DestinationAlpha,
+    OneMinusDestinationAlpha,
+}
+
+/// How the source and destination of a blend are combined, after being multiplied by their
+/// [`BlendFactor`]s.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BlendEquation {
+    /// `source * source_factor + destination * destination_factor`
+    Add,
+    /// `source * source_factor - destination * destination_factor`
+    Subtract,
+    /// `destination * destination_factor - source * source_factor`
+    ReverseSubtract,
+}
+
+/// The blend of either the color or alpha channels, see [`BlendFactors`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BlendComponent {
+    pub equation: BlendEquation,
+    pub source_factor: BlendFactor,
+    pub destination_factor: BlendFactor,
+}
+
+impl BlendComponent {
+    const fn add(source_factor: BlendFactor, destination_factor: BlendFactor) -> Self {
+        Self {
+            equation: BlendEquation::Add,
+            source_factor,
+            destination_factor,
+        }
+    }
+}
+
+/// A renderer agnostic description of the blend state needed to draw with a [`BlendMode`], with
+/// the color and alpha channels blended separately.
+///
+/// Each [`BlendMode`] needs different factors depending on whether the texture has premultiplied
+/// alpha, so renderers must support 8 blend states in total. The factors match those used by the
+/// official Spine runtimes. Use [`BlendMode::blend_factors`] to look them up and translate them
+/// into the graphics library's own types once, instead of matching on every case.
+///
+/// ```
+/// use rusty_spine::{
+///     draw::{BlendEquation, BlendFactor},
+///     BlendMode,
+/// };
+///
+/// let blend_factors = BlendMode::Additive.blend_factors(true);
+/// assert_eq!(blend_factors.color.equation, BlendEquation::Add);
+/// assert_eq!(blend_factors.color.source_factor, BlendFactor::One);
+/// assert_eq!(blend_factors.color.destination_factor, BlendFactor::One);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BlendFactors {
+    pub color: BlendComponent,
+    pub alpha: BlendComponent,
+}
+
+impl BlendMode {
+    /// The blend state needed to draw with this blend mode, depending on whether the texture has
+    /// premultiplied alpha. See [`BlendFactors`].
+    #[must_use]
+    pub const fn blend_factors(self, premultiplied_alpha: bool) -> BlendFactors {
+        use BlendFactor::{
+            DestinationColor, One, OneMinusSourceAlpha, OneMinusSourceColor, SourceAlpha,
+        };
+        let color_source = if premultiplied_alpha {
+            One
+        } else {
+            SourceAlpha
+        };
+        match self {
+            Self::Normal => BlendFactors {
+                color: BlendComponent::add(color_source, OneMinusSourceAlpha),
+                alpha: BlendComponent::add(One, OneMinusSourceAlpha),
+            },
+            Self::Additive => BlendFactors {
+                color: BlendComponent::add(color_source, One),
+                alpha: BlendComponent::add(One, One),
+            },
+            Self::Multiply => BlendFactors {
+                color: BlendComponent::add(DestinationColor, OneMinusSourceAlpha),
+                alpha: BlendComponent::add(One, OneMinusSourceAlpha),
+            },
+            Self::Screen => BlendFactors {
+                color: BlendComponent::add(One, OneMinusSourceColor),
+                alpha: BlendComponent::add(One, OneMinusSourceColor),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::BlendMode;
+
+    use super::BlendFactor;
+
+    #[test]
+    fn blend_factors() {
+        for blend_mode in [
+            BlendMode::Normal,
+            BlendMode::Additive,
+            BlendMode::Multiply,
+            BlendMode::Screen,
+        ] {
+            let straight = blend_mode.blend_factors(false);
+            let premultiplied = blend_mode.blend_factors(true);
+            assert_eq!(straight.alpha, premultiplied.alpha);
+            assert_eq!(
+                straight.color.destination_factor,
+                premultiplied.color.destination_factor
+            );
+            let pma_changes_source = matches!(blend_mode, BlendMode::Normal | BlendMode::Additive);
+            assert_eq!(
+                straight.color.source_factor == BlendFactor::SourceAlpha,
+                pma_changes_source
+            );
+            assert_ne!(premultiplied.color.source_factor, BlendFactor::SourceAlpha);
+        }
+    }
+}
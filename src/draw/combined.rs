@@ -1,6 +1,9 @@
+use core::ops::Range;
+
+use crate::prelude::*;
 use crate::{c::c_void, BlendMode, Skeleton, SkeletonClipping};
 
-use super::{ColorSpace, CullDirection};
+use super::{BlendFactors, ColorSpace, CullDirection, IndexFormat, RenderRules};
 
 #[allow(unused_imports)]
 use crate::{draw::SimpleDrawer, extension};
@@ -16,13 +19,182 @@ pub struct CombinedRenderable {
     /// A list of dark color attributes for a mesh.
     /// See the [Spine User Guide](http://en.esotericsoftware.com/spine-slots#Tint-black).
     pub dark_colors: Vec<[f32; 4]>,
-    /// A list of indices for a mesh.
+    /// A list of indices for a mesh when using [`IndexFormat::U16`], otherwise empty.
     pub indices: Vec<u16>,
+    /// A list of indices for a mesh when using [`IndexFormat::U32`], otherwise empty.
+    pub indices_u32: Vec<u32>,
+    /// The type of this mesh's indices, which the renderer should bind them as.
+    pub index_format: IndexFormat,
     /// The blend mode to use when drawing this mesh.
     pub blend_mode: BlendMode,
+    /// Whether the colors were premultiplied, and the texture is expected to have premultiplied
+    /// alpha. Matches [`CombinedDrawer::premultiplied_alpha`] unless overridden by a
+    /// [`RenderRules`] rule.
+    pub premultiplied_alpha: bool,
+    /// The tag set by a matching [`RenderRules`] rule, if any.
+    pub tag: Option<String>,
     /// The attachment's renderer object as a raw pointer. Usually represents the texture created
     /// from [`extension::set_create_texture_cb`].
     pub attachment_renderer_object: Option<*const c_void>,
+    /// The draw order index of the first slot drawn into this renderable, which identifies it
+    /// across frames so long as the draw order and attachments don't change. Like
+    /// [`SimpleRenderable::slot_index`](`super::SimpleRenderable::slot_index`), this is an index
+    /// into [`Skeleton::draw_order`](`crate::Skeleton::draw_order`), not a slot data index.
+    pub first_slot_index: usize,
+}
+
+impl CombinedRenderable {
+    /// The blend state to draw this renderable with, from its `blend_mode` and
+    /// `premultiplied_alpha`. See [`BlendMode::blend_factors`].
+    #[must_use]
+    pub const fn blend_factors(&self) -> BlendFactors {
+        self.blend_mode.blend_factors(self.premultiplied_alpha)
+    }
+}
+
+/// Reusable buffers holding every renderable of a skeleton, filled by
+/// [`CombinedDrawer::draw_into`] or
+/// [`SkeletonController::combined_renderables_into`](`crate::controller::SkeletonController::combined_renderables_into`).
+///
+/// Each renderable is a [`MeshBatch`], whose vertex attributes and indices are ranges of the
+/// buffers. Filling the buffers clears them but keeps their capacity, so drawing into the same
+/// buffers every frame doesn't allocate once they are large enough.
+#[derive(Debug, Default, Clone)]
+pub struct MeshBuffers {
+    pub vertices: Vec<[f32; 2]>,
+    pub uvs: Vec<[f32; 2]>,
+    /// Vertex positions when using
+    /// [`VertexPositionFormat::Unorm16`](`crate::draw::VertexPositionFormat::Unorm16`),
+    /// otherwise empty, decoded with [`MeshBatch::vertex_offset`] and
+    /// [`MeshBatch::vertex_scale`].
+    pub unorm_vertices: Vec<[u16; 2]>,
+    /// UVs when using
+    /// [`VertexPositionFormat::Unorm16`](`crate::draw::VertexPositionFormat::Unorm16`),
+    /// otherwise empty.
+    pub unorm_uvs: Vec<[u16; 2]>,
+    pub colors: Vec<[f32; 4]>,
+    /// See the [Spine User Guide](http://en.esotericsoftware.com/spine-slots#Tint-black).
+    pub dark_colors: Vec<[f32; 4]>,
+    /// Colors when using
+    /// [`VertexColorFormat::PackedRgba8`](`crate::draw::VertexColorFormat::PackedRgba8`),
+    /// otherwise empty.
+    pub packed_colors: Vec<[u8; 4]>,
+    /// Dark colors when using
+    /// [`VertexColorFormat::PackedRgba8`](`crate::draw::VertexColorFormat::PackedRgba8`),
+    /// otherwise empty.
+    pub packed_dark_colors: Vec<[u8; 4]>,
+    /// Triangle indices of batches using [`IndexFormat::U16`], relative to the first vertex of
+    /// their batch.
+    pub indices: Vec<u16>,
+    /// Triangle indices of batches using [`IndexFormat::U32`], relative to the first vertex of
+    /// their batch.
+    pub indices_u32: Vec<u32>,
+    /// The renderables, in the order they are drawn.
+    pub batches: Vec<MeshBatch>,
+    /// The index format to generate for every batch. If [`None`], each batch uses
+    /// [`IndexFormat::U16`] unless it has too many vertices. If [`IndexFormat::U16`], batches are
+    /// split so they all fit.
+    pub index_format: Option<IndexFormat>,
+    /// Scratch space for the world vertices of an attachment.
+    world_vertices: Vec<f32>,
+    /// Scratch space for the indices of an attachment.
+    attachment_indices: Vec<u16>,
+}
+
+impl MeshBuffers {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[must_use]
+    pub fn with_index_format(self, index_format: Option<IndexFormat>) -> Self {
+        Self {
+            index_format,
+            ..self
+        }
+    }
+
+    /// Remove all renderables, keeping the allocated capacity.
+    pub fn clear(&mut self) {
+        self.vertices.clear();
+        self.uvs.clear();
+        self.unorm_vertices.clear();
+        self.unorm_uvs.clear();
+        self.colors.clear();
+        self.dark_colors.clear();
+        self.packed_colors.clear();
+        self.packed_dark_colors.clear();
+        self.indices.clear();
+        self.indices_u32.clear();
+        self.batches.clear();
+    }
+
+    /// Split the buffers into a renderable for each batch.
+    pub(crate) fn into_renderables(mut self) -> Vec<CombinedRenderable> {
+        let mut renderables = Vec::with_capacity(self.batches.len());
+        while let Some(batch) = self.batches.pop() {
+            let (indices, indices_u32) = match batch.index_format {
+                IndexFormat::U16 => (self.indices.split_off(batch.indices.start), vec![]),
+                IndexFormat::U32 => (vec![], self.indices_u32.split_off(batch.indices.start)),
+            };
+            self.vertices.truncate(batch.vertices.end);
+            self.uvs.truncate(batch.vertices.end);
+            self.colors.truncate(batch.vertices.end);
+            self.dark_colors.truncate(batch.vertices.end);
+            renderables.push(CombinedRenderable {
+                vertices: self.vertices.split_off(batch.vertices.start),
+                uvs: self.uvs.split_off(batch.vertices.start),
+                colors: self.colors.split_off(batch.vertices.start),
+                dark_colors: self.dark_colors.split_off(batch.vertices.start),
+                indices,
+                indices_u32,
+                index_format: batch.index_format,
+                blend_mode: batch.blend_mode,
+                premultiplied_alpha: batch.premultiplied_alpha,
+                tag: batch.tag,
+                attachment_renderer_object: batch.attachment_renderer_object,
+                first_slot_index: batch.first_slot_index,
+            });
+        }
+        renderables.reverse();
+        renderables
+    }
+}
+
+/// A renderable in [`MeshBuffers`], drawn with a single blend mode and texture.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MeshBatch {
+    /// The range of the batch's vertex attributes in the buffers.
+    pub vertices: Range<usize>,
+    /// The range of the batch's indices in [`MeshBuffers::indices`] or
+    /// [`MeshBuffers::indices_u32`], depending on its [`index_format`](`Self::index_format`).
+    pub indices: Range<usize>,
+    /// The type of the batch's indices, which the renderer should bind them as.
+    pub index_format: IndexFormat,
+    /// The minimum corner of the batch's bounds, used to decode
+    /// [`MeshBuffers::unorm_vertices`].
+    pub vertex_offset: [f32; 2],
+    /// The size of the batch's bounds, used to decode [`MeshBuffers::unorm_vertices`].
+    pub vertex_scale: [f32; 2],
+    pub blend_mode: BlendMode,
+    /// See [`CombinedRenderable::premultiplied_alpha`].
+    pub premultiplied_alpha: bool,
+    /// The tag set by a matching [`RenderRules`] rule, if any.
+    pub tag: Option<String>,
+    /// See [`CombinedRenderable::attachment_renderer_object`].
+    pub attachment_renderer_object: Option<*const c_void>,
+    /// See [`CombinedRenderable::first_slot_index`].
+    pub first_slot_index: usize,
+}
+
+impl MeshBatch {
+    /// The blend state to draw this batch with, from its `blend_mode` and
+    /// `premultiplied_alpha`. See [`BlendMode::blend_factors`].
+    #[must_use]
+    pub const fn blend_factors(&self) -> BlendFactors {
+        self.blend_mode.blend_factors(self.premultiplied_alpha)
+    }
 }
 
 /// A combined drawer with a mesh combining optimization.
@@ -44,6 +216,10 @@ impl CombinedDrawer {
     /// renderables are expected to be rendered in the order provided with the first renderable
     /// being drawn behind all the others.
     ///
+    /// The renderables and their order only depend on the draw order and the attachments of the
+    /// slots, so they stay the same across frames until either changes, and each renderable can be
+    /// identified by its [`first_slot_index`](`CombinedRenderable::first_slot_index`).
+    ///
     /// This drawer can provide a significant performance advantage over the [`SimpleDrawer`] in
     /// most cases.
     ///
@@ -53,20 +229,85 @@ impl CombinedDrawer {
     pub fn draw(
         &self,
         skeleton: &mut Skeleton,
-        mut clipper: Option<&mut SkeletonClipping>,
+        clipper: Option<&mut SkeletonClipping>,
     ) -> Vec<CombinedRenderable> {
-        let mut renderables = vec![];
-        let mut vertices = vec![];
-        let mut uvs = vec![];
-        let mut colors = vec![];
-        let mut dark_colors = vec![];
-        let mut indices = vec![];
-        let mut blend_mode = BlendMode::Normal;
-        let mut attachment_renderer_object = None;
-        let mut world_vertices = vec![];
-        world_vertices.resize(1000, 0.);
-        let mut vertex_base: u16 = 0;
-        let mut index_base: u16 = 0;
+        self.draw_with_rules(skeleton, clipper, &RenderRules::new())
+    }
+
+    /// The same as [`draw`](`Self::draw`), but with the blend mode, premultiplied alpha, and tag
+    /// of each attachment overridden by any matching [`RenderRules`]. Attachments are only
+    /// batched together if these also match.
+    ///
+    /// # Panics
+    ///
+    /// Panics if not using the default attachment loader with valid atlas regions.
+    pub fn draw_with_rules(
+        &self,
+        skeleton: &mut Skeleton,
+        clipper: Option<&mut SkeletonClipping>,
+        rules: &RenderRules,
+    ) -> Vec<CombinedRenderable> {
+        let mut buffers = MeshBuffers::new();
+        self.draw_into(skeleton, clipper, rules, &mut buffers);
+        buffers.into_renderables()
+    }
+
+    /// The same as [`draw_with_rules`](`Self::draw_with_rules`), but clears and refills
+    /// `buffers` instead of allocating new renderables, so the buffers can be reused every frame.
+    /// Each renderable is a [`MeshBatch`] of the buffers.
+    ///
+    /// ```
+    /// # #[path="../test.rs"]
+    /// # mod test;
+    /// # use rusty_spine::{draw::*, SkeletonClipping};
+    /// # let (mut skeleton, _) = test::TestAsset::spineboy().instance(true);
+    /// let drawer = CombinedDrawer {
+    ///     cull_direction: CullDirection::Clockwise,
+    ///     premultiplied_alpha: false,
+    ///     color_space: ColorSpace::SRGB,
+    /// };
+    /// let mut clipper = SkeletonClipping::new();
+    /// let mut buffers = MeshBuffers::new();
+    /// // every frame
+    /// drawer.draw_into(&mut skeleton, Some(&mut clipper), &RenderRules::new(), &mut buffers);
+    /// for batch in &buffers.batches {
+    ///     let vertices = &buffers.vertices[batch.vertices.clone()];
+    ///     let indices = &buffers.indices[batch.indices.clone()];
+    ///     // draw the vertices with the indices, which start at 0 for each batch
+    /// }
+    /// ```
+    ///
+    /// The `unorm` and `packed` attributes of the buffers are left empty.
+    ///
+    /// # Panics
+    ///
+    /// Panics if not using the default attachment loader with valid atlas regions.
+    pub fn draw_into(
+        &self,
+        skeleton: &mut Skeleton,
+        mut clipper: Option<&mut SkeletonClipping>,
+        rules: &RenderRules,
+        buffers: &mut MeshBuffers,
+    ) {
+        buffers.clear();
+        let MeshBuffers {
+            vertices,
+            uvs,
+            colors,
+            dark_colors,
+            indices,
+            indices_u32,
+            batches,
+            index_format,
+            world_vertices,
+            attachment_indices,
+            ..
+        } = buffers;
+        let index_format = *index_format;
+        let mut batch: Option<MeshBatch> = None;
+        if world_vertices.len() < 1000 {
+            world_vertices.resize(1000, 0.);
+        }
         for slot_index in 0..skeleton.slots_count() {
             let Some(slot) = skeleton.draw_order_at_index(slot_index) else {
                 continue;
@@ -93,23 +334,35 @@ impl CombinedDrawer {
             }
 
             if let Some(mesh_attachment) = slot.attachment().and_then(|a| a.as_mesh()) {
+                // the vertices are read below with a stride of 2 for every world vertex float
+                let world_vertices_length = mesh_attachment.world_vertices_length() as usize * 2;
+                if world_vertices.len() < world_vertices_length {
+                    world_vertices.resize(world_vertices_length, 0.);
+                }
                 unsafe {
-                    mesh_attachment.compute_world_vertices(
+                    mesh_attachment.compute_world_vertices_unchecked(
                         &slot,
                         0,
                         mesh_attachment.world_vertices_length(),
-                        &mut world_vertices,
+                        world_vertices,
                         0,
                         2,
                     );
                 }
             } else if let Some(region_attachment) = slot.attachment().and_then(|a| a.as_region()) {
                 unsafe {
-                    region_attachment.compute_world_vertices(&slot, &mut world_vertices, 0, 2);
+                    region_attachment.compute_world_vertices_unchecked(&slot, world_vertices, 0, 2);
                 }
             }
 
-            let next_blend_mode = slot.data().blend_mode();
+            let render_override = rules.resolve(&slot);
+            let next_blend_mode = render_override
+                .blend_mode
+                .unwrap_or_else(|| slot.data().blend_mode());
+            let next_premultiplied_alpha = render_override
+                .premultiplied_alpha
+                .unwrap_or(self.premultiplied_alpha);
+            let next_tag = render_override.tag;
             let next_attachment_renderer_object =
                 slot.attachment().and_then(|a| a.as_mesh()).map_or_else(
                     || {
@@ -155,39 +408,47 @@ impl CombinedDrawer {
                     },
                 );
 
-            if slot_index == 0 {
-                blend_mode = next_blend_mode;
-                attachment_renderer_object = next_attachment_renderer_object;
-            }
-            if blend_mode != next_blend_mode
-                || attachment_renderer_object != next_attachment_renderer_object
-            {
-                renderables.push(CombinedRenderable {
-                    vertices,
-                    uvs,
-                    indices,
-                    colors,
-                    dark_colors,
-                    blend_mode,
-                    attachment_renderer_object,
-                });
-                vertices = vec![];
-                uvs = vec![];
-                colors = vec![];
-                dark_colors = vec![];
-                indices = vec![];
-                vertex_base = 0;
-                index_base = 0;
+            if batch.as_ref().is_some_and(|batch| {
+                batch.blend_mode != next_blend_mode
+                    || batch.premultiplied_alpha != next_premultiplied_alpha
+                    || batch.tag != next_tag
+                    || batch.attachment_renderer_object != next_attachment_renderer_object
+            }) {
+                if let Some(batch) = batch.take() {
+                    let vertex_end = vertices.len();
+                    finish_batch(
+                        batch,
+                        vertex_end,
+                        index_format,
+                        indices,
+                        indices_u32,
+                        batches,
+                    );
+                }
             }
-            blend_mode = next_blend_mode;
-            attachment_renderer_object = next_attachment_renderer_object;
+            let batch = batch.get_or_insert(MeshBatch {
+                vertices: vertices.len()..vertices.len(),
+                indices: indices_u32.len()..indices_u32.len(),
+                index_format: IndexFormat::U32,
+                vertex_offset: [0., 0.],
+                vertex_scale: [0., 0.],
+                blend_mode: next_blend_mode,
+                premultiplied_alpha: next_premultiplied_alpha,
+                tag: next_tag,
+                attachment_renderer_object: next_attachment_renderer_object,
+                first_slot_index: slot_index,
+            });
+            let premultiplied_alpha = batch.premultiplied_alpha;
+            // the start of this attachment in the buffers
+            let vertex_start = vertices.len();
+            attachment_indices.clear();
 
             let (color, dark_color) = if let Some(mesh_attachment) =
                 slot.attachment().and_then(|a| a.as_mesh())
             {
                 let mut color = mesh_attachment.color() * slot.color() * skeleton.color();
                 let mut dark_color = slot.dark_color().unwrap_or_default();
-                if self.premultiplied_alpha {
+                if premultiplied_alpha {
                     color.premultiply_alpha();
                     dark_color *= color.a;
                     dark_color.a = 1.0;
@@ -205,7 +466,7 @@ impl CombinedDrawer {
                 };
 
                 uvs.resize(
-                    vertex_base as usize + mesh_attachment.world_vertices_length() as usize,
+                    vertex_start + mesh_attachment.world_vertices_length() as usize,
                     [0., 0.],
                 );
                 for i in 0..mesh_attachment.world_vertices_length() {
@@ -222,7 +483,7 @@ impl CombinedDrawer {
                 macro_rules! copy_uvs {
                     ($i:ident) => {
                         let index = *mesh_attachment.triangles().offset($i);
-                        uvs[vertex_base as usize + index as usize] = [
+                        uvs[vertex_start + index as usize] = [
                             *mesh_attachment.c_ptr_mut().uvs.offset(index as isize * 2),
                             *mesh_attachment
                                 .c_ptr_mut()
@@ -230,7 +491,7 @@ impl CombinedDrawer {
                                 .offset(index as isize * 2 + 1),
                         ];
                         let index = *mesh_attachment.triangles().offset($i + 1);
-                        uvs[vertex_base as usize + index as usize] = [
+                        uvs[vertex_start + index as usize] = [
                             *mesh_attachment.c_ptr_mut().uvs.offset(index as isize * 2),
                             *mesh_attachment
                                 .c_ptr_mut()
@@ -238,7 +499,7 @@ impl CombinedDrawer {
                                 .offset(index as isize * 2 + 1),
                         ];
                         let index = *mesh_attachment.triangles().offset($i + 2);
-                        uvs[vertex_base as usize + index as usize] = [
+                        uvs[vertex_start + index as usize] = [
                             *mesh_attachment.c_ptr_mut().uvs.offset(index as isize * 2),
                             *mesh_attachment
                                 .c_ptr_mut()
@@ -251,18 +512,18 @@ impl CombinedDrawer {
                 if matches!(self.cull_direction, CullDirection::CounterClockwise) {
                     for i in (0..mesh_attachment.triangles_count() as isize).step_by(3) {
                         unsafe {
-                            indices.push(vertex_base + *mesh_attachment.triangles().offset(i + 2));
-                            indices.push(vertex_base + *mesh_attachment.triangles().offset(i + 1));
-                            indices.push(vertex_base + *mesh_attachment.triangles().offset(i));
+                            attachment_indices.push(*mesh_attachment.triangles().offset(i + 2));
+                            attachment_indices.push(*mesh_attachment.triangles().offset(i + 1));
+                            attachment_indices.push(*mesh_attachment.triangles().offset(i));
                             copy_uvs!(i);
                         }
                     }
                 } else {
                     for i in (0..mesh_attachment.triangles_count() as isize).step_by(3) {
                         unsafe {
-                            indices.push(vertex_base + *mesh_attachment.triangles().offset(i));
-                            indices.push(vertex_base + *mesh_attachment.triangles().offset(i + 1));
-                            indices.push(vertex_base + *mesh_attachment.triangles().offset(i + 2));
+                            attachment_indices.push(*mesh_attachment.triangles().offset(i));
+                            attachment_indices.push(*mesh_attachment.triangles().offset(i + 1));
+                            attachment_indices.push(*mesh_attachment.triangles().offset(i + 2));
                             copy_uvs!(i);
                         }
                     }
@@ -272,7 +533,7 @@ impl CombinedDrawer {
             } else if let Some(region_attachment) = slot.attachment().and_then(|a| a.as_region()) {
                 let mut color = region_attachment.color() * slot.color() * skeleton.color();
                 let mut dark_color = slot.dark_color().unwrap_or_default();
-                if self.premultiplied_alpha {
+                if premultiplied_alpha {
                     color.premultiply_alpha();
                     dark_color *= color.a;
                     dark_color.a = 1.0;
@@ -305,19 +566,9 @@ impl CombinedDrawer {
                 }
 
                 if matches!(self.cull_direction, CullDirection::CounterClockwise) {
-                    indices.push(vertex_base + 2);
-                    indices.push(vertex_base + 1);
-                    indices.push(vertex_base);
-                    indices.push(vertex_base);
-                    indices.push(vertex_base + 3);
-                    indices.push(vertex_base + 2);
+                    attachment_indices.extend([2, 1, 0, 0, 3, 2]);
                 } else {
-                    indices.push(vertex_base);
-                    indices.push(vertex_base + 1);
-                    indices.push(vertex_base + 2);
-                    indices.push(vertex_base + 2);
-                    indices.push(vertex_base + 3);
-                    indices.push(vertex_base);
+                    attachment_indices.extend([0, 1, 2, 2, 3, 0]);
                 }
 
                 (color, dark_color)
@@ -327,14 +578,11 @@ impl CombinedDrawer {
 
             if let Some(clipper) = clipper.as_deref_mut() {
                 if clipper.is_clipping() {
-                    for i in index_base..indices.len() as u16 {
-                        indices[i as usize] -= vertex_base;
-                    }
                     unsafe {
                         clipper.clip_triangles(
-                            &mut vertices.as_mut_slice()[(vertex_base as usize)..],
-                            &mut indices.as_mut_slice()[(index_base as usize)..],
-                            &mut uvs.as_mut_slice()[(vertex_base as usize)..],
+                            &mut vertices.as_mut_slice()[vertex_start..],
+                            attachment_indices,
+                            &mut uvs.as_mut_slice()[vertex_start..],
                             2,
                         );
                         let clipped_triangles_size =
@@ -343,71 +591,132 @@ impl CombinedDrawer {
                             (*clipper.c_ptr_ref().clippedVertices).size as usize;
                         let clipped_uvs_size = (*clipper.c_ptr_ref().clippedUVs).size as usize;
                         colors.resize(
-                            vertex_base as usize + (clipped_vertices_size / 2),
+                            vertex_start + (clipped_vertices_size / 2),
                             [color.r, color.g, color.b, color.a],
                         );
                         dark_colors.resize(
-                            vertex_base as usize + (clipped_vertices_size / 2),
+                            vertex_start + (clipped_vertices_size / 2),
                             [dark_color.r, dark_color.g, dark_color.b, dark_color.a],
                         );
-                        indices.resize(index_base as usize + clipped_triangles_size, 0);
-                        std::ptr::copy_nonoverlapping(
+                        attachment_indices.resize(clipped_triangles_size, 0);
+                        core::ptr::copy_nonoverlapping(
                             (*clipper.c_ptr_ref().clippedTriangles).items,
-                            indices.as_mut_ptr().offset(index_base as isize),
+                            attachment_indices.as_mut_ptr(),
                             clipped_triangles_size,
                         );
-                        vertices.resize(vertex_base as usize + clipped_vertices_size / 2, [0., 0.]);
-                        std::ptr::copy_nonoverlapping(
+                        vertices.resize(vertex_start + clipped_vertices_size / 2, [0., 0.]);
+                        core::ptr::copy_nonoverlapping(
                             (*clipper.c_ptr_ref().clippedVertices).items,
-                            vertices
-                                .as_mut_ptr()
-                                .offset(vertex_base as isize)
-                                .cast::<f32>(),
+                            vertices.as_mut_ptr().add(vertex_start).cast::<f32>(),
                             clipped_vertices_size,
                         );
-                        uvs.resize(vertex_base as usize + clipped_uvs_size / 2, [0., 0.]);
-                        std::ptr::copy_nonoverlapping(
+                        uvs.resize(vertex_start + clipped_uvs_size / 2, [0., 0.]);
+                        core::ptr::copy_nonoverlapping(
                             (*clipper.c_ptr_ref().clippedUVs).items,
-                            uvs.as_mut_ptr().offset(vertex_base as isize).cast::<f32>(),
+                            uvs.as_mut_ptr().add(vertex_start).cast::<f32>(),
                             clipped_uvs_size,
                         );
                     }
-                    for i in index_base..indices.len() as u16 {
-                        indices[i as usize] += vertex_base;
-                    }
                 }
             }
 
-            vertex_base = vertices.len() as u16;
-            index_base = indices.len() as u16;
+            if index_format == Some(IndexFormat::U16)
+                && vertex_start > batch.vertices.start
+                && vertices.len() - batch.vertices.start > IndexFormat::U16_MAX_VERTICES
+            {
+                // continue in a new batch so the indices of both fit in `u16`
+                let next_batch = MeshBatch {
+                    vertices: vertex_start..vertex_start,
+                    indices: indices_u32.len()..indices_u32.len(),
+                    ..batch.clone()
+                };
+                let batch = core::mem::replace(batch, next_batch);
+                finish_batch(
+                    batch,
+                    vertex_start,
+                    index_format,
+                    indices,
+                    indices_u32,
+                    batches,
+                );
+            }
+            let vertex_base = vertex_start - batch.vertices.start;
+            assert!(
+                index_format != Some(IndexFormat::U16)
+                    || vertices.len() - batch.vertices.start <= IndexFormat::U16_MAX_VERTICES,
+                "attachment has too many vertices for u16 indices"
+            );
+            indices_u32.extend(
+                attachment_indices
+                    .iter()
+                    .map(|index| (vertex_base + usize::from(*index)) as u32),
+            );
 
             if let Some(clipper) = clipper.as_deref_mut() {
                 clipper.clip_end(&slot);
             }
         }
 
-        if !indices.is_empty() {
-            renderables.push(CombinedRenderable {
-                vertices,
-                uvs,
+        if let Some(batch) = batch {
+            let vertex_end = vertices.len();
+            finish_batch(
+                batch,
+                vertex_end,
+                index_format,
                 indices,
-                colors,
-                dark_colors,
-                blend_mode,
-                attachment_renderer_object,
-            });
+                indices_u32,
+                batches,
+            );
         }
+        // drop the vertices of attachments without triangles at the end
+        let vertices_len = batches.last().map_or(0, |batch| batch.vertices.end);
+        vertices.truncate(vertices_len);
+        uvs.truncate(vertices_len);
+        colors.truncate(vertices_len);
+        dark_colors.truncate(vertices_len);
 
         if let Some(clipper) = clipper {
             clipper.clip_end2();
         }
-        renderables
     }
 }
 
+/// End `batch` at `vertex_end` and the end of the indices, moving its indices to
+/// [`MeshBuffers::indices`] if it uses `u16` indices, or dropping it if it has no triangles.
+fn finish_batch(
+    mut batch: MeshBatch,
+    vertex_end: usize,
+    index_format: Option<IndexFormat>,
+    indices: &mut Vec<u16>,
+    indices_u32: &mut Vec<u32>,
+    batches: &mut Vec<MeshBatch>,
+) {
+    if indices_u32.len() == batch.indices.start {
+        return;
+    }
+    batch.vertices.end = vertex_end;
+    batch.index_format = index_format.unwrap_or_else(|| IndexFormat::fitting(batch.vertices.len()));
+    match batch.index_format {
+        IndexFormat::U16 => {
+            let index_start = indices.len();
+            indices.extend(
+                indices_u32
+                    .drain(batch.indices.start..)
+                    .map(|index| index as u16),
+            );
+            batch.indices = index_start..indices.len();
+        }
+        IndexFormat::U32 => batch.indices.end = indices_u32.len(),
+    }
+    batches.push(batch);
+}
+
 #[cfg(test)]
 mod test {
-    use crate::test::TestAsset;
+    use crate::{
+        draw::{NamePattern, RenderOverride},
+        test::TestAsset,
+    };
 
     use super::*;
 
@@ -428,4 +737,155 @@ mod test {
             }
         }
     }
+
+    /// Ensure attachments with different render overrides are not combined.
+    #[test]
+    fn combined_drawer_rules() {
+        let (mut skeleton, _) = TestAsset::spineboy().instance(true);
+        let drawer = CombinedDrawer {
+            cull_direction: CullDirection::Clockwise,
+            premultiplied_alpha: false,
+            color_space: ColorSpace::Linear,
+        };
+        let rules = RenderRules::new().with_rule(
+            NamePattern::Exact("head".to_owned()),
+            RenderOverride::new()
+                .with_premultiplied_alpha(true)
+                .with_tag("head"),
+        );
+        let renderables = drawer.draw_with_rules(&mut skeleton, None, &rules);
+        let tagged = renderables
+            .iter()
+            .filter(|renderable| renderable.tag.is_some())
+            .collect::<Vec<_>>();
+        assert_eq!(tagged.len(), 1);
+        let head_vertices = skeleton
+            .find_slot("head")
+            .unwrap()
+            .attachment()
+            .and_then(|attachment| attachment.as_mesh())
+            .unwrap()
+            .world_vertices_length() as usize;
+        assert_eq!(tagged[0].vertices.len(), head_vertices);
+        assert!(tagged[0].premultiplied_alpha);
+        assert!(renderables
+            .iter()
+            .filter(|renderable| renderable.tag.is_none())
+            .all(|renderable| !renderable.premultiplied_alpha));
+    }
+
+    /// Ensure renderables are identified by draw order index.
+    #[test]
+    fn combined_drawer_first_slot_index() {
+        let (mut skeleton, _) = TestAsset::spineboy().instance(true);
+        unsafe {
+            let c_skeleton = skeleton.c_ptr_mut();
+            core::slice::from_raw_parts_mut(c_skeleton.drawOrder, c_skeleton.slotsCount as usize)
+                .reverse();
+        }
+        let drawer = CombinedDrawer {
+            cull_direction: CullDirection::Clockwise,
+            premultiplied_alpha: false,
+            color_space: ColorSpace::Linear,
+        };
+        let renderables = drawer.draw(&mut skeleton, None);
+        let first_drawn_index = skeleton
+            .draw_order()
+            .position(|slot| {
+                slot.attachment()
+                    .is_some_and(|a| a.as_region().is_some() || a.as_mesh().is_some())
+            })
+            .unwrap();
+        let first_drawn_slot = skeleton.draw_order_at_index(first_drawn_index).unwrap();
+        assert_ne!(first_drawn_slot.data().index(), first_drawn_index);
+        assert_eq!(renderables[0].first_slot_index, first_drawn_index);
+    }
+
+    /// Ensure each index format draws the same triangles.
+    #[test]
+    fn combined_drawer_index_formats() {
+        let (mut skeleton, _) = TestAsset::spineboy().instance(true);
+        let drawer = CombinedDrawer {
+            cull_direction: CullDirection::Clockwise,
+            premultiplied_alpha: false,
+            color_space: ColorSpace::Linear,
+        };
+        let mut clipper = SkeletonClipping::new();
+        let draw = |skeleton: &mut Skeleton, clipper: &mut SkeletonClipping, index_format| {
+            let mut buffers = MeshBuffers::new().with_index_format(index_format);
+            drawer.draw_into(skeleton, Some(clipper), &RenderRules::new(), &mut buffers);
+            buffers
+        };
+        let automatic = draw(&mut skeleton, &mut clipper, None);
+        assert!(automatic.indices_u32.is_empty());
+        assert!(automatic
+            .batches
+            .iter()
+            .all(|batch| batch.index_format == IndexFormat::U16));
+        let u16_buffers = draw(&mut skeleton, &mut clipper, Some(IndexFormat::U16));
+        assert_eq!(u16_buffers.batches, automatic.batches);
+        assert_eq!(u16_buffers.indices, automatic.indices);
+        let u32_buffers = draw(&mut skeleton, &mut clipper, Some(IndexFormat::U32));
+        assert!(u32_buffers.indices.is_empty());
+        assert_eq!(u32_buffers.batches.len(), automatic.batches.len());
+        for (u32_batch, u16_batch) in u32_buffers.batches.iter().zip(&automatic.batches) {
+            assert_eq!(u32_batch.index_format, IndexFormat::U32);
+            assert_eq!(u32_batch.vertices, u16_batch.vertices);
+            assert!(u32_buffers.indices_u32[u32_batch.indices.clone()]
+                .iter()
+                .copied()
+                .eq(automatic.indices[u16_batch.indices.clone()]
+                    .iter()
+                    .map(|index| u32::from(*index))));
+        }
+
+        let renderables = drawer.draw(&mut skeleton, Some(&mut clipper));
+        assert!(renderables.iter().all(|renderable| {
+            renderable.index_format == IndexFormat::U16 && renderable.indices_u32.is_empty()
+        }));
+    }
+
+    /// Ensure batches with too many vertices for `u16` indices use `u32` indices.
+    #[test]
+    fn finish_batch_index_format() {
+        let batch = MeshBatch {
+            vertices: 0..0,
+            indices: 0..0,
+            index_format: IndexFormat::U32,
+            vertex_offset: [0., 0.],
+            vertex_scale: [0., 0.],
+            blend_mode: BlendMode::Normal,
+            premultiplied_alpha: false,
+            tag: None,
+            attachment_renderer_object: None,
+            first_slot_index: 0,
+        };
+        let mut indices = vec![];
+        let mut batches = vec![];
+        for (vertices_count, index_format) in [
+            (IndexFormat::U16_MAX_VERTICES, IndexFormat::U16),
+            (IndexFormat::U16_MAX_VERTICES + 1, IndexFormat::U32),
+        ] {
+            let last_index = vertices_count as u32 - 1;
+            let mut indices_u32 = vec![0, 1, last_index];
+            finish_batch(
+                batch.clone(),
+                vertices_count,
+                None,
+                &mut indices,
+                &mut indices_u32,
+                &mut batches,
+            );
+            let batch = batches.pop().unwrap();
+            assert_eq!(batch.index_format, index_format);
+            assert_eq!(batch.vertices, 0..vertices_count);
+            match index_format {
+                IndexFormat::U16 => {
+                    assert!(indices_u32.is_empty());
+                    assert_eq!(indices[batch.indices], [0, 1, u16::MAX]);
+                }
+                IndexFormat::U32 => assert_eq!(indices_u32[batch.indices], [0, 1, last_index]),
+            }
+        }
+    }
 }
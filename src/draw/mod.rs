@@ -2,14 +2,27 @@
 //!
 //! Drawers generate mesh information ready to be used in graphics libraries and game engines.
 //!
-//! Two implementations are currently provided:
+//! Three implementations are currently provided:
 //! - [`SimpleDrawer`]
 //! - [`CombinedDrawer`]
+//! - [`BatchedDrawer`]
+//!
+//! Each drawer can override how attachments are rendered based on their names, see
+//! [`RenderRules`].
+//!
+//! The blend state needed to draw each renderable can be looked up with
+//! [`BlendMode::blend_factors`](`crate::BlendMode::blend_factors`), see [`BlendFactors`].
 
+mod batched;
+mod blend;
 mod combined;
+mod rules;
 mod simple;
 
+pub use batched::*;
+pub use blend::*;
 pub use combined::*;
+pub use rules::*;
 pub use simple::*;
 
 /// Cull direction to use with helper draw functions.
@@ -25,3 +38,54 @@ pub enum ColorSpace {
     SRGB,
     Linear,
 }
+
+/// Vertex color format to use with [`SkeletonController`](`crate::controller::SkeletonController`)
+/// renderables.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VertexColorFormat {
+    /// One `[f32; 4]` per vertex.
+    Float,
+    /// One `[u8; 4]` per vertex, a quarter of the size of [`VertexColorFormat::Float`]. Colors are
+    /// packed after alpha is premultiplied and after conversion to the requested [`ColorSpace`],
+    /// so they match the float colors to within rounding. Linear colors lose precision in dark
+    /// tones when stored in 8 bits, so prefer [`ColorSpace::SRGB`] with an sRGB vertex format
+    /// or decoding in the shader.
+    PackedRgba8,
+}
+
+/// The type of the indices of generated render data.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IndexFormat {
+    /// `u16` indices, for renderables with up to
+    /// [`U16_MAX_VERTICES`](`Self::U16_MAX_VERTICES`) vertices.
+    U16,
+    /// `u32` indices, for renderables with any number of vertices.
+    U32,
+}
+
+impl IndexFormat {
+    /// The most vertices which can be indexed with [`IndexFormat::U16`].
+    pub const U16_MAX_VERTICES: usize = u16::MAX as usize + 1;
+
+    /// The smallest index format which can index `vertices_count` vertices.
+    #[must_use]
+    pub const fn fitting(vertices_count: usize) -> Self {
+        if vertices_count <= Self::U16_MAX_VERTICES {
+            Self::U16
+        } else {
+            Self::U32
+        }
+    }
+}
+
+/// Vertex position and UV format to use with
+/// [`SkeletonController`](`crate::controller::SkeletonController`) renderables.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VertexPositionFormat {
+    /// One `[f32; 2]` per vertex for both positions and UVs.
+    Float,
+    /// One `[u16; 2]` per vertex for both positions and UVs, half the size of
+    /// [`VertexPositionFormat::Float`], for use as normalized vertex attributes. Positions are
+    /// stored as a fraction of the renderable's bounds and UVs as a fraction of the texture.
+    Unorm16,
+}
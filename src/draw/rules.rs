@@ -0,0 +1,208 @@
+use crate::prelude::*;
+use crate::{BlendMode, Slot};
+
+/// A pattern matched against attachment and region names by [`RenderRules`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NamePattern {
+    /// Matches names equal to the string.
+    Exact(String),
+    /// Matches names starting with the string.
+    Prefix(String),
+    /// Matches names ending with the string, such as `"_add"`.
+    Suffix(String),
+    /// Matches names containing the string anywhere.
+    Contains(String),
+}
+
+impl NamePattern {
+    /// Returns `true` if `name` matches this pattern.
+    #[must_use]
+    pub fn matches(&self, name: &str) -> bool {
+        match self {
+            Self::Exact(pattern) => name == pattern,
+            Self::Prefix(pattern) => name.starts_with(pattern.as_str()),
+            Self::Suffix(pattern) => name.ends_with(pattern.as_str()),
+            Self::Contains(pattern) => name.contains(pattern.as_str()),
+        }
+    }
+}
+
+/// Changes to how an attachment is rendered, applied by [`RenderRules`]. Fields set to [`None`]
+/// are left unchanged.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RenderOverride {
+    /// The blend mode to draw with, instead of the slot's blend mode.
+    pub blend_mode: Option<BlendMode>,
+    /// Whether the attachment's texture has premultiplied alpha, instead of the drawer's setting.
+    pub premultiplied_alpha: Option<bool>,
+    /// A custom tag passed through to the renderable, for engine-specific rendering such as
+    /// choosing a shader.
+    pub tag: Option<String>,
+}
+
+impl RenderOverride {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[must_use]
+    pub fn with_blend_mode(self, blend_mode: BlendMode) -> Self {
+        Self {
+            blend_mode: Some(blend_mode),
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn with_premultiplied_alpha(self, premultiplied_alpha: bool) -> Self {
+        Self {
+            premultiplied_alpha: Some(premultiplied_alpha),
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn with_tag(self, tag: &str) -> Self {
+        Self {
+            tag: Some(tag.to_owned()),
+            ..self
+        }
+    }
+}
+
+/// A list of rules which override how attachments are rendered based on their names, so art can
+/// encode rendering intent in attachment or atlas region names.
+///
+/// ```
+/// # #[path="../test.rs"]
+/// # mod test;
+/// use rusty_spine::{
+///     draw::{ColorSpace, CullDirection, NamePattern, RenderOverride, RenderRules, SimpleDrawer},
+///     BlendMode,
+/// };
+///
+/// # let (mut skeleton, _) = test::TestAsset::spineboy().instance(true);
+/// let rules = RenderRules::new()
+///     .with_rule(
+///         NamePattern::Suffix("_add".to_owned()),
+///         RenderOverride::new().with_blend_mode(BlendMode::Additive),
+///     )
+///     .with_rule(
+///         NamePattern::Prefix("ui_".to_owned()),
+///         RenderOverride::new()
+///             .with_premultiplied_alpha(false)
+///             .with_tag("ui"),
+///     );
+/// let drawer = SimpleDrawer {
+///     cull_direction: CullDirection::Clockwise,
+///     premultiplied_alpha: true,
+///     color_space: ColorSpace::SRGB,
+/// };
+/// let renderables = drawer.draw_with_rules(&mut skeleton, None, &rules);
+/// ```
+///
+/// Rules are matched against both the attachment's name and its path, which is the name of the
+/// atlas region it uses. When several rules match, later rules take precedence for each field
+/// they set.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RenderRules {
+    rules: Vec<(NamePattern, RenderOverride)>,
+}
+
+impl RenderRules {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { rules: Vec::new() }
+    }
+
+    /// Add a rule applying `render_override` to attachments with names matching `pattern`.
+    #[must_use]
+    pub fn with_rule(mut self, pattern: NamePattern, render_override: RenderOverride) -> Self {
+        self.add_rule(pattern, render_override);
+        self
+    }
+
+    /// Add a rule applying `render_override` to attachments with names matching `pattern`.
+    pub fn add_rule(&mut self, pattern: NamePattern, render_override: RenderOverride) {
+        self.rules.push((pattern, render_override));
+    }
+
+    /// Returns `true` if there are no rules.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+
+    /// The combined override of every rule matching the slot's current attachment.
+    #[must_use]
+    pub fn resolve(&self, slot: &Slot) -> RenderOverride {
+        let mut resolved = RenderOverride::default();
+        if self.rules.is_empty() {
+            return resolved;
+        }
+        let Some(attachment) = slot.attachment() else {
+            return resolved;
+        };
+        let path = attachment.as_region().map_or_else(
+            || {
+                attachment
+                    .as_mesh()
+                    .map(|mesh_attachment| mesh_attachment.path().to_owned())
+                    .unwrap_or_default()
+            },
+            |region_attachment| region_attachment.path().to_owned(),
+        );
+        for (pattern, render_override) in &self.rules {
+            if !pattern.matches(attachment.name()) && !pattern.matches(&path) {
+                continue;
+            }
+            if render_override.blend_mode.is_some() {
+                resolved.blend_mode = render_override.blend_mode;
+            }
+            if render_override.premultiplied_alpha.is_some() {
+                resolved.premultiplied_alpha = render_override.premultiplied_alpha;
+            }
+            if render_override.tag.is_some() {
+                resolved.tag.clone_from(&render_override.tag);
+            }
+        }
+        resolved
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{test::TestAsset, BlendMode};
+
+    use super::*;
+
+    #[test]
+    fn resolve() {
+        let (skeleton, _) = TestAsset::spineboy().instance(true);
+        let slot = skeleton.find_slot("head").unwrap();
+        let rules = RenderRules::new()
+            .with_rule(
+                NamePattern::Exact("head".to_owned()),
+                RenderOverride::new()
+                    .with_blend_mode(BlendMode::Additive)
+                    .with_tag("first"),
+            )
+            .with_rule(
+                NamePattern::Suffix("ad".to_owned()),
+                RenderOverride::new().with_tag("second"),
+            )
+            .with_rule(
+                NamePattern::Prefix("eye".to_owned()),
+                RenderOverride::new().with_premultiplied_alpha(true),
+            );
+        assert_eq!(
+            rules.resolve(&slot),
+            RenderOverride {
+                blend_mode: Some(BlendMode::Additive),
+                premultiplied_alpha: None,
+                tag: Some("second".to_owned()),
+            }
+        );
+    }
+}
@@ -1,9 +1,10 @@
+use crate::prelude::*;
 use crate::{
     c::{c_void, spMeshAttachment_updateRegion},
     BlendMode, Color, Skeleton, SkeletonClipping,
 };
 
-use super::{ColorSpace, CullDirection};
+use super::{BlendFactors, ColorSpace, CullDirection, RenderRules};
 
 #[allow(unused_imports)]
 use crate::extension;
@@ -26,11 +27,26 @@ pub struct SimpleRenderable {
     pub dark_color: Color,
     /// The blend mode to use when drawing this mesh.
     pub blend_mode: BlendMode,
+    /// Whether the colors were premultiplied, and the texture is expected to have premultiplied
+    /// alpha. Matches [`SimpleDrawer::premultiplied_alpha`] unless overridden by a
+    /// [`RenderRules`] rule.
+    pub premultiplied_alpha: bool,
+    /// The tag set by a matching [`RenderRules`] rule, if any.
+    pub tag: Option<String>,
     /// The attachment's renderer object as a raw pointer. Usually represents the texture created
     /// from [`extension::set_create_texture_cb`].
     pub attachment_renderer_object: Option<*const c_void>,
 }
 
+impl SimpleRenderable {
+    /// The blend state to draw this renderable with, from its `blend_mode` and
+    /// `premultiplied_alpha`. See [`BlendMode::blend_factors`].
+    #[must_use]
+    pub const fn blend_factors(&self) -> BlendFactors {
+        self.blend_mode.blend_factors(self.premultiplied_alpha)
+    }
+}
+
 /// A simple drawer with no optimizations.
 ///
 /// Assumes use of the default atlas attachment loader.
@@ -56,9 +72,24 @@ impl SimpleDrawer {
     ///
     /// Panics if not using the default attachment loader with valid atlas regions.
     pub fn draw(
+        &self,
+        skeleton: &mut Skeleton,
+        clipper: Option<&mut SkeletonClipping>,
+    ) -> Vec<SimpleRenderable> {
+        self.draw_with_rules(skeleton, clipper, &RenderRules::new())
+    }
+
+    /// The same as [`draw`](`Self::draw`), but with the blend mode, premultiplied alpha, and tag
+    /// of each renderable overridden by any matching [`RenderRules`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if not using the default attachment loader with valid atlas regions.
+    pub fn draw_with_rules(
         &self,
         skeleton: &mut Skeleton,
         mut clipper: Option<&mut SkeletonClipping>,
+        rules: &RenderRules,
     ) -> Vec<SimpleRenderable> {
         let mut renderables = vec![];
         let mut world_vertices = vec![];
@@ -86,7 +117,7 @@ impl SimpleDrawer {
                 color = mesh_attachment.color();
 
                 unsafe {
-                    mesh_attachment.compute_world_vertices(
+                    mesh_attachment.compute_world_vertices_unchecked(
                         &slot,
                         0,
                         mesh_attachment.world_vertices_length(),
@@ -162,7 +193,12 @@ impl SimpleDrawer {
                 let mut world_vertices = vec![];
                 world_vertices.resize(1000, 0.);
                 unsafe {
-                    region_attachment.compute_world_vertices(&slot, &mut world_vertices, 0, 2);
+                    region_attachment.compute_world_vertices_unchecked(
+                        &slot,
+                        &mut world_vertices,
+                        0,
+                        2,
+                    );
                 }
 
                 vertices.reserve(4);
@@ -221,7 +257,7 @@ impl SimpleDrawer {
                         let clipped_vertices_size =
                             (*clipper.c_ptr_ref().clippedVertices).size as usize;
                         vertices.resize(clipped_vertices_size / 2, [0., 0.]);
-                        std::ptr::copy_nonoverlapping(
+                        core::ptr::copy_nonoverlapping(
                             (*clipper.c_ptr_ref().clippedVertices).items,
                             vertices.as_mut_ptr().cast::<f32>(),
                             clipped_vertices_size,
@@ -229,14 +265,14 @@ impl SimpleDrawer {
                         let clipped_triangles_size =
                             (*clipper.c_ptr_ref().clippedTriangles).size as usize;
                         indices.resize(clipped_triangles_size, 0);
-                        std::ptr::copy_nonoverlapping(
+                        core::ptr::copy_nonoverlapping(
                             (*clipper.c_ptr_ref().clippedTriangles).items,
                             indices.as_mut_ptr(),
                             clipped_triangles_size,
                         );
                         let clipped_uvs_size = (*clipper.c_ptr_ref().clippedUVs).size as usize;
                         uvs.resize(clipped_uvs_size / 2, [0., 0.]);
-                        std::ptr::copy_nonoverlapping(
+                        core::ptr::copy_nonoverlapping(
                             (*clipper.c_ptr_ref().clippedUVs).items,
                             uvs.as_mut_ptr().cast::<f32>(),
                             clipped_uvs_size,
@@ -283,9 +319,13 @@ impl SimpleDrawer {
                     },
                 );
 
+            let render_override = rules.resolve(&slot);
+            let premultiplied_alpha = render_override
+                .premultiplied_alpha
+                .unwrap_or(self.premultiplied_alpha);
             color *= slot.color() * skeleton.color();
             let mut dark_color = slot.dark_color().unwrap_or_default();
-            if self.premultiplied_alpha {
+            if premultiplied_alpha {
                 color.premultiply_alpha();
                 dark_color *= color.a;
                 dark_color.a = 1.0;
@@ -309,7 +349,11 @@ impl SimpleDrawer {
                 indices,
                 color,
                 dark_color,
-                blend_mode: slot.data().blend_mode(),
+                blend_mode: render_override
+                    .blend_mode
+                    .unwrap_or_else(|| slot.data().blend_mode()),
+                premultiplied_alpha,
+                tag: render_override.tag,
                 attachment_renderer_object,
             });
             if let Some(clipper) = clipper.as_deref_mut() {
@@ -1,27 +1,94 @@
-use std::{error, ffi::NulError, fmt};
+use alloc::ffi::NulError;
+use core::{error, fmt};
+#[cfg(feature = "std")]
+use std::io;
+
+use crate::prelude::*;
 
 /// Error types when interacting with the Spine runtime.
 #[derive(Debug)]
 pub enum SpineError {
     /// A parsing error straight from the Spine C runtime.
-    ParsingFailed { reason: String },
-    /// A wrapper for [`std::ffi::NulError`].
+    ///
+    /// When loading skeleton data, `element` is the part of the data which failed, if the runtime
+    /// reported it, and `path` is the file being loaded, if known. `version_mismatch` is set if
+    /// the data was exported by a version of Spine this runtime doesn't support.
+    ParsingFailed {
+        reason: String,
+        element: Option<Box<ParsingElement>>,
+        path: Option<String>,
+        version_mismatch: Option<Box<VersionMismatch>>,
+    },
+    /// A wrapper for [`NulError`](`alloc::ffi::NulError`).
     NulError(NulError),
     /// An error when something couldn't be found, represented by `what` it was and its `name`.
     NotFound { what: String, name: String },
     /// An error when failing to read files.
     FailedToReadFile { file: String },
+    /// A wrapper for [`std::io::Error`].
+    #[cfg(feature = "std")]
+    Io(io::Error),
     /// An error when a specified path is not utf-8.
     PathNotUtf8,
     /// Failed to create the requested type.
     CreationFailed { what: String },
+    /// More listener notifications were queued than the capacity of the event queue. See
+    /// [`EventQueueSettings`](`crate::EventQueueSettings`).
+    EventQueueOverflow { capacity: usize, queued: usize },
+    /// Attempted to mutate data after it was frozen. See
+    /// [`SkeletonData::freeze`](`crate::SkeletonData::freeze`).
+    Frozen { what: String },
+    /// An attachment can't be used by a slot, for instance because it is weighted to bones the
+    /// skeleton doesn't have.
+    IncompatibleAttachment { attachment: String, reason: String },
+    /// The requested operation can't be performed on this data, described by `what`.
+    Unsupported { what: String },
+    /// Skeleton data exported by an older version of Spine can't be upgraded, listing each part
+    /// of the data which blocks the upgrade. See `compat::upgrade_json`, available with the
+    /// `compat` feature.
+    UnsupportedLegacyData {
+        version: String,
+        unsupported: Vec<String>,
+    },
 }
 
 impl SpineError {
-    pub(crate) fn new_from_spine(reason: &str) -> Self {
+    /// A parsing error from the skeleton JSON or binary loaders, with the element and version
+    /// mismatch recovered from the runtime's error message.
+    pub(crate) fn new_skeleton_parsing_failed(reason: &str, path: Option<&str>) -> Self {
+        let version_mismatch = reason
+            .strip_prefix("Skeleton version ")
+            .and_then(|versions| versions.split_once(" does not match runtime version "))
+            .map(|(data, runtime)| {
+                Box::new(VersionMismatch {
+                    data: data.to_owned(),
+                    runtime: runtime.to_owned(),
+                })
+            });
+        let element = PARSING_ELEMENTS.iter().find_map(|(prefix, what)| {
+            let name = reason.strip_prefix(prefix)?;
+            Some(Box::new(ParsingElement {
+                what: (*what).to_owned(),
+                name: name.to_owned(),
+            }))
+        });
         Self::ParsingFailed {
             reason: reason.to_owned(),
+            element,
+            path: path.map(ToOwned::to_owned),
+            version_mismatch,
+        }
+    }
+
+    /// Sets the path of the file which failed to parse, for errors from reading data in memory,
+    /// such as with [`SkeletonJson::read_skeleton_data`](`crate::SkeletonJson::read_skeleton_data`).
+    /// Errors other than [`SpineError::ParsingFailed`] are unchanged.
+    #[must_use]
+    pub fn with_path(mut self, file_path: &str) -> Self {
+        if let Self::ParsingFailed { path, .. } = &mut self {
+            *path = Some(file_path.to_owned());
         }
+        self
     }
 
     pub(crate) fn new_not_found(what: &str, name: &str) -> Self {
@@ -36,19 +103,105 @@ impl SpineError {
             what: what.to_owned(),
         }
     }
+
+    pub(crate) fn new_frozen(what: &str) -> Self {
+        Self::Frozen {
+            what: what.to_owned(),
+        }
+    }
+
+    pub(crate) fn new_unsupported(what: &str) -> Self {
+        Self::Unsupported {
+            what: what.to_owned(),
+        }
+    }
+
+    pub(crate) fn new_incompatible_attachment(attachment: &str, reason: &str) -> Self {
+        Self::IncompatibleAttachment {
+            attachment: attachment.to_owned(),
+            reason: reason.to_owned(),
+        }
+    }
 }
 
+/// The part of the skeleton data which failed to parse, see [`SpineError::ParsingFailed`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParsingElement {
+    /// What kind of element it is, such as `"bone"` or `"slot"`.
+    pub what: String,
+    /// The name of the element in the skeleton data.
+    pub name: String,
+}
+
+/// The version of Spine which exported skeleton data, and the version supported by the runtime,
+/// see [`SpineError::ParsingFailed`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VersionMismatch {
+    /// The version of Spine which exported the data, such as `4.1.24`.
+    pub data: String,
+    /// The version of Spine supported by the runtime.
+    pub runtime: String,
+}
+
+/// Prefixes of the skeleton loaders' error messages which are followed by the name of an
+/// element, and what kind of element it is.
+const PARSING_ELEMENTS: [(&str, &str); 18] = [
+    ("Parent bone not found: ", "bone"),
+    ("Slot bone not found: ", "bone"),
+    ("IK bone not found: ", "bone"),
+    ("Target bone not found: ", "bone"),
+    ("Physics bone not found: ", "bone"),
+    ("Bone not found: ", "bone"),
+    ("Invalid timeline type for a bone: ", "bone"),
+    ("Slot not found: ", "slot"),
+    ("Invalid timeline type for a slot: ", "slot"),
+    ("IK constraint not found: ", "IK constraint"),
+    ("Skin bone constraint not found: ", "constraint"),
+    ("Attachment not found: ", "attachment"),
+    ("Unknown attachment type: ", "attachment type"),
+    ("Parent mesh not found: ", "mesh"),
+    ("Skin not found: ", "skin"),
+    ("Event not found: ", "event"),
+    ("Animation corrupted: ", "animation"),
+    ("Unable to read skeleton file: ", "file"),
+];
+
 impl From<NulError> for SpineError {
     fn from(err: NulError) -> Self {
         Self::NulError(err)
     }
 }
 
+#[cfg(feature = "std")]
+impl From<io::Error> for SpineError {
+    fn from(err: io::Error) -> Self {
+        Self::Io(err)
+    }
+}
+
 impl fmt::Display for SpineError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            SpineError::ParsingFailed { reason } => {
-                write!(f, "Spine parsing failed: {reason}")?;
+            SpineError::ParsingFailed {
+                reason,
+                path,
+                version_mismatch,
+                ..
+            } => {
+                write!(f, "Spine parsing failed")?;
+                if let Some(path) = path {
+                    write!(f, " for {path}")?;
+                }
+                if let Some(version_mismatch) = version_mismatch {
+                    let VersionMismatch { data, runtime } = version_mismatch.as_ref();
+                    write!(
+                        f,
+                        ": data is {data}, runtime is {runtime}. Export the skeleton from Spine \
+                        {runtime}"
+                    )?;
+                } else {
+                    write!(f, ": {reason}")?;
+                }
                 Ok(())
             }
             SpineError::NulError(error) => {
@@ -64,6 +217,11 @@ impl fmt::Display for SpineError {
                 write!(f, "Failed to read file: {file}")?;
                 Ok(())
             }
+            #[cfg(feature = "std")]
+            SpineError::Io(error) => {
+                write!(f, "IO error: {error}")?;
+                Ok(())
+            }
             SpineError::PathNotUtf8 => {
                 write!(f, "Path not utf-8")?;
                 Ok(())
@@ -72,8 +230,99 @@ impl fmt::Display for SpineError {
                 write!(f, "Failed to create {what}")?;
                 Ok(())
             }
+            SpineError::EventQueueOverflow { capacity, queued } => {
+                write!(
+                    f,
+                    "Event queue overflow: {queued} notifications queued, capacity is {capacity}"
+                )?;
+                Ok(())
+            }
+            SpineError::Frozen { what } => {
+                write!(f, "{what} is frozen")?;
+                Ok(())
+            }
+            SpineError::IncompatibleAttachment { attachment, reason } => {
+                write!(f, "Incompatible attachment {attachment}: {reason}")?;
+                Ok(())
+            }
+            SpineError::Unsupported { what } => {
+                write!(f, "Unsupported: {what}")?;
+                Ok(())
+            }
+            SpineError::UnsupportedLegacyData {
+                version,
+                unsupported,
+            } => {
+                write!(f, "Unsupported Spine {version} data")?;
+                for what in unsupported {
+                    write!(f, "\n  {what}")?;
+                }
+                Ok(())
+            }
         }
     }
 }
 
 impl error::Error for SpineError {}
+
+#[cfg(test)]
+mod tests {
+    use crate::test::TestAsset;
+
+    use super::*;
+
+    #[test]
+    fn skeleton_parsing_failed() {
+        let json = br#"{
+            "skeleton": { "spine": "4.2.00" },
+            "bones": [ { "name": "root" }, { "name": "arm", "parent": "torso" } ]
+        }"#;
+        let Err(error) = TestAsset::spineboy()
+            .skeleton_json()
+            .read_skeleton_data(json)
+        else {
+            panic!("expected parsing to fail");
+        };
+        let SpineError::ParsingFailed { ref element, .. } = error else {
+            panic!("expected a parsing error");
+        };
+        assert_eq!(
+            element,
+            &Some(Box::new(ParsingElement {
+                what: "bone".to_owned(),
+                name: "torso".to_owned(),
+            }))
+        );
+        assert_eq!(
+            error.with_path("arm.json").to_string(),
+            "Spine parsing failed for arm.json: Parent bone not found: torso"
+        );
+
+        let json = br#"{ "skeleton": { "spine": "4.1.24" }, "bones": [ { "name": "root" } ] }"#;
+        let Err(error) = TestAsset::spineboy()
+            .skeleton_json()
+            .read_skeleton_data(json)
+        else {
+            panic!("expected parsing to fail");
+        };
+        let SpineError::ParsingFailed {
+            ref version_mismatch,
+            ..
+        } = error
+        else {
+            panic!("expected a parsing error");
+        };
+        assert_eq!(
+            version_mismatch,
+            &Some(Box::new(VersionMismatch {
+                data: "4.1.24".to_owned(),
+                runtime: "4.2".to_owned(),
+            }))
+        );
+        assert_eq!(
+            error.to_string(),
+            "Spine parsing failed: data is 4.1.24, runtime is 4.2. Export the skeleton from \
+            Spine 4.2"
+        );
+    }
+}
@@ -1,6 +1,9 @@
+use crate::prelude::*;
 use crate::{
     c::{spEvent, spEventData},
-    c_interface::{NewFromPtr, SyncPtr},
+    c_interface::{from_c_str, NewFromPtr, SyncPtr},
+    error::SpineError,
+    skeleton_data::check_event_data_frozen,
     TrackEntry,
 };
 
@@ -18,6 +21,8 @@ pub enum AnimationEvent<'a> {
     Interrupt {
         /// The track this event originated from.
         track_entry: TrackEntry,
+        /// Why the track entry was interrupted.
+        reason: InterruptReason,
     },
     End {
         /// The track this event originated from.
@@ -55,6 +60,206 @@ pub enum AnimationEvent<'a> {
     },
 }
 
+/// Why a track entry was interrupted, reported with [`AnimationEvent::Interrupt`].
+///
+/// Clearing a track with [`AnimationState::clear_track`] or [`AnimationState::clear_tracks`]
+/// ends its track entries without interrupting them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum InterruptReason {
+    /// The next track entry queued on the track started, such as one queued with
+    /// [`AnimationState::add_animation`]. This is normal sequence progression.
+    Queued,
+    /// Replaced by a new animation with [`AnimationState::set_animation`] or one of its
+    /// variants, and mixed out.
+    Replaced,
+    /// Replaced by an empty animation with [`AnimationState::set_empty_animation`] or
+    /// [`AnimationState::set_empty_animations`], and mixed out.
+    Emptied,
+    /// Replaced before it was ever applied, so the track was reused for the new track entry
+    /// without mixing this one out.
+    Discarded,
+}
+
+/// An [`AnimationEvent`] which owns its data, so it can be stored and handled after the update
+/// which fired it.
+///
+/// To buffer events, see [`AnimationState::set_event_buffering`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum OwnedAnimationEvent {
+    Start {
+        /// The index of the track this event originated from.
+        track_index: usize,
+        /// The name of the animation on the track entry.
+        animation: String,
+    },
+    Interrupt {
+        /// The index of the track this event originated from.
+        track_index: usize,
+        /// The name of the animation on the track entry.
+        animation: String,
+        /// Why the track entry was interrupted.
+        reason: InterruptReason,
+    },
+    End {
+        /// The index of the track this event originated from.
+        track_index: usize,
+        /// The name of the animation on the track entry.
+        animation: String,
+    },
+    Complete {
+        /// The index of the track this event originated from.
+        track_index: usize,
+        /// The name of the animation on the track entry.
+        animation: String,
+    },
+    Dispose {
+        /// The index of the track this event originated from.
+        track_index: usize,
+        /// The name of the animation on the track entry.
+        animation: String,
+    },
+    Event {
+        /// The index of the track this event originated from.
+        track_index: usize,
+        /// The name of the animation on the track entry.
+        animation: String,
+        /// The name of the event, which is unique across all events in the skeleton.
+        name: String,
+        /// The animation time this event was keyed.
+        time: f32,
+        /// The event's int value.
+        int: i32,
+        /// The event's float value.
+        float: f32,
+        /// The event's string value or an empty string.
+        string: String,
+        /// The event's audio path or an empty string.
+        audio_path: String,
+        /// The event's audio volume.
+        volume: f32,
+        /// The event's audio balance.
+        balance: f32,
+    },
+}
+
+impl OwnedAnimationEvent {
+    /// The index of the track this event originated from.
+    #[must_use]
+    pub const fn track_index(&self) -> usize {
+        match self {
+            Self::Start { track_index, .. }
+            | Self::Interrupt { track_index, .. }
+            | Self::End { track_index, .. }
+            | Self::Complete { track_index, .. }
+            | Self::Dispose { track_index, .. }
+            | Self::Event { track_index, .. } => *track_index,
+        }
+    }
+
+    /// The name of the animation on the track entry this event originated from.
+    #[must_use]
+    pub fn animation(&self) -> &str {
+        match self {
+            Self::Start { animation, .. }
+            | Self::Interrupt { animation, .. }
+            | Self::End { animation, .. }
+            | Self::Complete { animation, .. }
+            | Self::Dispose { animation, .. }
+            | Self::Event { animation, .. } => animation,
+        }
+    }
+}
+
+impl From<AnimationEvent<'_>> for OwnedAnimationEvent {
+    fn from(animation_event: AnimationEvent) -> Self {
+        let track_info = |track_entry: &TrackEntry| {
+            (
+                track_entry.track_index(),
+                track_entry.animation().name().to_owned(),
+            )
+        };
+        match animation_event {
+            AnimationEvent::Start { track_entry } => {
+                let (track_index, animation) = track_info(&track_entry);
+                Self::Start {
+                    track_index,
+                    animation,
+                }
+            }
+            AnimationEvent::Interrupt {
+                track_entry,
+                reason,
+            } => {
+                let (track_index, animation) = track_info(&track_entry);
+                Self::Interrupt {
+                    track_index,
+                    animation,
+                    reason,
+                }
+            }
+            AnimationEvent::End { track_entry } => {
+                let (track_index, animation) = track_info(&track_entry);
+                Self::End {
+                    track_index,
+                    animation,
+                }
+            }
+            AnimationEvent::Complete { track_entry } => {
+                let (track_index, animation) = track_info(&track_entry);
+                Self::Complete {
+                    track_index,
+                    animation,
+                }
+            }
+            AnimationEvent::Dispose { track_entry } => {
+                let (track_index, animation) = track_info(&track_entry);
+                Self::Dispose {
+                    track_index,
+                    animation,
+                }
+            }
+            AnimationEvent::Event {
+                track_entry,
+                name,
+                time,
+                int,
+                float,
+                string,
+                audio_path,
+                volume,
+                balance,
+                ..
+            } => {
+                let (track_index, animation) = track_info(&track_entry);
+                Self::Event {
+                    track_index,
+                    animation,
+                    name: name.to_owned(),
+                    time,
+                    int,
+                    float,
+                    string: string.to_owned(),
+                    audio_path: audio_path.to_owned(),
+                    volume,
+                    balance,
+                }
+            }
+        }
+    }
+}
+
+/// Audio authored for an event, see [`Event::audio`] and [`EventData::audio`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EventAudio<'a> {
+    /// The path of the audio file, relative to the skeleton's audio folder, see
+    /// [`SkeletonData::audio_path`](`crate::SkeletonData::audio_path`).
+    pub path: &'a str,
+    /// The volume to play the audio at, from `0` to `1`.
+    pub volume: f32,
+    /// The stereo balance to play the audio at, from `-1` (left) to `1` (right).
+    pub balance: f32,
+}
+
 /// Events fired from animations.
 ///
 /// [Spine API Reference](http://esotericsoftware.com/spine-api-reference#Event)
@@ -74,14 +279,15 @@ impl NewFromPtr<spEvent> for Event {
 }
 
 impl Event {
-    c_accessor_tmp_ptr_mut!(
+    c_accessor_tmp_ptr_data_mut!(
         /// The events's setup pose data.
         data,
         /// The events's mutable setup pose data.
         data_mut,
         data,
         EventData,
-        spEventData
+        spEventData,
+        Self::check_data_frozen
     );
     c_accessor!(
         /// The animation time this event was keyed.
@@ -118,7 +324,51 @@ impl Event {
         balance,
         f32
     );
+
+    /// The event's audio path from its [`EventData`], or an empty string.
+    #[must_use]
+    pub fn audio_path(&self) -> &str {
+        unsafe {
+            let c_audio_path = (*self.c_ptr_ref().data).audioPath;
+            if c_audio_path.is_null() {
+                ""
+            } else {
+                from_c_str(core::ffi::CStr::from_ptr(c_audio_path))
+            }
+        }
+    }
+
+    /// The audio to play for this event, or [`None`] if the event has no audio. The volume and
+    /// balance may be keyed per event, overriding those of the [`EventData`].
+    ///
+    /// ```
+    /// # #[path="./test.rs"]
+    /// # mod test;
+    /// # use rusty_spine::AnimationEvent;
+    /// # let (_, mut animation_state) = test::TestAsset::spineboy().instance(true);
+    /// animation_state.set_listener(|_, animation_event| {
+    ///     if let AnimationEvent::Event { event, .. } = animation_event {
+    ///         if let Some(audio) = event.audio() {
+    ///             println!("play {} at volume {}", audio.path, audio.volume);
+    ///         }
+    ///     }
+    /// });
+    /// ```
+    #[must_use]
+    pub fn audio(&self) -> Option<EventAudio> {
+        let path = self.audio_path();
+        (!path.is_empty()).then(|| EventAudio {
+            path,
+            volume: self.volume(),
+            balance: self.balance(),
+        })
+    }
+
     c_ptr!(c_event, spEvent);
+
+    fn check_data_frozen(&self) -> Result<(), SpineError> {
+        check_event_data_frozen(unsafe { self.c_ptr_ref().data })
+    }
 }
 
 /// Static event data imported from Spine.
@@ -177,5 +427,17 @@ impl EventData {
         balance,
         f32
     );
+
+    /// The default audio of events using this data, or [`None`] if it has no audio.
+    #[must_use]
+    pub fn audio(&self) -> Option<EventAudio> {
+        let path = self.audio_path();
+        (!path.is_empty()).then(|| EventAudio {
+            path,
+            volume: self.volume(),
+            balance: self.balance(),
+        })
+    }
+
     c_ptr!(c_event_data, spEventData);
 }
@@ -0,0 +1,179 @@
+use crate::prelude::*;
+use crate::{
+    c::{
+        _spEventQueue, _spEventQueue_ensureCapacity, spEventType, SP_ANIMATION_DISPOSE,
+        SP_ANIMATION_END, SP_ANIMATION_EVENT,
+    },
+    error::SpineError,
+};
+
+#[allow(unused_imports)]
+use crate::AnimationState;
+
+/// What to do when more listener notifications are queued during a single
+/// [`AnimationState::update`] or [`AnimationState::apply`] than the queue's capacity.
+///
+/// End and dispose notifications release track entries when they are delivered, so they are
+/// never discarded and may exceed the capacity regardless of the policy.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum EventQueueOverflow {
+    /// Deliver every notification, growing the queue as needed. This matches the behavior of the
+    /// Spine runtime.
+    #[default]
+    Grow,
+    /// Discard the oldest notifications so only the most recent ones are delivered.
+    DropOldest,
+    /// Discard the newest notifications and report the overflow as an error from
+    /// [`AnimationState::try_update`] or [`AnimationState::try_apply`].
+    Error,
+}
+
+/// Limits for the listener notifications queued by an [`AnimationState`].
+///
+/// See [`AnimationState::set_event_queue_settings`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EventQueueSettings {
+    /// The maximum amount of notifications delivered from a single update or apply. The queue
+    /// preallocates enough memory for this many notifications, up to 1024.
+    pub capacity: usize,
+    /// What to do once the capacity is exceeded.
+    pub overflow: EventQueueOverflow,
+}
+
+impl Default for EventQueueSettings {
+    fn default() -> Self {
+        Self {
+            capacity: 64,
+            overflow: EventQueueOverflow::Grow,
+        }
+    }
+}
+
+impl EventQueueSettings {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[must_use]
+    pub const fn with_capacity(self, capacity: usize) -> Self {
+        Self { capacity, ..self }
+    }
+
+    #[must_use]
+    pub const fn with_overflow(self, overflow: EventQueueOverflow) -> Self {
+        Self { overflow, ..self }
+    }
+}
+
+/// Counters for the listener notifications queued by an [`AnimationState`].
+///
+/// See [`AnimationState::event_queue_statistics`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct EventQueueStatistics {
+    /// The total amount of notifications delivered to listeners.
+    pub delivered: u64,
+    /// The total amount of notifications discarded due to the overflow policy.
+    pub dropped: u64,
+    /// The amount of updates or applies which queued more notifications than the capacity.
+    pub overflows: u64,
+    /// The most notifications queued by a single update or apply.
+    pub peak: usize,
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct EventQueue {
+    pub(crate) settings: EventQueueSettings,
+    pub(crate) statistics: EventQueueStatistics,
+}
+
+impl EventQueue {
+    /// Grows the C queue so it can hold `capacity` notifications without reallocating.
+    pub(crate) unsafe fn reserve(&self, queue: *mut _spEventQueue) {
+        // Event notifications take up to three items: the type, the track entry, and the event.
+        let items = (self.settings.capacity.min(1024) * 3) as i32;
+        loop {
+            // The C queue only doubles its capacity once per call.
+            let objects_capacity = (*queue).objectsCapacity;
+            if objects_capacity >= items {
+                break;
+            }
+            let new_objects = objects_capacity - (*queue).objectsCount + 1;
+            _spEventQueue_ensureCapacity(queue, new_objects);
+        }
+    }
+
+    /// Applies the overflow policy to the notifications currently in the C queue. Must only be
+    /// called while draining is disabled.
+    pub(crate) unsafe fn limit(&mut self, queue: *mut _spEventQueue) -> Result<(), SpineError> {
+        let objects = (*queue).objects;
+        let objects_count = (*queue).objectsCount as usize;
+        let mut notifications = 0;
+        let mut index = 0;
+        while index < objects_count {
+            index += notification_len((*objects.add(index)).type_0 as spEventType);
+            notifications += 1;
+        }
+        self.statistics.peak = self.statistics.peak.max(notifications);
+        let excess = notifications.saturating_sub(self.settings.capacity);
+        if excess == 0 || self.settings.overflow == EventQueueOverflow::Grow {
+            self.statistics.delivered += notifications as u64;
+            return Ok(());
+        }
+        self.statistics.overflows += 1;
+
+        let mut spans = Vec::with_capacity(notifications);
+        let mut index = 0;
+        while index < objects_count {
+            let event_type = (*objects.add(index)).type_0 as spEventType;
+            let len = notification_len(event_type);
+            let required = event_type == SP_ANIMATION_END || event_type == SP_ANIMATION_DISPOSE;
+            spans.push((index, len, required, false));
+            index += len;
+        }
+        let mut remaining_excess = excess;
+        let mut drop_span = |span: &mut (usize, usize, bool, bool)| {
+            if remaining_excess > 0 && !span.2 {
+                span.3 = true;
+                remaining_excess -= 1;
+            }
+        };
+        if self.settings.overflow == EventQueueOverflow::DropOldest {
+            spans.iter_mut().for_each(&mut drop_span);
+        } else {
+            spans.iter_mut().rev().for_each(&mut drop_span);
+        }
+
+        let mut write_index = 0;
+        let mut dropped = 0;
+        for (read_index, len, _, is_dropped) in spans {
+            if is_dropped {
+                dropped += 1;
+                continue;
+            }
+            if read_index != write_index {
+                core::ptr::copy(objects.add(read_index), objects.add(write_index), len);
+            }
+            write_index += len;
+        }
+        (*queue).objectsCount = write_index as i32;
+        self.statistics.dropped += dropped;
+        self.statistics.delivered += notifications as u64 - dropped;
+        if self.settings.overflow == EventQueueOverflow::Error {
+            Err(SpineError::EventQueueOverflow {
+                capacity: self.settings.capacity,
+                queued: notifications,
+            })
+        } else {
+            Ok(())
+        }
+    }
+}
+
+const fn notification_len(event_type: spEventType) -> usize {
+    if event_type == SP_ANIMATION_EVENT {
+        3
+    } else {
+        2
+    }
+}
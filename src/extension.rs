@@ -11,60 +11,169 @@
 //! * `void _spAtlasPage_disposeTexture (spAtlasPage* self);`
 //! * `char* _spUtil_readFile (const char* path, int* length);`
 //!
-//! They can be set with the functions found on this page.
+//! They can be set with the functions found on this page. The texture callbacks can also be
+//! overridden for individual atlases with [`TextureCallbacks`], so several engines or plugins
+//! using this crate in one process don't replace each other's callbacks.
+//!
+//! Atlases can be created and dropped on any thread, so the texture callbacks are called on
+//! whichever thread does so, and every callback must be [`Send`] and [`Sync`].
 //!
 //! You can read more about these functions on the
 //! [spine-c Runtime Docs](http://en.esotericsoftware.com/spine-c#Integrating-spine-c-in-your-engine).
 
-use std::ffi::CStr;
-use std::fs::read;
-use std::sync::{Arc, Mutex, Once};
+use alloc::{
+    collections::{BTreeMap, BTreeSet},
+    sync::Arc,
+};
+use core::{any::Any, ffi::CStr, fmt};
 
 use crate::c::{c_int, c_void, size_t};
 use crate::c_interface::NewFromPtr;
+use crate::diagnostics::{self, LiveObjectKind};
+use crate::prelude::*;
+use crate::sync::{lock, Mutex, MutexGuard};
 use crate::{
-    atlas::AtlasPage,
-    c::{c_char, spAtlasPage},
+    atlas::{dispose_page_texture, take_pending_page, AtlasPage},
+    c::{c_char, spAtlas, spAtlasPage},
+    Atlas,
 };
 
-type CreateTextureCb = Box<dyn Fn(&mut AtlasPage, &str)>;
-type DisposeTextureCb = Box<dyn Fn(&mut AtlasPage)>;
-type ReadFileCb = Box<dyn Fn(&str) -> Option<Vec<u8>>>;
+type CreateTextureCb = Arc<dyn Fn(&mut AtlasPage, &str) + Send + Sync>;
+type DisposeTextureCb = Arc<dyn Fn(&mut AtlasPage) + Send + Sync>;
+type ReadFileCb = Arc<dyn Fn(&str) -> Option<Vec<u8>> + Send + Sync>;
 
-#[derive(Default)]
 pub(crate) struct Extension {
     create_texture_cb: Option<CreateTextureCb>,
     dispose_texture_cb: Option<DisposeTextureCb>,
     read_file_cb: Option<ReadFileCb>,
+    /// The addresses of the [`TextureCallbacks`] of atlases being created, see [`create_atlas`].
+    creating_atlases: BTreeSet<usize>,
+    /// The dispose callbacks overridden for atlas pages, by page address.
+    page_dispose_texture_cbs: BTreeMap<usize, DisposeTextureCb>,
 }
 
+static EXTENSION: Mutex<Extension> = Mutex::new(Extension {
+    create_texture_cb: None,
+    dispose_texture_cb: None,
+    read_file_cb: None,
+    creating_atlases: BTreeSet::new(),
+    page_dispose_texture_cbs: BTreeMap::new(),
+});
+
 impl Extension {
-    fn singleton() -> Arc<Mutex<Extension>> {
-        static START: Once = Once::new();
-        static mut INSTANCE: Option<Arc<Mutex<Extension>>> = None;
-        START.call_once(|| unsafe {
-            INSTANCE = Some(Arc::new(Mutex::new(Extension::default())));
-        });
-        unsafe {
-            let singleton = INSTANCE.as_ref().unwrap();
-            singleton.clone()
-        }
+    fn singleton() -> MutexGuard<'static, Extension> {
+        lock(&EXTENSION)
+    }
+
+    /// The callbacks the atlas is being created with, if created by [`create_atlas`].
+    unsafe fn atlas_callbacks(&self, c_atlas: *mut spAtlas) -> Option<TextureCallbacks> {
+        let callbacks = (*c_atlas).rendererObject;
+        self.creating_atlases
+            .contains(&callbacks.addr())
+            .then(|| (*callbacks.cast::<TextureCallbacks>()).clone())
+    }
+}
+
+/// Texture callbacks for the atlases created with them, which take precedence over the global
+/// callbacks set with [`set_create_texture_cb`] and [`set_dispose_texture_cb`]. Callbacks which
+/// are not set fall back to the global ones.
+///
+/// ```
+/// # #[path="./test.rs"]
+/// # mod test;
+/// use rusty_spine::{extension::TextureCallbacks, Atlas};
+///
+/// struct PluginTexture(pub String);
+///
+/// let callbacks = TextureCallbacks::new()
+///     .create_texture_cb(|atlas_page, path| {
+///         atlas_page.set_texture(PluginTexture(path.to_owned()));
+///     })
+///     .dispose_texture_cb(|atlas_page| {
+///         atlas_page.take_texture::<PluginTexture>();
+///     });
+/// let atlas =
+///     Atlas::new_from_file_with_callbacks("assets/spineboy/export/spineboy.atlas", &callbacks)?;
+/// # assert!(atlas.pages().next().unwrap().texture::<PluginTexture>().is_some());
+/// # Ok::<(), rusty_spine::SpineError>(())
+/// ```
+///
+/// A page keeps the dispose callback of the atlas it was created with, even if the callbacks are
+/// dropped before the atlas.
+#[derive(Default, Clone)]
+pub struct TextureCallbacks {
+    create_texture_cb: Option<CreateTextureCb>,
+    dispose_texture_cb: Option<DisposeTextureCb>,
+}
+
+impl TextureCallbacks {
+    /// Create callbacks which fall back to the global callbacks.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the callback called instead of the one set with [`set_create_texture_cb`].
+    #[must_use]
+    pub fn create_texture_cb<F>(mut self, create_texture_cb: F) -> Self
+    where
+        F: Fn(&mut AtlasPage, &str) + Send + Sync + 'static,
+    {
+        self.create_texture_cb = Some(Arc::new(create_texture_cb));
+        self
+    }
+
+    /// Set the callback called instead of the one set with [`set_dispose_texture_cb`].
+    #[must_use]
+    pub fn dispose_texture_cb<F>(mut self, dispose_texture_cb: F) -> Self
+    where
+        F: Fn(&mut AtlasPage) + Send + Sync + 'static,
+    {
+        self.dispose_texture_cb = Some(Arc::new(dispose_texture_cb));
+        self
+    }
+}
+
+impl fmt::Debug for TextureCallbacks {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TextureCallbacks")
+            .field("create_texture_cb", &self.create_texture_cb.is_some())
+            .field("dispose_texture_cb", &self.dispose_texture_cb.is_some())
+            .finish()
     }
 }
 
+/// Create an atlas with `create`, which is passed the renderer object the atlas must be created
+/// with, so its pages use `callbacks`.
+pub(crate) fn create_atlas(
+    callbacks: &TextureCallbacks,
+    create: impl FnOnce(*mut c_void) -> *mut spAtlas,
+) -> *mut spAtlas {
+    let renderer_object = core::ptr::from_ref(callbacks).cast_mut().cast::<c_void>();
+    Extension::singleton()
+        .creating_atlases
+        .insert(renderer_object.addr());
+    let c_atlas = create(renderer_object);
+    Extension::singleton()
+        .creating_atlases
+        .remove(&renderer_object.addr());
+    if !c_atlas.is_null() {
+        unsafe { (*c_atlas).rendererObject = core::ptr::null_mut() };
+    }
+    c_atlas
+}
+
 /// Set `_spAtlasPage_createTexture`
 ///
 /// The purpose of this callback is to allow loading textures in whichever engine is being used.
-/// The following example shows the intended usage by storing the texture on the renderer object
-/// of the [`AtlasPage`] which can be acquired later.
+/// The following example shows the intended usage by storing the texture on the [`AtlasPage`]
+/// with [`AtlasPage::set_texture`], where it can be acquired later. The texture is dropped when
+/// the page is disposed.
 /// ```
 /// struct SpineTexture(pub String);
 ///
 /// rusty_spine::extension::set_create_texture_cb(|atlas_page, path| {
-///     atlas_page.renderer_object().set(SpineTexture(path.to_owned()));
-/// });
-/// rusty_spine::extension::set_dispose_texture_cb(|atlas_page| unsafe {
-///     atlas_page.renderer_object().dispose::<SpineTexture>();
+///     atlas_page.set_texture(SpineTexture(path.to_owned()));
 /// });
 /// ```
 ///
@@ -80,6 +189,8 @@ impl Extension {
 /// or
 /// [`SkeletonCombinedRenderable::attachment_renderer_object`](`crate::controller::SkeletonCombinedRenderable::attachment_renderer_object`)
 ///
+/// To load the texture asynchronously, such as on `wasm32`, see [`AtlasPage::defer_texture`].
+///
 /// ```
 /// # #[path="./test.rs"]
 /// # mod test;
@@ -99,32 +210,30 @@ impl Extension {
 /// }
 /// ```
 ///
-/// # Panics
-///
-/// Panics if the internal mutex is poisoned.
+/// Setting the callback again replaces it for atlases created afterwards. To use different
+/// callbacks for some atlases, see [`TextureCallbacks`].
 pub fn set_create_texture_cb<F>(create_texture_cb: F)
 where
-    F: Fn(&mut AtlasPage, &str) + 'static,
+    F: Fn(&mut AtlasPage, &str) + Send + Sync + 'static,
 {
-    let singleton = Extension::singleton();
-    let mut extension = singleton.lock().unwrap();
-    extension.create_texture_cb = Some(Box::new(create_texture_cb));
+    let mut extension = Extension::singleton();
+    extension.create_texture_cb = Some(Arc::new(create_texture_cb));
 }
 
 /// Set `_spAtlasPage_disposeTexture`
 ///
-/// For an example, see [`set_create_texture_cb`].
-///
-/// # Panics
+/// Called before the texture set with [`AtlasPage::set_texture`] is dropped, so it can be taken
+/// with [`AtlasPage::take_texture`] to release engine resources. For an example, see
+/// [`set_create_texture_cb`].
 ///
-/// Panics if the internal mutex is poisoned.
+/// Setting the callback again replaces it for every page disposed afterwards, except pages of
+/// atlases created with a dispose callback in [`TextureCallbacks`].
 pub fn set_dispose_texture_cb<F>(dispose_texture_cb: F)
 where
-    F: Fn(&mut AtlasPage) + 'static,
+    F: Fn(&mut AtlasPage) + Send + Sync + 'static,
 {
-    let singleton = Extension::singleton();
-    let mut extension = singleton.lock().unwrap();
-    extension.dispose_texture_cb = Some(Box::new(dispose_texture_cb));
+    let mut extension = Extension::singleton();
+    extension.dispose_texture_cb = Some(Arc::new(dispose_texture_cb));
 }
 
 /// Set `_spUtil_readFile`
@@ -132,31 +241,74 @@ where
 /// Can be used to customize file loading when using functions which read files from disk. This
 /// callback is largely unnecessary as its possible to avoid calling these sorts of functions
 /// if read-from-disk is not desirable. Additionally, a default implementation using Rust's
-/// `std::fs::read` is provided if this callback remains unset.
+/// `std::fs::read` is provided if this callback remains unset and the `std` feature is enabled.
 ///
 /// ```
 /// rusty_spine::extension::set_read_file_cb(|path| {
 ///     std::fs::read(path).ok()
 /// });
 /// ```
-///
-/// # Panics
-///
-/// Panics if the internal mutex is poisoned.
 pub fn set_read_file_cb<F>(read_file_cb: F)
 where
-    F: Fn(&str) -> Option<Vec<u8>> + 'static,
+    F: Fn(&str) -> Option<Vec<u8>> + Send + Sync + 'static,
 {
-    let singleton = Extension::singleton();
-    let mut extension = singleton.lock().unwrap();
-    extension.read_file_cb = Some(Box::new(read_file_cb));
+    let mut extension = Extension::singleton();
+    extension.read_file_cb = Some(Arc::new(read_file_cb));
+}
+
+/// A token for an atlas page whose texture is loaded asynchronously, see
+/// [`AtlasPage::defer_texture`].
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub struct PendingTexture {
+    id: u64,
+}
+
+impl PendingTexture {
+    pub(crate) const fn new(id: u64) -> Self {
+        Self { id }
+    }
+
+    pub(crate) const fn into_id(self) -> u64 {
+        self.id
+    }
+}
+
+/// Complete a page of `atlas` whose texture was deferred with [`AtlasPage::defer_texture`], by
+/// setting its texture as if with [`AtlasPage::set_texture`].
+///
+/// # Errors
+///
+/// Returns the texture if the page does not belong to `atlas`, or if the page was disposed, or its
+/// texture was set or deferred again, since the token was created.
+pub fn resolve_texture<T: Any + Send + Sync>(
+    atlas: &mut Atlas,
+    pending_texture: PendingTexture,
+    texture: T,
+) -> Result<(), T> {
+    let Some(c_atlas_page) = take_pending_page(pending_texture, atlas.c_ptr()) else {
+        return Err(texture);
+    };
+    unsafe { AtlasPage::new_from_ptr(c_atlas_page) }.set_texture(texture);
+    Ok(())
 }
 
 #[no_mangle]
 extern "C" fn _spAtlasPage_createTexture(c_atlas_page: *mut spAtlasPage, c_path: *const c_char) {
-    let singleton = Extension::singleton();
-    let extension = singleton.lock().unwrap();
-    if let Some(cb) = &extension.create_texture_cb {
+    diagnostics::track(LiveObjectKind::AtlasPageTexture, c_atlas_page);
+    let create_texture_cb = {
+        let mut extension = Extension::singleton();
+        let callbacks = unsafe { extension.atlas_callbacks((*c_atlas_page).atlas) };
+        let callbacks = callbacks.unwrap_or_default();
+        if let Some(dispose_texture_cb) = callbacks.dispose_texture_cb {
+            extension
+                .page_dispose_texture_cbs
+                .insert(c_atlas_page.addr(), dispose_texture_cb);
+        }
+        callbacks
+            .create_texture_cb
+            .or_else(|| extension.create_texture_cb.clone())
+    };
+    if let Some(cb) = create_texture_cb {
         unsafe {
             cb(
                 &mut AtlasPage::new_from_ptr(c_atlas_page),
@@ -168,13 +320,20 @@ extern "C" fn _spAtlasPage_createTexture(c_atlas_page: *mut spAtlasPage, c_path:
 
 #[no_mangle]
 extern "C" fn _spAtlasPage_disposeTexture(c_atlas_page: *mut spAtlasPage) {
-    let singleton = Extension::singleton();
-    let extension = singleton.lock().unwrap();
-    if let Some(cb) = &extension.dispose_texture_cb {
+    diagnostics::untrack(LiveObjectKind::AtlasPageTexture, c_atlas_page);
+    let dispose_texture_cb = {
+        let mut extension = Extension::singleton();
+        extension
+            .page_dispose_texture_cbs
+            .remove(&c_atlas_page.addr())
+            .or_else(|| extension.dispose_texture_cb.clone())
+    };
+    if let Some(cb) = dispose_texture_cb {
         unsafe {
             cb(&mut AtlasPage::new_from_ptr(c_atlas_page));
         }
     }
+    dispose_page_texture(c_atlas_page);
 }
 
 extern "C" {
@@ -184,12 +343,11 @@ extern "C" {
 
 #[no_mangle]
 extern "C" fn _spUtil_readFile(c_path: *const c_char, c_length: *mut c_int) -> *mut c_char {
-    let singleton = Extension::singleton();
-    let extension = singleton.lock().unwrap();
-    extension.read_file_cb.as_ref().map_or_else(
+    let read_file_cb = Extension::singleton().read_file_cb.clone();
+    read_file_cb.map_or_else(
         || {
             let str = unsafe { CStr::from_ptr(c_path).to_str().unwrap().to_owned() };
-            read(str).map_or(std::ptr::null_mut(), |data| {
+            read_file(&str).map_or(core::ptr::null_mut(), |data| {
                 let c_data = unsafe { spine_malloc(data.len() as size_t) };
                 unsafe {
                     spine_memcpy(c_data, data.as_ptr().cast::<c_void>(), data.len() as size_t);
@@ -200,7 +358,7 @@ extern "C" fn _spUtil_readFile(c_path: *const c_char, c_length: *mut c_int) -> *
         },
         |cb| {
             cb(unsafe { CStr::from_ptr(c_path).to_str().unwrap() }).map_or(
-                std::ptr::null_mut(),
+                core::ptr::null_mut(),
                 |data| unsafe {
                     *c_length = data.len() as c_int;
                     let c_data = spine_malloc(data.len() as size_t);
@@ -211,3 +369,13 @@ extern "C" fn _spUtil_readFile(c_path: *const c_char, c_length: *mut c_int) -> *
         },
     )
 }
+
+#[cfg(feature = "std")]
+fn read_file(path: &str) -> Option<Vec<u8>> {
+    std::fs::read(path).ok()
+}
+
+#[cfg(not(feature = "std"))]
+const fn read_file(_path: &str) -> Option<Vec<u8>> {
+    None
+}
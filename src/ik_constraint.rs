@@ -1,9 +1,11 @@
 use crate::{
     c::{
-        spBone, spIkConstraint, spIkConstraintData, spIkConstraint_setToSetupPose,
-        spIkConstraint_update,
+        spBone, spBone_worldToParent, spIkConstraint, spIkConstraintData,
+        spIkConstraint_setToSetupPose, spIkConstraint_update, spSkeleton_findBone,
     },
-    c_interface::{NewFromPtr, SyncPtr},
+    c_interface::{to_c_str, NewFromPtr, SyncPtr},
+    error::SpineError,
+    skeleton_data::check_skeleton_data_frozen,
     Bone, IkConstraintData,
 };
 
@@ -35,13 +37,14 @@ impl IkConstraint {
         }
     }
 
-    c_accessor_tmp_ptr_mut!(
+    c_accessor_tmp_ptr_data_mut!(
         /// The IK constraint's setup pose data.
         data,
         data_mut,
         data,
         IkConstraintData,
-        spIkConstraintData
+        spIkConstraintData,
+        Self::check_data_frozen
     );
 
     c_accessor_bool!(active, active);
@@ -90,10 +93,13 @@ impl IkConstraint {
     );
 
     c_accessor!(bones_count, bonesCount, usize);
-    c_accessor_array!(
+    c_accessor_array_mut!(
         /// The bones that will be modified by this IK constraint.
         bones,
+        /// The mutable bones that will be modified by this IK constraint.
+        bones_mut,
         bone_at_index,
+        bone_at_index_mut,
         IkConstraint,
         Bone,
         spBone,
@@ -109,5 +115,91 @@ impl IkConstraint {
         spBone
     );
 
+    /// Set the bone that is the IK target, looked up by name in this constraint's skeleton.
+    ///
+    /// Changing the target can change the order bones and constraints need to be updated in, so
+    /// [`Skeleton::update_cache`](`crate::Skeleton::update_cache`) must be called afterward.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SpineError::NotFound`] if the skeleton has no bone named `bone_name`.
+    pub fn set_target_by_name(&mut self, bone_name: &str) -> Result<(), SpineError> {
+        let c_bone_name = to_c_str(bone_name);
+        unsafe {
+            let c_skeleton = (*self.c_ptr_ref().target).skeleton;
+            let c_target = spSkeleton_findBone(c_skeleton, c_bone_name.as_ptr());
+            if c_target.is_null() {
+                return Err(SpineError::new_not_found("Bone", bone_name));
+            }
+            self.c_ptr_mut().target = c_target;
+        }
+        Ok(())
+    }
+
+    /// Move the target bone so it is positioned at the given world coordinates, for instance to
+    /// aim at the mouse cursor or another entity. Takes effect on the next
+    /// [`Skeleton::update_world_transform`](`crate::Skeleton::update_world_transform`).
+    ///
+    /// ```
+    /// # #[path="./test.rs"]
+    /// # mod test;
+    /// # use rusty_spine::Physics;
+    /// # let (mut skeleton, _) = test::TestAsset::spineboy().instance(true);
+    /// skeleton.update_world_transform(Physics::None);
+    /// let mut ik_constraint = skeleton.find_ik_constraint_mut("aim-torso-ik").unwrap();
+    /// ik_constraint.set_mix(1.);
+    /// ik_constraint.set_target_world_position(300., 200.);
+    /// skeleton.update_world_transform(Physics::None);
+    /// ```
+    ///
+    /// The target's parent bone must have an up-to-date world transform.
+    pub fn set_target_world_position(&mut self, world_x: f32, world_y: f32) {
+        let mut target = self.target_mut();
+        let mut local_x = 0.;
+        let mut local_y = 0.;
+        unsafe {
+            spBone_worldToParent(target.c_ptr(), world_x, world_y, &mut local_x, &mut local_y);
+        }
+        target.set_x(local_x);
+        target.set_y(local_y);
+    }
+
     c_ptr!(c_ik_constraint, spIkConstraint);
+
+    fn check_data_frozen(&self) -> Result<(), SpineError> {
+        check_skeleton_data_frozen(unsafe { (*(*self.c_ptr_ref().target).skeleton).data })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{error::SpineError, test::TestAsset, Physics};
+
+    #[test]
+    fn ik_constraint_target_world_position() {
+        let (mut skeleton, _) = TestAsset::spineboy().instance(true);
+        skeleton.update_world_transform(Physics::None);
+        let mut ik_constraint = skeleton.find_ik_constraint_mut("aim-torso-ik").unwrap();
+        ik_constraint.set_target_world_position(300., 200.);
+        skeleton.update_world_transform(Physics::None);
+        let ik_constraint = skeleton.find_ik_constraint("aim-torso-ik").unwrap();
+        let target = ik_constraint.target();
+        assert!((target.world_x() - 300.).abs() < 0.01);
+        assert!((target.world_y() - 200.).abs() < 0.01);
+    }
+
+    #[test]
+    fn ik_constraint_set_target_by_name() {
+        let (mut skeleton, _) = TestAsset::spineboy().instance(true);
+        let mut ik_constraint = skeleton.find_ik_constraint_mut("aim-torso-ik").unwrap();
+        assert!(matches!(
+            ik_constraint.set_target_by_name("missing"),
+            Err(SpineError::NotFound { .. })
+        ));
+        ik_constraint.set_target_by_name("hip").unwrap();
+        skeleton.update_cache();
+        skeleton.update_world_transform(Physics::None);
+        let ik_constraint = skeleton.find_ik_constraint("aim-torso-ik").unwrap();
+        assert_eq!(ik_constraint.target().data().name(), "hip");
+    }
 }
@@ -0,0 +1,411 @@
+//! Minimal JSON helpers for generating Spine data at runtime, and reading it with the `compat`
+//! feature.
+
+use core::fmt::Write;
+
+use crate::color::Color;
+#[cfg(not(feature = "std"))]
+use crate::math::Float;
+use crate::prelude::*;
+
+/// Writes `value` as a quoted and escaped JSON string.
+pub(crate) fn write_string(out: &mut String, value: &str) {
+    out.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => {
+                let _ = write!(out, "\\u{:04x}", c as u32);
+            }
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
+
+/// Writes `value` as a JSON number. Non-finite values are not representable in JSON and are
+/// written as `0`.
+pub(crate) fn write_number(out: &mut String, value: f32) {
+    if value.is_finite() {
+        let _ = write!(out, "{value}");
+    } else {
+        out.push('0');
+    }
+}
+
+/// Formats `color` as `rrggbbaa` hex, the format Spine uses for colors in JSON.
+pub(crate) fn color_hex(color: Color) -> String {
+    let mut hex = String::with_capacity(8);
+    for channel in [color.r, color.g, color.b, color.a] {
+        let _ = write!(hex, "{:02x}", (channel.clamp(0., 1.) * 255.).round() as u8);
+    }
+    hex
+}
+
+/// Writes the comma separated members of a JSON object, tracking whether a separator is needed.
+pub(crate) struct ObjectWriter<'a> {
+    out: &'a mut String,
+    empty: bool,
+}
+
+impl<'a> ObjectWriter<'a> {
+    pub(crate) fn new(out: &'a mut String) -> Self {
+        out.push('{');
+        Self { out, empty: true }
+    }
+
+    /// Writes the key of the next member and returns the output to write its value to.
+    pub(crate) fn key(&mut self, key: &str) -> &mut String {
+        if !self.empty {
+            self.out.push(',');
+        }
+        self.empty = false;
+        write_string(self.out, key);
+        self.out.push(':');
+        self.out
+    }
+
+    pub(crate) fn string(&mut self, key: &str, value: &str) {
+        write_string(self.key(key), value);
+    }
+
+    pub(crate) fn number(&mut self, key: &str, value: f32) {
+        write_number(self.key(key), value);
+    }
+
+    pub(crate) fn boolean(&mut self, key: &str, value: bool) {
+        self.key(key).push_str(if value { "true" } else { "false" });
+    }
+
+    pub(crate) fn end(self) {
+        self.out.push('}');
+    }
+}
+
+/// A parsed JSON value. Object members keep their order.
+#[cfg(feature = "compat")]
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum JsonValue {
+    Null,
+    Bool(bool),
+    Number(f64),
+    String(String),
+    Array(Vec<JsonValue>),
+    Object(Vec<(String, JsonValue)>),
+}
+
+#[cfg(feature = "compat")]
+impl JsonValue {
+    /// Parses a JSON document, returning a description of the first syntax error on failure.
+    pub(crate) fn parse(text: &str) -> Result<Self, String> {
+        let mut parser = Parser {
+            bytes: text.as_bytes(),
+            position: 0,
+        };
+        let value = parser.value()?;
+        parser.skip_whitespace();
+        if parser.position == parser.bytes.len() {
+            Ok(value)
+        } else {
+            Err(parser.error("trailing characters"))
+        }
+    }
+
+    pub(crate) fn get(&self, key: &str) -> Option<&Self> {
+        match self {
+            Self::Object(members) => members
+                .iter()
+                .find(|(name, _)| name == key)
+                .map(|(_, value)| value),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn get_mut(&mut self, key: &str) -> Option<&mut Self> {
+        match self {
+            Self::Object(members) => members
+                .iter_mut()
+                .find(|(name, _)| name == key)
+                .map(|(_, value)| value),
+            _ => None,
+        }
+    }
+
+    /// Sets the member `key` of an object, replacing it in place if it exists. Does nothing if
+    /// this is not an object.
+    pub(crate) fn insert(&mut self, key: &str, value: Self) {
+        if let Some(existing) = self.get_mut(key) {
+            *existing = value;
+        } else if let Self::Object(members) = self {
+            members.push((key.to_owned(), value));
+        }
+    }
+
+    pub(crate) fn remove(&mut self, key: &str) -> Option<Self> {
+        match self {
+            Self::Object(members) => {
+                let index = members.iter().position(|(name, _)| name == key)?;
+                Some(members.remove(index).1)
+            }
+            _ => None,
+        }
+    }
+
+    pub(crate) const fn as_f64(&self) -> Option<f64> {
+        match self {
+            Self::Number(number) => Some(*number),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn as_str(&self) -> Option<&str> {
+        match self {
+            Self::String(string) => Some(string),
+            _ => None,
+        }
+    }
+
+    pub(crate) const fn as_array_mut(&mut self) -> Option<&mut Vec<Self>> {
+        match self {
+            Self::Array(values) => Some(values),
+            _ => None,
+        }
+    }
+
+    pub(crate) const fn as_object_mut(&mut self) -> Option<&mut Vec<(String, Self)>> {
+        match self {
+            Self::Object(members) => Some(members),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn write(&self, out: &mut String) {
+        match self {
+            Self::Null => out.push_str("null"),
+            Self::Bool(value) => out.push_str(if *value { "true" } else { "false" }),
+            Self::Number(value) => {
+                let _ = write!(out, "{value}");
+            }
+            Self::String(value) => write_string(out, value),
+            Self::Array(values) => {
+                out.push('[');
+                for (index, value) in values.iter().enumerate() {
+                    if index > 0 {
+                        out.push(',');
+                    }
+                    value.write(out);
+                }
+                out.push(']');
+            }
+            Self::Object(members) => {
+                let mut object = ObjectWriter::new(out);
+                for (key, value) in members {
+                    value.write(object.key(key));
+                }
+                object.end();
+            }
+        }
+    }
+}
+
+#[cfg(feature = "compat")]
+struct Parser<'a> {
+    bytes: &'a [u8],
+    position: usize,
+}
+
+#[cfg(feature = "compat")]
+impl Parser<'_> {
+    fn error(&self, what: &str) -> String {
+        format!("{what} at byte {}", self.position)
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self
+            .bytes
+            .get(self.position)
+            .is_some_and(u8::is_ascii_whitespace)
+        {
+            self.position += 1;
+        }
+    }
+
+    fn expect(&mut self, literal: &str) -> Result<(), String> {
+        if self.bytes[self.position..].starts_with(literal.as_bytes()) {
+            self.position += literal.len();
+            Ok(())
+        } else {
+            Err(self.error(&format!("expected `{literal}`")))
+        }
+    }
+
+    fn value(&mut self) -> Result<JsonValue, String> {
+        self.skip_whitespace();
+        match self.bytes.get(self.position) {
+            Some(b'n') => self.expect("null").map(|()| JsonValue::Null),
+            Some(b't') => self.expect("true").map(|()| JsonValue::Bool(true)),
+            Some(b'f') => self.expect("false").map(|()| JsonValue::Bool(false)),
+            Some(b'"') => self.string().map(JsonValue::String),
+            Some(b'[') => {
+                self.position += 1;
+                let mut values = vec![];
+                self.skip_whitespace();
+                if self.bytes.get(self.position) == Some(&b']') {
+                    self.position += 1;
+                    return Ok(JsonValue::Array(values));
+                }
+                loop {
+                    values.push(self.value()?);
+                    self.skip_whitespace();
+                    match self.bytes.get(self.position) {
+                        Some(b',') => self.position += 1,
+                        Some(b']') => {
+                            self.position += 1;
+                            return Ok(JsonValue::Array(values));
+                        }
+                        _ => return Err(self.error("expected `,` or `]`")),
+                    }
+                }
+            }
+            Some(b'{') => {
+                self.position += 1;
+                let mut members = vec![];
+                self.skip_whitespace();
+                if self.bytes.get(self.position) == Some(&b'}') {
+                    self.position += 1;
+                    return Ok(JsonValue::Object(members));
+                }
+                loop {
+                    self.skip_whitespace();
+                    let key = self.string()?;
+                    self.skip_whitespace();
+                    self.expect(":")?;
+                    members.push((key, self.value()?));
+                    self.skip_whitespace();
+                    match self.bytes.get(self.position) {
+                        Some(b',') => self.position += 1,
+                        Some(b'}') => {
+                            self.position += 1;
+                            return Ok(JsonValue::Object(members));
+                        }
+                        _ => return Err(self.error("expected `,` or `}`")),
+                    }
+                }
+            }
+            Some(b'-' | b'0'..=b'9') => {
+                let start = self.position;
+                while self.bytes.get(self.position).is_some_and(|byte| {
+                    matches!(byte, b'-' | b'+' | b'.' | b'e' | b'E' | b'0'..=b'9')
+                }) {
+                    self.position += 1;
+                }
+                core::str::from_utf8(&self.bytes[start..self.position])
+                    .ok()
+                    .and_then(|number| number.parse().ok())
+                    .map(JsonValue::Number)
+                    .ok_or_else(|| self.error("invalid number"))
+            }
+            _ => Err(self.error("expected a value")),
+        }
+    }
+
+    fn string(&mut self) -> Result<String, String> {
+        self.expect("\"")?;
+        let mut bytes = vec![];
+        loop {
+            let Some(&byte) = self.bytes.get(self.position) else {
+                return Err(self.error("unterminated string"));
+            };
+            self.position += 1;
+            match byte {
+                b'"' => break,
+                b'\\' => {
+                    let Some(&escape) = self.bytes.get(self.position) else {
+                        return Err(self.error("unterminated string"));
+                    };
+                    self.position += 1;
+                    let c = match escape {
+                        b'"' => '"',
+                        b'\\' => '\\',
+                        b'/' => '/',
+                        b'b' => '\u{8}',
+                        b'f' => '\u{c}',
+                        b'n' => '\n',
+                        b'r' => '\r',
+                        b't' => '\t',
+                        b'u' => {
+                            let mut code = self.hex4()?;
+                            if (0xD800..0xDC00).contains(&code) {
+                                self.expect("\\u")?;
+                                let low = self.hex4()?;
+                                code = 0x10000
+                                    + ((code - 0xD800) << 10)
+                                    + (low.wrapping_sub(0xDC00) & 0x3FF);
+                            }
+                            char::from_u32(code).unwrap_or(char::REPLACEMENT_CHARACTER)
+                        }
+                        _ => return Err(self.error("invalid escape")),
+                    };
+                    bytes.extend_from_slice(c.encode_utf8(&mut [0; 4]).as_bytes());
+                }
+                byte => bytes.push(byte),
+            }
+        }
+        String::from_utf8(bytes).map_err(|_| self.error("invalid utf-8"))
+    }
+
+    fn hex4(&mut self) -> Result<u32, String> {
+        let code = self
+            .bytes
+            .get(self.position..self.position + 4)
+            .and_then(|hex| core::str::from_utf8(hex).ok())
+            .and_then(|hex| u32::from_str_radix(hex, 16).ok())
+            .ok_or_else(|| self.error("invalid unicode escape"))?;
+        self.position += 4;
+        Ok(code)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn write_object() {
+        let mut out = String::new();
+        let mut object = ObjectWriter::new(&mut out);
+        object.string("name", "a \"quoted\"\nname");
+        object.number("x", 1.5);
+        object.number("y", f32::NAN);
+        object.end();
+        assert_eq!(out, r#"{"name":"a \"quoted\"\nname","x":1.5,"y":0}"#);
+    }
+
+    #[cfg(feature = "compat")]
+    #[test]
+    fn parse_and_write() {
+        let text = r#"{"a":[1,-2.5,1e3,true,false,null],"b":"\u00e9\n\"","c":{}}"#;
+        let value = JsonValue::parse(text).unwrap();
+        assert_eq!(
+            value.get("a").unwrap(),
+            &JsonValue::Array(vec![
+                JsonValue::Number(1.),
+                JsonValue::Number(-2.5),
+                JsonValue::Number(1000.),
+                JsonValue::Bool(true),
+                JsonValue::Bool(false),
+                JsonValue::Null,
+            ])
+        );
+        assert_eq!(value.get("b").unwrap().as_str(), Some("\u{e9}\n\""));
+        let mut out = String::new();
+        value.write(&mut out);
+        assert_eq!(JsonValue::parse(&out).unwrap(), value);
+        assert!(JsonValue::parse("{\"a\":}").is_err());
+        assert!(JsonValue::parse("[1] 2").is_err());
+    }
+}
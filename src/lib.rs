@@ -12,6 +12,16 @@
 //! To find and manage bones, see [`Bone`].
 //!
 //! To receive animation events, see [`AnimationState::set_listener`].
+//!
+//! To find Spine objects that were never freed, see [`diagnostics`].
+//!
+//! To detect what this build supports at runtime, see [`capabilities`](`capabilities()`).
+//!
+//! The `std` feature is enabled by default. Without it, the crate is `no_std` and only requires
+//! `alloc`, so it can run on platforms with a restricted standard library, such as console SDKs
+//! and embedded devices. Skeletons and atlases are then loaded from slices, and conveniences
+//! which access the filesystem are unavailable. The `libm` feature must be enabled instead, to
+//! provide float math functions.
 
 #![deny(
     clippy::missing_panics_doc,
@@ -32,32 +42,59 @@
 )]
 // https://github.com/rust-lang/rust-clippy/issues/11382
 #![allow(clippy::arc_with_non_send_sync)]
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+#[cfg(not(any(feature = "std", feature = "libm")))]
+compile_error!("rusty_spine requires either the `std` or the `libm` feature");
 
 #[macro_use]
 pub mod c_interface;
 pub mod c;
+pub mod diagnostics;
 pub mod extension;
+pub mod probe;
+pub mod skeleton_export;
+pub mod wardrobe;
 
+#[cfg(feature = "bench")]
+pub mod bench;
+#[cfg(feature = "bevy")]
+pub mod bevy;
+#[cfg(feature = "compat")]
+pub mod compat;
 #[cfg(feature = "draw_functions")]
 pub mod controller;
 #[cfg(feature = "draw_functions")]
 pub mod draw;
+#[cfg(feature = "wgpu-renderer")]
+pub mod renderer;
 
-mod animation;
+#[path = "animation.rs"]
+mod animation_mod;
 mod animation_state;
 mod animation_state_data;
+mod atlas_builder;
 #[path = "atlas.rs"]
 mod atlas_mod;
 mod attachment;
 mod attachment_loader;
 mod bone;
 mod bounding_box_attachment;
+mod capabilities;
 mod clipping_attachment;
 mod color;
+mod custom_attachment;
+mod debug_dump;
 mod error;
 mod event;
+mod event_queue;
 mod ik_constraint;
 mod ik_constraint_data;
+mod json;
+#[cfg(not(feature = "std"))]
+mod math;
 mod mesh_attachment;
 mod path_attachment;
 mod path_constraint;
@@ -66,31 +103,38 @@ mod physics;
 mod physics_constraint;
 mod physics_constraint_data;
 mod point_attachment;
+mod random;
 mod region_attachment;
 mod renderer_object;
 mod skeleton;
 mod skeleton_binary;
 mod skeleton_clipping;
 mod skeleton_data;
+mod skeleton_data_builder;
 mod skeleton_json;
 mod skin;
 mod slot;
+mod sync;
 mod texture_region;
 mod transform_constraint;
 mod transform_constraint_data;
 
-pub use animation::*;
+pub use animation_mod::{animation, Animation};
 pub use animation_state::*;
 pub use animation_state_data::*;
+pub use atlas_builder::*;
 pub use atlas_mod::{atlas, Atlas};
 pub use attachment::*;
 pub use attachment_loader::*;
 pub use bone::*;
 pub use bounding_box_attachment::*;
+pub use capabilities::*;
 pub use clipping_attachment::*;
 pub use color::*;
+pub use custom_attachment::*;
 pub use error::*;
 pub use event::*;
+pub use event_queue::*;
 pub use ik_constraint::*;
 pub use ik_constraint_data::*;
 pub use mesh_attachment::*;
@@ -107,6 +151,7 @@ pub use skeleton::*;
 pub use skeleton_binary::*;
 pub use skeleton_clipping::*;
 pub use skeleton_data::*;
+pub use skeleton_data_builder::*;
 pub use skeleton_json::*;
 pub use skin::*;
 pub use slot::*;
@@ -116,3 +161,15 @@ pub use transform_constraint_data::*;
 
 #[cfg(test)]
 pub mod test;
+
+/// Items of the standard library prelude which are not in the `core` prelude.
+mod prelude {
+    pub(crate) use alloc::{
+        borrow::ToOwned,
+        boxed::Box,
+        format,
+        string::{String, ToString},
+        vec,
+        vec::Vec,
+    };
+}
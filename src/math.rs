@@ -0,0 +1,91 @@
+//! Float functions which are only inherent methods with the `std` feature. Without it, they are
+//! provided by [`Float`] using `libm`, which requires the `libm` feature.
+
+pub(crate) trait Float: Sized {
+    fn sqrt(self) -> Self;
+    fn sin(self) -> Self;
+    fn cos(self) -> Self;
+    fn sin_cos(self) -> (Self, Self);
+    fn acos(self) -> Self;
+    fn atan2(self, other: Self) -> Self;
+    fn powf(self, n: Self) -> Self;
+    fn floor(self) -> Self;
+    fn ceil(self) -> Self;
+    fn round(self) -> Self;
+    fn rem_euclid(self, rhs: Self) -> Self;
+}
+
+#[cfg(feature = "libm")]
+macro_rules! libm {
+    ($function:ident($($arg:expr),*)) => {
+        libm::$function($($arg),*)
+    };
+}
+
+// Without `libm` the crate never builds, so this placeholder only keeps the `compile_error!` in
+// lib.rs from being buried under an error for every call below.
+#[cfg(not(feature = "libm"))]
+macro_rules! libm {
+    ($function:ident($($arg:expr),*)) => {{
+        $(let _ = $arg;)*
+        0.
+    }};
+}
+
+macro_rules! impl_float {
+    ($type:ty, $sqrt:ident, $sin:ident, $cos:ident, $acos:ident, $atan2:ident, $pow:ident, $floor:ident, $ceil:ident, $round:ident) => {
+        impl Float for $type {
+            fn sqrt(self) -> Self {
+                libm!($sqrt(self))
+            }
+
+            fn sin(self) -> Self {
+                libm!($sin(self))
+            }
+
+            fn cos(self) -> Self {
+                libm!($cos(self))
+            }
+
+            fn sin_cos(self) -> (Self, Self) {
+                (libm!($sin(self)), libm!($cos(self)))
+            }
+
+            fn acos(self) -> Self {
+                libm!($acos(self))
+            }
+
+            fn atan2(self, other: Self) -> Self {
+                libm!($atan2(self, other))
+            }
+
+            fn powf(self, n: Self) -> Self {
+                libm!($pow(self, n))
+            }
+
+            fn floor(self) -> Self {
+                libm!($floor(self))
+            }
+
+            fn ceil(self) -> Self {
+                libm!($ceil(self))
+            }
+
+            fn round(self) -> Self {
+                libm!($round(self))
+            }
+
+            fn rem_euclid(self, rhs: Self) -> Self {
+                let remainder = self % rhs;
+                if remainder < 0. {
+                    remainder + rhs.abs()
+                } else {
+                    remainder
+                }
+            }
+        }
+    };
+}
+
+impl_float!(f32, sqrtf, sinf, cosf, acosf, atan2f, powf, floorf, ceilf, roundf);
+impl_float!(f64, sqrt, sin, cos, acos, atan2, pow, floor, ceil, round);
@@ -1,3 +1,4 @@
+use crate::prelude::*;
 use crate::{
     c::{
         c_float, c_ushort, spAttachment, spMeshAttachment, spMeshAttachment_newLinkedMesh,
@@ -47,9 +48,16 @@ impl MeshAttachment {
         }
     }
 
+    /// Recomputes the UVs of this mesh from its [`region`](`Self::region`). Must be called after
+    /// the region changes. Does nothing if the mesh has no region.
+    ///
+    /// To update every attachment in a skeleton at once, see
+    /// [`Skeleton::update_attachment_uvs`](`crate::Skeleton::update_attachment_uvs`).
     pub fn update_region(&mut self) {
         unsafe {
-            spMeshAttachment_updateRegion(self.c_ptr());
+            if !self.c_ptr_ref().region.is_null() {
+                spMeshAttachment_updateRegion(self.c_ptr());
+            }
         }
     }
 
@@ -77,8 +85,202 @@ impl MeshAttachment {
     c_accessor_passthrough!(region_uvs, regionUVs, *mut c_float);
     c_ptr!(c_mesh_attachment, spMeshAttachment);
     // TODO: sequence accessor
+
+    /// The texture coordinates of the mesh as `u, v` pairs, one pair per vertex. Empty until the
+    /// region has been applied with [`update_region`](`Self::update_region`).
+    #[must_use]
+    pub fn uvs_slice(&self) -> &[f32] {
+        let uvs = unsafe { self.c_ptr_ref().uvs };
+        if uvs.is_null() {
+            &[]
+        } else {
+            unsafe {
+                core::slice::from_raw_parts(
+                    uvs,
+                    self.vertex_attachment().worldVerticesLength as usize,
+                )
+            }
+        }
+    }
+
+    /// The triangle indices of the mesh, three per triangle.
+    #[must_use]
+    pub fn triangles_slice(&self) -> &[u16] {
+        let triangles = unsafe { self.c_ptr_ref().triangles };
+        if triangles.is_null() {
+            &[]
+        } else {
+            unsafe { core::slice::from_raw_parts(triangles, self.triangles_count() as usize) }
+        }
+    }
+
+    /// An iterator over the bone influences of each vertex of a weighted mesh. Each item is itself
+    /// an iterator of `(bone_index, weight, local_position)` tuples, where `bone_index` is the
+    /// index of the bone in [`Skeleton::bones`](`crate::Skeleton::bones`) and `local_position` is
+    /// the position of the vertex in that bone's space.
+    ///
+    /// ```
+    /// # #[path="./test.rs"]
+    /// # mod test;
+    /// # let (skeleton, _) = test::TestAsset::spineboy().instance(true);
+    /// for slot in skeleton.slots() {
+    ///     let Some(mesh_attachment) = slot.mesh_attachment() else {
+    ///         continue;
+    ///     };
+    ///     for (vertex_index, influences) in mesh_attachment.bone_influences().enumerate() {
+    ///         for (bone_index, weight, [x, y]) in influences {
+    ///             println!("vertex {vertex_index}: bone {bone_index} ({weight}) at ({x}, {y})");
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// Unweighted meshes are only influenced by the slot's bone, so the iterator is empty and
+    /// [`vertices`](`Self::vertices`) contains the `x, y` positions in that bone's space instead.
+    #[must_use]
+    pub fn bone_influences(&self) -> MeshBoneInfluences<'_> {
+        MeshBoneInfluences {
+            bones: self.bones(),
+            vertices: self.vertices(),
+        }
+    }
+
+    /// The [`bone_influences`](`Self::bone_influences`) of each vertex packed into
+    /// `max_influences` influences per vertex, ready to be uploaded as vertex attributes for GPU
+    /// skinning.
+    ///
+    /// ```
+    /// # #[path="./test.rs"]
+    /// # mod test;
+    /// # let (skeleton, _) = test::TestAsset::spineboy().instance(true);
+    /// # let slot = skeleton.find_slot("head").unwrap();
+    /// # let mesh_attachment = slot.mesh_attachment().unwrap();
+    /// let influences = mesh_attachment.skin_influences_packed(4);
+    /// for vertex_index in 0..influences.vertices_count() {
+    ///     let range = vertex_index * 4..vertex_index * 4 + 4;
+    ///     let bone_indices = &influences.bone_indices[range.clone()];
+    ///     let weights = &influences.weights[range];
+    /// }
+    /// ```
+    ///
+    /// Vertices influenced by more bones keep their strongest influences, and the weights are
+    /// renormalized to sum to 1. Influences are sorted by weight, strongest first, and unused
+    /// influences have a bone index and weight of 0. Like `bone_influences`, this is empty for
+    /// unweighted meshes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_influences` is 0.
+    #[must_use]
+    pub fn skin_influences_packed(&self, max_influences: usize) -> PackedSkinInfluences {
+        assert!(max_influences > 0, "vertices need at least one influence");
+        let mut packed = PackedSkinInfluences {
+            influences_per_vertex: max_influences,
+            ..Default::default()
+        };
+        let mut influences: Vec<(usize, f32, [f32; 2])> = vec![];
+        for vertex_influences in self.bone_influences() {
+            influences.clear();
+            influences.extend(vertex_influences);
+            influences.sort_by(|a, b| b.1.total_cmp(&a.1));
+            influences.truncate(max_influences);
+            let total_weight: f32 = influences.iter().map(|influence| influence.1).sum();
+            for i in 0..max_influences {
+                let (bone_index, weight, position) = influences.get(i).copied().unwrap_or_default();
+                packed.bone_indices.push(bone_index as u16);
+                packed.weights.push(if total_weight > 0. {
+                    weight / total_weight
+                } else {
+                    0.
+                });
+                packed.positions.push(position);
+            }
+        }
+        packed
+    }
+}
+
+/// The bone influences of every vertex of a weighted [`MeshAttachment`], with a fixed amount of
+/// influences per vertex. The influences of vertex `i` are at
+/// `i * influences_per_vertex..(i + 1) * influences_per_vertex` in each list.
+///
+/// See [`MeshAttachment::skin_influences_packed`].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PackedSkinInfluences {
+    /// The number of influences stored for each vertex.
+    pub influences_per_vertex: usize,
+    /// The index of each influence's bone in [`Skeleton::bones`](`crate::Skeleton::bones`).
+    pub bone_indices: Vec<u16>,
+    /// The weight of each influence.
+    pub weights: Vec<f32>,
+    /// The position of the vertex in the space of each influence's bone.
+    pub positions: Vec<[f32; 2]>,
+}
+
+impl PackedSkinInfluences {
+    /// The number of vertices.
+    #[must_use]
+    pub fn vertices_count(&self) -> usize {
+        self.weights
+            .len()
+            .checked_div(self.influences_per_vertex)
+            .unwrap_or_default()
+    }
+}
+
+/// An iterator over the bone influences of each vertex of a weighted [`MeshAttachment`].
+///
+/// See [`MeshAttachment::bone_influences`].
+#[derive(Debug, Clone)]
+pub struct MeshBoneInfluences<'a> {
+    bones: &'a [i32],
+    vertices: &'a [f32],
+}
+
+impl<'a> Iterator for MeshBoneInfluences<'a> {
+    type Item = VertexBoneInfluences<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (&influences_count, bones) = self.bones.split_first()?;
+        let influences_count = influences_count as usize;
+        let vertex_bones = bones.get(..influences_count)?;
+        let vertex_vertices = self.vertices.get(..influences_count * 3)?;
+        self.bones = &bones[influences_count..];
+        self.vertices = &self.vertices[influences_count * 3..];
+        Some(VertexBoneInfluences {
+            bones: vertex_bones,
+            vertices: vertex_vertices,
+        })
+    }
+}
+
+/// An iterator over the `(bone_index, weight, local_position)` influences of a single vertex.
+///
+/// See [`MeshAttachment::bone_influences`].
+#[derive(Debug, Clone)]
+pub struct VertexBoneInfluences<'a> {
+    bones: &'a [i32],
+    vertices: &'a [f32],
+}
+
+impl Iterator for VertexBoneInfluences<'_> {
+    type Item = (usize, f32, [f32; 2]);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (&bone_index, bones) = self.bones.split_first()?;
+        let [x, y, weight] = *self.vertices.first_chunk::<3>()?;
+        self.bones = bones;
+        self.vertices = &self.vertices[3..];
+        Some((bone_index as usize, weight, [x, y]))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.bones.len(), Some(self.bones.len()))
+    }
 }
 
+impl ExactSizeIterator for VertexBoneInfluences<'_> {}
+
 /// Functions available if using the `mint` feature.
 #[cfg(feature = "mint")]
 impl MeshAttachment {
@@ -92,3 +294,136 @@ impl MeshAttachment {
 
     c_vertex_attachment_accessors_mint!();
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{test::TestAsset, SpineError};
+
+    #[test]
+    fn slices_and_bone_influences() {
+        let (skeleton, _) = TestAsset::spineboy().instance(true);
+        let slot = skeleton.find_slot("eye").unwrap();
+        let mesh_attachment = slot.mesh_attachment().unwrap();
+        assert_eq!(mesh_attachment.triangles_slice(), [1, 3, 0, 1, 2, 3]);
+        assert_eq!(mesh_attachment.uvs_slice().len(), 8);
+
+        // the first vertex of the weighted mesh, as exported in spineboy-pro.json
+        assert_eq!(mesh_attachment.bone_influences().count(), 4);
+        let influences: Vec<(usize, f32, [f32; 2])> =
+            mesh_attachment.bone_influences().next().unwrap().collect();
+        let bone_index = |name: &str| skeleton.find_bone(name).unwrap().data().index();
+        let expected = [
+            (bone_index("head-control"), 0.3, [-36.8, -91.35]),
+            (bone_index("head"), 0.7, [73.41, -91.35]),
+        ];
+        assert_eq!(influences.len(), expected.len());
+        for (influence, expected) in influences.iter().zip(expected) {
+            assert_eq!(influence.0, expected.0);
+            assert!((influence.1 - expected.1).abs() < 0.001);
+            assert!((influence.2[0] - expected.2[0]).abs() < 0.01);
+            assert!((influence.2[1] - expected.2[1]).abs() < 0.01);
+        }
+    }
+
+    #[test]
+    fn skin_influences_packed() {
+        for asset in TestAsset::all() {
+            let (skeleton, _) = asset.instance(true);
+            for slot in skeleton.slots() {
+                let Some(mesh_attachment) = slot.mesh_attachment() else {
+                    continue;
+                };
+                for max_influences in [1, 2, 4] {
+                    let packed = mesh_attachment.skin_influences_packed(max_influences);
+                    assert_eq!(
+                        packed.vertices_count(),
+                        mesh_attachment.bone_influences().count()
+                    );
+                    assert_eq!(
+                        packed.bone_indices.len(),
+                        packed.vertices_count() * max_influences
+                    );
+                    assert_eq!(packed.positions.len(), packed.bone_indices.len());
+                    for (weights, influences) in packed
+                        .weights
+                        .chunks_exact(max_influences)
+                        .zip(mesh_attachment.bone_influences())
+                    {
+                        assert!((weights.iter().sum::<f32>() - 1.).abs() < 0.001);
+                        assert!(weights.windows(2).all(|pair| pair[0] >= pair[1]));
+                        if influences.len() <= max_influences {
+                            let mut expected =
+                                influences.map(|(_, weight, _)| weight).collect::<Vec<_>>();
+                            expected.sort_by(|a, b| b.total_cmp(a));
+                            for (weight, expected) in weights.iter().zip(expected) {
+                                assert!((weight - expected).abs() < 0.001);
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn compute_world_vertices() {
+        let (skeleton, _) = TestAsset::spineboy().instance(true);
+        let slot = skeleton.find_slot("head").unwrap();
+        let mesh_attachment = slot.attachment().unwrap().as_mesh().unwrap();
+        let length = mesh_attachment.world_vertices_length();
+        let mut vertices = vec![0.; length as usize];
+        let mut expected = vec![0.; length as usize];
+        mesh_attachment
+            .compute_world_vertices(&slot, 0, length, &mut vertices, 0, 2)
+            .unwrap();
+        unsafe {
+            mesh_attachment.compute_world_vertices_unchecked(&slot, 0, length, &mut expected, 0, 2);
+        }
+        assert_eq!(vertices, expected);
+
+        // Weighted meshes can't be computed with the bones of a smaller skeleton
+        let (coin, _) = TestAsset::all()[2].instance(true);
+        let weighted_mesh = skeleton
+            .slots()
+            .filter_map(|slot| {
+                slot.attachment()
+                    .and_then(|attachment| attachment.as_mesh())
+            })
+            .find(|mesh| {
+                mesh.bones()
+                    .iter()
+                    .any(|bone| *bone as usize >= coin.bones_count())
+            })
+            .unwrap();
+        let coin_slot = coin.slot_at_index(0).unwrap();
+        let mut vertices = vec![0.; weighted_mesh.world_vertices_length() as usize];
+        assert!(matches!(
+            weighted_mesh.compute_world_vertices(
+                &coin_slot,
+                0,
+                weighted_mesh.world_vertices_length(),
+                &mut vertices,
+                0,
+                2
+            ),
+            Err(SpineError::IncompatibleAttachment { .. })
+        ));
+    }
+
+    #[test]
+    #[should_panic(expected = "world_vertices is too small")]
+    fn compute_world_vertices_too_small() {
+        let (skeleton, _) = TestAsset::spineboy().instance(true);
+        let slot = skeleton.find_slot("head").unwrap();
+        let mesh_attachment = slot.attachment().unwrap().as_mesh().unwrap();
+        let mut vertices = [0.; 4];
+        let _ = mesh_attachment.compute_world_vertices(
+            &slot,
+            0,
+            mesh_attachment.world_vertices_length(),
+            &mut vertices,
+            0,
+            2,
+        );
+    }
+}
@@ -1,6 +1,9 @@
+#[cfg(not(feature = "std"))]
+use crate::math::Float;
 use crate::{
     c::{c_float, spAttachment, spPathAttachment, spVertexAttachment},
     c_interface::{NewFromPtr, SyncPtr},
+    slot::Slot,
 };
 
 /// An attachment of vertices forming a Bezier curve.
@@ -31,7 +34,7 @@ impl PathAttachment {
     c_attachment_accessors!();
     c_vertex_attachment_accessors!();
     c_accessor_bool_mut!(
-        /// If `true, the start and end knots are connected.
+        /// If `true`, the start and end knots are connected.
         closed,
         /// Set closed, see [`closed`](`Self::closed`).
         set_closed,
@@ -59,11 +62,153 @@ impl PathAttachment {
         lengths,
         *mut c_float
     );
+    /// The lengths along the path in the setup pose from the start of the path to the end of
+    /// each Bezier curve. There is one length per knot, so open paths have an extra, unused
+    /// length.
+    #[must_use]
+    pub fn lengths_slice(&self) -> &[f32] {
+        let lengths = unsafe { self.c_ptr_ref().lengths };
+        if lengths.is_null() {
+            &[]
+        } else {
+            unsafe {
+                core::slice::from_raw_parts(lengths, self.c_ptr_ref().lengthsLength.max(0) as usize)
+            }
+        }
+    }
+
+    /// The amount of Bezier curves in this path.
+    #[must_use]
+    pub fn curves_count(&self) -> usize {
+        let knots = self.world_vertices_length().max(0) as usize / 6;
+        if self.closed() {
+            knots
+        } else {
+            knots.saturating_sub(1)
+        }
+    }
+
+    /// Computes the world position and rotation (in degrees) of the point a percentage (0-1) of
+    /// the way along the path, for example to move a projectile or follower along an authored
+    /// path. Returns [`None`] if the path has no curves.
+    ///
+    /// The percentage is distributed across the Bezier curves using
+    /// [`lengths_slice`](`Self::lengths_slice`). Closed paths wrap around, while open paths are
+    /// clamped to their ends.
+    ///
+    /// # Safety
+    ///
+    /// The slot passed in must be the same slot this attachment originated from.
+    #[must_use]
+    pub unsafe fn compute_world_position(
+        &self,
+        slot: &Slot,
+        percent: f32,
+    ) -> Option<(f32, f32, f32)> {
+        let curves_count = self.curves_count();
+        let lengths = self.lengths_slice();
+        if curves_count == 0 || lengths.len() < curves_count {
+            return None;
+        }
+        let path_length = lengths[curves_count - 1];
+        let mut distance = percent * path_length;
+        if self.closed() {
+            distance = distance.rem_euclid(path_length);
+        } else {
+            distance = distance.clamp(0., path_length);
+        }
+        let curve = lengths[..curves_count]
+            .iter()
+            .position(|length| distance <= *length)
+            .unwrap_or(curves_count - 1);
+        let start = if curve == 0 { 0. } else { lengths[curve - 1] };
+        let curve_length = lengths[curve] - start;
+        let t = if curve_length > 0. {
+            (distance - start) / curve_length
+        } else {
+            0.
+        };
+
+        let mut world = [0.; 8];
+        let world_vertices_length = self.world_vertices_length();
+        if self.closed() && curve == curves_count - 1 {
+            self.compute_world_vertices_unchecked(
+                slot,
+                world_vertices_length - 4,
+                4,
+                &mut world,
+                0,
+                2,
+            );
+            self.compute_world_vertices_unchecked(slot, 0, 4, &mut world, 4, 2);
+        } else {
+            self.compute_world_vertices_unchecked(slot, curve as i32 * 6 + 2, 8, &mut world, 0, 2);
+        }
+        Some(bezier_position(&world, t))
+    }
+
     c_ptr!(c_path_attachment, spPathAttachment);
 }
 
+/// Evaluates a cubic Bezier curve at `t`, returning the position and tangent rotation in degrees.
+fn bezier_position(curve: &[f32; 8], t: f32) -> (f32, f32, f32) {
+    let [x1, y1, cx1, cy1, cx2, cy2, x2, y2] = *curve;
+    let u = 1. - t;
+    let uu = u * u;
+    let tt = t * t;
+    let x = uu * u * x1 + 3. * uu * t * cx1 + 3. * u * tt * cx2 + tt * t * x2;
+    let y = uu * u * y1 + 3. * uu * t * cy1 + 3. * u * tt * cy2 + tt * t * y2;
+    let mut dx = 3. * uu * (cx1 - x1) + 6. * u * t * (cx2 - cx1) + 3. * tt * (x2 - cx2);
+    let mut dy = 3. * uu * (cy1 - y1) + 6. * u * t * (cy2 - cy1) + 3. * tt * (y2 - cy2);
+    if dx == 0. && dy == 0. {
+        // The control point overlaps the end point, so use the direction of the whole curve
+        dx = x2 - x1;
+        dy = y2 - y1;
+    }
+    (x, y, dy.atan2(dx).to_degrees())
+}
+
 /// Functions available if using the `mint` feature.
 #[cfg(feature = "mint")]
 impl PathAttachment {
     c_vertex_attachment_accessors_mint!();
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{test::TestAsset, Physics};
+
+    #[test]
+    fn path_world_position() {
+        let (mut skeleton, _) = TestAsset::all()[5].instance(true);
+        skeleton.update_world_transform(Physics::None);
+        let path_constraint = skeleton.find_path_constraint("back-leg-path").unwrap();
+        let slot = path_constraint.target();
+        let path_attachment = slot.attachment().unwrap().as_path().unwrap();
+        assert!(path_attachment.curves_count() > 0);
+        assert!(path_attachment.lengths_slice().len() >= path_attachment.curves_count());
+
+        let mut start = [0.; 2];
+        let mut end = [0.; 2];
+        let world_vertices_length = path_attachment.world_vertices_length();
+        unsafe {
+            path_attachment.compute_world_vertices_unchecked(&slot, 2, 2, &mut start, 0, 2);
+            path_attachment.compute_world_vertices_unchecked(
+                &slot,
+                world_vertices_length - 4,
+                2,
+                &mut end,
+                0,
+                2,
+            );
+        }
+        let (x, y, _) = unsafe { path_attachment.compute_world_position(&slot, 0.) }.unwrap();
+        assert!((x - start[0]).abs() < 0.01 && (y - start[1]).abs() < 0.01);
+        let (x, y, _) = unsafe { path_attachment.compute_world_position(&slot, 1.) }.unwrap();
+        assert!((x - end[0]).abs() < 0.01 && (y - end[1]).abs() < 0.01);
+        assert_eq!(
+            path_constraint.world_positions().len(),
+            path_constraint.bones_count()
+        );
+    }
+}
@@ -1,9 +1,12 @@
+use crate::prelude::*;
 use crate::{
     c::{
         spBone, spPathConstraint, spPathConstraintData, spPathConstraint_setToSetupPose,
-        spPathConstraint_update, spSlot,
+        spPathConstraint_update, spSkeleton_findSlot, spSlot,
     },
-    c_interface::{NewFromPtr, SyncPtr},
+    c_interface::{to_c_str, NewFromPtr, SyncPtr},
+    error::SpineError,
+    skeleton_data::check_skeleton_data_frozen,
     Bone, PathConstraintData, Slot,
 };
 
@@ -38,13 +41,14 @@ impl PathConstraint {
         }
     }
 
-    c_accessor_tmp_ptr_mut!(
+    c_accessor_tmp_ptr_data_mut!(
         /// The path constraint's setup pose data.
         data,
         data_mut,
         data,
         PathConstraintData,
-        spPathConstraintData
+        spPathConstraintData,
+        Self::check_data_frozen
     );
 
     c_accessor_bool!(active, active);
@@ -88,10 +92,13 @@ impl PathConstraint {
     );
 
     c_accessor!(bones_count, bonesCount, usize);
-    c_accessor_array!(
+    c_accessor_array_mut!(
         /// The bones that will be modified by this path constraint.
         bones,
+        /// The mutable bones that will be modified by this path constraint.
+        bones_mut,
         bone_at_index,
+        bone_at_index_mut,
         PathConstraint,
         Bone,
         spBone,
@@ -107,7 +114,51 @@ impl PathConstraint {
         spSlot
     );
 
+    /// Set the slot whose path attachment will be used to constrain the bones, looked up by name
+    /// in this constraint's skeleton.
+    ///
+    /// Changing the target can change the order bones and constraints need to be updated in, so
+    /// [`Skeleton::update_cache`](`crate::Skeleton::update_cache`) must be called afterward.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SpineError::NotFound`] if the skeleton has no slot named `slot_name`.
+    pub fn set_target_by_name(&mut self, slot_name: &str) -> Result<(), SpineError> {
+        let c_slot_name = to_c_str(slot_name);
+        unsafe {
+            let c_skeleton = (*(*self.c_ptr_ref().target).bone).skeleton;
+            let c_target = spSkeleton_findSlot(c_skeleton, c_slot_name.as_ptr());
+            if c_target.is_null() {
+                return Err(SpineError::new_not_found("Slot", slot_name));
+            }
+            self.c_ptr_mut().target = c_target;
+        }
+        Ok(())
+    }
+
+    /// The world positions and rotations (in degrees) along the path computed for the constrained
+    /// bones during the last [`update`](`Self::update`), as `x, y, rotation` triples.
+    #[must_use]
+    pub fn world_positions(&self) -> Vec<(f32, f32, f32)> {
+        let positions = unsafe { self.c_ptr_ref().positions };
+        if positions.is_null() {
+            return vec![];
+        }
+        let positions = unsafe {
+            core::slice::from_raw_parts(positions, self.c_ptr_ref().positionsCount.max(0) as usize)
+        };
+        positions
+            .chunks_exact(3)
+            .take(self.bones_count())
+            .map(|position| (position[0], position[1], position[2]))
+            .collect()
+    }
+
     c_ptr!(c_path_constraint, spPathConstraint);
+
+    fn check_data_frozen(&self) -> Result<(), SpineError> {
+        check_skeleton_data_frozen(unsafe { (*(*(*self.c_ptr_ref().target).bone).skeleton).data })
+    }
 }
 
 /// Functions available if using the `mint` feature.
@@ -1,6 +1,7 @@
 use crate::c::spPhysics;
 
 /// Determines how physics and other non-deterministic updates are applied.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Physics {
     /// Physics are not updated or applied.
     None = 0,
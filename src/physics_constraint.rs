@@ -5,6 +5,8 @@ use crate::{
         spPhysicsConstraint_translate, spPhysicsConstraint_update,
     },
     c_interface::{NewFromPtr, SyncPtr},
+    error::SpineError,
+    skeleton_data::check_skeleton_data_frozen,
     Bone, Physics, PhysicsConstraintData,
 };
 
@@ -23,20 +25,24 @@ impl NewFromPtr<spPhysicsConstraint> for PhysicsConstraint {
 }
 
 impl PhysicsConstraint {
-    pub fn reset(&self) {
+    /// Resets the physics state, so the constrained bones come to rest immediately without any
+    /// forces carried over from previous updates.
+    pub fn reset(&mut self) {
         unsafe {
             spPhysicsConstraint_reset(self.c_physics_constraint.0);
         }
     }
 
     /// Applies the constraint to the constrained bones.
-    pub fn update(&self, physics: Physics) {
+    pub fn update(&mut self, physics: Physics) {
         unsafe {
             spPhysicsConstraint_update(self.c_physics_constraint.0, physics as spPhysics);
         }
     }
 
-    pub fn set_to_setup_pose(&self) {
+    /// Sets this constraint's properties, such as inertia and strength, to their setup pose
+    /// values from [`data`](`Self::data`).
+    pub fn set_to_setup_pose(&mut self) {
         unsafe {
             spPhysicsConstraint_setToSetupPose(self.c_physics_constraint.0);
         }
@@ -58,20 +64,53 @@ impl PhysicsConstraint {
         }
     }
 
-    c_accessor_tmp_ptr_mut!(
+    c_accessor_tmp_ptr_data_mut!(
         /// The physics constraint's setup pose data.
         data,
         data_mut,
         data,
         PhysicsConstraintData,
-        spPhysicsConstraintData
+        spPhysicsConstraintData,
+        Self::check_data_frozen
     );
 
-    c_accessor_bool!(active, active);
-    c_accessor_mut!(damping, set_damping, damping, f32);
-    c_accessor_mut!(gravity, set_gravity, gravity, f32);
-    c_accessor_mut!(inertia, set_intertia, inertia, f32);
-    c_accessor_mut!(mass_inverse, set_mass_inverse, massInverse, f32);
+    c_accessor_bool!(
+        /// Returns false when this constraint won't be updated by
+        /// [`Skeleton::update_world_transform`](`crate::Skeleton::update_world_transform`) because
+        /// a skin is required and the active skin does not contain this constraint.
+        active,
+        active
+    );
+    c_accessor_mut!(
+        /// Reduces the speed of the bone's movement, from 0 (the bone keeps moving) to 1 (the bone
+        /// stops immediately).
+        damping,
+        set_damping,
+        damping,
+        f32
+    );
+    c_accessor_mut!(
+        /// A constant force applied downward, in skeleton units per second squared.
+        gravity,
+        set_gravity,
+        gravity,
+        f32
+    );
+    c_accessor_mut!(
+        /// A percentage (0-1) of how much the bone follows the movement of its parent bones, where
+        /// 0 leaves the bone behind and 1 moves it with its parents.
+        inertia,
+        set_inertia,
+        inertia,
+        f32
+    );
+    c_accessor_mut!(
+        /// The inverse of the bone's mass, where larger values make forces move the bone more.
+        mass_inverse,
+        set_mass_inverse,
+        massInverse,
+        f32
+    );
     c_accessor_mut!(
         /// A percentage (0-1) that controls the mix between the constrained and unconstrained poses.
         mix,
@@ -79,8 +118,25 @@ impl PhysicsConstraint {
         mix,
         f32
     );
-    c_accessor_mut!(strength, set_strength, strength, f32);
-    c_accessor_mut!(wind, set_wind, wind, f32);
+    c_accessor_mut!(
+        /// How strongly the bone is pulled back towards its unconstrained pose, like a spring.
+        strength,
+        set_strength,
+        strength,
+        f32
+    );
+    c_accessor_mut!(
+        /// A constant force applied horizontally, in skeleton units per second squared.
+        wind,
+        set_wind,
+        wind,
+        f32
+    );
+
+    #[deprecated(note = "renamed to `set_inertia`")]
+    pub fn set_intertia(&mut self, inertia: f32) {
+        self.set_inertia(inertia);
+    }
 
     c_accessor_tmp_ptr_mut!(
         /// The bone constrained by this physics constraint.
@@ -92,4 +148,8 @@ impl PhysicsConstraint {
     );
 
     c_ptr!(c_physics_constraint, spPhysicsConstraint);
+
+    fn check_data_frozen(&self) -> Result<(), SpineError> {
+        check_skeleton_data_frozen(unsafe { (*(*self.c_ptr_ref().bone).skeleton).data })
+    }
 }
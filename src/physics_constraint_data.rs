@@ -49,7 +49,7 @@ impl PhysicsConstraintData {
     c_accessor_bool!(damping_global, dampingGlobal);
     c_accessor!(gravity, gravity, f32);
     c_accessor_bool!(gravity_global, gravityGlobal);
-    c_accessor!(inertia, gravity, f32);
+    c_accessor!(inertia, inertia, f32);
     c_accessor_bool!(inertia_global, inertiaGlobal);
     c_accessor!(limit, limit, f32);
     c_accessor_bool!(mass_global, massGlobal);
@@ -31,16 +31,34 @@ impl PointAttachment {
         unsafe { &self.c_ptr_ref().super_0 }
     }
 
+    /// Computes the world position and rotation (in degrees) of this point, for instance to
+    /// spawn a muzzle flash or projectile. The bone should be the bone of the slot this attachment
+    /// is on, and must have an up-to-date world transform.
+    ///
+    /// ```
+    /// use rusty_spine::Slot;
+    ///
+    /// fn spawn_projectile(slot: &Slot) {
+    ///     let Some(point_attachment) = slot.attachment().and_then(|attachment| attachment.as_point())
+    ///     else {
+    ///         return;
+    ///     };
+    ///     let (x, y, rotation) = point_attachment.compute_world_position(&slot.bone());
+    ///     // spawn a projectile at `x, y` travelling in the direction of `rotation`
+    /// }
+    /// ```
     #[must_use]
-    pub fn compute_world_position(&self, bone: &Bone) -> (f32, f32) {
+    pub fn compute_world_position(&self, bone: &Bone) -> (f32, f32, f32) {
         let mut x = 0.;
         let mut y = 0.;
         unsafe {
             spPointAttachment_computeWorldPosition(self.c_ptr(), bone.c_ptr(), &mut x, &mut y);
         }
-        (x, y)
+        (x, y, self.compute_world_rotation(bone))
     }
 
+    /// Computes the world rotation (in degrees) of this point. See
+    /// [`compute_world_position`](`Self::compute_world_position`).
     #[must_use]
     pub fn compute_world_rotation(&self, bone: &Bone) -> f32 {
         unsafe { spPointAttachment_computeWorldRotation(self.c_ptr(), bone.c_ptr()) }
@@ -53,9 +71,27 @@ impl PointAttachment {
         color,
         color
     );
-    c_accessor_mut!(rotation, set_rotation, rotation, f32);
-    c_accessor_mut!(x, set_x, x, f32);
-    c_accessor_mut!(y, set_y, x, f32);
+    c_accessor_mut!(
+        /// The local rotation in degrees, counter clockwise.
+        rotation,
+        set_rotation,
+        rotation,
+        f32
+    );
+    c_accessor_mut!(
+        /// The local x translation.
+        x,
+        set_x,
+        x,
+        f32
+    );
+    c_accessor_mut!(
+        /// The local y translation.
+        y,
+        set_y,
+        y,
+        f32
+    );
     c_ptr!(c_point_attachment, spPointAttachment);
 }
 
@@ -69,4 +105,41 @@ impl PointAttachment {
             y: self.y(),
         }
     }
+
+    pub fn set_translation(&mut self, translation: impl Into<Vector2<f32>>) {
+        let translation: Vector2<f32> = translation.into();
+        self.set_x(translation.x);
+        self.set_y(translation.y);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        c::{spAttachment, spPointAttachment_create},
+        c_interface::NewFromPtr,
+        test::TestAsset,
+        Attachment, Physics,
+    };
+
+    #[test]
+    fn point_attachment_world_position() {
+        let (mut skeleton, _) = TestAsset::spineboy().instance(true);
+        skeleton.update_world_transform(Physics::None);
+        let attachment = unsafe {
+            Attachment::new_from_ptr(
+                spPointAttachment_create(c"point".as_ptr()).cast::<spAttachment>(),
+            )
+        };
+        let mut point_attachment = attachment.as_point().unwrap();
+        point_attachment.set_x(3.);
+        point_attachment.set_y(4.);
+        point_attachment.set_rotation(90.);
+        assert_eq!(point_attachment.x(), 3.);
+        assert_eq!(point_attachment.y(), 4.);
+        let root = skeleton.bone_root();
+        let (x, y, rotation) = point_attachment.compute_world_position(&root);
+        assert_eq!((x, y), root.local_to_world(3., 4.));
+        assert!((rotation - (root.world_rotation_x() + 90.)).abs() < 0.01);
+    }
 }
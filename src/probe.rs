@@ -0,0 +1,210 @@
+//! Reads the version of Spine which exported skeleton data without loading it, so asset
+//! pipelines can quickly validate many files.
+//!
+//! ```
+//! use rusty_spine::probe;
+//!
+//! for path in [
+//!     "assets/spineboy/export/spineboy-pro.json",
+//!     "assets/spineboy/export/spineboy-pro.skel",
+//! ] {
+//!     let version = probe::skeleton_version(&std::fs::read(path)?)?;
+//!     if !version.is_supported() {
+//!         println!(
+//!             "{path} was exported by Spine {}, re-export it from Spine {}",
+//!             version.version,
+//!             probe::RUNTIME_VERSION
+//!         );
+//!     }
+//! }
+//! # Ok::<(), rusty_spine::SpineError>(())
+//! ```
+//!
+//! Only the start of the data is read, so data with a supported version may still fail to load
+//! if it is corrupted.
+
+use crate::prelude::*;
+use crate::SpineError;
+
+/// The version of Spine supported by this runtime. Data exported by any `4.2.xx` version of
+/// Spine can be loaded.
+pub const RUNTIME_VERSION: &str = "4.2";
+
+/// The format of skeleton data, see [`SpineVersion`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SkeletonFormat {
+    /// A `.json` export, read by [`SkeletonJson`](`crate::SkeletonJson`).
+    Json,
+    /// A `.skel` export, read by [`SkeletonBinary`](`crate::SkeletonBinary`).
+    Binary,
+}
+
+/// The version of Spine which exported skeleton data, see [`skeleton_version`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SpineVersion {
+    /// The full version, such as `4.2.22` or `4.2.64-beta`.
+    pub version: String,
+    /// The major version, such as `4` for `4.2.22`.
+    pub major: u32,
+    /// The minor version, such as `2` for `4.2.22`.
+    pub minor: u32,
+    /// The format of the data.
+    pub format: SkeletonFormat,
+}
+
+impl SpineVersion {
+    /// Returns `true` if this runtime can load data exported by this version of Spine, see
+    /// [`RUNTIME_VERSION`].
+    #[must_use]
+    pub fn is_supported(&self) -> bool {
+        format!("{}.{}", self.major, self.minor) == RUNTIME_VERSION
+    }
+}
+
+/// Reads the version of Spine which exported JSON or binary skeleton data, detecting the format
+/// from the data.
+///
+/// # Errors
+///
+/// Returns [`SpineError::ParsingFailed`] if the data is not skeleton data, or doesn't contain a
+/// version.
+pub fn skeleton_version(data: &[u8]) -> Result<SpineVersion, SpineError> {
+    let first_byte = data.iter().find(|byte| !byte.is_ascii_whitespace());
+    let json_version = if first_byte == Some(&b'{') {
+        json_version(data)
+    } else {
+        None
+    };
+    let (version, format) = if let Some(version) = json_version {
+        (version, SkeletonFormat::Json)
+    } else if let Some(version) = binary_version(data) {
+        (version, SkeletonFormat::Binary)
+    } else {
+        return Err(SpineError::new_skeleton_parsing_failed(
+            "Skeleton version not found",
+            None,
+        ));
+    };
+    let mut numbers = version
+        .split(|c: char| !c.is_ascii_digit())
+        .map(str::parse::<u32>);
+    let (Some(Ok(major)), Some(Ok(minor))) = (numbers.next(), numbers.next()) else {
+        return Err(SpineError::new_skeleton_parsing_failed(
+            &format!("Invalid skeleton version: {version}"),
+            None,
+        ));
+    };
+    Ok(SpineVersion {
+        version: version.to_owned(),
+        major,
+        minor,
+        format,
+    })
+}
+
+/// The `spine` member of the `skeleton` object, which Spine writes at the start of the data.
+fn json_version(data: &[u8]) -> Option<&str> {
+    let skeleton = find(data, b"\"skeleton\"")?;
+    let mut rest = &data[skeleton..];
+    loop {
+        rest = &rest[find(rest, b"\"spine\"")? + 7..];
+        let value = rest
+            .trim_ascii_start()
+            .strip_prefix(b":")?
+            .trim_ascii_start();
+        // A `"spine"` not followed by a string is the name of something else, such as a bone.
+        if let Some(value) = value.strip_prefix(b"\"") {
+            let end = value.iter().position(|byte| *byte == b'"')?;
+            return core::str::from_utf8(&value[..end]).ok();
+        }
+    }
+}
+
+/// The version string after the hash. Spine 4.x writes the hash as 8 bytes, and Spine 3.x as a
+/// string.
+fn binary_version(data: &[u8]) -> Option<&str> {
+    let looks_like_version = |version: &&str| {
+        version.len() >= 3 && version.as_bytes()[0].is_ascii_digit() && version.contains('.')
+    };
+    data.get(8..)
+        .and_then(read_string)
+        .map(|(version, _)| version)
+        .filter(looks_like_version)
+        .or_else(|| {
+            let (_, rest) = read_string(data)?;
+            read_string(rest)
+                .map(|(version, _)| version)
+                .filter(looks_like_version)
+        })
+}
+
+/// Reads a string written by Spine as its length plus one in a varint, followed by its bytes,
+/// returning the string and the data after it.
+fn read_string(data: &[u8]) -> Option<(&str, &[u8])> {
+    let mut length = 0;
+    let mut read = 0;
+    loop {
+        let byte = *data.get(read)?;
+        length |= usize::from(byte & 0x7F) << (7 * read);
+        read += 1;
+        if byte & 0x80 == 0 {
+            break;
+        }
+        if read == 5 {
+            return None;
+        }
+    }
+    let length = length.checked_sub(1)?;
+    let string = data.get(read..read + length)?;
+    Some((core::str::from_utf8(string).ok()?, &data[read + length..]))
+}
+
+fn find(data: &[u8], pattern: &[u8]) -> Option<usize> {
+    data.windows(pattern.len())
+        .position(|window| window == pattern)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test::TestAsset;
+
+    use super::*;
+
+    #[test]
+    fn probe_assets() {
+        for asset in TestAsset::all() {
+            for (data, format) in [
+                (asset.json_data, SkeletonFormat::Json),
+                (asset.binary_data, SkeletonFormat::Binary),
+            ] {
+                let version = skeleton_version(data).unwrap();
+                assert_eq!(version.format, format);
+                assert_eq!((version.major, version.minor), (4, 2));
+                assert!(version.version.starts_with("4.2."));
+                assert!(version.is_supported());
+            }
+        }
+    }
+
+    #[test]
+    fn probe_other_versions() {
+        let json = br#"{ "bones": [ { "name": "spine" } ], "skeleton": { "spine" : "4.1.24" } }"#;
+        let version = skeleton_version(json).unwrap();
+        assert_eq!(version.version, "4.1.24");
+        assert_eq!(version.format, SkeletonFormat::Json);
+        assert!(!version.is_supported());
+
+        let mut binary = vec![12];
+        binary.extend_from_slice(b"hash-string");
+        binary.push(7);
+        binary.extend_from_slice(b"3.8.99");
+        binary.extend_from_slice(&[0; 16]);
+        let version = skeleton_version(&binary).unwrap();
+        assert_eq!(version.version, "3.8.99");
+        assert_eq!(version.format, SkeletonFormat::Binary);
+        assert_eq!((version.major, version.minor), (3, 8));
+
+        assert!(skeleton_version(b"{}").is_err());
+        assert!(skeleton_version(b"").is_err());
+    }
+}
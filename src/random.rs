@@ -0,0 +1,45 @@
+//! A small, seedable random number generator for helpers which need reproducible randomness
+//! without pulling in a dependency.
+
+/// The `SplitMix64` generator, which is fast and produces well distributed values from any seed,
+/// including `0`.
+#[derive(Debug, Clone)]
+pub(crate) struct Random {
+    state: u64,
+}
+
+impl Random {
+    pub(crate) const fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    pub(crate) const fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// A value in the range `[0, 1)`.
+    pub(crate) fn next_f32(&mut self) -> f32 {
+        // The upper 24 bits fit exactly in an f32 mantissa.
+        (self.next_u64() >> 40) as f32 / (1u64 << 24) as f32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn random_range() {
+        let mut random = Random::new(0);
+        let mut other = Random::new(0);
+        for _ in 0..1000 {
+            let value = random.next_f32();
+            assert!((0. ..1.).contains(&value));
+            assert_eq!(value, other.next_f32());
+        }
+    }
+}
@@ -1,12 +1,19 @@
+use core::ptr::null_mut;
+
+use crate::prelude::*;
 use crate::{
+    atlas::{AtlasPage, AtlasRegion},
+    bone::Bone,
     c::{
-        c_float, spAttachment, spRegionAttachment, spRegionAttachment_computeWorldVertices,
+        _spAttachmentVtable, _spMalloc, _spRegionAttachment_copy, _spRegionAttachment_dispose,
+        c_float, c_int, size_t, spAtlasRegion, spAttachment, spKeyValueArray, spRegionAttachment,
+        spRegionAttachment_computeWorldVertices, spRegionAttachment_create,
         spRegionAttachment_updateRegion, spTextureRegion,
     },
-    c_interface::SyncPtr,
+    c_interface::{to_c_str, NewFromPtr, SyncPtr},
     slot::Slot,
     texture_region::TextureRegion,
-    Color,
+    Attachment, Color,
 };
 
 #[cfg(feature = "mint")]
@@ -25,6 +32,73 @@ pub struct RegionProps {
     pub color: Color,
 }
 
+/// A rectangle of an [`AtlasPage`] to draw with a [`RegionAttachment`] created by
+/// [`RegionAttachment::new_from_texture_region`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CustomTextureRegion {
+    /// The left texture coordinate.
+    pub u: f32,
+    /// The top texture coordinate.
+    pub v: f32,
+    /// The right texture coordinate.
+    pub u2: f32,
+    /// The bottom texture coordinate.
+    pub v2: f32,
+    /// The width of the region, in pixels.
+    pub width: i32,
+    /// The height of the region, in pixels.
+    pub height: i32,
+}
+
+/// The atlas region owned by attachments created with
+/// [`RegionAttachment::new_from_texture_region`], freed when the attachment is disposed.
+#[repr(C)]
+#[derive(Clone, Copy)]
+struct CustomAtlasRegion {
+    atlas_region: spAtlasRegion,
+    key_values: spKeyValueArray,
+}
+
+impl CustomAtlasRegion {
+    /// Moves the region to the heap and points the attachment at it.
+    unsafe fn attach(mut self, c_region_attachment: *mut spRegionAttachment) {
+        self.atlas_region.keyValues = null_mut();
+        let custom_region = Box::into_raw(Box::new(self));
+        (*custom_region).atlas_region.keyValues = &mut (*custom_region).key_values;
+        (*custom_region).atlas_region.super_0.rendererObject = custom_region.cast();
+        (*c_region_attachment).rendererObject = custom_region.cast();
+        (*c_region_attachment).region = &mut (*custom_region).atlas_region.super_0;
+        let vtable = (*c_region_attachment)
+            .super_0
+            .vtable
+            .cast_mut()
+            .cast::<_spAttachmentVtable>();
+        (*vtable).dispose = Some(dispose_custom_region_attachment);
+        (*vtable).copy = Some(copy_custom_region_attachment);
+    }
+}
+
+unsafe extern "C" fn dispose_custom_region_attachment(c_attachment: *mut spAttachment) {
+    let c_region_attachment = c_attachment.cast::<spRegionAttachment>();
+    drop(Box::from_raw(
+        (*c_region_attachment)
+            .rendererObject
+            .cast::<CustomAtlasRegion>(),
+    ));
+    _spRegionAttachment_dispose(c_attachment);
+}
+
+unsafe extern "C" fn copy_custom_region_attachment(
+    c_attachment: *mut spAttachment,
+) -> *mut spAttachment {
+    let c_copy = _spRegionAttachment_copy(c_attachment);
+    let custom_region = *(*c_attachment.cast::<spRegionAttachment>())
+        .rendererObject
+        .cast::<CustomAtlasRegion>();
+    custom_region.attach(c_copy.cast::<spRegionAttachment>());
+    c_copy
+}
+
 /// An attachment which draws a texture.
 ///
 /// [Spine API Reference](http://esotericsoftware.com/spine-api-reference#RegionAttachment)
@@ -46,10 +120,166 @@ impl RegionAttachment {
         unsafe { &self.c_ptr_ref().super_0 }
     }
 
+    /// Create a new region attachment which draws an [`AtlasRegion`], sized to the region's
+    /// original image size, such as to swap in a weapon sprite which isn't in any of the
+    /// skeleton's skins. Assign it to a slot with
+    /// [`Skeleton::set_region_attachment`](`crate::Skeleton::set_region_attachment`).
+    ///
+    /// ```
+    /// # #[path="./test.rs"]
+    /// # mod test;
+    /// # use rusty_spine::RegionAttachment;
+    /// # let atlas = test::TestAsset::spineboy().atlas();
+    /// # let (mut skeleton, _) = test::TestAsset::spineboy().instance(true);
+    /// let region = atlas.find_region("gun").unwrap();
+    /// // SAFETY: the atlas outlives the skeleton.
+    /// let attachment = unsafe { RegionAttachment::new_from_atlas_region("laser-gun", &region) };
+    /// skeleton.set_region_attachment("gun", &attachment)?;
+    /// # Ok::<(), rusty_spine::SpineError>(())
+    /// ```
+    ///
     /// # Safety
     ///
-    /// The slot passed in must be the same slot this attachment originated from.
-    pub unsafe fn compute_world_vertices(
+    /// The attachment refers to the region directly, so the [`Atlas`](`crate::Atlas`) must
+    /// outlive the attachment, its copies, and any skeleton it is assigned to, or they must not be
+    /// drawn or have their region read after the atlas is dropped.
+    #[must_use]
+    pub unsafe fn new_from_atlas_region(name: &str, atlas_region: &AtlasRegion) -> Attachment {
+        unsafe {
+            let c_region_attachment = Self::create(name, atlas_region.name());
+            let c_atlas_region = atlas_region.c_ptr();
+            (*c_region_attachment).rendererObject = c_atlas_region.cast();
+            (*c_region_attachment).region = &mut (*c_atlas_region).super_0;
+            Self::finish(c_region_attachment)
+        }
+    }
+
+    /// Create a new region attachment which draws a rectangle of an [`AtlasPage`], for textures
+    /// which aren't split into regions by the atlas. Assign it to a slot with
+    /// [`Skeleton::set_region_attachment`](`crate::Skeleton::set_region_attachment`).
+    ///
+    /// ```
+    /// # #[path="./test.rs"]
+    /// # mod test;
+    /// # use rusty_spine::{CustomTextureRegion, RegionAttachment};
+    /// # let atlas = test::TestAsset::spineboy().atlas();
+    /// let page = atlas.pages().next().unwrap();
+    /// // SAFETY: the attachment is dropped before the atlas.
+    /// let attachment = unsafe {
+    ///     RegionAttachment::new_from_texture_region(
+    ///         "sticker",
+    ///         &page,
+    ///         CustomTextureRegion {
+    ///             u: 0.,
+    ///             v: 0.,
+    ///             u2: 0.25,
+    ///             v2: 0.25,
+    ///             width: 64,
+    ///             height: 64,
+    ///         },
+    ///     )
+    /// };
+    /// ```
+    ///
+    /// # Safety
+    ///
+    /// The attachment refers to the page directly, so the [`Atlas`](`crate::Atlas`) must outlive
+    /// the attachment, its copies, and any skeleton it is assigned to, or they must not be drawn
+    /// or have their region read after the atlas is dropped.
+    #[must_use]
+    pub unsafe fn new_from_texture_region(
+        name: &str,
+        page: &AtlasPage,
+        region: CustomTextureRegion,
+    ) -> Attachment {
+        unsafe {
+            let c_region_attachment = Self::create(name, name);
+            let mut custom_region: CustomAtlasRegion = core::mem::zeroed();
+            custom_region.atlas_region.super_0 = spTextureRegion {
+                rendererObject: null_mut(),
+                u: region.u,
+                v: region.v,
+                u2: region.u2,
+                v2: region.v2,
+                degrees: 0,
+                offsetX: 0.,
+                offsetY: 0.,
+                width: region.width,
+                height: region.height,
+                originalWidth: region.width,
+                originalHeight: region.height,
+            };
+            custom_region.atlas_region.page = page.c_ptr();
+            custom_region.attach(c_region_attachment);
+            Self::finish(c_region_attachment)
+        }
+    }
+
+    unsafe fn create(name: &str, path: &str) -> *mut spRegionAttachment {
+        let c_name = to_c_str(name);
+        let c_region_attachment = spRegionAttachment_create(c_name.as_ptr());
+        // The path is freed by Spine, so it must be allocated by Spine
+        let c_path = to_c_str(path);
+        let c_path = c_path.as_bytes_with_nul();
+        let c_path_copy = _spMalloc(
+            c_path.len() as size_t,
+            c"region_attachment.rs".as_ptr(),
+            line!() as c_int,
+        )
+        .cast::<u8>();
+        core::ptr::copy_nonoverlapping(c_path.as_ptr(), c_path_copy, c_path.len());
+        (*c_region_attachment).path = c_path_copy.cast();
+        c_region_attachment
+    }
+
+    unsafe fn finish(c_region_attachment: *mut spRegionAttachment) -> Attachment {
+        let region = &*(*c_region_attachment).region;
+        (*c_region_attachment).width = region.originalWidth as f32;
+        (*c_region_attachment).height = region.originalHeight as f32;
+        spRegionAttachment_updateRegion(c_region_attachment);
+        Attachment::new_from_ptr(c_region_attachment.cast())
+    }
+
+    /// Computes the world positions of the attachment's four corners from the bone's world
+    /// transform, writing them to the first eight floats of `vertices` in the order bottom right,
+    /// bottom left, top left, top right. The bone's world transform must be up to date, see
+    /// [`Skeleton::update_world_transform`](`crate::Skeleton::update_world_transform`).
+    ///
+    /// ```
+    /// # #[path="./test.rs"]
+    /// # mod test;
+    /// # let (skeleton, _) = test::TestAsset::spineboy().instance(true);
+    /// let slot = skeleton.find_slot("gun").unwrap();
+    /// let region_attachment = slot.attachment().unwrap().as_region().unwrap();
+    /// let mut vertices = [0.; 8];
+    /// region_attachment.compute_world_vertices(&slot.bone(), &mut vertices);
+    /// ```
+    ///
+    /// Unlike [`compute_world_vertices_unchecked`](`Self::compute_world_vertices_unchecked`),
+    /// this does not advance the attachment's sequence, if any.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `vertices` has fewer than eight floats.
+    pub fn compute_world_vertices(&self, bone: &Bone, vertices: &mut [f32]) {
+        assert!(vertices.len() >= 8, "vertices must hold at least 8 floats");
+        let offsets = unsafe { self.c_ptr_ref().offset };
+        // The offsets are stored bottom left first, but spine-c outputs bottom right first
+        for (vertex, index) in vertices.chunks_exact_mut(2).zip([6, 0, 2, 4]) {
+            let [x, y] = [offsets[index], offsets[index + 1]];
+            vertex[0] = x * bone.a() + y * bone.b() + bone.world_x();
+            vertex[1] = x * bone.c() + y * bone.d() + bone.world_y();
+        }
+    }
+
+    /// Computes world vertices through the slot's bone, advancing the attachment's sequence if
+    /// it has one. See [`compute_world_vertices`](`Self::compute_world_vertices`).
+    ///
+    /// # Safety
+    ///
+    /// The slot passed in must be the same slot this attachment originated from, and
+    /// `vertices` must be large enough to hold eight floats `stride` apart from `offset`.
+    pub unsafe fn compute_world_vertices_unchecked(
         &self,
         slot: &Slot,
         vertices: &mut [f32],
@@ -65,6 +295,12 @@ impl RegionAttachment {
         );
     }
 
+    /// Recomputes the UVs and vertex offsets of this attachment from its
+    /// [`region`](`Self::region`) and properties. Must be called after the region, size,
+    /// translation, rotation, or scale change.
+    ///
+    /// To update every attachment in a skeleton at once, see
+    /// [`Skeleton::update_attachment_uvs`](`crate::Skeleton::update_attachment_uvs`).
     pub fn update_region(&mut self) {
         unsafe {
             spRegionAttachment_updateRegion(self.c_ptr());
@@ -172,3 +408,102 @@ impl RegionAttachment {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::test::TestAsset;
+    #[cfg(feature = "draw_functions")]
+    use crate::{
+        c::spAttachment_copy,
+        c_interface::NewFromPtr,
+        draw::{ColorSpace, CullDirection, SimpleDrawer},
+        Attachment, SpineError,
+    };
+
+    #[cfg(feature = "draw_functions")]
+    use super::*;
+
+    #[test]
+    #[cfg(feature = "draw_functions")]
+    fn new_from_regions() {
+        let atlas = TestAsset::spineboy().atlas();
+        let (mut skeleton, _) = TestAsset::spineboy().instance(true);
+        let region = atlas.find_region("head").unwrap();
+        let attachment = unsafe { RegionAttachment::new_from_atlas_region("big-gun", &region) };
+        let region_attachment = attachment.as_region().unwrap();
+        assert_eq!(region_attachment.path(), "head");
+        assert_eq!(
+            region_attachment.width(),
+            region.texture_region().original_width() as f32
+        );
+        skeleton.set_region_attachment("gun", &attachment).unwrap();
+        assert!(skeleton.set_region_attachment("none", &attachment).is_err());
+        drop(attachment);
+        let mesh = skeleton
+            .get_attachment_for_slot_name("eye", "eye-surprised")
+            .unwrap();
+        assert!(matches!(
+            skeleton.set_region_attachment("eye", &mesh),
+            Err(SpineError::IncompatibleAttachment { .. })
+        ));
+
+        let page = atlas.pages().next().unwrap();
+        let attachment = unsafe {
+            RegionAttachment::new_from_texture_region(
+                "sticker",
+                &page,
+                CustomTextureRegion {
+                    u: 0.,
+                    v: 0.,
+                    u2: 0.5,
+                    v2: 0.25,
+                    width: 64,
+                    height: 32,
+                },
+            )
+        };
+        let copy = unsafe { Attachment::new_from_ptr(spAttachment_copy(attachment.c_ptr())) };
+        drop(attachment);
+        let region_attachment = copy.as_region().unwrap();
+        assert_eq!(region_attachment.width(), 64.);
+        assert_eq!(region_attachment.region().unwrap().u2(), 0.5);
+        skeleton.set_region_attachment("head", &copy).unwrap();
+        drop(copy);
+
+        skeleton.update_world_transform(crate::Physics::None);
+        let drawer = SimpleDrawer {
+            cull_direction: CullDirection::Clockwise,
+            premultiplied_alpha: false,
+            color_space: ColorSpace::SRGB,
+        };
+        let renderables = drawer.draw(&mut skeleton, None);
+        let head = skeleton.find_slot("head").unwrap();
+        let sticker = renderables
+            .iter()
+            .find(|renderable| {
+                skeleton
+                    .draw_order_at_index(renderable.slot_index)
+                    .unwrap()
+                    .data()
+                    .name()
+                    == head.data().name()
+            })
+            .unwrap();
+        assert_eq!(sticker.vertices.len(), 4);
+        assert!(sticker.uvs.iter().all(|[u, v]| *u <= 0.5 && *v <= 0.25));
+    }
+
+    #[test]
+    fn compute_world_vertices() {
+        let (mut skeleton, _) = TestAsset::spineboy().instance(true);
+        skeleton.find_bone_mut("gun").unwrap().set_rotation(30.);
+        skeleton.update_world_transform(crate::Physics::Update);
+        let slot = skeleton.find_slot("gun").unwrap();
+        let region_attachment = slot.attachment().unwrap().as_region().unwrap();
+        let mut vertices = [0.; 8];
+        let mut expected = [0.; 8];
+        region_attachment.compute_world_vertices(&slot.bone(), &mut vertices);
+        unsafe { region_attachment.compute_world_vertices_unchecked(&slot, &mut expected, 0, 2) };
+        assert_eq!(vertices, expected);
+    }
+}
@@ -0,0 +1,5 @@
+//! Reference renderers for graphics libraries, each behind its own feature.
+//!
+//! - [`wgpu`] with the `wgpu-renderer` feature
+
+pub mod wgpu;
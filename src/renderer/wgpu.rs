@@ -0,0 +1,567 @@
+//! A reference renderer using [wgpu](https://wgpu.rs), available with the `wgpu-renderer`
+//! feature.
+//!
+//! [`SpinePipelines`] creates a pipeline for each of the 8 combinations of [`BlendMode`] and
+//! premultiplied alpha, shared by every [`SpineRenderer`], which draws a [`SkeletonController`]
+//! with two color tinting.
+//!
+//! Rendering a skeleton takes three steps:
+//! 1. Upload the image of each atlas page with [`SpinePipelines::create_texture`] and store it on
+//!    the page with [`AtlasPage::set_texture`], usually from
+//!    [`set_create_texture_cb`](`crate::extension::set_create_texture_cb`).
+//! 2. Every frame, before the render pass, upload the skeleton's meshes with
+//!    [`SpineRenderer::prepare`].
+//! 3. Draw them in a render pass with [`SpineRenderer::render`].
+//!
+//! ```no_run
+//! # fn run(
+//! #     device: std::sync::Arc<wgpu::Device>,
+//! #     queue: std::sync::Arc<wgpu::Queue>,
+//! #     encoder: &mut wgpu::CommandEncoder,
+//! #     view: &wgpu::TextureView,
+//! #     load_image: fn(&str) -> (u32, u32, Vec<u8>),
+//! # ) -> Result<(), rusty_spine::SpineError> {
+//! use std::sync::Arc;
+//! use rusty_spine::{
+//!     controller::SkeletonController,
+//!     renderer::wgpu::{SpinePipelines, SpineRenderer},
+//!     AnimationStateData, Atlas, SkeletonJson,
+//! };
+//!
+//! let pipelines = Arc::new(SpinePipelines::new(&device, wgpu::TextureFormat::Bgra8Unorm));
+//! rusty_spine::extension::set_create_texture_cb({
+//!     let (device, queue, pipelines) = (device.clone(), queue.clone(), pipelines.clone());
+//!     move |page, path| {
+//!         let (width, height, rgba8) = load_image(path);
+//!         page.set_texture(pipelines.create_texture(&device, &queue, page, width, height, &rgba8));
+//!     }
+//! });
+//! let atlas = Arc::new(Atlas::new_from_file("skeleton.atlas")?);
+//! let skeleton_data = Arc::new(SkeletonJson::new(atlas).read_skeleton_data_file("skeleton.json")?);
+//! let animation_state_data = Arc::new(AnimationStateData::new(skeleton_data.clone()));
+//! let mut controller = SkeletonController::new(skeleton_data, animation_state_data);
+//! let mut renderer = SpineRenderer::new(&device, pipelines);
+//!
+//! // every frame
+//! renderer.set_transform(&queue, [
+//!     [1. / 500., 0., 0., 0.],
+//!     [0., 1. / 500., 0., 0.],
+//!     [0., 0., 1., 0.],
+//!     [0., -0.5, 0., 1.],
+//! ]);
+//! renderer.prepare(&device, &mut controller);
+//! let mut render_pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+//!     label: None,
+//!     color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+//!         view,
+//!         resolve_target: None,
+//!         ops: wgpu::Operations::default(),
+//!     })],
+//!     depth_stencil_attachment: None,
+//!     timestamp_writes: None,
+//!     occlusion_query_set: None,
+//! });
+//! renderer.render(&mut render_pass);
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Like the official Spine runtimes, colors are blended without sRGB conversion, so render targets
+//! should use a non-sRGB format such as `Bgra8Unorm`.
+//!
+//! The renderer is intentionally small, so it can be copied and adapted when more control is
+//! needed.
+
+use std::{borrow::Cow, mem::size_of, ops::Range, sync::Arc};
+
+use ::wgpu::{
+    util::{BufferInitDescriptor, DeviceExt, TextureDataOrder},
+    vertex_attr_array, AddressMode, BindGroup, BindGroupDescriptor, BindGroupEntry,
+    BindGroupLayout, BindGroupLayoutDescriptor, BindGroupLayoutEntry, BindingResource, BindingType,
+    BlendOperation, BlendState, Buffer, BufferAddress, BufferBindingType, BufferUsages,
+    ColorTargetState, ColorWrites, Device, Extent3d, FilterMode, FragmentState, IndexFormat,
+    MultisampleState, PipelineCompilationOptions, PipelineLayoutDescriptor, PrimitiveState, Queue,
+    RenderPass, RenderPipeline, RenderPipelineDescriptor, SamplerBindingType, SamplerDescriptor,
+    ShaderModuleDescriptor, ShaderSource, ShaderStages, TextureDescriptor, TextureDimension,
+    TextureFormat, TextureSampleType, TextureUsages, TextureViewDescriptor, TextureViewDimension,
+    VertexAttribute, VertexBufferLayout, VertexState, VertexStepMode,
+};
+
+use crate::{
+    atlas::{AtlasFilter, AtlasPage, AtlasWrap},
+    controller::SkeletonController,
+    draw::{BlendComponent, BlendEquation, BlendFactor, BlendFactors},
+    BlendMode,
+};
+
+const SHADER: &str = r"
+struct Uniforms {
+    transform: mat4x4<f32>,
+};
+
+@group(0) @binding(0) var<uniform> uniforms: Uniforms;
+@group(1) @binding(0) var page_texture: texture_2d<f32>;
+@group(1) @binding(1) var page_sampler: sampler;
+
+struct VertexOutput {
+    @builtin(position) position: vec4<f32>,
+    @location(0) uv: vec2<f32>,
+    @location(1) color: vec4<f32>,
+    @location(2) dark_color: vec4<f32>,
+};
+
+@vertex
+fn vertex(
+    @location(0) position: vec2<f32>,
+    @location(1) uv: vec2<f32>,
+    @location(2) color: vec4<f32>,
+    @location(3) dark_color: vec4<f32>,
+) -> VertexOutput {
+    var output: VertexOutput;
+    output.position = uniforms.transform * vec4<f32>(position, 0.0, 1.0);
+    output.uv = uv;
+    output.color = color;
+    output.dark_color = dark_color;
+    return output;
+}
+
+@fragment
+fn fragment(input: VertexOutput) -> @location(0) vec4<f32> {
+    let tex_color = textureSample(page_texture, page_sampler, input.uv);
+    return vec4<f32>(
+        ((tex_color.a - 1.0) * input.dark_color.a + 1.0 - tex_color.rgb) * input.dark_color.rgb
+            + tex_color.rgb * input.color.rgb,
+        tex_color.a * input.color.a,
+    );
+}
+";
+
+const VERTEX_ATTRIBUTES: [VertexAttribute; 4] =
+    vertex_attr_array![0 => Float32x2, 1 => Float32x2, 2 => Float32x4, 3 => Float32x4];
+
+/// The number of `f32`s in each vertex: position, UV, color, and dark color.
+const VERTEX_FLOATS: usize = 12;
+
+/// The layout of the vertex buffer used by [`SpineRenderer`], matching the vertex data of
+/// controller renderables: a `vec2` position, `vec2` UV, `vec4` color, and `vec4` dark color at
+/// locations 0 to 3.
+#[must_use]
+pub const fn vertex_layout() -> VertexBufferLayout<'static> {
+    VertexBufferLayout {
+        array_stride: (VERTEX_FLOATS * size_of::<f32>()) as BufferAddress,
+        step_mode: VertexStepMode::Vertex,
+        attributes: &VERTEX_ATTRIBUTES,
+    }
+}
+
+impl From<BlendFactor> for ::wgpu::BlendFactor {
+    fn from(factor: BlendFactor) -> Self {
+        match factor {
+            BlendFactor::Zero => Self::Zero,
+            BlendFactor::One => Self::One,
+            BlendFactor::SourceColor => Self::Src,
+            BlendFactor::OneMinusSourceColor => Self::OneMinusSrc,
+            BlendFactor::SourceAlpha => Self::SrcAlpha,
+            BlendFactor::OneMinusSourceAlpha => Self::OneMinusSrcAlpha,
+            BlendFactor::DestinationColor => Self::Dst,
+            BlendFactor::OneMinusDestinationColor => Self::OneMinusDst,
+            BlendFactor::DestinationAlpha => Self::DstAlpha,
+            BlendFactor::OneMinusDestinationAlpha => Self::OneMinusDstAlpha,
+        }
+    }
+}
+
+impl From<BlendEquation> for BlendOperation {
+    fn from(equation: BlendEquation) -> Self {
+        match equation {
+            BlendEquation::Add => Self::Add,
+            BlendEquation::Subtract => Self::Subtract,
+            BlendEquation::ReverseSubtract => Self::ReverseSubtract,
+        }
+    }
+}
+
+impl From<BlendComponent> for ::wgpu::BlendComponent {
+    fn from(component: BlendComponent) -> Self {
+        Self {
+            src_factor: component.source_factor.into(),
+            dst_factor: component.destination_factor.into(),
+            operation: component.equation.into(),
+        }
+    }
+}
+
+impl From<BlendFactors> for BlendState {
+    fn from(blend_factors: BlendFactors) -> Self {
+        Self {
+            color: blend_factors.color.into(),
+            alpha: blend_factors.alpha.into(),
+        }
+    }
+}
+
+const fn pipeline_index(blend_mode: BlendMode, premultiplied_alpha: bool) -> usize {
+    blend_mode as usize * 2 + premultiplied_alpha as usize
+}
+
+const fn filter_mode(filter: AtlasFilter) -> FilterMode {
+    match filter {
+        AtlasFilter::Nearest | AtlasFilter::MipmapNearestNearest => FilterMode::Nearest,
+        _ => FilterMode::Linear,
+    }
+}
+
+const fn address_mode(wrap: AtlasWrap) -> AddressMode {
+    match wrap {
+        AtlasWrap::MirroredRepeat => AddressMode::MirrorRepeat,
+        AtlasWrap::Repeat => AddressMode::Repeat,
+        AtlasWrap::ClampToEdge | AtlasWrap::Unknown => AddressMode::ClampToEdge,
+    }
+}
+
+/// The texture of an atlas page uploaded by [`SpinePipelines::create_texture`], to be stored on
+/// the page with [`AtlasPage::set_texture`].
+#[derive(Debug, Clone)]
+pub struct SpineTexture {
+    bind_group: Arc<BindGroup>,
+}
+
+#[derive(Debug)]
+struct PreparedDrawCall {
+    bind_group: Arc<BindGroup>,
+    pipeline_index: usize,
+    indices: Range<u32>,
+    base_vertex: i32,
+}
+
+/// The pipelines and bind group layouts shared by every [`SpineRenderer`] drawing to the same
+/// render target format, see the [module documentation](`self`).
+#[derive(Debug)]
+pub struct SpinePipelines {
+    pipelines: Vec<RenderPipeline>,
+    uniform_bind_group_layout: BindGroupLayout,
+    texture_bind_group_layout: BindGroupLayout,
+}
+
+impl SpinePipelines {
+    /// Create the pipelines for drawing to render targets of the given format.
+    #[must_use]
+    pub fn new(device: &Device, format: TextureFormat) -> Self {
+        let shader = device.create_shader_module(ShaderModuleDescriptor {
+            label: Some("spine_shader"),
+            source: ShaderSource::Wgsl(Cow::Borrowed(SHADER)),
+        });
+        let uniform_bind_group_layout =
+            device.create_bind_group_layout(&BindGroupLayoutDescriptor {
+                label: Some("spine_uniform_bind_group_layout"),
+                entries: &[BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: ShaderStages::VERTEX,
+                    ty: BindingType::Buffer {
+                        ty: BufferBindingType::Uniform,
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                }],
+            });
+        let texture_bind_group_layout =
+            device.create_bind_group_layout(&BindGroupLayoutDescriptor {
+                label: Some("spine_texture_bind_group_layout"),
+                entries: &[
+                    BindGroupLayoutEntry {
+                        binding: 0,
+                        visibility: ShaderStages::FRAGMENT,
+                        ty: BindingType::Texture {
+                            sample_type: TextureSampleType::Float { filterable: true },
+                            view_dimension: TextureViewDimension::D2,
+                            multisampled: false,
+                        },
+                        count: None,
+                    },
+                    BindGroupLayoutEntry {
+                        binding: 1,
+                        visibility: ShaderStages::FRAGMENT,
+                        ty: BindingType::Sampler(SamplerBindingType::Filtering),
+                        count: None,
+                    },
+                ],
+            });
+        let pipeline_layout = device.create_pipeline_layout(&PipelineLayoutDescriptor {
+            label: Some("spine_pipeline_layout"),
+            bind_group_layouts: &[&uniform_bind_group_layout, &texture_bind_group_layout],
+            push_constant_ranges: &[],
+        });
+        let mut pipelines = vec![];
+        for blend_mode in [
+            BlendMode::Normal,
+            BlendMode::Additive,
+            BlendMode::Multiply,
+            BlendMode::Screen,
+        ] {
+            for premultiplied_alpha in [false, true] {
+                debug_assert_eq!(
+                    pipelines.len(),
+                    pipeline_index(blend_mode, premultiplied_alpha)
+                );
+                pipelines.push(device.create_render_pipeline(&RenderPipelineDescriptor {
+                    label: Some("spine_pipeline"),
+                    layout: Some(&pipeline_layout),
+                    vertex: VertexState {
+                        module: &shader,
+                        entry_point: "vertex",
+                        compilation_options: PipelineCompilationOptions::default(),
+                        buffers: &[vertex_layout()],
+                    },
+                    primitive: PrimitiveState::default(),
+                    depth_stencil: None,
+                    multisample: MultisampleState::default(),
+                    fragment: Some(FragmentState {
+                        module: &shader,
+                        entry_point: "fragment",
+                        compilation_options: PipelineCompilationOptions::default(),
+                        targets: &[Some(ColorTargetState {
+                            format,
+                            blend: Some(blend_mode.blend_factors(premultiplied_alpha).into()),
+                            write_mask: ColorWrites::ALL,
+                        })],
+                    }),
+                    multiview: None,
+                }));
+            }
+        }
+        Self {
+            pipelines,
+            uniform_bind_group_layout,
+            texture_bind_group_layout,
+        }
+    }
+
+    /// Upload the image of an atlas page, as tightly packed RGBA8 pixels, with a sampler using
+    /// the page's filters and wrapping.
+    ///
+    /// The texture is created with the `Rgba8Unorm` format. Pixels are uploaded as is, so they
+    /// must already be premultiplied if [`AtlasPage::pma`] is set.
+    #[must_use]
+    pub fn create_texture(
+        &self,
+        device: &Device,
+        queue: &Queue,
+        page: &AtlasPage,
+        width: u32,
+        height: u32,
+        rgba8: &[u8],
+    ) -> SpineTexture {
+        let texture = device.create_texture_with_data(
+            queue,
+            &TextureDescriptor {
+                label: Some(page.name()),
+                size: Extent3d {
+                    width,
+                    height,
+                    depth_or_array_layers: 1,
+                },
+                mip_level_count: 1,
+                sample_count: 1,
+                dimension: TextureDimension::D2,
+                format: TextureFormat::Rgba8Unorm,
+                usage: TextureUsages::TEXTURE_BINDING | TextureUsages::COPY_DST,
+                view_formats: &[],
+            },
+            TextureDataOrder::LayerMajor,
+            rgba8,
+        );
+        let view = texture.create_view(&TextureViewDescriptor::default());
+        let sampler = device.create_sampler(&SamplerDescriptor {
+            label: Some(page.name()),
+            address_mode_u: address_mode(page.u_wrap()),
+            address_mode_v: address_mode(page.v_wrap()),
+            mag_filter: filter_mode(page.mag_filter()),
+            min_filter: filter_mode(page.min_filter()),
+            ..Default::default()
+        });
+        let bind_group = device.create_bind_group(&BindGroupDescriptor {
+            label: Some(page.name()),
+            layout: &self.texture_bind_group_layout,
+            entries: &[
+                BindGroupEntry {
+                    binding: 0,
+                    resource: BindingResource::TextureView(&view),
+                },
+                BindGroupEntry {
+                    binding: 1,
+                    resource: BindingResource::Sampler(&sampler),
+                },
+            ],
+        });
+        SpineTexture {
+            bind_group: Arc::new(bind_group),
+        }
+    }
+}
+
+/// Draws a [`SkeletonController`] with wgpu, see the [module documentation](`self`).
+///
+/// Each renderer holds the meshes of one skeleton between [`prepare`](`Self::prepare`) and
+/// [`render`](`Self::render`), so a renderer is needed for each skeleton drawn in the same
+/// frame.
+#[derive(Debug)]
+pub struct SpineRenderer {
+    pipelines: Arc<SpinePipelines>,
+    uniform_buffer: Buffer,
+    uniform_bind_group: BindGroup,
+    vertex_buffer: Option<Buffer>,
+    index_buffer: Option<Buffer>,
+    draw_calls: Vec<PreparedDrawCall>,
+}
+
+impl SpineRenderer {
+    /// Create a renderer using the given pipelines. The transform starts as the identity matrix.
+    #[must_use]
+    pub fn new(device: &Device, pipelines: Arc<SpinePipelines>) -> Self {
+        let uniform_buffer = device.create_buffer_init(&BufferInitDescriptor {
+            label: Some("spine_uniform_buffer"),
+            contents: &matrix_bytes([
+                [1., 0., 0., 0.],
+                [0., 1., 0., 0.],
+                [0., 0., 1., 0.],
+                [0., 0., 0., 1.],
+            ]),
+            usage: BufferUsages::UNIFORM | BufferUsages::COPY_DST,
+        });
+        let uniform_bind_group = device.create_bind_group(&BindGroupDescriptor {
+            label: Some("spine_uniform_bind_group"),
+            layout: &pipelines.uniform_bind_group_layout,
+            entries: &[BindGroupEntry {
+                binding: 0,
+                resource: uniform_buffer.as_entire_binding(),
+            }],
+        });
+        Self {
+            pipelines,
+            uniform_buffer,
+            uniform_bind_group,
+            vertex_buffer: None,
+            index_buffer: None,
+            draw_calls: vec![],
+        }
+    }
+
+    /// Set the column major matrix transforming skeleton coordinates into clip space.
+    pub fn set_transform(&self, queue: &Queue, transform: [[f32; 4]; 4]) {
+        queue.write_buffer(&self.uniform_buffer, 0, &matrix_bytes(transform));
+    }
+
+    /// Render the controller's skeleton with [`SkeletonController::draw_list`] and upload the
+    /// meshes, replacing those from the previous call.
+    ///
+    /// Draw calls from atlas pages without a [`SpineTexture`] are skipped.
+    pub fn prepare(&mut self, device: &Device, controller: &mut SkeletonController) {
+        let mut vertices: Vec<u8> = vec![];
+        let mut indices: Vec<u8> = vec![];
+        let mut vertices_count = 0;
+        let mut indices_count = 0;
+        self.draw_calls.clear();
+        for draw_call in
+            controller.draw_list(|page| Some(page.texture::<SpineTexture>()?.bind_group.clone()))
+        {
+            let Some(bind_group) = draw_call.texture else {
+                continue;
+            };
+            for (index, vertex) in draw_call.vertices.iter().enumerate() {
+                let uv = draw_call.uvs[index];
+                let color = draw_call.colors[index];
+                let dark_color = draw_call.dark_colors[index];
+                for value in vertex.iter().chain(&uv).chain(&color).chain(&dark_color) {
+                    vertices.extend_from_slice(&value.to_ne_bytes());
+                }
+            }
+            for index in &draw_call.indices {
+                indices.extend_from_slice(&index.to_ne_bytes());
+            }
+            let first_index = indices_count;
+            indices_count += draw_call.indices.len() as u32;
+            self.draw_calls.push(PreparedDrawCall {
+                bind_group,
+                pipeline_index: pipeline_index(draw_call.blend_mode, draw_call.premultiplied_alpha),
+                indices: first_index..indices_count,
+                base_vertex: vertices_count,
+            });
+            vertices_count += draw_call.vertices.len() as i32;
+        }
+        if self.draw_calls.is_empty() {
+            self.vertex_buffer = None;
+            self.index_buffer = None;
+            return;
+        }
+        self.vertex_buffer = Some(device.create_buffer_init(&BufferInitDescriptor {
+            label: Some("spine_vertex_buffer"),
+            contents: &vertices,
+            usage: BufferUsages::VERTEX,
+        }));
+        self.index_buffer = Some(device.create_buffer_init(&BufferInitDescriptor {
+            label: Some("spine_index_buffer"),
+            contents: &indices,
+            usage: BufferUsages::INDEX,
+        }));
+    }
+
+    /// Draw the meshes uploaded by the last call to [`prepare`](`Self::prepare`).
+    pub fn render<'a>(&'a self, render_pass: &mut RenderPass<'a>) {
+        let (Some(vertex_buffer), Some(index_buffer)) = (&self.vertex_buffer, &self.index_buffer)
+        else {
+            return;
+        };
+        render_pass.set_bind_group(0, &self.uniform_bind_group, &[]);
+        render_pass.set_vertex_buffer(0, vertex_buffer.slice(..));
+        render_pass.set_index_buffer(index_buffer.slice(..), IndexFormat::Uint16);
+        for draw_call in &self.draw_calls {
+            render_pass.set_pipeline(&self.pipelines.pipelines[draw_call.pipeline_index]);
+            render_pass.set_bind_group(1, &draw_call.bind_group, &[]);
+            render_pass.draw_indexed(draw_call.indices.clone(), draw_call.base_vertex, 0..1);
+        }
+    }
+}
+
+fn matrix_bytes(matrix: [[f32; 4]; 4]) -> Vec<u8> {
+    matrix
+        .iter()
+        .flatten()
+        .flat_map(|value| value.to_ne_bytes())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{draw::BlendFactor, BlendMode};
+
+    use super::{pipeline_index, vertex_layout, VERTEX_FLOATS};
+
+    #[test]
+    fn pipelines_and_layout() {
+        let mut indices = vec![];
+        for blend_mode in [
+            BlendMode::Normal,
+            BlendMode::Additive,
+            BlendMode::Multiply,
+            BlendMode::Screen,
+        ] {
+            for premultiplied_alpha in [false, true] {
+                indices.push(pipeline_index(blend_mode, premultiplied_alpha));
+            }
+        }
+        assert_eq!(indices, (0..8).collect::<Vec<_>>());
+
+        let blend_state: ::wgpu::BlendState = BlendMode::Additive.blend_factors(false).into();
+        assert_eq!(
+            blend_state.color.src_factor,
+            ::wgpu::BlendFactor::from(BlendFactor::SourceAlpha)
+        );
+        assert_eq!(blend_state.color.dst_factor, ::wgpu::BlendFactor::One);
+
+        let layout = vertex_layout();
+        let last = layout.attributes.last().unwrap();
+        assert_eq!(last.offset + last.format.size(), layout.array_stride);
+        assert_eq!(layout.array_stride as usize, VERTEX_FLOATS * 4);
+    }
+}
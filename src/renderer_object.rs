@@ -1,3 +1,4 @@
+use crate::prelude::*;
 use crate::{
     atlas::AtlasRegion,
     c::{c_void, spAtlasRegion},
@@ -38,7 +39,7 @@ impl<'a> RendererObject<'a> {
         assert!(
             ptr != 1 as *mut c_void,
             "Renderer object cannot be set to an empty type, please add member variables: {}",
-            std::any::type_name::<T>()
+            core::any::type_name::<T>()
         );
         *self.renderer_object = ptr;
     }
@@ -95,12 +96,12 @@ impl<'a> RendererObject<'a> {
     pub unsafe fn dispose<T>(&mut self) {
         if !self.renderer_object.is_null() {
             drop(Box::from_raw((*self.renderer_object).cast::<T>()));
-            *self.renderer_object = std::ptr::null_mut();
+            *self.renderer_object = core::ptr::null_mut();
         }
     }
 
     /// Set renderer object to null, potentially leaking the memory previously pointed to.
     pub fn forget(&mut self) {
-        *self.renderer_object = std::ptr::null_mut();
+        *self.renderer_object = core::ptr::null_mut();
     }
 }
@@ -1,26 +1,31 @@
-use std::{borrow::Cow, sync::Arc};
+use alloc::{borrow::Cow, collections::BTreeMap, sync::Arc};
 
+use crate::prelude::*;
 use crate::{
+    attachment::check_vertex_bones,
     bone::Bone,
     c::{
         spBone, spIkConstraint, spPathConstraint, spPhysics, spPhysicsConstraint, spSkeleton,
         spSkeletonData, spSkeleton_create, spSkeleton_dispose,
         spSkeleton_getAttachmentForSlotIndex, spSkeleton_getAttachmentForSlotName,
-        spSkeleton_setAttachment, spSkeleton_setBonesToSetupPose, spSkeleton_setSkin,
-        spSkeleton_setSkinByName, spSkeleton_setSlotsToSetupPose, spSkeleton_setToSetupPose,
-        spSkeleton_update, spSkeleton_updateCache, spSkeleton_updateWorldTransform,
-        spSkeleton_updateWorldTransformWith, spSkin, spSlot, spTransformConstraint,
+        spSkeleton_physicsRotate, spSkeleton_physicsTranslate, spSkeleton_setBonesToSetupPose,
+        spSkeleton_setSkin, spSkeleton_setSkinByName, spSkeleton_setSlotsToSetupPose,
+        spSkeleton_setToSetupPose, spSkeleton_update, spSkeleton_updateCache,
+        spSkeleton_updateWorldTransform, spSkeleton_updateWorldTransformWith, spSkin, spSlot,
+        spSlot_setAttachment, spTransformConstraint, spVertexAttachment,
     },
     c_interface::{to_c_str, CTmpMut, CTmpRef, NewFromPtr, SyncPtr},
+    diagnostics::{self, LiveObjectKind},
     error::SpineError,
     skeleton_data::SkeletonData,
     skin::Skin,
     slot::Slot,
-    Attachment, IkConstraint, PathConstraint, Physics, PhysicsConstraint, TransformConstraint,
+    Attachment, AttachmentType, IkConstraint, PathConstraint, Physics, PhysicsConstraint,
+    TransformConstraint,
 };
 
 #[allow(unused_imports)]
-use crate::{SkeletonBinary, SkeletonJson};
+use crate::{RegionAttachment, SkeletonBinary, SkeletonJson};
 
 #[cfg(feature = "mint")]
 use mint::Vector2;
@@ -32,8 +37,9 @@ use mint::Vector2;
 pub struct Skeleton {
     c_skeleton: SyncPtr<spSkeleton>,
     owns_memory: bool,
-    _skeleton_data: Arc<SkeletonData>,
+    skeleton_data: Arc<SkeletonData>,
     _skin: Option<Skin>, // keep-alive for user created skins
+    _attachments: BTreeMap<usize, Attachment>, // keep-alive for user assigned attachments
 }
 
 impl Skeleton {
@@ -43,11 +49,13 @@ impl Skeleton {
     #[must_use]
     pub fn new(skeleton_data: Arc<SkeletonData>) -> Self {
         let c_skeleton = unsafe { spSkeleton_create(skeleton_data.c_ptr()) };
+        diagnostics::track(LiveObjectKind::Skeleton, c_skeleton);
         Self {
             c_skeleton: SyncPtr(c_skeleton),
             owns_memory: true,
-            _skeleton_data: skeleton_data,
+            skeleton_data,
             _skin: None, // keep alive user-created skins
+            _attachments: BTreeMap::new(),
         }
     }
 
@@ -90,6 +98,36 @@ impl Skeleton {
         spSkeleton_updateWorldTransformWith(self.c_ptr(), parent.c_ptr(), physics as spPhysics);
     }
 
+    /// Translates every physics constraint in the skeleton, so on the next update with
+    /// [`Physics::Update`] their bones react as if the whole skeleton had moved by `x` and `y` in
+    /// world space. Use this when teleporting or moving a character independently of its root
+    /// bone, such as by moving the entity it's attached to.
+    ///
+    /// ```
+    /// # #[path="./test.rs"]
+    /// # mod test;
+    /// # use rusty_spine::Physics;
+    /// # let (mut skeleton, _) = test::TestAsset::spineboy().instance(true);
+    /// // the character was moved 10 units to the right
+    /// skeleton.physics_translate(10., 0.);
+    /// skeleton.update(0.016);
+    /// skeleton.update_world_transform(Physics::Update);
+    /// ```
+    pub fn physics_translate(&mut self, x: f32, y: f32) {
+        unsafe {
+            spSkeleton_physicsTranslate(self.c_ptr(), x, y);
+        }
+    }
+
+    /// Rotates every physics constraint in the skeleton around the world space point `x`, `y`, so
+    /// on the next update with [`Physics::Update`] their bones react as if the whole skeleton had
+    /// rotated by `degrees`. See [`physics_translate`](`Self::physics_translate`).
+    pub fn physics_rotate(&mut self, x: f32, y: f32, degrees: f32) {
+        unsafe {
+            spSkeleton_physicsRotate(self.c_ptr(), x, y, degrees);
+        }
+    }
+
     /// Sets the bones, constraints, slots, and draw order to their setup pose values.
     pub fn set_to_setup_pose(&mut self) {
         unsafe {
@@ -111,6 +149,252 @@ impl Skeleton {
         }
     }
 
+    /// The axis-aligned bounding box of every region and mesh attachment drawn by the skeleton,
+    /// in world space, or [`None`] if no attachments are drawn. Useful for culling and framing
+    /// the skeleton with a camera.
+    ///
+    /// ```
+    /// # #[path="./test.rs"]
+    /// # mod test;
+    /// # use rusty_spine::Physics;
+    /// # let (mut skeleton, _) = test::TestAsset::spineboy().instance(true);
+    /// skeleton.update_world_transform(Physics::None);
+    /// if let Some(bounds) = skeleton.bounds() {
+    ///     let [center_x, center_y] = bounds.center();
+    ///     println!("{}x{} at {center_x}, {center_y}", bounds.width(), bounds.height());
+    /// }
+    /// ```
+    ///
+    /// The bounds are computed from the current world transforms, so call
+    /// [`update_world_transform`](`Self::update_world_transform`) first. Slots of inactive bones
+    /// are skipped, and clipping is not taken into account. For skeletons updated every frame,
+    /// [`SkeletonController::bounds`](`crate::controller::SkeletonController::bounds`) caches the
+    /// result until the skeleton changes.
+    #[must_use]
+    pub fn bounds(&self) -> Option<Bounds> {
+        let mut world_vertices = vec![];
+        self.draw_order()
+            .filter_map(|slot| slot_bounds(&slot, &mut world_vertices))
+            .reduce(|bounds, slot_bounds| bounds.union(&slot_bounds))
+    }
+
+    /// The axis-aligned bounding box of the region or mesh attachment currently shown by the slot
+    /// named `slot_name`, in world space. Useful for cropping a portrait camera onto part of a
+    /// character, such as the face, in the current pose.
+    ///
+    /// ```
+    /// # #[path="./test.rs"]
+    /// # mod test;
+    /// # use rusty_spine::Physics;
+    /// # let (mut skeleton, _) = test::TestAsset::spineboy().instance(true);
+    /// skeleton.update_world_transform(Physics::None);
+    /// if let Some(face) = skeleton.slot_bounds("head") {
+    ///     let [center_x, center_y] = face.center();
+    ///     println!("zoom to {}x{} at {center_x}, {center_y}", face.width(), face.height());
+    /// }
+    /// ```
+    ///
+    /// Returns [`None`] if the slot doesn't exist, its bone is inactive, or it doesn't show a
+    /// region or mesh attachment. Since the current attachment is used, the bounds follow skin
+    /// changes and attachments keyed by animations. See [`bounds`](`Self::bounds`).
+    #[must_use]
+    pub fn slot_bounds(&self, slot_name: &str) -> Option<Bounds> {
+        let slot = self.find_slot(slot_name)?;
+        slot_bounds(&slot, &mut vec![])
+    }
+
+    /// Fills `out` with the world transform of every bone as a column major 4x4 matrix, indexed
+    /// by bone index, such as for skinning meshes on the GPU. Entries after the last bone are left
+    /// unchanged, so `out` can be a fixed size uniform array.
+    ///
+    /// ```
+    /// # #[path="./test.rs"]
+    /// # mod test;
+    /// # let (skeleton, _) = test::TestAsset::spineboy().instance(true);
+    /// let mut bone_matrices = vec![[0.; 16]; skeleton.bones_count()];
+    /// // every frame, after updating world transforms
+    /// skeleton.bone_world_matrices(&mut bone_matrices);
+    /// ```
+    ///
+    /// See [`bone_world_affines`](`Self::bone_world_affines`) for a smaller layout.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out` is shorter than [`bones_count`](`Self::bones_count`).
+    pub fn bone_world_matrices(&self, out: &mut [[f32; 16]]) {
+        self.fill_bone_world_transforms(out, |bone| {
+            [
+                bone.a(),
+                bone.c(),
+                0.,
+                0.,
+                bone.b(),
+                bone.d(),
+                0.,
+                0.,
+                0.,
+                0.,
+                1.,
+                0.,
+                bone.world_x(),
+                bone.world_y(),
+                0.,
+                1.,
+            ]
+        });
+    }
+
+    /// Fills `out` with the world transform of every bone as a column major 2x3 affine matrix,
+    /// `[a, c, b, d, world_x, world_y]`, indexed by bone index. This takes less than half the
+    /// uniform space of [`bone_world_matrices`](`Self::bone_world_matrices`), and a vertex is
+    /// transformed as `x * m[0..2] + y * m[2..4] + m[4..6]`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out` is shorter than [`bones_count`](`Self::bones_count`).
+    pub fn bone_world_affines(&self, out: &mut [[f32; 6]]) {
+        self.fill_bone_world_transforms(out, |bone| {
+            [
+                bone.a(),
+                bone.c(),
+                bone.b(),
+                bone.d(),
+                bone.world_x(),
+                bone.world_y(),
+            ]
+        });
+    }
+
+    fn fill_bone_world_transforms<T>(&self, out: &mut [T], transform: impl Fn(&Bone) -> T) {
+        assert!(
+            out.len() >= self.bones_count(),
+            "buffer for {} bones is too small for {} bones",
+            out.len(),
+            self.bones_count()
+        );
+        for (out, bone) in out.iter_mut().zip(self.bones()) {
+            *out = transform(&bone);
+        }
+    }
+
+    /// Captures the local transform of every bone and the attachment of every slot, to be
+    /// restored later with [`restore_pose`](`Self::restore_pose`). Useful for hit-pause or rewind
+    /// features.
+    ///
+    /// ```
+    /// # #[path="./test.rs"]
+    /// # mod test;
+    /// # use rusty_spine::Physics;
+    /// # let (mut skeleton, mut animation_state) = test::TestAsset::spineboy().instance(true);
+    /// let pose = skeleton.snapshot_pose();
+    /// animation_state.set_animation_by_name(0, "run", true)?;
+    /// animation_state.update(0.25);
+    /// animation_state.apply(&mut skeleton);
+    ///
+    /// // rewind
+    /// skeleton.restore_pose(&pose)?;
+    /// skeleton.update_world_transform(Physics::None);
+    /// # Ok::<(), rusty_spine::SpineError>(())
+    /// ```
+    ///
+    /// Other state, such as slot colors, the draw order, constraints, and the skeleton's
+    /// position, is not captured.
+    #[must_use]
+    pub fn snapshot_pose(&self) -> SkeletonPose {
+        SkeletonPose {
+            skeleton_data: self.skeleton_data.clone(),
+            bones: self
+                .bones()
+                .map(|bone| BonePose {
+                    x: bone.x(),
+                    y: bone.y(),
+                    rotation: bone.rotation(),
+                    scale_x: bone.scale_x(),
+                    scale_y: bone.scale_y(),
+                    shear_x: bone.shear_x(),
+                    shear_y: bone.shear_y(),
+                })
+                .collect(),
+            attachments: self
+                .slots()
+                .map(|slot| slot.attachment().map(|attachment| (*attachment).clone()))
+                .collect(),
+        }
+    }
+
+    /// Restores a pose captured by [`snapshot_pose`](`Self::snapshot_pose`). Call
+    /// [`update_world_transform`](`Self::update_world_transform`) afterwards to apply it.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SpineError::Unsupported`] if the pose was captured from a skeleton created from
+    /// different [`SkeletonData`].
+    pub fn restore_pose(&mut self, pose: &SkeletonPose) -> Result<(), SpineError> {
+        if !Arc::ptr_eq(&self.skeleton_data, &pose.skeleton_data) {
+            return Err(SpineError::new_unsupported(
+                "restoring a pose from a skeleton with different skeleton data",
+            ));
+        }
+        for (mut bone, bone_pose) in self.bones_mut().zip(&pose.bones) {
+            bone.set_x(bone_pose.x);
+            bone.set_y(bone_pose.y);
+            bone.set_rotation(bone_pose.rotation);
+            bone.set_scale_x(bone_pose.scale_x);
+            bone.set_scale_y(bone_pose.scale_y);
+            bone.set_shear_x(bone_pose.shear_x);
+            bone.set_shear_y(bone_pose.shear_y);
+        }
+        for (slot, attachment) in self.slots_mut().zip(&pose.attachments) {
+            unsafe {
+                spSlot_setAttachment(
+                    slot.c_ptr(),
+                    attachment
+                        .as_ref()
+                        .map_or(core::ptr::null_mut(), Attachment::c_ptr),
+                );
+            }
+        }
+        // Keep the attachments alive while the slots use them, as for `swap_attachment`
+        for (slot_index, attachment) in pose.attachments.iter().enumerate() {
+            match attachment {
+                Some(attachment) => {
+                    self._attachments.insert(slot_index, attachment.clone());
+                }
+                None => {
+                    self._attachments.remove(&slot_index);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Recomputes the UVs (and, for region attachments, the vertex offsets) of every region and
+    /// mesh attachment in the skeleton's skins from their texture regions. Call this after
+    /// rebinding or modifying attachment regions, for instance after an atlas is hot reloaded or
+    /// repacked, so the changes are reflected when drawing.
+    ///
+    /// Attachments are shared by all skeletons created from the same [`SkeletonData`], so this
+    /// only needs to be called on one of them.
+    pub fn update_attachment_uvs(&mut self) {
+        let mut skins: Vec<Skin> = self
+            .data()
+            .skins()
+            .map(|skin| unsafe { Skin::new_from_ptr(skin.c_ptr()) })
+            .collect();
+        if let Some(skin) = self.skin() {
+            skins.push(unsafe { Skin::new_from_ptr(skin.c_ptr()) });
+        }
+        for skin in &skins {
+            for entry in skin.attachments() {
+                if let Some(mut region_attachment) = entry.attachment.as_region() {
+                    region_attachment.update_region();
+                } else if let Some(mut mesh_attachment) = entry.attachment.as_mesh() {
+                    mesh_attachment.update_region();
+                }
+            }
+        }
+    }
+
     /// Set the skeleton's skin. If the skin is a user-created one (via [`Skin::new`]), then a
     /// clone is created and used instead, to help ensure memory safety. If this behavior is not
     /// desired then [`Skeleton::set_skin_unchecked`] can be used instead.
@@ -236,7 +520,41 @@ impl Skeleton {
 
     #[must_use]
     pub fn find_bone_mut(&mut self, name: &str) -> Option<CTmpMut<Skeleton, Bone>> {
-        self.bones_mut().find(|bone| bone.data().name() == name)
+        self.bone_mut(name)
+    }
+
+    /// The bone named `name`, for modifying its pose. The skeleton is borrowed mutably for as
+    /// long as the bone is, so the compiler guarantees nothing else reads or modifies the
+    /// skeleton while the bone is being changed:
+    ///
+    /// ```compile_fail
+    /// # #[path="./test.rs"]
+    /// # mod test;
+    /// # let (mut skeleton, _) = test::TestAsset::spineboy().instance(true);
+    /// let mut head = skeleton.bone_mut("head").unwrap();
+    /// let neck = skeleton.bone_mut("neck").unwrap(); // error: skeleton is already borrowed
+    /// head.set_rotation(0.);
+    /// ```
+    ///
+    /// Modify one bone at a time instead, and update the world transform afterwards:
+    ///
+    /// ```
+    /// # #[path="./test.rs"]
+    /// # mod test;
+    /// # use rusty_spine::Physics;
+    /// # let (mut skeleton, _) = test::TestAsset::spineboy().instance(true);
+    /// if let Some(mut head) = skeleton.bone_mut("head") {
+    ///     head.set_rotation(15.);
+    /// }
+    /// if let Some(mut neck) = skeleton.bone_mut("neck") {
+    ///     neck.set_rotation(-5.);
+    /// }
+    /// skeleton.update_world_transform(Physics::Update);
+    /// ```
+    #[must_use]
+    pub fn bone_mut(&mut self, name: &str) -> Option<CTmpMut<'_, Skeleton, Bone>> {
+        let c_bone = self.find_bone(name)?.c_ptr();
+        Some(CTmpMut::new(self, unsafe { Bone::new_from_ptr(c_bone) }))
     }
 
     #[must_use]
@@ -246,7 +564,25 @@ impl Skeleton {
 
     #[must_use]
     pub fn find_slot_mut(&mut self, name: &str) -> Option<CTmpMut<Skeleton, Slot>> {
-        self.slots_mut().find(|slot| slot.data().name() == name)
+        self.slot_mut(name)
+    }
+
+    /// The slot named `name`, for modifying its color or attachment. Like
+    /// [`bone_mut`](`Self::bone_mut`), the skeleton is borrowed mutably for as long as the slot
+    /// is.
+    ///
+    /// ```
+    /// # #[path="./test.rs"]
+    /// # mod test;
+    /// # let (mut skeleton, _) = test::TestAsset::spineboy().instance(true);
+    /// if let Some(mut gun) = skeleton.slot_mut("gun") {
+    ///     gun.color_mut().set_a(0.5);
+    /// }
+    /// ```
+    #[must_use]
+    pub fn slot_mut(&mut self, name: &str) -> Option<CTmpMut<'_, Skeleton, Slot>> {
+        let c_slot = self.find_slot(name)?.c_ptr();
+        Some(CTmpMut::new(self, unsafe { Slot::new_from_ptr(c_slot) }))
     }
 
     #[must_use]
@@ -315,23 +651,117 @@ impl Skeleton {
             .find(|transform_constraint| transform_constraint.data().name() == name)
     }
 
-    pub fn set_attachment(&mut self, slot_name: &str, attachment_name: Option<&str>) -> bool {
-        let c_slot_name = to_c_str(slot_name);
-        attachment_name.map_or_else(
-            || unsafe {
-                spSkeleton_setAttachment(self.c_ptr(), c_slot_name.as_ptr(), std::ptr::null()) != 0
-            },
-            |attachment_name| {
-                let c_attachment_name = to_c_str(attachment_name);
-                unsafe {
-                    spSkeleton_setAttachment(
-                        self.c_ptr(),
-                        c_slot_name.as_ptr(),
-                        c_attachment_name.as_ptr(),
-                    ) != 0
-                }
-            },
-        )
+    /// Set the attachment of the slot named `slot_name` to the attachment named
+    /// `attachment_name` from the skeleton's skin or default skin, or clear it with [`None`].
+    /// See [`Slot::set_attachment_by_name`].
+    ///
+    /// ```
+    /// # #[path="./test.rs"]
+    /// # mod test;
+    /// # let (mut skeleton, _) = test::TestAsset::spineboy().instance(true);
+    /// skeleton.set_attachment("eye", Some("eye-surprised"))?;
+    /// skeleton.set_attachment("mouth", None)?;
+    /// # Ok::<(), rusty_spine::SpineError>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SpineError::NotFound`] if the slot or the attachment does not exist.
+    pub fn set_attachment(
+        &mut self,
+        slot_name: &str,
+        attachment_name: Option<&str>,
+    ) -> Result<(), SpineError> {
+        let Some(mut slot) = self.find_slot_mut(slot_name) else {
+            return Err(SpineError::new_not_found("Slot", slot_name));
+        };
+        slot.set_attachment_by_name(attachment_name)?;
+        let slot_index = slot.data().index();
+        self._attachments.remove(&slot_index);
+        Ok(())
+    }
+
+    /// Set the attachment of the slot named `slot_name` to a region attachment, such as one
+    /// created by [`RegionAttachment::new_from_atlas_region`]. See
+    /// [`swap_attachment`](`Self::swap_attachment`).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SpineError::NotFound`] if the slot does not exist, or
+    /// [`SpineError::IncompatibleAttachment`] if the attachment is not a region attachment.
+    pub fn set_region_attachment(
+        &mut self,
+        slot_name: &str,
+        attachment: &Attachment,
+    ) -> Result<(), SpineError> {
+        if attachment.as_region().is_none() {
+            return Err(SpineError::new_incompatible_attachment(
+                attachment.name(),
+                "not a region attachment",
+            ));
+        }
+        self.swap_attachment(slot_name, Some(attachment))
+    }
+
+    /// Set the attachment of the slot named `slot_name`, or clear it with [`None`]. Unlike
+    /// [`Slot::set_attachment`], this checks that the attachment can be used by this skeleton
+    /// and keeps the slot's deform buffer consistent with it, so attachments from other skins,
+    /// other skeletons, or created at runtime can be swapped in safely. The skeleton keeps the
+    /// attachment alive until another attachment is assigned to the slot this way.
+    ///
+    /// ```
+    /// # #[path="./test.rs"]
+    /// # mod test;
+    /// # let (mut skeleton, _) = test::TestAsset::spineboy().instance(true);
+    /// let eye = skeleton
+    ///     .get_attachment_for_slot_name("eye", "eye-surprised")
+    ///     .unwrap();
+    /// skeleton.swap_attachment("eye", Some(&eye))?;
+    /// # Ok::<(), rusty_spine::SpineError>(())
+    /// ```
+    ///
+    /// The slot's deform buffer is kept if it belongs to the new attachment, such as when
+    /// assigning the same attachment again or switching between linked meshes, and is otherwise
+    /// cleared, to be filled again the next time a deform timeline is applied to the slot. The
+    /// attachment is replaced as usual by animations keying the slot's attachment, or by
+    /// [`Skeleton::set_slots_to_setup_pose`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SpineError::NotFound`] if the slot does not exist, or
+    /// [`SpineError::IncompatibleAttachment`] if the attachment is weighted to bones this
+    /// skeleton doesn't have.
+    pub fn swap_attachment(
+        &mut self,
+        slot_name: &str,
+        attachment: Option<&Attachment>,
+    ) -> Result<(), SpineError> {
+        let bones_count = self.bones_count();
+        let Some(slot) = self.find_slot_mut(slot_name) else {
+            return Err(SpineError::new_not_found("Slot", slot_name));
+        };
+        let vertex_attachment = attachment.and_then(vertex_attachment);
+        if let (Some(attachment), Some(vertex_attachment)) = (attachment, vertex_attachment) {
+            check_vertex_bones(unsafe { &*vertex_attachment }, bones_count).map_err(|reason| {
+                SpineError::new_incompatible_attachment(attachment.name(), reason)
+            })?;
+        }
+        unsafe {
+            spSlot_setAttachment(
+                slot.c_ptr(),
+                attachment.map_or(core::ptr::null_mut(), |attachment| attachment.c_ptr()),
+            );
+        }
+        let slot_index = slot.data().index();
+        match attachment {
+            Some(attachment) => {
+                self._attachments.insert(slot_index, attachment.clone());
+            }
+            None => {
+                self._attachments.remove(&slot_index);
+            }
+        }
+        Ok(())
     }
 
     pub fn get_attachment_for_slot_name(
@@ -360,6 +790,11 @@ impl Skeleton {
         slot_index: usize,
         attachment_name: &str,
     ) -> Option<Attachment> {
+        ffi_check!(
+            slot_index < self.slots_count(),
+            "slot index {slot_index} out of range for a skeleton with {} slots",
+            self.slots_count()
+        );
         let c_attachment_name = to_c_str(attachment_name);
         unsafe {
             let c_attachment = spSkeleton_getAttachmentForSlotIndex(
@@ -377,15 +812,28 @@ impl Skeleton {
 
     // TODO: iterators for ik, transform, path constraints
 
-    c_accessor_tmp_ptr_mut!(
+    c_accessor_tmp_ptr!(
         /// The skeleton's setup pose data.
         data,
-        /// The skeleton's mutable setup pose data.
-        data_mut,
         data,
         SkeletonData,
         spSkeletonData
     );
+
+    /// The skeleton's mutable setup pose data. Changes affect every skeleton sharing this data.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SpineError::Frozen`] if the data has been frozen with
+    /// [`SkeletonData::freeze`].
+    pub fn data_mut(&mut self) -> Result<CTmpMut<'_, Self, SkeletonData>, SpineError> {
+        if self.skeleton_data.is_frozen() {
+            return Err(SpineError::new_frozen("SkeletonData"));
+        }
+        Ok(CTmpMut::new(self, unsafe {
+            SkeletonData::new_from_ptr(self.c_ptr_ref().data)
+        }))
+    }
     c_accessor_color_mut!(
         /// The color to tint all the skeleton's attachments.
         color,
@@ -407,25 +855,25 @@ impl Skeleton {
     );
     c_accessor!(
         /// The number of IK constraints in this skeleton.
-        ik_contraints_count,
+        ik_constraints_count,
         ikConstraintsCount,
         usize
     );
     c_accessor!(
         /// The number of path constraints in this skeleton.
-        path_contraints_count,
+        path_constraints_count,
         pathConstraintsCount,
         usize
     );
     c_accessor!(
         /// The number of physics constraints in this skeleton.
-        physics_contraints_count,
+        physics_constraints_count,
         physicsConstraintsCount,
         usize
     );
     c_accessor!(
         /// The number of transform constraints in this skeleton.
-        transform_contraints_count,
+        transform_constraints_count,
         transformConstraintsCount,
         usize
     );
@@ -513,48 +961,105 @@ impl Skeleton {
     c_accessor_array_mut!(
         ik_constraints,
         ik_constraints_mut,
-        ik_contraint_at_index,
+        ik_constraint_at_index,
         ik_constraint_at_index_mut,
         Skeleton,
         IkConstraint,
         spIkConstraint,
         ikConstraints,
-        ik_contraints_count
+        ik_constraints_count
     );
     c_accessor_array_mut!(
         path_constraints,
         path_constraints_mut,
-        path_contraint_at_index,
+        path_constraint_at_index,
         path_constraint_at_index_mut,
         Skeleton,
         PathConstraint,
         spPathConstraint,
         pathConstraints,
-        path_contraints_count
+        path_constraints_count
     );
     c_accessor_array_mut!(
         physics_constraints,
         physics_constraints_mut,
-        physics_contraint_at_index,
+        physics_constraint_at_index,
         physics_constraint_at_index_mut,
         Skeleton,
         PhysicsConstraint,
         spPhysicsConstraint,
         physicsConstraints,
-        physics_contraints_count
+        physics_constraints_count
     );
     c_accessor_array_mut!(
         transform_constraints,
         transform_constraints_mut,
-        transform_contraint_at_index,
+        transform_constraint_at_index,
         transform_constraint_at_index_mut,
         Skeleton,
         TransformConstraint,
         spTransformConstraint,
         transformConstraints,
-        transform_contraints_count
+        transform_constraints_count
     );
     c_accessor_tmp_ptr_optional_mut!(skin, skin_mut, skin, Skin, spSkin);
+    #[deprecated(note = "renamed to `ik_constraints_count`")]
+    #[must_use]
+    pub fn ik_contraints_count(&self) -> usize {
+        self.ik_constraints_count()
+    }
+
+    #[deprecated(note = "renamed to `ik_constraint_at_index`")]
+    #[must_use]
+    pub fn ik_contraint_at_index(&self, index: usize) -> Option<CTmpRef<'_, Self, IkConstraint>> {
+        self.ik_constraint_at_index(index)
+    }
+
+    #[deprecated(note = "renamed to `transform_constraints_count`")]
+    #[must_use]
+    pub fn transform_contraints_count(&self) -> usize {
+        self.transform_constraints_count()
+    }
+
+    #[deprecated(note = "renamed to `transform_constraint_at_index`")]
+    #[must_use]
+    pub fn transform_contraint_at_index(
+        &self,
+        index: usize,
+    ) -> Option<CTmpRef<'_, Self, TransformConstraint>> {
+        self.transform_constraint_at_index(index)
+    }
+
+    #[deprecated(note = "renamed to `path_constraints_count`")]
+    #[must_use]
+    pub fn path_contraints_count(&self) -> usize {
+        self.path_constraints_count()
+    }
+
+    #[deprecated(note = "renamed to `path_constraint_at_index`")]
+    #[must_use]
+    pub fn path_contraint_at_index(
+        &self,
+        index: usize,
+    ) -> Option<CTmpRef<'_, Self, PathConstraint>> {
+        self.path_constraint_at_index(index)
+    }
+
+    #[deprecated(note = "renamed to `physics_constraints_count`")]
+    #[must_use]
+    pub fn physics_contraints_count(&self) -> usize {
+        self.physics_constraints_count()
+    }
+
+    #[deprecated(note = "renamed to `physics_constraint_at_index`")]
+    #[must_use]
+    pub fn physics_contraint_at_index(
+        &self,
+        index: usize,
+    ) -> Option<CTmpRef<'_, Self, PhysicsConstraint>> {
+        self.physics_constraint_at_index(index)
+    }
+
     c_ptr!(c_skeleton, spSkeleton);
 }
 
@@ -590,12 +1095,477 @@ impl Skeleton {
     }
 }
 
+/// Functions available if using the `glam` feature.
+#[cfg(feature = "glam")]
+impl Skeleton {
+    /// Fills `out` with the world transform of every bone, indexed by bone index. See
+    /// [`bone_world_matrices`](`Self::bone_world_matrices`).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out` is shorter than [`bones_count`](`Self::bones_count`).
+    pub fn bone_world_mat4s(&self, out: &mut [glam::Mat4]) {
+        self.fill_bone_world_transforms(out, Bone::world_mat4);
+    }
+
+    /// Fills `out` with the world transform of every bone, indexed by bone index. See
+    /// [`bone_world_affines`](`Self::bone_world_affines`).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out` is shorter than [`bones_count`](`Self::bones_count`).
+    pub fn bone_world_affine2s(&self, out: &mut [glam::Affine2]) {
+        self.fill_bone_world_transforms(out, Bone::world_affine2);
+    }
+}
+
 impl Drop for Skeleton {
     fn drop(&mut self) {
         if self.owns_memory {
+            diagnostics::untrack(LiveObjectKind::Skeleton, self.c_skeleton.0);
             unsafe {
                 spSkeleton_dispose(self.c_skeleton.0);
             }
         }
     }
 }
+
+/// The bounds of the region or mesh attachment shown by `slot`, using `world_vertices` to
+/// compute its world vertices.
+fn slot_bounds(slot: &Slot, world_vertices: &mut Vec<f32>) -> Option<Bounds> {
+    let bone = slot.bone();
+    if !bone.active() {
+        return None;
+    }
+    let attachment = slot.attachment()?;
+    if let Some(region_attachment) = attachment.as_region() {
+        world_vertices.resize(8, 0.);
+        region_attachment.compute_world_vertices(&bone, world_vertices);
+    } else if let Some(mesh_attachment) = attachment.as_mesh() {
+        let length = mesh_attachment.world_vertices_length();
+        world_vertices.resize(length.max(0) as usize, 0.);
+        mesh_attachment
+            .compute_world_vertices(slot, 0, length, world_vertices, 0, 2)
+            .ok()?;
+    } else {
+        return None;
+    }
+    world_vertices
+        .chunks_exact(2)
+        .map(|vertex| [vertex[0], vertex[1]])
+        .fold(None, |bounds: Option<Bounds>, point| {
+            Some(bounds.map_or(
+                Bounds {
+                    min: point,
+                    max: point,
+                },
+                |bounds| bounds.union_point(point),
+            ))
+        })
+}
+
+/// An axis-aligned bounding box in world space, see [`Skeleton::bounds`] and [`Skeleton::slot_bounds`].
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Bounds {
+    /// The bottom left corner, with the smallest x and y.
+    pub min: [f32; 2],
+    /// The top right corner, with the largest x and y.
+    pub max: [f32; 2],
+}
+
+impl Bounds {
+    #[must_use]
+    pub fn width(&self) -> f32 {
+        self.max[0] - self.min[0]
+    }
+
+    #[must_use]
+    pub fn height(&self) -> f32 {
+        self.max[1] - self.min[1]
+    }
+
+    #[must_use]
+    pub fn center(&self) -> [f32; 2] {
+        [
+            (self.min[0] + self.max[0]) / 2.,
+            (self.min[1] + self.max[1]) / 2.,
+        ]
+    }
+
+    /// Returns `true` if the point is inside the bounds or on their edge.
+    #[must_use]
+    pub fn contains(&self, point: [f32; 2]) -> bool {
+        point[0] >= self.min[0]
+            && point[0] <= self.max[0]
+            && point[1] >= self.min[1]
+            && point[1] <= self.max[1]
+    }
+
+    /// The smallest bounds containing both these bounds and `other`.
+    #[must_use]
+    pub const fn union(&self, other: &Self) -> Self {
+        self.union_point(other.min).union_point(other.max)
+    }
+
+    const fn union_point(&self, point: [f32; 2]) -> Self {
+        Self {
+            min: [self.min[0].min(point[0]), self.min[1].min(point[1])],
+            max: [self.max[0].max(point[0]), self.max[1].max(point[1])],
+        }
+    }
+}
+
+/// The local bone transforms and slot attachments of a [`Skeleton`], see
+/// [`Skeleton::snapshot_pose`].
+#[derive(Debug, Clone)]
+pub struct SkeletonPose {
+    skeleton_data: Arc<SkeletonData>,
+    bones: Vec<BonePose>,
+    attachments: Vec<Option<Attachment>>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct BonePose {
+    x: f32,
+    y: f32,
+    rotation: f32,
+    scale_x: f32,
+    scale_y: f32,
+    shear_x: f32,
+    shear_y: f32,
+}
+
+/// The vertex attachment data of `attachment`, or [`None`] if it has no vertices.
+fn vertex_attachment(attachment: &Attachment) -> Option<*const spVertexAttachment> {
+    match attachment.attachment_type() {
+        AttachmentType::BoundingBox
+        | AttachmentType::Mesh
+        | AttachmentType::LinkedMesh
+        | AttachmentType::Path
+        | AttachmentType::Clipping => Some(attachment.c_ptr().cast::<spVertexAttachment>()),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{test::TestAsset, Physics, SpineError};
+
+    #[test]
+    fn bone_world_matrices() {
+        let (mut skeleton, _) = TestAsset::spineboy().instance(true);
+        skeleton.set_scale_x(-1.5);
+        skeleton.update_world_transform(Physics::None);
+        let mut matrices = vec![[0.; 16]; skeleton.bones_count() + 1];
+        let mut affines = vec![[0.; 6]; skeleton.bones_count()];
+        skeleton.bone_world_matrices(&mut matrices);
+        skeleton.bone_world_affines(&mut affines);
+        assert_eq!(matrices[skeleton.bones_count()], [0.; 16]);
+        for bone in skeleton.bones() {
+            let index = bone.data().index();
+            let (matrix, affine) = (matrices[index], affines[index]);
+            let (world_x, world_y) = bone.local_to_world(10., 5.);
+            let x = matrix[0] * 10. + matrix[4] * 5. + matrix[12];
+            let y = matrix[1] * 10. + matrix[5] * 5. + matrix[13];
+            assert!((x - world_x).abs() < 0.01 && (y - world_y).abs() < 0.01);
+            let x = affine[0] * 10. + affine[2] * 5. + affine[4];
+            let y = affine[1] * 10. + affine[3] * 5. + affine[5];
+            assert!((x - world_x).abs() < 0.01 && (y - world_y).abs() < 0.01);
+        }
+
+        #[cfg(feature = "glam")]
+        {
+            let mut mat4s = vec![glam::Mat4::ZERO; skeleton.bones_count()];
+            skeleton.bone_world_mat4s(&mut mat4s);
+            for (mat4, matrix) in mat4s.iter().zip(&matrices) {
+                assert_eq!(mat4.to_cols_array(), *matrix);
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn bone_world_matrices_too_small() {
+        let (skeleton, _) = TestAsset::spineboy().instance(true);
+        skeleton.bone_world_matrices(&mut [[0.; 16]; 4]);
+    }
+
+    #[test]
+    fn bone_and_slot_mut() {
+        let (mut skeleton, _) = TestAsset::spineboy().instance(true);
+        let world_x = skeleton.find_bone("head").unwrap().world_x();
+        skeleton.bone_mut("neck").unwrap().set_x(100.);
+        skeleton.update_world_transform(Physics::None);
+        assert_ne!(skeleton.find_bone("head").unwrap().world_x(), world_x);
+
+        skeleton.slot_mut("gun").unwrap().color_mut().set_a(0.5);
+        assert_eq!(skeleton.find_slot("gun").unwrap().color().a, 0.5);
+
+        assert!(skeleton.bone_mut("missing").is_none());
+        assert!(skeleton.slot_mut("missing").is_none());
+    }
+
+    #[test]
+    fn physics_translate() {
+        let celestial_circus = &TestAsset::all()[8];
+        let (mut moved, _) = celestial_circus.instance(true);
+        let (mut still, _) = celestial_circus.instance(true);
+        let constraint = moved.find_physics_constraint("bench-front").unwrap();
+        assert!((constraint.data().inertia() - 0.4071).abs() < 0.0001);
+        assert!((constraint.inertia() - 0.4071).abs() < 0.0001);
+        moved
+            .find_physics_constraint_mut("bench-front")
+            .unwrap()
+            .set_inertia(0.5);
+        assert_eq!(
+            moved
+                .find_physics_constraint("bench-front")
+                .unwrap()
+                .inertia(),
+            0.5
+        );
+
+        let mut update = |translate: f32| {
+            moved.physics_translate(translate, 0.);
+            for skeleton in [&mut moved, &mut still] {
+                skeleton.update(0.016);
+                skeleton.update_world_transform(Physics::Update);
+            }
+        };
+        update(0.);
+        update(100.);
+        update(0.);
+        let world_x =
+            |skeleton: &crate::Skeleton| skeleton.find_bone("cloud-perspective").unwrap().world_x();
+        assert_ne!(world_x(&moved), world_x(&still));
+    }
+
+    #[test]
+    fn bounds() {
+        let (mut skeleton, _) = TestAsset::spineboy().instance(true);
+        skeleton.update_world_transform(Physics::None);
+        let bounds = skeleton.bounds().unwrap();
+        let data = skeleton.data();
+        assert!((bounds.min[0] - data.x()).abs() < 1.);
+        assert!((bounds.min[1] - data.y()).abs() < 1.);
+        assert!((bounds.width() - data.width()).abs() < 1.);
+        assert!((bounds.height() - data.height()).abs() < 1.);
+
+        skeleton.set_x(100.);
+        skeleton.update_world_transform(Physics::None);
+        let moved = skeleton.bounds().unwrap();
+        assert!((moved.min[0] - bounds.min[0] - 100.).abs() < 0.01);
+        assert!(moved.union(&bounds).contains(bounds.min));
+
+        let head = skeleton.slot_bounds("head").unwrap();
+        assert!(head.width() > 0. && head.width() < moved.width());
+        assert!(moved.union(&head) == moved);
+        let head_bone = skeleton.find_bone("head").unwrap();
+        assert!(head.center()[1] > head_bone.world_y());
+        assert!(skeleton.slot_bounds("missing").is_none());
+
+        for mut slot in skeleton.slots_mut() {
+            unsafe { slot.set_attachment(None) };
+        }
+        assert!(skeleton.bounds().is_none());
+    }
+
+    #[test]
+    fn slot_bounds() {
+        let (mut skeleton, _) = TestAsset::spineboy().instance(true);
+        skeleton.update_world_transform(Physics::None);
+
+        // region and mesh attachments are both framed, and together make up the skeleton bounds
+        let gun = skeleton.slot_bounds("gun").unwrap();
+        let head = skeleton.slot_bounds("head").unwrap();
+        assert!(gun.width() > 0. && gun.height() > 0.);
+        assert!(head.width() > 0. && head.height() > 0.);
+        let slot_names: Vec<String> = skeleton
+            .slots()
+            .map(|slot| slot.data().name().to_owned())
+            .collect();
+        let union = slot_names
+            .iter()
+            .filter_map(|slot_name| skeleton.slot_bounds(slot_name))
+            .reduce(|bounds, slot_bounds| bounds.union(&slot_bounds))
+            .unwrap();
+        assert!(union == skeleton.bounds().unwrap());
+
+        // slots without an attachment have no bounds
+        assert!(skeleton.slot_bounds("muzzle").is_none());
+        skeleton
+            .find_slot_mut("gun")
+            .unwrap()
+            .set_attachment_by_name(None)
+            .unwrap();
+        assert!(skeleton.slot_bounds("gun").is_none());
+    }
+
+    #[test]
+    fn snapshot_and_restore_pose() {
+        let (mut skeleton, mut animation_state) = TestAsset::spineboy().instance(true);
+        let pose = skeleton.snapshot_pose();
+        let head_rotation = skeleton.find_bone("head").unwrap().rotation();
+        let eye = |skeleton: &crate::Skeleton| {
+            skeleton
+                .find_slot("eye")
+                .unwrap()
+                .attachment()
+                .map(|attachment| attachment.name().to_owned())
+        };
+        let eye_attachment = eye(&skeleton);
+
+        animation_state
+            .set_animation_by_name(0, "death", false)
+            .unwrap();
+        animation_state.update(0.25);
+        animation_state.apply(&mut skeleton);
+        assert_ne!(
+            skeleton.find_bone("head").unwrap().rotation(),
+            head_rotation
+        );
+        assert_ne!(eye(&skeleton), eye_attachment);
+
+        skeleton.restore_pose(&pose).unwrap();
+        assert_eq!(
+            skeleton.find_bone("head").unwrap().rotation(),
+            head_rotation
+        );
+        assert_eq!(eye(&skeleton), eye_attachment);
+
+        let (mut coin, _) = TestAsset::all()[2].instance(true);
+        assert!(matches!(
+            coin.restore_pose(&pose),
+            Err(SpineError::Unsupported { .. })
+        ));
+    }
+
+    #[test]
+    #[cfg(all(feature = "strict-ffi", debug_assertions))]
+    #[should_panic(expected = "strict-ffi: slot index 1000 out of range")]
+    fn strict_ffi_slot_index() {
+        let (mut skeleton, _) = TestAsset::spineboy().instance(true);
+        let _ = skeleton.get_attachment_for_slot_index(1000, "head");
+    }
+
+    #[test]
+    fn update_attachment_uvs() {
+        let (mut skeleton, _) = TestAsset::spineboy().instance(true);
+        let mesh_attachment = skeleton
+            .data()
+            .default_skin()
+            .attachments()
+            .into_iter()
+            .find_map(|entry| entry.attachment.as_mesh())
+            .unwrap();
+        let uvs = mesh_attachment.uvs_slice().to_vec();
+        skeleton.update_attachment_uvs();
+        assert_eq!(mesh_attachment.uvs_slice(), uvs.as_slice());
+        unsafe {
+            let region = mesh_attachment.c_ptr_ref().region;
+            (*region).u += 0.01;
+            (*region).u2 += 0.01;
+        }
+        skeleton.update_attachment_uvs();
+        assert_ne!(mesh_attachment.uvs_slice(), uvs.as_slice());
+    }
+
+    #[test]
+    fn swap_attachment() {
+        let (mut skeleton, mut animation_state) = TestAsset::spineboy().instance(true);
+        // The hoverboard animation deforms the weighted front foot mesh
+        animation_state
+            .set_animation_by_name(0, "hoverboard", true)
+            .unwrap();
+        animation_state.update(0.1);
+        animation_state.apply(&mut skeleton);
+        let deform_count = skeleton.find_slot("front-foot").unwrap().deform_count();
+        assert!(deform_count > 0);
+        let front_foot = skeleton
+            .get_attachment_for_slot_name("front-foot", "front-foot")
+            .unwrap();
+        skeleton
+            .swap_attachment("front-foot", Some(&front_foot))
+            .unwrap();
+        assert_eq!(
+            skeleton.find_slot("front-foot").unwrap().deform_count(),
+            deform_count
+        );
+        let head = skeleton
+            .get_attachment_for_slot_name("head", "head")
+            .unwrap();
+        skeleton.swap_attachment("front-foot", Some(&head)).unwrap();
+        assert_eq!(skeleton.find_slot("front-foot").unwrap().deform_count(), 0);
+
+        // Weighted meshes can't be used by skeletons with fewer bones
+        let (mut coin, _) = TestAsset::all()[2].instance(true);
+        let weighted_mesh = skeleton
+            .data()
+            .default_skin()
+            .attachments()
+            .into_iter()
+            .find(|entry| {
+                entry.attachment.as_mesh().is_some_and(|mesh| {
+                    mesh.bones()
+                        .iter()
+                        .any(|bone| *bone as usize >= coin.bones_count())
+                })
+            })
+            .unwrap()
+            .attachment;
+        let slot_name = coin.slot_at_index(0).unwrap().data().name().to_owned();
+        assert!(matches!(
+            coin.swap_attachment(&slot_name, Some(&weighted_mesh)),
+            Err(SpineError::IncompatibleAttachment { .. })
+        ));
+        assert!(matches!(
+            coin.swap_attachment("missing", None),
+            Err(SpineError::NotFound { .. })
+        ));
+        coin.swap_attachment(&slot_name, None).unwrap();
+        assert!(coin.slot_at_index(0).unwrap().attachment().is_none());
+    }
+
+    #[test]
+    fn set_attachment() {
+        let (mut skeleton, _) = TestAsset::spineboy().instance(true);
+        skeleton
+            .set_attachment("eye", Some("eye-surprised"))
+            .unwrap();
+        let eye = skeleton.find_slot("eye").unwrap();
+        assert_eq!(eye.attachment().unwrap().name(), "eye-surprised");
+        assert!(matches!(
+            skeleton.set_attachment("missing", None),
+            Err(SpineError::NotFound { .. })
+        ));
+        assert!(matches!(
+            skeleton.set_attachment("eye", Some("missing")),
+            Err(SpineError::NotFound { .. })
+        ));
+        let eye = skeleton.find_slot("eye").unwrap();
+        assert_eq!(eye.attachment().unwrap().name(), "eye-surprised");
+
+        // The hoverboard animation deforms the weighted front foot mesh
+        let (mut skeleton, mut animation_state) = TestAsset::spineboy().instance(true);
+        animation_state
+            .set_animation_by_name(0, "hoverboard", true)
+            .unwrap();
+        animation_state.update(0.1);
+        animation_state.apply(&mut skeleton);
+        let deform_count = skeleton.find_slot("front-foot").unwrap().deform_count();
+        assert!(deform_count > 0);
+        skeleton
+            .find_slot_mut("front-foot")
+            .unwrap()
+            .set_attachment_by_name(Some("front-foot"))
+            .unwrap();
+        assert_eq!(
+            skeleton.find_slot("front-foot").unwrap().deform_count(),
+            deform_count
+        );
+        skeleton.set_attachment("front-foot", None).unwrap();
+        let front_foot = skeleton.find_slot("front-foot").unwrap();
+        assert!(front_foot.attachment().is_none());
+        assert_eq!(front_foot.deform_count(), 0);
+    }
+}
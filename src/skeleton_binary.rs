@@ -1,13 +1,16 @@
-use std::{
-    ffi::{CStr, CString},
-    path::Path,
-    sync::Arc,
-};
+#[cfg(feature = "std")]
+use alloc::ffi::CString;
+use alloc::sync::Arc;
+use core::ffi::CStr;
+#[cfg(feature = "std")]
+use std::{io::Read, path::Path};
 
+#[cfg(feature = "std")]
+use crate::c::spSkeletonBinary_readSkeletonDataFile;
 use crate::{
     c::{
         c_uchar, spSkeletonBinary, spSkeletonBinary_create, spSkeletonBinary_dispose,
-        spSkeletonBinary_readSkeletonData, spSkeletonBinary_readSkeletonDataFile,
+        spSkeletonBinary_readSkeletonData,
     },
     c_interface::{from_c_str, SyncPtr},
     error::SpineError,
@@ -58,7 +61,8 @@ impl SkeletonBinary {
     ///
     /// # Errors
     ///
-    /// Returns [`SpineError::ParsingFailed`] if parsing of the binary data failed.
+    /// Returns [`SpineError::ParsingFailed`] if parsing of the binary data failed, including
+    /// data exported by an unsupported version of Spine.
     pub fn read_skeleton_data(&self, data: &[u8]) -> Result<SkeletonData, SpineError> {
         let c_skeleton_data = unsafe {
             spSkeletonBinary_readSkeletonData(
@@ -71,10 +75,40 @@ impl SkeletonBinary {
             Ok(SkeletonData::new(c_skeleton_data, self.atlas.clone()))
         } else {
             let c_error = unsafe { CStr::from_ptr((*self.c_skeleton_binary.0).error) };
-            Err(SpineError::new_from_spine(from_c_str(c_error)))
+            Err(SpineError::new_skeleton_parsing_failed(
+                from_c_str(c_error),
+                None,
+            ))
         }
     }
 
+    /// Read the Spine skeleton binary data from a reader, such as an archive entry, an asset pack,
+    /// or a network stream. The reader is read to the end before parsing.
+    ///
+    /// ```
+    /// # #[path="./test.rs"]
+    /// # mod test;
+    /// # let skeleton_binary = test::TestAsset::spineboy().skeleton_binary();
+    /// let file = std::fs::File::open("assets/spineboy/export/spineboy-pro.skel")?;
+    /// let skeleton_data =
+    ///     skeleton_binary.read_skeleton_data_from_reader(std::io::BufReader::new(file))?;
+    /// # Ok::<(), rusty_spine::SpineError>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SpineError::Io`] if reading from `reader` failed. Returns
+    /// [`SpineError::ParsingFailed`] if parsing of the binary data failed.
+    #[cfg(feature = "std")]
+    pub fn read_skeleton_data_from_reader<R: Read>(
+        &self,
+        mut reader: R,
+    ) -> Result<SkeletonData, SpineError> {
+        let mut data = vec![];
+        reader.read_to_end(&mut data)?;
+        self.read_skeleton_data(&data)
+    }
+
     /// Read the Spine skeleton binary data from a file. See [`SkeletonBinary::new`] for a full
     /// example.
     ///
@@ -83,6 +117,7 @@ impl SkeletonBinary {
     /// Returns [`SpineError::ParsingFailed`] if parsing of the binary data failed. Returns
     /// [`SpineError::NulError`] if `path` contains an internal 0 byte. Returns
     /// [`SpineError::PathNotUtf8`] if the specified path is not utf-8.
+    #[cfg(feature = "std")]
     pub fn read_skeleton_data_file<P: AsRef<Path>>(
         &self,
         path: P,
@@ -98,7 +133,10 @@ impl SkeletonBinary {
             Ok(SkeletonData::new(c_skeleton_data, self.atlas.clone()))
         } else {
             let c_error = unsafe { CStr::from_ptr((*self.c_skeleton_binary.0).error) };
-            Err(SpineError::new_from_spine(from_c_str(c_error)))
+            Err(SpineError::new_skeleton_parsing_failed(
+                from_c_str(c_error),
+                Some(path_str),
+            ))
         }
     }
 
@@ -126,3 +164,36 @@ impl Drop for SkeletonBinary {
         }
     }
 }
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::{test::TestAsset, SpineError};
+
+    #[test]
+    fn read_skeleton_data_from_reader() {
+        struct FailingReader;
+
+        impl std::io::Read for FailingReader {
+            fn read(&mut self, _buf: &mut [u8]) -> std::io::Result<usize> {
+                Err(std::io::Error::other("disconnected"))
+            }
+        }
+
+        let asset = TestAsset::spineboy();
+        let skeleton_binary = asset.skeleton_binary();
+        let skeleton_data = skeleton_binary
+            .read_skeleton_data_from_reader(asset.binary_data)
+            .unwrap();
+        let expected = asset.skeleton_data(false);
+        assert_eq!(skeleton_data.bones_count(), expected.bones_count());
+        assert_eq!(
+            skeleton_data.animations_count(),
+            expected.animations_count()
+        );
+
+        assert!(matches!(
+            skeleton_binary.read_skeleton_data_from_reader(FailingReader),
+            Err(SpineError::Io(_))
+        ));
+    }
+}
@@ -1,8 +1,10 @@
+use crate::prelude::*;
 use crate::{
     c::{
         spSkeletonClipping, spSkeletonClipping_clipEnd, spSkeletonClipping_clipEnd2,
         spSkeletonClipping_clipStart, spSkeletonClipping_clipTriangles, spSkeletonClipping_create,
         spSkeletonClipping_dispose, spSkeletonClipping_isClipping,
+        spVertexAttachment_computeWorldVertices,
     },
     c_interface::SyncPtr,
     clipping_attachment::ClippingAttachment,
@@ -12,13 +14,95 @@ use crate::{
 #[cfg(doc)]
 use crate::draw::SimpleDrawer;
 
+/// The vertices, texture coordinates, and triangle indices of a mesh after clipping, returned by
+/// [`SkeletonClipping::clip_mesh`].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ClippedMesh {
+    pub vertices: Vec<[f32; 2]>,
+    pub uvs: Vec<[f32; 2]>,
+    pub indices: Vec<u16>,
+}
+
+/// Counters for the triangles clipped by a [`SkeletonClipping`].
+///
+/// See [`SkeletonClipping::statistics`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct ClippingStatistics {
+    /// The total amount of triangles passed in to be clipped while clipping was active.
+    pub triangles_in: u64,
+    /// The total amount of triangles produced by clipping them.
+    pub triangles_out: u64,
+    /// The amount of times clipping was skipped because the clipping polygon was degenerate, see
+    /// [`SkeletonClipping::take_warnings`].
+    pub skipped_clips: u64,
+}
+
+/// A clipping attachment which was skipped because its polygon can't be clipped against, see
+/// [`SkeletonClipping::take_warnings`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClippingWarning {
+    /// The name of the slot showing the clipping attachment.
+    pub slot: String,
+    /// The name of the clipping attachment.
+    pub attachment: String,
+    /// What is wrong with the clipping polygon.
+    pub problem: ClippingPolygonProblem,
+}
+
+/// Why a clipping polygon was skipped, see [`ClippingWarning`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ClippingPolygonProblem {
+    /// The polygon has fewer than 3 vertices.
+    TooFewVertices,
+    /// A vertex is infinite or NaN, usually from a bone scaled to infinity or NaN.
+    NonFiniteVertex,
+    /// The polygon has no area, for instance because its bone is scaled to 0.
+    ZeroArea,
+    /// Edges of the polygon cross each other.
+    SelfIntersecting,
+}
+
 /// Active state for [`ClippingAttachment`] during mesh generation.
 ///
-/// For example usage, see the [`SimpleDrawer::draw`] implementation.
+/// Custom renderers walk the skeleton's draw order, starting clipping at each slot with a
+/// clipping attachment, clipping the mesh of every following slot, and ending clipping after each
+/// slot so the clipper can stop at the clipping attachment's end slot:
+///
+/// ```
+/// # #[path="./test.rs"]
+/// # mod test;
+/// # use rusty_spine::SkeletonClipping;
+/// # let (skeleton, _) = test::TestAsset::spineboy().instance(true);
+/// let mut clipper = SkeletonClipping::new();
+/// for slot in skeleton.draw_order() {
+///     if let Some(clipping_attachment) = slot.attachment().and_then(|a| a.as_clipping()) {
+///         clipper.clip_start(&slot, &clipping_attachment);
+///         continue;
+///     }
+///     // compute the attachment's world vertices, uvs and indices
+///     # let (vertices, uvs, indices) = (vec![], vec![], vec![]);
+///     let clipped = clipper.clip_mesh(&vertices, &uvs, &indices);
+///     // draw `clipped.vertices`, `clipped.uvs`, and `clipped.indices`
+///     clipper.clip_end(&slot);
+/// }
+/// clipper.clip_end2();
+/// ```
+///
+/// For a complete example, see the [`SimpleDrawer::draw`] implementation.
+///
+/// Clipping polygons which are degenerate or self-intersecting can't be triangulated correctly,
+/// and would produce corrupted meshes, so they are skipped instead, leaving the attachments they
+/// would clip unclipped. Each skipped polygon is reported once by
+/// [`take_warnings`](`Self::take_warnings`).
 #[derive(Debug)]
 pub struct SkeletonClipping {
     c_skeleton_clipping: SyncPtr<spSkeletonClipping>,
     owns_memory: bool,
+    statistics: ClippingStatistics,
+    warnings: Vec<ClippingWarning>,
+    /// Time spent clipping, see [`SkeletonClipping::take_clipping_time`].
+    #[cfg(feature = "profile")]
+    clipping_time: core::time::Duration,
 }
 
 impl Default for SkeletonClipping {
@@ -33,13 +117,56 @@ impl SkeletonClipping {
         Self {
             c_skeleton_clipping: unsafe { SyncPtr(spSkeletonClipping_create()) },
             owns_memory: true,
+            statistics: ClippingStatistics::default(),
+            warnings: vec![],
+            #[cfg(feature = "profile")]
+            clipping_time: core::time::Duration::ZERO,
         }
     }
 
+    /// Start clipping against the polygon of `clip`, shown by `slot`, until the clipping
+    /// attachment's end slot. Does nothing if clipping is already active, or if the polygon is
+    /// degenerate, see [`take_warnings`](`Self::take_warnings`).
     pub fn clip_start(&mut self, slot: &Slot, clip: &ClippingAttachment) {
+        if self.is_clipping() {
+            return;
+        }
+        #[cfg(feature = "profile")]
+        let start = crate::controller::Stopwatch::start();
+        let polygon = unsafe {
+            let vertex_attachment = &mut (*clip.c_ptr()).super_0;
+            let length = vertex_attachment.worldVerticesLength.max(0);
+            let mut polygon = vec![[0.; 2]; length as usize / 2];
+            spVertexAttachment_computeWorldVertices(
+                vertex_attachment,
+                slot.c_ptr(),
+                0,
+                polygon.len() as i32 * 2,
+                polygon.as_mut_ptr().cast::<f32>(),
+                0,
+                2,
+            );
+            polygon
+        };
+        if let Some(problem) = polygon_problem(&polygon) {
+            self.statistics.skipped_clips += 1;
+            let warning = ClippingWarning {
+                slot: slot.data().name().to_owned(),
+                attachment: clip.name().to_owned(),
+                problem,
+            };
+            if !self.warnings.contains(&warning) {
+                self.warnings.push(warning);
+            }
+            return;
+        }
         unsafe {
             spSkeletonClipping_clipStart(self.c_ptr_mut(), slot.c_ptr(), clip.c_ptr());
         }
+        #[cfg(feature = "profile")]
+        {
+            self.clipping_time += start.elapsed();
+        }
     }
 
     pub fn clip_end(&mut self, slot: &Slot) {
@@ -59,16 +186,134 @@ impl SkeletonClipping {
         unsafe { spSkeletonClipping_isClipping(self.c_ptr_mut()) != 0 }
     }
 
+    /// Counters for the triangles clipped by this clipper.
+    #[must_use]
+    pub const fn statistics(&self) -> ClippingStatistics {
+        self.statistics
+    }
+
+    /// Reset the [`statistics`](`Self::statistics`) counters.
+    pub fn reset_statistics(&mut self) {
+        self.statistics = ClippingStatistics::default();
+    }
+
+    /// Remove and return the clipping polygons skipped since the last call, each reported once
+    /// even if skipped many times.
+    ///
+    /// ```
+    /// # use rusty_spine::SkeletonClipping;
+    /// # let mut clipper = SkeletonClipping::new();
+    /// // ... draw the skeleton using the clipper ...
+    /// for warning in clipper.take_warnings() {
+    ///     println!(
+    ///         "Skipped clipping {} in slot {}: {:?}",
+    ///         warning.attachment, warning.slot, warning.problem
+    ///     );
+    /// }
+    /// ```
+    pub fn take_warnings(&mut self) -> Vec<ClippingWarning> {
+        core::mem::take(&mut self.warnings)
+    }
+
+    /// Clip a mesh against the active clipping attachment, or copy it unchanged if clipping is not
+    /// active. `vertices` are in world space, with one entry in `uvs` for each vertex, and every
+    /// three `indices` form a triangle.
+    ///
+    /// Clipped meshes may have more or fewer vertices than the original mesh, and meshes entirely
+    /// outside the clipping polygon have none.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `vertices` and `uvs` differ in length, if the number of `indices` is not a
+    /// multiple of 3, or if an index is out of range of `vertices`.
+    #[must_use]
+    pub fn clip_mesh(
+        &mut self,
+        vertices: &[[f32; 2]],
+        uvs: &[[f32; 2]],
+        indices: &[u16],
+    ) -> ClippedMesh {
+        assert_eq!(
+            vertices.len(),
+            uvs.len(),
+            "clip_mesh requires one uv for each vertex"
+        );
+        assert_eq!(
+            indices.len() % 3,
+            0,
+            "clip_mesh requires indices to form triangles"
+        );
+        assert!(
+            indices
+                .iter()
+                .all(|&index| (index as usize) < vertices.len()),
+            "clip_mesh index out of range of vertices"
+        );
+        if !self.is_clipping() {
+            return ClippedMesh {
+                vertices: vertices.to_vec(),
+                uvs: uvs.to_vec(),
+                indices: indices.to_vec(),
+            };
+        }
+        let mut vertices = vertices.to_vec();
+        let mut uvs = uvs.to_vec();
+        let mut indices = indices.to_vec();
+        unsafe {
+            self.clip_triangles(&mut vertices, &mut indices, &mut uvs, 2);
+            let clipping = self.c_ptr_ref();
+            let clipped_vertices = &*clipping.clippedVertices;
+            let clipped_uvs = &*clipping.clippedUVs;
+            let clipped_triangles = &*clipping.clippedTriangles;
+            ClippedMesh {
+                vertices: core::slice::from_raw_parts(
+                    clipped_vertices.items.cast::<[f32; 2]>(),
+                    clipped_vertices.size as usize / 2,
+                )
+                .to_vec(),
+                uvs: core::slice::from_raw_parts(
+                    clipped_uvs.items.cast::<[f32; 2]>(),
+                    clipped_uvs.size as usize / 2,
+                )
+                .to_vec(),
+                indices: core::slice::from_raw_parts(
+                    clipped_triangles.items,
+                    clipped_triangles.size as usize,
+                )
+                .to_vec(),
+            }
+        }
+    }
+
+    /// Clip triangles in place against the active clipping attachment, leaving the results in
+    /// the clipper's internal buffers. Prefer [`clip_mesh`](`Self::clip_mesh`), which checks its
+    /// inputs and returns the results.
+    ///
     /// # Safety
     ///
     /// The triangles data passed in must represent valid mesh data.
     pub unsafe fn clip_triangles(
-        &self,
+        &mut self,
         vertices: &mut [[f32; 2]],
         triangles: &mut [u16],
         uvs: &mut [[f32; 2]],
         stride: i32,
     ) {
+        ffi_check!(
+            stride >= 2,
+            "vertex stride {stride} is smaller than a vertex position"
+        );
+        #[cfg(feature = "profile")]
+        let start = crate::controller::Stopwatch::start();
+        ffi_check!(
+            triangles.iter().all(|index| {
+                let index = usize::from(*index);
+                index * stride as usize + 1 < vertices.len() * 2 && index < uvs.len()
+            }),
+            "triangle index out of range for {} vertices and {} uvs",
+            vertices.len(),
+            uvs.len()
+        );
         spSkeletonClipping_clipTriangles(
             self.c_ptr(),
             vertices.as_mut_ptr().cast::<f32>(),
@@ -78,6 +323,21 @@ impl SkeletonClipping {
             uvs.as_mut_ptr().cast::<f32>(),
             stride,
         );
+        if self.is_clipping() {
+            self.statistics.triangles_in += triangles.len() as u64 / 3;
+            self.statistics.triangles_out += (*self.c_ptr_ref().clippedTriangles).size as u64 / 3;
+        }
+        #[cfg(feature = "profile")]
+        {
+            self.clipping_time += start.elapsed();
+        }
+    }
+
+    /// Return and reset the time spent clipping, see
+    /// [`FrameStats::clipping`](`crate::controller::FrameStats::clipping`).
+    #[cfg(feature = "profile")]
+    pub(crate) fn take_clipping_time(&mut self) -> core::time::Duration {
+        core::mem::take(&mut self.clipping_time)
     }
 
     c_ptr!(c_skeleton_clipping, spSkeletonClipping);
@@ -93,6 +353,52 @@ impl SkeletonClipping {
     spArrayFloatArray *clippingPolygons;*/
 }
 
+/// What is wrong with a clipping polygon in world space, or [`None`] if it can be clipped
+/// against.
+fn polygon_problem(polygon: &[[f32; 2]]) -> Option<ClippingPolygonProblem> {
+    if polygon.len() < 3 {
+        return Some(ClippingPolygonProblem::TooFewVertices);
+    }
+    if polygon
+        .iter()
+        .any(|vertex| !vertex[0].is_finite() || !vertex[1].is_finite())
+    {
+        return Some(ClippingPolygonProblem::NonFiniteVertex);
+    }
+    let edges = || (0..polygon.len()).map(|i| (polygon[i], polygon[(i + 1) % polygon.len()]));
+    // Twice the signed area, compared to the size of the polygon to allow for rounding error.
+    let area: f32 = edges().map(|(a, b)| a[0] * b[1] - b[0] * a[1]).sum();
+    let size = polygon
+        .iter()
+        .flat_map(|vertex| {
+            [
+                (vertex[0] - polygon[0][0]).abs(),
+                (vertex[1] - polygon[0][1]).abs(),
+            ]
+        })
+        .fold(0., f32::max);
+    if area.abs() <= size * size * 1e-5 {
+        return Some(ClippingPolygonProblem::ZeroArea);
+    }
+    let cross = |o: [f32; 2], a: [f32; 2], b: [f32; 2]| {
+        (a[0] - o[0]) * (b[1] - o[1]) - (a[1] - o[1]) * (b[0] - o[0])
+    };
+    for (i, (a, b)) in edges().enumerate() {
+        // Adjacent edges share a vertex, so only later edges which aren't adjacent are checked.
+        for (c, d) in edges().skip(i + 2) {
+            if c == b || d == a {
+                continue;
+            }
+            let crosses_cd = cross(a, b, c) * cross(a, b, d) < 0.;
+            let crosses_ab = cross(c, d, a) * cross(c, d, b) < 0.;
+            if crosses_cd && crosses_ab {
+                return Some(ClippingPolygonProblem::SelfIntersecting);
+            }
+        }
+    }
+    None
+}
+
 impl Drop for SkeletonClipping {
     fn drop(&mut self) {
         if self.owns_memory {
@@ -102,3 +408,109 @@ impl Drop for SkeletonClipping {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{test::TestAsset, Physics};
+
+    use super::*;
+
+    #[test]
+    fn clip_mesh() {
+        let (mut skeleton, _) = TestAsset::spineboy().instance(true);
+        skeleton
+            .set_attachment("clipping", Some("clipping"))
+            .unwrap();
+        skeleton.update_world_transform(Physics::None);
+
+        let vertices = [[-10000., 300.], [-9000., 300.], [-9000., 400.]];
+        let uvs = [[0., 0.], [1., 0.], [1., 1.]];
+        let indices = [0, 1, 2];
+        let mut clipper = SkeletonClipping::new();
+        let passthrough = clipper.clip_mesh(&vertices, &uvs, &indices);
+        assert_eq!(passthrough.vertices, vertices);
+        assert_eq!(passthrough.uvs, uvs);
+        assert_eq!(passthrough.indices, indices);
+
+        let slot = skeleton.find_slot("clipping").unwrap();
+        let clipping_attachment = slot.attachment().unwrap().as_clipping().unwrap();
+        clipper.clip_start(&slot, &clipping_attachment);
+        assert!(clipper.is_clipping());
+        let outside = clipper.clip_mesh(&vertices, &uvs, &indices);
+        assert!(outside.vertices.is_empty());
+        assert!(outside.indices.is_empty());
+
+        let straddling = [[-100., 300.], [300., 300.], [300., 400.]];
+        let clipped = clipper.clip_mesh(&straddling, &uvs, &indices);
+        assert!(!clipped.indices.is_empty());
+        assert_eq!(clipped.indices.len() % 3, 0);
+        assert_eq!(clipped.vertices.len(), clipped.uvs.len());
+        for vertex in &clipped.vertices {
+            assert!(vertex[0] > 0.);
+        }
+        for index in &clipped.indices {
+            assert!((*index as usize) < clipped.vertices.len());
+        }
+        clipper.clip_end2();
+        assert!(!clipper.is_clipping());
+
+        let statistics = clipper.statistics();
+        assert_eq!(statistics.triangles_in, 2);
+        assert_eq!(statistics.triangles_out as usize, clipped.indices.len() / 3);
+        clipper.reset_statistics();
+        assert_eq!(clipper.statistics(), ClippingStatistics::default());
+    }
+
+    #[test]
+    fn skip_degenerate_polygons() {
+        let (mut skeleton, _) = TestAsset::spineboy().instance(true);
+        skeleton
+            .set_attachment("clipping", Some("clipping"))
+            .unwrap();
+        skeleton.bone_mut("root").unwrap().set_scale_x(0.);
+        skeleton.update_world_transform(Physics::None);
+
+        let mut clipper = SkeletonClipping::new();
+        for _ in 0..2 {
+            let slot = skeleton.find_slot("clipping").unwrap();
+            let clipping_attachment = slot.attachment().unwrap().as_clipping().unwrap();
+            clipper.clip_start(&slot, &clipping_attachment);
+            assert!(!clipper.is_clipping());
+        }
+        assert_eq!(clipper.statistics().skipped_clips, 2);
+        assert_eq!(
+            clipper.take_warnings(),
+            [ClippingWarning {
+                slot: "clipping".to_owned(),
+                attachment: "clipping".to_owned(),
+                problem: ClippingPolygonProblem::ZeroArea,
+            }]
+        );
+        assert!(clipper.take_warnings().is_empty());
+    }
+
+    #[test]
+    fn polygon_problems() {
+        let square = [[0., 0.], [1., 0.], [1., 1.], [0., 1.]];
+        assert_eq!(polygon_problem(&square), None);
+        assert_eq!(
+            polygon_problem(&square[..2]),
+            Some(ClippingPolygonProblem::TooFewVertices)
+        );
+        let bowtie = [[0., 0.], [2., 1.], [2., 0.], [0., 2.]];
+        assert_eq!(
+            polygon_problem(&bowtie),
+            Some(ClippingPolygonProblem::SelfIntersecting)
+        );
+        let line = [[0., 0.], [1., 1.], [2., 2.]];
+        assert_eq!(
+            polygon_problem(&line),
+            Some(ClippingPolygonProblem::ZeroArea)
+        );
+        let nan = [[0., 0.], [f32::NAN, 0.], [1., 1.]];
+        assert_eq!(
+            polygon_problem(&nan),
+            Some(ClippingPolygonProblem::NonFiniteVertex)
+        );
+    }
+}
@@ -1,16 +1,25 @@
-use std::sync::Arc;
+use alloc::{collections::BTreeSet, sync::Arc};
 
+#[cfg(not(feature = "std"))]
+use crate::math::Float;
+use crate::prelude::*;
 use crate::{
-    animation::Animation,
+    animation_mod::Animation,
     bone::BoneData,
     c::{
-        spAnimation, spBoneData, spIkConstraintData, spPathConstraintData, spPhysicsConstraintData,
-        spSkeletonData, spSkeletonData_dispose, spSkin, spSlotData, spTransformConstraintData,
+        spAnimation, spBoneData, spCurveTimeline, spEventData, spIkConstraintData,
+        spPathConstraintData, spPhysicsConstraintData, spSkeletonData, spSkeletonData_dispose,
+        spSkin, spSkinEntry, spSkin_getAttachments, spSlotData, spTransformConstraintData,
+        spTranslateTimeline, SP_TIMELINE_TRANSLATE, SP_TIMELINE_TRANSLATEX, SP_TIMELINE_TRANSLATEY,
     },
-    c_interface::{CTmpRef, NewFromPtr, SyncPtr},
+    c_interface::{from_c_str, CTmpRef, NewFromPtr, SyncPtr},
+    diagnostics::{self, LiveObjectKind},
+    error::SpineError,
     skin::Skin,
     slot::SlotData,
-    Atlas, IkConstraintData, PathConstraintData, PhysicsConstraintData, TransformConstraintData,
+    sync::{lock, Mutex},
+    Atlas, Attachment, EventData, IkConstraintData, PathConstraintData, PhysicsConstraintData,
+    TransformConstraintData,
 };
 
 #[cfg(feature = "mint")]
@@ -29,6 +38,44 @@ pub struct SkeletonData {
     _atlas: Option<Arc<Atlas>>,
 }
 
+/// The addresses of skeleton data frozen with [`SkeletonData::freeze`], so data reached through
+/// the skeletons created from it can check whether it may be mutated.
+static FROZEN_SKELETON_DATA: Mutex<BTreeSet<usize>> = Mutex::new(BTreeSet::new());
+
+/// Returns [`SpineError::Frozen`] if the skeleton data has been frozen.
+pub(crate) fn check_skeleton_data_frozen(
+    c_skeleton_data: *const spSkeletonData,
+) -> Result<(), SpineError> {
+    if lock(&FROZEN_SKELETON_DATA).contains(&c_skeleton_data.addr()) {
+        Err(SpineError::new_frozen("SkeletonData"))
+    } else {
+        Ok(())
+    }
+}
+
+/// Returns [`SpineError::Frozen`] if the event data belongs to skeleton data which has been
+/// frozen. Events don't refer to their skeleton, so every frozen skeleton data is searched.
+pub(crate) fn check_event_data_frozen(c_event_data: *const spEventData) -> Result<(), SpineError> {
+    let frozen = lock(&FROZEN_SKELETON_DATA);
+    for c_skeleton_data in frozen.iter().map(|&addr| addr as *const spSkeletonData) {
+        // Frozen skeleton data is removed from the set before it is disposed
+        let events = unsafe {
+            let c_skeleton_data = &*c_skeleton_data;
+            core::slice::from_raw_parts(
+                c_skeleton_data.events,
+                c_skeleton_data.eventsCount as usize,
+            )
+        };
+        if events
+            .iter()
+            .any(|&event| event.cast_const() == c_event_data)
+        {
+            return Err(SpineError::new_frozen("SkeletonData"));
+        }
+    }
+    Ok(())
+}
+
 impl NewFromPtr<spSkeletonData> for SkeletonData {
     unsafe fn new_from_ptr(c_skeleton_data: *mut spSkeletonData) -> Self {
         Self {
@@ -40,10 +87,8 @@ impl NewFromPtr<spSkeletonData> for SkeletonData {
 }
 
 impl SkeletonData {
-    pub(crate) const fn new(
-        c_skeleton_data: *mut spSkeletonData,
-        atlas: Option<Arc<Atlas>>,
-    ) -> Self {
+    pub(crate) fn new(c_skeleton_data: *mut spSkeletonData, atlas: Option<Arc<Atlas>>) -> Self {
+        diagnostics::track(LiveObjectKind::SkeletonData, c_skeleton_data);
         Self {
             c_skeleton_data: SyncPtr(c_skeleton_data),
             owns_memory: true,
@@ -51,6 +96,49 @@ impl SkeletonData {
         }
     }
 
+    /// Prevent any further changes to this skeleton data. Afterwards, APIs which could mutate it,
+    /// such as [`Skeleton::data_mut`](`crate::Skeleton::data_mut`),
+    /// [`AnimationStateData::skeleton_data_mut`](`crate::AnimationStateData::skeleton_data_mut`),
+    /// and the `data_mut` accessors of bones, slots, constraints, and events, return
+    /// [`SpineError::Frozen`](`crate::SpineError::Frozen`).
+    ///
+    /// ```
+    /// # #[path="./test.rs"]
+    /// # mod test;
+    /// # use std::sync::Arc;
+    /// # use rusty_spine::Skeleton;
+    /// # let skeleton_data = test::TestAsset::spineboy().skeleton_data(true);
+    /// skeleton_data.freeze();
+    /// let skeleton_data = Arc::new(skeleton_data);
+    /// let mut skeleton = Skeleton::new(skeleton_data.clone());
+    /// assert!(skeleton.data_mut().is_err());
+    /// ```
+    ///
+    /// Skeleton data is shared between every [`Skeleton`](`crate::Skeleton`) created from it,
+    /// which may live on other threads, so freezing it before sharing guarantees they all see
+    /// the same data. Freezing can't be undone.
+    ///
+    /// The runtime doesn't make internal copies of skeleton data to protect it from changes, so
+    /// freezing doesn't change the cost of creating or updating skeletons.
+    pub fn freeze(&self) {
+        lock(&FROZEN_SKELETON_DATA).insert(self.c_ptr().addr());
+    }
+
+    /// Returns `true` if [`freeze`](`Self::freeze`) has been called.
+    #[must_use]
+    pub fn is_frozen(&self) -> bool {
+        check_skeleton_data_frozen(self.c_ptr()).is_err()
+    }
+
+    /// Returns `true` if any slot uses two color tinting, in which case attachments should be
+    /// drawn with a shader which applies the dark color of
+    /// [`SlotData::dark_color`](`crate::SlotData::dark_color`). Dark colors can only be
+    /// animated for slots which have one in the setup pose.
+    #[must_use]
+    pub fn uses_two_color_tint(&self) -> bool {
+        self.slots().any(|slot| slot.dark_color().is_some())
+    }
+
     #[must_use]
     pub fn find_bone(&self, name: &str) -> Option<CTmpRef<SkeletonData, BoneData>> {
         self.bones().find(|bone| bone.name() == name)
@@ -71,6 +159,11 @@ impl SkeletonData {
         self.animations().find(|animation| animation.name() == name)
     }
 
+    #[must_use]
+    pub fn find_event(&self, name: &str) -> Option<CTmpRef<SkeletonData, EventData>> {
+        self.events().find(|event| event.name() == name)
+    }
+
     #[must_use]
     pub fn find_ik_constraint(
         &self,
@@ -107,6 +200,107 @@ impl SkeletonData {
             .find(|transform_constraint| transform_constraint.name() == name)
     }
 
+    /// An iterator over every attachment in every skin, including the default skin. An attachment
+    /// shared by several skins is returned once for each skin.
+    ///
+    /// ```
+    /// # #[path="./test.rs"]
+    /// # mod test;
+    /// # let (skeleton, _) = test::TestAsset::spineboy().instance(true);
+    /// let vertices: usize = skeleton
+    ///     .data()
+    ///     .attachments()
+    ///     .filter_map(|skin_attachment| skin_attachment.attachment.as_mesh())
+    ///     .map(|mesh| mesh.world_vertices_length() as usize / 2)
+    ///     .sum();
+    /// ```
+    #[must_use]
+    pub const fn attachments(&self) -> SkinAttachments<'_> {
+        SkinAttachments {
+            skeleton_data: self,
+            skin_index: 0,
+            entry: core::ptr::null_mut(),
+        }
+    }
+
+    /// Calls `f` with the skin, slot index, name, and attachment of every attachment in every
+    /// skin, see [`attachments`](`Self::attachments`).
+    ///
+    /// ```
+    /// # #[path="./test.rs"]
+    /// # mod test;
+    /// # let (skeleton, _) = test::TestAsset::spineboy().instance(true);
+    /// skeleton.data().for_each_attachment(|skin, slot_index, name, attachment| {
+    ///     if name.contains(' ') {
+    ///         println!("{}: attachment {name:?} in slot {slot_index} has a space", skin.name());
+    ///     }
+    /// });
+    /// ```
+    pub fn for_each_attachment<F: FnMut(&Skin, usize, &str, &Attachment)>(&self, mut f: F) {
+        for skin_attachment in self.attachments() {
+            f(
+                &skin_attachment.skin,
+                skin_attachment.slot_index,
+                skin_attachment.name,
+                &skin_attachment.attachment,
+            );
+        }
+    }
+
+    /// Totals over the attachments of every skin, for sizing GPU buffers and bone arrays before
+    /// any skeleton is drawn. An attachment shared by several skins is counted once.
+    ///
+    /// ```
+    /// # #[path="./test.rs"]
+    /// # mod test;
+    /// # let (skeleton, _) = test::TestAsset::spineboy().instance(true);
+    /// let statistics = skeleton.data().mesh_statistics();
+    /// let vertex_buffer_size = statistics.max_vertices * std::mem::size_of::<[f32; 4]>();
+    /// let bone_matrices_size = statistics.bones_count * std::mem::size_of::<[f32; 6]>();
+    /// ```
+    ///
+    /// Region attachments count as 4 vertices and 6 indices. Clipping can add vertices when
+    /// drawing, so buffers for clipped attachments may need to be larger than these totals.
+    #[must_use]
+    pub fn mesh_statistics(&self) -> MeshStatistics {
+        let mut statistics = MeshStatistics {
+            bones_count: self.bones_count(),
+            ..MeshStatistics::default()
+        };
+        let mut counted = BTreeSet::new();
+        for skin_attachment in self.attachments() {
+            let attachment = &skin_attachment.attachment;
+            if !counted.insert(attachment.c_ptr()) {
+                continue;
+            }
+            let (vertices, indices, bone_influences) =
+                if let Some(mesh_attachment) = attachment.as_mesh() {
+                    (
+                        mesh_attachment.world_vertices_length() as usize / 2,
+                        mesh_attachment.triangles_count() as usize,
+                        mesh_attachment
+                            .bone_influences()
+                            .map(|influences| influences.len())
+                            .max()
+                            .unwrap_or(1),
+                    )
+                } else if attachment.as_region().is_some() {
+                    (4, 6, 1)
+                } else {
+                    if attachment.as_clipping().is_some() {
+                        statistics.clipping_attachments += 1;
+                    }
+                    continue;
+                };
+            statistics.max_vertices = statistics.max_vertices.max(vertices);
+            statistics.max_indices = statistics.max_indices.max(indices);
+            statistics.total_vertices += vertices;
+            statistics.total_indices += indices;
+            statistics.max_bone_influences = statistics.max_bone_influences.max(bone_influences);
+        }
+        statistics
+    }
+
     c_accessor_string_optional!(
         /// The Spine version used to export the skeleton data, or [`None`].
         version,
@@ -160,25 +354,25 @@ impl SkeletonData {
     c_accessor!(animations_count, animationsCount, usize);
     c_accessor!(
         /// The number of IK constraints in this skeleton.
-        ik_contraints_count,
+        ik_constraints_count,
         ikConstraintsCount,
         usize
     );
     c_accessor!(
         /// The number of path constraints in this skeleton.
-        path_contraints_count,
+        path_constraints_count,
         pathConstraintsCount,
         usize
     );
     c_accessor!(
         /// The number of physics constraints in this skeleton.
-        physics_contraints_count,
+        physics_constraints_count,
         physicsConstraintsCount,
         usize
     );
     c_accessor!(
         /// The number of transform constraints in this skeleton.
-        transform_contraints_count,
+        transform_constraints_count,
         transformConstraintsCount,
         usize
     );
@@ -219,42 +413,243 @@ impl SkeletonData {
         animations,
         animations_count
     );
+    c_accessor_array!(
+        events,
+        event_at_index,
+        SkeletonData,
+        EventData,
+        spEventData,
+        events,
+        events_count
+    );
     c_accessor_array!(
         ik_constraints,
-        ik_contraint_at_index,
+        ik_constraint_at_index,
         SkeletonData,
         IkConstraintData,
         spIkConstraintData,
         ikConstraints,
-        ik_contraints_count
+        ik_constraints_count
     );
     c_accessor_array!(
         path_constraints,
-        path_contraint_at_index,
+        path_constraint_at_index,
         SkeletonData,
         PathConstraintData,
         spPathConstraintData,
         pathConstraints,
-        path_contraints_count
+        path_constraints_count
     );
     c_accessor_array!(
         physics_constraints,
-        physics_contraint_at_index,
+        physics_constraint_at_index,
         SkeletonData,
         PhysicsConstraintData,
         spPhysicsConstraintData,
         physicsConstraints,
-        physics_contraints_count
+        physics_constraints_count
     );
     c_accessor_array!(
         transform_constraints,
-        transform_contraint_at_index,
+        transform_constraint_at_index,
         SkeletonData,
         TransformConstraintData,
         spTransformConstraintData,
         transformConstraints,
-        transform_contraints_count
+        transform_constraints_count
     );
+
+    #[deprecated(note = "renamed to `ik_constraints_count`")]
+    #[must_use]
+    pub fn ik_contraints_count(&self) -> usize {
+        self.ik_constraints_count()
+    }
+
+    #[deprecated(note = "renamed to `ik_constraint_at_index`")]
+    #[must_use]
+    pub fn ik_contraint_at_index(
+        &self,
+        index: usize,
+    ) -> Option<CTmpRef<'_, Self, IkConstraintData>> {
+        self.ik_constraint_at_index(index)
+    }
+
+    #[deprecated(note = "renamed to `transform_constraints_count`")]
+    #[must_use]
+    pub fn transform_contraints_count(&self) -> usize {
+        self.transform_constraints_count()
+    }
+
+    #[deprecated(note = "renamed to `transform_constraint_at_index`")]
+    #[must_use]
+    pub fn transform_contraint_at_index(
+        &self,
+        index: usize,
+    ) -> Option<CTmpRef<'_, Self, TransformConstraintData>> {
+        self.transform_constraint_at_index(index)
+    }
+
+    #[deprecated(note = "renamed to `path_constraints_count`")]
+    #[must_use]
+    pub fn path_contraints_count(&self) -> usize {
+        self.path_constraints_count()
+    }
+
+    #[deprecated(note = "renamed to `path_constraint_at_index`")]
+    #[must_use]
+    pub fn path_contraint_at_index(
+        &self,
+        index: usize,
+    ) -> Option<CTmpRef<'_, Self, PathConstraintData>> {
+        self.path_constraint_at_index(index)
+    }
+
+    #[deprecated(note = "renamed to `physics_constraints_count`")]
+    #[must_use]
+    pub fn physics_contraints_count(&self) -> usize {
+        self.physics_constraints_count()
+    }
+
+    #[deprecated(note = "renamed to `physics_constraint_at_index`")]
+    #[must_use]
+    pub fn physics_contraint_at_index(
+        &self,
+        index: usize,
+    ) -> Option<CTmpRef<'_, Self, PhysicsConstraintData>> {
+        self.physics_constraint_at_index(index)
+    }
+
+    /// Bake a rotation in degrees, an offset, and a uniform scale into the setup pose and
+    /// animations, as if the skeleton were placed inside a parent with that transform. For
+    /// instance, assets authored facing right can be rotated by `90.` to face up in a top-down
+    /// game, once at load instead of correcting the transform every frame.
+    ///
+    /// ```
+    /// # #[path="./test.rs"]
+    /// # mod test;
+    /// use std::sync::Arc;
+    ///
+    /// use rusty_spine::{Physics, Skeleton};
+    ///
+    /// let mut skeleton_data = test::TestAsset::spineboy().skeleton_data(true);
+    /// skeleton_data.bake_transform(90., 0., 0., 0.5)?;
+    /// let mut skeleton = Skeleton::new(Arc::new(skeleton_data));
+    /// skeleton.update_world_transform(Physics::None);
+    /// # Ok::<(), rusty_spine::SpineError>(())
+    /// ```
+    ///
+    /// The setup pose and translation keys of the root bones are transformed, along with the
+    /// skeleton's bounding box, and child bones follow. Bones which don't inherit rotation or
+    /// scale from their parent, see [`BoneData::inherit`], are not rotated or scaled. Bezier
+    /// curves of root bone translation keys are exact for multiples of 90 degrees, and are
+    /// resampled for other rotations, which is exact at the curve's sample points and close
+    /// between them.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SpineError::Unsupported`] if a root bone is keyed with separate X and Y
+    /// translation timelines and the rotation is not a multiple of 180 degrees, since those
+    /// timelines can't move the bone along the other axis. The data is not changed in this case.
+    pub fn bake_transform(
+        &mut self,
+        rotation: f32,
+        offset_x: f32,
+        offset_y: f32,
+        scale: f32,
+    ) -> Result<(), SpineError> {
+        let (sin, cos) = if rotation.rem_euclid(90.) == 0. {
+            match (rotation.rem_euclid(360.) / 90.) as i32 {
+                0 => (0., 1.),
+                1 => (1., 0.),
+                2 => (0., -1.),
+                _ => (-1., 0.),
+            }
+        } else {
+            rotation.to_radians().sin_cos()
+        };
+        let matrix = [cos * scale, -sin * scale, sin * scale, cos * scale];
+        let transform =
+            |x: f32, y: f32| (matrix[0] * x + matrix[1] * y, matrix[2] * x + matrix[3] * y);
+
+        let root_bones: Vec<i32> = self
+            .bones()
+            .filter(|bone| bone.parent().is_none())
+            .map(|bone| bone.index() as i32)
+            .collect();
+        let mut translate_timelines = vec![];
+        for animation in self.animations() {
+            for timeline in animation.timelines() {
+                let c_timeline = timeline.c_ptr();
+                let (type_0, bone_index) = unsafe {
+                    (
+                        (*c_timeline).type_0,
+                        (*c_timeline.cast::<spTranslateTimeline>()).boneIndex,
+                    )
+                };
+                if matches!(
+                    type_0,
+                    SP_TIMELINE_TRANSLATE | SP_TIMELINE_TRANSLATEX | SP_TIMELINE_TRANSLATEY
+                ) && root_bones.contains(&bone_index)
+                {
+                    if type_0 != SP_TIMELINE_TRANSLATE && sin != 0. {
+                        return Err(SpineError::new_unsupported(&format!(
+                            "baking a rotation of {rotation} degrees into the separate X and Y \
+                             translation timelines of animation {}",
+                            animation.name()
+                        )));
+                    }
+                    translate_timelines.push(c_timeline);
+                }
+            }
+        }
+
+        let c_skeleton_data = unsafe { self.c_ptr_mut() };
+        for bone_index in root_bones {
+            let bone_data = unsafe { &mut **c_skeleton_data.bones.add(bone_index as usize) };
+            let (x, y) = transform(bone_data.x, bone_data.y);
+            bone_data.x = x + offset_x;
+            bone_data.y = y + offset_y;
+            bone_data.rotation += rotation;
+            bone_data.scaleX *= scale;
+            bone_data.scaleY *= scale;
+        }
+        for c_timeline in translate_timelines {
+            unsafe {
+                if (*c_timeline).type_0 == SP_TIMELINE_TRANSLATE {
+                    bake_translate_timeline(c_timeline.cast(), matrix);
+                } else {
+                    // Only reached when the rotation is a multiple of 180 degrees.
+                    bake_translate_axis_timeline(c_timeline.cast(), cos * scale);
+                }
+            }
+        }
+
+        let corners = [
+            (c_skeleton_data.x, c_skeleton_data.y),
+            (c_skeleton_data.x + c_skeleton_data.width, c_skeleton_data.y),
+            (
+                c_skeleton_data.x,
+                c_skeleton_data.y + c_skeleton_data.height,
+            ),
+            (
+                c_skeleton_data.x + c_skeleton_data.width,
+                c_skeleton_data.y + c_skeleton_data.height,
+            ),
+        ]
+        .map(|(x, y)| transform(x, y));
+        let (min_x, max_x, min_y, max_y) = corners.iter().fold(
+            (f32::MAX, f32::MIN, f32::MAX, f32::MIN),
+            |(min_x, max_x, min_y, max_y), (x, y)| {
+                (min_x.min(*x), max_x.max(*x), min_y.min(*y), max_y.max(*y))
+            },
+        );
+        c_skeleton_data.x = min_x + offset_x;
+        c_skeleton_data.y = min_y + offset_y;
+        c_skeleton_data.width = max_x - min_x;
+        c_skeleton_data.height = max_y - min_y;
+        Ok(())
+    }
+
     c_ptr!(c_skeleton_data, spSkeletonData);
 
     // TODO: accessors and methods for the arrays in spSkeletonData
@@ -282,12 +677,379 @@ impl SkeletonData {
     }
 }
 
+/// The number of floats for each bezier curve in a curve timeline, which are 9 time and value
+/// pairs sampled between two keyframes.
+const BEZIER_SIZE: usize = 18;
+
+/// Transform the `[time, x, y]` keys of a translate timeline, and its bezier curves, by `matrix`.
+unsafe fn bake_translate_timeline(c_timeline: *mut spCurveTimeline, matrix: [f32; 4]) {
+    let timeline = &mut *c_timeline;
+    let frames = core::slice::from_raw_parts_mut(
+        (*timeline.super_0.frames).items,
+        (*timeline.super_0.frames).size as usize,
+    );
+    let curves =
+        core::slice::from_raw_parts_mut((*timeline.curves).items, (*timeline.curves).size as usize);
+    let original_frames = frames.to_vec();
+    let original_curves = curves.to_vec();
+    let frame_count = timeline.super_0.frameCount as usize;
+    for frame in 0..frame_count {
+        let i = frame * 3;
+        let (x, y) = (original_frames[i + 1], original_frames[i + 2]);
+        frames[i + 1] = matrix[0] * x + matrix[1] * y;
+        frames[i + 2] = matrix[2] * x + matrix[3] * y;
+
+        let curve_type = original_curves[frame] as usize;
+        if curve_type < 2 || frame + 1 == frame_count {
+            continue;
+        }
+        // The value of an axis at `time`, as the runtime interpolates the bezier samples.
+        let value_at = |axis: usize, time: f32| {
+            let samples = &original_curves[curve_type - 2 + axis * BEZIER_SIZE..][..BEZIER_SIZE];
+            let points = core::iter::once((original_frames[i], original_frames[i + 1 + axis]))
+                .chain(samples.chunks(2).map(|pair| (pair[0], pair[1])))
+                .chain(core::iter::once((
+                    original_frames[i + 3],
+                    original_frames[i + 4 + axis],
+                )))
+                .collect::<Vec<_>>();
+            points.windows(2).find(|pair| time <= pair[1].0).map_or(
+                points[points.len() - 1].1,
+                |pair| {
+                    let ((time1, value1), (time2, value2)) = (pair[0], pair[1]);
+                    if time2 > time1 {
+                        value1 + (time - time1) / (time2 - time1) * (value2 - value1)
+                    } else {
+                        value2
+                    }
+                },
+            )
+        };
+        for axis in 0..2 {
+            let start = curve_type - 2 + axis * BEZIER_SIZE;
+            let (x_factor, y_factor) = (matrix[axis * 2], matrix[axis * 2 + 1]);
+            if x_factor == 0. || y_factor == 0. {
+                // The axis only depends on one of the original axes, so use its samples as is.
+                let (source_axis, factor) = if y_factor == 0. {
+                    (0, x_factor)
+                } else {
+                    (1, y_factor)
+                };
+                let source_start = curve_type - 2 + source_axis * BEZIER_SIZE;
+                for sample in (0..BEZIER_SIZE).step_by(2) {
+                    curves[start + sample] = original_curves[source_start + sample];
+                    curves[start + sample + 1] =
+                        original_curves[source_start + sample + 1] * factor;
+                }
+                continue;
+            }
+            for sample in (start..start + BEZIER_SIZE).step_by(2) {
+                let time = original_curves[sample];
+                let (x, y) = if axis == 0 {
+                    (original_curves[sample + 1], value_at(1, time))
+                } else {
+                    (value_at(0, time), original_curves[sample + 1])
+                };
+                curves[sample + 1] = x_factor * x + y_factor * y;
+            }
+        }
+    }
+}
+
+/// Totals over the attachments of a [`SkeletonData`], see [`SkeletonData::mesh_statistics`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct MeshStatistics {
+    /// The most vertices in a single region or mesh attachment.
+    pub max_vertices: usize,
+    /// The most triangle indices in a single region or mesh attachment.
+    pub max_indices: usize,
+    /// The vertices of all region and mesh attachments.
+    pub total_vertices: usize,
+    /// The triangle indices of all region and mesh attachments.
+    pub total_indices: usize,
+    /// The number of bones in the skeleton.
+    pub bones_count: usize,
+    /// The most bones influencing a single vertex. Vertices of regions and unweighted meshes are
+    /// influenced by one bone.
+    pub max_bone_influences: usize,
+    /// The number of clipping attachments.
+    pub clipping_attachments: usize,
+}
+
+/// An attachment in one of a [`SkeletonData`]'s skins, see [`SkeletonData::attachments`].
+pub struct SkinAttachment<'a> {
+    /// The skin containing the attachment.
+    pub skin: CTmpRef<'a, SkeletonData, Skin>,
+    /// The index of the slot the attachment is in, see [`SkeletonData::slot_at_index`].
+    pub slot_index: usize,
+    /// The name the attachment is stored under in the skin, which may differ from
+    /// [`Attachment::name`] if the attachment is shared.
+    pub name: &'a str,
+    pub attachment: Attachment,
+}
+
+/// An iterator over every attachment in every skin of a [`SkeletonData`], see
+/// [`SkeletonData::attachments`].
+pub struct SkinAttachments<'a> {
+    skeleton_data: &'a SkeletonData,
+    skin_index: usize,
+    entry: *mut spSkinEntry,
+}
+
+impl<'a> Iterator for SkinAttachments<'a> {
+    type Item = SkinAttachment<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.entry.is_null() {
+            let skin = self.skeleton_data.skin_at_index(self.skin_index)?;
+            self.entry = unsafe { spSkin_getAttachments(skin.c_ptr()) };
+            self.skin_index += 1;
+        }
+        let entry = unsafe { &*self.entry };
+        self.entry = entry.next;
+        Some(SkinAttachment {
+            skin: self.skeleton_data.skin_at_index(self.skin_index - 1)?,
+            slot_index: entry.slotIndex as usize,
+            name: if entry.name.is_null() {
+                ""
+            } else {
+                unsafe { from_c_str(core::ffi::CStr::from_ptr(entry.name)) }
+            },
+            attachment: unsafe { Attachment::new_from_ptr(entry.attachment) },
+        })
+    }
+}
+
+/// Scale the `[time, value]` keys of a translate X or Y timeline, and its bezier curves.
+unsafe fn bake_translate_axis_timeline(c_timeline: *mut spCurveTimeline, scale: f32) {
+    let timeline = &mut *c_timeline;
+    let frames = core::slice::from_raw_parts_mut(
+        (*timeline.super_0.frames).items,
+        (*timeline.super_0.frames).size as usize,
+    );
+    let curves =
+        core::slice::from_raw_parts_mut((*timeline.curves).items, (*timeline.curves).size as usize);
+    let frame_count = timeline.super_0.frameCount as usize;
+    for frame in 0..frame_count {
+        frames[frame * 2 + 1] *= scale;
+    }
+    for sample in (frame_count..curves.len()).step_by(2) {
+        curves[sample + 1] *= scale;
+    }
+}
+
 impl Drop for SkeletonData {
     fn drop(&mut self) {
         if self.owns_memory {
+            diagnostics::untrack(LiveObjectKind::SkeletonData, self.c_skeleton_data.0);
+            lock(&FROZEN_SKELETON_DATA).remove(&self.c_skeleton_data.0.addr());
             unsafe {
                 spSkeletonData_dispose(self.c_skeleton_data.0);
             }
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use alloc::sync::Arc;
+
+    use crate::{
+        animation::{MixBlend, MixDirection},
+        c::{spEvent_create, spEvent_dispose},
+        c_interface::NewFromPtr,
+        test::TestAsset,
+        Event, Inherit, Physics, Skeleton, SkeletonData, SpineError,
+    };
+
+    const OFFSET: [f32; 2] = [5., -8.];
+    const SCALE: f32 = 2.;
+
+    fn baked_position(rotation: f32, x: f32, y: f32) -> (f32, f32) {
+        let (sin, cos) = rotation.to_radians().sin_cos();
+        (
+            (cos * x - sin * y) * SCALE + OFFSET[0],
+            (sin * x + cos * y) * SCALE + OFFSET[1],
+        )
+    }
+
+    /// Compare the world positions of bones which inherit every part of their parents' transforms.
+    fn assert_baked(rotation: f32, original: &Skeleton, baked: &Skeleton, tolerance: f32) {
+        // Parents always come before their children.
+        let mut inherits = vec![];
+        for (bone, baked_bone) in original.bones().zip(baked.bones()) {
+            let bone_inherits = bone.data().inherit() == Inherit::Normal
+                && bone
+                    .data()
+                    .parent()
+                    .is_none_or(|parent| inherits[parent.index()]);
+            inherits.push(bone_inherits);
+            if !bone_inherits {
+                continue;
+            }
+            let (x, y) = baked_position(rotation, bone.world_x(), bone.world_y());
+            assert!((baked_bone.world_x() - x).abs() < tolerance);
+            assert!((baked_bone.world_y() - y).abs() < tolerance);
+        }
+    }
+
+    fn skeleton_data(animations: &str) -> SkeletonData {
+        let json = format!(
+            r#"{{
+                "skeleton": {{ "spine": "4.2.00" }},
+                "bones": [
+                    {{ "name": "root", "x": 3, "y": 4 }},
+                    {{ "name": "child", "parent": "root", "x": 30, "rotation": 20 }}
+                ],
+                "animations": {{ {animations} }}
+            }}"#
+        );
+        TestAsset::spineboy()
+            .skeleton_json()
+            .read_skeleton_data(json.as_bytes())
+            .unwrap()
+    }
+
+    #[test]
+    fn bake_transform() {
+        let animations = r#""move": { "bones": { "root": { "translate": [
+            { "x": 0, "y": 0, "curve": [0.25, 0, 0.75, 10, 0.1, 0, 0.9, -5] },
+            { "time": 1, "x": 10, "y": -5 }
+        ] } } }"#;
+        // Right angles swap the axes of bezier curves, other angles resample them.
+        for (rotation, tolerance) in [(90., 0.001), (-37., 0.2)] {
+            let original_data = Arc::new(skeleton_data(animations));
+            let mut baked_data = skeleton_data(animations);
+            baked_data
+                .bake_transform(rotation, OFFSET[0], OFFSET[1], SCALE)
+                .unwrap();
+            let baked_data = Arc::new(baked_data);
+            let mut original = Skeleton::new(original_data.clone());
+            let mut baked = Skeleton::new(baked_data.clone());
+            for time in [0., 0.1, 0.37, 0.5, 0.81, 1.] {
+                for (skeleton, data) in [(&mut original, &original_data), (&mut baked, &baked_data)]
+                {
+                    data.find_animation("move")
+                        .unwrap()
+                        .apply(
+                            skeleton,
+                            0.,
+                            time,
+                            false,
+                            None,
+                            1.,
+                            MixBlend::Setup,
+                            MixDirection::In,
+                        )
+                        .unwrap();
+                    skeleton.update_world_transform(Physics::None);
+                }
+                assert_baked(rotation, &original, &baked, tolerance);
+            }
+        }
+
+        let mut separate_axes = skeleton_data(
+            r#""slide": { "bones": { "root": { "translatex": [
+                { "x": 0 }, { "time": 1, "x": 20 }
+            ] } } }"#,
+        );
+        assert!(matches!(
+            separate_axes.bake_transform(90., 0., 0., 1.),
+            Err(SpineError::Unsupported { .. })
+        ));
+        assert_eq!(separate_axes.find_bone("root").unwrap().x(), 3.);
+        assert!(separate_axes.bake_transform(180., 0., 0., 1.).is_ok());
+    }
+
+    #[test]
+    fn bake_transform_setup_pose() {
+        let original_data = Arc::new(TestAsset::spineboy().skeleton_data(true));
+        let mut baked_data = TestAsset::spineboy().skeleton_data(true);
+        baked_data
+            .bake_transform(37., OFFSET[0], OFFSET[1], SCALE)
+            .unwrap();
+        let mut original = Skeleton::new(original_data);
+        let mut baked = Skeleton::new(Arc::new(baked_data));
+        original.update_world_transform(Physics::None);
+        baked.update_world_transform(Physics::None);
+        assert_baked(37., &original, &baked, 0.01);
+    }
+
+    #[test]
+    fn attachments() {
+        for asset in TestAsset::all() {
+            let skeleton_data = asset.skeleton_data(true);
+            let expected: usize = skeleton_data
+                .skins()
+                .map(|skin| skin.attachments().len())
+                .sum();
+            assert!(expected > 0);
+            assert_eq!(skeleton_data.attachments().count(), expected);
+            let mut visited = 0;
+            skeleton_data.for_each_attachment(|skin, slot_index, name, attachment| {
+                assert!(skeleton_data.find_skin(skin.name()).is_some());
+                assert!(slot_index < skeleton_data.slots_count());
+                assert!(!name.is_empty());
+                assert!(!attachment.name().is_empty());
+                visited += 1;
+            });
+            assert_eq!(visited, expected);
+        }
+    }
+
+    #[test]
+    fn mesh_statistics() {
+        let skeleton_data = TestAsset::spineboy().skeleton_data(true);
+        let statistics = skeleton_data.mesh_statistics();
+        assert_eq!(statistics.bones_count, skeleton_data.bones_count());
+        assert!(statistics.max_vertices >= 4);
+        assert!(statistics.max_indices >= 6);
+        assert!(statistics.total_vertices >= statistics.max_vertices);
+        assert!(statistics.total_indices >= statistics.max_indices);
+        assert_eq!(statistics.total_indices % 3, 0);
+        // Spineboy has weighted meshes and one clipping attachment.
+        assert!(statistics.max_bone_influences > 1);
+        assert_eq!(statistics.clipping_attachments, 1);
+    }
+
+    #[test]
+    fn freeze_child_data() {
+        fn assert_child_data(skeleton: &mut Skeleton, frozen: bool) {
+            let check = |result: Result<(), SpineError>| {
+                assert_eq!(matches!(result, Err(SpineError::Frozen { .. })), frozen);
+            };
+            for mut bone in skeleton.bones_mut() {
+                check(bone.data_mut().map(|_| ()));
+            }
+            for mut slot in skeleton.slots_mut() {
+                check(slot.data_mut().map(|_| ()));
+            }
+            for mut ik_constraint in skeleton.ik_constraints_mut() {
+                check(ik_constraint.data_mut().map(|_| ()));
+            }
+            for mut path_constraint in skeleton.path_constraints_mut() {
+                check(path_constraint.data_mut().map(|_| ()));
+            }
+            for mut physics_constraint in skeleton.physics_constraints_mut() {
+                check(physics_constraint.data_mut().map(|_| ()));
+            }
+            for mut transform_constraint in skeleton.transform_constraints_mut() {
+                check(transform_constraint.data_mut().map(|_| ()));
+            }
+            for event_data in skeleton.data().events() {
+                let mut event =
+                    unsafe { Event::new_from_ptr(spEvent_create(0., event_data.c_ptr())) };
+                check(event.data_mut().map(|_| ()));
+                unsafe { spEvent_dispose(event.c_ptr()) };
+            }
+        }
+
+        for test_asset in TestAsset::all() {
+            let (mut skeleton, _) = test_asset.instance(true);
+            assert_child_data(&mut skeleton, false);
+            skeleton.data().freeze();
+            assert!(skeleton.data().is_frozen());
+            assert_child_data(&mut skeleton, true);
+        }
+    }
+}
@@ -0,0 +1,457 @@
+use alloc::sync::Arc;
+
+use crate::prelude::*;
+use crate::{
+    color::Color,
+    error::SpineError,
+    json::{color_hex, ObjectWriter},
+    skeleton_data::SkeletonData,
+    skeleton_json::SkeletonJson,
+    Atlas,
+};
+
+/// A bone created by a [`SkeletonDataBuilder`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct BoneBuilder {
+    name: String,
+    parent: Option<String>,
+    x: f32,
+    y: f32,
+    rotation: f32,
+    scale_x: f32,
+    scale_y: f32,
+    length: f32,
+}
+
+impl BoneBuilder {
+    /// A bone in the setup pose with no parent. Every skeleton needs exactly one such root bone.
+    #[must_use]
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_owned(),
+            parent: None,
+            x: 0.,
+            y: 0.,
+            rotation: 0.,
+            scale_x: 1.,
+            scale_y: 1.,
+            length: 0.,
+        }
+    }
+
+    /// The parent bone, which must have been added to the builder before this bone.
+    #[must_use]
+    pub fn with_parent(self, parent: &str) -> Self {
+        Self {
+            parent: Some(parent.to_owned()),
+            ..self
+        }
+    }
+
+    /// The setup pose translation relative to the parent bone.
+    #[must_use]
+    pub fn with_translation(self, x: f32, y: f32) -> Self {
+        Self { x, y, ..self }
+    }
+
+    /// The setup pose rotation (in degrees) relative to the parent bone.
+    #[must_use]
+    pub fn with_rotation(self, rotation: f32) -> Self {
+        Self { rotation, ..self }
+    }
+
+    /// The setup pose scale.
+    #[must_use]
+    pub fn with_scale(self, scale_x: f32, scale_y: f32) -> Self {
+        Self {
+            scale_x,
+            scale_y,
+            ..self
+        }
+    }
+
+    /// The length of the bone, only used for debug drawing and IK constraints.
+    #[must_use]
+    pub fn with_length(self, length: f32) -> Self {
+        Self { length, ..self }
+    }
+}
+
+/// A slot created by a [`SkeletonDataBuilder`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct SlotBuilder {
+    name: String,
+    bone: String,
+    color: Color,
+    attachment: Option<String>,
+}
+
+impl SlotBuilder {
+    /// A slot attached to `bone`, which must have been added to the builder. Slots are drawn in
+    /// the order they are added.
+    #[must_use]
+    pub fn new(name: &str, bone: &str) -> Self {
+        Self {
+            name: name.to_owned(),
+            bone: bone.to_owned(),
+            color: Color::new_rgba(1., 1., 1., 1.),
+            attachment: None,
+        }
+    }
+
+    /// The setup pose color of the slot.
+    #[must_use]
+    pub fn with_color(self, color: Color) -> Self {
+        Self { color, ..self }
+    }
+
+    /// The name of the attachment visible in the setup pose.
+    #[must_use]
+    pub fn with_attachment(self, attachment: &str) -> Self {
+        Self {
+            attachment: Some(attachment.to_owned()),
+            ..self
+        }
+    }
+}
+
+/// A region attachment in the default skin created by a [`SkeletonDataBuilder`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct RegionAttachmentBuilder {
+    slot: String,
+    name: String,
+    path: Option<String>,
+    x: f32,
+    y: f32,
+    rotation: f32,
+    scale_x: f32,
+    scale_y: f32,
+    size: Option<(f32, f32)>,
+    color: Color,
+}
+
+impl RegionAttachmentBuilder {
+    /// A region attachment for `slot`, which must have been added to the builder. Unless a path is
+    /// given using [`with_path`](`Self::with_path`), `name` is also the name of the atlas region.
+    #[must_use]
+    pub fn new(slot: &str, name: &str) -> Self {
+        Self {
+            slot: slot.to_owned(),
+            name: name.to_owned(),
+            path: None,
+            x: 0.,
+            y: 0.,
+            rotation: 0.,
+            scale_x: 1.,
+            scale_y: 1.,
+            size: None,
+            color: Color::new_rgba(1., 1., 1., 1.),
+        }
+    }
+
+    /// The name of the atlas region to display.
+    #[must_use]
+    pub fn with_path(self, path: &str) -> Self {
+        Self {
+            path: Some(path.to_owned()),
+            ..self
+        }
+    }
+
+    /// The translation of the region's center relative to the slot's bone.
+    #[must_use]
+    pub fn with_translation(self, x: f32, y: f32) -> Self {
+        Self { x, y, ..self }
+    }
+
+    /// The rotation (in degrees) relative to the slot's bone.
+    #[must_use]
+    pub fn with_rotation(self, rotation: f32) -> Self {
+        Self { rotation, ..self }
+    }
+
+    /// The scale of the region.
+    #[must_use]
+    pub fn with_scale(self, scale_x: f32, scale_y: f32) -> Self {
+        Self {
+            scale_x,
+            scale_y,
+            ..self
+        }
+    }
+
+    /// The unscaled size of the region. Defaults to the original size of the atlas region, in
+    /// atlas pixels.
+    #[must_use]
+    pub fn with_size(self, width: f32, height: f32) -> Self {
+        Self {
+            size: Some((width, height)),
+            ..self
+        }
+    }
+
+    /// The color of the region.
+    #[must_use]
+    pub fn with_color(self, color: Color) -> Self {
+        Self { color, ..self }
+    }
+
+    fn path(&self) -> &str {
+        self.path.as_deref().unwrap_or(&self.name)
+    }
+}
+
+/// Creates a [`SkeletonData`] programmatically rather than from a Spine editor export, for simple
+/// procedural rigs made of bones, slots, and region attachments.
+///
+/// The resulting skeleton has no animations and a single default skin containing every region
+/// attachment, but otherwise works like any other skeleton, including with
+/// [`SkeletonController`](`crate::controller::SkeletonController`).
+///
+/// ```
+/// # #[path="./test.rs"]
+/// # mod test;
+/// use std::sync::Arc;
+/// use rusty_spine::{
+///     BoneBuilder, RegionAttachmentBuilder, Skeleton, SkeletonDataBuilder, SlotBuilder,
+/// };
+///
+/// # let atlas = Arc::new(test::TestAsset::spineboy().atlas());
+/// let skeleton_data = SkeletonDataBuilder::new()
+///     .bone(BoneBuilder::new("root"))
+///     .bone(BoneBuilder::new("sign").with_parent("root").with_translation(0., 50.))
+///     .slot(SlotBuilder::new("sign", "sign").with_attachment("crosshair"))
+///     .region_attachment(RegionAttachmentBuilder::new("sign", "crosshair"))
+///     .build(atlas)?;
+/// let skeleton = Skeleton::new(Arc::new(skeleton_data));
+/// # Ok::<(), rusty_spine::SpineError>(())
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SkeletonDataBuilder {
+    bones: Vec<BoneBuilder>,
+    slots: Vec<SlotBuilder>,
+    region_attachments: Vec<RegionAttachmentBuilder>,
+}
+
+impl SkeletonDataBuilder {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a bone. Parent bones must be added before their children.
+    #[must_use]
+    pub fn bone(mut self, bone: BoneBuilder) -> Self {
+        self.bones.push(bone);
+        self
+    }
+
+    /// Add a slot. Slots are drawn in the order they are added.
+    #[must_use]
+    pub fn slot(mut self, slot: SlotBuilder) -> Self {
+        self.slots.push(slot);
+        self
+    }
+
+    /// Add a region attachment to the default skin.
+    #[must_use]
+    pub fn region_attachment(mut self, region_attachment: RegionAttachmentBuilder) -> Self {
+        self.region_attachments.push(region_attachment);
+        self
+    }
+
+    /// Create the skeleton data, loading region attachments from `atlas`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SpineError::CreationFailed`] if no bones were added.
+    /// Returns [`SpineError::NotFound`] if a parent bone, slot bone, attachment slot, or atlas
+    /// region does not exist. Returns [`SpineError::ParsingFailed`] if the Spine runtime rejects
+    /// the skeleton for any other reason.
+    pub fn build(&self, atlas: Arc<Atlas>) -> Result<SkeletonData, SpineError> {
+        let json = self.to_json(&atlas)?;
+        SkeletonJson::new(atlas).read_skeleton_data(json.as_bytes())
+    }
+
+    fn to_json(&self, atlas: &Atlas) -> Result<String, SpineError> {
+        if self.bones.is_empty() {
+            return Err(SpineError::new_creation_failed(
+                "SkeletonData without bones",
+            ));
+        }
+        let mut json = String::new();
+        let mut root = ObjectWriter::new(&mut json);
+        {
+            let out = root.key("skeleton");
+            let mut skeleton = ObjectWriter::new(out);
+            skeleton.string("spine", "4.2.00");
+            skeleton.end();
+        }
+        {
+            let out = root.key("bones");
+            out.push('[');
+            for (index, bone) in self.bones.iter().enumerate() {
+                if index > 0 {
+                    out.push(',');
+                }
+                let mut object = ObjectWriter::new(out);
+                object.string("name", &bone.name);
+                if let Some(parent) = &bone.parent {
+                    if !self.bones[..index]
+                        .iter()
+                        .any(|other| &other.name == parent)
+                    {
+                        return Err(SpineError::new_not_found("parent bone", parent));
+                    }
+                    object.string("parent", parent);
+                }
+                object.number("x", bone.x);
+                object.number("y", bone.y);
+                object.number("rotation", bone.rotation);
+                object.number("scaleX", bone.scale_x);
+                object.number("scaleY", bone.scale_y);
+                object.number("length", bone.length);
+                object.end();
+            }
+            out.push(']');
+        }
+        {
+            let out = root.key("slots");
+            out.push('[');
+            for (index, slot) in self.slots.iter().enumerate() {
+                if index > 0 {
+                    out.push(',');
+                }
+                if !self.bones.iter().any(|bone| bone.name == slot.bone) {
+                    return Err(SpineError::new_not_found("bone", &slot.bone));
+                }
+                let mut object = ObjectWriter::new(out);
+                object.string("name", &slot.name);
+                object.string("bone", &slot.bone);
+                object.string("color", &color_hex(slot.color));
+                if let Some(attachment) = &slot.attachment {
+                    object.string("attachment", attachment);
+                }
+                object.end();
+            }
+            out.push(']');
+        }
+        {
+            let out = root.key("skins");
+            out.push('[');
+            let mut skin = ObjectWriter::new(out);
+            skin.string("name", "default");
+            let mut attachments = ObjectWriter::new(skin.key("attachments"));
+            for slot in &self.slots {
+                let mut slot_region_attachments = self
+                    .region_attachments
+                    .iter()
+                    .filter(|region_attachment| region_attachment.slot == slot.name)
+                    .peekable();
+                if slot_region_attachments.peek().is_none() {
+                    continue;
+                }
+                let mut slot_attachments = ObjectWriter::new(attachments.key(&slot.name));
+                for region_attachment in slot_region_attachments {
+                    let (width, height) = match region_attachment.size {
+                        Some(size) => size,
+                        None => {
+                            let Some(atlas_region) = atlas.find_region(region_attachment.path())
+                            else {
+                                return Err(SpineError::new_not_found(
+                                    "atlas region",
+                                    region_attachment.path(),
+                                ));
+                            };
+                            let texture_region = atlas_region.texture_region();
+                            (
+                                texture_region.original_width() as f32,
+                                texture_region.original_height() as f32,
+                            )
+                        }
+                    };
+                    let mut object =
+                        ObjectWriter::new(slot_attachments.key(&region_attachment.name));
+                    object.string("type", "region");
+                    object.string("path", region_attachment.path());
+                    object.number("x", region_attachment.x);
+                    object.number("y", region_attachment.y);
+                    object.number("rotation", region_attachment.rotation);
+                    object.number("scaleX", region_attachment.scale_x);
+                    object.number("scaleY", region_attachment.scale_y);
+                    object.number("width", width);
+                    object.number("height", height);
+                    object.string("color", &color_hex(region_attachment.color));
+                    object.end();
+                }
+                slot_attachments.end();
+            }
+            if let Some(region_attachment) =
+                self.region_attachments.iter().find(|region_attachment| {
+                    !self
+                        .slots
+                        .iter()
+                        .any(|slot| slot.name == region_attachment.slot)
+                })
+            {
+                return Err(SpineError::new_not_found("slot", &region_attachment.slot));
+            }
+            attachments.end();
+            skin.end();
+            out.push(']');
+        }
+        root.end();
+        Ok(json)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::sync::Arc;
+
+    use crate::{test::TestAsset, Skeleton, SpineError};
+
+    use super::*;
+
+    #[test]
+    fn build_skeleton_data() {
+        let atlas = Arc::new(TestAsset::spineboy().atlas());
+        let skeleton_data = SkeletonDataBuilder::new()
+            .bone(BoneBuilder::new("root"))
+            .bone(
+                BoneBuilder::new("arm")
+                    .with_parent("root")
+                    .with_translation(10., 20.)
+                    .with_rotation(45.),
+            )
+            .slot(SlotBuilder::new("hand", "arm").with_attachment("fist"))
+            .region_attachment(
+                RegionAttachmentBuilder::new("hand", "fist").with_path("front-fist-closed"),
+            )
+            .region_attachment(
+                RegionAttachmentBuilder::new("hand", "open")
+                    .with_path("front-fist-open")
+                    .with_size(10., 10.),
+            )
+            .build(atlas)
+            .unwrap();
+        assert_eq!(skeleton_data.bones_count(), 2);
+        assert_eq!(skeleton_data.slots_count(), 1);
+        let mut skeleton = Skeleton::new(Arc::new(skeleton_data));
+        skeleton.set_to_setup_pose();
+        let slot = skeleton.find_slot("hand").unwrap();
+        let region_attachment = slot.region_attachment().unwrap();
+        assert_eq!(region_attachment.path(), "front-fist-closed");
+        assert_eq!(skeleton.find_bone("arm").unwrap().x(), 10.);
+    }
+
+    #[test]
+    fn build_skeleton_data_missing_parent() {
+        let atlas = Arc::new(TestAsset::spineboy().atlas());
+        let result = SkeletonDataBuilder::new()
+            .bone(BoneBuilder::new("root"))
+            .bone(BoneBuilder::new("arm").with_parent("body"))
+            .build(atlas);
+        assert!(matches!(result, Err(SpineError::NotFound { .. })));
+    }
+}
@@ -0,0 +1,1460 @@
+//! Serializes [`SkeletonData`] back into Spine JSON, so asset pipeline tools can inspect,
+//! transform, and re-save skeletons.
+//!
+//! ```
+//! # #[path="./test.rs"]
+//! # mod test;
+//! use rusty_spine::skeleton_export::{to_json_with_settings, SkeletonExportSettings};
+//!
+//! # let (skeleton_data, _) = test::TestAsset::spineboy().instance_data(true);
+//! // Strip every animation except "walk" and "run" before shipping.
+//! let json = to_json_with_settings(
+//!     &skeleton_data,
+//!     &SkeletonExportSettings::new().with_animations(["walk", "run"]),
+//! )?;
+//! std::fs::write(std::env::temp_dir().join("spineboy-stripped.json"), json)?;
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+//!
+//! The exported JSON can be read by [`SkeletonJson`](`crate::SkeletonJson`), and contains the
+//! skeleton's bones, slots, constraints, skins, events, and animations. Only data kept by the
+//! runtime is exported, so the JSON may differ from the file the skeleton was loaded from:
+//! - Bezier curves are reconstructed from the runtime's sampled curves, and match the original
+//!   curves to within floating point precision.
+//! - Skeleton data loaded with a [`scale`](`crate::SkeletonJson::scale`) is exported at that
+//!   scale.
+
+use alloc::collections::BTreeMap;
+use core::ffi::{c_char, CStr};
+
+#[cfg(not(feature = "std"))]
+use crate::math::Float;
+use crate::prelude::*;
+use crate::{
+    c::{
+        spAlphaTimeline, spAttachment, spAttachmentTimeline, spBoneData, spBoundingBoxAttachment,
+        spClippingAttachment, spColor, spCurveTimeline, spDeformTimeline, spDrawOrderTimeline,
+        spEventTimeline, spIkConstraintTimeline, spInheritTimeline, spMeshAttachment,
+        spPathAttachment, spPathConstraintMixTimeline, spPathConstraintPositionTimeline,
+        spPhysicsConstraintResetTimeline, spPhysicsConstraintTimeline, spPointAttachment,
+        spRGB2Timeline, spRGBA2Timeline, spRGBATimeline, spRGBTimeline, spRegionAttachment,
+        spRotateTimeline, spScaleTimeline, spScaleXTimeline, spScaleYTimeline, spSequence,
+        spSequenceTimeline, spShearTimeline, spShearXTimeline, spShearYTimeline, spSkeletonData,
+        spSkin, spSkin_getAttachments, spTimeline, spTransformConstraintTimeline,
+        spTranslateTimeline, spTranslateXTimeline, spTranslateYTimeline, spVertexAttachment,
+        SP_ATTACHMENT_BOUNDING_BOX, SP_ATTACHMENT_CLIPPING, SP_ATTACHMENT_LINKED_MESH,
+        SP_ATTACHMENT_MESH, SP_ATTACHMENT_PATH, SP_ATTACHMENT_POINT, SP_ATTACHMENT_REGION,
+        SP_TIMELINE_ALPHA, SP_TIMELINE_ATTACHMENT, SP_TIMELINE_DEFORM, SP_TIMELINE_DRAWORDER,
+        SP_TIMELINE_EVENT, SP_TIMELINE_IKCONSTRAINT, SP_TIMELINE_INHERIT,
+        SP_TIMELINE_PATHCONSTRAINTMIX, SP_TIMELINE_PATHCONSTRAINTPOSITION,
+        SP_TIMELINE_PATHCONSTRAINTSPACING, SP_TIMELINE_PHYSICSCONSTRAINT_DAMPING,
+        SP_TIMELINE_PHYSICSCONSTRAINT_GRAVITY, SP_TIMELINE_PHYSICSCONSTRAINT_INERTIA,
+        SP_TIMELINE_PHYSICSCONSTRAINT_MASS, SP_TIMELINE_PHYSICSCONSTRAINT_MIX,
+        SP_TIMELINE_PHYSICSCONSTRAINT_RESET, SP_TIMELINE_PHYSICSCONSTRAINT_STRENGTH,
+        SP_TIMELINE_PHYSICSCONSTRAINT_WIND, SP_TIMELINE_RGB, SP_TIMELINE_RGB2, SP_TIMELINE_RGBA,
+        SP_TIMELINE_RGBA2, SP_TIMELINE_ROTATE, SP_TIMELINE_SCALE, SP_TIMELINE_SCALEX,
+        SP_TIMELINE_SCALEY, SP_TIMELINE_SEQUENCE, SP_TIMELINE_SHEAR, SP_TIMELINE_SHEARX,
+        SP_TIMELINE_SHEARY, SP_TIMELINE_TRANSFORMCONSTRAINT, SP_TIMELINE_TRANSLATE,
+        SP_TIMELINE_TRANSLATEX, SP_TIMELINE_TRANSLATEY,
+    },
+    color::Color,
+    error::SpineError,
+    json::{color_hex, write_number, write_string, ObjectWriter},
+    skeleton_data::SkeletonData,
+};
+
+/// Settings for [`to_json_with_settings`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SkeletonExportSettings {
+    /// The names of the animations to export, or [`None`] to export every animation.
+    pub animations: Option<Vec<String>>,
+}
+
+impl SkeletonExportSettings {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Only export the animations with the given names.
+    #[must_use]
+    pub fn with_animations<I, S>(self, animations: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        Self {
+            animations: Some(
+                animations
+                    .into_iter()
+                    .map(|name| name.as_ref().to_owned())
+                    .collect(),
+            ),
+        }
+    }
+}
+
+/// Serializes the skeleton data into Spine JSON. See the [module documentation](`self`).
+///
+/// # Errors
+///
+/// Returns [`SpineError::IncompatibleAttachment`] if a skin contains an attachment of a custom
+/// type, which can't be represented in Spine JSON.
+pub fn to_json(skeleton_data: &SkeletonData) -> Result<String, SpineError> {
+    to_json_with_settings(skeleton_data, &SkeletonExportSettings::new())
+}
+
+/// Serializes the skeleton data into Spine JSON, filtered by `settings`. See the [module
+/// documentation](`self`).
+///
+/// # Errors
+///
+/// Returns [`SpineError::NotFound`] if an animation listed in the settings does not exist, or
+/// [`SpineError::IncompatibleAttachment`] if a skin contains an attachment of a custom type,
+/// which can't be represented in Spine JSON.
+pub fn to_json_with_settings(
+    skeleton_data: &SkeletonData,
+    settings: &SkeletonExportSettings,
+) -> Result<String, SpineError> {
+    if let Some(animations) = &settings.animations {
+        for animation_name in animations {
+            if skeleton_data.find_animation(animation_name).is_none() {
+                return Err(SpineError::new_not_found("Animation", animation_name));
+            }
+        }
+    }
+    let data = unsafe { skeleton_data.c_ptr_ref() };
+    let skin_entries = unsafe { SkinEntries::new(data) };
+    let mut json = String::new();
+    let mut root = ObjectWriter::new(&mut json);
+    unsafe {
+        write_skeleton(root.key("skeleton"), data);
+        write_array(
+            root.key("bones"),
+            slice(data.bones, data.bonesCount),
+            |out, bone| {
+                write_bone(out, &**bone);
+            },
+        );
+        write_array(
+            root.key("slots"),
+            slice(data.slots, data.slotsCount),
+            |out, slot| {
+                write_slot(out, &**slot);
+            },
+        );
+        write_array(
+            root.key("ik"),
+            slice(data.ikConstraints, data.ikConstraintsCount),
+            |out, ik_constraint| write_ik_constraint(out, &**ik_constraint),
+        );
+        write_array(
+            root.key("transform"),
+            slice(data.transformConstraints, data.transformConstraintsCount),
+            |out, transform_constraint| write_transform_constraint(out, &**transform_constraint),
+        );
+        write_array(
+            root.key("path"),
+            slice(data.pathConstraints, data.pathConstraintsCount),
+            |out, path_constraint| write_path_constraint(out, &**path_constraint),
+        );
+        write_array(
+            root.key("physics"),
+            slice(data.physicsConstraints, data.physicsConstraintsCount),
+            |out, physics_constraint| write_physics_constraint(out, &**physics_constraint),
+        );
+        {
+            let out = root.key("skins");
+            out.push('[');
+            for (index, skin) in slice(data.skins, data.skinsCount).iter().enumerate() {
+                if index > 0 {
+                    out.push(',');
+                }
+                write_skin(out, data, &**skin, &skin_entries)?;
+            }
+            out.push(']');
+        }
+        {
+            let mut events = ObjectWriter::new(root.key("events"));
+            for event_data in slice(data.events, data.eventsCount) {
+                let event_data = &**event_data;
+                let mut object = ObjectWriter::new(events.key(c_str(event_data.name)));
+                object.number("int", event_data.intValue as f32);
+                object.number("float", event_data.floatValue);
+                if let Some(string) = c_str_optional(event_data.stringValue) {
+                    object.string("string", string);
+                }
+                if let Some(audio) = c_str_optional(event_data.audioPath) {
+                    object.string("audio", audio);
+                    object.number("volume", event_data.volume);
+                    object.number("balance", event_data.balance);
+                }
+                object.end();
+            }
+            events.end();
+        }
+        {
+            let mut animations = ObjectWriter::new(root.key("animations"));
+            for animation in slice(data.animations, data.animationsCount) {
+                let animation = &**animation;
+                let name = c_str(animation.name);
+                if settings
+                    .animations
+                    .as_ref()
+                    .is_some_and(|animations| !animations.iter().any(|n| n == name))
+                {
+                    continue;
+                }
+                let mut tree = JsonTree::default();
+                for timeline in slice((*animation.timelines).items, (*animation.timelines).size) {
+                    write_timeline(&mut tree, data, *timeline, &skin_entries);
+                }
+                tree.write(animations.key(name));
+            }
+            animations.end();
+        }
+    }
+    root.end();
+    Ok(json)
+}
+
+/// The skin, slot, and entry name of every attachment, so linked meshes and timelines can refer
+/// to the attachments they use.
+struct SkinEntries {
+    entries: BTreeMap<usize, (String, usize, String)>,
+}
+
+impl SkinEntries {
+    unsafe fn new(data: &spSkeletonData) -> Self {
+        let mut entries = BTreeMap::new();
+        for skin in slice(data.skins, data.skinsCount) {
+            let skin_name = c_str((**skin).name);
+            let mut entry = spSkin_getAttachments(*skin);
+            while !entry.is_null() {
+                entries
+                    .entry((*entry).attachment as usize)
+                    .or_insert_with(|| {
+                        (
+                            skin_name.to_owned(),
+                            (*entry).slotIndex as usize,
+                            c_str((*entry).name).to_owned(),
+                        )
+                    });
+                entry = (*entry).next;
+            }
+        }
+        Self { entries }
+    }
+
+    fn get(&self, attachment: *const spAttachment) -> Option<&(String, usize, String)> {
+        self.entries.get(&(attachment as usize))
+    }
+}
+
+/// Nested JSON objects, built from timelines in any order and written with each object's members
+/// in the order they were first inserted.
+#[derive(Default)]
+struct JsonTree {
+    members: Vec<(String, JsonNode)>,
+}
+
+enum JsonNode {
+    Object(JsonTree),
+    Value(String),
+}
+
+impl JsonTree {
+    fn insert(&mut self, path: &[&str], value: String) {
+        let Some((key, rest)) = path.split_first() else {
+            return;
+        };
+        let index = self
+            .members
+            .iter()
+            .position(|(member_key, _)| member_key == key)
+            .unwrap_or_else(|| {
+                let node = if rest.is_empty() {
+                    JsonNode::Value(String::new())
+                } else {
+                    JsonNode::Object(Self::default())
+                };
+                self.members.push(((*key).to_owned(), node));
+                self.members.len() - 1
+            });
+        match &mut self.members[index].1 {
+            JsonNode::Object(tree) => tree.insert(rest, value),
+            JsonNode::Value(existing) => *existing = value,
+        }
+    }
+
+    fn write(&self, out: &mut String) {
+        let mut object = ObjectWriter::new(out);
+        for (key, node) in &self.members {
+            match node {
+                JsonNode::Object(tree) => tree.write(object.key(key)),
+                JsonNode::Value(value) => object.key(key).push_str(value),
+            }
+        }
+        object.end();
+    }
+}
+
+unsafe fn write_skeleton(out: &mut String, data: &spSkeletonData) {
+    let mut object = ObjectWriter::new(out);
+    if let Some(hash) = c_str_optional(data.hash) {
+        object.string("hash", hash);
+    }
+    if let Some(version) = c_str_optional(data.version) {
+        object.string("spine", version);
+    }
+    object.number("x", data.x);
+    object.number("y", data.y);
+    object.number("width", data.width);
+    object.number("height", data.height);
+    object.number("referenceScale", data.referenceScale);
+    object.number("fps", data.fps);
+    if let Some(images) = c_str_optional(data.imagesPath) {
+        object.string("images", images);
+    }
+    if let Some(audio) = c_str_optional(data.audioPath) {
+        object.string("audio", audio);
+    }
+    object.end();
+}
+
+unsafe fn write_bone(out: &mut String, bone: &spBoneData) {
+    let mut object = ObjectWriter::new(out);
+    object.string("name", c_str(bone.name));
+    if !bone.parent.is_null() {
+        object.string("parent", c_str((*bone.parent).name));
+    }
+    object.number("length", bone.length);
+    object.number("x", bone.x);
+    object.number("y", bone.y);
+    object.number("rotation", bone.rotation);
+    object.number("scaleX", bone.scaleX);
+    object.number("scaleY", bone.scaleY);
+    object.number("shearX", bone.shearX);
+    object.number("shearY", bone.shearY);
+    object.string("inherit", INHERIT_NAMES[bone.inherit as usize]);
+    object.boolean("skin", bone.skinRequired != 0);
+    object.string("color", &color_hex(color(&bone.color)));
+    if let Some(icon) = c_str_optional(bone.icon).filter(|icon| !icon.is_empty()) {
+        object.string("icon", icon);
+    }
+    object.boolean("visible", bone.visible != 0);
+    object.end();
+}
+
+unsafe fn write_slot(out: &mut String, slot: &crate::c::spSlotData) {
+    let mut object = ObjectWriter::new(out);
+    object.string("name", c_str(slot.name));
+    object.string("bone", c_str((*slot.boneData).name));
+    object.string("color", &color_hex(color(&slot.color)));
+    if !slot.darkColor.is_null() {
+        object.string("dark", &color_hex(color(&*slot.darkColor))[..6]);
+    }
+    if let Some(attachment_name) = c_str_optional(slot.attachmentName) {
+        object.string("attachment", attachment_name);
+    }
+    object.string("blend", BLEND_MODE_NAMES[slot.blendMode as usize]);
+    object.boolean("visible", slot.visible != 0);
+    object.end();
+}
+
+unsafe fn write_constraint_bones(object: &mut ObjectWriter, bones: &[*mut spBoneData]) {
+    write_array(object.key("bones"), bones, |out, bone| {
+        write_string(out, c_str((**bone).name));
+    });
+}
+
+unsafe fn write_ik_constraint(out: &mut String, ik_constraint: &crate::c::spIkConstraintData) {
+    let mut object = ObjectWriter::new(out);
+    object.string("name", c_str(ik_constraint.name));
+    object.number("order", ik_constraint.order as f32);
+    object.boolean("skin", ik_constraint.skinRequired != 0);
+    write_constraint_bones(
+        &mut object,
+        slice(ik_constraint.bones, ik_constraint.bonesCount),
+    );
+    object.string("target", c_str((*ik_constraint.target).name));
+    object.number("mix", ik_constraint.mix);
+    object.number("softness", ik_constraint.softness);
+    object.boolean("bendPositive", ik_constraint.bendDirection > 0);
+    object.boolean("compress", ik_constraint.compress != 0);
+    object.boolean("stretch", ik_constraint.stretch != 0);
+    object.boolean("uniform", ik_constraint.uniform != 0);
+    object.end();
+}
+
+unsafe fn write_transform_constraint(
+    out: &mut String,
+    transform_constraint: &crate::c::spTransformConstraintData,
+) {
+    let mut object = ObjectWriter::new(out);
+    object.string("name", c_str(transform_constraint.name));
+    object.number("order", transform_constraint.order as f32);
+    object.boolean("skin", transform_constraint.skinRequired != 0);
+    write_constraint_bones(
+        &mut object,
+        slice(transform_constraint.bones, transform_constraint.bonesCount),
+    );
+    object.string("target", c_str((*transform_constraint.target).name));
+    object.boolean("local", transform_constraint.local != 0);
+    object.boolean("relative", transform_constraint.relative != 0);
+    object.number("rotation", transform_constraint.offsetRotation);
+    object.number("x", transform_constraint.offsetX);
+    object.number("y", transform_constraint.offsetY);
+    object.number("scaleX", transform_constraint.offsetScaleX);
+    object.number("scaleY", transform_constraint.offsetScaleY);
+    object.number("shearY", transform_constraint.offsetShearY);
+    object.number("mixRotate", transform_constraint.mixRotate);
+    object.number("mixX", transform_constraint.mixX);
+    object.number("mixY", transform_constraint.mixY);
+    object.number("mixScaleX", transform_constraint.mixScaleX);
+    object.number("mixScaleY", transform_constraint.mixScaleY);
+    object.number("mixShearY", transform_constraint.mixShearY);
+    object.end();
+}
+
+unsafe fn write_path_constraint(
+    out: &mut String,
+    path_constraint: &crate::c::spPathConstraintData,
+) {
+    let mut object = ObjectWriter::new(out);
+    object.string("name", c_str(path_constraint.name));
+    object.number("order", path_constraint.order as f32);
+    object.boolean("skin", path_constraint.skinRequired != 0);
+    write_constraint_bones(
+        &mut object,
+        slice(path_constraint.bones, path_constraint.bonesCount),
+    );
+    object.string("target", c_str((*path_constraint.target).name));
+    object.string(
+        "positionMode",
+        POSITION_MODE_NAMES[path_constraint.positionMode as usize],
+    );
+    object.string(
+        "spacingMode",
+        SPACING_MODE_NAMES[path_constraint.spacingMode as usize],
+    );
+    object.string(
+        "rotateMode",
+        ROTATE_MODE_NAMES[path_constraint.rotateMode as usize],
+    );
+    object.number("rotation", path_constraint.offsetRotation);
+    object.number("position", path_constraint.position);
+    object.number("spacing", path_constraint.spacing);
+    object.number("mixRotate", path_constraint.mixRotate);
+    object.number("mixX", path_constraint.mixX);
+    object.number("mixY", path_constraint.mixY);
+    object.end();
+}
+
+unsafe fn write_physics_constraint(
+    out: &mut String,
+    physics_constraint: &crate::c::spPhysicsConstraintData,
+) {
+    let mut object = ObjectWriter::new(out);
+    object.string("name", c_str(physics_constraint.name));
+    object.number("order", physics_constraint.order as f32);
+    object.boolean("skin", physics_constraint.skinRequired != 0);
+    object.string("bone", c_str((*physics_constraint.bone).name));
+    object.number("x", physics_constraint.x);
+    object.number("y", physics_constraint.y);
+    object.number("rotate", physics_constraint.rotate);
+    object.number("scaleX", physics_constraint.scaleX);
+    object.number("shearX", physics_constraint.shearX);
+    object.number("limit", physics_constraint.limit);
+    object.number("fps", (1. / physics_constraint.step).round());
+    object.number("inertia", physics_constraint.inertia);
+    object.number("strength", physics_constraint.strength);
+    object.number("damping", physics_constraint.damping);
+    object.number("mass", 1. / physics_constraint.massInverse);
+    object.number("wind", physics_constraint.wind);
+    object.number("gravity", physics_constraint.gravity);
+    object.number("mix", physics_constraint.mix);
+    object.boolean("inertiaGlobal", physics_constraint.inertiaGlobal != 0);
+    object.boolean("strengthGlobal", physics_constraint.strengthGlobal != 0);
+    object.boolean("dampingGlobal", physics_constraint.dampingGlobal != 0);
+    object.boolean("massGlobal", physics_constraint.massGlobal != 0);
+    object.boolean("windGlobal", physics_constraint.windGlobal != 0);
+    object.boolean("gravityGlobal", physics_constraint.gravityGlobal != 0);
+    object.boolean("mixGlobal", physics_constraint.mixGlobal != 0);
+    object.end();
+}
+
+unsafe fn write_skin(
+    out: &mut String,
+    data: &spSkeletonData,
+    skin: &spSkin,
+    skin_entries: &SkinEntries,
+) -> Result<(), SpineError> {
+    let mut object = ObjectWriter::new(out);
+    object.string("name", c_str(skin.name));
+    for (key, names) in [
+        (
+            "bones",
+            array_names(skin.bones.as_ref().map(|a| (a.items, a.size))),
+        ),
+        (
+            "ik",
+            array_names(skin.ikConstraints.as_ref().map(|a| (a.items, a.size))),
+        ),
+        (
+            "transform",
+            array_names(
+                skin.transformConstraints
+                    .as_ref()
+                    .map(|a| (a.items, a.size)),
+            ),
+        ),
+        (
+            "path",
+            array_names(skin.pathConstraints.as_ref().map(|a| (a.items, a.size))),
+        ),
+        (
+            "physics",
+            array_names(skin.physicsConstraints.as_ref().map(|a| (a.items, a.size))),
+        ),
+    ] {
+        if !names.is_empty() {
+            write_array(object.key(key), &names, |out, name| write_string(out, name));
+        }
+    }
+    let mut slots: BTreeMap<usize, Vec<(&str, *mut spAttachment)>> = BTreeMap::new();
+    let mut entry = spSkin_getAttachments(core::ptr::from_ref(skin).cast_mut());
+    while !entry.is_null() {
+        slots
+            .entry((*entry).slotIndex as usize)
+            .or_default()
+            .push((c_str((*entry).name), (*entry).attachment));
+        entry = (*entry).next;
+    }
+    let mut attachments = ObjectWriter::new(object.key("attachments"));
+    for (slot_index, entries) in slots {
+        let slot_name = c_str((**data.slots.add(slot_index)).name);
+        let mut slot_attachments = ObjectWriter::new(attachments.key(slot_name));
+        for (entry_name, attachment) in entries.into_iter().rev() {
+            write_attachment(
+                slot_attachments.key(entry_name),
+                entry_name,
+                &*attachment,
+                skin_entries,
+            )?;
+        }
+        slot_attachments.end();
+    }
+    attachments.end();
+    object.end();
+    Ok(())
+}
+
+/// The names of the items of a constraint or bone array, which all start with a name.
+unsafe fn array_names<T>(array: Option<(*mut *mut T, i32)>) -> Vec<&'static str> {
+    array.map_or_else(Vec::new, |(items, size)| {
+        slice(items, size)
+            .iter()
+            .map(|item| c_str(*item.cast::<*const c_char>()))
+            .collect()
+    })
+}
+
+unsafe fn write_attachment(
+    out: &mut String,
+    entry_name: &str,
+    attachment: &spAttachment,
+    skin_entries: &SkinEntries,
+) -> Result<(), SpineError> {
+    let name = c_str(attachment.name);
+    let mut object = ObjectWriter::new(out);
+    if name != entry_name {
+        object.string("name", name);
+    }
+    let attachment_ptr = core::ptr::from_ref(attachment);
+    match attachment.type_0 {
+        SP_ATTACHMENT_REGION => {
+            let region = &*attachment_ptr.cast::<spRegionAttachment>();
+            write_path(&mut object, name, region.path);
+            object.number("x", region.x);
+            object.number("y", region.y);
+            object.number("scaleX", region.scaleX);
+            object.number("scaleY", region.scaleY);
+            object.number("rotation", region.rotation);
+            object.number("width", region.width);
+            object.number("height", region.height);
+            object.string("color", &color_hex(color(&region.color)));
+            write_sequence(&mut object, region.sequence);
+        }
+        SP_ATTACHMENT_MESH | SP_ATTACHMENT_LINKED_MESH => {
+            let mesh = &*attachment_ptr.cast::<spMeshAttachment>();
+            let vertex_attachment = &mesh.super_0;
+            write_path(&mut object, name, mesh.path);
+            object.string("color", &color_hex(color(&mesh.color)));
+            object.number("width", mesh.width);
+            object.number("height", mesh.height);
+            write_sequence(&mut object, mesh.sequence);
+            if let Some((skin_name, _, parent_name)) = mesh
+                .parentMesh
+                .as_ref()
+                .and_then(|parent| skin_entries.get(core::ptr::from_ref(&parent.super_0.super_0)))
+            {
+                object.string("type", "linkedmesh");
+                object.string("parent", parent_name);
+                if skin_name != "default" {
+                    object.string("skin", skin_name);
+                }
+                object.boolean(
+                    "timelines",
+                    vertex_attachment.timelineAttachment
+                        != core::ptr::from_ref(attachment).cast_mut(),
+                );
+            } else {
+                object.string("type", "mesh");
+                write_numbers(
+                    object.key("uvs"),
+                    slice(mesh.regionUVs, vertex_attachment.worldVerticesLength)
+                        .iter()
+                        .copied(),
+                );
+                write_numbers(
+                    object.key("triangles"),
+                    slice(mesh.triangles, mesh.trianglesCount)
+                        .iter()
+                        .map(|index| f32::from(*index)),
+                );
+                write_vertices(&mut object, vertex_attachment);
+                object.number("hull", mesh.hullLength as f32);
+                if mesh.edgesCount > 0 {
+                    write_numbers(
+                        object.key("edges"),
+                        slice(mesh.edges, mesh.edgesCount)
+                            .iter()
+                            .map(|index| f32::from(*index)),
+                    );
+                }
+            }
+        }
+        SP_ATTACHMENT_BOUNDING_BOX => {
+            let bounding_box = &*attachment_ptr.cast::<spBoundingBoxAttachment>();
+            object.string("type", "boundingbox");
+            write_vertex_count(&mut object, &bounding_box.super_0);
+            write_vertices(&mut object, &bounding_box.super_0);
+            object.string("color", &color_hex(color(&bounding_box.color)));
+        }
+        SP_ATTACHMENT_PATH => {
+            let path = &*attachment_ptr.cast::<spPathAttachment>();
+            object.string("type", "path");
+            object.boolean("closed", path.closed != 0);
+            object.boolean("constantSpeed", path.constantSpeed != 0);
+            write_vertex_count(&mut object, &path.super_0);
+            write_vertices(&mut object, &path.super_0);
+            write_numbers(
+                object.key("lengths"),
+                slice(path.lengths, path.lengthsLength).iter().copied(),
+            );
+            object.string("color", &color_hex(color(&path.color)));
+        }
+        SP_ATTACHMENT_POINT => {
+            let point = &*attachment_ptr.cast::<spPointAttachment>();
+            object.string("type", "point");
+            object.number("x", point.x);
+            object.number("y", point.y);
+            object.number("rotation", point.rotation);
+            object.string("color", &color_hex(color(&point.color)));
+        }
+        SP_ATTACHMENT_CLIPPING => {
+            let clipping = &*attachment_ptr.cast::<spClippingAttachment>();
+            object.string("type", "clipping");
+            if !clipping.endSlot.is_null() {
+                object.string("end", c_str((*clipping.endSlot).name));
+            }
+            write_vertex_count(&mut object, &clipping.super_0);
+            write_vertices(&mut object, &clipping.super_0);
+            object.string("color", &color_hex(color(&clipping.color)));
+        }
+        _ => {
+            return Err(SpineError::new_incompatible_attachment(
+                name,
+                "custom attachment types can't be exported to JSON",
+            ))
+        }
+    }
+    object.end();
+    Ok(())
+}
+
+unsafe fn write_path(object: &mut ObjectWriter, name: &str, path: *const c_char) {
+    if let Some(path) = c_str_optional(path).filter(|path| *path != name) {
+        object.string("path", path);
+    }
+}
+
+unsafe fn write_sequence(object: &mut ObjectWriter, sequence: *const spSequence) {
+    let Some(sequence) = sequence.as_ref() else {
+        return;
+    };
+    let mut sequence_object = ObjectWriter::new(object.key("sequence"));
+    sequence_object.number(
+        "count",
+        sequence.regions.as_ref().map_or(0, |regions| regions.size) as f32,
+    );
+    sequence_object.number("start", sequence.start as f32);
+    sequence_object.number("digits", sequence.digits as f32);
+    sequence_object.number("setupIndex", sequence.setupIndex as f32);
+    sequence_object.end();
+}
+
+unsafe fn write_vertex_count(object: &mut ObjectWriter, vertex_attachment: &spVertexAttachment) {
+    object.number(
+        "vertexCount",
+        (vertex_attachment.worldVerticesLength / 2) as f32,
+    );
+}
+
+/// Writes the vertices in Spine's JSON format, where the vertices of weighted attachments are
+/// written as a bone count followed by a bone index, x, y, and weight for each bone.
+unsafe fn write_vertices(object: &mut ObjectWriter, vertex_attachment: &spVertexAttachment) {
+    let vertices = slice(vertex_attachment.vertices, vertex_attachment.verticesCount);
+    if vertex_attachment.bones.is_null() {
+        write_numbers(object.key("vertices"), vertices.iter().copied());
+        return;
+    }
+    let bones = slice(vertex_attachment.bones, vertex_attachment.bonesCount);
+    let mut values = Vec::with_capacity(bones.len() + vertices.len());
+    let mut bone_index = 0;
+    let mut vertex_index = 0;
+    while bone_index < bones.len() {
+        let bones_count = bones[bone_index] as usize;
+        values.push(bones_count as f32);
+        for bone in &bones[bone_index + 1..bone_index + 1 + bones_count] {
+            values.push(*bone as f32);
+            values.extend_from_slice(&vertices[vertex_index..vertex_index + 3]);
+            vertex_index += 3;
+        }
+        bone_index += bones_count + 1;
+    }
+    write_numbers(object.key("vertices"), values.into_iter());
+}
+
+unsafe fn write_timeline(
+    tree: &mut JsonTree,
+    data: &spSkeletonData,
+    timeline: *mut spTimeline,
+    skin_entries: &SkinEntries,
+) {
+    let bone_name = |index: i32| c_str((**data.bones.offset(index as isize)).name);
+    let slot_name = |index: i32| c_str((**data.slots.offset(index as isize)).name);
+    let physics_constraint_name = |index: i32| {
+        if index < 0 {
+            ""
+        } else {
+            c_str((**data.physicsConstraints.add(index as usize)).name)
+        }
+    };
+    let mut out = String::new();
+    let path: [&str; 3] = match (*timeline).type_0 {
+        SP_TIMELINE_ROTATE => {
+            let timeline = &*timeline.cast::<spRotateTimeline>();
+            write_curve_frames(&mut out, &timeline.super_0, &["value"]);
+            ["bones", bone_name(timeline.boneIndex), "rotate"]
+        }
+        SP_TIMELINE_TRANSLATE => {
+            let timeline = &*timeline.cast::<spTranslateTimeline>();
+            write_curve_frames(&mut out, &timeline.super_0, &["x", "y"]);
+            ["bones", bone_name(timeline.boneIndex), "translate"]
+        }
+        SP_TIMELINE_TRANSLATEX => {
+            let timeline = &*timeline.cast::<spTranslateXTimeline>();
+            write_curve_frames(&mut out, &timeline.super_0, &["value"]);
+            ["bones", bone_name(timeline.boneIndex), "translatex"]
+        }
+        SP_TIMELINE_TRANSLATEY => {
+            let timeline = &*timeline.cast::<spTranslateYTimeline>();
+            write_curve_frames(&mut out, &timeline.super_0, &["value"]);
+            ["bones", bone_name(timeline.boneIndex), "translatey"]
+        }
+        SP_TIMELINE_SCALE => {
+            let timeline = &*timeline.cast::<spScaleTimeline>();
+            write_curve_frames(&mut out, &timeline.super_0, &["x", "y"]);
+            ["bones", bone_name(timeline.boneIndex), "scale"]
+        }
+        SP_TIMELINE_SCALEX => {
+            let timeline = &*timeline.cast::<spScaleXTimeline>();
+            write_curve_frames(&mut out, &timeline.super_0, &["value"]);
+            ["bones", bone_name(timeline.boneIndex), "scalex"]
+        }
+        SP_TIMELINE_SCALEY => {
+            let timeline = &*timeline.cast::<spScaleYTimeline>();
+            write_curve_frames(&mut out, &timeline.super_0, &["value"]);
+            ["bones", bone_name(timeline.boneIndex), "scaley"]
+        }
+        SP_TIMELINE_SHEAR => {
+            let timeline = &*timeline.cast::<spShearTimeline>();
+            write_curve_frames(&mut out, &timeline.super_0, &["x", "y"]);
+            ["bones", bone_name(timeline.boneIndex), "shear"]
+        }
+        SP_TIMELINE_SHEARX => {
+            let timeline = &*timeline.cast::<spShearXTimeline>();
+            write_curve_frames(&mut out, &timeline.super_0, &["value"]);
+            ["bones", bone_name(timeline.boneIndex), "shearx"]
+        }
+        SP_TIMELINE_SHEARY => {
+            let timeline = &*timeline.cast::<spShearYTimeline>();
+            write_curve_frames(&mut out, &timeline.super_0, &["value"]);
+            ["bones", bone_name(timeline.boneIndex), "sheary"]
+        }
+        SP_TIMELINE_INHERIT => {
+            let timeline = &*timeline.cast::<spInheritTimeline>();
+            write_frames(&mut out, &timeline.super_0, |object, frame| {
+                object.string("value", INHERIT_NAMES[frame[1] as usize]);
+            });
+            ["bones", bone_name(timeline.boneIndex), "inherit"]
+        }
+        SP_TIMELINE_RGBA => {
+            let timeline = &*timeline.cast::<spRGBATimeline>();
+            write_color_frames(&mut out, &timeline.super_0, 4, &[("color", 1, true)]);
+            ["slots", slot_name(timeline.slotIndex), "rgba"]
+        }
+        SP_TIMELINE_RGB => {
+            let timeline = &*timeline.cast::<spRGBTimeline>();
+            write_color_frames(&mut out, &timeline.super_0, 3, &[("color", 1, false)]);
+            ["slots", slot_name(timeline.slotIndex), "rgb"]
+        }
+        SP_TIMELINE_RGBA2 => {
+            let timeline = &*timeline.cast::<spRGBA2Timeline>();
+            write_color_frames(
+                &mut out,
+                &timeline.super_0,
+                7,
+                &[("light", 1, true), ("dark", 5, false)],
+            );
+            ["slots", slot_name(timeline.slotIndex), "rgba2"]
+        }
+        SP_TIMELINE_RGB2 => {
+            let timeline = &*timeline.cast::<spRGB2Timeline>();
+            write_color_frames(
+                &mut out,
+                &timeline.super_0,
+                6,
+                &[("light", 1, false), ("dark", 4, false)],
+            );
+            ["slots", slot_name(timeline.slotIndex), "rgb2"]
+        }
+        SP_TIMELINE_ALPHA => {
+            let timeline = &*timeline.cast::<spAlphaTimeline>();
+            write_curve_frames(&mut out, &timeline.super_0, &["value"]);
+            ["slots", slot_name(timeline.slotIndex), "alpha"]
+        }
+        SP_TIMELINE_ATTACHMENT => {
+            let timeline = &*timeline.cast::<spAttachmentTimeline>();
+            let mut frame_index = 0;
+            write_frames(&mut out, &timeline.super_0, |object, _| {
+                if let Some(name) = c_str_optional(*timeline.attachmentNames.add(frame_index)) {
+                    object.string("name", name);
+                }
+                frame_index += 1;
+            });
+            ["slots", slot_name(timeline.slotIndex), "attachment"]
+        }
+        SP_TIMELINE_IKCONSTRAINT => {
+            let timeline = &*timeline.cast::<spIkConstraintTimeline>();
+            write_curve_frames_with(&mut out, &timeline.super_0, 2, |object, frame| {
+                object.number("mix", frame[1]);
+                object.number("softness", frame[2]);
+                object.boolean("bendPositive", frame[3] > 0.);
+                object.boolean("compress", frame[4] != 0.);
+                object.boolean("stretch", frame[5] != 0.);
+            });
+            tree.insert(
+                &[
+                    "ik",
+                    c_str(
+                        (**data
+                            .ikConstraints
+                            .offset(timeline.ikConstraintIndex as isize))
+                        .name,
+                    ),
+                ],
+                out,
+            );
+            return;
+        }
+        SP_TIMELINE_TRANSFORMCONSTRAINT => {
+            let timeline = &*timeline.cast::<spTransformConstraintTimeline>();
+            write_curve_frames(
+                &mut out,
+                &timeline.super_0,
+                &[
+                    "mixRotate",
+                    "mixX",
+                    "mixY",
+                    "mixScaleX",
+                    "mixScaleY",
+                    "mixShearY",
+                ],
+            );
+            tree.insert(
+                &[
+                    "transform",
+                    c_str(
+                        (**data
+                            .transformConstraints
+                            .offset(timeline.transformConstraintIndex as isize))
+                        .name,
+                    ),
+                ],
+                out,
+            );
+            return;
+        }
+        SP_TIMELINE_PATHCONSTRAINTPOSITION | SP_TIMELINE_PATHCONSTRAINTSPACING => {
+            let timeline = &*timeline.cast::<spPathConstraintPositionTimeline>();
+            write_curve_frames(&mut out, &timeline.super_0, &["value"]);
+            [
+                "path",
+                c_str(
+                    (**data
+                        .pathConstraints
+                        .offset(timeline.pathConstraintIndex as isize))
+                    .name,
+                ),
+                if timeline.super_0.super_0.type_0 == SP_TIMELINE_PATHCONSTRAINTPOSITION {
+                    "position"
+                } else {
+                    "spacing"
+                },
+            ]
+        }
+        SP_TIMELINE_PATHCONSTRAINTMIX => {
+            let timeline = &*timeline.cast::<spPathConstraintMixTimeline>();
+            write_curve_frames(&mut out, &timeline.super_0, &["mixRotate", "mixX", "mixY"]);
+            [
+                "path",
+                c_str(
+                    (**data
+                        .pathConstraints
+                        .offset(timeline.pathConstraintIndex as isize))
+                    .name,
+                ),
+                "mix",
+            ]
+        }
+        timeline_type @ SP_TIMELINE_PHYSICSCONSTRAINT_INERTIA
+            ..=SP_TIMELINE_PHYSICSCONSTRAINT_MIX => {
+            let timeline = &*timeline.cast::<spPhysicsConstraintTimeline>();
+            write_curve_frames(&mut out, &timeline.super_0, &["value"]);
+            [
+                "physics",
+                physics_constraint_name(timeline.physicsConstraintIndex),
+                match timeline_type {
+                    SP_TIMELINE_PHYSICSCONSTRAINT_INERTIA => "inertia",
+                    SP_TIMELINE_PHYSICSCONSTRAINT_STRENGTH => "strength",
+                    SP_TIMELINE_PHYSICSCONSTRAINT_DAMPING => "damping",
+                    SP_TIMELINE_PHYSICSCONSTRAINT_MASS => "mass",
+                    SP_TIMELINE_PHYSICSCONSTRAINT_WIND => "wind",
+                    SP_TIMELINE_PHYSICSCONSTRAINT_GRAVITY => "gravity",
+                    _ => "mix",
+                },
+            ]
+        }
+        SP_TIMELINE_PHYSICSCONSTRAINT_RESET => {
+            let timeline = &*timeline.cast::<spPhysicsConstraintResetTimeline>();
+            write_frames(&mut out, &timeline.super_0, |_, _| {});
+            [
+                "physics",
+                physics_constraint_name(timeline.physicsConstraintIndex),
+                "reset",
+            ]
+        }
+        SP_TIMELINE_DEFORM => {
+            let timeline = &*timeline.cast::<spDeformTimeline>();
+            let Some((skin_name, _, entry_name)) = skin_entries.get(timeline.attachment) else {
+                return;
+            };
+            write_deform_frames(&mut out, timeline);
+            tree.insert(
+                &[
+                    "attachments",
+                    skin_name,
+                    slot_name(timeline.slotIndex),
+                    entry_name,
+                    "deform",
+                ],
+                out,
+            );
+            return;
+        }
+        SP_TIMELINE_SEQUENCE => {
+            let timeline = &*timeline.cast::<spSequenceTimeline>();
+            let Some((skin_name, _, entry_name)) = skin_entries.get(timeline.attachment) else {
+                return;
+            };
+            write_frames(&mut out, &timeline.super_0, |object, frame| {
+                let mode_and_index = frame[1] as i32;
+                object.string("mode", SEQUENCE_MODE_NAMES[(mode_and_index & 0xf) as usize]);
+                object.number("index", (mode_and_index >> 4) as f32);
+                object.number("delay", frame[2]);
+            });
+            tree.insert(
+                &[
+                    "attachments",
+                    skin_name,
+                    slot_name(timeline.slotIndex),
+                    entry_name,
+                    "sequence",
+                ],
+                out,
+            );
+            return;
+        }
+        SP_TIMELINE_DRAWORDER => {
+            let timeline = &*timeline.cast::<spDrawOrderTimeline>();
+            let mut frame_index = 0;
+            write_frames(&mut out, &timeline.super_0, |object, _| {
+                let draw_order = *timeline.drawOrders.add(frame_index);
+                if !draw_order.is_null() {
+                    write_draw_order_offsets(object, data, slice(draw_order, timeline.slotsCount));
+                }
+                frame_index += 1;
+            });
+            tree.insert(&["drawOrder"], out);
+            return;
+        }
+        SP_TIMELINE_EVENT => {
+            let timeline = &*timeline.cast::<spEventTimeline>();
+            let mut frame_index = 0;
+            write_frames(&mut out, &timeline.super_0, |object, _| {
+                let event = &**timeline.events.add(frame_index);
+                object.string("name", c_str((*event.data).name));
+                object.number("int", event.intValue as f32);
+                object.number("float", event.floatValue);
+                if let Some(string) = c_str_optional(event.stringValue) {
+                    object.string("string", string);
+                }
+                if !(*event.data).audioPath.is_null() {
+                    object.number("volume", event.volume);
+                    object.number("balance", event.balance);
+                }
+                frame_index += 1;
+            });
+            tree.insert(&["events"], out);
+            return;
+        }
+        _ => return,
+    };
+    tree.insert(&path, out);
+}
+
+/// Writes the frames of any timeline as an array of objects with a `time`, calling `f` with
+/// each frame's entries to write the rest of the frame.
+unsafe fn write_frames<F>(out: &mut String, timeline: &spTimeline, mut f: F)
+where
+    F: FnMut(&mut ObjectWriter, &[f32]),
+{
+    let frames = slice((*timeline.frames).items, (*timeline.frames).size);
+    let frame_entries = timeline.frameEntries as usize;
+    out.push('[');
+    for (index, frame) in frames.chunks_exact(frame_entries).enumerate() {
+        if index > 0 {
+            out.push(',');
+        }
+        let mut object = ObjectWriter::new(out);
+        object.number("time", frame[0]);
+        f(&mut object, frame);
+        object.end();
+    }
+    out.push(']');
+}
+
+/// Writes the frames of a curve timeline whose values are each written under a key in `names`.
+unsafe fn write_curve_frames(out: &mut String, timeline: &spCurveTimeline, names: &[&str]) {
+    write_curve_frames_with(out, timeline, names.len(), |object, frame| {
+        for (name, value) in names.iter().zip(&frame[1..]) {
+            object.number(name, *value);
+        }
+    });
+}
+
+/// Writes the frames of a curve timeline whose values are colors, where `colors` lists the key,
+/// first frame entry, and whether the color has alpha for each color.
+unsafe fn write_color_frames(
+    out: &mut String,
+    timeline: &spCurveTimeline,
+    curves_count: usize,
+    colors: &[(&str, usize, bool)],
+) {
+    write_curve_frames_with(out, timeline, curves_count, |object, frame| {
+        for (name, start, alpha) in colors {
+            let color = Color::new_rgba(
+                frame[*start],
+                frame[start + 1],
+                frame[start + 2],
+                if *alpha { frame[start + 3] } else { 1. },
+            );
+            let hex = color_hex(color);
+            object.string(name, if *alpha { &hex } else { &hex[..6] });
+        }
+    });
+}
+
+/// Writes the frames of a curve timeline with `curves_count` curved values, which are the frame
+/// entries after the time.
+unsafe fn write_curve_frames_with<F>(
+    out: &mut String,
+    timeline: &spCurveTimeline,
+    curves_count: usize,
+    mut f: F,
+) where
+    F: FnMut(&mut ObjectWriter, &[f32]),
+{
+    let frame_entries = timeline.super_0.frameEntries as usize;
+    let frames = slice(
+        (*timeline.super_0.frames).items,
+        (*timeline.super_0.frames).size,
+    );
+    let mut frame_index = 0;
+    write_frames(out, &timeline.super_0, |object, frame| {
+        f(object, frame);
+        if let Some(next_frame) = frames.get((frame_index + 1) * frame_entries..) {
+            write_curve(
+                object,
+                timeline,
+                frame_index,
+                curves_count,
+                |value_index| (frame[value_index + 1], next_frame[value_index + 1]),
+                frame[0],
+                next_frame.first().copied().unwrap_or_default(),
+            );
+        }
+        frame_index += 1;
+    });
+}
+
+/// Writes the frames of a deform timeline, whose curve goes from 0 to 1 between frames.
+unsafe fn write_deform_frames(out: &mut String, timeline: &spDeformTimeline) {
+    let curve_timeline = &timeline.super_0;
+    let vertex_attachment = &*timeline.attachment.cast::<spVertexAttachment>();
+    let setup_vertices = if vertex_attachment.bones.is_null() {
+        slice(vertex_attachment.vertices, vertex_attachment.verticesCount)
+    } else {
+        &[]
+    };
+    let frames = slice(
+        (*curve_timeline.super_0.frames).items,
+        (*curve_timeline.super_0.frames).size,
+    );
+    let mut frame_index = 0;
+    write_frames(out, &curve_timeline.super_0, |object, frame| {
+        let vertices = slice(
+            *timeline.frameVertices.add(frame_index),
+            timeline.frameVerticesCount,
+        );
+        object.number("offset", 0.);
+        write_numbers(
+            object.key("vertices"),
+            vertices.iter().enumerate().map(|(index, vertex)| {
+                vertex - setup_vertices.get(index).copied().unwrap_or_default()
+            }),
+        );
+        if let Some(next_time) = frames.get(frame_index + 1) {
+            write_curve(
+                object,
+                curve_timeline,
+                frame_index,
+                1,
+                |_| (0., 1.),
+                frame[0],
+                *next_time,
+            );
+        }
+        frame_index += 1;
+    });
+}
+
+/// Writes the curve between a frame and the next frame, if it isn't linear. Spine stores bezier
+/// curves as 9 points sampled along the curve, so the control points are solved for from the
+/// samples at 30% and 70%.
+unsafe fn write_curve<F>(
+    object: &mut ObjectWriter,
+    timeline: &spCurveTimeline,
+    frame_index: usize,
+    curves_count: usize,
+    values: F,
+    time1: f32,
+    time2: f32,
+) where
+    F: Fn(usize) -> (f32, f32),
+{
+    const STEPPED: f32 = 1.;
+    const BEZIER: f32 = 2.;
+    const BEZIER_SIZE: usize = 18;
+    let curves = slice((*timeline.curves).items, (*timeline.curves).size);
+    let Some(curve_type) = curves.get(frame_index).copied() else {
+        return;
+    };
+    if curve_type == STEPPED {
+        object.string("curve", "stepped");
+        return;
+    }
+    if curve_type < BEZIER {
+        return;
+    }
+    let start = (curve_type - BEZIER) as usize;
+    let mut control_points = Vec::with_capacity(curves_count * 4);
+    for value_index in 0..curves_count {
+        let (value1, value2) = values(value_index);
+        let samples = &curves[start + value_index * BEZIER_SIZE..][..BEZIER_SIZE];
+        let (cx1, cx2) = solve_control_points(time1, time2, samples[4], samples[12]);
+        let (cy1, cy2) = solve_control_points(value1, value2, samples[5], samples[13]);
+        control_points.extend([cx1, cy1, cx2, cy2]);
+    }
+    write_numbers(object.key("curve"), control_points.into_iter());
+}
+
+/// Solves for the control points of a one dimensional cubic bezier curve from `start` to `end`
+/// passing through `at_30` and `at_70` at t=0.3 and t=0.7.
+fn solve_control_points(start: f32, end: f32, at_30: f32, at_70: f32) -> (f32, f32) {
+    // b(t) = (1-t)^3 * start + 3(1-t)^2 t * c1 + 3(1-t) t^2 * c2 + t^3 * end
+    let (a, b) = (0.441_f64, 0.189_f64);
+    let (start, end) = (f64::from(start), f64::from(end));
+    let r1 = f64::from(at_30) - 0.343 * start - 0.027 * end;
+    let r2 = f64::from(at_70) - 0.027 * start - 0.343 * end;
+    let determinant = a * a - b * b;
+    let c1 = (r1 * a - r2 * b) / determinant;
+    let c2 = (r2 * a - r1 * b) / determinant;
+    (c1 as f32, c2 as f32)
+}
+
+/// Writes the offsets which move each slot from its setup pose position to its position in
+/// `draw_order`, which lists the slot indices in draw order.
+unsafe fn write_draw_order_offsets(
+    object: &mut ObjectWriter,
+    data: &spSkeletonData,
+    draw_order: &[i32],
+) {
+    let mut positions = vec![0; draw_order.len()];
+    for (position, slot_index) in draw_order.iter().enumerate() {
+        positions[*slot_index as usize] = position;
+    }
+    // Slots without an offset fill the remaining positions in order, so only listing the moved
+    // slots doesn't always reproduce the draw order. If it doesn't, list every slot.
+    let moved: Vec<usize> = (0..positions.len())
+        .filter(|slot_index| positions[*slot_index] != *slot_index)
+        .collect();
+    let slot_indices = if apply_draw_order_offsets(&positions, &moved) == draw_order {
+        moved
+    } else {
+        (0..positions.len()).collect()
+    };
+    let out = object.key("offsets");
+    out.push('[');
+    for (index, slot_index) in slot_indices.into_iter().enumerate() {
+        if index > 0 {
+            out.push(',');
+        }
+        let mut offset = ObjectWriter::new(out);
+        offset.string("slot", c_str((**data.slots.add(slot_index)).name));
+        offset.number("offset", positions[slot_index] as f32 - slot_index as f32);
+        offset.end();
+    }
+    out.push(']');
+}
+
+/// Builds a draw order from offsets the same way the JSON loader does.
+fn apply_draw_order_offsets(positions: &[usize], slot_indices: &[usize]) -> Vec<i32> {
+    let mut draw_order = vec![-1; positions.len()];
+    let mut unchanged = vec![];
+    let mut original_index = 0;
+    for slot_index in slot_indices {
+        while original_index != *slot_index {
+            unchanged.push(original_index);
+            original_index += 1;
+        }
+        draw_order[positions[*slot_index]] = *slot_index as i32;
+        original_index += 1;
+    }
+    unchanged.extend(original_index..positions.len());
+    for position in (0..draw_order.len()).rev() {
+        if draw_order[position] == -1 {
+            draw_order[position] = unchanged.pop().map_or(-1, |index| index as i32);
+        }
+    }
+    draw_order
+}
+
+fn write_array<T, F>(out: &mut String, items: &[T], mut f: F)
+where
+    F: FnMut(&mut String, &T),
+{
+    out.push('[');
+    for (index, item) in items.iter().enumerate() {
+        if index > 0 {
+            out.push(',');
+        }
+        f(out, item);
+    }
+    out.push(']');
+}
+
+fn write_numbers(out: &mut String, values: impl Iterator<Item = f32>) {
+    out.push('[');
+    for (index, value) in values.enumerate() {
+        if index > 0 {
+            out.push(',');
+        }
+        write_number(out, value);
+    }
+    out.push(']');
+}
+
+const fn color(color: &spColor) -> Color {
+    Color::new_rgba(color.r, color.g, color.b, color.a)
+}
+
+const unsafe fn slice<'a, T>(items: *const T, count: i32) -> &'a [T] {
+    if items.is_null() || count <= 0 {
+        &[]
+    } else {
+        core::slice::from_raw_parts(items, count as usize)
+    }
+}
+
+unsafe fn c_str<'a>(string: *const c_char) -> &'a str {
+    c_str_optional(string).unwrap_or_default()
+}
+
+unsafe fn c_str_optional<'a>(string: *const c_char) -> Option<&'a str> {
+    if string.is_null() {
+        None
+    } else {
+        CStr::from_ptr(string).to_str().ok()
+    }
+}
+
+const INHERIT_NAMES: [&str; 5] = [
+    "normal",
+    "onlyTranslation",
+    "noRotationOrReflection",
+    "noScale",
+    "noScaleOrReflection",
+];
+const BLEND_MODE_NAMES: [&str; 4] = ["normal", "additive", "multiply", "screen"];
+const POSITION_MODE_NAMES: [&str; 2] = ["fixed", "percent"];
+const SPACING_MODE_NAMES: [&str; 4] = ["length", "fixed", "percent", "proportional"];
+const ROTATE_MODE_NAMES: [&str; 3] = ["tangent", "chain", "chainScale"];
+const SEQUENCE_MODE_NAMES: [&str; 7] = [
+    "hold",
+    "once",
+    "loop",
+    "pingpong",
+    "onceReverse",
+    "loopReverse",
+    "pingpongReverse",
+];
+
+#[cfg(test)]
+mod tests {
+    use alloc::sync::Arc;
+
+    use crate::{test::TestAsset, AnimationState, AnimationStateData, Physics, Skeleton};
+
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        for test_asset in TestAsset::all() {
+            for json in [true, false] {
+                let skeleton_data = Arc::new(test_asset.skeleton_data(json));
+                let exported = to_json(&skeleton_data).unwrap();
+                let reimported = Arc::new(
+                    test_asset
+                        .skeleton_json()
+                        .read_skeleton_data(exported.as_bytes())
+                        .unwrap_or_else(|error| {
+                            panic!("{}: {error}", test_asset.atlas_file);
+                        }),
+                );
+                assert_eq!(skeleton_data.bones_count(), reimported.bones_count());
+                assert_eq!(skeleton_data.slots_count(), reimported.slots_count());
+                assert_eq!(skeleton_data.skins_count(), reimported.skins_count());
+                assert_eq!(
+                    skeleton_data.animations_count(),
+                    reimported.animations_count()
+                );
+                for animation in skeleton_data.animations() {
+                    let mut original = Skeleton::new(skeleton_data.clone());
+                    let mut copy = Skeleton::new(reimported.clone());
+                    for fraction in [0., 0.37, 0.81] {
+                        let time = animation.duration() * fraction;
+                        for (skeleton, data) in
+                            [(&mut original, &skeleton_data), (&mut copy, &reimported)]
+                        {
+                            let mut animation_state = AnimationState::new(Arc::new(
+                                AnimationStateData::new(data.clone()),
+                            ));
+                            animation_state
+                                .set_animation_by_name(0, animation.name(), false)
+                                .unwrap();
+                            animation_state.update(time);
+                            skeleton.set_to_setup_pose();
+                            animation_state.apply(skeleton);
+                            skeleton.update_world_transform(Physics::None);
+                        }
+                        for (bone, copy_bone) in original.bones().zip(copy.bones()) {
+                            for (value, copy_value) in [
+                                (bone.world_x(), copy_bone.world_x()),
+                                (bone.world_y(), copy_bone.world_y()),
+                                (bone.a(), copy_bone.a()),
+                                (bone.d(), copy_bone.d()),
+                            ] {
+                                assert!(
+                                    (value - copy_value).abs() <= 0.01 * value.abs().max(1.),
+                                    "{} {} {}: {value} != {copy_value}",
+                                    test_asset.atlas_file,
+                                    animation.name(),
+                                    bone.data().name()
+                                );
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn strip_animations() {
+        let skeleton_data = TestAsset::spineboy().skeleton_data(true);
+        let settings = SkeletonExportSettings::new().with_animations(["walk"]);
+        let exported = to_json_with_settings(&skeleton_data, &settings).unwrap();
+        let reimported = TestAsset::spineboy()
+            .skeleton_json()
+            .read_skeleton_data(exported.as_bytes())
+            .unwrap();
+        assert_eq!(reimported.animations_count(), 1);
+        assert!(reimported.find_animation("walk").is_some());
+        assert!(to_json_with_settings(
+            &skeleton_data,
+            &SkeletonExportSettings::new().with_animations(["missing"])
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn draw_order_offsets() {
+        let positions = [1, 0, 2, 4, 3];
+        let moved = [0, 1, 3, 4];
+        assert_eq!(
+            apply_draw_order_offsets(&positions, &moved),
+            vec![1, 0, 2, 4, 3]
+        );
+    }
+
+    #[test]
+    fn bezier_control_points() {
+        let (c1, c2) = solve_control_points(0., 1., 0.216, 0.784);
+        assert!(c1.abs() < 1e-5);
+        assert!((c2 - 1.).abs() < 1e-5);
+    }
+}
@@ -1,13 +1,14 @@
-use std::{
-    ffi::{CStr, CString},
-    path::Path,
-    sync::Arc,
-};
+use alloc::{ffi::CString, sync::Arc};
+use core::ffi::CStr;
+#[cfg(feature = "std")]
+use std::path::Path;
 
+#[cfg(feature = "std")]
+use crate::c::spSkeletonJson_readSkeletonDataFile;
 use crate::{
     c::{
         spSkeletonJson, spSkeletonJson_create, spSkeletonJson_dispose,
-        spSkeletonJson_readSkeletonData, spSkeletonJson_readSkeletonDataFile,
+        spSkeletonJson_readSkeletonData,
     },
     c_interface::{from_c_str, SyncPtr},
     error::SpineError,
@@ -57,7 +58,8 @@ impl SkeletonJson {
     ///
     /// # Errors
     ///
-    /// Returns [`SpineError::ParsingFailed`] if parsing of the json data failed.
+    /// Returns [`SpineError::ParsingFailed`] if parsing of the json data failed, including
+    /// data exported by an unsupported version of Spine.
     pub fn read_skeleton_data(&self, json: &[u8]) -> Result<SkeletonData, SpineError> {
         let c_json = CString::new(json)?;
         let c_skeleton_data =
@@ -66,7 +68,10 @@ impl SkeletonJson {
             Ok(SkeletonData::new(c_skeleton_data, self.atlas.clone()))
         } else {
             let c_error = unsafe { CStr::from_ptr((*self.c_skeleton_json.0).error) };
-            Err(SpineError::new_from_spine(from_c_str(c_error)))
+            Err(SpineError::new_skeleton_parsing_failed(
+                from_c_str(c_error),
+                None,
+            ))
         }
     }
 
@@ -77,6 +82,7 @@ impl SkeletonJson {
     /// Returns [`SpineError::ParsingFailed`] if parsing of the json data failed. Returns
     /// [`SpineError::NulError`] if `path` contains an internal 0 byte. Returns
     /// [`SpineError::PathNotUtf8`] if the specified path is not utf-8.
+    #[cfg(feature = "std")]
     pub fn read_skeleton_data_file<P: AsRef<Path>>(
         &self,
         path: P,
@@ -91,7 +97,10 @@ impl SkeletonJson {
             Ok(SkeletonData::new(c_skeleton_data, self.atlas.clone()))
         } else {
             let c_error = unsafe { CStr::from_ptr((*self.c_skeleton_json.0).error) };
-            Err(SpineError::new_from_spine(from_c_str(c_error)))
+            Err(SpineError::new_skeleton_parsing_failed(
+                from_c_str(c_error),
+                Some(path_str),
+            ))
         }
     }
 
@@ -1,3 +1,4 @@
+use crate::prelude::*;
 use crate::{
     c::{
         spSkeletonData, spSkin, spSkin_addSkin, spSkin_copySkin, spSkin_create, spSkin_dispose,
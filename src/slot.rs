@@ -4,10 +4,12 @@ use crate::{
     c::{
         spAttachment, spBlendMode, spBone, spBoneData, spBoundingBoxAttachment,
         spClippingAttachment, spMeshAttachment, spPointAttachment, spRegionAttachment, spSkeleton,
-        spSlot, spSlotData, spSlotData_setAttachmentName, spSlot_setAttachment,
-        spSlot_setToSetupPose,
+        spSkeleton_getAttachmentForSlotIndex, spSlot, spSlotData, spSlotData_setAttachmentName,
+        spSlot_setAttachment, spSlot_setToSetupPose,
     },
     c_interface::{to_c_str, CTmpRef, NewFromPtr, SyncPtr},
+    error::SpineError,
+    skeleton_data::check_skeleton_data_frozen,
     AttachmentType, BoneData, BoundingBoxAttachment, ClippingAttachment, MeshAttachment,
     PointAttachment, RegionAttachment, Skeleton,
 };
@@ -58,11 +60,13 @@ impl Slot {
     ///
     /// # Safety
     ///
-    /// The attachment must be compatible with this slot, usually by originating from it.
+    /// The attachment must be compatible with this slot, usually by originating from it, and
+    /// must outlive its use by the slot. See [`Skeleton::swap_attachment`] for a safe
+    /// alternative.
     pub unsafe fn set_attachment(&mut self, attachment: Option<Attachment>) {
         attachment.map_or_else(
             || {
-                spSlot_setAttachment(self.c_ptr(), std::ptr::null_mut());
+                spSlot_setAttachment(self.c_ptr(), core::ptr::null_mut());
             },
             |attachment| {
                 spSlot_setAttachment(self.c_ptr(), attachment.c_ptr());
@@ -70,6 +74,54 @@ impl Slot {
         );
     }
 
+    /// Sets the attachment for this slot by name, looked up in the skeleton's skin and then its
+    /// default skin, or clears it with [`None`]. This is a safe way to do simple costume swaps
+    /// without constructing a [`Skin`](`crate::Skin`).
+    ///
+    /// ```
+    /// # #[path="./test.rs"]
+    /// # mod test;
+    /// # let (mut skeleton, _) = test::TestAsset::spineboy().instance(true);
+    /// let mut slot = skeleton.find_slot_mut("eye").unwrap();
+    /// slot.set_attachment_by_name(Some("eye-surprised"))?;
+    /// # Ok::<(), rusty_spine::SpineError>(())
+    /// ```
+    ///
+    /// The slot's deform buffer is kept if it belongs to the new attachment, such as when
+    /// assigning the same attachment again or switching between linked meshes, and is otherwise
+    /// cleared.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SpineError::NotFound`] if no attachment with the name exists for this slot. The
+    /// slot is left unchanged.
+    pub fn set_attachment_by_name(
+        &mut self,
+        attachment_name: Option<&str>,
+    ) -> Result<(), SpineError> {
+        let c_attachment = match attachment_name {
+            Some(attachment_name) => {
+                let c_attachment_name = to_c_str(attachment_name);
+                let c_attachment = unsafe {
+                    spSkeleton_getAttachmentForSlotIndex(
+                        self.bone().c_ptr_mut().skeleton,
+                        self.data().index() as i32,
+                        c_attachment_name.as_ptr(),
+                    )
+                };
+                if c_attachment.is_null() {
+                    return Err(SpineError::new_not_found("Attachment", attachment_name));
+                }
+                c_attachment
+            }
+            None => core::ptr::null_mut(),
+        };
+        unsafe {
+            spSlot_setAttachment(self.c_ptr(), c_attachment);
+        }
+        Ok(())
+    }
+
     /// Sets this slot to the setup pose.
     pub fn set_to_setup_pose(&mut self) {
         unsafe {
@@ -158,14 +210,15 @@ impl Slot {
         dark_color,
         darkColor
     );
-    c_accessor_tmp_ptr_mut!(
+    c_accessor_tmp_ptr_data_mut!(
         /// The slot's setup pose data.
         data,
         /// The slot's mutable setup pose data.
         data_mut,
         data,
         SlotData,
-        spSlotData
+        spSlotData,
+        Self::check_data_frozen
     );
     c_accessor_tmp_ptr_mut!(
         /// The bone this slot belongs to.
@@ -186,9 +239,70 @@ impl Slot {
         spAttachment
     );
     c_ptr!(c_slot, spSlot);
+
+    fn check_data_frozen(&self) -> Result<(), SpineError> {
+        check_skeleton_data_frozen(unsafe { (*(*self.c_ptr_ref().bone).skeleton).data })
+    }
     c_accessor!(sequence_index, sequenceIndex, usize);
+    c_accessor!(
+        /// The number of floats in this slot's deform buffer.
+        deform_count,
+        deformCount,
+        usize
+    );
+
+    /// Vertex offsets applied by deform timelines to the slot's vertex attachment (such as a
+    /// [`MeshAttachment`]), or an empty slice if the current attachment is not deformed.
+    ///
+    /// For unweighted meshes the offsets are applied once per vertex, for weighted meshes they are
+    /// applied once per bone influence. In both cases they are laid out as `x, y` pairs.
+    #[must_use]
+    pub fn deform_slice(&self) -> &[f32] {
+        let deform = unsafe { self.c_ptr_ref().deform };
+        if deform.is_null() {
+            &[]
+        } else {
+            unsafe { core::slice::from_raw_parts(deform, self.deform_count()) }
+        }
+    }
+
+    /// Clears the slot's deform buffer, so the current attachment is drawn undeformed until a
+    /// deform timeline is applied to the slot again.
+    pub fn clear_deform(&mut self) {
+        unsafe { self.c_ptr_mut().deformCount = 0 };
+    }
+
+    /// Mutable vertex offsets applied by deform timelines. See
+    /// [`deform_slice`](`Self::deform_slice`).
+    ///
+    /// Changes are overwritten the next time a deform timeline is applied to this slot.
+    #[must_use]
+    pub fn deform_slice_mut(&mut self) -> &mut [f32] {
+        let deform = unsafe { self.c_ptr_ref().deform };
+        if deform.is_null() {
+            &mut []
+        } else {
+            unsafe { core::slice::from_raw_parts_mut(deform, self.deform_count()) }
+        }
+    }
 
-    // TODO: accessors for deform
+    /// An iterator over the `(x, y)` pairs of [`deform_slice`](`Self::deform_slice`).
+    ///
+    /// ```
+    /// # #[path="./test.rs"]
+    /// # mod test;
+    /// # let (skeleton, animation_state) = test::TestAsset::spineboy().instance(true);
+    /// for slot in skeleton.slots() {
+    ///     for (x, y) in slot.deform_vertices() {
+    ///         println!("{}: offset ({x}, {y})", slot.data().name());
+    ///     }
+    /// }
+    /// ```
+    pub fn deform_vertices(&self) -> impl Iterator<Item = (f32, f32)> + '_ {
+        self.deform_slice()
+            .chunks_exact(2)
+            .map(|offset| (offset[0], offset[1]))
+    }
 }
 
 c_handle_decl!(
@@ -300,3 +414,36 @@ impl From<spBlendMode> for BlendMode {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::test::TestAsset;
+
+    #[test]
+    fn deform() {
+        let (mut skeleton, mut animation_state) = TestAsset::spineboy().instance(true);
+        let slot = skeleton.find_slot("front-foot").unwrap();
+        assert_eq!(slot.deform_count(), 0);
+        assert!(slot.deform_slice().is_empty());
+
+        animation_state
+            .set_animation_by_name(0, "hoverboard", true)
+            .unwrap();
+        animation_state.update(0.5);
+        animation_state.apply(&mut skeleton);
+        let mut slot = skeleton.find_slot_mut("front-foot").unwrap();
+        let deform_count = slot.deform_count();
+        assert!(deform_count > 0);
+        assert_eq!(slot.deform_slice().len(), deform_count);
+        assert!(slot.deform_slice().iter().any(|offset| *offset != 0.));
+        let deform_vertices: Vec<(f32, f32)> = slot.deform_vertices().collect();
+        assert_eq!(deform_vertices.len(), deform_count / 2);
+        assert_eq!(
+            deform_vertices[1],
+            (slot.deform_slice()[2], slot.deform_slice()[3])
+        );
+
+        slot.deform_slice_mut()[0] = 12.;
+        assert_eq!(slot.deform_vertices().next().unwrap().0, 12.);
+    }
+}
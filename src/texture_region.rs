@@ -20,20 +20,106 @@ impl NewFromPtr<spTextureRegion> for TextureRegion {
     }
 }
 
+/// The quad of a trimmed [`TextureRegion`] placed within its untrimmed frame.
+///
+/// See [`TextureRegion::untrimmed_quad`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct UntrimmedQuad {
+    /// The size of the untrimmed frame, in pixels.
+    pub frame_size: [f32; 2],
+    /// The corners of the trimmed image relative to the bottom left of the untrimmed frame, in
+    /// pixels. Ordered bottom left, top left, top right, bottom right.
+    pub positions: [[f32; 2]; 4],
+    /// The texture coordinates of each corner in `positions`, accounting for rotation in the
+    /// atlas.
+    pub uvs: [[f32; 2]; 4],
+}
+
 impl TextureRegion {
     c_accessor!(u, u, f32);
     c_accessor!(v, v, f32);
     c_accessor!(u2, u2, f32);
     c_accessor!(v2, v2, f32);
-    c_accessor!(degrees, degrees, i32);
-    c_accessor!(offset_x, offsetX, f32);
-    c_accessor!(offset_y, offsetY, f32);
-    c_accessor!(width, width, i32);
-    c_accessor!(height, height, i32);
-    c_accessor!(original_width, originalWidth, i32);
-    c_accessor!(original_height, originalHeight, i32);
+    c_accessor!(
+        /// The rotation of the region in the atlas texture, in degrees.
+        degrees,
+        degrees,
+        i32
+    );
+    c_accessor!(
+        /// The amount of whitespace trimmed from the left of the original image, in pixels.
+        offset_x,
+        offsetX,
+        f32
+    );
+    c_accessor!(
+        /// The amount of whitespace trimmed from the bottom of the original image, in pixels.
+        offset_y,
+        offsetY,
+        f32
+    );
+    c_accessor!(
+        /// The width of the trimmed image, in pixels.
+        width,
+        width,
+        i32
+    );
+    c_accessor!(
+        /// The height of the trimmed image, in pixels.
+        height,
+        height,
+        i32
+    );
+    c_accessor!(
+        /// The width of the original image before whitespace was trimmed, in pixels.
+        original_width,
+        originalWidth,
+        i32
+    );
+    c_accessor!(
+        /// The height of the original image before whitespace was trimmed, in pixels.
+        original_height,
+        originalHeight,
+        i32
+    );
     c_accessor_renderer_object!();
     c_ptr!(c_texture_region, spTextureRegion);
+
+    /// Places the trimmed image within its original, untrimmed frame. Useful for drawing regions
+    /// outside of a skeleton (for instance, in UI previews) at their original size and alignment.
+    ///
+    /// ```
+    /// # #[path="./test.rs"]
+    /// # mod test;
+    /// # let atlas = test::TestAsset::spineboy().atlas();
+    /// let region = atlas.find_region("front-fist-open").unwrap();
+    /// let quad = region.texture_region().untrimmed_quad();
+    /// let [frame_width, frame_height] = quad.frame_size;
+    /// for (position, uv) in quad.positions.iter().zip(quad.uvs.iter()) {
+    ///     // draw the vertex centered in a `frame_width` by `frame_height` cell
+    /// }
+    /// ```
+    ///
+    /// The corner order and texture coordinates match those of
+    /// [`RegionAttachment`](`crate::RegionAttachment`).
+    #[must_use]
+    pub fn untrimmed_quad(&self) -> UntrimmedQuad {
+        let left = self.offset_x();
+        let bottom = self.offset_y();
+        let right = left + self.width() as f32;
+        let top = bottom + self.height() as f32;
+        let (u, v, u2, v2) = (self.u(), self.v(), self.u2(), self.v2());
+        let uvs = if self.degrees() == 90 {
+            [[u2, v], [u2, v2], [u, v2], [u, v]]
+        } else {
+            [[u2, v2], [u, v2], [u, v], [u2, v]]
+        };
+        UntrimmedQuad {
+            frame_size: [self.original_width() as f32, self.original_height() as f32],
+            positions: [[left, bottom], [left, top], [right, top], [right, bottom]],
+            uvs,
+        }
+    }
 }
 
 /// Functions available if using the `mint` feature.
@@ -77,3 +163,52 @@ impl TextureRegion {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::Atlas;
+
+    const ATLAS: &str = "test.png
+size: 64, 64
+filter: Linear, Linear
+trimmed
+bounds: 0, 0, 10, 20
+offsets: 2, 3, 16, 24
+rotated
+bounds: 32, 0, 10, 20
+rotate: 90
+";
+
+    #[test]
+    fn untrimmed_quad() {
+        let atlas = Atlas::new(ATLAS.as_bytes(), "").unwrap();
+
+        let region = atlas.find_region("trimmed").unwrap();
+        let texture_region = region.texture_region();
+        let quad = texture_region.untrimmed_quad();
+        assert_eq!(quad.frame_size, [16., 24.]);
+        assert_eq!(quad.positions, [[2., 3.], [2., 23.], [12., 23.], [12., 3.]]);
+        let (u, v, u2, v2) = (
+            texture_region.u(),
+            texture_region.v(),
+            texture_region.u2(),
+            texture_region.v2(),
+        );
+        assert_eq!(quad.uvs, [[u2, v2], [u, v2], [u, v], [u2, v]]);
+
+        // untrimmed regions fill their frame, and rotated regions rotate their texture coordinates
+        let region = atlas.find_region("rotated").unwrap();
+        let texture_region = region.texture_region();
+        let quad = texture_region.untrimmed_quad();
+        assert_eq!(texture_region.degrees(), 90);
+        assert_eq!(quad.frame_size, [10., 20.]);
+        assert_eq!(quad.positions, [[0., 0.], [0., 20.], [10., 20.], [10., 0.]]);
+        let (u, v, u2, v2) = (
+            texture_region.u(),
+            texture_region.v(),
+            texture_region.u2(),
+            texture_region.v2(),
+        );
+        assert_eq!(quad.uvs, [[u2, v], [u2, v2], [u, v2], [u, v]]);
+    }
+}
@@ -1,9 +1,11 @@
 use crate::{
     c::{
-        spBone, spTransformConstraint, spTransformConstraintData,
+        spBone, spSkeleton_findBone, spTransformConstraint, spTransformConstraintData,
         spTransformConstraint_setToSetupPose, spTransformConstraint_update,
     },
-    c_interface::{NewFromPtr, SyncPtr},
+    c_interface::{to_c_str, NewFromPtr, SyncPtr},
+    error::SpineError,
+    skeleton_data::check_skeleton_data_frozen,
     Bone, TransformConstraintData,
 };
 
@@ -38,13 +40,14 @@ impl TransformConstraint {
         }
     }
 
-    c_accessor_tmp_ptr_mut!(
+    c_accessor_tmp_ptr_data_mut!(
         /// The transform constraint's setup pose data.
         data,
         data_mut,
         data,
         TransformConstraintData,
-        spTransformConstraintData
+        spTransformConstraintData,
+        Self::check_data_frozen
     );
 
     c_accessor_bool!(active, active);
@@ -66,7 +69,7 @@ impl TransformConstraint {
     );
     c_accessor_mut!(
         /// A percentage (0-1) that controls the mix between the constrained and unconstrained
-        /// scale X.
+        /// scale Y.
         mix_scale_y,
         set_mix_scale_y,
         mixScaleY,
@@ -76,7 +79,7 @@ impl TransformConstraint {
         /// A percentage (0-1) that controls the mix between the constrained and unconstrained
         /// shear Y.
         mix_shear_y,
-        set_shear_y,
+        set_mix_shear_y,
         mixShearY,
         f32
     );
@@ -98,10 +101,13 @@ impl TransformConstraint {
     );
 
     c_accessor!(bones_count, bonesCount, usize);
-    c_accessor_array!(
+    c_accessor_array_mut!(
         /// The bones that will be modified by this transform constraint.
         bones,
+        /// The mutable bones that will be modified by this transform constraint.
+        bones_mut,
         bone_at_index,
+        bone_at_index_mut,
         TransformConstraint,
         Bone,
         spBone,
@@ -117,7 +123,60 @@ impl TransformConstraint {
         spBone
     );
 
+    /// Set the target bone whose world transform will be copied to the constrained bones, looked
+    /// up by name in this constraint's skeleton.
+    ///
+    /// Changing the target can change the order bones and constraints need to be updated in, so
+    /// [`Skeleton::update_cache`](`crate::Skeleton::update_cache`) must be called afterward.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SpineError::NotFound`] if the skeleton has no bone named `bone_name`.
+    pub fn set_target_by_name(&mut self, bone_name: &str) -> Result<(), SpineError> {
+        let c_bone_name = to_c_str(bone_name);
+        unsafe {
+            let c_skeleton = (*self.c_ptr_ref().target).skeleton;
+            let c_target = spSkeleton_findBone(c_skeleton, c_bone_name.as_ptr());
+            if c_target.is_null() {
+                return Err(SpineError::new_not_found("Bone", bone_name));
+            }
+            self.c_ptr_mut().target = c_target;
+        }
+        Ok(())
+    }
+
+    /// Set every mix to the same percentage (0-1), for fading the entire constraint in or out,
+    /// such as to hand control of the bones over to procedural motion.
+    ///
+    /// ```
+    /// # #[path="./test.rs"]
+    /// # mod test;
+    /// # let (mut skeleton, _) = test::TestAsset::all()[6].instance(true);
+    /// let mut transform_constraint = skeleton
+    ///     .find_transform_constraint_mut("wheel-big-transform")
+    ///     .unwrap();
+    /// transform_constraint.set_mix_all(0.5);
+    /// ```
+    pub fn set_mix_all(&mut self, mix: f32) {
+        self.set_mix_rotate(mix);
+        self.set_mix_x(mix);
+        self.set_mix_y(mix);
+        self.set_mix_scale_x(mix);
+        self.set_mix_scale_y(mix);
+        self.set_mix_shear_y(mix);
+    }
+
+    /// Set the shear Y mix, see [`mix_shear_y`](`Self::mix_shear_y`).
+    #[deprecated(note = "renamed to `set_mix_shear_y`")]
+    pub fn set_shear_y(&mut self, mix_shear_y: f32) {
+        self.set_mix_shear_y(mix_shear_y);
+    }
+
     c_ptr!(c_transform_constraint, spTransformConstraint);
+
+    fn check_data_frozen(&self) -> Result<(), SpineError> {
+        check_skeleton_data_frozen(unsafe { (*(*self.c_ptr_ref().target).skeleton).data })
+    }
 }
 
 /// Functions available if using the `mint` feature.
@@ -151,3 +210,44 @@ impl TransformConstraint {
         self.set_mix_y(mix.y);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{error::SpineError, test::TestAsset, Physics};
+
+    #[test]
+    fn set_mix_all_and_target() {
+        let (mut skeleton, _) = TestAsset::all()[6].instance(true);
+        assert!(skeleton.transform_constraints_count() > 0);
+        assert!(skeleton.transform_constraint_at_index(0).is_some());
+
+        let mut transform_constraint = skeleton
+            .find_transform_constraint_mut("wheel-big-transform")
+            .unwrap();
+        transform_constraint.set_mix_all(0.25);
+        assert_eq!(transform_constraint.mix_rotate(), 0.25);
+        assert_eq!(transform_constraint.mix_x(), 0.25);
+        assert_eq!(transform_constraint.mix_y(), 0.25);
+        assert_eq!(transform_constraint.mix_scale_x(), 0.25);
+        assert_eq!(transform_constraint.mix_scale_y(), 0.25);
+        assert_eq!(transform_constraint.mix_shear_y(), 0.25);
+        assert_eq!(
+            transform_constraint.bones_mut().count(),
+            transform_constraint.bones_count()
+        );
+
+        assert!(matches!(
+            transform_constraint.set_target_by_name("missing"),
+            Err(SpineError::NotFound { .. })
+        ));
+        transform_constraint
+            .set_target_by_name("wheel-mid1")
+            .unwrap();
+        skeleton.update_cache();
+        skeleton.update_world_transform(Physics::None);
+        let transform_constraint = skeleton
+            .find_transform_constraint("wheel-big-transform")
+            .unwrap();
+        assert_eq!(transform_constraint.target().data().name(), "wheel-mid1");
+    }
+}
@@ -0,0 +1,531 @@
+//! Data-driven outfits for dress-up and character customization systems, built on Spine's
+//! [mix-and-match](http://esotericsoftware.com/spine-examples-mix-and-match) workflow.
+//!
+//! A [`Wardrobe`] groups a skeleton's skins into categories, such as `"hair"` or `"shoes"`. An
+//! [`Outfit`] picks one piece (skin) from any number of categories, and can be validated against
+//! the wardrobe and combined into a single [`Skin`].
+//!
+//! ```
+//! # #[path="./test.rs"]
+//! # mod test;
+//! use rusty_spine::wardrobe::{Outfit, Wardrobe};
+//!
+//! # use std::sync::Arc;
+//! # use rusty_spine::{Skeleton, SkeletonJson};
+//! # let asset = &test::TestAsset::all()[4];
+//! let atlas = Arc::new(asset.atlas());
+//! let skeleton_data = SkeletonJson::new(atlas.clone()).read_skeleton_data(asset.json_data)?;
+//! let mut skeleton = Skeleton::new(Arc::new(skeleton_data));
+//! let wardrobe = Wardrobe::new()
+//!     .with_piece("body", "goblin")
+//!     .with_piece("body", "goblingirl")
+//!     .with_required("body");
+//! let outfit = Outfit::new().with("body", "goblingirl");
+//! wardrobe.dress(&mut skeleton, &outfit)?;
+//! for page_name in wardrobe.texture_pages(&skeleton.data(), &atlas, &outfit)? {
+//!     println!("needs texture page {page_name}");
+//! }
+//! # Ok::<(), rusty_spine::SpineError>(())
+//! ```
+//!
+//! Skins exported with folder names, such as `"hair/brown"`, can be grouped automatically with
+//! [`Wardrobe::from_skin_folders`].
+//!
+//! For crowds, a [`SkinPool`] picks a random outfit from weighted pieces, so some variants can be
+//! rarer than others.
+
+use alloc::collections::{BTreeMap, BTreeSet};
+
+use crate::prelude::*;
+use crate::{
+    attachment::Attachment,
+    c::{spAtlasRegion, spTextureRegion},
+    error::SpineError,
+    random::Random,
+    skeleton::Skeleton,
+    skeleton_data::SkeletonData,
+    skin::Skin,
+    Atlas,
+};
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+struct Category {
+    /// The names of the skins which can be worn in this category.
+    pieces: BTreeSet<String>,
+    /// Whether every outfit must include a piece from this category.
+    required: bool,
+}
+
+/// The categories of outfit pieces available for a skeleton. See the [module
+/// documentation](`self`).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Wardrobe {
+    categories: BTreeMap<String, Category>,
+}
+
+impl Wardrobe {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a wardrobe from the skins of `skeleton_data` named like `"category/piece"`, using
+    /// the folder name as the category. Skins without a folder, such as the default skin, are
+    /// not included.
+    #[must_use]
+    pub fn from_skin_folders(skeleton_data: &SkeletonData) -> Self {
+        let mut wardrobe = Self::new();
+        for skin in skeleton_data.skins() {
+            if let Some((category, _)) = skin.name().rsplit_once('/') {
+                wardrobe.add_piece(category, skin.name());
+            }
+        }
+        wardrobe
+    }
+
+    /// Add a skin as a piece which can be worn in `category`, creating the category if needed.
+    #[must_use]
+    pub fn with_piece(mut self, category: &str, skin_name: &str) -> Self {
+        self.add_piece(category, skin_name);
+        self
+    }
+
+    /// Add a skin as a piece which can be worn in `category`, creating the category if needed.
+    pub fn add_piece(&mut self, category: &str, skin_name: &str) {
+        self.categories
+            .entry(category.to_owned())
+            .or_default()
+            .pieces
+            .insert(skin_name.to_owned());
+    }
+
+    /// Require every outfit to include a piece from `category`, creating the category if needed.
+    #[must_use]
+    pub fn with_required(mut self, category: &str) -> Self {
+        self.set_required(category, true);
+        self
+    }
+
+    /// Set whether every outfit must include a piece from `category`, creating the category if
+    /// needed.
+    pub fn set_required(&mut self, category: &str, required: bool) {
+        self.categories
+            .entry(category.to_owned())
+            .or_default()
+            .required = required;
+    }
+
+    /// An iterator over the names of the categories.
+    pub fn categories(&self) -> impl Iterator<Item = &str> {
+        self.categories.keys().map(String::as_str)
+    }
+
+    /// An iterator over the skin names of the pieces in `category`.
+    pub fn pieces(&self, category: &str) -> impl Iterator<Item = &str> {
+        self.categories
+            .get(category)
+            .into_iter()
+            .flat_map(|category| category.pieces.iter().map(String::as_str))
+    }
+
+    /// Checks that every piece of the outfit belongs to its category and exists in
+    /// `skeleton_data`, and that the outfit includes a piece from every required category.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SpineError::NotFound`] for the first category, piece, or skin which is missing.
+    pub fn validate(
+        &self,
+        skeleton_data: &SkeletonData,
+        outfit: &Outfit,
+    ) -> Result<(), SpineError> {
+        for (category_name, category) in &self.categories {
+            if category.required && !outfit.pieces.contains_key(category_name) {
+                return Err(SpineError::new_not_found(
+                    "Outfit piece for category",
+                    category_name,
+                ));
+            }
+        }
+        for (category_name, skin_name) in &outfit.pieces {
+            let Some(category) = self.categories.get(category_name) else {
+                return Err(SpineError::new_not_found(
+                    "Wardrobe category",
+                    category_name,
+                ));
+            };
+            if !category.pieces.contains(skin_name) {
+                return Err(SpineError::new_not_found(
+                    &format!("Piece in category {category_name}"),
+                    skin_name,
+                ));
+            }
+            if skeleton_data.find_skin(skin_name).is_none() {
+                return Err(SpineError::new_not_found("Skin", skin_name));
+            }
+        }
+        Ok(())
+    }
+
+    /// Validates the outfit and combines its pieces into a single skin. See
+    /// [`validate`](`Self::validate`).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SpineError::NotFound`] if the outfit is not valid.
+    pub fn build_skin(
+        &self,
+        skeleton_data: &SkeletonData,
+        outfit: &Outfit,
+    ) -> Result<Skin, SpineError> {
+        self.validate(skeleton_data, outfit)?;
+        let mut skin = Skin::new("outfit");
+        for skin_name in outfit.pieces.values() {
+            let Some(piece) = skeleton_data.find_skin(skin_name) else {
+                return Err(SpineError::new_not_found("Skin", skin_name));
+            };
+            // Safety: the piece was found in the same skeleton data
+            unsafe { skin.add_skin(&piece) };
+        }
+        Ok(skin)
+    }
+
+    /// Validates the outfit, combines its pieces into a single skin, and sets it on the skeleton.
+    /// The skeleton's slots are reset to the setup pose so the new attachments are visible. See
+    /// [`build_skin`](`Self::build_skin`).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SpineError::NotFound`] if the outfit is not valid, in which case the skeleton is
+    /// unchanged.
+    pub fn dress(&self, skeleton: &mut Skeleton, outfit: &Outfit) -> Result<(), SpineError> {
+        let skin = self.build_skin(&skeleton.data(), outfit)?;
+        // Safety: the skin was built from the skeleton's own data
+        unsafe { skeleton.set_skin(&skin) };
+        Ok(())
+    }
+
+    /// The names of the atlas pages used by the outfit's attachments and by the default skin, so
+    /// only the textures needed by the outfit have to be loaded. Only regions from `atlas`, which
+    /// should be the atlas the skeleton data was loaded with, are included, so attachments using
+    /// custom texture regions are skipped.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SpineError::NotFound`] if the outfit is not valid.
+    pub fn texture_pages(
+        &self,
+        skeleton_data: &SkeletonData,
+        atlas: &Atlas,
+        outfit: &Outfit,
+    ) -> Result<BTreeSet<String>, SpineError> {
+        let skin = self.build_skin(skeleton_data, outfit)?;
+        let atlas_regions: BTreeSet<*const spAtlasRegion> = atlas
+            .regions()
+            .map(|region| region.c_ptr().cast_const())
+            .collect();
+        let mut pages = BTreeSet::new();
+        let mut entries = skin.attachments();
+        if !unsafe { skeleton_data.c_ptr_ref().defaultSkin }.is_null() {
+            entries.extend(skeleton_data.default_skin().attachments());
+        }
+        for entry in entries {
+            add_texture_pages(&entry.attachment, &atlas_regions, &mut pages);
+        }
+        Ok(pages)
+    }
+}
+
+/// Adds the atlas page names of the regions drawn by `attachment` to `pages`, skipping regions
+/// which aren't in `atlas_regions`.
+fn add_texture_pages(
+    attachment: &Attachment,
+    atlas_regions: &BTreeSet<*const spAtlasRegion>,
+    pages: &mut BTreeSet<String>,
+) {
+    let (renderer_object, sequence) = if let Some(region_attachment) = attachment.as_region() {
+        unsafe {
+            (
+                region_attachment.c_ptr_ref().rendererObject,
+                region_attachment.c_ptr_ref().sequence,
+            )
+        }
+    } else if let Some(mesh_attachment) = attachment.as_mesh() {
+        unsafe {
+            (
+                mesh_attachment.c_ptr_ref().rendererObject,
+                mesh_attachment.c_ptr_ref().sequence,
+            )
+        }
+    } else {
+        return;
+    };
+    let mut regions: Vec<*const spTextureRegion> = vec![renderer_object.cast()];
+    if !sequence.is_null() {
+        unsafe {
+            let sequence_regions = (*sequence).regions;
+            if !sequence_regions.is_null() && !(*sequence_regions).items.is_null() {
+                regions.extend(
+                    core::slice::from_raw_parts(
+                        (*sequence_regions).items,
+                        (*sequence_regions).size as usize,
+                    )
+                    .iter()
+                    .map(|region| region.cast_const()),
+                );
+            }
+        }
+    }
+    for region in regions {
+        // Other texture regions may be smaller than an atlas region, so only atlas regions are
+        // read as one
+        let region = region.cast::<spAtlasRegion>();
+        if !atlas_regions.contains(&region) {
+            continue;
+        }
+        let page = unsafe { (*region).page };
+        if page.is_null() || unsafe { (*page).name.is_null() } {
+            continue;
+        }
+        let name = unsafe { core::ffi::CStr::from_ptr((*page).name) };
+        pages.insert(name.to_string_lossy().into_owned());
+    }
+}
+
+/// A choice of one piece (skin name) per [`Wardrobe`] category.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Outfit {
+    pieces: BTreeMap<String, String>,
+}
+
+impl Outfit {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Wear `skin_name` in `category`, replacing any piece already worn there.
+    #[must_use]
+    pub fn with(mut self, category: &str, skin_name: &str) -> Self {
+        self.set(category, skin_name);
+        self
+    }
+
+    /// Wear `skin_name` in `category`, replacing any piece already worn there.
+    pub fn set(&mut self, category: &str, skin_name: &str) {
+        self.pieces
+            .insert(category.to_owned(), skin_name.to_owned());
+    }
+
+    /// Stop wearing a piece in `category`, returning the skin name of the piece if there was one.
+    pub fn remove(&mut self, category: &str) -> Option<String> {
+        self.pieces.remove(category)
+    }
+
+    /// The skin name of the piece worn in `category`.
+    #[must_use]
+    pub fn get(&self, category: &str) -> Option<&str> {
+        self.pieces.get(category).map(String::as_str)
+    }
+
+    /// An iterator over the worn `(category, skin_name)` pairs.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.pieces
+            .iter()
+            .map(|(category, skin_name)| (category.as_str(), skin_name.as_str()))
+    }
+}
+
+/// Weighted pieces (skins) per category, for dressing crowds in random outfits. Each category
+/// contributes one piece to an outfit, chosen with a probability proportional to its weight.
+///
+/// ```
+/// # #[path="./test.rs"]
+/// # mod test;
+/// use rusty_spine::wardrobe::SkinPool;
+///
+/// # let (mut skeleton, _) = test::TestAsset::all()[4].instance(true);
+/// // One in four goblins is a goblin girl.
+/// let pool = SkinPool::new()
+///     .with_piece("body", "goblin", 3.)
+///     .with_piece("body", "goblingirl", 1.);
+/// let outfit = pool.dress(&mut skeleton, 42)?;
+/// println!("spawned wearing {:?}", outfit.get("body"));
+/// # Ok::<(), rusty_spine::SpineError>(())
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SkinPool {
+    categories: BTreeMap<String, Vec<(String, f32)>>,
+}
+
+impl SkinPool {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a skin as a piece which can be chosen for `category` with the given weight, creating
+    /// the category if needed. Pieces with a weight of `0` or less are never chosen.
+    #[must_use]
+    pub fn with_piece(mut self, category: &str, skin_name: &str, weight: f32) -> Self {
+        self.add_piece(category, skin_name, weight);
+        self
+    }
+
+    /// Add a skin as a piece which can be chosen for `category` with the given weight, creating
+    /// the category if needed, or replacing the weight if the piece was already added.
+    pub fn add_piece(&mut self, category: &str, skin_name: &str, weight: f32) {
+        let pieces = self.categories.entry(category.to_owned()).or_default();
+        if let Some(piece) = pieces.iter_mut().find(|(name, _)| name == skin_name) {
+            piece.1 = weight;
+        } else {
+            pieces.push((skin_name.to_owned(), weight));
+        }
+    }
+
+    /// The [`Wardrobe`] containing every piece of the pool, with every category required.
+    #[must_use]
+    pub fn wardrobe(&self) -> Wardrobe {
+        let mut wardrobe = Wardrobe::new();
+        for (category, pieces) in &self.categories {
+            for (skin_name, _) in pieces {
+                wardrobe.add_piece(category, skin_name);
+            }
+            wardrobe.set_required(category, true);
+        }
+        wardrobe
+    }
+
+    /// Choose one piece from every category. The same seed always produces the same outfit.
+    /// Categories without a piece of positive weight are left out of the outfit.
+    #[must_use]
+    pub fn choose(&self, seed: u64) -> Outfit {
+        let mut random = Random::new(seed);
+        let mut outfit = Outfit::new();
+        for (category, pieces) in &self.categories {
+            let total: f32 = pieces.iter().map(|(_, weight)| weight.max(0.)).sum();
+            if total <= 0. {
+                continue;
+            }
+            let mut remaining = random.next_f32() * total;
+            let mut chosen = None;
+            for (skin_name, weight) in pieces.iter().filter(|(_, weight)| *weight > 0.) {
+                chosen = Some(skin_name);
+                remaining -= weight;
+                if remaining < 0. {
+                    break;
+                }
+            }
+            if let Some(skin_name) = chosen {
+                outfit.set(category, skin_name);
+            }
+        }
+        outfit
+    }
+
+    /// Choose an outfit and set it on the skeleton, returning the outfit. See
+    /// [`choose`](`Self::choose`) and [`Wardrobe::dress`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SpineError::NotFound`] if a chosen skin is missing from the skeleton's data, or a
+    /// category has no piece of positive weight, in which case the skeleton is unchanged.
+    pub fn dress(&self, skeleton: &mut Skeleton, seed: u64) -> Result<Outfit, SpineError> {
+        let outfit = self.choose(seed);
+        self.wardrobe().dress(skeleton, &outfit)?;
+        Ok(outfit)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::sync::Arc;
+
+    use crate::{test::TestAsset, SkeletonJson, SpineError};
+
+    use super::*;
+
+    #[test]
+    fn wardrobe() {
+        let asset = &TestAsset::all()[4];
+        let atlas = Arc::new(asset.atlas());
+        let skeleton_data = SkeletonJson::new(atlas.clone())
+            .read_skeleton_data(asset.json_data)
+            .unwrap();
+        let mut skeleton = Skeleton::new(Arc::new(skeleton_data));
+        let wardrobe = Wardrobe::new()
+            .with_piece("body", "goblin")
+            .with_piece("body", "goblingirl")
+            .with_piece("hat", "missing")
+            .with_required("body");
+        assert_eq!(wardrobe.categories().collect::<Vec<_>>(), ["body", "hat"]);
+
+        assert!(matches!(
+            wardrobe.dress(&mut skeleton, &Outfit::new()),
+            Err(SpineError::NotFound { .. })
+        ));
+        assert!(matches!(
+            wardrobe.dress(&mut skeleton, &Outfit::new().with("body", "default")),
+            Err(SpineError::NotFound { .. })
+        ));
+        assert!(matches!(
+            wardrobe.dress(
+                &mut skeleton,
+                &Outfit::new().with("body", "goblin").with("hat", "missing")
+            ),
+            Err(SpineError::NotFound { .. })
+        ));
+        assert!(skeleton.skin().is_none());
+
+        let outfit = Outfit::new().with("body", "goblingirl");
+        wardrobe.dress(&mut skeleton, &outfit).unwrap();
+        let torso = skeleton.find_slot("torso").unwrap();
+        assert_eq!(torso.attachment().unwrap().name(), "goblingirl/torso");
+
+        let pages = wardrobe
+            .texture_pages(&skeleton.data(), &atlas, &outfit)
+            .unwrap();
+        assert_eq!(
+            pages.into_iter().collect::<Vec<_>>(),
+            ["goblins.png".to_owned()]
+        );
+        // regions from another atlas are never followed to their page
+        let pages = wardrobe
+            .texture_pages(&skeleton.data(), &asset.atlas(), &outfit)
+            .unwrap();
+        assert!(pages.is_empty());
+    }
+
+    #[test]
+    fn skin_pool() {
+        let (mut skeleton, _) = TestAsset::all()[4].instance(true);
+        let pool = SkinPool::new()
+            .with_piece("body", "goblin", 3.)
+            .with_piece("body", "goblingirl", 1.)
+            .with_piece("body", "never", 0.);
+        assert_eq!(pool.choose(7), pool.choose(7));
+        let goblin_girls = (0..1000)
+            .filter(|seed| pool.choose(*seed).get("body") == Some("goblingirl"))
+            .count();
+        assert!((150..350).contains(&goblin_girls), "{goblin_girls}");
+        assert!((0..1000).all(|seed| pool.choose(seed).get("body") != Some("never")));
+
+        let outfit = pool.dress(&mut skeleton, 3).unwrap();
+        let torso = skeleton.find_slot("torso").unwrap();
+        assert_eq!(
+            torso.attachment().unwrap().name(),
+            format!("{}/torso", outfit.get("body").unwrap())
+        );
+
+        let missing = pool.clone().with_piece("hat", "missing", 1.);
+        assert!(matches!(
+            missing.dress(&mut skeleton, 3),
+            Err(SpineError::NotFound { .. })
+        ));
+        let empty = pool.with_piece("hat", "missing", 0.);
+        assert!(matches!(
+            empty.dress(&mut skeleton, 3),
+            Err(SpineError::NotFound { .. })
+        ));
+    }
+}
@@ -126,6 +126,16 @@ pub fn fix_source(name: &str, mut src: String) -> String {
         src = replace_identifier(src, "readSequence", "readSequenceJson", 0);
         src = replace_identifier(src, "_readVertices", "_readVerticesJson", 0);
         src = replace_identifier(src, "string_starts_with", "string_starts_with_json", 0);
+        // Keyed audio events read their balance from the volume key, and ignore the defaults from
+        // the event data.
+        src = src.replace(
+            "event->volume = Json_getFloat(keyMap, \"volume\", 1);",
+            "event->volume = Json_getFloat(keyMap, \"volume\", eventData->volume);",
+        );
+        src = src.replace(
+            "event->balance = Json_getFloat(keyMap, \"volume\", 0);",
+            "event->balance = Json_getFloat(keyMap, \"balance\", eventData->balance);",
+        );
         src
     } else if name == "SkeletonBinary.c" {
         src = replace_identifier(src, "_spLinkedMesh", "_spLinkedMeshBinary", 0);
@@ -234,6 +244,7 @@ pub fn c2rust(input: &str, output: &str) {
 pub fn rust_fixes(input: &str, output: &str) {
     let mut src = read_to_string(input).unwrap();
     src = src.replace("libc::", "");
+    src = src.replace("std::ptr::", "core::ptr::");
     src = src.replace("#![register_tool(c2rust)]\n", "");
     src = src.replace("#![feature(extern_types, label_break_value)]\n", "");
     src = src.replace("pub type _IO_wide_data;", "");